@@ -20,9 +20,11 @@
 //!   and forces it false; exiting restores it (issue #279 parity).
 
 pub mod connectivity;
+pub mod network;
 pub mod store;
 
 pub use connectivity::{Connectivity, ConnectivityActor, ConnectivitySnapshot};
+pub use network::{detect_network_type, wifi_required, NetworkType};
 pub use store::{OfflineModeSettings, OfflineModeStore, QueuedScrobble};
 
 use serde::{Deserialize, Serialize};
@@ -176,6 +178,16 @@ impl OfflineModeEngine {
         store.set_show_network_folders_in_manual_offline(enabled)
     }
 
+    /// Persist the "download only on Wi-Fi" flag (Settings > Offline).
+    pub fn set_wifi_only_download(&self, enabled: bool) -> Result<(), String> {
+        let guard = self
+            .store
+            .lock()
+            .map_err(|e| format!("offline store lock poisoned: {}", e))?;
+        let store = guard.as_ref().ok_or("No active session")?;
+        store.set_wifi_only_download(enabled)
+    }
+
     /// Flip induced offline (Settings toggle). Always succeeds in either
     /// direction; persists the flag, handles the #279 snapshot/restore, then
     /// recomputes the mode (which flips the Qobuz gate).
@@ -0,0 +1,163 @@
+//! Network-type detection for the "download only on Wi-Fi" setting.
+//!
+//! Linux only: the interface carrying the default route (`ip route show
+//! default`, lowest metric wins) is classified as Wi-Fi when `iw dev` lists
+//! it (or sysfs marks it wireless), and otherwise by its kernel name —
+//! `en*`/`eth*` are Ethernet; modem and phone-tether names (`wwan*`, `wwp*`,
+//! `rmnet*`, `ppp*`, `usb*`) count as Cellular. Anything else, and every
+//! other platform, is `Unknown`, which the Wi-Fi-only gate treats as "not
+//! Wi-Fi".
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkType {
+    Wifi,
+    Ethernet,
+    Cellular,
+    Unknown,
+}
+
+impl NetworkType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NetworkType::Wifi => "wifi",
+            NetworkType::Ethernet => "ethernet",
+            NetworkType::Cellular => "cellular",
+            NetworkType::Unknown => "unknown",
+        }
+    }
+}
+
+/// Classify the connection the default route uses. Runs `ip` and `iw`, so
+/// call it off the UI thread.
+pub fn detect_network_type() -> NetworkType {
+    #[cfg(target_os = "linux")]
+    {
+        let Some(routes) = command_stdout("ip", &["route", "show", "default"]) else {
+            return NetworkType::Unknown;
+        };
+        let Some(iface) = default_route_interface(&routes) else {
+            return NetworkType::Unknown;
+        };
+        let wireless = command_stdout("iw", &["dev"])
+            .map(|out| wireless_interfaces(&out).iter().any(|w| *w == iface))
+            .unwrap_or(false)
+            || std::path::Path::new("/sys/class/net")
+                .join(&iface)
+                .join("wireless")
+                .exists();
+        classify_interface(&iface, wireless)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        NetworkType::Unknown
+    }
+}
+
+/// Whether the Wi-Fi-only setting blocks a download on `network`.
+pub fn wifi_required(wifi_only: bool, network: NetworkType) -> bool {
+    wifi_only && network != NetworkType::Wifi
+}
+
+#[cfg(target_os = "linux")]
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The `dev` of the lowest-metric `default` route in `ip route` output.
+fn default_route_interface(routes: &str) -> Option<String> {
+    routes
+        .lines()
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.first() != Some(&"default") {
+                return None;
+            }
+            let value_after = |key: &str| {
+                tokens
+                    .iter()
+                    .position(|t| *t == key)
+                    .and_then(|i| tokens.get(i + 1))
+                    .copied()
+            };
+            let dev = value_after("dev")?;
+            let metric = value_after("metric")
+                .and_then(|m| m.parse::<u32>().ok())
+                .unwrap_or(0);
+            Some((metric, dev.to_string()))
+        })
+        .min_by_key(|(metric, _)| *metric)
+        .map(|(_, dev)| dev)
+}
+
+/// Interface names from `iw dev` output (`Interface wlp2s0` lines).
+fn wireless_interfaces(iw_dev: &str) -> Vec<String> {
+    iw_dev
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Interface "))
+        .map(|name| name.trim().to_string())
+        .collect()
+}
+
+fn classify_interface(iface: &str, wireless: bool) -> NetworkType {
+    const CELLULAR: [&str; 5] = ["wwan", "wwp", "rmnet", "ppp", "usb"];
+    if wireless || iface.starts_with("wl") {
+        NetworkType::Wifi
+    } else if CELLULAR.iter().any(|p| iface.starts_with(p)) {
+        NetworkType::Cellular
+    } else if iface.starts_with("en") || iface.starts_with("eth") {
+        NetworkType::Ethernet
+    } else {
+        NetworkType::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_route_picks_the_lowest_metric() {
+        let routes = "default via 192.168.1.1 dev wlp2s0 proto dhcp src 192.168.1.20 metric 600\n\
+                      default via 10.0.0.1 dev enp3s0 proto dhcp metric 100\n";
+        assert_eq!(default_route_interface(routes).as_deref(), Some("enp3s0"));
+        assert_eq!(
+            default_route_interface("default dev ppp0 scope link").as_deref(),
+            Some("ppp0")
+        );
+        assert_eq!(default_route_interface("10.0.0.0/24 dev enp3s0"), None);
+    }
+
+    #[test]
+    fn interfaces_are_classified() {
+        let iw = "phy#0\n\tInterface wlp2s0\n\t\tifindex 3\n\t\ttype managed\n";
+        assert_eq!(wireless_interfaces(iw), vec!["wlp2s0".to_string()]);
+
+        assert_eq!(classify_interface("wlp2s0", true), NetworkType::Wifi);
+        assert_eq!(classify_interface("wlan0", false), NetworkType::Wifi);
+        assert_eq!(classify_interface("enp3s0", false), NetworkType::Ethernet);
+        assert_eq!(
+            classify_interface("wwp0s20u4", false),
+            NetworkType::Cellular
+        );
+        assert_eq!(classify_interface("usb0", false), NetworkType::Cellular);
+        assert_eq!(classify_interface("tun0", false), NetworkType::Unknown);
+    }
+
+    #[test]
+    fn wifi_only_blocks_everything_but_wifi() {
+        assert!(!wifi_required(false, NetworkType::Cellular));
+        assert!(!wifi_required(true, NetworkType::Wifi));
+        assert!(wifi_required(true, NetworkType::Ethernet));
+        assert!(wifi_required(true, NetworkType::Unknown));
+    }
+}
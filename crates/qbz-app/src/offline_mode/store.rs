@@ -9,6 +9,7 @@
 //! - `show_network_folders_in_manual_offline` — network-mount policy (D9).
 //! - `pre_offline_stream_first_track` — the issue #279 snapshot of
 //!   `audio_settings.stream_first_track` taken on entering induced offline.
+//! - `wifi_only_download` — only start offline-cache downloads on Wi-Fi.
 //!
 //! The legacy columns/tables (cast/scrobbling flags, `pending_playlist_sync`,
 //! `scrobble_queue`, `cache_limit_bytes`) are still CREATED for byte-level
@@ -26,6 +27,7 @@ use std::path::Path;
 pub struct OfflineModeSettings {
     pub manual_offline_mode: bool,
     pub show_network_folders_in_manual_offline: bool,
+    pub wifi_only_download: bool,
 }
 
 /// One row of the Last.fm offline scrobble queue (`scrobble_queue`). Mirrors
@@ -108,6 +110,7 @@ impl OfflineModeStore {
             "ALTER TABLE pending_playlist_sync ADD COLUMN local_track_ids TEXT",
             "ALTER TABLE pending_playlist_sync ADD COLUMN local_track_paths TEXT",
             "ALTER TABLE offline_settings ADD COLUMN cache_limit_bytes INTEGER",
+            "ALTER TABLE offline_settings ADD COLUMN wifi_only_download INTEGER NOT NULL DEFAULT 0",
         ];
         for migration in migrations {
             let _ = conn.execute(migration, []);
@@ -120,13 +123,15 @@ impl OfflineModeStore {
         self.conn
             .query_row(
                 "SELECT manual_offline_mode,
-                        COALESCE(show_network_folders_in_manual_offline, 0)
+                        COALESCE(show_network_folders_in_manual_offline, 0),
+                        COALESCE(wifi_only_download, 0)
                  FROM offline_settings WHERE id = 1",
                 [],
                 |row| {
                     Ok(OfflineModeSettings {
                         manual_offline_mode: row.get::<_, i64>(0)? != 0,
                         show_network_folders_in_manual_offline: row.get::<_, i64>(1)? != 0,
+                        wifi_only_download: row.get::<_, i64>(2)? != 0,
                     })
                 },
             )
@@ -153,6 +158,16 @@ impl OfflineModeStore {
         Ok(())
     }
 
+    pub fn set_wifi_only_download(&self, enabled: bool) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE offline_settings SET wifi_only_download = ?1 WHERE id = 1",
+                params![enabled as i64],
+            )
+            .map_err(|e| format!("Failed to set wifi-only download: {}", e))?;
+        Ok(())
+    }
+
     /// Issue #279 snapshot: the user's `stream_first_track` preference stashed
     /// when entering induced offline. `None` = no snapshot active.
     pub fn get_pre_offline_stream_first_track(&self) -> Result<Option<bool>, String> {
@@ -313,6 +328,7 @@ mod tests {
         let settings = store.get_settings().unwrap();
         assert!(!settings.manual_offline_mode);
         assert!(!settings.show_network_folders_in_manual_offline);
+        assert!(!settings.wifi_only_download);
         assert_eq!(store.get_pre_offline_stream_first_track().unwrap(), None);
 
         let _ = std::fs::remove_dir_all(dir);
@@ -347,6 +363,19 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn wifi_only_flag_round_trips() {
+        let dir = unique_test_dir("offline-store-wifi-only");
+        let store = OfflineModeStore::new_at(&dir).unwrap();
+
+        store.set_wifi_only_download(true).unwrap();
+        assert!(store.get_settings().unwrap().wifi_only_download);
+        store.set_wifi_only_download(false).unwrap();
+        assert!(!store.get_settings().unwrap().wifi_only_download);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn scrobble_queue_round_trips_and_marks_sent() {
         let dir = unique_test_dir("offline-store-scrobbles");
@@ -763,6 +763,7 @@ mod tests {
             gapless_ready: false,
             gapless_next_track_id: 0,
             bit_perfect_mode: None,
+            output_format: None,
             buffer_progress: None,
         }
     }
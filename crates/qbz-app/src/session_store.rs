@@ -4,6 +4,13 @@
 //! Tauri/Svelte shell also stores view restoration fields in the same DB table;
 //! those fields are modeled here only so the existing schema can round-trip
 //! unchanged during the extraction.
+//!
+//! Per-track position bookmarks live in the same DB (`track_bookmarks`): a
+//! track can hold several, one per position, each with an optional label.
+//!
+//! Listening statistics (`listening_stats`) are daily totals per artist and
+//! genre, fed by a [`PlaybackStatisticsCollector`]. The DB is per user, so
+//! each account keeps its own stats across session switches.
 
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
@@ -91,12 +98,117 @@ impl Default for PersistedShellViewState {
     }
 }
 
+/// A saved position inside a track.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bookmark {
+    pub id: i64,
+    /// Queue source of the track ("qobuz", "local", "plex", ...); track ids
+    /// are only unique within one.
+    pub source: String,
+    pub track_id: u64,
+    pub position_ms: u64,
+    pub label: Option<String>,
+    pub created_at: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct PersistedSessionSnapshot {
     pub playback: PersistedPlaybackSession,
     pub shell_view: PersistedShellViewState,
 }
 
+/// The track listening time is attributed to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListeningTrack {
+    pub track_id: u64,
+    pub artist_id: Option<u64>,
+    pub artist: String,
+    pub genre: Option<String>,
+}
+
+/// Listening time accumulated for one track, ready for
+/// [`SessionStore::record_listening`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListenedSegment {
+    pub track: ListeningTrack,
+    pub secs: u64,
+}
+
+/// Largest position advance between two ticks still counted as listening;
+/// anything bigger is a seek.
+const MAX_TICK_ADVANCE_SECS: u64 = 5;
+
+/// Turns playback ticks into listened time per track. Only forward progress
+/// while playing counts: pauses, seeks and replays of a section add nothing.
+#[derive(Debug, Default)]
+pub struct PlaybackStatisticsCollector {
+    track: Option<ListeningTrack>,
+    last_position: Option<u64>,
+    pending_secs: u64,
+}
+
+impl PlaybackStatisticsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attribute listening to `track` from now on. Returns what was still
+    /// pending for the previous track.
+    pub fn start_track(&mut self, track: ListeningTrack) -> Option<ListenedSegment> {
+        let previous = self.take_segment();
+        self.track = Some(track);
+        self.last_position = None;
+        previous
+    }
+
+    /// Feed one playback tick (`position_secs` of `track_id`).
+    pub fn observe(&mut self, track_id: u64, position_secs: u64, is_playing: bool) {
+        let Some(track) = &self.track else {
+            return;
+        };
+        if track.track_id != track_id || !is_playing {
+            self.last_position = None;
+            return;
+        }
+        if let Some(last) = self.last_position {
+            let advance = position_secs.saturating_sub(last);
+            if advance <= MAX_TICK_ADVANCE_SECS {
+                self.pending_secs += advance;
+            }
+        }
+        self.last_position = Some(position_secs);
+    }
+
+    /// Take the listening accumulated since the last take (None if nothing
+    /// was listened).
+    pub fn take_segment(&mut self) -> Option<ListenedSegment> {
+        let secs = std::mem::take(&mut self.pending_secs);
+        let track = self.track.clone()?;
+        (secs > 0).then_some(ListenedSegment { track, secs })
+    }
+}
+
+/// Listening time of one artist or genre over a period.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ListeningStatEntry {
+    pub name: String,
+    /// Set for artist entries when the Qobuz artist id is known
+    pub artist_id: Option<u64>,
+    pub secs: u64,
+}
+
+/// Aggregated listening statistics for `period` ("week" | "month" | "year").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ListeningStats {
+    pub period: String,
+    pub total_secs: u64,
+    pub top_artists: Vec<ListeningStatEntry>,
+    pub top_genres: Vec<ListeningStatEntry>,
+}
+
+/// Entries returned per ranking in [`ListeningStats`].
+const LISTENING_STATS_TOP: i64 = 10;
+
 pub struct SessionStore {
     conn: Connection,
 }
@@ -153,6 +265,26 @@ impl SessionStore {
                 source TEXT
             );
 
+            CREATE TABLE IF NOT EXISTS track_bookmarks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                source TEXT NOT NULL DEFAULT 'qobuz',
+                track_id INTEGER NOT NULL,
+                position_ms INTEGER NOT NULL,
+                label TEXT,
+                created_at INTEGER NOT NULL,
+                UNIQUE (source, track_id, position_ms)
+            );
+
+            CREATE TABLE IF NOT EXISTS listening_stats (
+                artist_key TEXT NOT NULL,
+                artist TEXT NOT NULL,
+                artist_id INTEGER,
+                genre TEXT NOT NULL DEFAULT '',
+                date TEXT NOT NULL,
+                duration_listened_secs INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (artist_key, genre, date)
+            );
+
             INSERT OR IGNORE INTO player_state (id, current_position_secs, volume, shuffle_enabled, repeat_mode, was_playing, saved_at)
             VALUES (1, 0, 0.75, 0, 'off', 0, 0);
             ",
@@ -252,6 +384,83 @@ impl SessionStore {
             );
         }
 
+        // listening_stats was first keyed by artist name, which merged
+        // namesakes; rebuild it keyed by `artist_key`.
+        let has_artist_key: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('listening_stats') WHERE name = 'artist_key'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(0)
+            > 0;
+
+        if !has_artist_key {
+            let migrated = conn.execute_batch(
+                "
+                BEGIN;
+                ALTER TABLE listening_stats RENAME TO listening_stats_by_name;
+                CREATE TABLE listening_stats (
+                    artist_key TEXT NOT NULL,
+                    artist TEXT NOT NULL,
+                    artist_id INTEGER,
+                    genre TEXT NOT NULL DEFAULT '',
+                    date TEXT NOT NULL,
+                    duration_listened_secs INTEGER NOT NULL DEFAULT 0,
+                    PRIMARY KEY (artist_key, genre, date)
+                );
+                INSERT INTO listening_stats
+                SELECT CASE WHEN artist_id IS NULL THEN 'name:' || artist ELSE 'id:' || artist_id END,
+                       artist, artist_id, genre, date, duration_listened_secs
+                FROM listening_stats_by_name;
+                DROP TABLE listening_stats_by_name;
+                COMMIT;
+                ",
+            );
+            if migrated.is_err() {
+                let _ = conn.execute_batch("ROLLBACK");
+            }
+        }
+
+        // track_bookmarks was first keyed by track id alone, so a local
+        // library row and a Qobuz track with the same id shared bookmarks;
+        // rebuild it keyed by `source` too. Old rows predate local
+        // bookmarks being told apart and are kept as Qobuz.
+        let has_bookmark_source: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('track_bookmarks') WHERE name = 'source'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(0)
+            > 0;
+
+        if !has_bookmark_source {
+            let migrated = conn.execute_batch(
+                "
+                BEGIN;
+                ALTER TABLE track_bookmarks RENAME TO track_bookmarks_by_id;
+                CREATE TABLE track_bookmarks (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    source TEXT NOT NULL DEFAULT 'qobuz',
+                    track_id INTEGER NOT NULL,
+                    position_ms INTEGER NOT NULL,
+                    label TEXT,
+                    created_at INTEGER NOT NULL,
+                    UNIQUE (source, track_id, position_ms)
+                );
+                INSERT INTO track_bookmarks (id, source, track_id, position_ms, label, created_at)
+                SELECT id, 'qobuz', track_id, position_ms, label, created_at
+                FROM track_bookmarks_by_id;
+                DROP TABLE track_bookmarks_by_id;
+                COMMIT;
+                ",
+            );
+            if migrated.is_err() {
+                let _ = conn.execute_batch("ROLLBACK");
+            }
+        }
+
         Ok(Self { conn })
     }
 
@@ -469,6 +678,170 @@ impl SessionStore {
         Ok(())
     }
 
+    /// Bookmark `position_ms` of a track. Bookmarking the same position
+    /// again replaces its label.
+    pub fn set_bookmark(
+        &self,
+        source: &str,
+        track_id: u64,
+        position_ms: u64,
+        label: Option<String>,
+    ) -> Result<(), String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        self.conn
+            .execute(
+                "INSERT INTO track_bookmarks (source, track_id, position_ms, label, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT(source, track_id, position_ms) DO UPDATE SET label = excluded.label",
+                params![source, track_id as i64, position_ms as i64, label, now],
+            )
+            .map_err(|e| format!("Failed to save bookmark: {}", e))?;
+
+        Ok(())
+    }
+
+    /// A track's bookmarks, in position order.
+    pub fn get_bookmarks(&self, source: &str, track_id: u64) -> Result<Vec<Bookmark>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, source, track_id, position_ms, label, created_at FROM track_bookmarks
+                 WHERE source = ?1 AND track_id = ?2 ORDER BY position_ms",
+            )
+            .map_err(|e| format!("Failed to prepare bookmark query: {}", e))?;
+
+        let bookmarks = stmt
+            .query_map(params![source, track_id as i64], |row| {
+                Ok(Bookmark {
+                    id: row.get(0)?,
+                    source: row.get(1)?,
+                    track_id: row.get::<_, i64>(2)? as u64,
+                    position_ms: row.get::<_, i64>(3)? as u64,
+                    label: row.get(4)?,
+                    created_at: row.get(5)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query bookmarks: {}", e))?
+            .filter_map(|result| result.ok())
+            .collect();
+
+        Ok(bookmarks)
+    }
+
+    pub fn delete_bookmark(
+        &self,
+        source: &str,
+        track_id: u64,
+        bookmark_id: i64,
+    ) -> Result<(), String> {
+        self.conn
+            .execute(
+                "DELETE FROM track_bookmarks WHERE source = ?1 AND track_id = ?2 AND id = ?3",
+                params![source, track_id as i64, bookmark_id],
+            )
+            .map_err(|e| format!("Failed to delete bookmark: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Add a listened segment to today's (local date) totals.
+    pub fn record_listening(&self, segment: &ListenedSegment) -> Result<(), String> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        self.record_listening_on(&today, segment)
+    }
+
+    fn record_listening_on(&self, date: &str, segment: &ListenedSegment) -> Result<(), String> {
+        let track = &segment.track;
+        // Artists are told apart by Qobuz id; local files without one fall
+        // back to the name.
+        let artist_key = match track.artist_id {
+            Some(id) => format!("id:{}", id),
+            None => format!("name:{}", track.artist),
+        };
+        self.conn
+            .execute(
+                "INSERT INTO listening_stats (artist_key, artist, artist_id, genre, date, duration_listened_secs)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(artist_key, genre, date) DO UPDATE SET
+                     duration_listened_secs = duration_listened_secs + excluded.duration_listened_secs,
+                     artist = excluded.artist",
+                params![
+                    artist_key,
+                    track.artist,
+                    track.artist_id.map(|id| id as i64),
+                    track.genre.as_deref().unwrap_or(""),
+                    date,
+                    segment.secs as i64
+                ],
+            )
+            .map_err(|e| format!("Failed to record listening: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Listening totals and the top 10 artists and genres for the last
+    /// `period`: "week" (7 days), "month" (30) or "year" (365).
+    pub fn get_listening_stats(&self, period: &str) -> Result<ListeningStats, String> {
+        let days = match period {
+            "week" => 7,
+            "month" => 30,
+            "year" => 365,
+            other => return Err(format!("Unknown listening stats period: {}", other)),
+        };
+        let since = (chrono::Local::now() - chrono::Duration::days(days - 1))
+            .format("%Y-%m-%d")
+            .to_string();
+        self.listening_stats_since(period, &since)
+    }
+
+    fn listening_stats_since(&self, period: &str, since: &str) -> Result<ListeningStats, String> {
+        let total_secs: i64 = self
+            .conn
+            .query_row(
+                "SELECT COALESCE(SUM(duration_listened_secs), 0) FROM listening_stats
+                 WHERE date >= ?1",
+                params![since],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to query listening stats: {}", e))?;
+
+        let top = |sql: &str| -> Result<Vec<ListeningStatEntry>, String> {
+            let mut stmt = self
+                .conn
+                .prepare(sql)
+                .map_err(|e| format!("Failed to prepare listening stats query: {}", e))?;
+            let entries = stmt
+                .query_map(params![since, LISTENING_STATS_TOP], |row| {
+                    Ok(ListeningStatEntry {
+                        name: row.get(0)?,
+                        artist_id: row.get::<_, Option<i64>>(1)?.map(|id| id as u64),
+                        secs: row.get::<_, i64>(2)? as u64,
+                    })
+                })
+                .map_err(|e| format!("Failed to query listening stats: {}", e))?
+                .filter_map(|result| result.ok())
+                .collect();
+            Ok(entries)
+        };
+
+        Ok(ListeningStats {
+            period: period.to_string(),
+            total_secs: total_secs as u64,
+            top_artists: top(
+                "SELECT MAX(artist), MAX(artist_id), SUM(duration_listened_secs) AS secs
+                 FROM listening_stats WHERE date >= ?1
+                 GROUP BY artist_key ORDER BY secs DESC LIMIT ?2",
+            )?,
+            top_genres: top("SELECT genre, NULL, SUM(duration_listened_secs) AS secs
+                 FROM listening_stats WHERE date >= ?1 AND genre != ''
+                 GROUP BY genre ORDER BY secs DESC LIMIT ?2")?,
+        })
+    }
+
     pub fn clear_session(&self) -> Result<(), String> {
         self.conn
             .execute("DELETE FROM queue_tracks", [])
@@ -619,6 +992,271 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn bookmarks_are_per_track_ordered_and_survive_clear_session() {
+        let dir = unique_test_dir("session-bookmarks");
+        let store = SessionStore::new_at(&dir).expect("open store");
+        let count = |source, track_id| {
+            store
+                .get_bookmarks(source, track_id)
+                .expect("get bookmarks")
+                .len()
+        };
+
+        store
+            .set_bookmark("qobuz", 42, 90_000, Some("Adagio".to_string()))
+            .expect("set bookmark");
+        store
+            .set_bookmark("qobuz", 42, 15_500, None)
+            .expect("set bookmark");
+        store
+            .set_bookmark("qobuz", 7, 1_000, None)
+            .expect("set bookmark");
+        store
+            .set_bookmark("qobuz", 42, 90_000, Some("Second movement".to_string()))
+            .expect("relabel bookmark");
+
+        let bookmarks = store.get_bookmarks("qobuz", 42).expect("get bookmarks");
+        let positions: Vec<u64> = bookmarks.iter().map(|b| b.position_ms).collect();
+        assert_eq!(positions, vec![15_500, 90_000]);
+        assert_eq!(bookmarks[1].label.as_deref(), Some("Second movement"));
+
+        store.clear_session().expect("clear session");
+        store
+            .delete_bookmark("qobuz", 7, bookmarks[0].id)
+            .expect("delete with the wrong track");
+        assert_eq!(count("qobuz", 42), 2);
+
+        store
+            .delete_bookmark("qobuz", 42, bookmarks[0].id)
+            .expect("delete bookmark");
+        let left = store.get_bookmarks("qobuz", 42).expect("get bookmarks");
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].position_ms, 90_000);
+        assert_eq!(count("qobuz", 7), 1);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn bookmarks_are_kept_apart_per_source() {
+        let dir = unique_test_dir("session-bookmark-sources");
+        let store = SessionStore::new_at(&dir).expect("open store");
+        let count = |source, track_id| {
+            store
+                .get_bookmarks(source, track_id)
+                .expect("get bookmarks")
+                .len()
+        };
+
+        store
+            .set_bookmark("qobuz", 42, 1_000, Some("Qobuz".to_string()))
+            .expect("set bookmark");
+        store
+            .set_bookmark("local", 42, 1_000, Some("Local".to_string()))
+            .expect("set bookmark");
+
+        let local = store.get_bookmarks("local", 42).expect("get bookmarks");
+        assert_eq!(local.len(), 1);
+        assert_eq!(local[0].label.as_deref(), Some("Local"));
+        store
+            .delete_bookmark("qobuz", 42, local[0].id)
+            .expect("delete with the wrong source");
+        assert_eq!(count("local", 42), 1);
+        let qobuz = store.get_bookmarks("qobuz", 42).expect("get bookmarks");
+        assert_eq!(qobuz[0].label.as_deref(), Some("Qobuz"));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn bookmarks_keyed_by_track_alone_migrate_as_qobuz() {
+        let dir = unique_test_dir("session-bookmark-migration");
+        std::fs::create_dir_all(&dir).expect("create dir");
+        Connection::open(dir.join("session.db"))
+            .and_then(|conn| {
+                conn.execute_batch(
+                    "CREATE TABLE track_bookmarks (
+                        id INTEGER PRIMARY KEY AUTOINCREMENT,
+                        track_id INTEGER NOT NULL,
+                        position_ms INTEGER NOT NULL,
+                        label TEXT,
+                        created_at INTEGER NOT NULL,
+                        UNIQUE (track_id, position_ms)
+                    );
+                    INSERT INTO track_bookmarks (track_id, position_ms, label, created_at)
+                    VALUES (42, 5000, 'Old', 1);",
+                )
+            })
+            .expect("seed old schema");
+
+        let store = SessionStore::new_at(&dir).expect("open store");
+        let migrated = store.get_bookmarks("qobuz", 42).expect("get bookmarks");
+        assert_eq!(migrated.len(), 1);
+        assert_eq!(migrated[0].label.as_deref(), Some("Old"));
+        store
+            .set_bookmark("local", 42, 5_000, None)
+            .expect("same position, other source");
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    fn listening(track_id: u64, artist: &str, genre: Option<&str>) -> ListeningTrack {
+        ListeningTrack {
+            track_id,
+            artist_id: Some(track_id * 10),
+            artist: artist.to_string(),
+            genre: genre.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn statistics_collector_counts_only_forward_playback() {
+        let mut collector = PlaybackStatisticsCollector::new();
+        collector.observe(1, 0, true); // no track started yet
+        assert_eq!(collector.take_segment(), None);
+
+        assert_eq!(collector.start_track(listening(1, "A", Some("Jazz"))), None);
+        for pos in [0, 1, 2, 3] {
+            collector.observe(1, pos, true);
+        }
+        collector.observe(1, 3, false); // paused
+        collector.observe(1, 4, true);
+        collector.observe(1, 120, true); // seek forward
+        collector.observe(1, 121, true);
+        collector.observe(2, 122, true); // another track's tick
+
+        let segment = collector.start_track(listening(2, "B", None)).unwrap();
+        assert_eq!(segment.track.artist, "A");
+        assert_eq!(segment.secs, 4);
+        assert_eq!(collector.take_segment(), None);
+    }
+
+    #[test]
+    fn listening_stats_aggregate_per_artist_and_genre() {
+        let dir = unique_test_dir("listening-stats");
+        let store = SessionStore::new_at(&dir).unwrap();
+        let seg = |track, artist, genre, secs| ListenedSegment {
+            track: listening(track, artist, genre),
+            secs,
+        };
+
+        store
+            .record_listening_on("2026-01-10", &seg(1, "A", Some("Jazz"), 100))
+            .unwrap();
+        store
+            .record_listening_on("2026-01-10", &seg(1, "A", Some("Jazz"), 50))
+            .unwrap();
+        store
+            .record_listening_on("2026-01-11", &seg(2, "B", Some("Rock"), 200))
+            .unwrap();
+        store
+            .record_listening_on("2026-01-11", &seg(3, "C", None, 20))
+            .unwrap();
+        store
+            .record_listening_on("2025-06-01", &seg(2, "B", Some("Rock"), 999))
+            .unwrap();
+        store.clear_session().unwrap();
+
+        let stats = store.listening_stats_since("week", "2026-01-05").unwrap();
+        assert_eq!(stats.total_secs, 370);
+        let artists: Vec<(&str, Option<u64>, u64)> = stats
+            .top_artists
+            .iter()
+            .map(|e| (e.name.as_str(), e.artist_id, e.secs))
+            .collect();
+        assert_eq!(
+            artists,
+            [
+                ("B", Some(20), 200),
+                ("A", Some(10), 150),
+                ("C", Some(30), 20)
+            ]
+        );
+        let genres: Vec<(&str, u64)> = stats
+            .top_genres
+            .iter()
+            .map(|e| (e.name.as_str(), e.secs))
+            .collect();
+        assert_eq!(genres, [("Rock", 200), ("Jazz", 150)]);
+
+        assert!(store.get_listening_stats("decade").is_err());
+        assert_eq!(store.get_listening_stats("year").unwrap().period, "year");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn namesake_artists_keep_separate_listening_totals() {
+        let dir = unique_test_dir("listening-namesakes");
+        let store = SessionStore::new_at(&dir).unwrap();
+        let seg = |artist_id, secs| ListenedSegment {
+            track: ListeningTrack {
+                track_id: 1,
+                artist_id,
+                artist: "Nirvana".to_string(),
+                genre: None,
+            },
+            secs,
+        };
+
+        store.record_listening_on("2026-01-10", &seg(Some(1), 100)).unwrap();
+        store.record_listening_on("2026-01-10", &seg(Some(2), 40)).unwrap();
+        store.record_listening_on("2026-01-10", &seg(None, 10)).unwrap();
+        store.record_listening_on("2026-01-11", &seg(Some(1), 5)).unwrap();
+
+        let stats = store.listening_stats_since("week", "2026-01-05").unwrap();
+        let artists: Vec<(Option<u64>, u64)> = stats
+            .top_artists
+            .iter()
+            .map(|e| (e.artist_id, e.secs))
+            .collect();
+        assert_eq!(artists, [(Some(1), 105), (Some(2), 40), (None, 10)]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn name_keyed_listening_stats_are_migrated() {
+        let dir = unique_test_dir("listening-migrate");
+        std::fs::create_dir_all(&dir).unwrap();
+        {
+            let conn = Connection::open(dir.join("session.db")).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE listening_stats (
+                     artist TEXT NOT NULL,
+                     artist_id INTEGER,
+                     genre TEXT NOT NULL DEFAULT '',
+                     date TEXT NOT NULL,
+                     duration_listened_secs INTEGER NOT NULL DEFAULT 0,
+                     PRIMARY KEY (artist, genre, date)
+                 );
+                 INSERT INTO listening_stats VALUES ('A', 7, 'Jazz', '2026-01-10', 60);
+                 INSERT INTO listening_stats VALUES ('B', NULL, '', '2026-01-10', 30);",
+            )
+            .unwrap();
+        }
+
+        let store = SessionStore::new_at(&dir).unwrap();
+        let track = ListeningTrack {
+            track_id: 1,
+            artist_id: Some(7),
+            artist: "A".to_string(),
+            genre: Some("Jazz".to_string()),
+        };
+        store
+            .record_listening_on("2026-01-10", &ListenedSegment { track, secs: 15 })
+            .unwrap();
+
+        let stats = store.listening_stats_since("week", "2026-01-05").unwrap();
+        assert_eq!(stats.total_secs, 105);
+        let artists: Vec<(&str, u64)> = stats
+            .top_artists
+            .iter()
+            .map(|e| (e.name.as_str(), e.secs))
+            .collect();
+        assert_eq!(artists, [("A", 75), ("B", 30)]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn clear_session_resets_playback_and_shell_view_fields() {
         let dir = unique_test_dir("session-clear");
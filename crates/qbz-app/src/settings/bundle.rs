@@ -680,11 +680,21 @@ const AUDIO_PORTABLE: &[&str] = &[
     "streaming_only",
     "normalization_enabled",
     "normalization_target_lufs",
+    "true_peak_limit_dbfs",
     "gapless_enabled",
+    "skip_silence",
+    "podcast_mode",
+    "playback_speed",
+    "skip_silence_threshold_ms",
     "allow_quality_fallback",
     "sync_audio_on_startup",
     "limit_quality_to_device",
     "preferred_sample_rate",
+    "eq_bands",
+    "crossfade_ms",
+    "repeat_crossfade_ms",
+    "hires_min_mbps",
+    "cd_min_mbps",
 ];
 const AUDIO_INTENT_FLAGS: &[&str] = &[
     "exclusive_mode",
@@ -1184,7 +1194,33 @@ fn apply_audio_writes(data_root: &Path, writes: &[(&str, &Value)]) -> Result<(),
             "normalization_target_lufs" => {
                 store.set_normalization_target_lufs(value.as_f64().unwrap_or(-14.0) as f32)?
             }
+            "true_peak_limit_dbfs" => {
+                store.set_true_peak_limit_dbfs(value.as_f64().map(|db| db as f32))?
+            }
             "gapless_enabled" => store.set_gapless_enabled(as_bool(value))?,
+            "skip_silence" => store.set_skip_silence(as_bool(value))?,
+            "podcast_mode" => store.set_podcast_mode(as_bool(value))?,
+            "playback_speed" => store.set_playback_speed(value.as_f64().unwrap_or(1.0) as f32)?,
+            "skip_silence_threshold_ms" => {
+                store.set_skip_silence_threshold_ms(value.as_u64().unwrap_or(0) as u32)?
+            }
+            "eq_bands" => {
+                let bands: Vec<qbz_audio::EqBand> = serde_json::from_value((*value).clone())
+                    .map_err(|e| format!("eq_bands: {e}"))?;
+                store.set_eq_bands(&bands)?
+            }
+            "crossfade_ms" => store.set_crossfade_ms(value.as_u64().unwrap_or(0) as u32)?,
+            "repeat_crossfade_ms" => {
+                store.set_repeat_crossfade_ms(value.as_u64().unwrap_or(0) as u32)?
+            }
+            "hires_min_mbps" => {
+                let cd = store.get_settings()?.cd_min_mbps;
+                store.set_bandwidth_thresholds(value.as_f64().unwrap_or(0.0) as f32, cd)?
+            }
+            "cd_min_mbps" => {
+                let hires = store.get_settings()?.hires_min_mbps;
+                store.set_bandwidth_thresholds(hires, value.as_f64().unwrap_or(0.0) as f32)?
+            }
             "allow_quality_fallback" => store.set_allow_quality_fallback(as_bool(value))?,
             "sync_audio_on_startup" => store.set_sync_audio_on_startup(as_bool(value))?,
             "quality_fallback_behavior" => {
@@ -1534,6 +1570,80 @@ pub fn default_filename() -> String {
     format!("qbz-settings-{}.qbzb", chrono::Utc::now().format("%Y%m%d"))
 }
 
+// ============================ QR transfer ============================
+
+/// Most bytes a QR code can hold (version 40, error-correction level L).
+const QR_MAX_BYTES: usize = 2953;
+
+impl Bundle {
+    /// The compact QR form of the bundle: minified JSON, gzip-compressed,
+    /// base64. Refuses a bundle that carries secrets — a QR code on screen is
+    /// readable by anyone in the room (export with `include_auth: false`).
+    pub fn to_qr_payload(&self) -> Result<String, BundleError> {
+        use base64::Engine;
+        use std::io::Write as _;
+
+        if self.contains_secrets() || self.domains.contains_key("auth") {
+            return Err(BundleError::Io(
+                "QR codes never carry credentials; export without the login tokens".into(),
+            ));
+        }
+        let json = serde_json::to_vec(self).map_err(|e| BundleError::Io(e.to_string()))?;
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        gz.write_all(&json)
+            .and_then(|()| gz.finish())
+            .map(|bytes| base64::engine::general_purpose::STANDARD.encode(bytes))
+            .map_err(|e| BundleError::Io(format!("could not compress bundle: {e}")))
+    }
+
+    /// Parse the text of a scanned settings QR code ([`Self::to_qr_payload`]).
+    /// Secrets are rejected here too, so a crafted code can't smuggle a login.
+    pub fn from_qr_payload(payload: &str) -> Result<Bundle, BundleError> {
+        use base64::Engine;
+        use std::io::Read as _;
+
+        let compressed = base64::engine::general_purpose::STANDARD
+            .decode(payload.trim())
+            .map_err(|e| BundleError::Parse(format!("not a QBZ settings code: {e}")))?;
+        let mut text = String::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut text)
+            .map_err(|e| BundleError::Parse(format!("not a QBZ settings code: {e}")))?;
+        let bundle = Bundle::parse(&text)?;
+        if bundle.contains_secrets() || bundle.domains.contains_key("auth") {
+            return Err(BundleError::Parse(
+                "settings codes must not carry credentials".into(),
+            ));
+        }
+        Ok(bundle)
+    }
+}
+
+/// Render the bundle's QR payload as a PNG (black modules on white, with the
+/// standard quiet zone).
+pub fn settings_to_qr_png(bundle: &Bundle) -> Result<Vec<u8>, BundleError> {
+    use qrcode::{EcLevel, QrCode};
+
+    let payload = bundle.to_qr_payload()?;
+    if payload.len() > QR_MAX_BYTES {
+        return Err(BundleError::Io(format!(
+            "settings are too large for a QR code ({} bytes, at most {QR_MAX_BYTES})",
+            payload.len()
+        )));
+    }
+    let code = QrCode::with_error_correction_level(payload.as_bytes(), EcLevel::L)
+        .map_err(|e| BundleError::Io(format!("could not build QR code: {e}")))?;
+    let img = code
+        .render::<image::Luma<u8>>()
+        .module_dimensions(4, 4)
+        .build();
+    let mut png = Vec::new();
+    image::DynamicImage::ImageLuma8(img)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| BundleError::Io(format!("could not encode QR code: {e}")))?;
+    Ok(png)
+}
+
 /// Serialize a bundle to `path`, ALWAYS mode 0600 — fail rather than fall back
 /// to a wider mode (04 §6). Shared by the CLI and the P1 desktop modal.
 pub fn write_bundle_file(path: &Path, bundle: &Bundle) -> Result<(), BundleError> {
@@ -574,3 +574,27 @@ fn device_pick_names_the_backend() {
     assert_eq!(pick.wanted, "hw:9,9");
     cleanup(&p);
 }
+
+#[test]
+fn qr_payload_round_trips_and_never_carries_secrets() {
+    let bundle = bundle_with(json!({
+        "audio": { "gapless_enabled": true },
+        "playback": { "autoplay_mode": "continue" }
+    }));
+    let payload = bundle.to_qr_payload().expect("payload");
+    let back = Bundle::from_qr_payload(&payload).expect("decode");
+    assert_eq!(back.schema_version, bundle.schema_version);
+    assert_eq!(back.domains, bundle.domains);
+
+    let png = settings_to_qr_png(&bundle).expect("png");
+    assert!(png.starts_with(b"\x89PNG"));
+
+    let auth = bundle_with(json!({ "auth": { "user_auth_token": "tok" } }));
+    assert!(auth.to_qr_payload().is_err());
+    let scrob = bundle_with(json!({
+        "integrations": { "scrobblers": { "lastfm_session_key": "sk-live" } }
+    }));
+    assert!(scrob.to_qr_payload().is_err());
+
+    assert!(Bundle::from_qr_payload("not base64 !").is_err());
+}
@@ -28,6 +28,9 @@ pub struct GraphicsSettings {
     /// Opt-in NVIDIA Wayland compatibility mode. The host applies the runtime
     /// environment changes before graphics initialization.
     pub nvidia_compat_mode: bool,
+    /// Cross-fade length, in milliseconds, when the generated theme changes.
+    /// 0 switches colors instantly.
+    pub theme_transition_ms: u32,
 }
 
 impl Default for GraphicsSettings {
@@ -40,6 +43,7 @@ impl Default for GraphicsSettings {
             gsk_renderer: None,
             preferred_gpu: "auto".to_string(),
             nvidia_compat_mode: false,
+            theme_transition_ms: 400,
         }
     }
 }
@@ -110,6 +114,9 @@ impl GraphicsSettingsStore {
         let _ = conn.execute_batch(
             "ALTER TABLE graphics_settings ADD COLUMN nvidia_compat_mode INTEGER NOT NULL DEFAULT 0;",
         );
+        let _ = conn.execute_batch(
+            "ALTER TABLE graphics_settings ADD COLUMN theme_transition_ms INTEGER NOT NULL DEFAULT 400;",
+        );
 
         Ok(Self { conn })
     }
@@ -128,7 +135,7 @@ impl GraphicsSettingsStore {
     pub fn get_settings(&self) -> Result<GraphicsSettings, String> {
         self.conn
             .query_row(
-                "SELECT hardware_acceleration, force_x11, gdk_scale, gdk_dpi_scale, gsk_renderer, preferred_gpu, nvidia_compat_mode FROM graphics_settings WHERE id = 1",
+                "SELECT hardware_acceleration, force_x11, gdk_scale, gdk_dpi_scale, gsk_renderer, preferred_gpu, nvidia_compat_mode, theme_transition_ms FROM graphics_settings WHERE id = 1",
                 [],
                 |row| {
                     Ok(GraphicsSettings {
@@ -141,6 +148,7 @@ impl GraphicsSettingsStore {
                             .get::<_, Option<String>>(5)?
                             .unwrap_or_else(|| "auto".to_string()),
                         nvidia_compat_mode: row.get::<_, i64>(6).unwrap_or(0) != 0,
+                        theme_transition_ms: row.get::<_, i64>(7).unwrap_or(400).max(0) as u32,
                     })
                 },
             )
@@ -216,6 +224,16 @@ impl GraphicsSettingsStore {
             .map_err(|e| format!("Failed to set nvidia_compat_mode: {}", e))?;
         Ok(())
     }
+
+    pub fn set_theme_transition_ms(&self, ms: u32) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE graphics_settings SET theme_transition_ms = ?1 WHERE id = 1",
+                params![ms as i64],
+            )
+            .map_err(|e| format!("Failed to set theme_transition_ms: {}", e))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -241,6 +259,7 @@ mod tests {
         assert_eq!(settings.gsk_renderer, None);
         assert_eq!(settings.preferred_gpu, "auto");
         assert!(!settings.nvidia_compat_mode);
+        assert_eq!(settings.theme_transition_ms, 400);
     }
 
     #[test]
@@ -254,6 +273,7 @@ mod tests {
         assert!(!settings.force_x11);
         assert_eq!(settings.preferred_gpu, "auto");
         assert!(!settings.nvidia_compat_mode);
+        assert_eq!(settings.theme_transition_ms, 400);
         let _ = std::fs::remove_dir_all(dir);
     }
 
@@ -281,6 +301,9 @@ mod tests {
             store
                 .set_nvidia_compat_mode(true)
                 .expect("set nvidia compat mode");
+            store
+                .set_theme_transition_ms(0)
+                .expect("set theme transition");
         }
 
         let reopened = GraphicsSettingsStore::new_at(&dir).expect("reopen store");
@@ -293,6 +316,7 @@ mod tests {
         assert_eq!(settings.gsk_renderer.as_deref(), Some("ngl"));
         assert_eq!(settings.preferred_gpu, "discrete");
         assert!(settings.nvidia_compat_mode);
+        assert_eq!(settings.theme_transition_ms, 0);
         let _ = std::fs::remove_dir_all(dir);
     }
 
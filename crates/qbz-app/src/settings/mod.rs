@@ -10,6 +10,7 @@ pub mod local_favorites;
 pub mod pinned_items;
 pub mod playback;
 pub mod plex;
+pub mod proxy;
 pub mod album_play_history;
 pub mod reco_store;
 pub mod remote_control;
@@ -19,3 +20,4 @@ pub mod search_ranking;
 pub mod search_service;
 pub mod subscription;
 pub mod tray;
+pub mod updates;
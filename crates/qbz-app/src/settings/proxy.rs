@@ -0,0 +1,258 @@
+//! HTTP proxy settings persistence.
+//!
+//! One global (not per-user) row: the proxy has to be known before login,
+//! since the very first request is the Qobuz sign-in. Changes take effect on
+//! the next start — the host calls [`install_for_process`] once, before any
+//! thread spawns, which exports the standard `HTTP_PROXY` / `HTTPS_PROXY` /
+//! `NO_PROXY` variables. reqwest reads those when a client is built, so every
+//! crate's client (Qobuz, integrations, cast, lyrics, ...) follows the proxy
+//! without each one threading the config through. Clients the host builds
+//! itself go through [`build_reqwest_client_with_proxy`] / [`client`].
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy URL for plain-HTTP requests, e.g. `http://proxy.corp:3128`.
+    pub http_proxy: Option<String>,
+    /// Proxy URL for HTTPS requests.
+    pub https_proxy: Option<String>,
+    /// Hosts/domains/CIDRs that bypass the proxy (curl `NO_PROXY` syntax).
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// Trim every field and drop empty entries.
+    pub fn sanitized(self) -> Self {
+        let clean = |value: Option<String>| {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        Self {
+            http_proxy: clean(self.http_proxy),
+            https_proxy: clean(self.https_proxy),
+            no_proxy: self
+                .no_proxy
+                .into_iter()
+                .map(|host| host.trim().to_string())
+                .filter(|host| !host.is_empty())
+                .collect(),
+        }
+    }
+
+    /// No proxy configured (direct connections).
+    pub fn is_empty(&self) -> bool {
+        self.http_proxy.is_none() && self.https_proxy.is_none()
+    }
+
+    /// Parse a comma/whitespace separated bypass list.
+    pub fn parse_no_proxy(value: &str) -> Vec<String> {
+        value
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(str::trim)
+            .filter(|host| !host.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// The bypass list in `NO_PROXY` form.
+    pub fn no_proxy_string(&self) -> String {
+        self.no_proxy.join(",")
+    }
+}
+
+/// Apply `config` to a client builder. An unparsable proxy URL is logged and
+/// skipped (that scheme connects directly) rather than failing the client.
+pub fn apply_proxy(
+    mut builder: reqwest::ClientBuilder,
+    config: &ProxyConfig,
+) -> reqwest::ClientBuilder {
+    let no_proxy = reqwest::NoProxy::from_string(&config.no_proxy_string());
+    if let Some(url) = &config.http_proxy {
+        match reqwest::Proxy::http(url.as_str()) {
+            Ok(proxy) => builder = builder.proxy(proxy.no_proxy(no_proxy.clone())),
+            Err(e) => log::warn!("[Proxy] ignoring invalid HTTP proxy '{}': {}", url, e),
+        }
+    }
+    if let Some(url) = &config.https_proxy {
+        match reqwest::Proxy::https(url.as_str()) {
+            Ok(proxy) => builder = builder.proxy(proxy.no_proxy(no_proxy)),
+            Err(e) => log::warn!("[Proxy] ignoring invalid HTTPS proxy '{}': {}", url, e),
+        }
+    }
+    builder
+}
+
+/// A plain client routed through `config`.
+pub fn build_reqwest_client_with_proxy(config: &ProxyConfig) -> reqwest::Client {
+    apply_proxy(reqwest::Client::builder(), config)
+        .build()
+        .unwrap_or_else(|e| {
+            log::warn!("[Proxy] client build failed ({}), using defaults", e);
+            reqwest::Client::new()
+        })
+}
+
+static ACTIVE: OnceLock<ProxyConfig> = OnceLock::new();
+
+/// Make `config` the process proxy: remembered for [`client`] and exported to
+/// the environment for every other reqwest client. Call once at startup
+/// BEFORE any thread spawns (`set_var` is not thread-safe). An empty config
+/// leaves the environment untouched, so a system-wide proxy still applies.
+pub fn install_for_process(config: ProxyConfig) {
+    if let Some(url) = &config.http_proxy {
+        std::env::set_var("HTTP_PROXY", url);
+        std::env::set_var("http_proxy", url);
+    }
+    if let Some(url) = &config.https_proxy {
+        std::env::set_var("HTTPS_PROXY", url);
+        std::env::set_var("https_proxy", url);
+    }
+    if !config.is_empty() && !config.no_proxy.is_empty() {
+        let hosts = config.no_proxy_string();
+        std::env::set_var("NO_PROXY", &hosts);
+        std::env::set_var("no_proxy", &hosts);
+    }
+    let _ = ACTIVE.set(config);
+}
+
+/// The proxy installed at startup (empty before [`install_for_process`]).
+pub fn active() -> ProxyConfig {
+    ACTIVE.get().cloned().unwrap_or_default()
+}
+
+/// A plain client routed through the startup proxy.
+pub fn client() -> reqwest::Client {
+    build_reqwest_client_with_proxy(&active())
+}
+
+pub struct ProxySettingsStore {
+    conn: Connection,
+}
+
+impl ProxySettingsStore {
+    fn open_at(dir: &Path, db_name: &str) -> Result<Self, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+        let db_path = dir.join(db_name);
+        let conn = Connection::open(&db_path)
+            .map_err(|e| format!("Failed to open proxy settings database: {}", e))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS proxy_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                http_proxy TEXT,
+                https_proxy TEXT,
+                no_proxy TEXT NOT NULL DEFAULT ''
+            );
+            INSERT OR IGNORE INTO proxy_settings (id) VALUES (1);",
+        )
+        .map_err(|e| format!("Failed to create proxy settings table: {}", e))?;
+
+        Ok(Self { conn })
+    }
+
+    pub fn new() -> Result<Self, String> {
+        let data_dir = dirs::data_dir()
+            .ok_or("Could not determine data directory")?
+            .join("qbz");
+        Self::open_at(&data_dir, "proxy_settings.db")
+    }
+
+    pub fn new_at(base_dir: &Path) -> Result<Self, String> {
+        Self::open_at(base_dir, "proxy_settings.db")
+    }
+
+    pub fn get_config(&self) -> Result<ProxyConfig, String> {
+        self.conn
+            .query_row(
+                "SELECT http_proxy, https_proxy, no_proxy FROM proxy_settings WHERE id = 1",
+                [],
+                |row| {
+                    Ok(ProxyConfig {
+                        http_proxy: row.get::<_, Option<String>>(0)?,
+                        https_proxy: row.get::<_, Option<String>>(1)?,
+                        no_proxy: ProxyConfig::parse_no_proxy(&row.get::<_, String>(2)?),
+                    })
+                },
+            )
+            .map_err(|e| format!("Failed to get proxy settings: {}", e))
+    }
+
+    pub fn set_config(&self, config: &ProxyConfig) -> Result<(), String> {
+        let config = config.clone().sanitized();
+        self.conn
+            .execute(
+                "UPDATE proxy_settings SET http_proxy = ?1, https_proxy = ?2, no_proxy = ?3 WHERE id = 1",
+                params![config.http_proxy, config.https_proxy, config.no_proxy_string()],
+            )
+            .map_err(|e| format!("Failed to set proxy settings: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_test_dir(name: &str) -> std::path::PathBuf {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("qbz-app-{name}-{}-{nonce}", std::process::id()))
+    }
+
+    #[test]
+    fn proxy_store_defaults_to_direct() {
+        let dir = unique_test_dir("proxy-default");
+        let store = ProxySettingsStore::new_at(&dir).expect("open store");
+
+        let config = store.get_config().expect("get config");
+
+        assert!(config.is_empty());
+        assert!(config.no_proxy.is_empty());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn proxy_store_round_trips_sanitized_config() {
+        let dir = unique_test_dir("proxy-persist");
+        {
+            let store = ProxySettingsStore::new_at(&dir).expect("open store");
+            store
+                .set_config(&ProxyConfig {
+                    http_proxy: Some(" http://proxy.corp:3128 ".to_string()),
+                    https_proxy: Some("".to_string()),
+                    no_proxy: vec![
+                        "localhost".to_string(),
+                        " ".to_string(),
+                        ".corp".to_string(),
+                    ],
+                })
+                .expect("set config");
+        }
+
+        let reopened = ProxySettingsStore::new_at(&dir).expect("reopen store");
+        let config = reopened.get_config().expect("get config");
+
+        assert_eq!(config.http_proxy.as_deref(), Some("http://proxy.corp:3128"));
+        assert_eq!(config.https_proxy, None);
+        assert_eq!(config.no_proxy, vec!["localhost", ".corp"]);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn no_proxy_parsing_accepts_commas_and_whitespace() {
+        assert_eq!(
+            ProxyConfig::parse_no_proxy("localhost, 10.0.0.0/8\n.corp,,"),
+            vec!["localhost", "10.0.0.0/8", ".corp"]
+        );
+    }
+}
@@ -26,7 +26,11 @@
 //! - Event logging (`log_play_event` / `log_favorite_event` / generic `insert_event`).
 //! - Read APIs: `get_recent_track_ids`, `get_recent_track_ids_since` (NEW —
 //!   time-windowed, for WeeklyQ's 7-day window), `get_favorite_track_ids`,
-//!   `get_top_genres`, `get_home_seeds` (mirrors `get_home_seeds_internal`).
+//!   `get_top_genres`, `get_home_seeds` (mirrors `get_home_seeds_internal`),
+//!   `get_genre_affinity_scores` (NEW — 7-day half-life decayed ranking of
+//!   top-level genres, subgenres rolled up via `reco_album_meta.genre_root_id`,
+//!   for ordering the genre chips) and `get_listening_streak` (NEW —
+//!   consecutive days with a play, also fed into the home seeds).
 //! - `train()` — the decay/weight scorer from Tauri's `v2_reco_train_scores`,
 //!   ported verbatim (same default lookback 90d / half-life 21d / max 5000
 //!   events / 200 per type, same event + item weights, same exponential decay).
@@ -36,7 +40,7 @@
 //! client + API cache and belongs in the frontend layer that has those. This
 //! module returns IDs (seeds); the caller resolves them.
 
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -50,6 +54,8 @@ pub enum RecoEventType {
     Play,
     Favorite,
     PlaylistAdd,
+    /// "Don't recommend this" — negative weight in `train()`.
+    Dislike,
 }
 
 impl RecoEventType {
@@ -58,6 +64,7 @@ impl RecoEventType {
             Self::Play => "play",
             Self::Favorite => "favorite",
             Self::PlaylistAdd => "playlist_add",
+            Self::Dislike => "dislike",
         }
     }
 }
@@ -67,6 +74,8 @@ pub enum RecoItemType {
     Track,
     Album,
     Artist,
+    /// Only used by dislikes (the id goes in `genre_id`).
+    Genre,
 }
 
 impl RecoItemType {
@@ -75,6 +84,7 @@ impl RecoItemType {
             Self::Track => "track",
             Self::Album => "album",
             Self::Artist => "artist",
+            Self::Genre => "genre",
         }
     }
 }
@@ -106,6 +116,31 @@ pub struct HomeSeeds {
     pub top_artist_ids: Vec<TopArtistSeed>,
     pub favorite_album_ids: Vec<String>,
     pub favorite_track_ids: Vec<u64>,
+    /// The user's [`ListeningStreak`] in days; longer streaks put more
+    /// fresh recent items ahead of the trained scores.
+    pub listening_streak: u32,
+}
+
+/// Consecutive completed days with at least one play (mirrors the
+/// `v2_get_listening_streak` result).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListeningStreak {
+    /// Days in a row up to and including yesterday; today never counts, so
+    /// the streak only breaks once a whole day passes without a play.
+    pub days: u32,
+    /// Local date (`YYYY-MM-DD`) of the most recent play, today included.
+    pub last_play_date: Option<String>,
+}
+
+/// An album played around the same time as a seed album (mirrors the
+/// `v2_reco_get_similar_albums` result rows; the caller resolves the id).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarAlbumCandidate {
+    pub album_id: String,
+    /// Decayed co-play score, after the shared artist / genre boosts.
+    pub score: f64,
+    /// Distinct days on which both albums were played close together.
+    pub co_play_days: u32,
 }
 
 /// Limits for a `get_home_seeds` call (mirrors the four `v2_reco_get_home*` args).
@@ -157,7 +192,6 @@ struct RecoEventRecord {
     track_id: Option<u64>,
     album_id: Option<String>,
     artist_id: Option<u64>,
-    #[allow(dead_code)]
     genre_id: Option<u64>,
     created_at: i64,
 }
@@ -171,6 +205,21 @@ struct RecoScoreEntry {
     score: f64,
 }
 
+/// Half-life of a play's weight in [`RecoStore::get_genre_affinity_scores`].
+const GENRE_AFFINITY_HALF_LIFE_DAYS: f64 = 7.0;
+
+/// Two plays at most this far apart count as listened together in
+/// [`RecoStore::get_similar_album_candidates`].
+const CO_PLAY_WINDOW_SECS: i64 = 2 * 60 * 60;
+
+/// Half-life of a co-play's weight in
+/// [`RecoStore::get_similar_album_candidates`].
+const CO_PLAY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Score multipliers for a candidate sharing the seed's artist / genre.
+const SAME_ARTIST_BOOST: f64 = 1.5;
+const SAME_GENRE_BOOST: f64 = 1.25;
+
 fn now_ts() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -274,6 +323,11 @@ impl RecoStore {
                 CREATE INDEX IF NOT EXISTS idx_reco_scores_lookup
                     ON reco_scores(score_type, item_type, score DESC);
 
+                CREATE TABLE IF NOT EXISTS reco_import_state (
+                    source TEXT PRIMARY KEY,
+                    last_ts INTEGER NOT NULL
+                );
+
                 CREATE TABLE IF NOT EXISTS reco_album_meta (
                     album_id TEXT PRIMARY KEY,
                     title TEXT NOT NULL,
@@ -281,6 +335,7 @@ impl RecoStore {
                     artist_id INTEGER,
                     artwork_url TEXT NOT NULL DEFAULT '',
                     genre_name TEXT NOT NULL DEFAULT '',
+                    genre_root_id INTEGER,
                     quality TEXT NOT NULL DEFAULT '',
                     release_date TEXT,
                     updated_at INTEGER NOT NULL
@@ -291,6 +346,7 @@ impl RecoStore {
 
         // Upgrade an old Tauri DB whose base schema predates the genre_id column.
         self.migrate_add_genre_id()?;
+        self.migrate_add_genre_root_id()?;
 
         Ok(())
     }
@@ -320,10 +376,39 @@ impl RecoStore {
         Ok(())
     }
 
+    /// Idempotent: add `reco_album_meta.genre_root_id` to a DB created before it.
+    fn migrate_add_genre_root_id(&self) -> Result<(), String> {
+        let has_column: bool = self
+            .conn
+            .prepare("PRAGMA table_info(reco_album_meta)")
+            .map_err(|e| format!("Failed to query table info: {}", e))?
+            .query_map([], |row| row.get::<_, String>(1))
+            .map_err(|e| format!("Failed to read table info: {}", e))?
+            .filter_map(Result::ok)
+            .any(|col| col == "genre_root_id");
+
+        if !has_column {
+            self.conn
+                .execute(
+                    "ALTER TABLE reco_album_meta ADD COLUMN genre_root_id INTEGER",
+                    [],
+                )
+                .map_err(|e| format!("Failed to add genre_root_id column: {}", e))?;
+        }
+        Ok(())
+    }
+
     // ---- Event logging ----
 
     /// Generic insert (mirrors `RecoStoreDb::insert_event`).
     pub fn insert_event(&self, event: &RecoEventInput) -> Result<(), String> {
+        self.insert_event_at(event, now_ts())
+    }
+
+    /// Insert an event that happened at `created_at` (Unix seconds) — for
+    /// history imports, whose plays must decay from when they happened, not
+    /// from when they were imported.
+    pub fn insert_event_at(&self, event: &RecoEventInput, created_at: i64) -> Result<(), String> {
         self.conn
             .execute(
                 r#"
@@ -340,7 +425,7 @@ impl RecoStore {
                     event.artist_id,
                     event.playlist_id,
                     event.genre_id,
-                    now_ts(),
+                    created_at,
                 ],
             )
             .map_err(|e| format!("Failed to insert reco event: {}", e))?;
@@ -386,6 +471,115 @@ impl RecoStore {
         })
     }
 
+    /// Log a dislike of one track, album, artist or genre. `item_id` is the
+    /// Qobuz id (albums are string ids, the rest numeric). A repeat dislike
+    /// replaces the earlier one rather than stacking another penalty.
+    pub fn log_dislike_event(&self, item_type: RecoItemType, item_id: &str) -> Result<(), String> {
+        self.clear_dislike_event(item_type, item_id)?;
+        self.insert_event(&Self::dislike_event(item_type, item_id)?)
+    }
+
+    /// Withdraw a dislike logged by [`Self::log_dislike_event`]. Returns how
+    /// many dislike events were removed.
+    pub fn clear_dislike_event(
+        &self,
+        item_type: RecoItemType,
+        item_id: &str,
+    ) -> Result<usize, String> {
+        let event = Self::dislike_event(item_type, item_id)?;
+        self.conn
+            .execute(
+                r#"
+                DELETE FROM reco_events
+                WHERE event_type = 'dislike' AND item_type = ?
+                  AND track_id IS ? AND album_id IS ? AND artist_id IS ? AND genre_id IS ?
+                "#,
+                params![
+                    item_type.as_str(),
+                    event.track_id,
+                    event.album_id.as_deref(),
+                    event.artist_id,
+                    event.genre_id,
+                ],
+            )
+            .map_err(|e| format!("Failed to clear dislike: {}", e))
+    }
+
+    fn dislike_event(item_type: RecoItemType, item_id: &str) -> Result<RecoEventInput, String> {
+        let numeric = || {
+            item_id
+                .parse::<u64>()
+                .map(Some)
+                .map_err(|_| format!("Invalid {} id: {}", item_type.as_str(), item_id))
+        };
+        let mut event = RecoEventInput {
+            event_type: RecoEventType::Dislike,
+            item_type,
+            track_id: None,
+            album_id: None,
+            artist_id: None,
+            playlist_id: None,
+            genre_id: None,
+        };
+        match item_type {
+            RecoItemType::Track => event.track_id = numeric()?,
+            RecoItemType::Album => event.album_id = Some(item_id.to_string()),
+            RecoItemType::Artist => event.artist_id = numeric()?,
+            RecoItemType::Genre => event.genre_id = numeric()?,
+        }
+        Ok(event)
+    }
+
+    /// Log a track play at the time it happened — an imported play, or one
+    /// reported late by a remote client (see [`Self::insert_event_at`]).
+    pub fn log_play_event_at(
+        &self,
+        track_id: u64,
+        album_id: Option<String>,
+        artist_id: Option<u64>,
+        genre_id: Option<u64>,
+        created_at: i64,
+    ) -> Result<(), String> {
+        self.insert_event_at(
+            &RecoEventInput {
+                event_type: RecoEventType::Play,
+                item_type: RecoItemType::Track,
+                track_id: Some(track_id),
+                album_id,
+                artist_id,
+                playlist_id: None,
+                genre_id,
+            },
+            created_at,
+        )
+    }
+
+    // ---- History import cursors ----
+
+    /// Newest source timestamp already imported from `source` (e.g.
+    /// `"listenbrainz"`), so a re-run only imports what is new.
+    pub fn get_import_cursor(&self, source: &str) -> Result<Option<i64>, String> {
+        match self.conn.query_row(
+            "SELECT last_ts FROM reco_import_state WHERE source = ?",
+            params![source],
+            |row| row.get::<_, i64>(0),
+        ) {
+            Ok(ts) => Ok(Some(ts)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to read import cursor: {}", e)),
+        }
+    }
+
+    pub fn set_import_cursor(&self, source: &str, last_ts: i64) -> Result<(), String> {
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO reco_import_state (source, last_ts) VALUES (?, ?)",
+                params![source, last_ts],
+            )
+            .map_err(|e| format!("Failed to save import cursor: {}", e))?;
+        Ok(())
+    }
+
     // ---- Read APIs ----
 
     /// Most-recently-played distinct track IDs (mirrors `get_recent_track_ids`).
@@ -585,6 +779,29 @@ impl RecoStore {
         Ok(ids)
     }
 
+    /// Artist ids the user has disliked — the radio builder leaves them out
+    /// of the pool.
+    pub fn get_disliked_artist_ids(&self) -> Result<Vec<u64>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+                SELECT DISTINCT artist_id
+                FROM reco_events
+                WHERE event_type = 'dislike' AND item_type = 'artist' AND artist_id IS NOT NULL
+                "#,
+            )
+            .map_err(|e| format!("Failed to prepare disliked artists query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, u64>(0))
+            .map_err(|e| format!("Failed to query disliked artists: {}", e))?;
+        let mut ids = Vec::new();
+        for row in rows {
+            ids.push(row.map_err(|e| format!("Failed to read disliked artist row: {}", e))?);
+        }
+        Ok(ids)
+    }
+
     /// The user's most-played genres by event count (mirrors `get_top_genre_ids`).
     /// Returns `(genre_id, genre_name)` — name from `reco_album_meta` (empty if unknown).
     pub fn get_top_genres(&self, limit: u32) -> Result<Vec<(u64, String)>, String> {
@@ -614,6 +831,44 @@ impl RecoStore {
         Ok(genres)
     }
 
+    /// Genres ranked by a recency-weighted play count: each play adds
+    /// `0.5^(age / 7 days)`, so this week's listening outweighs last month's.
+    /// Only plays whose album genre has been backfilled (id on the event)
+    /// count. A subgenre counts toward its top-level genre when the album's
+    /// `genre_root_id` is known, else toward itself. Returns
+    /// `(genre_id, score)`, highest first.
+    pub fn get_genre_affinity_scores(&self) -> Result<Vec<(u64, f64)>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+                SELECT COALESCE(m.genre_root_id, e.genre_id), e.created_at
+                FROM reco_events e
+                LEFT JOIN reco_album_meta m ON e.album_id = m.album_id
+                WHERE e.event_type = 'play'
+                  AND e.genre_id IS NOT NULL AND e.genre_id > 0
+                "#,
+            )
+            .map_err(|e| format!("Failed to prepare genre affinity query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, u64>(0)?, row.get::<_, i64>(1)?)))
+            .map_err(|e| format!("Failed to query genre affinity: {}", e))?;
+
+        let now = now_ts();
+        let half_life_secs = GENRE_AFFINITY_HALF_LIFE_DAYS * 86_400.0;
+        let mut scores: std::collections::HashMap<u64, f64> = std::collections::HashMap::new();
+        for row in rows {
+            let (genre, created_at) =
+                row.map_err(|e| format!("Failed to read genre affinity row: {}", e))?;
+            let age_secs = (now - created_at).max(0) as f64;
+            *scores.entry(genre).or_insert(0.0) += 0.5_f64.powf(age_secs / half_life_secs);
+        }
+
+        let mut ranked: Vec<(u64, f64)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(ranked)
+    }
+
     /// Favorite album ids NOT played within `recency_days` ("forgotten
     /// favorites"). Ported from Tauri `reco_store/db.rs:391-426`, adapted to
     /// the integer `created_at` (unix seconds) schema: Tauri's
@@ -656,6 +911,109 @@ impl RecoStore {
         Ok(albums)
     }
 
+    /// Albums the user tends to play alongside `seed_album_id`: every album
+    /// played within [`CO_PLAY_WINDOW_SECS`] of one of the seed's plays
+    /// co-occurs once per day (an album is many track plays, so raw pairs
+    /// would count album lengths). Each co-play day adds
+    /// `0.5^(age / 30 days)`; the sum is boosted when the candidate shares
+    /// the seed's main artist or (backfilled) genre. Disliked albums are
+    /// left out. Highest score first.
+    pub fn get_similar_album_candidates(
+        &self,
+        seed_album_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SimilarAlbumCandidate>, String> {
+        let seed_artist = self.main_album_value(seed_album_id, "artist_id")?;
+        let seed_genre = self.main_album_value(seed_album_id, "genre_id")?;
+
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+                SELECT DISTINCT o.album_id, o.created_at / 86400, o.artist_id, o.genre_id
+                FROM reco_events s
+                JOIN reco_events o
+                  ON o.event_type = 'play'
+                 AND o.album_id IS NOT NULL
+                 AND o.album_id != s.album_id
+                 AND o.created_at BETWEEN s.created_at - ?2 AND s.created_at + ?2
+                WHERE s.event_type = 'play' AND s.album_id = ?1
+                  AND o.album_id NOT IN (
+                    SELECT album_id FROM reco_events
+                    WHERE event_type = 'dislike' AND album_id IS NOT NULL
+                  )
+                "#,
+            )
+            .map_err(|e| format!("Failed to prepare similar albums query: {}", e))?;
+        let rows = stmt
+            .query_map(params![seed_album_id, CO_PLAY_WINDOW_SECS], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, Option<u64>>(2)?,
+                    row.get::<_, Option<u64>>(3)?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query similar albums: {}", e))?;
+
+        // album -> (co-play days, shares artist, shares genre)
+        let mut co_plays: std::collections::HashMap<String, (Vec<i64>, bool, bool)> =
+            std::collections::HashMap::new();
+        for row in rows {
+            let (album_id, day, artist_id, genre_id) =
+                row.map_err(|e| format!("Failed to read similar album row: {}", e))?;
+            let entry = co_plays.entry(album_id).or_default();
+            if !entry.0.contains(&day) {
+                entry.0.push(day);
+            }
+            entry.1 |= seed_artist.is_some() && artist_id == seed_artist;
+            entry.2 |= seed_genre.is_some() && genre_id == seed_genre;
+        }
+
+        let today = now_ts() / 86_400;
+        let mut ranked: Vec<SimilarAlbumCandidate> = co_plays
+            .into_iter()
+            .map(|(album_id, (days, same_artist, same_genre))| {
+                let mut score: f64 = days
+                    .iter()
+                    .map(|day| 0.5_f64.powf((today - day).max(0) as f64 / CO_PLAY_HALF_LIFE_DAYS))
+                    .sum();
+                if same_artist {
+                    score *= SAME_ARTIST_BOOST;
+                }
+                if same_genre {
+                    score *= SAME_GENRE_BOOST;
+                }
+                SimilarAlbumCandidate {
+                    album_id,
+                    score,
+                    co_play_days: days.len() as u32,
+                }
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.album_id.cmp(&b.album_id))
+        });
+        ranked.truncate(limit);
+        Ok(ranked)
+    }
+
+    /// The most frequent non-NULL `column` (`artist_id` / `genre_id`) of an
+    /// album's play events.
+    fn main_album_value(&self, album_id: &str, column: &str) -> Result<Option<u64>, String> {
+        let query = format!(
+            "SELECT {column} FROM reco_events
+             WHERE event_type = 'play' AND album_id = ? AND {column} IS NOT NULL
+             GROUP BY {column} ORDER BY COUNT(*) DESC LIMIT 1"
+        );
+        self.conn
+            .query_row(&query, params![album_id], |row| row.get::<_, u64>(0))
+            .optional()
+            .map_err(|e| format!("Failed to query album {}: {}", column, e))
+    }
+
     // ---- Scores (companion table, written by train()) ----
 
     fn has_scores(&self, score_type: &str) -> Result<bool, String> {
@@ -823,17 +1181,68 @@ impl RecoStore {
         Ok(())
     }
 
+    // ---- Listening streak ----
+
+    /// The current [`ListeningStreak`], in local calendar days.
+    pub fn get_listening_streak(&self) -> Result<ListeningStreak, String> {
+        self.listening_streak_on(chrono::Local::now().date_naive())
+    }
+
+    fn listening_streak_on(&self, today: chrono::NaiveDate) -> Result<ListeningStreak, String> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+                SELECT DISTINCT date(created_at, 'unixepoch', 'localtime') AS day
+                FROM reco_events
+                WHERE event_type = 'play'
+                ORDER BY day DESC
+                "#,
+            )
+            .map_err(|e| format!("Failed to prepare listening streak query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| format!("Failed to query listening streak: {}", e))?;
+        let mut days = Vec::new();
+        for row in rows {
+            days.push(row.map_err(|e| format!("Failed to read listening day: {}", e))?);
+        }
+
+        let mut streak = ListeningStreak {
+            days: 0,
+            last_play_date: days.first().cloned(),
+        };
+        let mut expected = today.pred_opt();
+        for day in days
+            .iter()
+            .filter_map(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .filter(|d| *d < today)
+        {
+            if Some(day) != expected {
+                break;
+            }
+            streak.days += 1;
+            expected = day.pred_opt();
+        }
+        Ok(streak)
+    }
+
     // ---- Home seeds ----
 
     /// Gather the home/Discover ID seeds (mirrors `get_home_seeds_internal`).
     /// When trained scores exist (`reco_scores` has a `score_type='all'` row),
     /// fresh recent items are merged ahead of scored items; otherwise it falls
-    /// back to the raw event-based queries.
+    /// back to the raw event-based queries. Dislikes only act through the
+    /// trained scores (see [`Self::train`]).
     pub fn get_home_seeds(&self, limits: HomeSeedLimits) -> Result<HomeSeeds, String> {
         let has_scores = self.has_scores("all")?;
+        // A daily listener's latest plays say more than older scores do:
+        // every two streak days add one fresh item, up to eight.
+        let listening_streak = self.get_listening_streak()?.days;
+        let fresh = 4 + (listening_streak / 2).min(4);
 
         let recently_played_album_ids = if has_scores {
-            let recent_fresh = self.get_recent_album_ids(4)?;
+            let recent_fresh = self.get_recent_album_ids(fresh)?;
             let scored = self.get_scored_album_ids("all", limits.recent_albums + 4)?;
             let merged =
                 merge_unique_preserve_order(recent_fresh, scored, limits.recent_albums as usize);
@@ -847,7 +1256,7 @@ impl RecoStore {
         };
 
         let continue_listening_track_ids = if has_scores {
-            let recent_fresh = self.get_recent_track_ids(4)?;
+            let recent_fresh = self.get_recent_track_ids(fresh)?;
             let scored = self.get_scored_track_ids("all", limits.continue_tracks + 4)?;
             let merged =
                 merge_unique_preserve_order(recent_fresh, scored, limits.continue_tracks as usize);
@@ -906,6 +1315,7 @@ impl RecoStore {
             top_artist_ids,
             favorite_album_ids,
             favorite_track_ids,
+            listening_streak,
         })
     }
 
@@ -920,8 +1330,13 @@ impl RecoStore {
     /// (primary=1.0; non-primary album=0.7 / artist=0.5 / track=0.85 / other=0.6),
     /// the same top-N-per-type cap, and the same `(all, favorite) x (track,
     /// album, artist)` six `replace_scores` writes.
+    ///
+    /// NEW: dislikes weigh -3.0 against the disliked track / album / artist;
+    /// a disliked genre subtracts the same from every item played in it.
+    /// Items whose score ends up at or below zero are not written, so they
+    /// drop out of the scored home seeds.
     pub fn train(&mut self, params: TrainParams) -> Result<(), String> {
-        use std::collections::HashMap;
+        use std::collections::{HashMap, HashSet};
 
         let now = now_ts();
         let since_ts = now.saturating_sub(params.lookback_days * 86_400);
@@ -942,6 +1357,7 @@ impl RecoStore {
                 "play" => 1.0,
                 "favorite" => 3.0,
                 "playlist_add" => 1.2,
+                "dislike" => -3.0,
                 _ => 1.0,
             }
         };
@@ -962,6 +1378,7 @@ impl RecoStore {
             let mut tracks: HashMap<u64, f64> = HashMap::new();
             let mut albums: HashMap<String, f64> = HashMap::new();
             let mut artists: HashMap<u64, f64> = HashMap::new();
+            let mut genre_penalties: HashMap<u64, f64> = HashMap::new();
 
             for event in &events {
                 if favorites_only && event.event_type != "favorite" {
@@ -970,6 +1387,13 @@ impl RecoStore {
                 let age_secs = (now - event.created_at).max(0);
                 let base_weight = event_weight(&event.event_type) * decay_factor(age_secs);
 
+                if event.item_type == "genre" {
+                    if let Some(genre_id) = event.genre_id {
+                        *genre_penalties.entry(genre_id).or_insert(0.0) += base_weight;
+                    }
+                    continue;
+                }
+
                 if let Some(track_id) = event.track_id {
                     let weight = base_weight * item_weight("track", event.item_type == "track");
                     *tracks.entry(track_id).or_insert(0.0) += weight;
@@ -983,12 +1407,43 @@ impl RecoStore {
                     *artists.entry(artist_id).or_insert(0.0) += weight;
                 }
             }
+
+            // A genre dislike hits each item played in that genre once.
+            let mut penalized_tracks: HashSet<u64> = HashSet::new();
+            let mut penalized_albums: HashSet<String> = HashSet::new();
+            let mut penalized_artists: HashSet<u64> = HashSet::new();
+            for event in &events {
+                if event.event_type == "dislike" {
+                    continue;
+                }
+                let Some(penalty) = event.genre_id.and_then(|g| genre_penalties.get(&g)) else {
+                    continue;
+                };
+                if let Some(track_id) = event.track_id {
+                    if penalized_tracks.insert(track_id) {
+                        *tracks.entry(track_id).or_insert(0.0) += penalty;
+                    }
+                }
+                if let Some(album_id) = event.album_id.as_ref() {
+                    if penalized_albums.insert(album_id.clone()) {
+                        *albums.entry(album_id.clone()).or_insert(0.0) += penalty;
+                    }
+                }
+                if let Some(artist_id) = event.artist_id {
+                    if penalized_artists.insert(artist_id) {
+                        *artists.entry(artist_id).or_insert(0.0) += penalty;
+                    }
+                }
+            }
             (tracks, albums, artists)
         };
 
         let max_per_type = params.max_per_type as usize;
         let build_track_entries = |scores: HashMap<u64, f64>| {
-            let mut entries: Vec<(u64, f64)> = scores.into_iter().collect();
+            let mut entries: Vec<(u64, f64)> = scores
+                .into_iter()
+                .filter(|(_, score)| *score > 0.0)
+                .collect();
             entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
             entries
                 .into_iter()
@@ -1002,7 +1457,10 @@ impl RecoStore {
                 .collect::<Vec<_>>()
         };
         let build_album_entries = |scores: HashMap<String, f64>| {
-            let mut entries: Vec<(String, f64)> = scores.into_iter().collect();
+            let mut entries: Vec<(String, f64)> = scores
+                .into_iter()
+                .filter(|(_, score)| *score > 0.0)
+                .collect();
             entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
             entries
                 .into_iter()
@@ -1016,7 +1474,10 @@ impl RecoStore {
                 .collect::<Vec<_>>()
         };
         let build_artist_entries = |scores: HashMap<u64, f64>| {
-            let mut entries: Vec<(u64, f64)> = scores.into_iter().collect();
+            let mut entries: Vec<(u64, f64)> = scores
+                .into_iter()
+                .filter(|(_, score)| *score > 0.0)
+                .collect();
             entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
             entries
                 .into_iter()
@@ -1062,6 +1523,22 @@ impl RecoStore {
         Ok(())
     }
 
+    /// Record the top-level genre of an album's genre (the first id of the
+    /// Qobuz genre `path`), so its plays roll up in
+    /// [`Self::get_genre_affinity_scores`].
+    pub fn set_album_genre_root(&self, album_id: &str, root_id: u64) -> Result<(), String> {
+        self.conn
+            .execute(
+                r#"INSERT INTO reco_album_meta
+                       (album_id, title, artist_name, genre_root_id, updated_at)
+                   VALUES (?, '', '', ?, ?)
+                   ON CONFLICT(album_id) DO UPDATE SET genre_root_id = excluded.genre_root_id"#,
+                params![album_id, root_id, now_ts()],
+            )
+            .map_err(|e| format!("Failed to upsert album genre root: {}", e))?;
+        Ok(())
+    }
+
     /// Backfill `genre_id` onto every still-NULL event of an album once its
     /// genre is known (ported from Tauri `db.rs:321-331`). Plays log
     /// `genre_id = None`, so the frontend calls this when it resolves an
@@ -1188,6 +1665,24 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn imported_plays_keep_their_time_and_cursor_persists() {
+        let dir = unique_test_dir("reco-import");
+        let store = RecoStore::new_at(&dir).expect("open");
+        let long_ago = now_ts() - 30 * 86_400;
+        store.log_play_event_at(42, Some("alb".into()), Some(7), None, long_ago).unwrap();
+
+        // Outside a 7-day window, inside the all-time one.
+        assert!(store.get_recent_track_ids_since(7 * 86_400, 10).unwrap().is_empty());
+        assert_eq!(store.get_recent_track_ids(10).unwrap(), vec![42]);
+
+        assert_eq!(store.get_import_cursor("listenbrainz").unwrap(), None);
+        store.set_import_cursor("listenbrainz", long_ago).unwrap();
+        store.set_import_cursor("listenbrainz", long_ago + 60).unwrap();
+        assert_eq!(store.get_import_cursor("listenbrainz").unwrap(), Some(long_ago + 60));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn windowed_recent_query_respects_window() {
         let dir = unique_test_dir("reco-window");
@@ -1228,6 +1723,50 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn genre_affinity_favors_recent_plays() {
+        let dir = unique_test_dir("reco-genre-affinity");
+        let store = RecoStore::new_at(&dir).expect("open");
+        let now = now_ts();
+        let day = 86_400;
+        // Jazz (5): 3 plays four weeks ago (3 * 1/16). Rock (6): 1 play today.
+        for track in 1..=3 {
+            insert_at(&store, "play", "track", Some(track), Some("jz"), Some(1), Some(5), now - 28 * day);
+        }
+        insert_at(&store, "play", "track", Some(4), Some("rk"), Some(2), Some(6), now);
+        // Favorites and plays without a genre don't count.
+        insert_at(&store, "favorite", "track", Some(5), Some("jz"), Some(1), Some(5), now);
+        insert_at(&store, "play", "track", Some(6), Some("xx"), Some(3), None, now);
+
+        let scores = store.get_genre_affinity_scores().unwrap();
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].0, 6);
+        assert!((scores[0].1 - 1.0).abs() < 1e-3);
+        assert_eq!(scores[1].0, 5);
+        assert!((scores[1].1 - 3.0 / 16.0).abs() < 1e-3);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn genre_affinity_rolls_subgenres_up_to_their_root() {
+        let dir = unique_test_dir("reco-genre-rollup");
+        let store = RecoStore::new_at(&dir).expect("open");
+        let now = now_ts();
+        // Bebop (80) and Cool Jazz (81) albums, both under Jazz (5).
+        insert_at(&store, "play", "track", Some(1), Some("bebop"), Some(1), Some(80), now);
+        insert_at(&store, "play", "track", Some(2), Some("cool"), Some(2), Some(81), now);
+        insert_at(&store, "play", "track", Some(3), Some("rk"), Some(3), Some(6), now);
+        store.set_album_genre_root("bebop", 5).unwrap();
+        store.set_album_genre_root("cool", 5).unwrap();
+
+        let scores = store.get_genre_affinity_scores().unwrap();
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].0, 5);
+        assert!((scores[0].1 - 2.0).abs() < 1e-3);
+        assert_eq!(scores[1].0, 6);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn home_seeds_shape_fallback_and_trained() {
         let dir = unique_test_dir("reco-homeseeds");
@@ -1253,6 +1792,39 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn listening_streak_counts_completed_days() {
+        let dir = unique_test_dir("reco-streak");
+        let store = RecoStore::new_at(&dir).expect("open");
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let play_on = |day: u32| {
+            let at = chrono::NaiveDate::from_ymd_opt(2026, 3, day)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_local_timezone(chrono::Local)
+                .unwrap()
+                .timestamp();
+            let track = Some(day as u64);
+            insert_at(&store, "play", "track", track, None, None, None, at);
+        };
+        let empty = store.listening_streak_on(today).unwrap();
+        assert_eq!(empty, ListeningStreak::default());
+
+        // 9, 8, 7 in a row; 6 missed; today never counts.
+        for day in [10, 9, 9, 8, 7, 5] {
+            play_on(day);
+        }
+        let streak = store.listening_streak_on(today).unwrap();
+        assert_eq!(streak.days, 3);
+        assert_eq!(streak.last_play_date.as_deref(), Some("2026-03-10"));
+
+        // Skipping yesterday breaks the streak.
+        let later = chrono::NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+        assert_eq!(store.listening_streak_on(later).unwrap().days, 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn train_favorite_outranks_play() {
         let dir = unique_test_dir("reco-train");
@@ -1273,6 +1845,95 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn dislikes_push_items_out_of_trained_seeds() {
+        let dir = unique_test_dir("reco-dislike");
+        let mut store = RecoStore::new_at(&dir).expect("open");
+        let now = now_ts();
+        // Artist 10 played twice, artist 11 once; album "jz" is genre 5.
+        insert_at(&store, "play", "track", Some(1), Some("rk"), Some(10), Some(6), now);
+        insert_at(&store, "play", "track", Some(2), Some("rk"), Some(10), Some(6), now);
+        insert_at(&store, "play", "track", Some(3), Some("jz"), Some(11), Some(5), now);
+        insert_at(&store, "play", "track", Some(4), Some("pp"), Some(12), Some(7), now);
+
+        store.log_dislike_event(RecoItemType::Artist, "10").unwrap();
+        store.log_dislike_event(RecoItemType::Artist, "10").unwrap();
+        store.log_dislike_event(RecoItemType::Artist, "12").unwrap();
+        store.log_dislike_event(RecoItemType::Genre, "5").unwrap();
+        assert!(store.log_dislike_event(RecoItemType::Track, "abc").is_err());
+        // A repeat replaces the earlier dislike; clearing withdraws it.
+        assert_eq!(
+            store
+                .clear_dislike_event(RecoItemType::Artist, "12")
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            store
+                .clear_dislike_event(RecoItemType::Artist, "10")
+                .unwrap(),
+            1
+        );
+        store.log_dislike_event(RecoItemType::Artist, "10").unwrap();
+        assert_eq!(store.get_disliked_artist_ids().unwrap(), vec![10]);
+
+        store.train(TrainParams::default()).unwrap();
+        let seeds = store.get_home_seeds(HomeSeedLimits::default()).unwrap();
+        let artists: Vec<u64> = seeds.top_artist_ids.iter().map(|s| s.artist_id).collect();
+        assert_eq!(artists, vec![12]); // 10 disliked, 11 only played in genre 5
+        let albums = store.get_scored_album_ids("all", 10).unwrap();
+        assert!(!albums.contains(&"jz".to_string()));
+        assert!(store.get_scored_track_ids("all", 10).unwrap().contains(&4));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn similar_albums_rank_by_decayed_co_plays() {
+        let dir = unique_test_dir("reco-similar");
+        let store = RecoStore::new_at(&dir).unwrap();
+        // 01:00 UTC today, so one session never straddles midnight.
+        let now = now_ts() / 86_400 * 86_400 + 3600;
+        let play = |album: &str, artist: u64, at: i64| {
+            insert_at(
+                &store,
+                "play",
+                "track",
+                Some(1),
+                Some(album),
+                Some(artist),
+                None,
+                at,
+            );
+        };
+        // Two tracks of the seed, then "near" (two tracks, same session) and
+        // "same" (the seed's artist) right after it, today.
+        play("seed", 1, now - 600);
+        play("seed", 1, now - 300);
+        play("near", 2, now);
+        play("near", 2, now + 60);
+        play("same", 1, now + 120);
+        // "old": listened with the seed once, a year ago.
+        play("seed", 1, now - 365 * 86_400);
+        play("old", 3, now - 365 * 86_400 + 60);
+        // "far": same day, but hours away from any seed play.
+        play("far", 4, now - 10 * 60 * 60);
+        // "meh": co-played, then disliked.
+        play("meh", 5, now + 180);
+        store.log_dislike_event(RecoItemType::Album, "meh").unwrap();
+
+        let similar = store.get_similar_album_candidates("seed", 10).unwrap();
+        let ids: Vec<&str> = similar.iter().map(|c| c.album_id.as_str()).collect();
+        assert_eq!(ids, vec!["same", "near", "old"]);
+        // The two "near" track plays are one co-play day.
+        assert_eq!(similar[1].co_play_days, 1);
+        assert!(similar[2].score < 0.01);
+        assert_eq!(
+            store.get_similar_album_candidates("seed", 1).unwrap().len(),
+            1
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn forgotten_favorites_excludes_recently_played() {
         let dir = unique_test_dir("reco-forgotten");
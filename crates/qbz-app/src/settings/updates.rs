@@ -0,0 +1,131 @@
+//! Update-check preferences persistence.
+//!
+//! One global (not per-user) row holding the release channel the About
+//! modal's "Check for updates" consults. Stable only ever sees full
+//! releases; Beta also sees GitHub pre-releases.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
+impl UpdateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => "stable",
+            UpdateChannel::Beta => "beta",
+        }
+    }
+
+    /// Parse a stored value; anything unknown falls back to Stable.
+    pub fn from_str_lossy(value: &str) -> Self {
+        match value {
+            "beta" => UpdateChannel::Beta,
+            _ => UpdateChannel::Stable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdatePreferences {
+    pub channel: UpdateChannel,
+}
+
+pub struct UpdatesStore {
+    conn: Connection,
+}
+
+impl UpdatesStore {
+    fn open_at(dir: &Path, db_name: &str) -> Result<Self, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+        let db_path = dir.join(db_name);
+        let conn = Connection::open(&db_path)
+            .map_err(|e| format!("Failed to open updates database: {}", e))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS update_preferences (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                channel TEXT NOT NULL DEFAULT 'stable'
+            );
+            INSERT OR IGNORE INTO update_preferences (id) VALUES (1);",
+        )
+        .map_err(|e| format!("Failed to create update preferences table: {}", e))?;
+
+        Ok(Self { conn })
+    }
+
+    pub fn new() -> Result<Self, String> {
+        let data_dir = dirs::data_dir()
+            .ok_or("Could not determine data directory")?
+            .join("qbz");
+        Self::open_at(&data_dir, "updates.db")
+    }
+
+    pub fn new_at(base_dir: &Path) -> Result<Self, String> {
+        Self::open_at(base_dir, "updates.db")
+    }
+
+    pub fn get_preferences(&self) -> Result<UpdatePreferences, String> {
+        self.conn
+            .query_row(
+                "SELECT channel FROM update_preferences WHERE id = 1",
+                [],
+                |row| {
+                    Ok(UpdatePreferences {
+                        channel: UpdateChannel::from_str_lossy(&row.get::<_, String>(0)?),
+                    })
+                },
+            )
+            .map_err(|e| format!("Failed to get update preferences: {}", e))
+    }
+
+    pub fn set_channel(&self, channel: UpdateChannel) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE update_preferences SET channel = ?1 WHERE id = 1",
+                params![channel.as_str()],
+            )
+            .map_err(|e| format!("Failed to set update channel: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_test_dir(name: &str) -> std::path::PathBuf {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("qbz-app-{name}-{}-{nonce}", std::process::id()))
+    }
+
+    #[test]
+    fn channel_defaults_to_stable_and_persists() {
+        let dir = unique_test_dir("updates-channel");
+        {
+            let store = UpdatesStore::new_at(&dir).expect("open store");
+            let prefs = store.get_preferences().expect("get preferences");
+            assert_eq!(prefs.channel, UpdateChannel::Stable);
+            store.set_channel(UpdateChannel::Beta).expect("set channel");
+        }
+
+        let reopened = UpdatesStore::new_at(&dir).expect("reopen store");
+        let prefs = reopened.get_preferences().expect("get preferences");
+
+        assert_eq!(prefs.channel, UpdateChannel::Beta);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -11,6 +11,7 @@
 use super::backend::{
     AlsaPlugin, AudioBackend, AudioBackendType, AudioDevice, BackendConfig, BackendResult,
 };
+use super::diagnostic::LatencyInfo;
 use rodio::{
     cpal::{
         traits::{DeviceTrait, HostTrait},
@@ -20,6 +21,7 @@ use rodio::{
 };
 use std::collections::HashMap;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 /// The PipeWire sink QBZ suspended to take a device exclusively (ALSA-direct
@@ -484,6 +486,60 @@ fn get_hw_supported_rates(card_name: &str) -> Option<Vec<u32>> {
     }
 }
 
+/// `(card number, PCM device number)` of an ALSA device id, for
+/// `/proc/asound` paths: `hw:1,0` / `plughw:1,0` directly,
+/// `<alias>:CARD=<name>,DEV=<n>` through the card's short name.
+fn proc_pcm_ids(device_id: &str) -> Option<(String, String)> {
+    let (_, args) = device_id.split_once(':')?;
+    if let Some(named) = args.strip_prefix("CARD=") {
+        let mut parts = named.splitn(2, ',');
+        let card = find_card_number_by_name(parts.next()?)?;
+        let dev = parts
+            .next()
+            .and_then(|d| d.strip_prefix("DEV="))
+            .unwrap_or("0");
+        return Some((card, dev.to_string()));
+    }
+    let mut parts = args.splitn(2, ',');
+    let card = parts.next()?;
+    if card.is_empty() || !card.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((card.to_string(), parts.next().unwrap_or("0").to_string()))
+}
+
+/// Latency of the open PCM behind `device_id`, from the kernel's
+/// `/proc/asound/cardN/pcmMp/sub0/hw_params` — the period and buffer sizes
+/// actually granted, whoever opened the device. Errors when the PCM is
+/// closed (nothing playing) or the id doesn't name a card.
+pub fn query_pcm_latency(device_id: &str) -> Result<LatencyInfo, String> {
+    let (card, dev) =
+        proc_pcm_ids(device_id).ok_or_else(|| format!("'{}' is not a card device", device_id))?;
+    let path = format!("/proc/asound/card{}/pcm{}p/sub0/hw_params", card, dev);
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+    parse_hw_params_latency(&text).ok_or_else(|| format!("'{}' is not open", device_id))
+}
+
+/// Pure half of [`query_pcm_latency`]. The device latency is one period
+/// (`period_size / rate`, what the hardware drains between wakeups); the
+/// rest of the ring buffer is queued ahead of it. A closed PCM's file just
+/// says `closed`, so None.
+fn parse_hw_params_latency(text: &str) -> Option<LatencyInfo> {
+    let field = |key: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| value.split_whitespace().next())
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+    let rate = field("rate:")? as u32;
+    let period = field("period_size:")?;
+    let buffer = field("buffer_size:")?;
+    Some(LatencyInfo::new(
+        LatencyInfo::frames_to_ms(period, rate),
+        LatencyInfo::frames_to_ms(buffer.saturating_sub(period), rate),
+    ))
+}
+
 // ============================================================================
 // ALSA Backend Implementation
 // ============================================================================
@@ -726,6 +782,12 @@ impl AlsaBackend {
         // alias itself may be undeclared for the selected DEV (snd-aloop only
         // declares `front` for DEV=0) and needlessly routes through alsa-lib
         // plugins, while the raw ids open the kernel PCM directly (#641).
+        // Cards with a UCM profile need its verb enabled before the PCM
+        // carries audio; a failure here is logged and the open proceeds.
+        if let Err(e) = apply_ucm_profile(device_id) {
+            log::warn!("[ALSA UCM] {}", e);
+        }
+
         let (hw_device, plughw_device) = if let Some(ids) = raw_open_ids(device_id) {
             ids
         } else if device_id.starts_with("hw:") {
@@ -1152,6 +1214,425 @@ pub fn create_dop_stream(
     }
 }
 
+/// Whether the card behind an ALSA device id is still registered. `None`
+/// for ids that don't name an ALSA card (PipeWire/Pulse sink names,
+/// `default`), which hot-plug can't reason about.
+pub fn is_device_present(device_id: &str) -> Option<bool> {
+    const CARD_PREFIXES: [&str; 6] = [
+        "hw:",
+        "plughw:",
+        "front:CARD=",
+        "sysdefault:CARD=",
+        "iec958:CARD=",
+        "hdmi:CARD=",
+    ];
+    if !CARD_PREFIXES.iter().any(|p| device_id.starts_with(p)) {
+        return None;
+    }
+    Some(is_card_present_in_proc(device_id))
+}
+
+// === ALSA UCM (Use Case Manager) ===
+//
+// Some cards (HifiBerry and other Raspberry Pi HATs, SoC codecs) only route
+// audio to the output once a UCM verb has run its enable sequence (mixer
+// paths, DAPM switches). alsa-rs doesn't bind use_case.h, so the handful of
+// calls we need are declared here; libasound is already linked via alsa-sys.
+
+/// Where alsa-lib looks for UCM2 profiles.
+const UCM2_ROOT: &str = "/usr/share/alsa/ucm2";
+/// Verb applied when the profile offers it (the standard playback use case).
+const UCM_DEFAULT_VERB: &str = "HiFi";
+
+mod ucm_ffi {
+    use std::os::raw::{c_char, c_int};
+
+    #[repr(C)]
+    pub struct snd_use_case_mgr_t {
+        _private: [u8; 0],
+    }
+
+    extern "C" {
+        pub fn snd_use_case_mgr_open(
+            uc_mgr: *mut *mut snd_use_case_mgr_t,
+            card_name: *const c_char,
+        ) -> c_int;
+        pub fn snd_use_case_mgr_close(uc_mgr: *mut snd_use_case_mgr_t) -> c_int;
+        pub fn snd_use_case_get_list(
+            uc_mgr: *mut snd_use_case_mgr_t,
+            identifier: *const c_char,
+            list: *mut *mut *const c_char,
+        ) -> c_int;
+        pub fn snd_use_case_free_list(list: *mut *const c_char, items: c_int) -> c_int;
+        pub fn snd_use_case_set(
+            uc_mgr: *mut snd_use_case_mgr_t,
+            identifier: *const c_char,
+            value: *const c_char,
+        ) -> c_int;
+    }
+}
+
+/// Verb applied per card number in this process, so a stream reopen doesn't
+/// re-run the enable sequence or undo a verb the user picked.
+static UCM_APPLIED: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+fn alsa_strerror(code: std::os::raw::c_int) -> String {
+    // SAFETY: snd_strerror returns a static string for any code.
+    unsafe { std::ffi::CStr::from_ptr(alsa_sys::snd_strerror(code)) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// An open UCM manager for one card, closed on drop.
+struct UcmManager(*mut ucm_ffi::snd_use_case_mgr_t);
+
+impl UcmManager {
+    fn open(card: &str) -> Result<Self, String> {
+        let name = std::ffi::CString::new(format!("hw:{}", card)).map_err(|e| e.to_string())?;
+        let mut mgr = std::ptr::null_mut();
+        // SAFETY: `mgr` is a valid out-pointer and `name` outlives the call.
+        let rc = unsafe { ucm_ffi::snd_use_case_mgr_open(&mut mgr, name.as_ptr()) };
+        if rc < 0 || mgr.is_null() {
+            return Err(format!(
+                "No UCM configuration for card {}: {}",
+                card,
+                alsa_strerror(rc)
+            ));
+        }
+        Ok(Self(mgr))
+    }
+
+    fn verbs(&self) -> Result<Vec<String>, String> {
+        let mut list: *mut *const std::os::raw::c_char = std::ptr::null_mut();
+        // SAFETY: the manager is open; `list` receives an alsa-owned array
+        // that is released with snd_use_case_free_list below.
+        let count =
+            unsafe { ucm_ffi::snd_use_case_get_list(self.0, c"_verbs".as_ptr(), &mut list) };
+        if count < 0 {
+            return Err(format!(
+                "Failed to list UCM verbs: {}",
+                alsa_strerror(count)
+            ));
+        }
+        // The list alternates verb name and comment.
+        let verbs = (0..count as usize)
+            .step_by(2)
+            .filter_map(|i| {
+                // SAFETY: i < count, and every entry is a NUL-terminated string or null.
+                let item = unsafe { *list.add(i) };
+                (!item.is_null()).then(|| {
+                    unsafe { std::ffi::CStr::from_ptr(item) }
+                        .to_string_lossy()
+                        .into_owned()
+                })
+            })
+            .collect();
+        if !list.is_null() {
+            // SAFETY: `list`/`count` come straight from snd_use_case_get_list.
+            unsafe { ucm_ffi::snd_use_case_free_list(list, count) };
+        }
+        Ok(verbs)
+    }
+
+    fn set_verb(&self, verb: &str) -> Result<(), String> {
+        let value = std::ffi::CString::new(verb).map_err(|e| e.to_string())?;
+        // SAFETY: the manager is open and both strings outlive the call.
+        let rc = unsafe { ucm_ffi::snd_use_case_set(self.0, c"_verb".as_ptr(), value.as_ptr()) };
+        if rc < 0 {
+            return Err(format!(
+                "Failed to set UCM verb '{}': {}",
+                verb,
+                alsa_strerror(rc)
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for UcmManager {
+    fn drop(&mut self) {
+        // SAFETY: opened by snd_use_case_mgr_open and closed exactly once.
+        unsafe { ucm_ffi::snd_use_case_mgr_close(self.0) };
+    }
+}
+
+/// Driver name from a `/proc/asound/cards` line:
+/// `" 0 [C20            ]: USB-Audio - Cambridge Audio USB Audio 2.0"` -> `"USB-Audio"`.
+fn parse_proc_card_driver(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once("]:")?;
+    let driver = rest.split(" - ").next()?.trim();
+    (!driver.is_empty()).then(|| driver.to_string())
+}
+
+/// The UCM2 profile file for a card under `root`, trying the layouts
+/// alsa-lib resolves: `conf.d/<driver>/<long name>.conf`,
+/// `conf.d/<driver>/<driver>.conf` and the legacy `<card>/<card>.conf`.
+fn find_ucm_profile(
+    root: &Path,
+    driver: Option<&str>,
+    short_name: &str,
+    long_name: &str,
+) -> Option<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(driver) = driver {
+        let dir = root.join("conf.d").join(driver);
+        candidates.push(dir.join(format!("{}.conf", long_name)));
+        candidates.push(dir.join(format!("{}.conf", driver)));
+    }
+    candidates.push(root.join(short_name).join(format!("{}.conf", short_name)));
+    candidates.into_iter().find(|p| p.is_file())
+}
+
+/// Card number of `device_id` when its card ships a UCM2 profile.
+fn ucm_card(device_id: &str) -> Option<String> {
+    let (card, _) = proc_pcm_ids(device_id)?;
+    let info = read_proc_asound_cards()
+        .into_iter()
+        .find(|c| c.number == card)?;
+    let driver = fs::read_to_string("/proc/asound/cards")
+        .ok()?
+        .lines()
+        .find(|l| l.trim_start().starts_with(&format!("{} [", card)))
+        .and_then(parse_proc_card_driver);
+    let profile = find_ucm_profile(
+        Path::new(UCM2_ROOT),
+        driver.as_deref(),
+        &info.short_name,
+        &info.long_name,
+    )?;
+    log::debug!("[ALSA UCM] Card {} profile: {}", card, profile.display());
+    Some(card)
+}
+
+/// `HiFi` when the profile offers it, else its first verb.
+fn default_ucm_verb(verbs: &[String]) -> Option<&String> {
+    verbs
+        .iter()
+        .find(|v| v.as_str() == UCM_DEFAULT_VERB)
+        .or_else(|| verbs.first())
+}
+
+fn remember_ucm_verb(card: &str, verb: &str) {
+    let mut applied = UCM_APPLIED.lock().unwrap_or_else(|e| e.into_inner());
+    applied
+        .get_or_insert_with(HashMap::new)
+        .insert(card.to_string(), verb.to_string());
+}
+
+/// Apply the UCM profile of `device_id`'s card before its PCM is opened:
+/// the `HiFi` verb when offered, else the profile's first verb. A no-op for
+/// cards without a UCM2 profile, and for cards already configured in this
+/// process (including by [`set_ucm_verb`]).
+pub fn apply_ucm_profile(device_id: &str) -> BackendResult<()> {
+    let Some(card) = ucm_card(device_id) else {
+        return Ok(());
+    };
+    let already = UCM_APPLIED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .is_some_and(|applied| applied.contains_key(&card));
+    if already {
+        return Ok(());
+    }
+    let mgr = UcmManager::open(&card)?;
+    let verbs = mgr.verbs()?;
+    let Some(verb) = default_ucm_verb(&verbs) else {
+        return Ok(());
+    };
+    mgr.set_verb(verb)?;
+    remember_ucm_verb(&card, verb);
+    log::info!("[ALSA UCM] Applied verb '{}' on card {}", verb, card);
+    Ok(())
+}
+
+/// Verbs offered by the UCM profile of `device_id`'s card (empty when the
+/// card has no profile).
+pub fn get_ucm_verbs(device_id: &str) -> BackendResult<Vec<String>> {
+    match ucm_card(device_id) {
+        Some(card) => UcmManager::open(&card)?.verbs(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// The verb in effect on `device_id`'s card: the one applied in this
+/// process (by [`apply_ucm_profile`] or [`set_ucm_verb`]), else the one
+/// [`apply_ucm_profile`] will pick when the device opens. None when the card
+/// has no UCM profile.
+pub fn current_ucm_verb(device_id: &str) -> BackendResult<Option<String>> {
+    let Some(card) = ucm_card(device_id) else {
+        return Ok(None);
+    };
+    let applied = UCM_APPLIED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .and_then(|applied| applied.get(&card).cloned());
+    if applied.is_some() {
+        return Ok(applied);
+    }
+    let verbs = UcmManager::open(&card)?.verbs()?;
+    Ok(default_ucm_verb(&verbs).cloned())
+}
+
+/// Apply a specific UCM verb on `device_id`'s card. It then sticks for the
+/// rest of the process: [`apply_ucm_profile`] won't replace it.
+pub fn set_ucm_verb(device_id: &str, verb: &str) -> BackendResult<()> {
+    let card =
+        ucm_card(device_id).ok_or_else(|| format!("Device '{}' has no UCM profile", device_id))?;
+    UcmManager::open(&card)?.set_verb(verb)?;
+    remember_ucm_verb(&card, verb);
+    log::info!("[ALSA UCM] Set verb '{}' on card {}", verb, card);
+    Ok(())
+}
+
+/// A playback PCM node appeared in or vanished from `/dev/snd`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceHotplugEvent {
+    /// Card number of the node that was created
+    Added { card: String },
+    /// Card number of the node that was removed
+    Removed { card: String },
+}
+
+/// Keeps the `/dev/snd` watch alive; dropping it stops notifications.
+pub struct DeviceWatcher {
+    _watcher: notify::RecommendedWatcher,
+}
+
+/// `pcmC1D0p` -> `Some("1")`. Capture nodes (`...c`), control and timer
+/// nodes are ignored: only playback PCMs matter for output.
+fn playback_pcm_card(node: &str) -> Option<String> {
+    let rest = node.strip_prefix("pcmC")?.strip_suffix('p')?;
+    let (card, dev) = rest.split_once('D')?;
+    if card.is_empty()
+        || dev.is_empty()
+        || !card.bytes().all(|b| b.is_ascii_digit())
+        || !dev.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some(card.to_string())
+}
+
+/// How long a card's node events are coalesced. A USB DAC creates or drops
+/// several PCM nodes (one per device, plus udev's renames) within a few ms.
+const HOTPLUG_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Per-card debounce for hot-plug node events: a burst on one card collapses
+/// into a single event carrying the card's last state, once the card has
+/// been quiet for [`HOTPLUG_DEBOUNCE`].
+#[derive(Default)]
+struct HotplugDebouncer {
+    /// card -> (last seen added/removed, time of the last node event)
+    pending: HashMap<String, (bool, std::time::Instant)>,
+}
+
+impl HotplugDebouncer {
+    fn push(&mut self, card: String, added: bool, now: std::time::Instant) {
+        self.pending.insert(card, (added, now));
+    }
+
+    /// Time until the earliest pending card settles (None = nothing pending)
+    fn next_due(&self, now: std::time::Instant) -> Option<std::time::Duration> {
+        self.pending
+            .values()
+            .map(|(_, at)| (*at + HOTPLUG_DEBOUNCE).saturating_duration_since(now))
+            .min()
+    }
+
+    /// Remove and return the events of every card that has settled.
+    fn take_due(&mut self, now: std::time::Instant) -> Vec<DeviceHotplugEvent> {
+        let due: Vec<String> = self
+            .pending
+            .iter()
+            .filter(|(_, (_, at))| now.duration_since(*at) >= HOTPLUG_DEBOUNCE)
+            .map(|(card, _)| card.clone())
+            .collect();
+        due.into_iter()
+            .filter_map(|card| {
+                let (added, _) = self.pending.remove(&card)?;
+                Some(if added {
+                    DeviceHotplugEvent::Added { card }
+                } else {
+                    DeviceHotplugEvent::Removed { card }
+                })
+            })
+            .collect()
+    }
+}
+
+/// Watch `/dev/snd` (inotify) for playback PCM nodes being created or
+/// removed — a USB DAC being plugged in or yanked. Node events are debounced
+/// per card, so `on_event` runs once per plug/unplug on a dedicated thread;
+/// keep it cheap and hand real work off. Callers should re-enumerate
+/// devices and, on removal, check whether the active output is gone
+/// ([`is_device_present`]).
+pub fn watch_pcm_devices<F>(on_event: F) -> Result<DeviceWatcher, String>
+where
+    F: Fn(DeviceHotplugEvent) + Send + 'static,
+{
+    use notify::{EventKind, RecursiveMode, Watcher};
+    use std::sync::mpsc::{self, RecvTimeoutError};
+
+    let (tx, rx) = mpsc::channel::<(String, bool)>();
+    // Exits when the watcher (and with it `tx`) is dropped.
+    std::thread::Builder::new()
+        .name("qbz-hotplug".into())
+        .spawn(move || {
+            let mut debouncer = HotplugDebouncer::default();
+            loop {
+                let received = match debouncer.next_due(std::time::Instant::now()) {
+                    Some(wait) => rx.recv_timeout(wait),
+                    None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+                match received {
+                    Ok((card, added)) => debouncer.push(card, added, std::time::Instant::now()),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+                for event in debouncer.take_due(std::time::Instant::now()) {
+                    on_event(event);
+                }
+            }
+        })
+        .map_err(|e| format!("Failed to start hot-plug thread: {}", e))?;
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        let added = match event.kind {
+            EventKind::Create(_) => true,
+            EventKind::Remove(_) => false,
+            _ => return,
+        };
+        for path in &event.paths {
+            let Some(card) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(playback_pcm_card)
+            else {
+                continue;
+            };
+            log::info!(
+                "[ALSA Backend] Hot-plug: playback PCM {} on card {}",
+                if added { "added" } else { "removed" },
+                card
+            );
+            let _ = tx.send((card, added));
+        }
+    })
+    .map_err(|e| format!("Failed to create /dev/snd watcher: {}", e))?;
+    watcher
+        .watch(
+            std::path::Path::new("/dev/snd"),
+            RecursiveMode::NonRecursive,
+        )
+        .map_err(|e| format!("Failed to watch /dev/snd: {}", e))?;
+    Ok(DeviceWatcher { _watcher: watcher })
+}
+
 impl AudioBackend for AlsaBackend {
     fn backend_type(&self) -> AudioBackendType {
         AudioBackendType::Alsa
@@ -1304,12 +1785,18 @@ impl AudioBackend for AlsaBackend {
             },
         };
 
-        // Create SupportedStreamConfig
+        // Create SupportedStreamConfig. Use the negotiated integer format
+        // when there is one (int32 reaches a 24-bit DAC without a float
+        // stage in alsa-lib); float otherwise.
+        let sample_format = config
+            .negotiated_format
+            .and_then(|f| f.to_cpal())
+            .unwrap_or(SampleFormat::F32);
         let supported_config = SupportedStreamConfig::new(
             stream_config.channels,
             stream_config.sample_rate,
             SupportedBufferSize::Range { min: 64, max: 8192 },
-            SampleFormat::F32,
+            sample_format,
         );
 
         // In exclusive mode, PipeWire may have re-acquired the device after the
@@ -1383,6 +1870,52 @@ impl AudioBackend for AlsaBackend {
 mod tests {
     use super::*;
 
+    #[test]
+    fn playback_pcm_card_parses_only_playback_nodes() {
+        assert_eq!(playback_pcm_card("pcmC1D0p"), Some("1".to_string()));
+        assert_eq!(playback_pcm_card("pcmC12D3p"), Some("12".to_string()));
+        assert_eq!(playback_pcm_card("pcmC1D0c"), None);
+        assert_eq!(playback_pcm_card("controlC1"), None);
+        assert_eq!(playback_pcm_card("pcmCD0p"), None);
+        assert_eq!(playback_pcm_card("timer"), None);
+    }
+
+    #[test]
+    fn hotplug_debounce_collapses_a_card_burst() {
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now();
+        let mut debouncer = HotplugDebouncer::default();
+        debouncer.push("1".into(), true, start);
+        debouncer.push("1".into(), true, start + Duration::from_millis(5));
+        debouncer.push("2".into(), false, start + Duration::from_millis(10));
+        assert!(debouncer
+            .take_due(start + Duration::from_millis(100))
+            .is_empty());
+
+        let mut events = debouncer.take_due(start + Duration::from_millis(400));
+        events.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
+        assert_eq!(
+            events,
+            vec![
+                DeviceHotplugEvent::Added { card: "1".into() },
+                DeviceHotplugEvent::Removed { card: "2".into() },
+            ]
+        );
+        assert_eq!(debouncer.next_due(start), None);
+    }
+
+    #[test]
+    fn is_device_present_ignores_non_card_ids() {
+        assert_eq!(is_device_present("default"), None);
+        assert_eq!(
+            is_device_present("alsa_output.usb-Topping_E30-00.analog-stereo"),
+            None
+        );
+        // No machine has 999 cards
+        assert_eq!(is_device_present("hw:999,0"), Some(false));
+    }
+
     #[test]
     fn build_hw_fallback_id_rewrites_iec958_alias() {
         // The exact case from issue #331 — HifiBerry Digi2 Pro on RPi OS.
@@ -1524,6 +2057,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hw_params_latency_is_period_plus_queued_ring() {
+        let open = "access: RW_INTERLEAVED\n\
+                    format: S32_LE\n\
+                    subformat: STD\n\
+                    channels: 2\n\
+                    rate: 96000 (96000/1)\n\
+                    period_size: 4800\n\
+                    buffer_size: 19200\n";
+        let latency = parse_hw_params_latency(open).expect("open pcm");
+        assert_eq!(latency.device_latency_ms, 50.0);
+        assert_eq!(latency.software_buffer_ms, 150.0);
+        assert_eq!(latency.total_latency_ms, 200.0);
+        assert_eq!(parse_hw_params_latency("closed\n"), None);
+    }
+
+    #[test]
+    fn proc_pcm_ids_reads_raw_card_numbers() {
+        assert_eq!(
+            proc_pcm_ids("hw:1,0"),
+            Some(("1".to_string(), "0".to_string()))
+        );
+        assert_eq!(
+            proc_pcm_ids("plughw:2,3"),
+            Some(("2".to_string(), "3".to_string()))
+        );
+        assert_eq!(proc_pcm_ids("default"), None);
+        assert_eq!(proc_pcm_ids("pulse:foo"), None);
+    }
+
+    #[test]
+    fn parse_proc_card_driver_reads_the_driver_field() {
+        assert_eq!(
+            parse_proc_card_driver(
+                " 0 [C20            ]: USB-Audio - Cambridge Audio USB Audio 2.0"
+            ),
+            Some("USB-Audio".to_string())
+        );
+        assert_eq!(parse_proc_card_driver("no card here"), None);
+    }
+
+    #[test]
+    fn find_ucm_profile_checks_driver_then_legacy_layout() {
+        let root = std::env::temp_dir().join(format!("qbz-ucm2-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        assert_eq!(
+            find_ucm_profile(&root, Some("HifiBerry"), "DAC", "HifiBerry DAC"),
+            None
+        );
+
+        fs::create_dir_all(root.join("DAC")).unwrap();
+        fs::write(root.join("DAC/DAC.conf"), "").unwrap();
+        assert_eq!(
+            find_ucm_profile(&root, Some("HifiBerry"), "DAC", "HifiBerry DAC"),
+            Some(root.join("DAC/DAC.conf"))
+        );
+
+        fs::create_dir_all(root.join("conf.d/HifiBerry")).unwrap();
+        fs::write(root.join("conf.d/HifiBerry/HifiBerry.conf"), "").unwrap();
+        assert_eq!(
+            find_ucm_profile(&root, Some("HifiBerry"), "DAC", "HifiBerry DAC"),
+            Some(root.join("conf.d/HifiBerry/HifiBerry.conf"))
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
     #[test]
     fn raw_open_ids_passes_through_non_alias_ids() {
         // Raw and virtual ids keep the caller's pre-existing handling.
@@ -874,56 +874,112 @@ impl AlsaDirectStream {
         &self.device_id
     }
 
+    /// Sample format the hardware accepted at open time
+    pub fn sample_format(&self) -> crate::backend::SampleFormat {
+        use crate::backend::SampleFormat;
+        match self.format {
+            Format::S32LE => SampleFormat::Int32,
+            Format::S243LE | Format::S24LE => SampleFormat::Int24,
+            Format::S16LE => SampleFormat::Int16,
+            _ => SampleFormat::Float32,
+        }
+    }
+
+    /// Query which PCM sample formats a device accepts, without configuring
+    /// it. Opens the PCM briefly (non-blocking), so it fails with a busy
+    /// error while another client holds the device exclusively.
+    pub fn probe_formats(device_id: &str) -> Result<Vec<crate::backend::SampleFormat>, String> {
+        use crate::backend::SampleFormat;
+        let pcm = PCM::new(device_id, Direction::Playback, true)
+            .map_err(|e| format!("Failed to open ALSA device '{}': {}", device_id, e))?;
+        let candidates = [
+            (SampleFormat::Int32, &[Format::S32LE][..]),
+            (SampleFormat::Int24, &[Format::S243LE, Format::S24LE][..]),
+            (SampleFormat::Float32, &[Format::FloatLE][..]),
+            (SampleFormat::Int16, &[Format::S16LE][..]),
+        ];
+        let mut supported = Vec::new();
+        for (sample_format, alsa_formats) in candidates {
+            // A fresh parameter space per test: set_format narrows it
+            let accepted = alsa_formats.iter().any(|f| {
+                HwParams::any(&pcm)
+                    .map(|hwp| hwp.set_format(*f).is_ok())
+                    .unwrap_or(false)
+            });
+            if accepted {
+                supported.push(sample_format);
+            }
+        }
+        Ok(supported)
+    }
+
     /// Try to set hardware volume via ALSA mixer
     ///
+    /// Uses the first of the usual control names the card exposes, else the
+    /// first element with a playback volume at all (see [`Self::mixer_elements`]).
+    ///
     /// Returns error if:
     /// - DAC doesn't have mixer controls (common for USB DACs)
     /// - Mixer API fails
     ///
     /// NOTE: Failure doesn't break playback, just means volume can't be controlled.
     pub fn set_hardware_volume(&self, volume: f32) -> Result<(), String> {
-        use alsa::mixer::SelemChannelId::*;
         use alsa::mixer::{Mixer, SelemId};
 
-        // Open mixer for device
-        let mixer = Mixer::new(&self.device_id, false)
-            .map_err(|e| format!("Failed to open mixer for {}: {}", self.device_id, e))?;
-
-        // Try to find a volume control element
-        // Common names: "Master", "PCM", "Speaker", "Headphone"
-        let control_names = ["Master", "PCM", "Speaker", "Headphone", "Digital"];
-
-        for name in &control_names {
-            let selem_id = SelemId::new(name, 0);
-
-            if let Some(selem) = mixer.find_selem(&selem_id) {
-                // Check if this element has playback volume control
-                if selem.has_playback_volume() {
-                    let (min, max) = selem.get_playback_volume_range();
-                    let target = min + ((max - min) as f32 * volume) as i64;
-
-                    log::info!(
-                        "[ALSA Direct] Setting hardware volume via '{}': {:.0}% (raw: {}/{})",
-                        name,
-                        volume * 100.0,
-                        target,
-                        max
-                    );
-
-                    // Set volume on all channels
-                    for channel in &[FrontLeft, FrontRight, FrontCenter, RearLeft, RearRight] {
-                        let _ = selem.set_playback_volume(*channel, target);
-                    }
+        let card = mixer_device(&self.device_id)
+            .ok_or_else(|| format!("No mixer for {}", self.device_id))?;
+        let mixer = Mixer::new(&card, false)
+            .map_err(|e| format!("Failed to open mixer for {}: {}", card, e))?;
+
+        // Common names first: "Master", "PCM", "Speaker", "Headphone"
+        let elements = Self::mixer_elements(&self.device_id)?;
+        let name = MIXER_CONTROL_NAMES
+            .iter()
+            .find(|name| elements.iter().any(|e| e == *name))
+            .map(|name| name.to_string())
+            .or_else(|| elements.first().cloned())
+            .ok_or_else(|| {
+                format!(
+                    "No volume control found for {}. DAC may not support hardware mixer.",
+                    self.device_id
+                )
+            })?;
 
-                    return Ok(());
-                }
-            }
-        }
+        let selem = mixer
+            .find_selem(&SelemId::new(&name, 0))
+            .ok_or_else(|| format!("Mixer control '{}' disappeared on {}", name, card))?;
+        let (min, max) = selem.get_playback_volume_range();
+        let target = min + ((max - min) as f32 * volume.clamp(0.0, 1.0)) as i64;
 
-        Err(format!(
-            "No volume control found for {}. DAC may not support hardware mixer.",
-            self.device_id
-        ))
+        log::info!(
+            "[ALSA Direct] Setting hardware volume via '{}': {:.0}% (raw: {}/{})",
+            name,
+            volume * 100.0,
+            target,
+            max
+        );
+
+        // Set volume on all channels
+        selem
+            .set_playback_volume_all(target)
+            .map_err(|e| format!("Failed to set '{}' volume: {}", name, e))
+    }
+
+    /// Names of the playback-volume mixer controls on `device_id`'s card, in
+    /// the order ALSA lists them. Empty when the card has none (typical for
+    /// DACs that only take volume from their own knob).
+    pub fn mixer_elements(device_id: &str) -> Result<Vec<String>, String> {
+        use alsa::mixer::{Mixer, Selem};
+
+        let card = mixer_device(device_id).ok_or_else(|| format!("No mixer for {}", device_id))?;
+        let mixer = Mixer::new(&card, false)
+            .map_err(|e| format!("Failed to open mixer for {}: {}", card, e))?;
+        Ok(mixer
+            .iter()
+            .filter_map(Selem::new)
+            .filter(|selem| selem.has_playback_volume())
+            .filter_map(|selem| selem.get_id().get_name().ok().map(str::to_string))
+            .collect())
     }
 
     /// Check if device is a bit-perfect hardware device
@@ -965,8 +1021,57 @@ impl AlsaDirectStream {
         2
     }
 
+    pub fn mixer_elements(_device_id: &str) -> Result<Vec<String>, String> {
+        Err("ALSA mixer is only available on Linux".to_string())
+    }
+
     /// Check if device is a bit-perfect hardware device (always false on non-Linux)
     pub fn is_hw_device(_device_id: &str) -> bool {
         false
     }
 }
+
+/// Playback-volume mixer controls available for `device_id` (what the
+/// hardware-volume setting can drive). Empty when there are none or the card
+/// can't be opened; failures are logged, not returned.
+pub fn alsa_mixer_elements(device_id: &str) -> Vec<String> {
+    AlsaDirectStream::mixer_elements(device_id).unwrap_or_else(|e| {
+        log::info!("[ALSA Direct] No mixer controls for {}: {}", device_id, e);
+        Vec::new()
+    })
+}
+
+/// Mixer control names tried first, most likely to be the DAC's main volume.
+#[cfg(target_os = "linux")]
+const MIXER_CONTROL_NAMES: [&str; 5] = ["Master", "PCM", "Speaker", "Headphone", "Digital"];
+
+/// The control device (`hw:<card>`) for a PCM id: mixers live on the card,
+/// so `hw:1,0`, `plughw:1,0` and `front:CARD=Zen,DEV=0` map to `hw:1` /
+/// `hw:Zen`. None for ids without a card (`default`, `pulse`, ...).
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn mixer_device(device_id: &str) -> Option<String> {
+    let (_, rest) = device_id.split_once(':')?;
+    let card = match rest.strip_prefix("CARD=") {
+        Some(named) => named.split(',').next()?,
+        None => rest.split(',').next()?,
+    };
+    (!card.is_empty()).then(|| format!("hw:{}", card))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixer_device_is_the_card_of_the_pcm() {
+        assert_eq!(mixer_device("hw:1,0"), Some("hw:1".to_string()));
+        assert_eq!(mixer_device("plughw:2,3"), Some("hw:2".to_string()));
+        assert_eq!(
+            mixer_device("front:CARD=Zen,DEV=0"),
+            Some("hw:Zen".to_string())
+        );
+        assert_eq!(mixer_device("hw:CARD=DAC"), Some("hw:DAC".to_string()));
+        assert_eq!(mixer_device("default"), None);
+        assert_eq!(mixer_device("hw:"), None);
+    }
+}
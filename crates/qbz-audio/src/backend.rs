@@ -142,6 +142,156 @@ pub struct BackendConfig {
     /// When true, skip `pactl set-default-sink` on stream creation.
     /// Preserves external routing (JACK, qjackctl, Reaper).
     pub skip_sink_switch: bool,
+
+    /// Output sample format picked by [`negotiate_format`] (None = not
+    /// negotiated; backends keep their float default)
+    pub negotiated_format: Option<SampleFormat>,
+}
+
+/// Device sample format, listed in bit-perfect preference order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SampleFormat {
+    Int32,
+    Int24,
+    Float32,
+    Int16,
+}
+
+impl SampleFormat {
+    /// Negotiation order: integer formats first so a 24-bit DAC gets
+    /// integer samples, float before 16-bit so hi-res content isn't truncated.
+    pub const PREFERENCE: [SampleFormat; 4] = [
+        SampleFormat::Int32,
+        SampleFormat::Int24,
+        SampleFormat::Float32,
+        SampleFormat::Int16,
+    ];
+
+    /// Integer resolution the format carries losslessly (f32 has a 24-bit
+    /// mantissa)
+    pub fn resolution_bits(self) -> u8 {
+        match self {
+            SampleFormat::Int32 => 32,
+            SampleFormat::Int24 | SampleFormat::Float32 => 24,
+            SampleFormat::Int16 => 16,
+        }
+    }
+
+    /// CPAL equivalent. Packed 24-bit has none: CPAL streams fall back to
+    /// float for it.
+    pub fn to_cpal(self) -> Option<rodio::cpal::SampleFormat> {
+        match self {
+            SampleFormat::Int32 => Some(rodio::cpal::SampleFormat::I32),
+            SampleFormat::Int24 => None,
+            SampleFormat::Float32 => Some(rodio::cpal::SampleFormat::F32),
+            SampleFormat::Int16 => Some(rodio::cpal::SampleFormat::I16),
+        }
+    }
+
+    /// Lowercase name used in the negotiation trace
+    pub fn label(self) -> &'static str {
+        match self {
+            SampleFormat::Int32 => "int32",
+            SampleFormat::Int24 => "int24",
+            SampleFormat::Float32 => "float32",
+            SampleFormat::Int16 => "int16",
+        }
+    }
+}
+
+/// One format/rate/channel combination a device accepts, as read from its
+/// PipeWire node (`pipewire_backend::query_node_formats`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupportedFormat {
+    pub sample_format: SampleFormat,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Highest-ranked format in `supported`, or None if it's empty. Logs when
+/// the winner can't hold `preferred_bit_depth` bits losslessly.
+pub fn pick_format(supported: &[SampleFormat], preferred_bit_depth: u8) -> Option<SampleFormat> {
+    pick_format_traced(supported, preferred_bit_depth, &mut Vec::new())
+}
+
+/// [`pick_format`], appending one `"Tried <format>: accepted|rejected"` line
+/// per format tried to `trace` (stops at the first accepted one).
+pub fn pick_format_traced(
+    supported: &[SampleFormat],
+    preferred_bit_depth: u8,
+    trace: &mut Vec<String>,
+) -> Option<SampleFormat> {
+    let picked = SampleFormat::PREFERENCE.into_iter().find(|f| {
+        let accepted = supported.contains(f);
+        trace.push(format!(
+            "Tried {}: {}",
+            f.label(),
+            if accepted { "accepted" } else { "rejected" }
+        ));
+        accepted
+    })?;
+    if picked.resolution_bits() < preferred_bit_depth {
+        log::warn!(
+            "[Backend] Best device format {:?} holds {} bits; {}-bit content will be truncated",
+            picked,
+            picked.resolution_bits(),
+            preferred_bit_depth
+        );
+        trace.push(format!(
+            "{} holds {} bits: {}-bit content will be truncated",
+            picked.label(),
+            picked.resolution_bits(),
+            preferred_bit_depth
+        ));
+    }
+    Some(picked)
+}
+
+/// Pick the best sample format a device accepts (int32 → int24 → float32 →
+/// int16). Only ALSA card ids are probed; shared servers (PipeWire, Pulse,
+/// the system default) mix in float regardless of what we ask for, so they
+/// negotiate to `Float32`.
+pub fn negotiate_format(device_id: &str, preferred_bit_depth: u8) -> BackendResult<SampleFormat> {
+    negotiate_format_traced(device_id, preferred_bit_depth, &mut Vec::new())
+}
+
+/// [`negotiate_format`], recording which formats were tried and whether the
+/// device accepted them in `trace` — the report behind "why does my 24-bit
+/// DAC play at 16-bit".
+pub fn negotiate_format_traced(
+    device_id: &str,
+    preferred_bit_depth: u8,
+    trace: &mut Vec<String>,
+) -> BackendResult<SampleFormat> {
+    #[cfg(target_os = "linux")]
+    if crate::alsa_backend::is_device_present(device_id).is_some() {
+        let supported = match crate::AlsaDirectStream::probe_formats(device_id) {
+            Ok(supported) => supported,
+            Err(e) => {
+                trace.push(format!("Probing {} failed: {}", device_id, e));
+                return Err(e);
+            }
+        };
+        let Some(picked) = pick_format_traced(&supported, preferred_bit_depth, trace) else {
+            return Err(format!(
+                "Device '{}' accepts none of int32/int24/float32/int16",
+                device_id
+            ));
+        };
+        log::info!(
+            "[Backend] Negotiated {:?} for {} (device supports {:?})",
+            picked,
+            device_id,
+            supported
+        );
+        return Ok(picked);
+    }
+    let _ = preferred_bit_depth;
+    trace.push(format!(
+        "{} is not an ALSA card: shared server mixes in float32",
+        device_id
+    ));
+    Ok(SampleFormat::Float32)
 }
 
 /// Result type for backend operations
@@ -228,10 +378,30 @@ pub enum BitPerfectMode {
     DirectHardware,
     /// Plugin hardware fallback (plughw:), bit-perfect with format conversion only
     PluginFallback,
+    /// DSD over PCM on a direct hw: stream (S32_LE carrier, 0x05/0xFA markers)
+    DoP,
     /// Not using bit-perfect path (pcm, pipewire, pulse)
     Disabled,
 }
 
+/// Software DSP stage applied in the render path before samples reach the
+/// device (e.g. the parametric EQ). Plugins work on one interleaved frame at
+/// a time, in place.
+///
+/// Any plugin in the chain breaks bit-perfect output by definition. An empty
+/// chain is a true bypass: samples pass through untouched.
+pub trait DspPlugin: Send {
+    /// Short identifier for logs and diagnostics
+    fn name(&self) -> &'static str;
+
+    /// (Re)configure for the stream format. Called before the first frame and
+    /// again whenever the sample rate or channel count changes.
+    fn prepare(&mut self, sample_rate: u32, channels: u16);
+
+    /// Process one interleaved frame (`channels` samples) in place
+    fn process_frame(&mut self, frame: &mut [f32]);
+}
+
 /// Audio backend trait
 ///
 /// All audio backends must implement this trait to provide
@@ -259,6 +429,17 @@ pub trait AudioBackend: Send + Sync {
         self.create_output_stream(config).map(|sink| (sink, None))
     }
 
+    /// Install the software DSP chain applied to every frame before it is
+    /// written to the device. An empty Vec restores the bit-perfect bypass.
+    ///
+    /// Every PCM path (rodio mixer, ALSA Direct, JACK feeder) renders through
+    /// the player's shared source pipeline, so the default installs the chain
+    /// there. Backends with their own render callback may override this.
+    fn set_dsp_chain(&self, plugins: Vec<Box<dyn DspPlugin>>) -> BackendResult<()> {
+        crate::dsp::global_chain().replace(plugins);
+        Ok(())
+    }
+
     /// Check if this backend is available on the current system
     fn is_available(&self) -> bool;
 
@@ -296,11 +477,13 @@ impl BackendManager {
                 backends.push(AudioBackendType::Pulse);
             }
 
-            // JACK (#263 Tier 3): offered now that the player wiring is in place
+            // JACK (#263 Tier 3): the player wiring is in place
             // (StreamType::Jack + dispatch + PlaybackEngine::Jack feeder/resampler).
-            // The binary links libjack, so reaching here means it is present;
-            // opening the client fails gracefully if no JACK server is reachable.
-            backends.push(AudioBackendType::Jack);
+            // Only offered while a JACK (or pipewire-jack) server answers, so the
+            // picker never lists a backend that cannot open.
+            if Self::is_jack_available() {
+                backends.push(AudioBackendType::Jack);
+            }
         }
 
         #[cfg(not(target_os = "linux"))]
@@ -311,6 +494,13 @@ impl BackendManager {
         backends
     }
 
+    /// Re-enumerate a backend's devices from scratch (after a hot-plug
+    /// change). Backends hold no device cache, so a fresh instance is a
+    /// fresh view of the hardware.
+    pub fn refresh_devices(backend_type: AudioBackendType) -> BackendResult<Vec<AudioDevice>> {
+        Self::create_backend(backend_type)?.enumerate_devices()
+    }
+
     /// Create a backend instance
     pub fn create_backend(backend_type: AudioBackendType) -> BackendResult<Box<dyn AudioBackend>> {
         // Install the custom ALSA error handler once per process, before any
@@ -409,6 +599,14 @@ impl BackendManager {
             .unwrap_or(false)
     }
 
+    #[cfg(target_os = "linux")]
+    fn is_jack_available() -> bool {
+        // Probe with a throwaway client. NO_START_SERVER so listing backends
+        // never spawns jackd as a side effect; the client is dropped (and
+        // deregistered) immediately.
+        jack::Client::new("qbz-probe", jack::ClientOptions::NO_START_SERVER).is_ok()
+    }
+
     #[cfg(target_os = "linux")]
     fn is_pulse_available() -> bool {
         // Check if PulseAudio is running
@@ -930,3 +1128,50 @@ impl CpalDefaultBackend {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_format_prefers_integer_over_float() {
+        use SampleFormat::*;
+        assert_eq!(pick_format(&[Float32, Int16, Int32], 24), Some(Int32));
+        assert_eq!(pick_format(&[Int16, Float32, Int24], 24), Some(Int24));
+        assert_eq!(pick_format(&[Int16, Float32], 24), Some(Float32));
+        assert_eq!(pick_format(&[Int16], 24), Some(Int16));
+        assert_eq!(pick_format(&[], 24), None);
+    }
+
+    #[test]
+    fn pick_format_traces_each_format_tried() {
+        use SampleFormat::*;
+        let mut trace = Vec::new();
+        assert_eq!(
+            pick_format_traced(&[Int16, Float32], 24, &mut trace),
+            Some(Float32)
+        );
+        assert_eq!(
+            trace,
+            [
+                "Tried int32: rejected",
+                "Tried int24: rejected",
+                "Tried float32: accepted"
+            ]
+        );
+
+        trace.clear();
+        assert_eq!(pick_format_traced(&[Int16], 24, &mut trace), Some(Int16));
+        assert_eq!(trace.len(), 5);
+        assert_eq!(trace[3], "Tried int16: accepted");
+        assert!(trace[4].contains("truncated"), "{trace:?}");
+    }
+
+    #[test]
+    fn shared_server_ids_negotiate_float() {
+        assert_eq!(
+            negotiate_format("alsa_output.usb-Topping_E30-00.analog-stereo", 24),
+            Ok(SampleFormat::Float32)
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Track-to-track crossfade for the gapless queue.
+//!
+//! Crossfade rides the existing gapless pre-queue: the player wraps the
+//! current track in a [`CrossfadeOut`] and, when the next track is handed
+//! over, offers its (pre-DSP) source to the current track's
+//! [`CrossfadeSlot`]. During the last `fade` of the current track both
+//! decoders run: the current track ramps linearly 1 → 0 while the next ramps
+//! 0 → 1, mixed sample by sample. When the current track's samples run out
+//! the engine moves on to the [`CrossfadeTail`] that was queued behind it,
+//! which keeps pulling from the SAME next-track decoder — so the next track
+//! continues exactly where the overlap left off.
+//!
+//! The hand-off may ask for a shorter overlap than the window the current
+//! track was armed with ([`CrossfadeSlot::offer_with_fade`]): the player arms
+//! the longer of the normal and the repeat-all wrap crossfade, and only learns
+//! which one applies when the next track arrives. A zero-length offer is a
+//! plain gapless cut.
+//!
+//! The next track's decoder crosses threads once: the player parks it in the
+//! slot and raises an atomic flag, and the audio thread takes it into local
+//! ownership when the overlap starts. Per-sample work only reads atomics.
+//!
+//! Both tracks must share sample rate and channel count (the gapless path
+//! already enforces this). A source with unknown total duration (streaming)
+//! cannot schedule its fade window and passes through untouched.
+
+use std::num::NonZero;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rodio::Source;
+
+type BoxedSource = Box<dyn Source<Item = f32> + Send>;
+
+/// Shared hand-off point between the current track and the next one.
+#[derive(Clone)]
+pub struct CrossfadeSlot {
+    /// The next track's decoder while nobody is pulling from it: parked here
+    /// by the offer, and again by the current track when it runs out mid-mix
+    next: Arc<Mutex<Option<BoxedSource>>>,
+    /// Set (after `next` is filled) once the next track is offered
+    offered: Arc<AtomicBool>,
+    /// Longest overlap the offered track allows, in interleaved samples
+    /// (`u64::MAX` = the whole armed window).
+    fade_limit: Arc<AtomicU64>,
+    /// Interleaved samples actually mixed (0 until the ramp starts)
+    overlap_samples: Arc<AtomicU64>,
+    channels: NonZero<u16>,
+    sample_rate: NonZero<u32>,
+}
+
+impl CrossfadeSlot {
+    /// Hand the next track's source to the current track. The overlap starts
+    /// as soon as the current track is inside its fade window.
+    pub fn offer(&self, source: BoxedSource) {
+        self.park(source, u64::MAX);
+    }
+
+    /// [`Self::offer`], overlapping for at most `fade` (capped by the window
+    /// the current track was armed with). `Duration::ZERO` = gapless cut.
+    pub fn offer_with_fade(&self, source: BoxedSource, fade: Duration) {
+        let frames = (fade.as_secs_f64() * self.sample_rate.get() as f64) as u64;
+        self.park(source, frames * self.channels.get() as u64);
+    }
+
+    fn park(&self, source: BoxedSource, fade_limit: u64) {
+        self.fade_limit.store(fade_limit, Ordering::Relaxed);
+        self.overlap_samples.store(0, Ordering::Relaxed);
+        if let Ok(mut next) = self.next.lock() {
+            *next = Some(source);
+        }
+        self.offered.store(true, Ordering::Release);
+    }
+
+    /// The source to queue behind the current track: continues the next
+    /// track after the overlap.
+    pub fn tail(&self) -> CrossfadeTail {
+        CrossfadeTail {
+            next: self.next.clone(),
+            source: None,
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+        }
+    }
+
+    /// How much of the next track the overlap actually played. Shorter than
+    /// the configured fade when the next track arrived late or the offer
+    /// asked for less; zero for a gapless cut.
+    pub fn overlap(&self) -> Duration {
+        let frames = self.overlap_samples.load(Ordering::Relaxed) / self.channels.get() as u64;
+        Duration::from_secs_f64(frames as f64 / self.sample_rate.get() as f64)
+    }
+
+    #[inline]
+    fn fade_limit(&self) -> u64 {
+        self.fade_limit.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn is_offered(&self) -> bool {
+        self.offered.load(Ordering::Acquire)
+    }
+
+    fn take(&self) -> Option<BoxedSource> {
+        self.next.lock().ok()?.take()
+    }
+
+    fn put_back(&self, source: BoxedSource) {
+        if let Ok(mut next) = self.next.lock() {
+            *next = Some(source);
+        }
+    }
+}
+
+/// Wraps the current track and mixes the next track into its last `fade`.
+pub struct CrossfadeOut<S>
+where
+    S: Source<Item = f32>,
+{
+    inner: S,
+    slot: CrossfadeSlot,
+    /// The next track's decoder, owned locally for the length of the mix
+    incoming: Option<BoxedSource>,
+    /// Total interleaved samples of the inner source (None = unknown)
+    total_samples: Option<u64>,
+    consumed: u64,
+    /// Fade window in interleaved samples
+    fade_samples: u64,
+    /// Length of the active mix ramp (set when the overlap starts; shorter
+    /// than `fade_samples` when the next track arrived late)
+    mix_len: Option<u64>,
+}
+
+impl<S> CrossfadeOut<S>
+where
+    S: Source<Item = f32>,
+{
+    pub fn new(source: S, fade: Duration) -> Self {
+        let channels = source.channels();
+        let sample_rate = source.sample_rate();
+        let samples_per_sec = sample_rate.get() as u64 * channels.get() as u64;
+        let total_samples = source
+            .total_duration()
+            .map(|d| (d.as_secs_f64() * samples_per_sec as f64) as u64);
+        // Whole frames only, and never more than half the track so a short
+        // track still has an audible body.
+        let frames = (fade.as_secs_f64() * sample_rate.get() as f64) as u64;
+        let mut fade_samples = frames * channels.get() as u64;
+        if let Some(total) = total_samples {
+            fade_samples =
+                fade_samples.min(total / 2 / channels.get() as u64 * channels.get() as u64);
+        }
+        Self {
+            inner: source,
+            slot: CrossfadeSlot {
+                next: Arc::new(Mutex::new(None)),
+                offered: Arc::new(AtomicBool::new(false)),
+                fade_limit: Arc::new(AtomicU64::new(u64::MAX)),
+                overlap_samples: Arc::new(AtomicU64::new(0)),
+                channels,
+                sample_rate,
+            },
+            incoming: None,
+            total_samples,
+            consumed: 0,
+            fade_samples,
+            mix_len: None,
+        }
+    }
+
+    /// Handle the player keeps to offer the next track into.
+    pub fn slot(&self) -> CrossfadeSlot {
+        self.slot.clone()
+    }
+
+    /// Park the half-drained next track back in the slot for the tail.
+    fn release(&mut self) {
+        if let Some(source) = self.incoming.take() {
+            self.slot.put_back(source);
+        }
+    }
+}
+
+impl<S> Drop for CrossfadeOut<S>
+where
+    S: Source<Item = f32>,
+{
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+impl<S> Iterator for CrossfadeOut<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(sample) = self.inner.next() else {
+            self.release();
+            return None;
+        };
+        let Some(total) = self.total_samples else {
+            return Some(sample);
+        };
+        let remaining = total.saturating_sub(self.consumed);
+        self.consumed += 1;
+
+        if self.fade_samples == 0 || remaining > self.fade_samples {
+            return Some(sample);
+        }
+
+        // Start the ramp on a frame boundary once the next track is offered
+        let mix_len = match self.mix_len {
+            Some(len) => len,
+            None => {
+                let at_frame_start = (self.consumed - 1) % self.slot.channels.get() as u64 == 0;
+                if !at_frame_start || !self.slot.is_offered() || remaining > self.slot.fade_limit()
+                {
+                    return Some(sample);
+                }
+                self.incoming = self.slot.take();
+                self.slot
+                    .overlap_samples
+                    .store(remaining, Ordering::Relaxed);
+                self.mix_len = Some(remaining);
+                remaining
+            }
+        };
+
+        let gain_in = 1.0 - (remaining as f32 / mix_len as f32);
+        let incoming = self.incoming.as_mut().and_then(|s| s.next()).unwrap_or(0.0);
+        Some(sample * (1.0 - gain_in) + incoming * gain_in)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> Source for CrossfadeOut<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        self.inner.current_span_len()
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+/// The next track after the overlap: drains the decoder the current track
+/// was mixing from.
+pub struct CrossfadeTail {
+    next: Arc<Mutex<Option<BoxedSource>>>,
+    /// Taken out of `next` on the first pull
+    source: Option<BoxedSource>,
+    channels: NonZero<u16>,
+    sample_rate: NonZero<u32>,
+}
+
+impl Iterator for CrossfadeTail {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.source.is_none() {
+            self.source = self.next.lock().ok()?.take();
+        }
+        self.source.as_mut()?.next()
+    }
+}
+
+impl Source for CrossfadeTail {
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.channels
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.sample_rate
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    fn mono(samples: Vec<f32>) -> SamplesBuffer {
+        // 10 Hz so one sample = 100ms and the fade maths stay readable
+        SamplesBuffer::new(
+            NonZero::new(1u16).unwrap(),
+            NonZero::new(10u32).unwrap(),
+            samples,
+        )
+    }
+
+    #[test]
+    fn without_next_track_passes_through() {
+        let out: Vec<f32> =
+            CrossfadeOut::new(mono(vec![1.0; 10]), Duration::from_millis(400)).collect();
+        assert_eq!(out, vec![1.0; 10]);
+    }
+
+    #[test]
+    fn mixes_linearly_and_tail_continues_next_track() {
+        let current = CrossfadeOut::new(mono(vec![1.0; 10]), Duration::from_millis(400));
+        let slot = current.slot();
+        slot.offer(Box::new(mono(vec![-1.0; 8])));
+
+        let out: Vec<f32> = current.collect();
+        // Last 4 samples ramp: remaining 4,3,2,1 of a 4-sample window
+        let expected_tail = [1.0, 0.5, 0.0, -0.5];
+        for (got, want) in out[6..].iter().zip(expected_tail) {
+            assert!((got - want).abs() < 1e-6, "{got} vs {want}");
+        }
+
+        // The next track already gave 4 samples to the overlap
+        let rest: Vec<f32> = slot.tail().collect();
+        assert_eq!(rest.len(), 4);
+        assert_eq!(slot.overlap(), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn late_next_track_gets_a_shorter_ramp_without_jump() {
+        let mut current = CrossfadeOut::new(mono(vec![1.0; 10]), Duration::from_millis(400));
+        let slot = current.slot();
+        // Consume into the fade window before the next track arrives
+        for _ in 0..8 {
+            assert_eq!(current.next(), Some(1.0));
+        }
+        slot.offer(Box::new(mono(vec![0.0; 8])));
+        let rest: Vec<f32> = current.collect();
+        assert_eq!(rest, vec![1.0, 0.5]);
+        // The timer offset follows the shortened ramp, not the armed window
+        assert_eq!(slot.overlap(), Duration::from_millis(200));
+        assert_eq!(slot.tail().count(), 6);
+    }
+
+    #[test]
+    fn offered_fade_shortens_or_cancels_the_overlap() {
+        let current = CrossfadeOut::new(mono(vec![1.0; 10]), Duration::from_millis(400));
+        let slot = current.slot();
+        slot.offer_with_fade(Box::new(mono(vec![0.0; 8])), Duration::from_millis(200));
+        let out: Vec<f32> = current.collect();
+        assert_eq!(out[6..], [1.0, 1.0, 1.0, 0.5]);
+
+        let current = CrossfadeOut::new(mono(vec![1.0; 10]), Duration::from_millis(400));
+        let slot = current.slot();
+        slot.offer_with_fade(Box::new(mono(vec![0.0; 8])), Duration::ZERO);
+        let out: Vec<f32> = current.collect();
+        assert_eq!(out, vec![1.0; 10]);
+        // Nothing was mixed, so the whole next track follows.
+        assert_eq!(slot.tail().count(), 8);
+        assert_eq!(slot.overlap(), Duration::ZERO);
+    }
+}
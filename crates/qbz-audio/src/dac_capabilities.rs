@@ -5,9 +5,15 @@
 //! Tauri command. The actual detection already lived in this crate
 //! (`PipeWireBackend::get_sink_supported_rates`, `get_device_supported_rates`);
 //! this module just assembles the DTO frontend-agnostically. Read-only.
+//!
+//! The PipeWire node's own `EnumFormat` params
+//! (`pipewire_backend::query_node_formats`) are consulted first: they give
+//! real formats and channel counts, and the rate range the device accepts.
 
 use serde::{Deserialize, Serialize};
 
+use crate::backend::{pick_format, SampleFormat, SupportedFormat};
+
 /// DAC capability descriptor surfaced to the wizard.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DacCapabilities {
@@ -24,6 +30,10 @@ pub struct DacCapabilities {
     /// the hardware. `serde(default)` keeps old serialized payloads readable.
     #[serde(default)]
     pub detected: bool,
+    /// Best output format for 24-bit content: the top-ranked format the node
+    /// advertises, else what [`crate::negotiate_format`] settles on.
+    #[serde(default)]
+    pub best_format: Option<SampleFormat>,
 }
 
 /// The common rate set used when real detection fails (continuous-range DACs,
@@ -37,26 +47,65 @@ fn nominal_formats() -> Vec<String> {
     vec!["S16LE".to_string(), "S24LE".to_string(), "F32LE".to_string()]
 }
 
-/// Pure assembly (testable): combine detected rates + description into the DTO,
-/// falling back to the common rate set when detection yielded nothing.
+/// Display name of a sample format, in the `S24LE` style of the nominal list.
+fn format_name(format: SampleFormat) -> &'static str {
+    match format {
+        SampleFormat::Int32 => "S32LE",
+        SampleFormat::Int24 => "S24LE",
+        SampleFormat::Float32 => "F32LE",
+        SampleFormat::Int16 => "S16LE",
+    }
+}
+
+/// Pure assembly (testable): combine the node's advertised formats, detected
+/// rates + description into the DTO, falling back to the common rate set when
+/// detection yielded nothing and to the nominal formats when the node
+/// advertised none.
 fn assemble(
     node_name: &str,
+    node_formats: &[SupportedFormat],
     detected_rates: Option<Vec<u32>>,
     description: Option<String>,
 ) -> DacCapabilities {
+    let mut node_rates: Vec<u32> = node_formats.iter().map(|f| f.sample_rate).collect();
+    node_rates.sort_unstable();
+    node_rates.dedup();
+    // A lone node rate is just the currently negotiated graph rate, not a
+    // capability — only a real list/range wins over the /proc probe.
+    let node_rates = (node_rates.len() > 1).then_some(node_rates);
     // Computed BEFORE the fallback collapses into `sample_rates`, so the DTO
     // can tell a real ceiling from the assumed common set (#638 F26).
-    let rates = detected_rates.filter(|r| !r.is_empty());
+    let rates = node_rates.or(detected_rates).filter(|r| !r.is_empty());
     let detected = rates.is_some();
     let sample_rates = rates.unwrap_or_else(|| FALLBACK_RATES.to_vec());
+
+    let sample_formats: Vec<SampleFormat> = SampleFormat::PREFERENCE
+        .into_iter()
+        .filter(|f| node_formats.iter().any(|n| n.sample_format == *f))
+        .collect();
+    let formats = if sample_formats.is_empty() {
+        nominal_formats()
+    } else {
+        sample_formats
+            .iter()
+            .map(|f| format_name(*f).to_string())
+            .collect()
+    };
+    let channels = node_formats
+        .iter()
+        .map(|f| f.channels as u32)
+        .max()
+        .or(Some(2));
+
     DacCapabilities {
         node_name: node_name.to_string(),
         sample_rates,
-        formats: nominal_formats(),
-        channels: Some(2),
+        formats,
+        channels,
         description,
         error: None,
         detected,
+        best_format: pick_format(&sample_formats, 24),
     }
 }
 
@@ -75,15 +124,26 @@ pub fn query_dac_capabilities(node_name: &str) -> DacCapabilities {
     })
     .map(|d| d.description.unwrap_or(d.name));
 
-    // Real sample rates: PipeWire sink -> ALSA card -> /proc/asound, with an
-    // ALSA-direct fallback. Hardware-only; non-Linux gets the fallback set.
+    // Real capabilities: the node's EnumFormat params first; then rates via
+    // PipeWire sink -> ALSA card -> /proc/asound, with an ALSA-direct
+    // fallback. Hardware-only; non-Linux gets the fallback set.
+    #[cfg(target_os = "linux")]
+    let node_formats = crate::pipewire_backend::query_node_formats(node_name)
+        .map_err(|e| log::debug!("[DacCapabilities] node formats unavailable: {}", e))
+        .unwrap_or_default();
+    #[cfg(not(target_os = "linux"))]
+    let node_formats: Vec<SupportedFormat> = Vec::new();
     #[cfg(target_os = "linux")]
     let detected = crate::pipewire_backend::PipeWireBackend::get_sink_supported_rates(node_name)
         .or_else(|| crate::alsa_backend::get_device_supported_rates(node_name));
     #[cfg(not(target_os = "linux"))]
     let detected: Option<Vec<u32>> = None;
 
-    assemble(node_name, detected, description)
+    let mut caps = assemble(node_name, &node_formats, detected, description);
+    if caps.best_format.is_none() {
+        caps.best_format = crate::backend::negotiate_format(node_name, 24).ok();
+    }
+    caps
 }
 
 #[cfg(test)]
@@ -92,7 +152,12 @@ mod tests {
 
     #[test]
     fn uses_detected_rates_when_present() {
-        let caps = assemble("alsa_output.usb-x", Some(vec![44100, 96000, 192000]), Some("My DAC".into()));
+        let caps = assemble(
+            "alsa_output.usb-x",
+            &[],
+            Some(vec![44100, 96000, 192000]),
+            Some("My DAC".into()),
+        );
         assert_eq!(caps.sample_rates, vec![44100, 96000, 192000]);
         assert_eq!(caps.description.as_deref(), Some("My DAC"));
         assert_eq!(caps.channels, Some(2));
@@ -102,17 +167,41 @@ mod tests {
 
     #[test]
     fn falls_back_when_detection_empty_or_missing() {
-        let none = assemble("x", None, None);
+        let none = assemble("x", &[], None, None);
         assert_eq!(none.sample_rates, FALLBACK_RATES.to_vec());
         assert!(!none.detected);
-        let empty = assemble("x", Some(vec![]), None);
+        let empty = assemble("x", &[], Some(vec![]), None);
         assert_eq!(empty.sample_rates, FALLBACK_RATES.to_vec());
         assert!(!empty.detected);
     }
 
     #[test]
     fn formats_are_nominal_not_empty() {
-        let caps = assemble("x", None, None);
+        let caps = assemble("x", &[], None, None);
         assert!(caps.formats.contains(&"S24LE".to_string()));
+        assert_eq!(caps.best_format, None);
+    }
+
+    #[test]
+    fn node_formats_win_over_probe_and_nominal_set() {
+        let node = |sample_format, sample_rate| SupportedFormat {
+            sample_format,
+            sample_rate,
+            channels: 2,
+        };
+        let formats = [
+            node(SampleFormat::Int16, 44100),
+            node(SampleFormat::Int24, 44100),
+            node(SampleFormat::Int24, 96000),
+        ];
+        let caps = assemble("x", &formats, Some(vec![48000]), None);
+        assert_eq!(caps.sample_rates, vec![44100, 96000]);
+        assert!(caps.detected);
+        assert_eq!(caps.formats, vec!["S24LE".to_string(), "S16LE".to_string()]);
+        assert_eq!(caps.best_format, Some(SampleFormat::Int24));
+
+        // A single advertised rate is only the current graph rate.
+        let caps = assemble("x", &formats[..2], Some(vec![48000, 96000]), None);
+        assert_eq!(caps.sample_rates, vec![48000, 96000]);
     }
 }
@@ -6,6 +6,10 @@
 //!
 //! Works for both rodio (PipeWire/ALSA via CPAL) and ALSA Direct paths
 //! via a transparent Source wrapper.
+//!
+//! Also home to the output-latency readout ([`AudioDiagnostic::measure_latency`]),
+//! which asks the backend what it is actually running with rather than what
+//! was requested.
 
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
@@ -14,6 +18,8 @@ use std::time::Duration;
 use rodio::Source;
 use serde::Serialize;
 
+use crate::backend::{AudioBackend, AudioBackendType};
+
 // ---------------------------------------------------------------------------
 // Shared diagnostic state (atomics — safe to clone across threads)
 // ---------------------------------------------------------------------------
@@ -135,6 +141,60 @@ pub struct BitDepthResult {
     pub effective_bits: u32,
 }
 
+// ---------------------------------------------------------------------------
+// Output latency
+// ---------------------------------------------------------------------------
+
+/// Output latency of the open stream, in milliseconds. `device_latency_ms`
+/// is what the hardware side buffers (ALSA period, or PipeWire's ALSA
+/// period plus headroom); `software_buffer_ms` is what sits queued in front
+/// of it (the rest of the ALSA ring, or the PipeWire graph quantum).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyInfo {
+    pub device_latency_ms: f32,
+    pub software_buffer_ms: f32,
+    pub total_latency_ms: f32,
+}
+
+impl LatencyInfo {
+    pub fn new(device_latency_ms: f32, software_buffer_ms: f32) -> Self {
+        Self {
+            device_latency_ms,
+            software_buffer_ms,
+            total_latency_ms: device_latency_ms + software_buffer_ms,
+        }
+    }
+
+    /// `frames` at `sample_rate`, in milliseconds.
+    pub fn frames_to_ms(frames: u64, sample_rate: u32) -> f32 {
+        if sample_rate == 0 {
+            return 0.0;
+        }
+        frames as f32 * 1000.0 / sample_rate as f32
+    }
+}
+
+impl AudioDiagnostic {
+    /// Read the latency `device_id` is running with on `backend`. PipeWire
+    /// reports it through the sink node (`pw-dump`); ALSA through the open
+    /// PCM's `hw_params` in `/proc/asound`, so the device must be playing.
+    /// Other backends don't expose it.
+    pub fn measure_latency(
+        backend: &dyn AudioBackend,
+        device_id: Option<&str>,
+    ) -> Result<LatencyInfo, String> {
+        let device_id = device_id.ok_or_else(|| "No output device selected".to_string())?;
+        match backend.backend_type() {
+            #[cfg(target_os = "linux")]
+            AudioBackendType::PipeWire => crate::pipewire_backend::query_node_latency(device_id),
+            #[cfg(target_os = "linux")]
+            AudioBackendType::Alsa => crate::alsa_backend::query_pcm_latency(device_id),
+            other => Err(format!("{:?} backend does not report latency", other)),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Source wrapper — transparent tap for bit-depth capture
 // ---------------------------------------------------------------------------
@@ -0,0 +1,259 @@
+//! Software DSP chain for the render path.
+//!
+//! The chain is a process-wide slot of [`DspPlugin`]s shared by every stream
+//! the player opens. [`DspSource`] wraps a decoded source and runs each
+//! interleaved frame through the chain before it reaches the device.
+//!
+//! When the chain is empty the wrapper is a pure pass-through (one relaxed
+//! atomic load per frame, no lock, no sample modification), so the default
+//! pipeline stays bit-perfect.
+
+use std::num::NonZero;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::backend::DspPlugin;
+
+struct ChainInner {
+    plugins: Vec<Box<dyn DspPlugin>>,
+    /// Format the plugins were last prepared for (sample_rate, channels)
+    prepared: Option<(u32, u16)>,
+}
+
+/// Shared, live-replaceable DSP chain.
+#[derive(Clone)]
+pub struct DspChain {
+    inner: Arc<Mutex<ChainInner>>,
+    active: Arc<AtomicBool>,
+}
+
+impl DspChain {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ChainInner {
+                plugins: Vec::new(),
+                prepared: None,
+            })),
+            active: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Swap in a new set of plugins. Takes effect on the next frame of the
+    /// currently playing source.
+    pub fn replace(&self, plugins: Vec<Box<dyn DspPlugin>>) {
+        let names: Vec<&str> = plugins.iter().map(|p| p.name()).collect();
+        log::info!("[DSP] Installing chain: {:?}", names);
+        if let Ok(mut inner) = self.inner.lock() {
+            let active = !plugins.is_empty();
+            inner.plugins = plugins;
+            inner.prepared = None;
+            self.active.store(active, Ordering::Release);
+        }
+    }
+
+    /// Remove every plugin (bit-perfect bypass)
+    pub fn clear(&self) {
+        self.replace(Vec::new());
+    }
+
+    /// Whether any plugin is installed
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Run one interleaved frame through the chain.
+    ///
+    /// Uses `try_lock` so the audio thread never blocks on a settings change:
+    /// the single frame that races a `replace` passes through unprocessed.
+    #[inline]
+    pub fn process_frame(&self, frame: &mut [f32], sample_rate: u32, channels: u16) {
+        if !self.is_active() {
+            return;
+        }
+        let Ok(mut inner) = self.inner.try_lock() else {
+            return;
+        };
+        if inner.prepared != Some((sample_rate, channels)) {
+            for plugin in inner.plugins.iter_mut() {
+                plugin.prepare(sample_rate, channels);
+            }
+            inner.prepared = Some((sample_rate, channels));
+        }
+        for plugin in inner.plugins.iter_mut() {
+            plugin.process_frame(frame);
+        }
+    }
+}
+
+impl Default for DspChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static GLOBAL_CHAIN: OnceLock<DspChain> = OnceLock::new();
+
+/// Process-wide chain used by the player pipeline. Lazily initialized empty.
+pub fn global_chain() -> &'static DspChain {
+    GLOBAL_CHAIN.get_or_init(DspChain::new)
+}
+
+/// Wraps a Source and runs every frame through a [`DspChain`].
+pub struct DspSource<S>
+where
+    S: Source<Item = f32>,
+{
+    inner: S,
+    chain: DspChain,
+    frame: Vec<f32>,
+    pos: usize,
+}
+
+impl<S> DspSource<S>
+where
+    S: Source<Item = f32>,
+{
+    pub fn new(source: S, chain: DspChain) -> Self {
+        let channels = source.channels().get() as usize;
+        Self {
+            inner: source,
+            chain,
+            frame: Vec::with_capacity(channels),
+            pos: 0,
+        }
+    }
+
+    /// Pull the next frame from the inner source and process it.
+    /// Returns false when the inner source is exhausted.
+    fn refill(&mut self) -> bool {
+        let channels = self.inner.channels();
+        let sample_rate = self.inner.sample_rate();
+        self.frame.clear();
+        self.pos = 0;
+        for _ in 0..channels.get() {
+            match self.inner.next() {
+                Some(sample) => self.frame.push(sample),
+                None => break,
+            }
+        }
+        if self.frame.is_empty() {
+            return false;
+        }
+        // A truncated trailing frame is passed through as-is
+        if self.frame.len() == channels.get() as usize {
+            self.chain
+                .process_frame(&mut self.frame, sample_rate.get(), channels.get());
+        }
+        true
+    }
+}
+
+impl<S> Iterator for DspSource<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.frame.len() && !self.refill() {
+            return None;
+        }
+        let sample = self.frame[self.pos];
+        self.pos += 1;
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.frame.len() - self.pos;
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.map(|u| u.saturating_add(buffered)),
+        )
+    }
+}
+
+impl<S> Source for DspSource<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        self.inner
+            .current_span_len()
+            .map(|len| len + (self.frame.len() - self.pos))
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    struct Halve;
+
+    impl DspPlugin for Halve {
+        fn name(&self) -> &'static str {
+            "halve"
+        }
+        fn prepare(&mut self, _sample_rate: u32, _channels: u16) {}
+        fn process_frame(&mut self, frame: &mut [f32]) {
+            for sample in frame.iter_mut() {
+                *sample *= 0.5;
+            }
+        }
+    }
+
+    fn stereo(samples: Vec<f32>) -> SamplesBuffer {
+        SamplesBuffer::new(
+            NonZero::new(2u16).unwrap(),
+            NonZero::new(44100u32).unwrap(),
+            samples,
+        )
+    }
+
+    #[test]
+    fn empty_chain_is_bit_exact_bypass() {
+        let samples = vec![0.1, -0.2, 0.3, -0.4, 0.5];
+        let out: Vec<f32> = DspSource::new(stereo(samples.clone()), DspChain::new()).collect();
+        assert_eq!(out, samples);
+    }
+
+    #[test]
+    fn chain_processes_full_frames() {
+        let chain = DspChain::new();
+        chain.replace(vec![Box::new(Halve)]);
+        let out: Vec<f32> = DspSource::new(stereo(vec![1.0, -1.0, 0.5, 0.25]), chain).collect();
+        assert_eq!(out, vec![0.5, -0.5, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn clear_restores_bypass() {
+        let chain = DspChain::new();
+        chain.replace(vec![Box::new(Halve)]);
+        chain.clear();
+        assert!(!chain.is_active());
+        let out: Vec<f32> = DspSource::new(stereo(vec![1.0, 1.0]), chain).collect();
+        assert_eq!(out, vec![1.0, 1.0]);
+    }
+}
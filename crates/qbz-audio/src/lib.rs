@@ -39,39 +39,48 @@ pub mod pulse_backend;
 pub mod jack_backend;
 pub mod alsa_direct;
 pub mod coreaudio_direct;
+pub mod crossfade;
 pub mod dac_capabilities;
 pub mod dac_probe;
 pub mod health;
+pub mod limiter;
 pub mod analysis;
 pub mod analyzer_tap;
 pub mod device_filter;
 pub mod device_reservation;
 pub mod diagnostic;
+pub mod dsp;
 pub mod dynamic_amplify;
 pub mod loudness;
 pub mod loudness_analyzer;
 pub mod loudness_cache;
 pub mod network_throttle;
 pub mod output_sinks;
+pub mod parametric_eq;
+pub mod podcast;
 pub mod settings;
+pub mod silence;
 pub mod visualizer;
 
 // Re-export commonly used types
 #[cfg(target_os = "linux")]
 pub use alsa_backend::{
-    device_supports_sample_rate, get_device_supported_rates, normalize_device_id_to_stable,
-    resolve_stable_to_current_hw,
+    apply_ucm_profile, current_ucm_verb, device_supports_sample_rate, get_device_supported_rates,
+    get_ucm_verbs, is_device_present, normalize_device_id_to_stable, resolve_stable_to_current_hw,
+    set_ucm_verb, watch_pcm_devices, DeviceHotplugEvent, DeviceWatcher,
 };
-pub use alsa_direct::AlsaDirectStream;
+pub use alsa_direct::{alsa_mixer_elements, AlsaDirectStream};
 #[cfg(target_os = "linux")]
 pub use jack_backend::JackStream;
 pub use analysis::SpectralAnalyzer;
 pub use analyzer_tap::{AnalyzerMessage, AnalyzerTap};
 pub use backend::{
     AlsaDirectError, AlsaPlugin, AudioBackend, AudioBackendType, AudioDevice, BackendConfig,
-    BackendManager, BackendResult, BitPerfectMode,
+    negotiate_format, negotiate_format_traced, BackendManager, BackendResult, BitPerfectMode,
+    DspPlugin, SampleFormat, SupportedFormat,
 };
 pub use coreaudio_direct::CoreAudioExclusiveGuard;
+pub use crossfade::{CrossfadeOut, CrossfadeSlot, CrossfadeTail};
 pub use dac_capabilities::{query_dac_capabilities, DacCapabilities};
 pub use dac_probe::{negotiated_active_rate, negotiated_stream_rate, NegotiatedRate};
 pub use health::{
@@ -79,14 +88,22 @@ pub use health::{
     InitSystem, Sandbox,
 };
 pub use device_reservation::{DeviceReservation, ReservationError};
-pub use diagnostic::{AudioDiagnostic, BitDepthResult, DiagnosticSource};
+pub use diagnostic::{AudioDiagnostic, BitDepthResult, DiagnosticSource, LatencyInfo};
+pub use dsp::{DspChain, DspSource};
 pub use dynamic_amplify::DynamicAmplify;
+pub use limiter::TruePeakLimiter;
 pub use loudness::{calculate_gain_factor, db_to_linear, extract_replaygain, ReplayGainData};
-pub use loudness_analyzer::LoudnessAnalyzer;
+pub use loudness_analyzer::{LoudnessAnalysisQueue, LoudnessAnalyzer};
 pub use loudness_cache::LoudnessCache;
 pub use output_sinks::{list_output_sinks, OutputSinkInfo};
-pub use settings::AudioSettings;
-pub use visualizer::{RingBuffer, TappedSource, VisualizerTap};
+pub use parametric_eq::{
+    eq_chain, graphic_eq_bands, graphic_eq_gains, EqBand, FilterType, ParametricEq,
+    GRAPHIC_EQ_FREQS,
+};
+pub use podcast::{SilenceSkip, TimeStretch};
+pub use settings::{AudioSettings, PlaylistAudioOverride};
+pub use silence::{AudioBounds, SilenceDetector};
+pub use visualizer::{RingBuffer, TappedSource, VisualizerMode, VisualizerTap};
 
 /// Stub: returns the ID unchanged on non-Linux (no ALSA normalization needed).
 #[cfg(not(target_os = "linux"))]
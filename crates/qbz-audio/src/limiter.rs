@@ -0,0 +1,403 @@
+//! True-peak limiter for the normalization path.
+//!
+//! Normalization gain can push the reconstructed (inter-sample) waveform
+//! past 0 dBFS even while every sample stays below it; the DAC's
+//! reconstruction filter then clips. [`TruePeakLimiter`] estimates the true
+//! peak of each frame with the ITU-R BS.1770-4 (Annex 2) 4× polyphase
+//! interpolator and applies soft-knee gain reduction to frames over the
+//! threshold.
+//!
+//! The audio is delayed by one interpolator length ([`LOOKAHEAD_FRAMES`]) so
+//! the gain for a frame is the minimum over every detection that frame
+//! contributes to — the reduction lands on the samples around the peak, not
+//! after it. Attack is instant, release is a 50ms exponential that snaps to
+//! unity once within [`UNITY_EPSILON`]. Frames under the knee leave with a
+//! gain of exactly 1.0, so quiet material is untouched — including after a
+//! limited passage has released.
+
+use std::collections::VecDeque;
+use std::num::NonZero;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::loudness::db_to_linear;
+
+/// Taps per interpolator phase.
+const TAPS: usize = 12;
+
+/// Frames the output lags the input: one full interpolator window.
+pub const LOOKAHEAD_FRAMES: usize = TAPS;
+
+/// Width of the soft knee around the threshold, dB.
+const KNEE_DB: f32 = 2.0;
+
+/// Release time constant.
+const RELEASE_MS: f32 = 50.0;
+
+/// A releasing gain this close to 1.0 is snapped to it; the exponential
+/// would otherwise only approach unity and never pass audio through
+/// untouched again.
+const UNITY_EPSILON: f32 = 1e-6;
+
+/// BS.1770-4 Annex 2 polyphase FIR for 4× oversampling (48 taps, 4 phases).
+const PHASES: [[f32; TAPS]; 4] = [
+    [
+        0.001_708_984_4,
+        0.010_986_328,
+        -0.019_653_32,
+        0.033_203_125,
+        -0.059_448_242,
+        0.137_329_1,
+        0.972_167_97,
+        -0.102_294_92,
+        0.047_607_42,
+        -0.026_611_328,
+        0.014_892_578,
+        -0.008_300_781,
+    ],
+    [
+        -0.029_174_805,
+        0.029_296_875,
+        -0.051_757_813,
+        0.089_111_33,
+        -0.166_503_9,
+        0.465_087_9,
+        0.779_785_16,
+        -0.200_317_38,
+        0.101_562_5,
+        -0.058_227_54,
+        0.033_081_055,
+        -0.018_920_898,
+    ],
+    [
+        -0.018_920_898,
+        0.033_081_055,
+        -0.058_227_54,
+        0.101_562_5,
+        -0.200_317_38,
+        0.779_785_16,
+        0.465_087_9,
+        -0.166_503_9,
+        0.089_111_33,
+        -0.051_757_813,
+        0.029_296_875,
+        -0.029_174_805,
+    ],
+    [
+        -0.008_300_781,
+        0.014_892_578,
+        -0.026_611_328,
+        0.047_607_42,
+        -0.102_294_92,
+        0.137_329_1,
+        0.972_167_97,
+        -0.059_448_242,
+        0.033_203_125,
+        -0.019_653_32,
+        0.010_986_328,
+        0.001_708_984_4,
+    ],
+];
+
+/// Gain reduction (dB, >= 0) for a peak at `level_db` against `threshold_db`:
+/// none below the knee, a quadratic blend inside it, and down to the
+/// threshold above it.
+fn reduction_db(level_db: f32, threshold_db: f32) -> f32 {
+    let over = level_db - threshold_db;
+    if over <= -KNEE_DB / 2.0 {
+        0.0
+    } else if over >= KNEE_DB / 2.0 {
+        over
+    } else {
+        let x = over + KNEE_DB / 2.0;
+        x * x / (2.0 * KNEE_DB)
+    }
+}
+
+/// Per-channel true-peak detector over the last [`TAPS`] samples.
+struct PeakDetector {
+    /// Ring of recent samples per channel; `pos` is the oldest slot.
+    history: Vec<[f32; TAPS]>,
+    pos: usize,
+}
+
+impl PeakDetector {
+    fn new(channels: usize) -> Self {
+        Self {
+            history: vec![[0.0; TAPS]; channels],
+            pos: 0,
+        }
+    }
+
+    /// Push one interleaved frame and return the largest absolute value among
+    /// its samples and the 4× interpolated points of the current window.
+    fn push(&mut self, frame: &[f32]) -> f32 {
+        let mut peak = 0.0f32;
+        for (history, &sample) in self.history.iter_mut().zip(frame) {
+            history[self.pos] = sample;
+            peak = peak.max(sample.abs());
+        }
+        self.pos = (self.pos + 1) % TAPS;
+        for history in &self.history {
+            for phase in &PHASES {
+                let mut acc = 0.0f32;
+                for (k, coeff) in phase.iter().enumerate() {
+                    acc += coeff * history[(self.pos + k) % TAPS];
+                }
+                peak = peak.max(acc.abs());
+            }
+        }
+        peak
+    }
+}
+
+pub struct TruePeakLimiter<S>
+where
+    S: Source<Item = f32>,
+{
+    inner: S,
+    channels: usize,
+    threshold_dbfs: f32,
+    detector: PeakDetector,
+    /// Delayed input frames awaiting output, interleaved.
+    delay: VecDeque<f32>,
+    /// Target gain of each delayed frame (same order as `delay`).
+    gains: VecDeque<f32>,
+    current_gain: f32,
+    release_coeff: f32,
+    /// Frame being emitted.
+    frame: Vec<f32>,
+    pos: usize,
+    /// Frame being read from the inner source (reused across reads).
+    input: Vec<f32>,
+    /// Inner source ran out; the delay line drains.
+    exhausted: bool,
+}
+
+impl<S> TruePeakLimiter<S>
+where
+    S: Source<Item = f32>,
+{
+    /// Limit `source` so its true peak stays at or under `threshold_dbfs`
+    /// (dBTP).
+    pub fn new(source: S, threshold_dbfs: f32) -> Self {
+        let channels = source.channels().get() as usize;
+        let sample_rate = source.sample_rate().get() as f32;
+        Self {
+            inner: source,
+            channels,
+            threshold_dbfs,
+            detector: PeakDetector::new(channels),
+            delay: VecDeque::with_capacity((LOOKAHEAD_FRAMES + 1) * channels),
+            gains: VecDeque::with_capacity(LOOKAHEAD_FRAMES + 1),
+            current_gain: 1.0,
+            release_coeff: (-1.0 / (RELEASE_MS / 1000.0 * sample_rate)).exp(),
+            frame: Vec::with_capacity(channels),
+            pos: 0,
+            input: Vec::with_capacity(channels),
+            exhausted: false,
+        }
+    }
+
+    /// Read one frame from the inner source into the delay line. A truncated
+    /// trailing frame is queued as-is (and not analysed).
+    fn read_frame(&mut self) -> bool {
+        self.input.clear();
+        for _ in 0..self.channels {
+            match self.inner.next() {
+                Some(sample) => self.input.push(sample),
+                None => break,
+            }
+        }
+        if self.input.len() < self.channels {
+            self.exhausted = true;
+            if self.input.is_empty() {
+                return false;
+            }
+            self.delay.extend(&self.input);
+            self.gains.push_back(1.0);
+            return true;
+        }
+        let peak = self.detector.push(&self.input);
+        let level_db = 20.0 * peak.max(1e-9).log10();
+        self.gains
+            .push_back(db_to_linear(-reduction_db(level_db, self.threshold_dbfs)));
+        self.delay.extend(&self.input);
+        true
+    }
+
+    /// Emit the oldest delayed frame with its smoothed gain. Returns false
+    /// once the delay line is empty and the inner source is done.
+    fn refill(&mut self) -> bool {
+        while !self.exhausted && self.gains.len() <= LOOKAHEAD_FRAMES {
+            if !self.read_frame() {
+                break;
+            }
+        }
+        if self.gains.is_empty() {
+            return false;
+        }
+        let target = self.gains.iter().copied().fold(1.0f32, f32::min);
+        self.current_gain = if target < self.current_gain {
+            target
+        } else {
+            target + (self.current_gain - target) * self.release_coeff
+        };
+        if 1.0 - self.current_gain < UNITY_EPSILON {
+            self.current_gain = 1.0;
+        }
+        self.gains.pop_front();
+        let len = self.channels.min(self.delay.len());
+        self.frame.clear();
+        self.frame.extend(self.delay.drain(..len));
+        if self.current_gain < 1.0 {
+            for sample in self.frame.iter_mut() {
+                *sample *= self.current_gain;
+            }
+        }
+        self.pos = 0;
+        true
+    }
+}
+
+impl<S> Iterator for TruePeakLimiter<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.frame.len() && !self.refill() {
+            return None;
+        }
+        let sample = self.frame[self.pos];
+        self.pos += 1;
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.delay.len() + (self.frame.len() - self.pos);
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.map(|u| u.saturating_add(buffered)),
+        )
+    }
+}
+
+impl<S> Source for TruePeakLimiter<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        let buffered = self.delay.len() + (self.frame.len() - self.pos);
+        self.inner.current_span_len().map(|len| len + buffered)
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    /// Stereo sine at fs/4 with a 45° phase offset: every sample sits at
+    /// `amplitude`·√½ while the waveform between them reaches `amplitude` —
+    /// the classic inter-sample-peak case.
+    fn quarter_rate_sine(amplitude: f32, frames: usize) -> Vec<f32> {
+        (0..frames)
+            .flat_map(|n| {
+                let phase = std::f32::consts::FRAC_PI_2 * n as f32 + std::f32::consts::FRAC_PI_4;
+                let sample = amplitude * phase.sin();
+                [sample, sample]
+            })
+            .collect()
+    }
+
+    fn stereo(samples: Vec<f32>) -> SamplesBuffer {
+        SamplesBuffer::new(
+            NonZero::new(2u16).unwrap(),
+            NonZero::new(48000u32).unwrap(),
+            samples,
+        )
+    }
+
+    fn true_peak_db(samples: &[f32]) -> f32 {
+        let mut detector = PeakDetector::new(2);
+        let peak = samples
+            .chunks(2)
+            .map(|frame| detector.push(frame))
+            .fold(0.0f32, f32::max);
+        20.0 * peak.log10()
+    }
+
+    #[test]
+    fn knee_is_continuous_and_caps_at_the_threshold() {
+        assert_eq!(reduction_db(-10.0, -1.0), 0.0);
+        assert_eq!(reduction_db(-2.0, -1.0), 0.0);
+        assert!((reduction_db(0.0, -1.0) - 1.0).abs() < 1e-6);
+        assert!((reduction_db(3.0, -1.0) - 4.0).abs() < 1e-6);
+        // Inside the knee the output level never exceeds the threshold.
+        for step in 0..=20 {
+            let level = -2.0 + step as f32 * 0.1;
+            assert!(level - reduction_db(level, -1.0) <= -1.0 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn quiet_material_passes_bit_exact() {
+        let input = quarter_rate_sine(0.5, 4_000);
+        let output: Vec<f32> = TruePeakLimiter::new(stereo(input.clone()), -1.0).collect();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn quiet_material_after_a_limited_passage_passes_bit_exact() {
+        // 1 s of limiting, then 1 s of quiet material: the release reaches
+        // unity about 0.6 s into the quiet second, after which samples are
+        // passed through unchanged.
+        let mut input = quarter_rate_sine(1.2, 48_000);
+        let quiet = quarter_rate_sine(0.5, 48_000);
+        input.extend(&quiet);
+        let output: Vec<f32> = TruePeakLimiter::new(stereo(input), -1.0).collect();
+        let tail = quiet.len() / 4;
+        assert_eq!(output[output.len() - tail..], quiet[quiet.len() - tail..]);
+    }
+
+    #[test]
+    fn inter_sample_peaks_are_pulled_under_the_threshold() {
+        // Sample peaks ≈ -3.4 / -1.4 dBFS (both under the threshold), true
+        // peaks ≈ -0.4 / +1.6 dBTP; both must land at or under -1 dBTP.
+        for amplitude in [0.95, 1.2] {
+            let input = quarter_rate_sine(amplitude, 4_000);
+            assert!(input.iter().all(|s| s.abs() < db_to_linear(-1.0)));
+            assert!(true_peak_db(&input) > -1.0);
+
+            let output: Vec<f32> = TruePeakLimiter::new(stereo(input.clone()), -1.0).collect();
+            assert_eq!(output.len(), input.len());
+            assert!(
+                true_peak_db(&output) <= -0.95,
+                "amplitude {amplitude}: {} dBTP",
+                true_peak_db(&output)
+            );
+        }
+    }
+}
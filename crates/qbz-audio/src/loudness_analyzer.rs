@@ -7,13 +7,26 @@
 //! - First measurement after ~10s of audio (EBU R128 needs sufficient data)
 //! - Refinement every ~5s thereafter (gain converges by ~30-60s)
 //! - Cached results are used immediately on cache hit
+//!
+//! [`LoudnessAnalysisQueue`] complements the live thread: it measures whole
+//! local files ahead of playback so the first play already starts at the
+//! right gain.
 
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::Receiver;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread;
 
 use ebur128::{EbuR128, Mode};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
 
 use super::analyzer_tap::AnalyzerMessage;
 use super::loudness::db_to_linear;
@@ -22,6 +35,10 @@ use super::loudness_cache::LoudnessCache;
 /// Maximum gain boost in dB (conservative clipping prevention)
 const MAX_GAIN_DB: f32 = 6.0;
 
+/// Files decoded in parallel by [`LoudnessAnalysisQueue`]. Decoding is
+/// CPU-bound; two workers keep an album moving without starving playback.
+const QUEUE_WORKERS: usize = 2;
+
 pub struct LoudnessAnalyzer;
 
 impl LoudnessAnalyzer {
@@ -42,6 +59,35 @@ impl LoudnessAnalyzer {
             .expect("Failed to spawn loudness analyzer thread")
     }
 
+    /// Measure a whole file's integrated loudness (LUFS). Blocking: decodes
+    /// the entire file, so call it off the async runtime.
+    pub fn analyze(path: &Path) -> Result<f32, String> {
+        let mut ebur128: Option<EbuR128> = None;
+        decode_file(path, |rate, channels, samples| {
+            if ebur128.is_none() {
+                ebur128 = Some(
+                    EbuR128::new(channels as u32, rate, Mode::I)
+                        .map_err(|e| format!("EBU R128 init failed: {}", e))?,
+                );
+            }
+            if let Some(ref mut meter) = ebur128 {
+                meter
+                    .add_frames_f32(samples)
+                    .map_err(|e| format!("EBU R128 feed failed: {}", e))?;
+            }
+            Ok(())
+        })?;
+
+        let loudness = ebur128
+            .ok_or_else(|| "File produced no audio".to_string())?
+            .loudness_global()
+            .map_err(|e| format!("Loudness measurement failed: {}", e))?;
+        if !loudness.is_finite() {
+            return Err("File is silent".to_string());
+        }
+        Ok(loudness as f32)
+    }
+
     fn run(rx: Receiver<AnalyzerMessage>, cache: Arc<LoudnessCache>) {
         let mut state: Option<AnalyzerState> = None;
 
@@ -241,8 +287,151 @@ impl AnalyzerState {
         self.initial_done = true;
 
         // Always cache the latest measurement for next playback
-        cache.set(self.track_id, adjustment_db, 0.0, "ebur128");
+        cache.set(
+            self.track_id,
+            adjustment_db,
+            0.0,
+            "ebur128",
+            self.target_lufs,
+        );
+    }
+}
+
+/// Background whole-file loudness analysis for local tracks.
+///
+/// Files are measured on the blocking pool, at most [`QUEUE_WORKERS`] at a
+/// time, and the result lands in the same [`LoudnessCache`] the live
+/// analyzer reads on `NewTrack`, so the next play of the track starts at its
+/// final gain. Each path is analysed at most once per queue lifetime.
+#[derive(Clone)]
+pub struct LoudnessAnalysisQueue {
+    cache: Arc<LoudnessCache>,
+    seen: Arc<Mutex<HashSet<PathBuf>>>,
+    workers: Arc<tokio::sync::Semaphore>,
+}
+
+impl LoudnessAnalysisQueue {
+    pub fn new(cache: Arc<LoudnessCache>) -> Self {
+        Self {
+            cache,
+            seen: Arc::new(Mutex::new(HashSet::new())),
+            workers: Arc::new(tokio::sync::Semaphore::new(QUEUE_WORKERS)),
+        }
+    }
+
+    /// Queue a file for analysis against `target_lufs`. Returns false (and
+    /// does nothing) when the path was already queued or the track already
+    /// has a cached measurement. `on_analyzed(track_id, gain_db)` runs on the
+    /// worker after the result is cached.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn enqueue<F>(
+        &self,
+        track_id: u64,
+        path: PathBuf,
+        target_lufs: f32,
+        on_analyzed: F,
+    ) -> bool
+    where
+        F: FnOnce(u64, f32) + Send + 'static,
+    {
+        if self.cache.get(track_id).is_some() {
+            return false;
+        }
+        match self.seen.lock() {
+            Ok(mut seen) => {
+                if !seen.insert(path.clone()) {
+                    return false;
+                }
+            }
+            Err(_) => return false,
+        }
+
+        let cache = self.cache.clone();
+        let workers = self.workers.clone();
+        tokio::spawn(async move {
+            let Ok(_permit) = workers.acquire_owned().await else {
+                return;
+            };
+            let measured =
+                tokio::task::spawn_blocking(move || (LoudnessAnalyzer::analyze(&path), path)).await;
+            match measured {
+                Ok((Ok(lufs), path)) => {
+                    let gain_db = target_lufs - lufs;
+                    log::info!(
+                        "[LoudnessQueue] Track {} ({}): {:.1} LUFS, adjustment {:.2} dB",
+                        track_id,
+                        path.display(),
+                        lufs,
+                        gain_db
+                    );
+                    cache.set(track_id, gain_db, 0.0, "ebur128", target_lufs);
+                    on_analyzed(track_id, gain_db);
+                }
+                Ok((Err(e), path)) => {
+                    log::warn!("[LoudnessQueue] {} not analysed: {}", path.display(), e);
+                }
+                Err(e) => log::warn!("[LoudnessQueue] Analysis task failed: {}", e),
+            }
+        });
+        true
+    }
+}
+
+/// Decode a whole file, handing each buffer to `on_buffer(sample_rate,
+/// channels, interleaved_samples)`. Blocking; shared by the whole-file
+/// scans (loudness, silence bounds).
+pub(crate) fn decode_file<F>(path: &Path, mut on_buffer: F) -> Result<(), String>
+where
+    F: FnMut(u32, usize, &[f32]) -> Result<(), String>,
+{
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+    let mut probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            mss,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("Probe failed: {}", e))?;
+
+    let track = probed
+        .format
+        .default_track()
+        .ok_or_else(|| "No supported audio track".to_string())?;
+    let track_id = track.id;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Decoder init failed: {}", e))?;
+
+    loop {
+        let packet = match probed.format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(_)) => break,
+            Err(e) => return Err(format!("Read error: {}", e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        match decoder.decode(&packet) {
+            Ok(audio_buf) => {
+                let spec = *audio_buf.spec();
+                let mut sample_buf = SampleBuffer::<f32>::new(audio_buf.frames() as u64, spec);
+                sample_buf.copy_interleaved_ref(audio_buf);
+                on_buffer(spec.rate, spec.channels.count(), sample_buf.samples())?;
+            }
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(format!("Decode error: {}", e)),
+        }
     }
+    Ok(())
 }
 
 /// Convert a dB adjustment to a capped linear gain factor.
@@ -250,3 +439,49 @@ fn compute_gain_capped(adjustment_db: f32) -> f32 {
     let capped_db = adjustment_db.min(MAX_GAIN_DB);
     db_to_linear(capped_db)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write a mono 16-bit PCM WAV of a 1 kHz sine at `amplitude`.
+    fn write_sine_wav(path: &Path, amplitude: f32, secs: u32) {
+        const RATE: u32 = 48_000;
+        let frames = RATE * secs;
+        let mut wav = Vec::with_capacity(44 + frames as usize * 2);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + frames * 2).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&RATE.to_le_bytes());
+        wav.extend_from_slice(&(RATE * 2).to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(frames * 2).to_le_bytes());
+        for n in 0..frames {
+            let x = (2.0 * std::f32::consts::PI * 1000.0 * n as f32 / RATE as f32).sin();
+            wav.extend_from_slice(&((x * amplitude * i16::MAX as f32) as i16).to_le_bytes());
+        }
+        std::fs::write(path, wav).unwrap();
+    }
+
+    #[test]
+    fn analyze_measures_sine_loudness() {
+        let path = std::env::temp_dir().join(format!("qbz-loudness-{}.wav", std::process::id()));
+        write_sine_wav(&path, 0.5, 5);
+        let lufs = LoudnessAnalyzer::analyze(&path);
+        let _ = std::fs::remove_file(&path);
+        // BS.1770: 1 kHz full-scale sine on one channel reads -3.01 LUFS;
+        // half amplitude is 6.02 dB lower.
+        let lufs = lufs.unwrap();
+        assert!((lufs + 9.03).abs() < 0.3, "measured {lufs} LUFS");
+    }
+
+    #[test]
+    fn analyze_rejects_missing_file() {
+        assert!(LoudnessAnalyzer::analyze(Path::new("/nonexistent/qbz.flac")).is_err());
+    }
+}
@@ -1,4 +1,9 @@
-//! Loudness cache — persists EBU R128 measurements in SQLite.
+//! Loudness cache — persists EBU R128 measurements in SQLite, plus the
+//! silence-trim bounds of local files (`track_silence`).
+//!
+//! Silence bounds are keyed by the file itself (path, size, mtime) rather
+//! than the library row id: row ids are reused across rescans, and a
+//! re-encoded or edited file must be scanned again.
 //!
 //! Follows the `AudioSettingsStore` pattern: database lives in
 //! `dirs::data_dir()/qbz/loudness_cache.db`.
@@ -6,7 +11,11 @@
 //! Thread-safe via `Mutex<Connection>`.
 
 use rusqlite::{params, Connection};
+use std::path::Path;
 use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use crate::silence::AudioBounds;
 
 #[derive(Debug, Clone)]
 pub struct CachedLoudness {
@@ -14,6 +23,10 @@ pub struct CachedLoudness {
     pub peak: f32,
     /// Source of the measurement: "ebur128" or "replaygain"
     pub source: String,
+    /// Normalization target the gain was computed against
+    /// (`gain_db = target - measured loudness`). None for rows cached before
+    /// the target was recorded.
+    pub target_lufs: Option<f32>,
 }
 
 pub struct LoudnessCache {
@@ -47,6 +60,49 @@ impl LoudnessCache {
         )
         .map_err(|e| format!("Failed to create loudness table: {}", e))?;
 
+        // Migration: record the target each gain was computed against, so
+        // the measured loudness can be recovered after the target changes.
+        let has_target: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('track_loudness') WHERE name = 'target_lufs'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|count| count > 0)
+            .unwrap_or(false);
+        if !has_target {
+            conn.execute_batch("ALTER TABLE track_loudness ADD COLUMN target_lufs REAL;")
+                .map_err(|e| format!("Failed to add loudness target column: {}", e))?;
+        }
+
+        // Migration: the first track_silence was keyed by library row id.
+        // It is only a cache, so drop it and let files be rescanned.
+        let silence_by_row_id: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('track_silence') WHERE name = 'track_id'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|count| count > 0)
+            .unwrap_or(false);
+        if silence_by_row_id {
+            log::info!("[LoudnessCache] Re-keying silence bounds by file");
+            conn.execute_batch("DROP TABLE track_silence;")
+                .map_err(|e| format!("Failed to drop old silence table: {}", e))?;
+        }
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS track_silence (
+                file_path TEXT PRIMARY KEY,
+                file_size INTEGER NOT NULL,
+                modified_secs INTEGER NOT NULL,
+                start_ms INTEGER NOT NULL,
+                end_ms INTEGER NOT NULL,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            )",
+        )
+        .map_err(|e| format!("Failed to create silence table: {}", e))?;
+
         log::info!("[LoudnessCache] Opened at {}", db_path.display());
 
         Ok(Self {
@@ -58,26 +114,35 @@ impl LoudnessCache {
     pub fn get(&self, track_id: u64) -> Option<CachedLoudness> {
         let conn = self.conn.lock().ok()?;
         conn.query_row(
-            "SELECT gain_db, peak, source FROM track_loudness WHERE track_id = ?1",
+            "SELECT gain_db, peak, source, target_lufs FROM track_loudness WHERE track_id = ?1",
             params![track_id as i64],
             |row| {
                 Ok(CachedLoudness {
                     gain_db: row.get::<_, f64>(0)? as f32,
                     peak: row.get::<_, f64>(1)? as f32,
                     source: row.get(2)?,
+                    target_lufs: row.get::<_, Option<f64>>(3)?.map(|t| t as f32),
                 })
             },
         )
         .ok()
     }
 
-    /// Store or update loudness data for a track.
-    pub fn set(&self, track_id: u64, gain_db: f32, peak: f32, source: &str) {
+    /// Store or update loudness data for a track. `gain_db` is relative to
+    /// `target_lufs`, which is stored with it.
+    pub fn set(&self, track_id: u64, gain_db: f32, peak: f32, source: &str, target_lufs: f32) {
         if let Ok(conn) = self.conn.lock() {
             let result = conn.execute(
-                "INSERT OR REPLACE INTO track_loudness (track_id, gain_db, peak, source, created_at)
-                 VALUES (?1, ?2, ?3, ?4, strftime('%s', 'now'))",
-                params![track_id as i64, gain_db as f64, peak as f64, source],
+                "INSERT OR REPLACE INTO track_loudness
+                    (track_id, gain_db, peak, source, target_lufs, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, strftime('%s', 'now'))",
+                params![
+                    track_id as i64,
+                    gain_db as f64,
+                    peak as f64,
+                    source,
+                    target_lufs as f64
+                ],
             );
             if let Err(e) = result {
                 log::warn!(
@@ -88,4 +153,64 @@ impl LoudnessCache {
             }
         }
     }
+
+    /// Look up the cached silence-trim bounds of a file. A miss when the
+    /// file changed (size or mtime) since it was scanned.
+    pub fn get_bounds(&self, path: &Path) -> Option<AudioBounds> {
+        let (size, modified) = file_stamp(path)?;
+        let conn = self.conn.lock().ok()?;
+        conn.query_row(
+            "SELECT start_ms, end_ms FROM track_silence
+             WHERE file_path = ?1 AND file_size = ?2 AND modified_secs = ?3",
+            params![path.to_string_lossy(), size, modified],
+            |row| {
+                Ok(AudioBounds {
+                    start_ms: row.get::<_, i64>(0)? as u64,
+                    end_ms: row.get::<_, i64>(1)? as u64,
+                })
+            },
+        )
+        .ok()
+    }
+
+    /// Store or update the silence-trim bounds of a file, stamped with its
+    /// current size and mtime.
+    pub fn set_bounds(&self, path: &Path, bounds: AudioBounds) {
+        let Some((size, modified)) = file_stamp(path) else {
+            return;
+        };
+        if let Ok(conn) = self.conn.lock() {
+            let result = conn.execute(
+                "INSERT OR REPLACE INTO track_silence
+                    (file_path, file_size, modified_secs, start_ms, end_ms, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, strftime('%s', 'now'))",
+                params![
+                    path.to_string_lossy(),
+                    size,
+                    modified,
+                    bounds.start_ms as i64,
+                    bounds.end_ms as i64
+                ],
+            );
+            if let Err(e) = result {
+                log::warn!(
+                    "[LoudnessCache] Failed to store silence bounds for {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// A file's (size, mtime in seconds), the silence bounds' freshness check.
+fn file_stamp(path: &Path) -> Option<(i64, i64)> {
+    let meta = std::fs::metadata(path).ok()?;
+    let modified = meta
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    Some((meta.len() as i64, modified as i64))
 }
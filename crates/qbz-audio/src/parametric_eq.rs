@@ -0,0 +1,321 @@
+//! Software parametric EQ (biquad bank) for per-device headphone curves.
+//!
+//! Each [`EqBand`] is one RBJ "Audio EQ Cookbook" biquad. Coefficients are
+//! computed in f64 and each channel keeps its own transposed direct form II
+//! state, so stereo images stay intact and low-Q / low-frequency bands don't
+//! accumulate f32 rounding noise.
+//!
+//! This is opt-in DSP: the EQ only runs when the user has configured bands,
+//! and any active band means the output is no longer bit-perfect.
+
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::DspPlugin;
+
+/// Biquad response shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterType {
+    /// Bell boost/cut around `freq`
+    Peaking,
+    /// Shelf boost/cut below `freq`
+    LowShelf,
+    /// Shelf boost/cut above `freq`
+    HighShelf,
+    /// 12 dB/oct low-pass at `freq` (`gain_db` ignored)
+    LowPass,
+    /// 12 dB/oct high-pass at `freq` (`gain_db` ignored)
+    HighPass,
+}
+
+/// One EQ band as persisted in `AudioSettings::eq_bands`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EqBand {
+    /// Center / corner frequency in Hz
+    pub freq: f32,
+    /// Gain in dB (peaking and shelf filters only)
+    pub gain_db: f32,
+    /// Quality factor (bandwidth). 0.707 = Butterworth
+    pub q: f32,
+    pub filter_type: FilterType,
+}
+
+/// Normalized biquad coefficients (a0 folded in)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Coefficients {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Coefficients {
+    const IDENTITY: Self = Self {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+
+    fn for_band(band: &EqBand, sample_rate: u32) -> Self {
+        let fs = sample_rate as f64;
+        // Keep the corner safely below Nyquist and Q strictly positive;
+        // an out-of-range band from a hand-edited settings row must never
+        // produce an unstable filter.
+        let freq = (band.freq as f64).clamp(10.0, fs * 0.45);
+        let q = (band.q as f64).max(0.05);
+        if !freq.is_finite() || !q.is_finite() || !band.gain_db.is_finite() {
+            return Self::IDENTITY;
+        }
+
+        let a = 10f64.powf(band.gain_db as f64 / 40.0);
+        let w0 = 2.0 * PI * freq / fs;
+        let (sin_w0, cos_w0) = w0.sin_cos();
+        let alpha = sin_w0 / (2.0 * q);
+        let sqrt_a_alpha = 2.0 * a.sqrt() * alpha;
+
+        let (b0, b1, b2, a0, a1, a2) = match band.filter_type {
+            FilterType::Peaking => (
+                1.0 + alpha * a,
+                -2.0 * cos_w0,
+                1.0 - alpha * a,
+                1.0 + alpha / a,
+                -2.0 * cos_w0,
+                1.0 - alpha / a,
+            ),
+            FilterType::LowShelf => (
+                a * ((a + 1.0) - (a - 1.0) * cos_w0 + sqrt_a_alpha),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0),
+                a * ((a + 1.0) - (a - 1.0) * cos_w0 - sqrt_a_alpha),
+                (a + 1.0) + (a - 1.0) * cos_w0 + sqrt_a_alpha,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0),
+                (a + 1.0) + (a - 1.0) * cos_w0 - sqrt_a_alpha,
+            ),
+            FilterType::HighShelf => (
+                a * ((a + 1.0) + (a - 1.0) * cos_w0 + sqrt_a_alpha),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0),
+                a * ((a + 1.0) + (a - 1.0) * cos_w0 - sqrt_a_alpha),
+                (a + 1.0) - (a - 1.0) * cos_w0 + sqrt_a_alpha,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos_w0),
+                (a + 1.0) - (a - 1.0) * cos_w0 - sqrt_a_alpha,
+            ),
+            FilterType::LowPass => (
+                (1.0 - cos_w0) / 2.0,
+                1.0 - cos_w0,
+                (1.0 - cos_w0) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos_w0,
+                1.0 - alpha,
+            ),
+            FilterType::HighPass => (
+                (1.0 + cos_w0) / 2.0,
+                -(1.0 + cos_w0),
+                (1.0 + cos_w0) / 2.0,
+                1.0 + alpha,
+                -2.0 * cos_w0,
+                1.0 - alpha,
+            ),
+        };
+
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+}
+
+/// Per-channel transposed direct form II state
+#[derive(Debug, Clone, Copy, Default)]
+struct BiquadState {
+    z1: f64,
+    z2: f64,
+}
+
+impl BiquadState {
+    #[inline]
+    fn process(&mut self, c: &Coefficients, x: f64) -> f64 {
+        let y = c.b0 * x + self.z1;
+        self.z1 = c.b1 * x - c.a1 * y + self.z2;
+        self.z2 = c.b2 * x - c.a2 * y;
+        y
+    }
+}
+
+/// Parametric EQ built from a list of bands. Implements [`DspPlugin`].
+pub struct ParametricEq {
+    bands: Vec<EqBand>,
+    coefficients: Vec<Coefficients>,
+    /// `states[band][channel]`
+    states: Vec<Vec<BiquadState>>,
+}
+
+impl ParametricEq {
+    pub fn new(bands: Vec<EqBand>) -> Self {
+        Self {
+            bands,
+            coefficients: Vec::new(),
+            states: Vec::new(),
+        }
+    }
+
+    pub fn bands(&self) -> &[EqBand] {
+        &self.bands
+    }
+}
+
+impl DspPlugin for ParametricEq {
+    fn name(&self) -> &'static str {
+        "parametric_eq"
+    }
+
+    fn prepare(&mut self, sample_rate: u32, channels: u16) {
+        self.coefficients = self
+            .bands
+            .iter()
+            .map(|band| Coefficients::for_band(band, sample_rate))
+            .collect();
+        self.states = vec![vec![BiquadState::default(); channels as usize]; self.bands.len()];
+    }
+
+    #[inline]
+    fn process_frame(&mut self, frame: &mut [f32]) {
+        for (coeffs, states) in self.coefficients.iter().zip(self.states.iter_mut()) {
+            for (sample, state) in frame.iter_mut().zip(states.iter_mut()) {
+                *sample = state.process(coeffs, *sample as f64) as f32;
+            }
+        }
+    }
+}
+
+/// Build the DSP chain for a persisted band list. No bands means an empty
+/// chain, i.e. the bit-perfect bypass.
+pub fn eq_chain(bands: &[EqBand]) -> Vec<Box<dyn DspPlugin>> {
+    if bands.is_empty() {
+        return Vec::new();
+    }
+    vec![Box::new(ParametricEq::new(bands.to_vec()))]
+}
+
+/// Center frequencies of the ten-band graphic EQ in Settings > Audio
+pub const GRAPHIC_EQ_FREQS: [f32; 10] = [
+    31.0, 62.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0,
+];
+
+/// One octave per band
+const GRAPHIC_EQ_Q: f32 = 1.41;
+
+/// Peaking bands for graphic-EQ slider gains. Flat sliders produce no band,
+/// so an all-zero EQ stays an empty (bit-perfect) chain.
+pub fn graphic_eq_bands(gains_db: &[f32; 10]) -> Vec<EqBand> {
+    GRAPHIC_EQ_FREQS
+        .iter()
+        .zip(gains_db)
+        .filter(|(_, gain)| **gain != 0.0)
+        .map(|(freq, gain)| EqBand {
+            freq: *freq,
+            gain_db: *gain,
+            q: GRAPHIC_EQ_Q,
+            filter_type: FilterType::Peaking,
+        })
+        .collect()
+}
+
+/// Slider gains for a persisted band list. Bands that aren't on the graphic
+/// EQ grid (imported headphone curves) leave their slider at 0.
+pub fn graphic_eq_gains(bands: &[EqBand]) -> [f32; 10] {
+    let mut gains = [0.0; 10];
+    for band in bands {
+        if band.filter_type != FilterType::Peaking {
+            continue;
+        }
+        if let Some(i) = GRAPHIC_EQ_FREQS.iter().position(|f| *f == band.freq) {
+            gains[i] = band.gain_db;
+        }
+    }
+    gains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48_000;
+
+    /// Steady-state peak amplitude of a unit sine at `freq` after the EQ.
+    fn sine_gain(eq: &mut ParametricEq, freq: f64) -> f64 {
+        eq.prepare(RATE, 1);
+        let mut peak = 0.0f64;
+        for n in 0..RATE as usize {
+            let x = (2.0 * PI * freq * n as f64 / RATE as f64).sin() as f32;
+            let mut frame = [x];
+            eq.process_frame(&mut frame);
+            // Skip the first 100ms of filter settling
+            if n > (RATE / 10) as usize {
+                peak = peak.max(frame[0].abs() as f64);
+            }
+        }
+        peak
+    }
+
+    fn band(filter_type: FilterType, freq: f32, gain_db: f32) -> EqBand {
+        EqBand {
+            freq,
+            gain_db,
+            q: 0.707,
+            filter_type,
+        }
+    }
+
+    #[test]
+    fn zero_gain_peaking_is_transparent() {
+        let mut eq = ParametricEq::new(vec![band(FilterType::Peaking, 1000.0, 0.0)]);
+        let gain = sine_gain(&mut eq, 1000.0);
+        assert!((gain - 1.0).abs() < 1e-3, "gain {gain}");
+    }
+
+    #[test]
+    fn peaking_boost_hits_target_at_center() {
+        let mut eq = ParametricEq::new(vec![band(FilterType::Peaking, 1000.0, 6.0)]);
+        let gain_db = 20.0 * sine_gain(&mut eq, 1000.0).log10();
+        assert!((gain_db - 6.0).abs() < 0.1, "gain {gain_db} dB");
+    }
+
+    #[test]
+    fn low_shelf_leaves_highs_alone() {
+        let mut eq = ParametricEq::new(vec![band(FilterType::LowShelf, 100.0, -6.0)]);
+        let high_db = 20.0 * sine_gain(&mut eq, 10_000.0).log10();
+        let low_db = 20.0 * sine_gain(&mut eq, 20.0).log10();
+        assert!(high_db.abs() < 0.1, "high {high_db} dB");
+        assert!((low_db + 6.0).abs() < 0.3, "low {low_db} dB");
+    }
+
+    #[test]
+    fn out_of_range_band_stays_stable() {
+        let mut eq = ParametricEq::new(vec![EqBand {
+            freq: 96_000.0,
+            gain_db: 3.0,
+            q: 0.0,
+            filter_type: FilterType::Peaking,
+        }]);
+        let gain = sine_gain(&mut eq, 1000.0);
+        assert!(gain.is_finite() && gain < 2.0);
+    }
+
+    #[test]
+    fn graphic_eq_round_trips_and_flat_is_bypass() {
+        assert!(graphic_eq_bands(&[0.0; 10]).is_empty());
+
+        let mut gains = [0.0; 10];
+        gains[0] = 4.0;
+        gains[7] = -3.0;
+        let bands = graphic_eq_bands(&gains);
+        assert_eq!(bands.len(), 2);
+        assert_eq!(graphic_eq_gains(&bands), gains);
+    }
+}
@@ -6,7 +6,12 @@
 //! - Creates stream using CPAL "pulse" or "pipewire" device
 //! - Does NOT change system default (only affects QBZ)
 
-use super::backend::{AudioBackend, AudioBackendType, AudioDevice, BackendConfig, BackendResult};
+use super::backend::{
+    AudioBackend, AudioBackendType, AudioDevice, BackendConfig, BackendResult, SupportedFormat,
+};
+// Aliased: CPAL's `SampleFormat` is the one stream setup below uses.
+use super::backend::SampleFormat as SampleFormatKind;
+use super::diagnostic::LatencyInfo;
 use rodio::{
     cpal::{
         traits::{DeviceTrait, HostTrait},
@@ -532,6 +537,211 @@ fn parse_pw_dump_sinks(json: &str) -> Vec<AudioDevice> {
     devices
 }
 
+/// Standard rates a `{ min, max }` rate range is expanded against.
+const STANDARD_RATES: [u32; 11] = [
+    32000, 44100, 48000, 88200, 96000, 176400, 192000, 352800, 384000, 705600, 768000,
+];
+
+/// Read a node's `EnumFormat` params from `pw-dump` — the formats, rates and
+/// channel counts the node (i.e. the ALSA device behind it) accepts.
+/// `device_id` is the `node.name` (or numeric object id). Errors when
+/// `pw-dump` is missing or fails, or the node is unknown / advertises no
+/// usable raw-audio format.
+pub fn query_node_formats(device_id: &str) -> BackendResult<Vec<SupportedFormat>> {
+    let output = Command::new("pw-dump")
+        .output()
+        .map_err(|e| format!("pw-dump unavailable: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("pw-dump exited with {}", output.status));
+    }
+    let json = String::from_utf8_lossy(&output.stdout);
+    let formats = parse_node_formats(&json, device_id);
+    if formats.is_empty() {
+        return Err(format!(
+            "No EnumFormat params for PipeWire node '{}'",
+            device_id
+        ));
+    }
+    log::info!(
+        "[PipeWire Backend] {} advertises {} format combination(s)",
+        device_id,
+        formats.len()
+    );
+    Ok(formats)
+}
+
+/// Pure half of [`query_node_formats`]: find the node in `pw-dump` JSON and
+/// expand each raw-audio `EnumFormat` entry into format × rate × channels.
+/// SPA choices (`{ "default": .., "alt1": .. }`) contribute every listed
+/// value; a `{ "min", "max" }` rate range contributes the standard rates
+/// inside it. Formats QBZ can't output (U8, F64, ...) are dropped.
+fn parse_node_formats(json: &str, device_id: &str) -> Vec<SupportedFormat> {
+    let root: serde_json::Value = match serde_json::from_str(json) {
+        Ok(v) => v,
+        Err(e) => {
+            log::warn!("[PipeWire Backend] pw-dump JSON parse failed: {}", e);
+            return Vec::new();
+        }
+    };
+    let Some(node) = find_node(&root, device_id) else {
+        return Vec::new();
+    };
+    let params = node
+        .get("info")
+        .and_then(|i| i.get("params"))
+        .and_then(|p| p.get("EnumFormat"))
+        .and_then(|v| v.as_array());
+
+    let mut formats = Vec::new();
+    for param in params.into_iter().flatten() {
+        if param.get("mediaSubtype").and_then(|v| v.as_str()) != Some("raw") {
+            continue;
+        }
+        let sample_formats: Vec<SampleFormatKind> = choice_values(param.get("format"))
+            .iter()
+            .filter_map(|v| v.as_str().and_then(spa_sample_format))
+            .collect();
+        let rates = choice_rates(param.get("rate"));
+        let channels: Vec<u16> = choice_values(param.get("channels"))
+            .iter()
+            .filter_map(|v| v.as_u64())
+            .map(|c| c as u16)
+            .collect();
+        for &sample_format in &sample_formats {
+            for &sample_rate in &rates {
+                for &channels in &channels {
+                    let f = SupportedFormat {
+                        sample_format,
+                        sample_rate,
+                        channels,
+                    };
+                    if !formats.contains(&f) {
+                        formats.push(f);
+                    }
+                }
+            }
+        }
+    }
+    formats
+}
+
+/// The `pw-dump` node object whose `node.name` (or numeric id) is
+/// `device_id`.
+fn find_node<'a>(root: &'a serde_json::Value, device_id: &str) -> Option<&'a serde_json::Value> {
+    let is_target = |obj: &serde_json::Value| {
+        if obj.get("type").and_then(|v| v.as_str()) != Some("PipeWire:Interface:Node") {
+            return false;
+        }
+        let name = obj
+            .get("info")
+            .and_then(|i| i.get("props"))
+            .and_then(|p| p.get("node.name"))
+            .and_then(|v| v.as_str());
+        let id = obj.get("id").and_then(|v| v.as_i64());
+        name == Some(device_id) || id.map(|id| id.to_string()).as_deref() == Some(device_id)
+    };
+    root.as_array()?.iter().find(|obj| is_target(obj))
+}
+
+/// Read a sink node's latency from `pw-dump`: the graph quantum it runs at
+/// (`node.latency`, "frames/rate") as the software buffer, and the ALSA
+/// period plus headroom behind it as the device latency. Errors when
+/// `pw-dump` fails or the node reports neither.
+pub fn query_node_latency(device_id: &str) -> BackendResult<LatencyInfo> {
+    let output = Command::new("pw-dump")
+        .output()
+        .map_err(|e| format!("pw-dump unavailable: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("pw-dump exited with {}", output.status));
+    }
+    let json = String::from_utf8_lossy(&output.stdout);
+    parse_node_latency(&json, device_id)
+        .ok_or_else(|| format!("PipeWire node '{}' reports no latency", device_id))
+}
+
+/// Pure half of [`query_node_latency`].
+fn parse_node_latency(json: &str, device_id: &str) -> Option<LatencyInfo> {
+    let root: serde_json::Value = serde_json::from_str(json).ok()?;
+    let props = find_node(&root, device_id)?.get("info")?.get("props")?;
+    let int = |key: &str| props.get(key).and_then(|v| v.as_u64());
+
+    // "1024/48000" — a fraction, so it carries its own rate.
+    let quantum: Option<(u64, u32)> = props
+        .get("node.latency")
+        .and_then(|v| v.as_str())
+        .and_then(|l| l.split_once('/'))
+        .and_then(|(frames, rate)| Some((frames.parse().ok()?, rate.parse().ok()?)));
+    let rate = int("audio.rate")
+        .map(|r| r as u32)
+        .or(quantum.map(|(_, rate)| rate))
+        .unwrap_or(48_000);
+    let headroom = int("api.alsa.headroom").unwrap_or(0);
+    let device = int("api.alsa.period-size").map(|period| period + headroom);
+
+    if quantum.is_none() && device.is_none() {
+        return None;
+    }
+    Some(LatencyInfo::new(
+        device.map_or(0.0, |frames| LatencyInfo::frames_to_ms(frames, rate)),
+        quantum.map_or(0.0, |(frames, rate)| {
+            LatencyInfo::frames_to_ms(frames, rate)
+        }),
+    ))
+}
+
+/// Every value of an SPA property: a scalar, an array, or a choice object
+/// (`default` plus `alt1`, `alt2`, ...).
+fn choice_values(value: Option<&serde_json::Value>) -> Vec<serde_json::Value> {
+    match value {
+        None => Vec::new(),
+        Some(serde_json::Value::Array(values)) => values.clone(),
+        Some(serde_json::Value::Object(map)) => map
+            .iter()
+            .filter(|(key, _)| *key == "default" || key.starts_with("alt"))
+            .map(|(_, v)| v.clone())
+            .collect(),
+        Some(v) => vec![v.clone()],
+    }
+}
+
+/// Rates of an SPA `rate` property. A `{ min, max }` range becomes the
+/// standard rates inside it (plus the default); sorted, deduplicated.
+fn choice_rates(value: Option<&serde_json::Value>) -> Vec<u32> {
+    let mut rates: Vec<u32> = choice_values(value)
+        .iter()
+        .filter_map(|v| v.as_u64())
+        .map(|r| r as u32)
+        .collect();
+    if let Some(range) = value.and_then(|v| v.as_object()) {
+        let bound = |key: &str| range.get(key).and_then(|v| v.as_u64()).map(|r| r as u32);
+        if let (Some(min), Some(max)) = (bound("min"), bound("max")) {
+            rates.extend(STANDARD_RATES.iter().filter(|r| (min..=max).contains(*r)));
+        }
+    }
+    rates.sort_unstable();
+    rates.dedup();
+    rates
+}
+
+/// SPA audio format name (`S32LE`, `S24_32LE`, `F32P`, ...) to the formats
+/// QBZ outputs. Both 24-bit layouts map to `Int24`; planar and endianness
+/// suffixes are ignored.
+fn spa_sample_format(name: &str) -> Option<SampleFormatKind> {
+    let base = name.trim_end_matches('P');
+    let base = base
+        .strip_suffix("LE")
+        .or_else(|| base.strip_suffix("BE"))
+        .or_else(|| base.strip_suffix("_OE"))
+        .unwrap_or(base);
+    match base {
+        "S32" => Some(SampleFormatKind::Int32),
+        "S24" | "S24_32" => Some(SampleFormatKind::Int24),
+        "F32" => Some(SampleFormatKind::Float32),
+        "S16" => Some(SampleFormatKind::Int16),
+        _ => None,
+    }
+}
+
 impl AudioBackend for PipeWireBackend {
     fn backend_type(&self) -> AudioBackendType {
         AudioBackendType::PipeWire
@@ -1043,6 +1253,66 @@ mod pw_dump_tests {
         assert!(!pci.is_default);
     }
 
+    const FORMATS_FIXTURE: &str = r#"[
+      {
+        "id": 53, "type": "PipeWire:Interface:Node",
+        "info": {
+          "props": { "media.class": "Audio/Sink", "node.name": "alsa_output.usb-dac" },
+          "params": { "EnumFormat": [
+            { "mediaType": "audio", "mediaSubtype": "raw",
+              "format": { "default": "S32LE", "alt1": "S24_32LE", "alt2": "U8" },
+              "rate": { "default": 48000, "min": 44100, "max": 96000 },
+              "channels": 2 },
+            { "mediaType": "audio", "mediaSubtype": "iec958", "format": "S16LE",
+              "rate": 48000, "channels": 2 }
+          ] }
+        }
+      }
+    ]"#;
+
+    #[test]
+    fn enum_format_expands_choices_and_rate_ranges() {
+        use super::parse_node_formats;
+        use crate::backend::SampleFormat;
+        let formats = parse_node_formats(FORMATS_FIXTURE, "alsa_output.usb-dac");
+        // {S32, S24_32} x {44.1, 48, 88.2, 96 kHz} x stereo; U8 and the
+        // iec958 passthrough entry are dropped.
+        assert_eq!(formats.len(), 8);
+        assert!(formats.iter().all(|f| f.channels == 2));
+        assert!(formats
+            .iter()
+            .any(|f| f.sample_format == SampleFormat::Int24 && f.sample_rate == 88200));
+        assert!(!formats.iter().any(|f| f.sample_rate == 192000));
+        // The numeric object id finds the same node.
+        assert_eq!(parse_node_formats(FORMATS_FIXTURE, "53"), formats);
+        assert!(parse_node_formats(FORMATS_FIXTURE, "alsa_output.other").is_empty());
+    }
+
+    #[test]
+    fn node_latency_reads_quantum_and_alsa_period() {
+        use super::parse_node_latency;
+        let json = r#"[
+          { "id": 61, "type": "PipeWire:Interface:Node",
+            "info": { "props": {
+              "node.name": "alsa_output.usb-dac",
+              "node.latency": "1024/48000",
+              "audio.rate": 96000,
+              "api.alsa.period-size": 960,
+              "api.alsa.headroom": 96
+            } } }
+        ]"#;
+        let latency = parse_node_latency(json, "alsa_output.usb-dac").expect("latency");
+        assert!((latency.software_buffer_ms - 21.333).abs() < 0.01);
+        assert!((latency.device_latency_ms - 11.0).abs() < 0.01);
+        assert!((latency.total_latency_ms - 32.333).abs() < 0.01);
+        // Nothing to report without either prop.
+        assert_eq!(
+            parse_node_latency(FORMATS_FIXTURE, "alsa_output.usb-dac"),
+            None
+        );
+        assert_eq!(parse_node_latency(json, "alsa_output.other"), None);
+    }
+
     #[test]
     fn empty_or_garbage_json_yields_no_devices() {
         assert!(parse_pw_dump_sinks("not json").is_empty());
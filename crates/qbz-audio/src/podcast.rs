@@ -0,0 +1,549 @@
+//! Podcast mode: variable-speed playback and silence skipping for long-form
+//! speech (lectures, audiobooks).
+//!
+//! [`TimeStretch`] changes tempo without changing pitch using WSOLA
+//! (waveform-similarity overlap-add): Hann-windowed frames are taken from the
+//! input every `hop × speed` frames and overlap-added every `hop` frames; each
+//! frame's exact start is searched within a small tolerance for the offset
+//! that best continues the previous frame, which keeps voiced speech free of
+//! the phasiness plain OLA produces. The speed is read from a shared atomic
+//! per output hop, so a change applies mid-track. At 1.0× the search lands on
+//! the natural continuation and the output equals the input.
+//!
+//! [`SilenceSkip`] shortens pauses: once a run of silent 10ms blocks is longer
+//! than the configured gap, further silent blocks are dropped until sound
+//! returns. The media time dropped is added to a shared counter so position
+//! reporting can stay on the media clock.
+
+use std::num::NonZero;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::loudness::db_to_linear;
+
+/// Slowest accepted playback speed.
+pub const MIN_PLAYBACK_SPEED: f32 = 0.5;
+
+/// Fastest accepted playback speed.
+pub const MAX_PLAYBACK_SPEED: f32 = 3.0;
+
+/// Analysis frame length. 40ms spans a few pitch periods of any voice.
+const FRAME_MS: u32 = 40;
+
+/// How far (each way) a frame start may move to match the previous frame.
+const TOLERANCE_MS: u32 = 10;
+
+/// Correlation is computed on every n-th frame (and every n-th offset) —
+/// plenty for speech, a fraction of the cost.
+const SEARCH_DECIMATION: usize = 4;
+
+/// Block length the silence skipper classifies.
+const BLOCK_MS: u32 = 10;
+
+/// Peak level (dBFS) under which a block counts as silence. Above the noise
+/// floor of most spoken-word recordings, well below quiet speech.
+const SILENCE_DB: f32 = -50.0;
+
+/// `speed` clamped to the supported range (1.0 for NaN).
+pub fn clamp_speed(speed: f32) -> f32 {
+    if speed.is_nan() {
+        1.0
+    } else {
+        speed.clamp(MIN_PLAYBACK_SPEED, MAX_PLAYBACK_SPEED)
+    }
+}
+
+pub struct TimeStretch<S>
+where
+    S: Source<Item = f32>,
+{
+    inner: S,
+    channels: usize,
+    /// Playback speed, f32 stored as bits.
+    speed: Arc<AtomicU32>,
+    frame_len: usize,
+    hop: usize,
+    tolerance: usize,
+    /// Periodic Hann window; halves `hop` apart sum to 1.
+    window: Vec<f32>,
+    /// Buffered input, interleaved, starting at absolute frame `input_start`.
+    input: Vec<f32>,
+    /// Channel sum of `input`, one value per frame (the search signal).
+    mono: Vec<f32>,
+    input_start: usize,
+    /// Absolute input frame where the next analysis frame nominally starts.
+    nominal: f64,
+    /// Start of the previous analysis frame; None before the first.
+    prev: Option<usize>,
+    /// Overlap-add accumulator, `frame_len` frames.
+    accum: Vec<f32>,
+    /// Finished output, interleaved; `pos` is the next sample.
+    out: Vec<f32>,
+    pos: usize,
+    exhausted: bool,
+    done: bool,
+}
+
+impl<S> TimeStretch<S>
+where
+    S: Source<Item = f32>,
+{
+    /// Stretch `source` to the speed held in `speed` (f32 bits, clamped to
+    /// [`MIN_PLAYBACK_SPEED`]..=[`MAX_PLAYBACK_SPEED`]).
+    pub fn new(source: S, speed: Arc<AtomicU32>) -> Self {
+        let channels = source.channels().get() as usize;
+        let rate = source.sample_rate().get() as usize;
+        let hop = (rate * FRAME_MS as usize / 2000).max(1);
+        let frame_len = hop * 2;
+        let window = (0..frame_len)
+            .map(|i| {
+                let phase = std::f32::consts::PI * i as f32 / frame_len as f32;
+                phase.sin().powi(2)
+            })
+            .collect();
+        Self {
+            inner: source,
+            channels,
+            speed,
+            frame_len,
+            hop,
+            tolerance: rate * TOLERANCE_MS as usize / 1000,
+            window,
+            input: Vec::new(),
+            mono: Vec::new(),
+            input_start: 0,
+            nominal: 0.0,
+            prev: None,
+            accum: vec![0.0; frame_len * channels],
+            out: Vec::with_capacity(hop * channels),
+            pos: 0,
+            exhausted: false,
+            done: false,
+        }
+    }
+
+    fn current_speed(&self) -> f32 {
+        clamp_speed(f32::from_bits(self.speed.load(Ordering::Relaxed)))
+    }
+
+    /// Absolute frame one past the buffered input.
+    fn input_end(&self) -> usize {
+        self.input_start + self.mono.len()
+    }
+
+    /// Buffer input until absolute frame `end` (or the source ends).
+    fn fill_to(&mut self, end: usize) {
+        while !self.exhausted && self.input_end() < end {
+            let mut sum = 0.0;
+            for ch in 0..self.channels {
+                match self.inner.next() {
+                    Some(sample) => {
+                        self.input.push(sample);
+                        sum += sample;
+                    }
+                    None => {
+                        // Drop a truncated trailing frame.
+                        self.input.truncate(self.input.len() - ch);
+                        self.exhausted = true;
+                        return;
+                    }
+                }
+            }
+            self.mono.push(sum);
+        }
+    }
+
+    fn sample_at(&self, frame: usize, ch: usize) -> f32 {
+        if frame < self.input_start || frame >= self.input_end() {
+            return 0.0;
+        }
+        self.input[(frame - self.input_start) * self.channels + ch]
+    }
+
+    fn mono_at(&self, frame: usize) -> f32 {
+        if frame < self.input_start || frame >= self.input_end() {
+            return 0.0;
+        }
+        self.mono[frame - self.input_start]
+    }
+
+    /// Normalized correlation of the frame at `candidate` with the frame at
+    /// `target`, over the decimated sample set.
+    fn similarity(&self, candidate: usize, target: usize) -> f32 {
+        let mut cross = 0.0f32;
+        let mut energy = 0.0f32;
+        for i in (0..self.frame_len).step_by(SEARCH_DECIMATION) {
+            let c = self.mono_at(candidate + i);
+            cross += c * self.mono_at(target + i);
+            energy += c * c;
+        }
+        if energy <= f32::EPSILON {
+            0.0
+        } else {
+            cross / energy.sqrt()
+        }
+    }
+
+    /// Start of the next analysis frame: the offset within the tolerance of
+    /// `nominal` that best continues the previous frame. The nominal start
+    /// itself is tried first, so ties keep it.
+    fn best_start(&self, nominal: usize) -> usize {
+        let Some(prev) = self.prev else {
+            return nominal;
+        };
+        let target = prev + self.hop;
+        let low = nominal.saturating_sub(self.tolerance).max(self.input_start);
+        let high = nominal + self.tolerance;
+        let mut best = nominal;
+        let mut best_score = self.similarity(nominal, target);
+        for candidate in (low..=high).step_by(SEARCH_DECIMATION) {
+            let score = self.similarity(candidate, target);
+            if score > best_score {
+                best = candidate;
+                best_score = score;
+            }
+        }
+        best
+    }
+
+    /// Produce the next `hop` output frames. Returns false once the input is
+    /// used up and the accumulator drained.
+    fn refill(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let nominal = self.nominal.round() as usize;
+        let continuation = self.prev.map_or(0, |p| p + self.hop);
+        self.fill_to(nominal.max(continuation) + self.tolerance + self.frame_len);
+
+        let (hop, channels) = (self.hop, self.channels);
+        self.out.clear();
+        self.pos = 0;
+        if self.exhausted && nominal >= self.input_end() {
+            // Drain the fading tail of the last frame and stop.
+            self.done = true;
+            if self.prev.is_none() {
+                return false;
+            }
+            self.out.extend_from_slice(&self.accum[..hop * channels]);
+            return true;
+        }
+
+        let start = self.best_start(nominal);
+        if self.prev.is_none() {
+            // Prime with the missing falling half so the first hop isn't a
+            // fade-in.
+            for i in 0..hop {
+                for ch in 0..channels {
+                    self.accum[i * channels + ch] += self.window[i + hop] * self.sample_at(i, ch);
+                }
+            }
+        }
+        for i in 0..self.frame_len {
+            let w = self.window[i];
+            for ch in 0..channels {
+                self.accum[i * channels + ch] += w * self.sample_at(start + i, ch);
+            }
+        }
+        self.out.extend_from_slice(&self.accum[..hop * channels]);
+        self.accum.copy_within(hop * channels.., 0);
+        let tail = self.accum.len() - hop * channels;
+        self.accum[tail..].fill(0.0);
+
+        self.prev = Some(start);
+        self.nominal += hop as f64 * self.current_speed() as f64;
+
+        // Forget input no later frame can reach.
+        let keep_from = (self.nominal.round() as usize)
+            .saturating_sub(self.tolerance)
+            .min(start + hop);
+        if keep_from > self.input_start {
+            let drop = (keep_from - self.input_start).min(self.mono.len());
+            self.mono.drain(..drop);
+            self.input.drain(..drop * channels);
+            self.input_start += drop;
+        }
+        true
+    }
+}
+
+impl<S> Iterator for TimeStretch<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.out.len() && !self.refill() {
+            return None;
+        }
+        let sample = self.out[self.pos];
+        self.pos += 1;
+        Some(sample)
+    }
+}
+
+impl<S> Source for TimeStretch<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.inner.sample_rate()
+    }
+
+    /// The inner duration at the current speed.
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner
+            .total_duration()
+            .map(|d| d.div_f32(self.current_speed()))
+    }
+}
+
+pub struct SilenceSkip<S>
+where
+    S: Source<Item = f32>,
+{
+    inner: S,
+    channels: usize,
+    rate: u64,
+    block_frames: usize,
+    /// Silent frames kept before skipping starts.
+    keep_frames: u64,
+    level: f32,
+    silent_run: u64,
+    skipped_frames: u64,
+    /// Media milliseconds dropped so far, shared with position reporting.
+    skipped_ms: Arc<AtomicU64>,
+    published_ms: u64,
+    block: Vec<f32>,
+    pos: usize,
+}
+
+impl<S> SilenceSkip<S>
+where
+    S: Source<Item = f32>,
+{
+    /// Drop silence beyond the first `max_gap_ms` of every pause, adding the
+    /// dropped time to `skipped_ms`.
+    pub fn new(source: S, max_gap_ms: u32, skipped_ms: Arc<AtomicU64>) -> Self {
+        let channels = source.channels().get() as usize;
+        let rate = source.sample_rate().get() as u64;
+        let block_frames = ((rate * BLOCK_MS as u64) / 1000).max(1) as usize;
+        Self {
+            inner: source,
+            channels,
+            rate,
+            block_frames,
+            keep_frames: rate * max_gap_ms as u64 / 1000,
+            level: db_to_linear(SILENCE_DB),
+            silent_run: 0,
+            skipped_frames: 0,
+            skipped_ms,
+            published_ms: 0,
+            block: Vec::with_capacity(block_frames * channels),
+            pos: 0,
+        }
+    }
+
+    /// Read the next block worth playing. Returns false at the end.
+    fn refill(&mut self) -> bool {
+        loop {
+            self.block.clear();
+            self.pos = 0;
+            for _ in 0..self.block_frames * self.channels {
+                match self.inner.next() {
+                    Some(sample) => self.block.push(sample),
+                    None => break,
+                }
+            }
+            if self.block.is_empty() {
+                return false;
+            }
+            let frames = (self.block.len() / self.channels) as u64;
+            let peak = self.block.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+            if peak >= self.level {
+                self.silent_run = 0;
+                return true;
+            }
+            self.silent_run += frames;
+            if self.silent_run <= self.keep_frames {
+                return true;
+            }
+            self.skipped_frames += frames;
+            let ms = self.skipped_frames * 1000 / self.rate;
+            if ms > self.published_ms {
+                self.skipped_ms
+                    .fetch_add(ms - self.published_ms, Ordering::Relaxed);
+                self.published_ms = ms;
+            }
+        }
+    }
+}
+
+impl<S> Iterator for SilenceSkip<S>
+where
+    S: Source<Item = f32>,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.block.len() && !self.refill() {
+            return None;
+        }
+        let sample = self.block[self.pos];
+        self.pos += 1;
+        Some(sample)
+    }
+}
+
+impl<S> Source for SilenceSkip<S>
+where
+    S: Source<Item = f32>,
+{
+    #[inline]
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> NonZero<u16> {
+        self.inner.channels()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> NonZero<u32> {
+        self.inner.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    const RATE: u32 = 44_100;
+
+    fn stereo(samples: Vec<f32>) -> SamplesBuffer {
+        SamplesBuffer::new(
+            NonZero::new(2u16).unwrap(),
+            NonZero::new(RATE).unwrap(),
+            samples,
+        )
+    }
+
+    fn sine(freq: f32, secs: f32) -> Vec<f32> {
+        let frames = (RATE as f32 * secs) as usize;
+        (0..frames)
+            .flat_map(|n| {
+                let s = 0.5 * (2.0 * std::f32::consts::PI * freq * n as f32 / RATE as f32).sin();
+                [s, s]
+            })
+            .collect()
+    }
+
+    fn speed(value: f32) -> Arc<AtomicU32> {
+        Arc::new(AtomicU32::new(value.to_bits()))
+    }
+
+    /// Upward zero crossings per second of the left channel.
+    fn frequency(samples: &[f32]) -> f32 {
+        let left: Vec<f32> = samples.iter().step_by(2).copied().collect();
+        let crossings = left
+            .windows(2)
+            .filter(|w| w[0] < 0.0 && w[1] >= 0.0)
+            .count();
+        crossings as f32 * RATE as f32 / left.len() as f32
+    }
+
+    #[test]
+    fn unit_speed_reproduces_the_input() {
+        let input = sine(220.0, 1.0);
+        let output: Vec<f32> = TimeStretch::new(stereo(input.clone()), speed(1.0)).collect();
+        assert!(output.len() >= input.len());
+        let worst = input
+            .iter()
+            .zip(&output)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0f32, f32::max);
+        assert!(worst < 1e-4, "max deviation {worst}");
+    }
+
+    #[test]
+    fn speed_changes_length_but_not_pitch() {
+        let input = sine(220.0, 2.0);
+        for factor in [0.5f32, 1.5, 2.0, 3.0] {
+            let output: Vec<f32> = TimeStretch::new(stereo(input.clone()), speed(factor)).collect();
+            let expected = input.len() as f32 / factor;
+            let ratio = output.len() as f32 / expected;
+            assert!(
+                (0.97..1.05).contains(&ratio),
+                "{factor}x: length ratio {ratio}"
+            );
+            let pitch = frequency(&output);
+            assert!((pitch - 220.0).abs() < 6.0, "{factor}x: {pitch} Hz");
+        }
+    }
+
+    #[test]
+    fn reported_duration_follows_the_speed() {
+        let stretch = TimeStretch::new(stereo(sine(220.0, 2.0)), speed(2.0));
+        let duration = stretch.total_duration().unwrap();
+        assert!((duration.as_secs_f32() - 1.0).abs() < 0.01);
+        assert_eq!(clamp_speed(10.0), MAX_PLAYBACK_SPEED);
+        assert_eq!(clamp_speed(0.1), MIN_PLAYBACK_SPEED);
+        assert_eq!(clamp_speed(f32::NAN), 1.0);
+    }
+
+    #[test]
+    fn long_pauses_are_shortened_and_counted() {
+        // 0.5s tone, 2s silence, 0.5s tone.
+        let mut input = sine(220.0, 0.5);
+        input.extend(vec![0.0; RATE as usize * 2 * 2]);
+        input.extend(sine(220.0, 0.5));
+        let skipped = Arc::new(AtomicU64::new(0));
+        let output: Vec<f32> =
+            SilenceSkip::new(stereo(input.clone()), 300, skipped.clone()).collect();
+
+        // Everything but ~300ms of the pause is dropped.
+        let kept_ms = output.len() as u64 * 1000 / (RATE as u64 * 2);
+        assert!((1_290..=1_320).contains(&kept_ms), "kept {kept_ms}ms");
+        let skipped = skipped.load(Ordering::Relaxed);
+        assert!((1_690..=1_710).contains(&skipped), "skipped {skipped}ms");
+        // The tone itself passes untouched.
+        assert_eq!(output[..1000], input[..1000]);
+    }
+
+    #[test]
+    fn short_pauses_pass_through() {
+        let mut input = sine(220.0, 0.5);
+        input.extend(vec![0.0; RATE as usize / 5 * 2]);
+        input.extend(sine(220.0, 0.5));
+        let skipped = Arc::new(AtomicU64::new(0));
+        let output: Vec<f32> =
+            SilenceSkip::new(stereo(input.clone()), 300, skipped.clone()).collect();
+        assert_eq!(output, input);
+        assert_eq!(skipped.load(Ordering::Relaxed), 0);
+    }
+}
@@ -5,7 +5,7 @@
 //! NOTE: Tauri command wrappers remain in qbz-nix. This module contains only
 //! the core types and persistence logic.
 
-use crate::{AlsaPlugin, AudioBackendType};
+use crate::{AlsaPlugin, AudioBackendType, EqBand};
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -80,12 +80,69 @@ pub struct AudioSettings {
     /// everything else converts.
     #[serde(default = "default_dsd_mode")]
     pub dsd_mode: String,
+    /// Software parametric EQ bands (per-device headphone curves). Empty
+    /// (default) = no DSP in the render path, output stays bit-perfect.
+    #[serde(default)]
+    pub eq_bands: Vec<EqBand>,
+    /// Track-to-track crossfade length in milliseconds (0 = disabled, hard
+    /// gapless cut). Rides the gapless pre-queue, so it only applies between
+    /// same-format tracks; suppressed while repeat-one is active.
+    #[serde(default)]
+    pub crossfade_ms: u32,
+    /// Crossfade length for the repeat-all wrap from the last queue track
+    /// back to the first, set independently of `crossfade_ms` (0 = gapless
+    /// cut at the wrap).
+    #[serde(default)]
+    pub repeat_crossfade_ms: u32,
+    /// Skip leading/trailing silence of local files: playback starts at the
+    /// first audible moment and ends after the last (see `silence`).
+    #[serde(default)]
+    pub skip_silence: bool,
+    /// Adaptive quality: below this measured bandwidth (Mb/s), Hi-Res
+    /// streaming requests are capped at CD quality. 0 = disabled.
+    #[serde(default)]
+    pub hires_min_mbps: f32,
+    /// Adaptive quality: below this measured bandwidth (Mb/s), streaming
+    /// requests are capped at MP3. 0 = disabled.
+    #[serde(default)]
+    pub cd_min_mbps: f32,
+    /// True-peak ceiling (dBTP) for the limiter that follows normalization
+    /// gain (see `limiter`). None = limiter off. Only engaged while
+    /// normalization is on, so the default pipeline stays bit-perfect.
+    #[serde(default = "default_true_peak_limit_dbfs")]
+    pub true_peak_limit_dbfs: Option<f32>,
+    /// Podcast mode: time-stretch playback to `playback_speed` (pitch kept)
+    /// and shorten pauses (see `podcast`).
+    #[serde(default)]
+    pub podcast_mode: bool,
+    /// Podcast-mode speed, `MIN_PLAYBACK_SPEED..=MAX_PLAYBACK_SPEED`.
+    #[serde(default = "default_playback_speed")]
+    pub playback_speed: f32,
+    /// Podcast mode: pauses longer than this (ms) are cut down to it.
+    /// 0 = silence skipping off.
+    #[serde(default)]
+    pub skip_silence_threshold_ms: u32,
 }
 
+/// Upper bound for `crossfade_ms`. The gapless pre-queue requests the next
+/// track 10s before the end, so a longer fade could never start on time.
+pub const MAX_CROSSFADE_MS: u32 = 10_000;
+
 fn default_dsd_mode() -> String {
     "convert".to_string()
 }
 
+/// Lowest accepted true-peak ceiling, dBTP.
+pub const MIN_TRUE_PEAK_LIMIT_DBFS: f32 = -12.0;
+
+fn default_true_peak_limit_dbfs() -> Option<f32> {
+    Some(-1.0)
+}
+
+fn default_playback_speed() -> f32 {
+    1.0
+}
+
 impl Default for AudioSettings {
     fn default() -> Self {
         Self {
@@ -123,10 +180,66 @@ impl Default for AudioSettings {
             allow_quality_fallback: false, // Off by default — fail rather than silently downgrade
             reserve_dac_while_running: false, // Off by default — opt-in DAC reservation (Lifetime B)
             dsd_mode: default_dsd_mode(), // "convert" — safe on every DAC
+            eq_bands: Vec::new(), // No EQ by default — bit-perfect
+            crossfade_ms: 0, // Off by default — hard gapless transitions
+            repeat_crossfade_ms: 0, // Off by default — hard cut at the repeat-all wrap
+            skip_silence: false, // Off by default — play files exactly as recorded
+            hires_min_mbps: 0.0, // Off by default — no bandwidth probe
+            cd_min_mbps: 0.0, // Off by default — no bandwidth probe
+            true_peak_limit_dbfs: default_true_peak_limit_dbfs(), // -1 dBTP, EBU R128 delivery ceiling
+            podcast_mode: false, // Off by default — music plays untouched
+            playback_speed: default_playback_speed(), // 1.0× until the user picks a speed
+            skip_silence_threshold_ms: 0, // Off by default — pauses kept as recorded
         }
     }
 }
 
+/// Per-playlist overrides of the crossfade and normalization settings,
+/// applied while a queue launched from that playlist is playing. `None`
+/// fields fall through to the global setting.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlaylistAudioOverride {
+    pub playlist_id: u64,
+    pub crossfade_ms: Option<u32>,
+    pub normalization_enabled: Option<bool>,
+    pub normalization_target_lufs: Option<f32>,
+}
+
+impl PlaylistAudioOverride {
+    /// Whether no field overrides anything.
+    pub fn is_empty(&self) -> bool {
+        self.crossfade_ms.is_none()
+            && self.normalization_enabled.is_none()
+            && self.normalization_target_lufs.is_none()
+    }
+
+    /// Overlay the set fields onto `settings`.
+    pub fn apply_to(&self, settings: &mut AudioSettings) {
+        if let Some(ms) = self.crossfade_ms {
+            settings.crossfade_ms = ms.min(MAX_CROSSFADE_MS);
+        }
+        if let Some(enabled) = self.normalization_enabled {
+            settings.normalization_enabled = enabled;
+        }
+        if let Some(target) = self.normalization_target_lufs {
+            settings.normalization_target_lufs = target;
+        }
+    }
+}
+
+/// Checkpoints kept in `previous_audio_settings`; older ones are pruned.
+const MAX_CHECKPOINTS: i64 = 20;
+
+/// A saved copy of the audio settings, taken before a change so the change
+/// can be undone with [`AudioSettingsStore::rollback`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsCheckpoint {
+    pub id: i64,
+    /// Unix seconds.
+    pub created_at: i64,
+    pub settings: AudioSettings,
+}
+
 pub struct AudioSettingsStore {
     conn: Connection,
 }
@@ -233,6 +346,50 @@ impl AudioSettingsStore {
             "ALTER TABLE audio_settings ADD COLUMN dsd_mode TEXT DEFAULT 'convert'",
             [],
         );
+        let _ = conn.execute("ALTER TABLE audio_settings ADD COLUMN eq_bands TEXT", []);
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN crossfade_ms INTEGER DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN hires_min_mbps REAL DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN cd_min_mbps REAL DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN repeat_crossfade_ms INTEGER DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN skip_silence INTEGER DEFAULT 0",
+            [],
+        );
+        // NULL = limiter off, so the column needs its own "unset" marker:
+        // `true_peak_limit_set` stays 0 until the user first touches it and
+        // the read falls back to the -1 dBTP default.
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN true_peak_limit_dbfs REAL",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN true_peak_limit_set INTEGER DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN podcast_mode INTEGER DEFAULT 0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN playback_speed REAL DEFAULT 1.0",
+            [],
+        );
+        let _ = conn.execute(
+            "ALTER TABLE audio_settings ADD COLUMN skip_silence_threshold_ms INTEGER DEFAULT 0",
+            [],
+        );
 
         // Seed the single settings row on first run with the OOTB default backend
         // ("System"). INSERT OR IGNORE is a one-time seed: it only fires when the
@@ -252,6 +409,25 @@ impl AudioSettingsStore {
         )
         .map_err(|e| format!("Failed to seed audio settings row: {}", e))?;
 
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS previous_audio_settings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at INTEGER NOT NULL,
+                settings TEXT NOT NULL
+            );",
+        )
+        .map_err(|e| format!("Failed to create audio settings checkpoint table: {}", e))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS playlist_audio_overrides (
+                playlist_id INTEGER PRIMARY KEY,
+                crossfade_ms INTEGER,
+                normalization_enabled INTEGER,
+                normalization_target_lufs REAL
+            );",
+        )
+        .map_err(|e| format!("Failed to create playlist audio overrides table: {}", e))?;
+
         // One-time backfill (#638 Phase C / F10): installs that first ran a
         // pre-#45 build had `limit_quality_to_device` backfilled to 1 by the
         // original DEFAULT-1 migration and still read `true` today. That was
@@ -298,7 +474,7 @@ impl AudioSettingsStore {
     pub fn get_settings(&self) -> Result<AudioSettings, String> {
         self.conn
             .query_row(
-                "SELECT output_device, exclusive_mode, dac_passthrough, preferred_sample_rate, backend_type, alsa_plugin, alsa_hardware_volume, stream_first_track, stream_buffer_seconds, streaming_only, limit_quality_to_device, device_max_sample_rate, normalization_enabled, normalization_target_lufs, gapless_enabled, device_sample_rate_limits, pw_force_bitperfect, sync_audio_on_startup, quality_fallback_behavior, skip_sink_switch, allow_quality_fallback, reserve_dac_while_running, dsd_mode FROM audio_settings WHERE id = 1",
+                "SELECT output_device, exclusive_mode, dac_passthrough, preferred_sample_rate, backend_type, alsa_plugin, alsa_hardware_volume, stream_first_track, stream_buffer_seconds, streaming_only, limit_quality_to_device, device_max_sample_rate, normalization_enabled, normalization_target_lufs, gapless_enabled, device_sample_rate_limits, pw_force_bitperfect, sync_audio_on_startup, quality_fallback_behavior, skip_sink_switch, allow_quality_fallback, reserve_dac_while_running, dsd_mode, eq_bands, crossfade_ms, hires_min_mbps, cd_min_mbps, repeat_crossfade_ms, skip_silence, true_peak_limit_dbfs, true_peak_limit_set, podcast_mode, playback_speed, skip_silence_threshold_ms FROM audio_settings WHERE id = 1",
                 [],
                 |row| {
                     // Parse backend_type from JSON string
@@ -317,6 +493,12 @@ impl AudioSettingsStore {
                         .and_then(|s| serde_json::from_str(&s).ok())
                         .unwrap_or_default();
 
+                    // Parse eq_bands from JSON string
+                    let eq_bands: Vec<EqBand> = row
+                        .get::<_, Option<String>>(23)?
+                        .and_then(|s| serde_json::from_str(&s).ok())
+                        .unwrap_or_default();
+
                     Ok(AudioSettings {
                         output_device: row.get(0)?,
                         exclusive_mode: row.get::<_, i64>(1)? != 0,
@@ -348,6 +530,25 @@ impl AudioSettingsStore {
                         dsd_mode: row
                             .get::<_, Option<String>>(22)?
                             .unwrap_or_else(default_dsd_mode),
+                        eq_bands,
+                        crossfade_ms: row.get::<_, Option<i64>>(24)?.unwrap_or(0) as u32,
+                        hires_min_mbps: row.get::<_, Option<f64>>(25)?.unwrap_or(0.0) as f32,
+                        cd_min_mbps: row.get::<_, Option<f64>>(26)?.unwrap_or(0.0) as f32,
+                        repeat_crossfade_ms: row.get::<_, Option<i64>>(27)?.unwrap_or(0) as u32,
+                        skip_silence: row.get::<_, Option<i64>>(28)?.unwrap_or(0) != 0,
+                        true_peak_limit_dbfs: if row.get::<_, Option<i64>>(30)?.unwrap_or(0) != 0 {
+                            row.get::<_, Option<f64>>(29)?.map(|db| db as f32)
+                        } else {
+                            default_true_peak_limit_dbfs()
+                        },
+                        podcast_mode: row.get::<_, Option<i64>>(31)?.unwrap_or(0) != 0,
+                        playback_speed: row
+                            .get::<_, Option<f64>>(32)?
+                            .map(|speed| speed as f32)
+                            .unwrap_or_else(default_playback_speed),
+                        skip_silence_threshold_ms: row
+                            .get::<_, Option<i64>>(33)?
+                            .unwrap_or(0) as u32,
                     })
                 },
             )
@@ -580,6 +781,61 @@ impl AudioSettingsStore {
         Ok(())
     }
 
+    pub fn set_skip_silence(&self, enabled: bool) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET skip_silence = ?1 WHERE id = 1",
+                params![enabled as i64],
+            )
+            .map_err(|e| format!("Failed to set skip silence: {}", e))?;
+        Ok(())
+    }
+
+    /// Persist the true-peak ceiling (None = limiter off), clamped to
+    /// `MIN_TRUE_PEAK_LIMIT_DBFS..=0`.
+    pub fn set_true_peak_limit_dbfs(&self, limit: Option<f32>) -> Result<(), String> {
+        let clamped = limit.map(|db| db.clamp(MIN_TRUE_PEAK_LIMIT_DBFS, 0.0));
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET true_peak_limit_dbfs = ?1, true_peak_limit_set = 1 WHERE id = 1",
+                params![clamped.map(|db| db as f64)],
+            )
+            .map_err(|e| format!("Failed to set true peak limit: {}", e))?;
+        Ok(())
+    }
+
+    pub fn set_podcast_mode(&self, enabled: bool) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET podcast_mode = ?1 WHERE id = 1",
+                params![enabled as i64],
+            )
+            .map_err(|e| format!("Failed to set podcast mode: {}", e))?;
+        Ok(())
+    }
+
+    /// Persist the podcast-mode speed, clamped to
+    /// `MIN_PLAYBACK_SPEED..=MAX_PLAYBACK_SPEED`.
+    pub fn set_playback_speed(&self, speed: f32) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET playback_speed = ?1 WHERE id = 1",
+                params![crate::podcast::clamp_speed(speed) as f64],
+            )
+            .map_err(|e| format!("Failed to set playback speed: {}", e))?;
+        Ok(())
+    }
+
+    pub fn set_skip_silence_threshold_ms(&self, ms: u32) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET skip_silence_threshold_ms = ?1 WHERE id = 1",
+                params![ms as i64],
+            )
+            .map_err(|e| format!("Failed to set skip silence threshold: {}", e))?;
+        Ok(())
+    }
+
     pub fn set_allow_quality_fallback(&self, enabled: bool) -> Result<(), String> {
         self.conn
             .execute(
@@ -626,6 +882,67 @@ impl AudioSettingsStore {
         Ok(())
     }
 
+    /// Persist the parametric EQ band list. An empty list disables the EQ.
+    /// Applying it to the live render path is the caller's responsibility
+    /// (`Player::reload_settings` installs the chain).
+    pub fn set_eq_bands(&self, bands: &[EqBand]) -> Result<(), String> {
+        let json = serde_json::to_string(bands)
+            .map_err(|e| format!("Failed to serialize EQ bands: {}", e))?;
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET eq_bands = ?1 WHERE id = 1",
+                params![json],
+            )
+            .map_err(|e| format!("Failed to set EQ bands: {}", e))?;
+        Ok(())
+    }
+
+    pub fn get_eq_bands(&self) -> Result<Vec<EqBand>, String> {
+        Ok(self.get_settings()?.eq_bands)
+    }
+
+    /// Persist the crossfade length (0 = disabled), clamped to
+    /// `MAX_CROSSFADE_MS`.
+    pub fn set_crossfade_ms(&self, ms: u32) -> Result<(), String> {
+        let clamped = ms.min(MAX_CROSSFADE_MS);
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET crossfade_ms = ?1 WHERE id = 1",
+                params![clamped as i64],
+            )
+            .map_err(|e| format!("Failed to set crossfade duration: {}", e))?;
+        Ok(())
+    }
+
+    /// Persist the repeat-all wrap crossfade length (0 = gapless cut),
+    /// clamped to `MAX_CROSSFADE_MS`.
+    pub fn set_repeat_crossfade_ms(&self, ms: u32) -> Result<(), String> {
+        let clamped = ms.min(MAX_CROSSFADE_MS);
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET repeat_crossfade_ms = ?1 WHERE id = 1",
+                params![clamped as i64],
+            )
+            .map_err(|e| format!("Failed to set repeat crossfade duration: {}", e))?;
+        Ok(())
+    }
+
+    /// Persist the adaptive-quality bandwidth thresholds in Mb/s (0 =
+    /// disabled). Negative values are stored as 0.
+    pub fn set_bandwidth_thresholds(
+        &self,
+        hires_min_mbps: f32,
+        cd_min_mbps: f32,
+    ) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE audio_settings SET hires_min_mbps = ?1, cd_min_mbps = ?2 WHERE id = 1",
+                params![hires_min_mbps.max(0.0) as f64, cd_min_mbps.max(0.0) as f64],
+            )
+            .map_err(|e| format!("Failed to set bandwidth thresholds: {}", e))?;
+        Ok(())
+    }
+
     pub fn set_pw_force_bitperfect(&self, enabled: bool) -> Result<(), String> {
         self.conn
             .execute(
@@ -679,28 +996,158 @@ impl AudioSettingsStore {
         Ok(())
     }
 
-    /// Reset all audio settings to their default values
-    pub fn reset_all(&self) -> Result<AudioSettings, String> {
-        // ADR-003: quality_fallback_behavior must survive reset_all()
-        let saved_fallback = self
-            .get_quality_fallback_behavior()
-            .unwrap_or_else(|_| "ask".to_string());
+    /// The audio override for `playlist_id`, if one is stored.
+    pub fn get_playlist_override(
+        &self,
+        playlist_id: u64,
+    ) -> Result<Option<PlaylistAudioOverride>, String> {
+        let result = self.conn.query_row(
+            "SELECT crossfade_ms, normalization_enabled, normalization_target_lufs
+             FROM playlist_audio_overrides WHERE playlist_id = ?1",
+            params![playlist_id as i64],
+            |row| {
+                Ok(PlaylistAudioOverride {
+                    playlist_id,
+                    crossfade_ms: row.get::<_, Option<i64>>(0)?.map(|v| v as u32),
+                    normalization_enabled: row.get::<_, Option<i64>>(1)?.map(|v| v != 0),
+                    normalization_target_lufs: row.get::<_, Option<f64>>(2)?.map(|v| v as f32),
+                })
+            },
+        );
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to get playlist audio override: {}", e)),
+        }
+    }
 
-        let defaults = AudioSettings::default();
-        let backend_json: Option<String> = defaults
+    /// Store an override, replacing any previous one for the playlist. An
+    /// override with no fields set removes the row.
+    pub fn set_playlist_override(&self, value: &PlaylistAudioOverride) -> Result<(), String> {
+        if value.is_empty() {
+            self.conn
+                .execute(
+                    "DELETE FROM playlist_audio_overrides WHERE playlist_id = ?1",
+                    params![value.playlist_id as i64],
+                )
+                .map_err(|e| format!("Failed to remove playlist audio override: {}", e))?;
+            return Ok(());
+        }
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO playlist_audio_overrides
+                 (playlist_id, crossfade_ms, normalization_enabled, normalization_target_lufs)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    value.playlist_id as i64,
+                    value.crossfade_ms.map(|ms| ms.min(MAX_CROSSFADE_MS) as i64),
+                    value.normalization_enabled.map(|enabled| enabled as i64),
+                    value.normalization_target_lufs.map(|target| target as f64),
+                ],
+            )
+            .map_err(|e| format!("Failed to set playlist audio override: {}", e))?;
+        Ok(())
+    }
+
+    /// Save the current settings as a checkpoint. When they match the most
+    /// recent checkpoint that one is returned instead, so repeated calls
+    /// without a change in between don't stack duplicates.
+    pub fn checkpoint(&self) -> Result<SettingsCheckpoint, String> {
+        let settings = self.get_settings()?;
+        let json = serde_json::to_string(&settings)
+            .map_err(|e| format!("Failed to serialize audio settings: {}", e))?;
+        if let Some(latest) = self.latest_checkpoint()? {
+            if serde_json::to_string(&latest.settings).ok().as_deref() == Some(json.as_str()) {
+                return Ok(latest);
+            }
+        }
+
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.conn
+            .execute(
+                "INSERT INTO previous_audio_settings (created_at, settings) VALUES (?1, ?2)",
+                params![created_at, json],
+            )
+            .map_err(|e| format!("Failed to save audio settings checkpoint: {}", e))?;
+        let id = self.conn.last_insert_rowid();
+        self.conn
+            .execute(
+                "DELETE FROM previous_audio_settings WHERE id <= ?1",
+                params![id - MAX_CHECKPOINTS],
+            )
+            .map_err(|e| format!("Failed to prune audio settings checkpoints: {}", e))?;
+
+        Ok(SettingsCheckpoint {
+            id,
+            created_at,
+            settings,
+        })
+    }
+
+    /// The most recent checkpoint, if any.
+    pub fn latest_checkpoint(&self) -> Result<Option<SettingsCheckpoint>, String> {
+        let result = self.conn.query_row(
+            "SELECT id, created_at, settings FROM previous_audio_settings
+             ORDER BY id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        );
+        let (id, created_at, json) = match result {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(format!("Failed to get audio settings checkpoint: {}", e)),
+        };
+        let settings = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse audio settings checkpoint: {}", e))?;
+        Ok(Some(SettingsCheckpoint {
+            id,
+            created_at,
+            settings,
+        }))
+    }
+
+    /// Restore the settings saved in `checkpoint` and drop it (and any newer
+    /// checkpoint), so the next rollback steps further back.
+    /// `quality_fallback_behavior` is left alone, as in `reset_all`.
+    pub fn rollback(&self, checkpoint: &SettingsCheckpoint) -> Result<(), String> {
+        self.write_settings(&checkpoint.settings)?;
+        self.set_dsd_mode(&checkpoint.settings.dsd_mode)?;
+        self.conn
+            .execute(
+                "DELETE FROM previous_audio_settings WHERE id >= ?1",
+                params![checkpoint.id],
+            )
+            .map_err(|e| format!("Failed to drop audio settings checkpoint: {}", e))?;
+        Ok(())
+    }
+
+    /// Write every audio column except `dsd_mode` and
+    /// `quality_fallback_behavior` (shared by `reset_all` and `rollback`).
+    fn write_settings(&self, settings: &AudioSettings) -> Result<(), String> {
+        let backend_json: Option<String> = settings
             .backend_type
             .map(|b| serde_json::to_string(&b))
             .transpose()
             .map_err(|e| format!("Failed to serialize backend type: {}", e))?;
-        let plugin_json: Option<String> = defaults
+        let plugin_json: Option<String> = settings
             .alsa_plugin
             .map(|p| serde_json::to_string(&p))
             .transpose()
             .map_err(|e| format!("Failed to serialize ALSA plugin: {}", e))?;
 
-        // Serialize per-device limits (empty on reset)
-        let limits_json = serde_json::to_string(&defaults.device_sample_rate_limits)
+        let limits_json = serde_json::to_string(&settings.device_sample_rate_limits)
             .map_err(|e| format!("Failed to serialize device sample rate limits: {}", e))?;
+        let eq_json = serde_json::to_string(&settings.eq_bands)
+            .map_err(|e| format!("Failed to serialize EQ bands: {}", e))?;
 
         self.conn
             .execute(
@@ -725,33 +1172,66 @@ impl AudioSettingsStore {
                     sync_audio_on_startup = ?18,
                     skip_sink_switch = ?19,
                     allow_quality_fallback = ?20,
-                    reserve_dac_while_running = ?21
+                    reserve_dac_while_running = ?21,
+                    eq_bands = ?22,
+                    crossfade_ms = ?23,
+                    hires_min_mbps = ?24,
+                    cd_min_mbps = ?25,
+                    repeat_crossfade_ms = ?26,
+                    skip_silence = ?27,
+                    true_peak_limit_dbfs = ?28,
+                    true_peak_limit_set = 1,
+                    podcast_mode = ?29,
+                    playback_speed = ?30,
+                    skip_silence_threshold_ms = ?31
                 WHERE id = 1",
                 params![
-                    defaults.output_device,
-                    defaults.exclusive_mode as i64,
-                    defaults.dac_passthrough as i64,
-                    defaults.preferred_sample_rate.map(|r| r as i64),
+                    settings.output_device,
+                    settings.exclusive_mode as i64,
+                    settings.dac_passthrough as i64,
+                    settings.preferred_sample_rate.map(|r| r as i64),
                     backend_json,
                     plugin_json,
-                    defaults.alsa_hardware_volume as i64,
-                    defaults.stream_first_track as i64,
-                    defaults.stream_buffer_seconds as i64,
-                    defaults.streaming_only as i64,
-                    defaults.limit_quality_to_device as i64,
-                    defaults.device_max_sample_rate.map(|r| r as i64),
-                    defaults.normalization_enabled as i64,
-                    defaults.normalization_target_lufs as f64,
-                    defaults.gapless_enabled as i64,
+                    settings.alsa_hardware_volume as i64,
+                    settings.stream_first_track as i64,
+                    settings.stream_buffer_seconds as i64,
+                    settings.streaming_only as i64,
+                    settings.limit_quality_to_device as i64,
+                    settings.device_max_sample_rate.map(|r| r as i64),
+                    settings.normalization_enabled as i64,
+                    settings.normalization_target_lufs as f64,
+                    settings.gapless_enabled as i64,
                     limits_json,
-                    defaults.pw_force_bitperfect as i64,
-                    defaults.sync_audio_on_startup as i64,
-                    defaults.skip_sink_switch as i64,
-                    defaults.allow_quality_fallback as i64,
-                    defaults.reserve_dac_while_running as i64,
+                    settings.pw_force_bitperfect as i64,
+                    settings.sync_audio_on_startup as i64,
+                    settings.skip_sink_switch as i64,
+                    settings.allow_quality_fallback as i64,
+                    settings.reserve_dac_while_running as i64,
+                    eq_json,
+                    settings.crossfade_ms as i64,
+                    settings.hires_min_mbps as f64,
+                    settings.cd_min_mbps as f64,
+                    settings.repeat_crossfade_ms as i64,
+                    settings.skip_silence as i64,
+                    settings.true_peak_limit_dbfs.map(|db| db as f64),
+                    settings.podcast_mode as i64,
+                    settings.playback_speed as f64,
+                    settings.skip_silence_threshold_ms as i64,
                 ],
             )
-            .map_err(|e| format!("Failed to reset audio settings: {}", e))?;
+            .map_err(|e| format!("Failed to write audio settings: {}", e))?;
+        Ok(())
+    }
+
+    /// Reset all audio settings to their default values
+    pub fn reset_all(&self) -> Result<AudioSettings, String> {
+        // ADR-003: quality_fallback_behavior must survive reset_all()
+        let saved_fallback = self
+            .get_quality_fallback_behavior()
+            .unwrap_or_else(|_| "ask".to_string());
+
+        let defaults = AudioSettings::default();
+        self.write_settings(&defaults)?;
 
         // ADR-003: restore quality_fallback_behavior after reset (it is not an audio config)
         self.conn
@@ -834,6 +1314,81 @@ mod tests {
         (dir, store)
     }
 
+    #[test]
+    fn rollback_restores_the_checkpointed_settings() {
+        let (dir, store) = fresh_store("rollback");
+        assert!(store.latest_checkpoint().unwrap().is_none());
+
+        store.set_crossfade_ms(2_000).unwrap();
+        let first = store.checkpoint().unwrap();
+        // No change since: the same checkpoint comes back.
+        assert_eq!(store.checkpoint().unwrap().id, first.id);
+
+        store.set_crossfade_ms(5_000).unwrap();
+        store.set_dsd_mode("dop").unwrap();
+        let second = store.checkpoint().unwrap();
+        assert!(second.id > first.id);
+        store.set_output_device(Some("hw:9,0")).unwrap();
+        store
+            .set_quality_fallback_behavior("always_fallback")
+            .unwrap();
+
+        let latest = store.latest_checkpoint().unwrap().unwrap();
+        assert_eq!(latest.id, second.id);
+        store.rollback(&latest).unwrap();
+        let restored = store.get_settings().unwrap();
+        assert_eq!(restored.output_device, None);
+        assert_eq!(restored.crossfade_ms, 5_000);
+        assert_eq!(restored.dsd_mode, "dop");
+        assert_eq!(restored.quality_fallback_behavior, "always_fallback");
+
+        // Each rollback consumes its checkpoint and steps further back.
+        let previous = store.latest_checkpoint().unwrap().unwrap();
+        assert_eq!(previous.id, first.id);
+        store.rollback(&previous).unwrap();
+        let restored = store.get_settings().unwrap();
+        assert_eq!(restored.crossfade_ms, 2_000);
+        assert_eq!(restored.dsd_mode, default_dsd_mode());
+        assert!(store.latest_checkpoint().unwrap().is_none());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn playlist_override_round_trips_and_overlays() {
+        let (dir, store) = fresh_store("playlist-override");
+        assert_eq!(store.get_playlist_override(42), Ok(None));
+
+        let value = PlaylistAudioOverride {
+            playlist_id: 42,
+            crossfade_ms: Some(MAX_CROSSFADE_MS + 1_000),
+            normalization_enabled: Some(true),
+            normalization_target_lufs: None,
+        };
+        store.set_playlist_override(&value).unwrap();
+        let stored = store.get_playlist_override(42).unwrap().unwrap();
+        assert_eq!(stored.crossfade_ms, Some(MAX_CROSSFADE_MS));
+        assert_eq!(stored.normalization_enabled, Some(true));
+        assert_eq!(stored.normalization_target_lufs, None);
+        assert_eq!(store.get_playlist_override(7), Ok(None));
+
+        let mut settings = AudioSettings::default();
+        let target = settings.normalization_target_lufs;
+        stored.apply_to(&mut settings);
+        assert_eq!(settings.crossfade_ms, MAX_CROSSFADE_MS);
+        assert!(settings.normalization_enabled);
+        assert_eq!(settings.normalization_target_lufs, target);
+
+        let cleared = PlaylistAudioOverride {
+            playlist_id: 42,
+            crossfade_ms: None,
+            normalization_enabled: None,
+            normalization_target_lufs: None,
+        };
+        store.set_playlist_override(&cleared).unwrap();
+        assert_eq!(store.get_playlist_override(42), Ok(None));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn audio_settings_default_values_are_stable() {
         let settings = AudioSettings::default();
@@ -999,6 +1554,167 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn eq_bands_round_trip_and_reset() {
+        use crate::FilterType;
+
+        let (dir, store) = fresh_store("eq-bands");
+        assert!(store.get_eq_bands().expect("get eq").is_empty());
+
+        let bands = vec![
+            EqBand {
+                freq: 105.0,
+                gain_db: 4.5,
+                q: 0.7,
+                filter_type: FilterType::LowShelf,
+            },
+            EqBand {
+                freq: 3200.0,
+                gain_db: -2.0,
+                q: 2.0,
+                filter_type: FilterType::Peaking,
+            },
+        ];
+        store.set_eq_bands(&bands).expect("set eq");
+        assert_eq!(store.get_eq_bands().expect("get eq"), bands);
+
+        store.reset_all().expect("reset settings");
+        assert!(store.get_eq_bands().expect("get eq").is_empty());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn crossfade_ms_clamps_and_persists() {
+        let (dir, store) = fresh_store("crossfade");
+        assert_eq!(store.get_settings().expect("get settings").crossfade_ms, 0);
+
+        store.set_crossfade_ms(3_000).expect("set crossfade");
+        assert_eq!(store.get_settings().expect("get settings").crossfade_ms, 3_000);
+
+        store.set_crossfade_ms(60_000).expect("set long crossfade");
+        assert_eq!(
+            store.get_settings().expect("get settings").crossfade_ms,
+            MAX_CROSSFADE_MS
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn repeat_crossfade_is_independent_of_crossfade() {
+        let (dir, store) = fresh_store("repeat-crossfade");
+        let repeat_ms = |store: &AudioSettingsStore| {
+            store.get_settings().expect("get settings").repeat_crossfade_ms
+        };
+        assert_eq!(repeat_ms(&store), 0);
+
+        store.set_crossfade_ms(2_000).expect("set crossfade");
+        store
+            .set_repeat_crossfade_ms(5_000)
+            .expect("set repeat crossfade");
+        let settings = store.get_settings().expect("get settings");
+        assert_eq!(settings.crossfade_ms, 2_000);
+        assert_eq!(settings.repeat_crossfade_ms, 5_000);
+
+        store
+            .set_repeat_crossfade_ms(60_000)
+            .expect("set long repeat crossfade");
+        assert_eq!(repeat_ms(&store), MAX_CROSSFADE_MS);
+
+        store.reset_all().expect("reset settings");
+        assert_eq!(repeat_ms(&store), 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn true_peak_limit_defaults_on_and_can_be_turned_off() {
+        let (dir, store) = fresh_store("true-peak-limit");
+        let limit = |store: &AudioSettingsStore| {
+            store
+                .get_settings()
+                .expect("get settings")
+                .true_peak_limit_dbfs
+        };
+        assert_eq!(limit(&store), Some(-1.0));
+
+        store
+            .set_true_peak_limit_dbfs(None)
+            .expect("disable limiter");
+        assert_eq!(limit(&store), None);
+        store
+            .set_true_peak_limit_dbfs(Some(-40.0))
+            .expect("set low ceiling");
+        assert_eq!(limit(&store), Some(MIN_TRUE_PEAK_LIMIT_DBFS));
+        store
+            .set_true_peak_limit_dbfs(Some(3.0))
+            .expect("set high ceiling");
+        assert_eq!(limit(&store), Some(0.0));
+
+        store.reset_all().expect("reset settings");
+        assert_eq!(limit(&store), Some(-1.0));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn skip_silence_persists_and_resets() {
+        let (dir, store) = fresh_store("skip-silence");
+        assert!(!store.get_settings().expect("get settings").skip_silence);
+
+        store.set_skip_silence(true).expect("enable skip silence");
+        assert!(store.get_settings().expect("get settings").skip_silence);
+
+        store.reset_all().expect("reset settings");
+        assert!(!store.get_settings().expect("get settings").skip_silence);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn podcast_mode_persists_clamps_and_resets() {
+        let (dir, store) = fresh_store("podcast-mode");
+        let settings = store.get_settings().expect("get settings");
+        assert!(!settings.podcast_mode);
+        assert_eq!(settings.playback_speed, 1.0);
+        assert_eq!(settings.skip_silence_threshold_ms, 0);
+
+        store.set_podcast_mode(true).expect("enable podcast mode");
+        store.set_playback_speed(1.5).expect("set speed");
+        store
+            .set_skip_silence_threshold_ms(400)
+            .expect("set threshold");
+        let settings = store.get_settings().expect("get settings");
+        assert!(settings.podcast_mode);
+        assert_eq!(settings.playback_speed, 1.5);
+        assert_eq!(settings.skip_silence_threshold_ms, 400);
+
+        store.set_playback_speed(8.0).expect("set fast speed");
+        let speed = store.get_settings().expect("get settings").playback_speed;
+        assert_eq!(speed, crate::podcast::MAX_PLAYBACK_SPEED);
+
+        store.reset_all().expect("reset settings");
+        let settings = store.get_settings().expect("get settings");
+        assert!(!settings.podcast_mode);
+        assert_eq!(settings.playback_speed, 1.0);
+        assert_eq!(settings.skip_silence_threshold_ms, 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn bandwidth_thresholds_persist_and_reset() {
+        let (dir, store) = fresh_store("bandwidth");
+        let settings = store.get_settings().expect("get settings");
+        assert_eq!((settings.hires_min_mbps, settings.cd_min_mbps), (0.0, 0.0));
+
+        store
+            .set_bandwidth_thresholds(25.0, -1.0)
+            .expect("set thresholds");
+        let settings = store.get_settings().expect("get settings");
+        assert_eq!((settings.hires_min_mbps, settings.cd_min_mbps), (25.0, 0.0));
+
+        store.reset_all().expect("reset settings");
+        let settings = store.get_settings().expect("get settings");
+        assert_eq!(settings.hires_min_mbps, 0.0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn deserializes_legacy_json_without_reserve_dac_field() {
         let legacy = r#"{
@@ -1028,5 +1744,8 @@ mod tests {
             serde_json::from_str(legacy).expect("legacy JSON should deserialize");
 
         assert!(!settings.reserve_dac_while_running);
+        assert!(settings.eq_bands.is_empty());
+        assert_eq!(settings.crossfade_ms, 0);
+        assert_eq!(settings.hires_min_mbps, 0.0);
     }
 }
@@ -0,0 +1,190 @@
+//! Leading/trailing silence detection for the "skip silence" setting.
+//!
+//! [`SilenceDetector`] decodes a whole file and measures the RMS of short
+//! windows (all channels together); the audio bounds run from the start of
+//! the first window above the threshold to the end of the last one. The scan
+//! is blocking, so callers run it on the blocking pool and keep the result in
+//! [`LoudnessCache`](crate::LoudnessCache) so a file is only scanned once.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::loudness::db_to_linear;
+use crate::loudness_analyzer::decode_file;
+
+/// Default RMS threshold (dBFS) below which a window counts as silence.
+pub const DEFAULT_SILENCE_THRESHOLD_DB: f32 = -60.0;
+
+/// Length of each RMS window.
+const WINDOW_MS: u64 = 50;
+
+/// The audible part of a track, in milliseconds from the start of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioBounds {
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+pub struct SilenceDetector {
+    threshold_db: f32,
+}
+
+impl Default for SilenceDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_SILENCE_THRESHOLD_DB)
+    }
+}
+
+impl SilenceDetector {
+    /// Detector treating windows quieter than `threshold_db` (RMS, dBFS) as
+    /// silence.
+    pub fn new(threshold_db: f32) -> Self {
+        Self { threshold_db }
+    }
+
+    /// Scan a file for its first and last non-silent window. Blocking:
+    /// decodes the entire file. Errors when the file can't be decoded or is
+    /// silent throughout.
+    pub fn find_audio_bounds(&self, path: &Path) -> Result<AudioBounds, String> {
+        let mut scanner: Option<BoundsScanner> = None;
+        decode_file(path, |rate, channels, samples| {
+            scanner
+                .get_or_insert_with(|| BoundsScanner::new(rate, channels, self.threshold_db))
+                .feed(samples);
+            Ok(())
+        })?;
+        scanner
+            .ok_or_else(|| "File produced no audio".to_string())?
+            .finish()
+    }
+}
+
+/// Windowed RMS scan over interleaved samples.
+struct BoundsScanner {
+    sample_rate: u32,
+    channels: usize,
+    window_samples: u64,
+    /// Threshold as a mean square, so windows compare without a sqrt.
+    threshold_sq: f64,
+    sum_sq: f64,
+    in_window: u64,
+    window_index: u64,
+    total_samples: u64,
+    first_loud: Option<u64>,
+    last_loud: Option<u64>,
+}
+
+impl BoundsScanner {
+    fn new(sample_rate: u32, channels: usize, threshold_db: f32) -> Self {
+        let channels = channels.max(1);
+        let window_frames = (sample_rate as u64 * WINDOW_MS / 1000).max(1);
+        let threshold = db_to_linear(threshold_db) as f64;
+        Self {
+            sample_rate,
+            channels,
+            window_samples: window_frames * channels as u64,
+            threshold_sq: threshold * threshold,
+            sum_sq: 0.0,
+            in_window: 0,
+            window_index: 0,
+            total_samples: 0,
+            first_loud: None,
+            last_loud: None,
+        }
+    }
+
+    fn feed(&mut self, samples: &[f32]) {
+        for &sample in samples {
+            self.sum_sq += sample as f64 * sample as f64;
+            self.in_window += 1;
+            self.total_samples += 1;
+            if self.in_window == self.window_samples {
+                self.close_window();
+            }
+        }
+    }
+
+    fn close_window(&mut self) {
+        if self.in_window > 0 && self.sum_sq / self.in_window as f64 > self.threshold_sq {
+            self.first_loud.get_or_insert(self.window_index);
+            self.last_loud = Some(self.window_index);
+        }
+        self.window_index += 1;
+        self.sum_sq = 0.0;
+        self.in_window = 0;
+    }
+
+    fn finish(mut self) -> Result<AudioBounds, String> {
+        self.close_window();
+        let (Some(first), Some(last)) = (self.first_loud, self.last_loud) else {
+            return Err("File is silent".to_string());
+        };
+        let samples_per_sec = self.sample_rate as u64 * self.channels as u64;
+        let to_ms = |samples: u64| samples * 1000 / samples_per_sec;
+        Ok(AudioBounds {
+            start_ms: to_ms(first * self.window_samples),
+            end_ms: to_ms((last + 1) * self.window_samples).min(to_ms(self.total_samples)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stereo samples: `lead` and `tail` seconds of silence around `body`
+    /// seconds of a constant -20 dBFS signal, at 1 kHz.
+    fn padded(lead: f32, body: f32, tail: f32) -> Vec<f32> {
+        let frames = |secs: f32| (secs * 1000.0) as usize;
+        let level = db_to_linear(-20.0);
+        let mut samples = vec![0.0; frames(lead) * 2];
+        samples.extend(std::iter::repeat(level).take(frames(body) * 2));
+        samples.extend(std::iter::repeat(0.0).take(frames(tail) * 2));
+        samples
+    }
+
+    fn scan(samples: &[f32]) -> Result<AudioBounds, String> {
+        let mut scanner = BoundsScanner::new(1000, 2, DEFAULT_SILENCE_THRESHOLD_DB);
+        for chunk in samples.chunks(333) {
+            scanner.feed(chunk);
+        }
+        scanner.finish()
+    }
+
+    #[test]
+    fn bounds_trim_leading_and_trailing_silence() {
+        assert_eq!(
+            scan(&padded(2.0, 3.0, 1.5)),
+            Ok(AudioBounds {
+                start_ms: 2_000,
+                end_ms: 5_000,
+            })
+        );
+        // No silence: the bounds are the whole track.
+        assert_eq!(
+            scan(&padded(0.0, 1.02, 0.0)),
+            Ok(AudioBounds {
+                start_ms: 0,
+                end_ms: 1_020,
+            })
+        );
+    }
+
+    #[test]
+    fn quiet_noise_counts_as_silence() {
+        let mut samples = padded(1.0, 1.0, 0.0);
+        for sample in samples.iter_mut().take(2_000) {
+            *sample = db_to_linear(-70.0);
+        }
+        assert_eq!(scan(&samples).map(|b| b.start_ms), Ok(1_000));
+        assert!(scan(&[0.0; 4_000]).is_err());
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        assert!(SilenceDetector::default()
+            .find_audio_bounds(Path::new("/nonexistent/qbz.flac"))
+            .is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Bass-band beat detection for the visualizer.
+//!
+//! Works on the same spectrum the FFT producer already computes from the
+//! [`RingBuffer`](super::RingBuffer) tap: the caller sums the 60–180 Hz
+//! (kick / bass) power once per frame and feeds it to [`BeatDetector::process`].
+//! A beat fires when that energy jumps above `threshold ×` its rolling
+//! one-second average. The tempo estimate is the median inter-beat interval
+//! over the last 4 bars (16 beats in 4/4), so a single missed or doubled beat
+//! does not swing the reported BPM.
+
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+/// Lower/upper edge of the analysed band (Hz)
+pub const BEAT_BAND_HZ: (f32, f32) = (60.0, 180.0);
+
+/// Default energy ratio over the rolling average that counts as a beat
+pub const DEFAULT_BEAT_THRESHOLD: f32 = 1.4;
+
+/// Accepted range for the user threshold knob
+pub const BEAT_THRESHOLD_RANGE: (f32, f32) = (1.05, 3.0);
+
+/// Rolling-average length
+const HISTORY_SECS: f32 = 1.0;
+
+/// Tempo range the estimator accepts; also bounds the re-trigger holdoff
+const MIN_BPM: f32 = 50.0;
+const MAX_BPM: f32 = 200.0;
+
+/// 4 bars of 4/4
+const WINDOW_BEATS: usize = 16;
+
+/// Below this the band is treated as silence (no beats from noise floor)
+const SILENCE_FLOOR: f32 = 1e-6;
+
+/// A detected beat (`visualizer:beat`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BeatEvent {
+    /// Tempo from the median inter-beat interval; 0.0 until two beats were seen
+    pub bpm_estimate: f32,
+    /// 0.0–1.0: interval regularity scaled by how full the 4-bar window is
+    pub confidence: f32,
+}
+
+pub struct BeatDetector {
+    history: VecDeque<f32>,
+    history_len: usize,
+    threshold: f32,
+    /// Seconds since the detector started (advanced by `process`)
+    clock: f64,
+    /// Times of the most recent beats, oldest first
+    beat_times: VecDeque<f64>,
+}
+
+impl BeatDetector {
+    /// `frames_per_sec` is the rate `process` is called at (the FFT loop's
+    /// `TARGET_FPS`); it sizes the rolling average.
+    pub fn new(frames_per_sec: u32) -> Self {
+        let history_len = ((frames_per_sec as f32 * HISTORY_SECS).round() as usize).max(2);
+        Self {
+            history: VecDeque::with_capacity(history_len),
+            history_len,
+            threshold: DEFAULT_BEAT_THRESHOLD,
+            clock: 0.0,
+            beat_times: VecDeque::with_capacity(WINDOW_BEATS + 1),
+        }
+    }
+
+    /// Set the energy ratio that triggers a beat (clamped to
+    /// [`BEAT_THRESHOLD_RANGE`]). Non-finite values are ignored.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        if threshold.is_finite() {
+            self.threshold = threshold.clamp(BEAT_THRESHOLD_RANGE.0, BEAT_THRESHOLD_RANGE.1);
+        }
+    }
+
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Forget the rolling average and the tempo window (track change / seek).
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.beat_times.clear();
+    }
+
+    /// Feed one frame of bass-band energy. `dt_secs` is the wall time since
+    /// the previous call. Returns a [`BeatEvent`] when this frame is a beat.
+    pub fn process(&mut self, band_energy: f32, dt_secs: f32) -> Option<BeatEvent> {
+        self.clock += dt_secs.max(0.0) as f64;
+
+        let warmed_up = self.history.len() >= self.history_len / 2;
+        let average = if self.history.is_empty() {
+            0.0
+        } else {
+            self.history.iter().sum::<f32>() / self.history.len() as f32
+        };
+
+        self.history.push_back(band_energy);
+        if self.history.len() > self.history_len {
+            self.history.pop_front();
+        }
+
+        if !warmed_up || !band_energy.is_finite() || band_energy < SILENCE_FLOOR {
+            return None;
+        }
+        if band_energy <= average * self.threshold {
+            return None;
+        }
+        // Holdoff: nothing faster than MAX_BPM can be a new beat
+        if let Some(&last) = self.beat_times.back() {
+            if self.clock - last < (60.0 / MAX_BPM) as f64 {
+                return None;
+            }
+        }
+
+        self.beat_times.push_back(self.clock);
+        if self.beat_times.len() > WINDOW_BEATS + 1 {
+            self.beat_times.pop_front();
+        }
+        Some(self.estimate())
+    }
+
+    fn estimate(&self) -> BeatEvent {
+        let min_interval = 60.0 / MAX_BPM;
+        let max_interval = 60.0 / MIN_BPM;
+        let mut intervals: Vec<f32> = self
+            .beat_times
+            .iter()
+            .zip(self.beat_times.iter().skip(1))
+            .map(|(a, b)| (b - a) as f32)
+            .filter(|dt| (min_interval..=max_interval).contains(dt))
+            .collect();
+        if intervals.is_empty() {
+            return BeatEvent {
+                bpm_estimate: 0.0,
+                confidence: 0.0,
+            };
+        }
+        intervals.sort_by(|a, b| a.total_cmp(b));
+        let median = intervals[intervals.len() / 2];
+
+        let mean = intervals.iter().sum::<f32>() / intervals.len() as f32;
+        let variance =
+            intervals.iter().map(|dt| (dt - mean).powi(2)).sum::<f32>() / intervals.len() as f32;
+        let regularity = (1.0 - variance.sqrt() / mean).clamp(0.0, 1.0);
+        let fill = (intervals.len() as f32 / WINDOW_BEATS as f32).min(1.0);
+
+        BeatEvent {
+            bpm_estimate: 60.0 / median,
+            confidence: regularity * fill,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FPS: u32 = 30;
+    const DT: f32 = 1.0 / FPS as f32;
+
+    #[test]
+    fn steady_kick_estimates_tempo() {
+        let mut detector = BeatDetector::new(FPS);
+        // 120 BPM = one kick every 15 frames at 30 fps
+        let mut last = None;
+        let mut beats = 0;
+        for frame in 0..(FPS * 12) {
+            let energy = if frame % 15 == 0 { 1.0 } else { 0.1 };
+            if let Some(event) = detector.process(energy, DT) {
+                beats += 1;
+                last = Some(event);
+            }
+        }
+        let event = last.expect("no beat detected");
+        assert!(beats >= 20, "only {beats} beats");
+        assert!((event.bpm_estimate - 120.0).abs() < 1.0, "{event:?}");
+        assert!(event.confidence > 0.9, "{event:?}");
+    }
+
+    #[test]
+    fn silence_and_steady_tone_fire_nothing() {
+        let mut detector = BeatDetector::new(FPS);
+        for _ in 0..FPS * 5 {
+            assert_eq!(detector.process(0.0, DT), None);
+        }
+        let mut detector = BeatDetector::new(FPS);
+        for _ in 0..FPS * 5 {
+            assert_eq!(detector.process(0.5, DT), None);
+        }
+    }
+
+    #[test]
+    fn threshold_is_clamped() {
+        let mut detector = BeatDetector::new(FPS);
+        detector.set_threshold(10.0);
+        assert_eq!(detector.threshold(), BEAT_THRESHOLD_RANGE.1);
+        detector.set_threshold(f32::NAN);
+        assert_eq!(detector.threshold(), BEAT_THRESHOLD_RANGE.1);
+    }
+}
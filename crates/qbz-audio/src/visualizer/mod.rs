@@ -7,20 +7,25 @@
 //! - RingBuffer: Lockless ring buffer for sample capture
 //! - TappedSource: Audio source wrapper that taps samples
 //! - VisualizerTap: Shared state for visualization
+//! - BeatDetector: Bass-band beat/tempo detection fed by the FFT producer
 //!
 //! The Tauri-specific FFT thread and event emission remain in qbz-nix.
 
+mod beat;
 mod processor;
 mod ring_buffer;
 mod tapped_source;
 
+pub use beat::{BeatDetector, BeatEvent, BEAT_THRESHOLD_RANGE, DEFAULT_BEAT_THRESHOLD};
 pub use processor::{spawn_visualizer_thread, VizFrame, VizSink};
 pub use ring_buffer::RingBuffer;
 pub use tapped_source::TappedSource;
 
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
 /// Number of frequency bins to send to frontend
 pub const NUM_BARS: usize = 16;
 
@@ -31,6 +36,40 @@ pub const FFT_SIZE: usize = 4096;
 /// Target frames per second for visualization updates
 pub const TARGET_FPS: u64 = 30;
 
+/// Extra render mode requested by the frontend. The legacy streams (bars,
+/// stereo waveform, spectral ribbon, energy, transient, beat) are produced in
+/// every mode because the immersive shaders consume them; the mode selects
+/// which additional per-frame payload the FFT producer emits on top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VisualizerMode {
+    /// 16 log-spaced bars only
+    #[default]
+    Bars,
+    /// Mono oscilloscope line, 128 points per frame
+    Waveform,
+    /// One 256-point power spectrum row per frame; the frontend scrolls rows
+    SpectrogramWaterfall,
+}
+
+impl VisualizerMode {
+    fn as_u8(self) -> u8 {
+        match self {
+            Self::Bars => 0,
+            Self::Waveform => 1,
+            Self::SpectrogramWaterfall => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Waveform,
+            2 => Self::SpectrogramWaterfall,
+            _ => Self::Bars,
+        }
+    }
+}
+
 /// Shared state for visualization that can be passed to the audio thread
 #[derive(Clone)]
 pub struct VisualizerTap {
@@ -47,6 +86,11 @@ pub struct VisualizerTap {
     pub paused: Arc<AtomicBool>,
     /// Current sample rate
     pub sample_rate: Arc<AtomicU32>,
+    /// Beat detector trigger ratio (f32 bits), read by the FFT producer
+    /// every frame
+    pub beat_threshold: Arc<AtomicU32>,
+    /// Selected [`VisualizerMode`] (see `VisualizerMode::as_u8`)
+    pub mode: Arc<AtomicU8>,
 }
 
 impl VisualizerTap {
@@ -57,6 +101,8 @@ impl VisualizerTap {
             enabled: Arc::new(AtomicBool::new(false)),
             paused: Arc::new(AtomicBool::new(false)),
             sample_rate: Arc::new(AtomicU32::new(44100)),
+            beat_threshold: Arc::new(AtomicU32::new(DEFAULT_BEAT_THRESHOLD.to_bits())),
+            mode: Arc::new(AtomicU8::new(VisualizerMode::Bars.as_u8())),
         }
     }
 
@@ -97,6 +143,35 @@ impl VisualizerTap {
     pub fn set_sample_rate(&self, rate: u32) {
         self.sample_rate.store(rate, Ordering::Relaxed);
     }
+
+    /// Set the beat detector threshold: how far the 60–180 Hz energy must
+    /// rise above its rolling average to count as a beat. Clamped to
+    /// [`BEAT_THRESHOLD_RANGE`]; non-finite values are ignored.
+    pub fn set_beat_threshold(&self, threshold: f32) {
+        if threshold.is_finite() {
+            let clamped = threshold.clamp(BEAT_THRESHOLD_RANGE.0, BEAT_THRESHOLD_RANGE.1);
+            self.beat_threshold
+                .store(clamped.to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    /// Select the extra payload the FFT producer emits. Takes effect on the
+    /// next frame.
+    pub fn set_mode(&self, mode: VisualizerMode) {
+        self.mode.store(mode.as_u8(), Ordering::Relaxed);
+    }
+
+    /// Currently selected render mode
+    #[inline]
+    pub fn mode(&self) -> VisualizerMode {
+        VisualizerMode::from_u8(self.mode.load(Ordering::Relaxed))
+    }
+
+    /// Current beat detector threshold
+    #[inline]
+    pub fn beat_threshold(&self) -> f32 {
+        f32::from_bits(self.beat_threshold.load(Ordering::Relaxed))
+    }
 }
 
 impl Default for VisualizerTap {
@@ -1,8 +1,8 @@
 //! Frontend-agnostic FFT/visualizer producer.
 //!
 //! All DSP — Hann window, FFT, log-bars, energy bands, transient detection,
-//! waveform downsample, and the spectral ribbon — lives here so any frontend
-//! (Tauri, Slint, headless) can consume the same five typed streams without a
+//! beat detection, waveform downsample, and the spectral ribbon — lives here so
+//! any frontend (Tauri, Slint, headless) can consume the same typed streams without a
 //! framework dependency. A [`VizSink`] is the only seam: the producer thread
 //! computes a [`VizFrame`] and hands it to `sink.submit(...)`.
 //!
@@ -22,7 +22,10 @@ use spectrum_analyzer::{samples_fft_to_spectrum, FrequencyLimit};
 
 use crate::SpectralAnalyzer;
 
-use super::{VisualizerTap, FFT_SIZE, NUM_BARS, TARGET_FPS};
+use super::beat::BEAT_BAND_HZ;
+use super::{
+    BeatDetector, BeatEvent, VisualizerMode, VisualizerTap, FFT_SIZE, NUM_BARS, TARGET_FPS,
+};
 
 /// Number of energy bands for the Energy Bands visualizer
 const NUM_ENERGY_BANDS: usize = 5;
@@ -30,6 +33,13 @@ const NUM_SPECTRAL_BANDS: usize = 512;
 const SPECTRAL_UPDATE_RATE_HZ: u32 = 58;
 const SPECTRAL_SMOOTHING: f32 = 0.30;
 
+/// `VisualizerMode::Waveform` line resolution
+const WAVEFORM_MONO_POINTS: usize = 128;
+/// `VisualizerMode::SpectrogramWaterfall` row width
+const WATERFALL_BINS: usize = 256;
+/// Power floor mapped to 0.0 in a waterfall row (0 dBFS maps to 1.0)
+const WATERFALL_FLOOR_DB: f32 = -90.0;
+
 /// Energy band frequency ranges (Hz):
 /// Sub-bass (20-60), Bass (60-250), Mids (250-2k), Presence (2k-6k), Air (6k-20k)
 const ENERGY_BAND_RANGES: [(f32, f32); NUM_ENERGY_BANDS] = [
@@ -40,8 +50,8 @@ const ENERGY_BAND_RANGES: [(f32, f32); NUM_ENERGY_BANDS] = [
     (6000.0, 20000.0),
 ];
 
-/// One frame of computed visualization data. Each variant corresponds to one of
-/// the five streams the Tauri build historically emitted as `viz:*` events; the
+/// One frame of computed visualization data. The first five variants are the
+/// streams the Tauri build historically emitted as `viz:*` events; the
 /// payload is the decoded magnitudes, not the LE-f32 byte blob (the Tauri
 /// adapter re-serializes those for backward compatibility).
 #[derive(Clone, Debug)]
@@ -56,6 +66,15 @@ pub enum VizFrame {
     Energy5([f32; 5]),
     /// A single transient intensity, submitted only on detection (`viz:transient`).
     Transient1(f32),
+    /// A bass-band beat with the running tempo estimate, submitted only on
+    /// detection (`visualizer:beat`).
+    Beat(BeatEvent),
+    /// 128-point mono waveform, `VisualizerMode::Waveform` only
+    /// (`visualizer:waveform`).
+    Wave128(Vec<f32>),
+    /// 256-point power spectrum row (0.0–1.0 over a 90 dB range),
+    /// `VisualizerMode::SpectrogramWaterfall` only (`visualizer:waterfall`).
+    Waterfall256(Vec<f32>),
 }
 
 /// Frontend-agnostic consumer of visualization frames. Implemented by the Tauri
@@ -88,7 +107,7 @@ pub fn spawn_visualizer_thread(tap: VisualizerTap, sink: Arc<dyn VizSink>) -> Jo
 }
 
 /// Main FFT processing loop. Reads samples from the tap's ring buffer, computes
-/// all five streams at `TARGET_FPS`, and submits them to the sink. The enabled
+/// every stream at `TARGET_FPS`, and submits them to the sink. The enabled
 /// path (pacing, `sample_rate` reads, `SpectralAnalyzer` cadence, DSP) matches
 /// the historical Tauri loop; while DISABLED or PAUSED the thread parks (see
 /// [`IDLE_POLL`]) instead of spinning at `TARGET_FPS`.
@@ -98,6 +117,7 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
     let mut windowed = vec![0.0f32; FFT_SIZE];
     let mut output = vec![0.0f32; NUM_BARS];
     let mut smoothed = vec![0.0f32; NUM_BARS];
+    let mut magnitudes = Vec::with_capacity(FFT_SIZE / 2);
 
     // Waveform: 256 L + 256 R = 512 floats, written straight into the per-frame
     // Box (see the submit site — the Box itself must stay per-frame).
@@ -120,6 +140,10 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
     const TRANSIENT_THRESHOLD: f32 = 0.04; // RMS jump threshold (sensitive)
     const TRANSIENT_COOLDOWN_FRAMES: u32 = 3; // ~100ms at 30fps
 
+    // Beat detection state (60-180 Hz energy vs its rolling average)
+    let mut beat_detector = BeatDetector::new(TARGET_FPS as u32);
+    let mut last_beat_frame: Option<Instant> = None;
+
     // Smoothing factor: 0 = no smoothing, higher = more smoothing
     const SMOOTHING: f32 = 0.65;
 
@@ -142,6 +166,7 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
 
         {
             let sample_rate = tap.sample_rate.load(Ordering::Relaxed);
+            let mode = tap.mode();
 
             // Get samples from ring buffer
             tap.ring_buffer.snapshot(&mut samples);
@@ -244,6 +269,32 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
                     }
 
                     prev_rms = raw_rms;
+
+                    // --- Beat Detection: bass-band power vs rolling average ---
+                    let (beat_lo, beat_hi) = BEAT_BAND_HZ;
+                    let beat_energy: f32 = data
+                        .iter()
+                        .filter(|(freq, _)| freq.val() >= beat_lo && freq.val() < beat_hi)
+                        .map(|(_, magnitude)| magnitude.val() * magnitude.val())
+                        .sum();
+                    // Real frame spacing, so a late frame doesn't skew the tempo
+                    let dt = last_beat_frame
+                        .map(|t| frame_start.duration_since(t).as_secs_f32())
+                        .unwrap_or(0.0);
+                    last_beat_frame = Some(frame_start);
+                    beat_detector.set_threshold(tap.beat_threshold());
+                    if let Some(beat) = beat_detector.process(beat_energy, dt) {
+                        sink.submit(VizFrame::Beat(beat));
+                    }
+
+                    if mode == VisualizerMode::SpectrogramWaterfall {
+                        magnitudes.clear();
+                        magnitudes.extend(data.iter().map(|(_, magnitude)| magnitude.val()));
+                        sink.submit(VizFrame::Waterfall256(power_spectrum_row(
+                            &magnitudes,
+                            WATERFALL_BINS,
+                        )));
+                    }
                 }
                 Err(e) => {
                     log::debug!("FFT error: {:?}", e);
@@ -264,6 +315,13 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
                 wave[WAVEFORM_POINTS + i] = samples[base + 1]; // R
             }
             sink.submit(VizFrame::Wave256x2(wave));
+
+            if mode == VisualizerMode::Waveform {
+                sink.submit(VizFrame::Wave128(downsample_mono(
+                    &samples,
+                    WAVEFORM_MONO_POINTS,
+                )));
+            }
         }
 
         // Maintain target FPS
@@ -274,6 +332,48 @@ fn run_fft_loop(tap: VisualizerTap, sink: Arc<dyn VizSink>) {
     }
 }
 
+/// Mix interleaved stereo down to mono and reduce it to `points` values.
+/// Each point keeps the largest-magnitude sample of its bucket (sign
+/// preserved), so short peaks survive the downsample.
+fn downsample_mono(interleaved: &[f32], points: usize) -> Vec<f32> {
+    let frames = interleaved.len() / 2;
+    if points == 0 || frames == 0 {
+        return vec![0.0; points];
+    }
+    let bucket = (frames / points).max(1);
+    (0..points)
+        .map(|i| {
+            let start = (i * bucket).min(frames);
+            let end = ((i + 1) * bucket).min(frames);
+            interleaved[start * 2..end * 2]
+                .chunks_exact(2)
+                .map(|frame| (frame[0] + frame[1]) * 0.5)
+                .fold(
+                    0.0f32,
+                    |peak, s| if s.abs() > peak.abs() { s } else { peak },
+                )
+        })
+        .collect()
+}
+
+/// Average FFT magnitudes into `bins` linear groups of power and map each to
+/// 0.0–1.0 on a dB scale ([`WATERFALL_FLOOR_DB`] → 0.0, 0 dB → 1.0).
+fn power_spectrum_row(magnitudes: &[f32], bins: usize) -> Vec<f32> {
+    if bins == 0 || magnitudes.is_empty() {
+        return vec![0.0; bins];
+    }
+    (0..bins)
+        .map(|i| {
+            let start = i * magnitudes.len() / bins;
+            let end = ((i + 1) * magnitudes.len() / bins).max(start + 1);
+            let group = &magnitudes[start..end.min(magnitudes.len())];
+            let power = group.iter().map(|m| m * m).sum::<f32>() / group.len().max(1) as f32;
+            let db = 10.0 * power.max(1e-12).log10();
+            ((db - WATERFALL_FLOOR_DB) / -WATERFALL_FLOOR_DB).clamp(0.0, 1.0)
+        })
+        .collect()
+}
+
 /// Map spectrum data to logarithmically-spaced frequency bars.
 ///
 /// Human hearing is logarithmic, so we use log-spaced bars to match how we
@@ -358,4 +458,32 @@ mod tests {
         // Last bar should approach 20000Hz (but won't reach it since t < 1.0)
         assert!(freqs[num_bars - 1] > 10000.0);
     }
+
+    #[test]
+    fn mono_downsample_keeps_peaks() {
+        // 8 stereo frames -> 4 points of 2 frames each
+        let interleaved = [
+            0.1, 0.1, 0.9, 0.7, // bucket 0: peak 0.8
+            -0.2, -0.2, 0.0, 0.0, // bucket 1: peak -0.2
+            0.0, 0.0, 0.0, 0.0, // bucket 2: silence
+            0.5, -0.5, 0.3, 0.3, // bucket 3: L/R cancel, then 0.3
+        ];
+        let out = downsample_mono(&interleaved, 4);
+        let expected = [0.8, -0.2, 0.0, 0.3];
+        for (got, want) in out.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-6, "{out:?}");
+        }
+    }
+
+    #[test]
+    fn waterfall_row_maps_power_to_unit_range() {
+        let mut magnitudes = vec![0.0f32; 512];
+        magnitudes[0..2].fill(1.0); // 0 dB
+        magnitudes[2..4].fill(10f32.powf(-45.0 / 20.0)); // -45 dB
+        let row = power_spectrum_row(&magnitudes, 256);
+        assert_eq!(row.len(), 256);
+        assert!((row[0] - 1.0).abs() < 1e-4);
+        assert!((row[1] - 0.5).abs() < 1e-3, "{}", row[1]);
+        assert_eq!(row[255], 0.0);
+    }
 }
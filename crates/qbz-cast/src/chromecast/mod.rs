@@ -2,6 +2,13 @@
 //!
 //! Provides Chromecast device discovery, connection management,
 //! and media control via the Cast protocol.
+//!
+//! Playback is single-item: each track is a fresh `LOAD` and the app
+//! advances the queue itself. rust_cast's media channel only speaks
+//! LOAD / PLAY / PAUSE / STOP / SEEK / GET_STATUS and offers no way to send
+//! other media-namespace messages on its connection, so receiver-side queues
+//! (`QUEUE_LOAD` / `QUEUE_UPDATE`, letting the receiver prefetch the next
+//! item) need queue support in rust_cast or our own Cast channel first.
 
 pub mod device;
 pub mod discovery;
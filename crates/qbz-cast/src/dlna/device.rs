@@ -55,6 +55,12 @@ pub struct DlnaConnection {
     // it false so the pre-check runs.
     uri_freshly_set: bool,
     is_playing: bool,
+    // Last transport state the renderer reported (GetTransportInfo via
+    // `get_position_info`) or that a successful Play/Pause/Stop implies. `seek`
+    // consults it: some renderers drop a paused stream once their buffer runs
+    // dry and fall back to STOPPED/NO_MEDIA_PRESENT, where a bare Seek faults.
+    // None = unknown (fresh connection or freshly set URI).
+    transport_state: Option<String>,
 }
 
 impl DlnaConnection {
@@ -111,6 +117,7 @@ impl DlnaConnection {
             last_set_uri_payload: None,
             uri_freshly_set: false,
             is_playing: false,
+            transport_state: None,
         })
     }
 
@@ -191,6 +198,7 @@ impl DlnaConnection {
         // later reports 702 "no contents" (see the retry loop in `play`).
         self.last_set_uri_payload = Some(payload);
         self.uri_freshly_set = true;
+        self.transport_state = None;
         log::info!("DLNA: Set URI to {}", redact_media_uri(uri));
 
         Ok(())
@@ -300,6 +308,7 @@ impl DlnaConnection {
                 Ok(response) => {
                     log::info!("DLNA: Play response: {:?}", response);
                     self.is_playing = true;
+                    self.transport_state = Some("PLAYING".to_string());
                     log::info!("DLNA: Play started successfully");
                     return Ok(());
                 }
@@ -359,12 +368,9 @@ impl DlnaConnection {
         )
         .await
         {
-            Ok(Ok(resp)) => matches!(
-                resp.get("CurrentTransportState")
-                    .map(|s| s.trim().to_ascii_uppercase())
-                    .as_deref(),
-                Some("STOPPED") | Some("NO_MEDIA_PRESENT")
-            ),
+            Ok(Ok(resp)) => resp
+                .get("CurrentTransportState")
+                .is_some_and(|s| is_idle_state(s)),
             _ => false,
         }
     }
@@ -390,6 +396,7 @@ impl DlnaConnection {
         .await?;
 
         self.is_playing = false;
+        self.transport_state = Some("PAUSED_PLAYBACK".to_string());
         log::info!("DLNA: Pause");
         Ok(())
     }
@@ -416,34 +423,52 @@ impl DlnaConnection {
 
         self.is_playing = false;
         self.current_uri = None;
+        self.transport_state = Some("STOPPED".to_string());
         log::info!("DLNA: Stop");
         Ok(())
     }
 
-    /// Seek to position
+    /// Seek to position (AVTransport `Seek`, `REL_TIME`, `HH:MM:SS` target).
+    ///
+    /// If the renderer last reported an idle transport (it dropped a paused
+    /// stream once its buffer ran out), the track URI is re-asserted with
+    /// `SetAVTransportURI` first — a bare Seek on an empty transport faults.
     pub async fn seek(&mut self, position_secs: u64) -> Result<(), DlnaError> {
         if !self.connected {
             return Err(DlnaError::NotConnected);
         }
 
-        let hours = position_secs / 3600;
-        let minutes = (position_secs % 3600) / 60;
-        let seconds = position_secs % 60;
-        let time_str = format!("{:02}:{:02}:{:02}", hours, minutes, seconds);
-
         let av_service = self
             .av_transport_service
             .as_ref()
             .ok_or_else(|| DlnaError::Playback("Device has no AVTransport service".to_string()))?;
 
-        let payload = format!(
-            "<InstanceID>0</InstanceID><Unit>REL_TIME</Unit><Target>{}</Target>",
-            time_str
-        );
+        let idle = self.transport_state.as_deref().is_some_and(is_idle_state);
+        if idle {
+            if let Some(set_uri_payload) = self.last_set_uri_payload.as_deref() {
+                log::info!("DLNA: transport idle before Seek; re-asserting SetAVTransportURI");
+                Self::run_action(
+                    av_service,
+                    &self.device_url,
+                    "SetAVTransportURI",
+                    set_uri_payload,
+                    10,
+                )
+                .await?;
+                self.transport_state = None;
+            }
+        }
 
-        Self::run_action(av_service, &self.device_url, "Seek", &payload, 10).await?;
+        Self::run_action(
+            av_service,
+            &self.device_url,
+            "Seek",
+            &seek_payload(position_secs),
+            10,
+        )
+        .await?;
 
-        log::info!("DLNA: Seek to {}", time_str);
+        log::info!("DLNA: Seek to {}", format_hms(position_secs));
         Ok(())
     }
 
@@ -520,7 +545,7 @@ impl DlnaConnection {
     }
 
     /// Get current playback position and transport state
-    pub async fn get_position_info(&self) -> Result<DlnaPositionInfo, DlnaError> {
+    pub async fn get_position_info(&mut self) -> Result<DlnaPositionInfo, DlnaError> {
         if !self.connected {
             return Err(DlnaError::NotConnected);
         }
@@ -575,6 +600,7 @@ impl DlnaConnection {
             .get("CurrentTransportState")
             .map(|s| s.to_string())
             .unwrap_or_else(|| "UNKNOWN".to_string());
+        self.transport_state = Some(transport_state.clone());
 
         Ok(DlnaPositionInfo {
             position_secs,
@@ -584,6 +610,32 @@ impl DlnaConnection {
     }
 }
 
+/// Whether an AVTransport state means the renderer holds no playable content
+fn is_idle_state(state: &str) -> bool {
+    matches!(
+        state.trim().to_ascii_uppercase().as_str(),
+        "STOPPED" | "NO_MEDIA_PRESENT"
+    )
+}
+
+/// Format seconds as the `HH:MM:SS` time AVTransport expects
+fn format_hms(secs: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+/// `Seek` action arguments for a relative-time target
+fn seek_payload(position_secs: u64) -> String {
+    format!(
+        "<InstanceID>0</InstanceID><Unit>REL_TIME</Unit><Target>{}</Target>",
+        format_hms(position_secs)
+    )
+}
+
 /// Parse time string "HH:MM:SS" or "H:MM:SS" to seconds
 fn parse_time_string(time: &str) -> u64 {
     let parts: Vec<&str> = time.split(':').collect();
@@ -606,12 +658,7 @@ fn parse_time_string(time: &str) -> u64 {
 fn build_didl_metadata(uri: &str, metadata: &DlnaMetadata, content_type: &str) -> String {
     let duration = metadata
         .duration_secs
-        .map(|d| {
-            let hours = d / 3600;
-            let minutes = (d % 3600) / 60;
-            let seconds = d % 60;
-            format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
-        })
+        .map(format_hms)
         .unwrap_or_else(|| "00:00:00".to_string());
 
     let artwork = metadata
@@ -711,7 +758,24 @@ fn redact_media_uri(s: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{redact_media_uri, service_type_matches};
+    use super::{format_hms, is_idle_state, redact_media_uri, seek_payload, service_type_matches};
+
+    #[test]
+    fn seek_target_is_rel_time_hms() {
+        assert_eq!(format_hms(0), "00:00:00");
+        assert_eq!(format_hms(3723), "01:02:03");
+        assert_eq!(
+            seek_payload(59),
+            "<InstanceID>0</InstanceID><Unit>REL_TIME</Unit><Target>00:00:59</Target>"
+        );
+    }
+
+    #[test]
+    fn idle_states_are_case_insensitive() {
+        assert!(is_idle_state("STOPPED"));
+        assert!(is_idle_state(" no_media_present "));
+        assert!(!is_idle_state("PAUSED_PLAYBACK"));
+    }
 
     #[test]
     fn redacts_path_token() {
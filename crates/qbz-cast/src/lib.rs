@@ -12,11 +12,21 @@
 //!
 //! - **MediaServer**: Local HTTP server for streaming audio to cast devices.
 //!   Supports byte-range requests for seeking.
+//!
+//! - **Scan**: one-shot discovery of both protocols at once
+//!   ([`discover_all`]).
+//!
+//! AirPlay is not supported. Now-playing metadata and artwork on AirPlay
+//! (RTSP `SET_PARAMETER` with a DMAP body) ride an established RAOP session,
+//! and so does device volume (`SET_PARAMETER` with `volume: <dBFS>`, 0.0 to
+//! -30.0; changes read back with `GET_PARAMETER`), so they can only land
+//! together with an AirPlay sender.
 
 pub mod chromecast;
 pub mod dlna;
 pub mod errors;
 pub mod media_server;
+pub mod scan;
 
 // Re-export error types at root
 pub use errors::{CastError, DlnaError};
@@ -35,6 +45,9 @@ pub use dlna::{
     DiscoveredDlnaDevice, DlnaConnection, DlnaDiscovery, DlnaMetadata, DlnaPositionInfo, DlnaStatus,
 };
 
+// Re-export the combined scan
+pub use scan::{discover_all, AllDevicesSnapshot, DEFAULT_SCAN_SECS};
+
 /// Cast device type alias for backwards compatibility
 pub type CastDevice = CastDeviceConnection;
 
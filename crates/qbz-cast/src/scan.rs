@@ -0,0 +1,46 @@
+//! One-shot discovery of every supported protocol.
+//!
+//! The picker keeps long-running discoveries open while it is shown; a scan
+//! instead runs its own Chromecast (mDNS) and DLNA (SSDP) discoveries side
+//! by side for a fixed window, snapshots what answered and stops them. It
+//! doesn't touch a picker's discoveries, so both can run at once.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{DeviceDiscovery, DiscoveredDevice, DiscoveredDlnaDevice, DlnaDiscovery};
+
+/// Default listening window of [`discover_all`], in seconds.
+pub const DEFAULT_SCAN_SECS: u64 = 5;
+
+/// Devices found by [`discover_all`], per protocol.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AllDevicesSnapshot {
+    pub chromecast: Vec<DiscoveredDevice>,
+    pub dlna: Vec<DiscoveredDlnaDevice>,
+}
+
+/// Run Chromecast and DLNA discovery together for `duration` and return
+/// every device that answered. A protocol whose discovery fails to start
+/// is logged and reported empty.
+pub async fn discover_all(duration: Duration) -> AllDevicesSnapshot {
+    let mut chromecast = DeviceDiscovery::new();
+    let mut dlna = DlnaDiscovery::new();
+    if let Err(e) = chromecast.start_discovery() {
+        log::warn!("[cast] scan: chromecast discovery start failed: {e}");
+    }
+    if let Err(e) = dlna.start_discovery().await {
+        log::warn!("[cast] scan: dlna discovery start failed: {e}");
+    }
+
+    tokio::time::sleep(duration).await;
+
+    let snapshot = AllDevicesSnapshot {
+        chromecast: chromecast.get_discovered_devices(),
+        dlna: dlna.get_discovered_devices(),
+    };
+    let _ = chromecast.stop_discovery();
+    let _ = dlna.stop_discovery();
+    snapshot
+}
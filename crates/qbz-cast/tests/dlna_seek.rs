@@ -0,0 +1,188 @@
+//! DLNA seek against a hand-rolled UPnP MediaRenderer mock: a tiny_http
+//! server that serves a device description and records every SOAP action.
+
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use qbz_cast::dlna::{DiscoveredDlnaDevice, DlnaConnection, DlnaMetadata};
+
+const DESCRIPTION: &str = r#"<?xml version="1.0"?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+  <specVersion><major>1</major><minor>0</minor></specVersion>
+  <device>
+    <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+    <friendlyName>Mock Renderer</friendlyName>
+    <manufacturer>qbz</manufacturer>
+    <modelName>mock</modelName>
+    <UDN>uuid:qbz-mock-renderer</UDN>
+    <serviceList>
+      <service>
+        <serviceType>urn:schemas-upnp-org:service:AVTransport:1</serviceType>
+        <serviceId>urn:upnp-org:serviceId:AVTransport</serviceId>
+        <SCPDURL>/avt.xml</SCPDURL>
+        <controlURL>/avt/control</controlURL>
+        <eventSubURL>/avt/event</eventSubURL>
+      </service>
+    </serviceList>
+  </device>
+</root>"#;
+
+/// One recorded SOAP call: (action name, request body)
+type Calls = Arc<Mutex<Vec<(String, String)>>>;
+
+struct MockRenderer {
+    url: String,
+    calls: Calls,
+}
+
+impl MockRenderer {
+    /// Start the mock. `transport_state` is what GetTransportInfo reports.
+    fn start(transport_state: &'static str) -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let calls: Calls = Arc::default();
+        let recorded = calls.clone();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                if request.url() == "/description.xml" {
+                    let _ = request.respond(tiny_http::Response::from_string(DESCRIPTION));
+                    continue;
+                }
+                let action = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("SOAPACTION"))
+                    .map(|h| h.value.as_str().trim_matches('"').to_string())
+                    .and_then(|v| v.rsplit('#').next().map(str::to_string))
+                    .unwrap_or_default();
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                recorded.lock().unwrap().push((action.clone(), body));
+
+                let args = match action.as_str() {
+                    "GetTransportInfo" => format!(
+                        "<CurrentTransportState>{transport_state}</CurrentTransportState>\
+                         <CurrentTransportStatus>OK</CurrentTransportStatus>\
+                         <CurrentSpeed>1</CurrentSpeed>"
+                    ),
+                    "GetPositionInfo" => "<Track>1</Track><TrackDuration>00:04:00</TrackDuration>\
+                                          <RelTime>00:01:00</RelTime>"
+                        .to_string(),
+                    _ => String::new(),
+                };
+                let envelope = format!(
+                    r#"<?xml version="1.0"?><s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:{action}Response xmlns:u="urn:schemas-upnp-org:service:AVTransport:1">{args}</u:{action}Response></s:Body></s:Envelope>"#
+                );
+                let header =
+                    tiny_http::Header::from_bytes("Content-Type", "text/xml; charset=\"utf-8\"")
+                        .unwrap();
+                let _ =
+                    request.respond(tiny_http::Response::from_string(envelope).with_header(header));
+            }
+        });
+        Self {
+            url: format!("http://127.0.0.1:{port}/description.xml"),
+            calls,
+        }
+    }
+
+    fn device(&self) -> DiscoveredDlnaDevice {
+        DiscoveredDlnaDevice {
+            id: "uuid:qbz-mock-renderer".to_string(),
+            name: "Mock Renderer".to_string(),
+            manufacturer: "qbz".to_string(),
+            model: "mock".to_string(),
+            ip: "127.0.0.1".to_string(),
+            url: self.url.clone(),
+            has_av_transport: true,
+            has_rendering_control: false,
+        }
+    }
+
+    fn actions(&self) -> Vec<String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(a, _)| a.clone())
+            .collect()
+    }
+
+    fn body_of(&self, action: &str) -> String {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|(a, _)| a == action)
+            .map(|(_, b)| b.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn metadata() -> DlnaMetadata {
+    DlnaMetadata {
+        title: "Track".to_string(),
+        artist: "Artist".to_string(),
+        album: "Album".to_string(),
+        artwork_url: None,
+        duration_secs: Some(240),
+    }
+}
+
+#[tokio::test]
+async fn seek_sends_rel_time_soap_envelope() {
+    let mock = MockRenderer::start("PLAYING");
+    let mut conn = DlnaConnection::connect(mock.device()).await.unwrap();
+
+    conn.seek(3723).await.unwrap();
+
+    let body = mock.body_of("Seek");
+    assert!(body.contains("<InstanceID>0</InstanceID>"), "{body}");
+    assert!(body.contains("<Unit>REL_TIME</Unit>"), "{body}");
+    assert!(body.contains("<Target>01:02:03</Target>"), "{body}");
+}
+
+#[tokio::test]
+async fn seek_reasserts_uri_when_renderer_dropped_the_stream() {
+    // The renderer fell back to STOPPED while paused
+    let mock = MockRenderer::start("STOPPED");
+    let mut conn = DlnaConnection::connect(mock.device()).await.unwrap();
+    conn.load_media("http://127.0.0.1:1/audio/t/1", &metadata(), "audio/flac")
+        .await
+        .unwrap();
+    let info = conn.get_position_info().await.unwrap();
+    assert_eq!(info.transport_state, "STOPPED");
+
+    conn.seek(60).await.unwrap();
+
+    let actions = mock.actions();
+    let tail: Vec<&str> = actions
+        .iter()
+        .rev()
+        .take(2)
+        .rev()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(tail, ["SetAVTransportURI", "Seek"], "{actions:?}");
+}
+
+#[tokio::test]
+async fn seek_on_playing_transport_is_a_bare_seek() {
+    let mock = MockRenderer::start("PLAYING");
+    let mut conn = DlnaConnection::connect(mock.device()).await.unwrap();
+    conn.load_media("http://127.0.0.1:1/audio/t/1", &metadata(), "audio/flac")
+        .await
+        .unwrap();
+    conn.get_position_info().await.unwrap();
+
+    conn.seek(60).await.unwrap();
+
+    let set_uri_calls = mock
+        .actions()
+        .iter()
+        .filter(|a| *a == "SetAVTransportURI")
+        .count();
+    assert_eq!(set_uri_calls, 1);
+}
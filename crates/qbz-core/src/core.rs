@@ -3,19 +3,22 @@
 //! The main orchestrator that connects all QBZ subsystems and provides
 //! a unified API for frontends.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use qbz_models::{
-    ArtistStoryResponse,
+    ArtistBiography, ArtistStoryResponse,
     AssetOrigin, ExternalStreamAsset, StreamQualityInfo,
     Album, Artist, ArtistAlbums, CoreEvent, DiscoverAlbum, DiscoverData, DiscoverPlaylistsResponse,
     DiscoverResponse, FrontendAdapter, GenreInfo, LabelExploreResponse, LabelGetListResponse,
     LabelListPage, LabelPageData, LabelStoryResponse, PageArtistResponse,
-    MostPopularItem, Playlist, PlaylistDuplicateResult, PlaylistTag, Quality, QueueState,
-    QueueTrack, ReleasesGridResponse,
+    MostPopularItem, Playlist, PlaylistDuplicateResult, PlaylistExportSummary, PlaylistTag,
+    Quality, QueueState,
+    QueueStats, QueueTrack, ReleasesGridResponse,
     RepeatMode, SearchAllResults, SearchResultsPage, StreamUrl, Track, TrackToAnalyse,
-    TracksContainer, UserSession,
+    TracksContainer, UserSession, WorkMetadata,
 };
 use qbz_integrations::musicbrainz::cache::MusicBrainzCache;
 use qbz_integrations::musicbrainz::genre::{extract_affinity_seeds, genre_summary, is_broad_genre};
@@ -23,9 +26,10 @@ use qbz_integrations::musicbrainz::location::compute_affinity_score;
 use qbz_integrations::musicbrainz::{
     location, AffinitySeeds, AlbumAppearance, ArtistMetadata, ArtistRelationships,
     DiscoveryArtist, DiscoveryResponse, LocationCandidate, LocationDiscoveryResponse,
-    MusicBrainzClient, MusicianAppearances, MusicianConfidence, Period, RelatedArtist,
-    ResolvedArtist, ResolvedMusician, Tag,
+    MusicBrainzClient, MusicianAppearances, MusicianConfidence,
+    ResolvedArtist, ResolvedMusician, ResolvedTrack, Tag,
 };
+use qbz_audio::{AudioBounds, LoudnessAnalysisQueue, LoudnessCache, SilenceDetector};
 use qbz_player::{PlaybackState, Player, QueueManager};
 use qbz_qobuz::QobuzClient;
 
@@ -252,9 +256,10 @@ pub struct QbzCore<A: FrontendAdapter> {
     player: Arc<Player>,
     /// MusicBrainz client (always present; enable/disable toggle lives inside)
     musicbrainz: Arc<MusicBrainzClient>,
-    /// Persistent MB cache. Opened by the frontend (which owns the
-    /// data-dir path) via `set_musicbrainz_cache`. Methods read the
-    /// cache before hitting the network and persist on miss.
+    /// Persistent per-user MB cache. Opened by the frontend (which owns
+    /// the data-dir path) via `set_musicbrainz_cache` on login and dropped
+    /// on logout. Methods read the cache before hitting the network and
+    /// persist on miss.
     musicbrainz_cache: Arc<std::sync::Mutex<Option<MusicBrainzCache>>>,
     /// Per-user artist-vector store for the playlist "Suggested Songs" engine.
     /// Opened by the frontend (owns the data dir) via `set_artist_vectors`.
@@ -269,6 +274,12 @@ pub struct QbzCore<A: FrontendAdapter> {
     /// Set explicitly by the frontend's local-playlist play path right after
     /// its `set_queue`.
     queue_offline_only: Arc<std::sync::atomic::AtomicBool>,
+    /// Background loudness pre-analysis. Opened on first use (the cache DB
+    /// lives in the data dir); None inside if the cache can't be opened.
+    loudness_queue: Arc<std::sync::OnceLock<Option<LoudnessAnalysisQueue>>>,
+    /// Silence-trim bounds cache (same DB as the loudness cache). Opened on
+    /// first use; None inside if the cache can't be opened.
+    silence_cache: Arc<std::sync::OnceLock<Option<Arc<LoudnessCache>>>>,
 }
 
 impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
@@ -287,6 +298,8 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             artist_vectors: Arc::new(tokio::sync::Mutex::new(None)),
             initialized: Arc::new(RwLock::new(false)),
             queue_offline_only: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            loudness_queue: Arc::new(std::sync::OnceLock::new()),
+            silence_cache: Arc::new(std::sync::OnceLock::new()),
         }
     }
 
@@ -304,8 +317,8 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .load(std::sync::atomic::Ordering::Relaxed)
     }
 
-    /// Install a MusicBrainz cache. The frontend owns the data path
-    /// and opens the cache; QbzCore just stores the handle and uses
+    /// Install the user's MusicBrainz cache. The frontend owns the data
+    /// path and opens the cache; QbzCore just stores the handle and uses
     /// it transparently in `musicbrainz_get_artist_metadata` and
     /// `musicbrainz_get_artist_relationships`.
     pub fn set_musicbrainz_cache(&self, cache: MusicBrainzCache) {
@@ -314,6 +327,28 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         }
     }
 
+    /// Close the MusicBrainz cache on logout, so the next account never
+    /// reads this one's entries. Lookups skip the cache until the next
+    /// `set_musicbrainz_cache`.
+    pub fn close_musicbrainz_cache(&self) {
+        if let Ok(mut guard) = self.musicbrainz_cache.lock() {
+            *guard = None;
+        }
+    }
+
+    /// Empty the current user's MusicBrainz cache. A no-op when none is
+    /// installed.
+    pub fn musicbrainz_clear_cache(&self) -> Result<(), CoreError> {
+        let guard = self
+            .musicbrainz_cache
+            .lock()
+            .map_err(|e| CoreError::Internal(e.to_string()))?;
+        match guard.as_ref() {
+            Some(cache) => cache.clear_all().map_err(CoreError::Internal),
+            None => Ok(()),
+        }
+    }
+
     /// Install the per-user artist-vector store (playlist Suggested Songs). The
     /// frontend owns the data path and opens it via
     /// `qbz_reco::ArtistVectorStore::open_at`.
@@ -421,6 +456,18 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         }
     }
 
+    /// Check email+password against Qobuz without signing in, returning the
+    /// account's user id. The current session (if any) is left as it is.
+    pub async fn verify_credentials(&self, email: &str, password: &str) -> Result<u64, CoreError> {
+        let client = self.client.read().await;
+        let client = client.as_ref().ok_or(CoreError::NotInitialized)?;
+        client
+            .authenticate(email, password)
+            .await
+            .map(|session| session.user_id)
+            .map_err(|e| CoreError::AuthFailed(e.to_string()))
+    }
+
     /// Restore a session from a saved OAuth user_auth_token.
     pub async fn login_with_token(&self, token: &str) -> Result<UserSession, CoreError> {
         let client = self.client.read().await;
@@ -478,6 +525,12 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         queue.get_state()
     }
 
+    /// Track count, total duration and format/source breakdown of the queue.
+    pub async fn get_queue_stats(&self) -> QueueStats {
+        let queue = self.queue.read().await;
+        queue.get_stats()
+    }
+
     /// Get all queue tracks and current index (for session persistence)
     pub async fn get_all_queue_tracks(&self) -> (Vec<QueueTrack>, Option<usize>) {
         let queue = self.queue.read().await;
@@ -496,6 +549,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
     pub async fn set_repeat_mode(&self, mode: RepeatMode) {
         let queue = self.queue.write().await;
         queue.set_repeat(mode.clone());
+        // Repeat-one would crossfade a track into itself
+        self.player
+            .state
+            .set_crossfade_suppressed(mode == RepeatMode::One);
         self.emit(CoreEvent::RepeatModeChanged { mode }).await;
     }
 
@@ -550,6 +607,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Add a track to the end of the queue
@@ -560,6 +621,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Add multiple tracks to the queue
@@ -570,6 +635,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Add a track to play next (after current)
@@ -580,6 +649,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Set the entire queue (replaces existing)
@@ -593,6 +666,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Replace queue contents and playback order atomically.
@@ -610,6 +687,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
     }
 
     /// Remove a track by index
@@ -620,6 +701,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
         removed
     }
 
@@ -631,6 +716,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             state: queue.get_state(),
         })
         .await;
+        self.emit(CoreEvent::QueueStatsChanged {
+            stats: queue.get_stats(),
+        })
+        .await;
         removed
     }
 
@@ -645,6 +734,32 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
                 state: queue.get_state(),
             })
             .await;
+            self.emit(CoreEvent::QueueStatsChanged {
+                stats: queue.get_stats(),
+            })
+            .await;
+        }
+        removed
+    }
+
+    /// Remove every upcoming track matching `pred` (shuffle-aware). Emits a
+    /// single `QueueUpdated` when anything was removed. Returns the removed
+    /// tracks in play order.
+    pub async fn remove_upcoming_where(
+        &self,
+        pred: impl Fn(&QueueTrack) -> bool,
+    ) -> Vec<QueueTrack> {
+        let queue = self.queue.write().await;
+        let removed = queue.remove_upcoming_where(pred);
+        if !removed.is_empty() {
+            self.emit(CoreEvent::QueueUpdated {
+                state: queue.get_state(),
+            })
+            .await;
+            self.emit(CoreEvent::QueueStatsChanged {
+                stats: queue.get_stats(),
+            })
+            .await;
         }
         removed
     }
@@ -818,6 +933,13 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         queue.peek_upcoming(count)
     }
 
+    /// Whether the next track is the repeat-all wrap back to the start of the
+    /// queue (picks the repeat crossfade length).
+    pub async fn next_wraps_around(&self) -> bool {
+        let queue = self.queue.read().await;
+        queue.next_wraps_around()
+    }
+
     /// The current queue track, if any (source-aware playback routing).
     pub async fn current_track(&self) -> Option<QueueTrack> {
         let queue = self.queue.read().await;
@@ -909,6 +1031,20 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .map_err(CoreError::Api)
     }
 
+    /// Cover URL of the Qobuz album that is `title` by `artist`, for local
+    /// albums without artwork. `None` when no search result matches both
+    /// (see [`pick_album_cover`]).
+    pub async fn find_album_cover_url(
+        &self,
+        title: &str,
+        artist: &str,
+    ) -> Result<Option<String>, CoreError> {
+        let results = self
+            .search_albums(&format!("{} {}", artist, title), 10, 0, None)
+            .await?;
+        Ok(pick_album_cover(&results.items, title, artist))
+    }
+
     /// Search for tracks
     pub async fn search_tracks(
         &self,
@@ -1017,7 +1153,8 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
 
     // ==================== Streaming ====================
 
-    /// Get stream URL for a track with quality fallback
+    /// Get a stream URL for playing a track, with quality fallback. The
+    /// tier is first capped to what the measured link sustains.
     pub async fn get_stream_url(
         &self,
         track_id: u64,
@@ -1026,6 +1163,7 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         let client = self.client.read().await;
         let client = client.as_ref().ok_or(CoreError::NotInitialized)?;
 
+        let quality = client.bandwidth_capped_quality(quality).await;
         client
             .get_stream_url_with_fallback(track_id, quality)
             .await
@@ -1063,6 +1201,21 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .map_err(|e| CoreError::Playback(e))
     }
 
+    /// Turn podcast mode (time-stretch + silence skipping) on or off for the
+    /// running player. Persisting it is the caller's job (`AudioSettingsStore`).
+    pub fn set_podcast_mode(&self, enabled: bool) -> Result<(), CoreError> {
+        self.player
+            .set_podcast_mode(enabled)
+            .map_err(|e| CoreError::Playback(e))
+    }
+
+    /// Set the podcast-mode playback speed (0.5 - 3.0)
+    pub fn set_playback_speed(&self, speed: f32) -> Result<(), CoreError> {
+        self.player
+            .set_playback_speed(speed)
+            .map_err(|e| CoreError::Playback(e))
+    }
+
     /// Get current playback state
     pub fn get_playback_state(&self) -> PlaybackState {
         let state = &self.player.state;
@@ -1080,6 +1233,98 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         Arc::clone(&self.player)
     }
 
+    /// ALSA hot-plug notification (from the `/dev/snd` watcher). On removal,
+    /// moves playback off the device if it was the active one. Returns
+    /// whether the active device was lost.
+    pub async fn handle_audio_hotplug(&self, card: String, connected: bool) -> bool {
+        let device_lost = !connected && self.player.handle_device_removed();
+        self.emit(CoreEvent::AudioDeviceHotPlug {
+            card,
+            connected,
+            device_lost,
+        })
+        .await;
+        device_lost
+    }
+
+    /// Queue local files (`(track_id, path)`) for background loudness
+    /// analysis, so normalization has a gain cached before they play. A
+    /// no-op while normalization is off. Emits `LoudnessAnalyzed` per file as
+    /// results land; returns how many files were newly queued (already
+    /// cached or already queued ones are skipped).
+    pub fn queue_loudness_analysis(&self, tracks: Vec<(u64, PathBuf)>) -> usize {
+        let Some(target_lufs) = self.player.normalization_target_lufs() else {
+            return 0;
+        };
+        let queue = self.loudness_queue.get_or_init(|| match LoudnessCache::new() {
+            Ok(cache) => Some(LoudnessAnalysisQueue::new(Arc::new(cache))),
+            Err(e) => {
+                log::warn!("[QbzCore] Loudness queue unavailable: {}", e);
+                None
+            }
+        });
+        let Some(queue) = queue else {
+            return 0;
+        };
+        tracks
+            .into_iter()
+            .filter(|(track_id, path)| {
+                let adapter = self.adapter.clone();
+                queue.enqueue(*track_id, path.clone(), target_lufs, move |track_id, gain_db| {
+                    tokio::spawn(async move {
+                        adapter
+                            .on_event(CoreEvent::LoudnessAnalyzed { track_id, gain_db })
+                            .await;
+                    });
+                })
+            })
+            .count()
+    }
+
+    /// The audible part of a local file, for the skip-silence setting. None
+    /// while the setting is off, or when the file can't be scanned. A cache
+    /// hit is instant; otherwise the whole file is decoded on the blocking
+    /// pool and the result cached under the file (path, size, mtime), so a
+    /// rescan reusing `track_id` for another file never picks it up.
+    pub async fn audio_bounds(&self, track_id: u64, path: PathBuf) -> Option<AudioBounds> {
+        if !self.player.skip_silence_enabled() {
+            return None;
+        }
+        let cache = self
+            .silence_cache
+            .get_or_init(|| match LoudnessCache::new() {
+                Ok(cache) => Some(Arc::new(cache)),
+                Err(e) => {
+                    log::warn!("[QbzCore] Silence cache unavailable: {}", e);
+                    None
+                }
+            })
+            .clone()?;
+        if let Some(bounds) = cache.get_bounds(&path) {
+            return Some(bounds);
+        }
+        let scanned = tokio::task::spawn_blocking(move || {
+            SilenceDetector::default()
+                .find_audio_bounds(&path)
+                .map(|bounds| (path, bounds))
+        })
+        .await;
+        match scanned {
+            Ok(Ok((path, bounds))) => {
+                cache.set_bounds(&path, bounds);
+                Some(bounds)
+            }
+            Ok(Err(e)) => {
+                log::warn!("[QbzCore] Silence scan of track {} failed: {}", track_id, e);
+                None
+            }
+            Err(e) => {
+                log::warn!("[QbzCore] Silence scan task failed: {}", e);
+                None
+            }
+        }
+    }
+
     // ==================== Favorites ====================
 
     /// Get favorites (albums, tracks, or artists)
@@ -1295,6 +1540,57 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .map_err(CoreError::Api)
     }
 
+    /// Save the queue as a new Qobuz playlist named `name`. Local and Plex
+    /// tracks are skipped (counted in the summary); the Qobuz ones are added
+    /// in batches of 50. If a batch fails the half-filled playlist is
+    /// deleted again, so the user never ends up with a partial copy.
+    /// Returns the new playlist's id for navigation.
+    pub async fn export_queue_as_qobuz_playlist(
+        &self,
+        name: &str,
+    ) -> Result<PlaylistExportSummary, CoreError> {
+        const BATCH: usize = 50;
+
+        let (track_ids, skipped_local) = self.queue.read().await.export_as_playlist();
+        if track_ids.is_empty() {
+            return Err(CoreError::Queue(
+                "The queue has no Qobuz tracks to export".to_string(),
+            ));
+        }
+
+        let client = self.client.read().await;
+        let client = client.as_ref().ok_or(CoreError::NotInitialized)?;
+
+        let playlist = client
+            .create_playlist(name, None, false)
+            .await
+            .map_err(CoreError::Api)?;
+        for batch in track_ids.chunks(BATCH) {
+            if let Err(e) = client.add_tracks_to_playlist(playlist.id, batch).await {
+                if let Err(del) = client.delete_playlist(playlist.id).await {
+                    log::warn!(
+                        "Could not delete partially exported playlist {}: {}",
+                        playlist.id,
+                        del
+                    );
+                }
+                return Err(CoreError::Api(e));
+            }
+        }
+        log::info!(
+            "Exported queue as playlist {} ({} tracks, {} local skipped)",
+            playlist.id,
+            track_ids.len(),
+            skipped_local
+        );
+
+        Ok(PlaylistExportSummary {
+            playlist_id: playlist.id,
+            exported: track_ids.len(),
+            skipped_local,
+        })
+    }
+
     /// Follow (subscribe to) a Qobuz playlist so it appears in the user's Qobuz
     /// account across every Qobuz client (and in their user-playlists list).
     pub async fn subscribe_playlist(&self, playlist_id: u64) -> Result<(), CoreError> {
@@ -1486,6 +1782,28 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .map_err(CoreError::Api)
     }
 
+    /// Get an artist's biography (cached for 24h, served stale when offline)
+    pub async fn get_artist_biography(
+        &self,
+        artist_id: u64,
+    ) -> Result<ArtistBiography, CoreError> {
+        let client = self.client.read().await;
+        let client = client.as_ref().ok_or(CoreError::NotInitialized)?;
+
+        client
+            .get_artist_biography(artist_id)
+            .await
+            .map_err(CoreError::Api)
+    }
+
+    /// Evict cached artist biographies; returns how many were removed
+    pub async fn clear_artist_biography_cache(&self) -> Result<usize, CoreError> {
+        let client = self.client.read().await;
+        let client = client.as_ref().ok_or(CoreError::NotInitialized)?;
+
+        Ok(client.clear_artist_biography_cache())
+    }
+
     /// Get similar artists
     pub async fn get_similar_artists(
         &self,
@@ -1589,7 +1907,8 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
     /// richer alternative to the Qobuz `/radio/artist` endpoint. Builds
     /// a session pool (seed tracks + similar artists + second-degree)
     /// in the local radio DB, pulls up to 50 track ids from the engine,
-    /// and resolves them to full tracks.
+    /// and resolves them to full tracks. `excluded_artist_ids` (the user's
+    /// disliked artists) are kept out of the pool.
     ///
     /// The radio DB uses a `!Send` rusqlite connection, so the pool
     /// build + pull run on blocking threads (mirroring the Tauri
@@ -1597,6 +1916,7 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
     pub async fn create_smart_artist_radio(
         &self,
         artist_id: u64,
+        excluded_artist_ids: Vec<u64>,
     ) -> Result<Vec<Track>, CoreError> {
         let client = {
             let guard = self.client.read().await;
@@ -1609,7 +1929,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             let builder = qbz_radio::RadioPoolBuilder::new(
                 &db,
                 &build_client,
-                qbz_radio::BuildRadioOptions::default(),
+                qbz_radio::BuildRadioOptions {
+                    excluded_artist_ids,
+                    ..Default::default()
+                },
             );
             let session = tokio::runtime::Handle::current()
                 .block_on(builder.create_artist_radio(artist_id))?;
@@ -1658,12 +1981,15 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
     /// blacklist lives in the UI/integration layer; callers that need it drop
     /// blacklisted rows after this returns). `seed_track_name` is accepted for
     /// call-site parity with the Tauri command (the local builder seeds by id,
-    /// so the name is not needed here) and currently unused.
+    /// so the name is not needed here) and currently unused. Disliked
+    /// artists are passed in as `excluded_artist_ids`, as for the artist
+    /// radio.
     pub async fn create_smart_track_radio(
         &self,
         track_id: u64,
         artist_id: u64,
         _seed_track_name: String,
+        excluded_artist_ids: Vec<u64>,
     ) -> Result<Vec<Track>, CoreError> {
         let client = {
             let guard = self.client.read().await;
@@ -1676,7 +2002,10 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             let builder = qbz_radio::RadioPoolBuilder::new(
                 &db,
                 &build_client,
-                qbz_radio::BuildRadioOptions::default(),
+                qbz_radio::BuildRadioOptions {
+                    excluded_artist_ids,
+                    ..Default::default()
+                },
             );
             let session = tokio::runtime::Handle::current()
                 .block_on(builder.create_track_radio(track_id, artist_id))?;
@@ -1721,6 +2050,89 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
         Ok(tracks)
     }
 
+    /// Genre radio: seeds a Smart Song Radio from the genre's new releases.
+    ///
+    /// Samples up to `seed_count` tracks from random recent albums of
+    /// `genre_id` (one per album, so the seeds spread across artists), then
+    /// expands the first seed with `create_smart_track_radio`. The queue
+    /// starts with the seeds and is topped up from the radio pull to
+    /// [`GENRE_RADIO_LENGTH`] tracks. Blacklisted artists and albums are
+    /// dropped from both the seeds and the expansion; disliked artists are
+    /// kept out of the radio pool as for the other smart radios.
+    pub async fn create_genre_radio(
+        &self,
+        genre_id: u64,
+        seed_count: usize,
+        excluded_artist_ids: Vec<u64>,
+        blacklist: &BlacklistFilter,
+        album_blacklist: &AlbumBlacklistFilter,
+    ) -> Result<Vec<Track>, CoreError> {
+        let salt = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos().to_string())
+            .unwrap_or_default();
+        let mut albums: Vec<Album> = self
+            .get_featured_albums("new-releases", 50, 0, Some(genre_id))
+            .await?
+            .items
+            .into_iter()
+            .filter(|album| !album_blacklisted(album, blacklist, album_blacklist))
+            .filter(|album| !excluded_artist_ids.contains(&album.artist.id))
+            .collect();
+        shuffle_with_seed(&mut albums, &genre_id.to_string(), Some(&salt));
+
+        let mut seeds: Vec<Track> = Vec::new();
+        let mut seed_artist_id = 0;
+        for album in albums {
+            if seeds.len() >= seed_count.max(1) {
+                break;
+            }
+            let Ok(full) = self.get_album(&album.id).await else {
+                continue;
+            };
+            let mut candidates: Vec<u64> = full
+                .tracks
+                .map(|container| container.items)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|track| track.streamable && track.duration > 0)
+                .map(|track| track.id)
+                .collect();
+            shuffle_with_seed(&mut candidates, &album.id, Some(&salt));
+            // Album tracks come without album metadata; re-fetch the pick so
+            // the queue row and the radio seed get the full track.
+            let Some(&track_id) = candidates.first() else {
+                continue;
+            };
+            if let Ok(track) = self.get_track(track_id).await {
+                if !track_blacklisted(&track, blacklist, album_blacklist) {
+                    if seeds.is_empty() {
+                        seed_artist_id = track.performer.as_ref().map_or(album.artist.id, |p| p.id);
+                    }
+                    seeds.push(track);
+                }
+            }
+        }
+
+        let first = seeds
+            .first()
+            .ok_or_else(|| CoreError::Internal(format!("no seed tracks for genre {genre_id}")))?;
+        let expansion = self
+            .create_smart_track_radio(
+                first.id,
+                seed_artist_id,
+                first.title.clone(),
+                excluded_artist_ids,
+            )
+            .await?;
+        Ok(assemble_genre_radio(
+            seeds,
+            expansion,
+            blacklist,
+            album_blacklist,
+        ))
+    }
+
     /// Get artist with albums (for album pagination)
     pub async fn get_artist_with_albums(
         &self,
@@ -2075,6 +2487,183 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .map_err(|e| CoreError::Internal(e.to_string()))
     }
 
+    /// Fetch an artist's MusicBrainz biography (the artist annotation).
+    /// Returns `None` if the artist has none.
+    pub async fn musicbrainz_get_artist_biography(
+        &self,
+        mbid: &str,
+    ) -> Result<Option<String>, CoreError> {
+        self.musicbrainz
+            .get_artist_biography(mbid)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))
+    }
+
+    /// MusicBrainz id of the release best matching `title` by `artist`.
+    /// Returns `None` when no result scores at least 90 (the search score
+    /// is 100 for an exact title + artist match).
+    pub async fn musicbrainz_find_release(
+        &self,
+        title: &str,
+        artist: &str,
+    ) -> Result<Option<String>, CoreError> {
+        let response = self
+            .musicbrainz
+            .search_release(title, artist)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))?;
+        // Results come sorted by score, best first.
+        Ok(response
+            .releases
+            .into_iter()
+            .find(|r| r.score.unwrap_or(0) >= 90)
+            .map(|r| r.id))
+    }
+
+    /// MusicBrainz recording best matching `title` by `artist`: its id and
+    /// the ISRCs the search result lists (often none; see
+    /// [`Self::musicbrainz_recording_isrcs`]). `None` when no result scores
+    /// at least 90.
+    pub async fn musicbrainz_find_recording(
+        &self,
+        title: &str,
+        artist: &str,
+    ) -> Result<Option<(String, Vec<String>)>, CoreError> {
+        let response = self
+            .musicbrainz
+            .search_recording(title, artist)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))?;
+        Ok(response
+            .recordings
+            .into_iter()
+            .find(|r| r.score.unwrap_or(0) >= 90)
+            .map(|r| (r.id, r.isrcs.unwrap_or_default())))
+    }
+
+    /// ISRCs of a MusicBrainz recording (empty when it has none).
+    pub async fn musicbrainz_recording_isrcs(
+        &self,
+        recording_mbid: &str,
+    ) -> Result<Vec<String>, CoreError> {
+        self.musicbrainz
+            .get_recording_isrcs(recording_mbid)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))
+    }
+
+    /// Resolve a whole album's ISRCs to MusicBrainz recordings. Cached
+    /// ISRCs are served from the recording cache; the rest go through
+    /// batched searches (`resolve_tracks_batch`, 25 ISRCs a request) and
+    /// are cached. Every ISRC asked for is a key, `None` when MusicBrainz
+    /// has no recording for it.
+    pub async fn musicbrainz_resolve_tracks(
+        &self,
+        isrcs: &[String],
+    ) -> Result<HashMap<String, Option<ResolvedTrack>>, CoreError> {
+        let mut resolved = HashMap::new();
+        let mut missing = Vec::new();
+        if let Ok(guard) = self.musicbrainz_cache.lock() {
+            for isrc in isrcs {
+                match guard
+                    .as_ref()
+                    .and_then(|c| c.get_track(isrc).ok().flatten())
+                {
+                    Some(track) => {
+                        resolved.insert(isrc.clone(), Some(track));
+                    }
+                    None => missing.push(isrc.clone()),
+                }
+            }
+        } else {
+            missing = isrcs.to_vec();
+        }
+        if missing.is_empty() {
+            return Ok(resolved);
+        }
+
+        let fetched = self
+            .musicbrainz
+            .resolve_tracks_batch(&missing)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))?;
+        if let Ok(guard) = self.musicbrainz_cache.lock() {
+            if let Some(cache) = guard.as_ref() {
+                for (isrc, track) in &fetched {
+                    if let Some(track) = track {
+                        let _ = cache.put_track(isrc, track);
+                    }
+                }
+            }
+        }
+        resolved.extend(fetched);
+        Ok(resolved)
+    }
+
+    /// Classical work a track performs (composer, opus, catalogue number,
+    /// key), found through its ISRC. Both the recording and its work are
+    /// cached, so reopening a track costs no request. `None` when
+    /// MusicBrainz has no recording for the ISRC or the recording no work.
+    pub async fn musicbrainz_track_work(
+        &self,
+        isrc: &str,
+    ) -> Result<Option<WorkMetadata>, CoreError> {
+        let cached = match self.musicbrainz_cache.lock() {
+            Ok(guard) => guard
+                .as_ref()
+                .and_then(|cache| cache.get_track(isrc).ok().flatten()),
+            Err(_) => None,
+        };
+        let track = match cached {
+            Some(track) => track,
+            None => {
+                let resolved = self
+                    .musicbrainz
+                    .resolve_track("", "", Some(isrc))
+                    .await
+                    .map_err(|e| CoreError::Internal(e.to_string()))?;
+                let Some(track) = resolved else {
+                    return Ok(None);
+                };
+                if let Ok(guard) = self.musicbrainz_cache.lock() {
+                    if let Some(cache) = guard.as_ref() {
+                        let _ = cache.put_track(isrc, &track);
+                    }
+                }
+                track
+            }
+        };
+        if track.work.is_some() {
+            return Ok(track.work);
+        }
+
+        let looked_up = match self.musicbrainz_cache.lock() {
+            Ok(guard) => guard.as_ref().and_then(|cache| {
+                cache
+                    .get_recording_work(&track.recording_mbid)
+                    .ok()
+                    .flatten()
+            }),
+            Err(_) => None,
+        };
+        if let Some(work) = looked_up {
+            return Ok(work);
+        }
+
+        let work = self
+            .musicbrainz
+            .get_recording_with_work(&track.recording_mbid)
+            .await
+            .map_err(|e| CoreError::Internal(e.to_string()))?
+            .work;
+        if let Ok(guard) = self.musicbrainz_cache.lock() {
+            if let Some(cache) = guard.as_ref() {
+                let _ = cache.set_recording_work(&track.recording_mbid, work.as_ref());
+            }
+        }
+        Ok(work)
+    }
+
     /// Generate playlist "Suggested Songs" via the artist_vectors engine.
     /// Resolves each playlist artist NAME to a confident MusicBrainz id, then
     /// runs the SuggestionsEngine over the core-owned clients + the per-user
@@ -2141,6 +2730,69 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             .await
     }
 
+    /// Rebuild the artist vectors for the user's played artists
+    /// (`(qobuz_id, name, mbid)`), linking the ones whose MusicBrainz / Qobuz
+    /// relations overlap so suggestions lean toward the user's own
+    /// listening.
+    ///
+    /// `mbid` is the id an earlier rebuild resolved the artist to; those
+    /// artists keep their stored vectors and are only relinked. Artists
+    /// without one are new: their names resolve to MusicBrainz ids the same
+    /// way as [`Self::generate_playlist_suggestions`] and their vectors are
+    /// built. Returns what each new artist resolved to (`None` when
+    /// MusicBrainz has no match), for the caller to remember.
+    pub async fn rebuild_artist_vectors(
+        &self,
+        artists: Vec<(u64, String, Option<String>)>,
+    ) -> Result<Vec<(u64, Option<String>)>, String> {
+        use std::collections::HashSet;
+
+        let mut batch: Vec<qbz_reco::BatchArtist> = Vec::new();
+        let mut resolutions: Vec<(u64, Option<String>)> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        for (qobuz_id, name, known) in artists {
+            let rebuild = known.is_none();
+            let mbid = match known {
+                Some(mbid) => Some(mbid),
+                None => {
+                    let mbid = match self.musicbrainz_resolve_artist(&name).await {
+                        Ok(resolved) => resolved.map(|r| r.mbid),
+                        // Not remembered: retried on the next rebuild.
+                        Err(_) => continue,
+                    };
+                    resolutions.push((qobuz_id, mbid.clone()));
+                    mbid
+                }
+            };
+            if let Some(mbid) = mbid.filter(|m| seen.insert(m.clone())) {
+                batch.push(qbz_reco::BatchArtist {
+                    mbid,
+                    name,
+                    qobuz_id: Some(qobuz_id),
+                    rebuild,
+                });
+            }
+        }
+
+        if batch.is_empty() {
+            return Ok(resolutions);
+        }
+
+        let builder = qbz_reco::ArtistVectorBuilder::new(
+            self.artist_vectors.clone(),
+            self.musicbrainz.clone(),
+            self.musicbrainz_cache.clone(),
+            self.client.clone(),
+            qbz_reco::RelationshipWeights::default(),
+        );
+        let built = builder.build_batch(&batch).await?;
+        log::info!(
+            "[Core] artist vectors: built {built}, relinked {}",
+            batch.len()
+        );
+        Ok(resolutions)
+    }
+
     /// Fetch the artist metadata (location, life_span, genre seeds) for
     /// the Origin section of the artist network sidebar. Resolves the
     /// real country from the begin_area hierarchy when a city-level
@@ -2191,10 +2843,9 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
     }
 
     /// Fetch the artist relationships (band members, member-of groups,
-    /// collaborators) for the Relationships section of the sidebar.
-    /// Splits `member of band` by direction: backward direction lists
-    /// members of *this* artist (still-active vs ended -> past), forward
-    /// direction lists groups this artist is a member of.
+    /// collaborators, influences) for the Relationships section of the
+    /// sidebar. See `ArtistRelationships`'s `From` impl for how the
+    /// directional relation types are split.
     pub async fn musicbrainz_get_artist_relationships(
         &self,
         mbid: &str,
@@ -2207,64 +2858,12 @@ impl<A: FrontendAdapter + Send + Sync + 'static> QbzCore<A> {
             }
         }
 
-        let artist = self
+        let result = self
             .musicbrainz
-            .get_artist_with_relations(mbid)
+            .get_artist_relationships(mbid)
             .await
             .map_err(|e| CoreError::Internal(e.to_string()))?;
 
-        let mut members = Vec::new();
-        let mut past_members = Vec::new();
-        let mut groups = Vec::new();
-        let mut collaborators = Vec::new();
-
-        if let Some(relations) = &artist.relations {
-            for relation in relations {
-                let Some(related_artist) = &relation.artist else {
-                    continue;
-                };
-
-                let related = RelatedArtist {
-                    mbid: related_artist.id.clone(),
-                    name: related_artist.name.clone(),
-                    role: relation
-                        .attributes
-                        .as_ref()
-                        .and_then(|a| a.first().cloned()),
-                    period: Some(Period {
-                        begin: relation.begin.clone(),
-                        end: relation.end.clone(),
-                    }),
-                    ended: relation.ended.unwrap_or(false),
-                };
-
-                match relation.relation_type.as_str() {
-                    "member of band" => {
-                        if relation.direction.as_deref() == Some("backward") {
-                            if related.ended {
-                                past_members.push(related);
-                            } else {
-                                members.push(related);
-                            }
-                        } else {
-                            groups.push(related);
-                        }
-                    }
-                    "collaboration" => {
-                        collaborators.push(related);
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        let result = ArtistRelationships {
-            members,
-            past_members,
-            groups,
-            collaborators,
-        };
-
         if let Ok(guard) = self.musicbrainz_cache.lock() {
             if let Some(cache) = guard.as_ref() {
                 let _ = cache.set_artist_relations(mbid, &result);
@@ -2768,6 +3367,45 @@ pub fn normalize_artist_name(name: &str) -> String {
         .join(" ")
 }
 
+/// Comparable form of an album title or artist name: lowercase
+/// alphanumerics only, without trailing `(...)` / `[...]` edition notes.
+fn album_match_key(value: &str) -> String {
+    let mut trimmed = value.trim();
+    while let Some(close) = trimmed.chars().last().filter(|c| matches!(*c, ')' | ']')) {
+        let open = if close == ')' { '(' } else { '[' };
+        match trimmed.rfind(open) {
+            Some(start) if start > 0 => trimmed = trimmed[..start].trim_end(),
+            _ => break,
+        }
+    }
+    trimmed
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Largest cover of the first album in `albums` that is `title` by
+/// `artist`, ignoring case, punctuation and edition notes. Search results
+/// are only a hint: a loose match would put another record's cover on the
+/// album.
+fn pick_album_cover(albums: &[Album], title: &str, artist: &str) -> Option<String> {
+    let (title, artist) = (album_match_key(title), album_match_key(artist));
+    if title.is_empty() || artist.is_empty() {
+        return None;
+    }
+    albums
+        .iter()
+        .filter(|album| album_match_key(&album.title) == title)
+        .filter(|album| album_match_key(&album.artist.name) == artist)
+        .find_map(|album| {
+            let image = &album.image;
+            [&image.mega, &image.extralarge, &image.large]
+                .into_iter()
+                .find_map(Clone::clone)
+        })
+}
+
 /// Deterministic shuffle keyed by `seed_mbid` (and optionally a tag).
 /// Same artist page produces the same order across runs; different
 /// artist or different fallback tag produces a different order.
@@ -2792,6 +3430,28 @@ fn shuffle_with_seed<T>(items: &mut Vec<T>, seed_mbid: &str, secondary_tag: Opti
     }
 }
 
+/// Length of the queue built by [`QbzCore::create_genre_radio`].
+pub const GENRE_RADIO_LENGTH: usize = 20;
+
+/// Queue of a genre radio: the sampled seeds first, then the radio
+/// expansion, without repeats or blacklisted tracks, capped at
+/// [`GENRE_RADIO_LENGTH`].
+fn assemble_genre_radio(
+    seeds: Vec<Track>,
+    expansion: Vec<Track>,
+    blacklist: &BlacklistFilter,
+    album_blacklist: &AlbumBlacklistFilter,
+) -> Vec<Track> {
+    let mut seen = std::collections::HashSet::new();
+    seeds
+        .into_iter()
+        .chain(expansion)
+        .filter(|track| !track_blacklisted(track, blacklist, album_blacklist))
+        .filter(|track| seen.insert(track.id))
+        .take(GENRE_RADIO_LENGTH)
+        .collect()
+}
+
 /// Pure set-intersection behind [`QbzCore::check_playlist_duplicates`] — split
 /// out so the duplicate logic is unit-testable without a live Qobuz client.
 /// `existing` = the playlist's current track ids; `track_ids` = the ids the
@@ -2954,6 +3614,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn album_cover_requires_title_and_artist_match() {
+        let album = |title: &str, artist: &str, cover: &str| {
+            let mut album = album_with_artists(1, &[]);
+            album.title = title.to_string();
+            album.artist.name = artist.to_string();
+            album.image.large = Some(cover.to_string());
+            album
+        };
+        let results = vec![
+            album("OK Computer OKNOTOK 1997 2017", "Radiohead", "oknotok.jpg"),
+            album("OK Computer (Remastered)", "Radiohead", "okc.jpg"),
+        ];
+        assert_eq!(
+            pick_album_cover(&results, "OK Computer", "radiohead"),
+            Some("okc.jpg".to_string())
+        );
+        assert_eq!(pick_album_cover(&results, "OK Computer", "Muse"), None);
+        assert_eq!(pick_album_cover(&results, "Kid A", "Radiohead"), None);
+    }
+
     #[test]
     fn album_blacklisted_blocks_on_primary_artist() {
         let album = album_with_artists(1, &[]);
@@ -3192,4 +3873,26 @@ mod tests {
         assert_eq!(r.total_tracks, 0);
         assert_eq!(r.duplicate_count, 0);
     }
+
+    #[test]
+    fn genre_radio_starts_with_seeds_and_caps_length() {
+        let track = |id: u64, performer: u64| Track {
+            id,
+            ..track_with(Some(performer), None)
+        };
+        let seeds = vec![track(1, 10), track(2, 20)];
+        // The track radio hoists its seed (1), repeats seed 2 and pulls a
+        // blacklisted artist (99).
+        let expansion = std::iter::once(track(1, 10))
+            .chain(std::iter::once(track(2, 20)))
+            .chain(std::iter::once(track(3, 99)))
+            .chain((100..130).map(|id| track(id, 30)))
+            .collect();
+        let bl: BlacklistFilter = [99].into_iter().collect();
+        let queue = assemble_genre_radio(seeds, expansion, &bl, &no_albums());
+        assert_eq!(queue.len(), GENRE_RADIO_LENGTH);
+        let ids: Vec<u64> = queue.iter().map(|t| t.id).collect();
+        assert_eq!(&ids[..3], &[1, 2, 100]);
+        assert!(!ids.contains(&3));
+    }
 }
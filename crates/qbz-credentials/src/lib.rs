@@ -36,6 +36,10 @@ use std::time::Duration;
 const SERVICE_NAME: &str = "qbz";
 const QOBUZ_CREDENTIALS_KEY: &str = "qobuz-credentials";
 const FALLBACK_FILE_NAME: &str = ".qbz-auth";
+/// Per-account fallback files are `.qbz-auth-{account_id}`; keyring keys are
+/// `qbz-{account_id}`.
+const ACCOUNT_FALLBACK_PREFIX: &str = ".qbz-auth-";
+const ACCOUNT_KEYRING_PREFIX: &str = "qbz-";
 const LEGACY_FALLBACK_FILE_NAME: &str = ".qbz-auth.legacy";
 const OAUTH_TOKEN_FILE_NAME: &str = ".qbz-oauth-token";
 const INSTALLATION_SALT_FILE_NAME: &str = ".qbz-cred-salt";
@@ -844,6 +848,160 @@ pub fn clear_qobuz_credentials() -> Result<(), String> {
     Ok(())
 }
 
+// ─── Saved accounts (multi-account switching) ─────────────────────────────────
+//
+// Family-plan and work-account users keep several Qobuz logins. Each one is
+// stored exactly like the single-account credentials (encrypted file first,
+// keyring as a write-through cache) but under its own file and keyring key,
+// so switching never touches the others. The file names double as the
+// account index: the keyring cannot be enumerated, the config dir can.
+
+/// One saved account, as listed in the account switcher.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountEntry {
+    pub account_id: String,
+    pub email: String,
+}
+
+/// The account id for a Qobuz user: the numeric user id. Stable across
+/// email changes, and two spellings of one address can't become two
+/// accounts.
+pub fn account_id_for_user(user_id: u64) -> String {
+    user_id.to_string()
+}
+
+/// Reject ids that would escape the config dir or collide with other files.
+fn validate_account_id(account_id: &str) -> Result<(), String> {
+    let valid = !account_id.is_empty()
+        && account_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid account id: {:?}", account_id))
+    }
+}
+
+fn account_fallback_path_at(root: &Path, account_id: &str) -> PathBuf {
+    root.join(format!("{ACCOUNT_FALLBACK_PREFIX}{account_id}"))
+}
+
+fn account_keyring_key(account_id: &str) -> String {
+    format!("{ACCOUNT_KEYRING_PREFIX}{account_id}")
+}
+
+fn write_account_file(
+    root: &Path,
+    portal: PortalKey,
+    account_id: &str,
+    credentials: &QobuzCredentials,
+) -> Result<(), String> {
+    validate_account_id(account_id)?;
+    let encrypted = encrypt_credentials_at(root, portal, credentials)?;
+    write_private_file(&account_fallback_path_at(root, account_id), encrypted)
+}
+
+fn read_account_file(
+    root: &Path,
+    portal: PortalKey,
+    account_id: &str,
+) -> Result<Option<QobuzCredentials>, String> {
+    validate_account_id(account_id)?;
+    let path = account_fallback_path_at(root, account_id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    tighten_private_file_mode(&path);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read account credentials file: {}", e))?;
+    decrypt_credentials_at(root, portal, &content).map(Some)
+}
+
+/// Every `.qbz-auth-{id}` under `root` that decrypts, sorted by email.
+/// Undecryptable files are skipped (and logged), never fatal.
+fn list_account_files(root: &Path, portal: PortalKey) -> Vec<AccountEntry> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut accounts: Vec<AccountEntry> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let account_id = name.strip_prefix(ACCOUNT_FALLBACK_PREFIX)?.to_string();
+            validate_account_id(&account_id).ok()?;
+            match read_account_file(root, portal, &account_id) {
+                Ok(Some(credentials)) => Some(AccountEntry {
+                    account_id,
+                    email: credentials.email,
+                }),
+                Ok(None) => None,
+                Err(e) => {
+                    log::warn!("[Credentials] Skipping saved account {}: {}", account_id, e);
+                    None
+                }
+            }
+        })
+        .collect();
+    accounts.sort_by(|a, b| a.email.to_lowercase().cmp(&b.email.to_lowercase()));
+    accounts
+}
+
+/// Save the email+password of one of several accounts. File is
+/// authoritative; the keyring (`qbz-{account_id}`) is best effort.
+pub fn save_qobuz_credentials_for(
+    account_id: &str,
+    email: &str,
+    password: &str,
+) -> Result<(), String> {
+    let root = config_qbz_root().ok_or("Could not determine config directory")?;
+    let credentials = QobuzCredentials {
+        email: email.to_string(),
+        password: password.to_string(),
+    };
+    write_account_file(&root, PortalKey::Session, account_id, &credentials)?;
+    log::info!("[Credentials] Saved credentials for account {}", account_id);
+
+    let json = serde_json::to_string(&credentials).unwrap_or_default();
+    if !json.is_empty() && keyring_set(&account_keyring_key(account_id), &json) {
+        log::debug!("[Credentials] Account credentials also saved to keyring");
+    }
+    Ok(())
+}
+
+/// Load the credentials saved for `account_id`. Keyring first, then file.
+pub fn load_qobuz_credentials_for(account_id: &str) -> Result<Option<QobuzCredentials>, String> {
+    validate_account_id(account_id)?;
+    if let Some(json) = keyring_get(&account_keyring_key(account_id)) {
+        if let Ok(credentials) = serde_json::from_str::<QobuzCredentials>(&json) {
+            return Ok(Some(credentials));
+        }
+    }
+    let root = config_qbz_root().ok_or("Could not determine config directory")?;
+    read_account_file(&root, PortalKey::Session, account_id)
+}
+
+/// Forget a saved account (keyring entry and file).
+pub fn remove_qobuz_credentials_for(account_id: &str) -> Result<(), String> {
+    validate_account_id(account_id)?;
+    keyring_delete(&account_keyring_key(account_id));
+    let root = config_qbz_root().ok_or("Could not determine config directory")?;
+    let path = account_fallback_path_at(&root, account_id);
+    if path.exists() {
+        fs::remove_file(&path)
+            .map_err(|e| format!("Failed to remove account credentials file: {}", e))?;
+    }
+    Ok(())
+}
+
+/// All saved accounts, sorted by email.
+pub fn list_saved_accounts() -> Vec<AccountEntry> {
+    match config_qbz_root() {
+        Some(root) => list_account_files(&root, PortalKey::Session),
+        None => Vec::new(),
+    }
+}
+
 // ─── OAuth token persistence ──────────────────────────────────────────────────
 //
 // OAuth login produces a `user_auth_token` instead of email+password.
@@ -1198,6 +1356,47 @@ mod tests {
         assert!(after_clear.is_none());
     }
 
+    #[test]
+    fn account_ids_are_file_name_safe() {
+        assert_eq!(account_id_for_user(1234567), "1234567");
+        assert!(validate_account_id(&account_id_for_user(u64::MAX)).is_ok());
+        assert!(validate_account_id("../escape").is_err());
+        assert!(validate_account_id("").is_err());
+    }
+
+    #[test]
+    fn accounts_are_stored_separately_and_listed() {
+        let dir = tempfile::tempdir().unwrap();
+        for (id, email) in [("work", "me@work.example"), ("family", "me@home.example")] {
+            let credentials = QobuzCredentials {
+                email: email.to_string(),
+                password: format!("pw-{id}"),
+            };
+            write_account_file(dir.path(), PortalKey::Never, id, &credentials).unwrap();
+        }
+
+        let work = read_account_file(dir.path(), PortalKey::Never, "work")
+            .unwrap()
+            .unwrap();
+        assert_eq!(work.password, "pw-work");
+        assert!(dir.path().join(".qbz-auth-family").exists());
+
+        let listed = list_account_files(dir.path(), PortalKey::Never);
+        assert_eq!(
+            listed,
+            vec![
+                AccountEntry {
+                    account_id: "family".to_string(),
+                    email: "me@home.example".to_string(),
+                },
+                AccountEntry {
+                    account_id: "work".to_string(),
+                    email: "me@work.example".to_string(),
+                },
+            ]
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn write_private_file_is_owner_rw_only() {
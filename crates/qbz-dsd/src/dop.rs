@@ -201,6 +201,23 @@ mod tests {
         assert_eq!(out[0], ((0x05 << 16) | 0x6969) << 8);
         assert_eq!(out[2], ((0xFA << 16) | 0x6969) << 8);
     }
+
+    #[test]
+    fn s32le_wire_bytes_carry_marker_in_byte_3() {
+        // What snd_pcm_writei actually sends for a stereo DSD64 capture:
+        // byte 0 padding, bytes 1-2 DSD payload (later bits low), byte 3 marker.
+        let mut p = DopPacker::new();
+        let mut out = Vec::new();
+        p.pack(&[vec![0x96, 0x69, 0x0F, 0xF0], vec![0x33, 0xCC, 0x5A, 0xA5]], &mut out);
+        let wire: Vec<u8> = out.iter().flat_map(|w| w.to_le_bytes()).collect();
+        assert_eq!(
+            wire,
+            [
+                0x00, 0x69, 0x96, 0x05, 0x00, 0xCC, 0x33, 0x05, // frame 0
+                0x00, 0xF0, 0x0F, 0xFA, 0x00, 0xA5, 0x5A, 0xFA, // frame 1
+            ]
+        );
+    }
 }
 
 #[cfg(test)]
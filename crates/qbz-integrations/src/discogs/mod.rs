@@ -11,6 +11,12 @@ use std::time::Duration;
 // Cloudflare Workers proxy URL - handles credentials
 const DISCOGS_PROXY_URL: &str = "https://qbz-api-proxy.blitzkriegfc.workers.dev/discogs";
 
+// Direct API, for endpoints that need no credentials (public collections)
+const DISCOGS_API_URL: &str = "https://api.discogs.com";
+
+/// Releases per collection page (the Discogs maximum)
+const COLLECTION_PAGE_SIZE: u32 = 100;
+
 /// Discogs API client
 pub struct DiscogsClient {
     client: Client,
@@ -127,6 +133,74 @@ pub struct DiscogsSearchResultExtended {
     pub cover_image: Option<String>,
 }
 
+/// One page of a user's collection
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct CollectionPage {
+    pub pagination: CollectionPagination,
+    pub releases: Vec<CollectionItem>,
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct CollectionPagination {
+    pub page: u32,
+    pub pages: u32,
+    pub per_page: u32,
+    pub items: u32,
+}
+
+/// A release in a user's collection
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct CollectionItem {
+    pub id: u64,
+    pub instance_id: Option<u64>,
+    pub date_added: Option<String>,
+    pub basic_information: CollectionRelease,
+}
+
+/// Release summary embedded in a collection item
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct CollectionRelease {
+    pub id: u64,
+    pub title: String,
+    /// Release year; Discogs sends 0 when unknown
+    pub year: Option<u32>,
+    #[serde(default)]
+    pub artists: Vec<DiscogsArtist>,
+    #[serde(default)]
+    pub formats: Vec<DiscogsFormat>,
+}
+
+/// Physical format of a release (e.g. "Vinyl", "CD")
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct DiscogsFormat {
+    pub name: String,
+    pub qty: Option<String>,
+    pub descriptions: Option<Vec<String>>,
+}
+
+impl CollectionItem {
+    /// Release year, `None` when Discogs doesn't know it
+    pub fn year(&self) -> Option<u32> {
+        self.basic_information.year.filter(|y| *y > 0)
+    }
+
+    /// First credited artist
+    pub fn artist(&self) -> Option<&str> {
+        self.basic_information
+            .artists
+            .first()
+            .map(|a| a.name.as_str())
+    }
+
+    /// First listed format
+    pub fn format(&self) -> Option<&str> {
+        self.basic_information
+            .formats
+            .first()
+            .map(|f| f.name.as_str())
+    }
+}
+
 impl DiscogsClient {
     /// Create a new Discogs client (proxy handles credentials)
     pub fn new() -> Self {
@@ -563,6 +637,47 @@ impl DiscogsClient {
         Ok(metadata)
     }
 
+    /// Get one page (1-based) of a user's collection, all folders. Only
+    /// public collections are readable: the request carries no credentials.
+    pub async fn get_collection_items(
+        &self,
+        username: &str,
+        page: u32,
+    ) -> Result<CollectionPage, String> {
+        let url = format!(
+            "{}/users/{}/collection/folders/0/releases?page={}&per_page={}",
+            DISCOGS_API_URL,
+            urlencoding::encode(username.trim()),
+            page.max(1),
+            COLLECTION_PAGE_SIZE
+        );
+
+        log::debug!(
+            "Fetching Discogs collection of {} (page {})",
+            username,
+            page
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch collection: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Discogs collection request failed with status: {}",
+                response.status()
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Discogs collection: {}", e))
+    }
+
     /// Download an image to the cache directory
     async fn download_image(&self, image_url: &str, path: &Path) -> Option<()> {
         log::debug!("Downloading Discogs artwork: {}", image_url);
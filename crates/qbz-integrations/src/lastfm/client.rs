@@ -449,10 +449,12 @@ impl LastFmClient {
     }
 
     /// user.getLovedTracks — explicitly loved tracks (strong taste seed).
+    /// Newest love first; paginate via `page` (1-based) until a short page.
     pub async fn get_loved_tracks(
         &self,
         user: &str,
         limit: u32,
+        page: u32,
     ) -> IntegrationResult<Vec<LastFmTrack>> {
         let url = format!("{}/user.getLovedTracks", LASTFM_PROXY_URL);
 
@@ -462,6 +464,7 @@ impl LastFmClient {
             .json(&json!({
                 "user": user,
                 "limit": limit,
+                "page": page,
             }))
             .send()
             .await?;
@@ -404,15 +404,37 @@ impl ListenBrainzClient {
         &self,
         user_name: &str,
         count: u32,
+    ) -> IntegrationResult<Vec<LbListen>> {
+        self.get_user_listens(user_name, None, None, count).await
+    }
+
+    /// One page of scrobble history inside a time window, newest first.
+    ///
+    /// `GET /user/{user_name}/listens?count={count}[&min_ts=..][&max_ts=..]`
+    ///
+    /// Both bounds are exclusive Unix timestamps. Walk the history backwards
+    /// by passing the oldest `listened_at` of the previous page as `max_ts`.
+    /// `count` is clamped to the API maximum of 1000. Response handling is
+    /// the same as [`Self::get_recent_listens`].
+    pub async fn get_user_listens(
+        &self,
+        user_name: &str,
+        min_ts: Option<u64>,
+        max_ts: Option<u64>,
+        count: u32,
     ) -> IntegrationResult<Vec<LbListen>> {
         let token = self.config.lock().await.token.clone();
 
         let url = format!("{}/user/{}/listens", LISTENBRAINZ_API_URL, user_name);
 
-        let mut request = self
-            .client
-            .get(&url)
-            .query(&[("count", count.to_string())]);
+        let mut query = vec![("count", count.min(1000).to_string())];
+        if let Some(min_ts) = min_ts {
+            query.push(("min_ts", min_ts.to_string()));
+        }
+        if let Some(max_ts) = max_ts {
+            query.push(("max_ts", max_ts.to_string()));
+        }
+        let mut request = self.client.get(&url).query(&query);
         if let Some(token) = token {
             request = request.header("Authorization", format!("Token {}", token));
         }
@@ -39,6 +39,7 @@
 
 mod client;
 mod models;
+mod retry;
 
 #[cfg(feature = "cache")]
 pub mod cache;
@@ -49,3 +50,4 @@ pub use models::{
     LbRecordingMeta, Listen, ListenBrainzStatus, ListenType, QueuedListen, SubmitListensPayload,
     TrackMetadata, UserInfo,
 };
+pub use retry::{flush_retry_delay, FLUSH_MAX_ATTEMPTS};
@@ -0,0 +1,41 @@
+//! Retry schedule for draining the offline listen queue.
+//!
+//! Listens are written to the queue before they are submitted, so a failed
+//! flush loses nothing — the rows simply wait for the next drain. Within one
+//! drain the host retries with exponential backoff (1 s, 2 s, 4 s, 8 s) and
+//! gives up after [`FLUSH_MAX_ATTEMPTS`]; the next online edge or shell entry
+//! starts a fresh drain.
+
+use std::time::Duration;
+
+/// Flush attempts per drain before giving up until the next trigger.
+pub const FLUSH_MAX_ATTEMPTS: u32 = 5;
+
+/// Delay before retrying after `attempt` (1-based) failed, or `None` once
+/// the drain should give up.
+pub fn flush_retry_delay(attempt: u32) -> Option<Duration> {
+    if attempt == 0 || attempt >= FLUSH_MAX_ATTEMPTS {
+        return None;
+    }
+    Some(Duration::from_secs(1 << (attempt - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_then_gives_up() {
+        let delays: Vec<_> = (1..=FLUSH_MAX_ATTEMPTS).map(flush_retry_delay).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                Some(Duration::from_secs(8)),
+                None,
+            ]
+        );
+    }
+}
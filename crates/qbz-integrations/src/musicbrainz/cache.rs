@@ -7,6 +7,8 @@ use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use qbz_models::WorkMetadata;
+
 use super::models::{
     ArtistMetadata, ArtistRelationships, ArtistType, LocationDiscoveryResponse, MatchConfidence,
     ResolvedArtist, ResolvedTrack,
@@ -123,6 +125,14 @@ impl MusicBrainzCache {
                 );
                 CREATE INDEX IF NOT EXISTS idx_mb_qobuz_validation_fetched ON mb_qobuz_validation(fetched_at);
 
+                -- Work a recording performs (JSON, 'null' when it has none)
+                CREATE TABLE IF NOT EXISTS mb_recording_works (
+                    recording_mbid TEXT PRIMARY KEY,
+                    data TEXT NOT NULL,
+                    fetched_at INTEGER NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS idx_mb_recording_works_fetched ON mb_recording_works(fetched_at);
+
                 -- V2 resolved tracks (simple cache)
                 CREATE TABLE IF NOT EXISTS resolved_tracks (
                     isrc TEXT PRIMARY KEY,
@@ -489,10 +499,13 @@ impl MusicBrainzCache {
     /// Get cached track by ISRC (V2 structured format)
     pub fn get_track(&self, isrc: &str) -> Result<Option<ResolvedTrack>, String> {
         let result: rusqlite::Result<ResolvedTrack> = self.conn.query_row(
-            "SELECT recording_mbid, title, artist_mbids, release_mbid, isrcs, confidence
-             FROM resolved_tracks WHERE isrc = ?",
+            "SELECT t.recording_mbid, t.title, t.artist_mbids, t.release_mbid, t.isrcs, t.confidence, w.data
+             FROM resolved_tracks t
+             LEFT JOIN mb_recording_works w ON w.recording_mbid = t.recording_mbid
+             WHERE t.isrc = ?",
             [isrc],
             |row| {
+                let work_json: Option<String> = row.get(6)?;
                 let artist_mbids_json: String = row.get(2)?;
                 let isrcs_json: String = row.get(4)?;
                 let confidence_str: String = row.get(5)?;
@@ -509,6 +522,7 @@ impl MusicBrainzCache {
                         "low" => MatchConfidence::Low,
                         _ => MatchConfidence::None,
                     },
+                    work: work_json.and_then(|json| serde_json::from_str(&json).ok().flatten()),
                 })
             },
         );
@@ -546,6 +560,51 @@ impl MusicBrainzCache {
         Ok(())
     }
 
+    /// Get the cached work of a recording. The outer `Option` is whether the
+    /// work was looked up at all; the inner one is `None` for a recording
+    /// that performs no work, so those aren't re-fetched either.
+    pub fn get_recording_work(
+        &self,
+        recording_mbid: &str,
+    ) -> Result<Option<Option<WorkMetadata>>, String> {
+        let min_fetched_at = Self::current_timestamp() - RECORDING_TTL_SECS;
+        let result: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT data FROM mb_recording_works WHERE recording_mbid = ? AND fetched_at > ?",
+                params![recording_mbid, min_fetched_at],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| format!("Failed to query work cache: {}", e))?;
+
+        if let Some(data) = result {
+            serde_json::from_str(&data)
+                .map(Some)
+                .map_err(|e| format!("Failed to parse cached work: {}", e))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Cache the work of a recording (`None` when it performs none)
+    pub fn set_recording_work(
+        &self,
+        recording_mbid: &str,
+        work: Option<&WorkMetadata>,
+    ) -> Result<(), String> {
+        let fetched_at = Self::current_timestamp();
+        let json =
+            serde_json::to_string(&work).map_err(|e| format!("Failed to serialize work: {}", e))?;
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO mb_recording_works (recording_mbid, data, fetched_at) VALUES (?, ?, ?)",
+                params![recording_mbid, json, fetched_at],
+            )
+            .map_err(|e| format!("Failed to cache work: {}", e))?;
+        Ok(())
+    }
+
     /// Get cached artist by name (V2 structured format)
     pub fn get_artist(&self, name: &str) -> Result<Option<ResolvedArtist>, String> {
         let name_lower = name.to_lowercase();
@@ -629,6 +688,7 @@ impl MusicBrainzCache {
             ("mb_artist_metadata", METADATA_TTL_SECS),
             ("mb_scene_cache", SCENE_TTL_SECS),
             ("mb_qobuz_validation", QOBUZ_VALIDATION_TTL_SECS),
+            ("mb_recording_works", RECORDING_TTL_SECS),
         ];
 
         for (table, ttl) in &tables_and_ttls {
@@ -662,6 +722,7 @@ impl MusicBrainzCache {
                 DELETE FROM mb_artist_metadata;
                 DELETE FROM mb_scene_cache;
                 DELETE FROM mb_qobuz_validation;
+                DELETE FROM mb_recording_works;
                 DELETE FROM resolved_tracks;
                 DELETE FROM resolved_artists;
                 UPDATE cache_stats SET value = 0;
@@ -3,10 +3,13 @@
 //! HTTP client with rate limiting and proper User-Agent handling.
 //! Uses Cloudflare Workers proxy for consistent rate limiting.
 
+use futures_util::future::join_all;
+use qbz_models::WorkMetadata;
 use reqwest::Client;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Semaphore};
 
 use super::models::*;
 use crate::error::{IntegrationError, IntegrationResult};
@@ -17,6 +20,9 @@ const MUSICBRAINZ_PROXY_URL: &str = "https://qbz-api-proxy.blitzkriegfc.workers.
 /// Direct MusicBrainz API URL (fallback)
 const MUSICBRAINZ_API_URL: &str = "https://musicbrainz.org/ws/2";
 
+/// ISRCs per batched recording search query
+const ISRC_BATCH_SIZE: usize = 25;
+
 /// Rate limiter for MusicBrainz API
 pub struct RateLimiter {
     last_request: Mutex<Instant>,
@@ -66,6 +72,9 @@ pub struct MusicBrainzConfig {
     pub enabled: bool,
     /// Use proxy instead of direct API
     pub use_proxy: bool,
+    /// Batched ISRC queries allowed in flight at once (rate limiter still
+    /// applies)
+    pub batch_concurrency: usize,
 }
 
 impl Default for MusicBrainzConfig {
@@ -78,8 +87,69 @@ impl Default for MusicBrainzConfig {
             // triggered the 503s). MB read access needs no key, so the proxy
             // added only the funnel. Flip to true to route via the proxy again.
             use_proxy: false,
+            batch_concurrency: 1,
+        }
+    }
+}
+
+/// Build a [`ResolvedTrack`] from an ISRC search hit
+fn resolved_track(
+    recording: &RecordingResult,
+    isrc: &str,
+    work: Option<WorkMetadata>,
+) -> ResolvedTrack {
+    let isrc_listed = recording
+        .isrcs
+        .iter()
+        .flatten()
+        .any(|i| i.eq_ignore_ascii_case(isrc));
+    let confidence = if isrc_listed {
+        MatchConfidence::Exact
+    } else {
+        MatchConfidence::from_score(recording.score)
+    };
+
+    ResolvedTrack {
+        recording_mbid: recording.id.clone(),
+        title: recording.title.clone().unwrap_or_default(),
+        artist_mbids: recording
+            .artist_credit
+            .as_ref()
+            .map(|ac| ac.iter().map(|a| a.artist.id.clone()).collect())
+            .unwrap_or_default(),
+        release_mbid: recording
+            .releases
+            .as_ref()
+            .and_then(|r| r.first())
+            .map(|r| r.id.clone()),
+        isrcs: recording.isrcs.clone().unwrap_or_default(),
+        confidence,
+        work,
+    }
+}
+
+/// Assign batched search hits back to the requested ISRCs. The first
+/// (highest-scored) recording carrying an ISRC wins.
+fn map_recordings_to_isrcs(
+    isrcs: &[String],
+    recordings: Vec<RecordingResult>,
+) -> HashMap<String, Option<RecordingResult>> {
+    let mut resolved: HashMap<String, Option<RecordingResult>> =
+        isrcs.iter().map(|isrc| (isrc.clone(), None)).collect();
+    for recording in recordings {
+        for (isrc, slot) in resolved.iter_mut() {
+            if slot.is_none()
+                && recording
+                    .isrcs
+                    .iter()
+                    .flatten()
+                    .any(|r| r.eq_ignore_ascii_case(isrc.trim()))
+            {
+                *slot = Some(recording.clone());
+            }
         }
     }
+    resolved
 }
 
 /// MusicBrainz API client
@@ -208,32 +278,9 @@ impl MusicBrainzClient {
         if let Some(isrc) = isrc {
             let response = self.search_recording_by_isrc(isrc).await?;
             if let Some(recording) = response.recordings.first() {
-                let confidence = if recording
-                    .isrcs
-                    .as_ref()
-                    .map_or(false, |isrcs| isrcs.contains(&isrc.to_string()))
-                {
-                    MatchConfidence::Exact
-                } else {
-                    MatchConfidence::from_score(recording.score)
-                };
-
-                return Ok(Some(ResolvedTrack {
-                    recording_mbid: recording.id.clone(),
-                    title: recording.title.clone().unwrap_or_default(),
-                    artist_mbids: recording
-                        .artist_credit
-                        .as_ref()
-                        .map(|ac| ac.iter().map(|a| a.artist.id.clone()).collect())
-                        .unwrap_or_default(),
-                    release_mbid: recording
-                        .releases
-                        .as_ref()
-                        .and_then(|r| r.first())
-                        .map(|r| r.id.clone()),
-                    isrcs: recording.isrcs.clone().unwrap_or_default(),
-                    confidence,
-                }));
+                // Work metadata costs a second request, so it is left to
+                // callers that display it (`get_recording_with_work`).
+                return Ok(Some(resolved_track(recording, isrc, None)));
             }
         }
 
@@ -243,6 +290,87 @@ impl MusicBrainzClient {
         Ok(None)
     }
 
+    /// Resolve many ISRCs to recordings with batched search queries
+    ///
+    /// ISRCs are grouped `ISRC_BATCH_SIZE` per query; up to
+    /// `batch_concurrency` queries are in flight at once, still paced by the
+    /// shared rate limiter. Every input ISRC is a key in the result, mapped to
+    /// `None` when MusicBrainz has no recording for it.
+    pub async fn resolve_recordings_batch(
+        &self,
+        isrcs: &[String],
+    ) -> IntegrationResult<HashMap<String, Option<RecordingResult>>> {
+        self.check_enabled().await?;
+
+        let mut unique: Vec<&str> = Vec::new();
+        for isrc in isrcs {
+            let isrc = isrc.trim();
+            if !isrc.is_empty() && !unique.iter().any(|u| u.eq_ignore_ascii_case(isrc)) {
+                unique.push(isrc);
+            }
+        }
+
+        let concurrency = self.config.lock().await.batch_concurrency.max(1);
+        let semaphore = Semaphore::new(concurrency);
+        let batches = unique.chunks(ISRC_BATCH_SIZE).map(|chunk| {
+            let semaphore = &semaphore;
+            async move {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .map_err(|e| IntegrationError::internal(e.to_string()))?;
+                self.search_recordings_by_isrcs(chunk).await
+            }
+        });
+
+        let mut recordings = Vec::new();
+        for response in join_all(batches).await {
+            recordings.extend(response?.recordings);
+        }
+        Ok(map_recordings_to_isrcs(isrcs, recordings))
+    }
+
+    /// Album-sized variant of [`Self::resolve_track`]: resolves every ISRC
+    /// through [`Self::resolve_recordings_batch`] so a 25-track album costs
+    /// one request instead of 25. Work metadata is not fetched on this path.
+    pub async fn resolve_tracks_batch(
+        &self,
+        isrcs: &[String],
+    ) -> IntegrationResult<HashMap<String, Option<ResolvedTrack>>> {
+        let recordings = self.resolve_recordings_batch(isrcs).await?;
+        Ok(recordings
+            .into_iter()
+            .map(|(isrc, recording)| {
+                let track = recording.map(|r| resolved_track(&r, &isrc, None));
+                (isrc, track)
+            })
+            .collect())
+    }
+
+    /// One search query for a batch of ISRCs (`isrc:A OR isrc:B ...`)
+    async fn search_recordings_by_isrcs(
+        &self,
+        isrcs: &[&str],
+    ) -> IntegrationResult<RecordingSearchResponse> {
+        self.rate_limiter.wait().await;
+
+        let base = self.base_url().await;
+        let query = isrcs
+            .iter()
+            .map(|isrc| format!("isrc:{}", isrc))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let url = format!(
+            "{}/recording?query={}&limit=100&fmt=json",
+            base,
+            urlencoding::encode(&query)
+        );
+
+        let response = self.client.get(&url).send().await?;
+        let response = self.handle_response_status(response).await?;
+        response.json().await.map_err(Into::into)
+    }
+
     /// Resolve an artist to get MusicBrainz ID
     ///
     /// Prefers exact name matches over highest score to avoid disambiguation
@@ -324,6 +452,54 @@ impl MusicBrainzClient {
         response.json().await.map_err(Into::into)
     }
 
+    /// Get an artist's biography: the annotation editors keep on the
+    /// MusicBrainz artist page. `None` when the artist has none.
+    pub async fn get_artist_biography(&self, mbid: &str) -> IntegrationResult<Option<String>> {
+        self.check_enabled().await?;
+        self.rate_limiter.wait().await;
+
+        let base = self.base_url().await;
+        let url = format!("{}/artist/{}?inc=annotation&fmt=json", base, mbid);
+
+        let response = self.client.get(&url).send().await?;
+        let response = self.handle_response_status(response).await?;
+        let artist: ArtistFullResponse = response.json().await?;
+        Ok(artist
+            .annotation
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty()))
+    }
+
+    /// Get an artist's members, groups, collaborators and influences
+    pub async fn get_artist_relationships(
+        &self,
+        mbid: &str,
+    ) -> IntegrationResult<ArtistRelationships> {
+        let artist = self.get_artist_with_relations(mbid).await?;
+        Ok(ArtistRelationships::from(&artist))
+    }
+
+    /// Get a recording with the work it performs (composer, key, opus)
+    pub async fn get_recording_with_work(
+        &self,
+        mbid: &str,
+    ) -> IntegrationResult<RecordingWithWork> {
+        self.check_enabled().await?;
+        self.rate_limiter.wait().await;
+
+        let base = self.base_url().await;
+        // work-level-rels pulls the composer relation off the work itself
+        let url = format!(
+            "{}/recording/{}?inc=work-rels+work-level-rels+artist-rels&fmt=json",
+            base, mbid
+        );
+
+        let response = self.client.get(&url).send().await?;
+        let response = self.handle_response_status(response).await?;
+        let recording: RecordingWorkResponse = response.json().await?;
+        Ok(recording.into())
+    }
+
     /// Fetch artist tags only (lightweight, no relations)
     pub async fn get_artist_tags(&self, mbid: &str) -> IntegrationResult<Vec<String>> {
         self.check_enabled().await?;
@@ -807,3 +983,42 @@ impl MusicBrainzClient {
             .replace('|', "\\|")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(id: &str, isrcs: &[&str]) -> RecordingResult {
+        RecordingResult {
+            id: id.to_string(),
+            score: Some(100),
+            title: None,
+            length: None,
+            artist_credit: None,
+            isrcs: Some(isrcs.iter().map(|i| i.to_string()).collect()),
+            releases: None,
+        }
+    }
+
+    #[test]
+    fn batch_results_map_back_to_every_requested_isrc() {
+        let isrcs = vec![
+            "usrc17607839".to_string(),
+            "GBAYE0000351".to_string(),
+            "FRZ039800212".to_string(),
+        ];
+        let mapped = map_recordings_to_isrcs(
+            &isrcs,
+            vec![
+                recording("rec-1", &["USRC17607839"]),
+                recording("rec-2", &["GBAYE0000351", "GBAYE0000352"]),
+                recording("rec-3", &["GBAYE0000351"]),
+            ],
+        );
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(mapped["usrc17607839"].as_ref().unwrap().id, "rec-1");
+        // First hit wins for an ISRC shared by several recordings
+        assert_eq!(mapped["GBAYE0000351"].as_ref().unwrap().id, "rec-2");
+        assert!(mapped["FRZ039800212"].is_none());
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Types for deserializing MusicBrainz JSON responses
 
+use qbz_models::WorkMetadata;
 use serde::{Deserialize, Serialize};
 
 /// Match confidence levels for MusicBrainz lookups
@@ -70,7 +71,7 @@ pub struct RecordingSearchResponse {
 }
 
 /// Single recording in search results
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct RecordingResult {
     pub id: String,
     pub score: Option<i32>,
@@ -91,7 +92,7 @@ pub struct RecordingLookupResponse {
 }
 
 /// Artist credit entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ArtistCredit {
     pub name: Option<String>,
     pub joinphrase: Option<String>,
@@ -99,7 +100,7 @@ pub struct ArtistCredit {
 }
 
 /// Reference to an artist
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ArtistRef {
     pub id: String,
     pub name: String,
@@ -109,7 +110,7 @@ pub struct ArtistRef {
 }
 
 /// Reference to a release (album)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ReleaseRef {
     pub id: String,
     pub title: Option<String>,
@@ -121,7 +122,7 @@ pub struct ReleaseRef {
 }
 
 /// Reference to a release group
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ReleaseGroupRef {
     pub id: String,
     #[serde(rename = "primary-type")]
@@ -212,6 +213,34 @@ pub struct Relation {
     pub ended: Option<bool>,
     pub attributes: Option<Vec<String>>,
     pub artist: Option<ArtistRef>,
+    pub work: Option<WorkRef>,
+}
+
+/// Reference to a work (composition), as embedded in a `performance` relation
+#[derive(Debug, Deserialize)]
+pub struct WorkRef {
+    pub id: String,
+    pub title: String,
+    pub attributes: Option<Vec<WorkAttribute>>,
+    /// Work-level relations (composer, lyricist). Only present when the
+    /// lookup asked for `work-level-rels`.
+    pub relations: Option<Vec<Relation>>,
+}
+
+/// Typed work attribute ("Key", "Opus number", "BWV catalogue number"...)
+#[derive(Debug, Deserialize)]
+pub struct WorkAttribute {
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    pub value: String,
+}
+
+/// Recording lookup with work relations
+#[derive(Debug, Deserialize)]
+pub struct RecordingWorkResponse {
+    pub id: String,
+    pub title: Option<String>,
+    pub relations: Option<Vec<Relation>>,
 }
 
 // ============ Resolved Types (for caching/output) ============
@@ -237,6 +266,105 @@ pub struct ResolvedTrack {
     pub release_mbid: Option<String>,
     pub isrcs: Vec<String>,
     pub confidence: MatchConfidence,
+    /// Work the recording performs (classical metadata). The resolvers leave
+    /// it empty; the cache fills it in once the work has been looked up
+    /// (`MusicBrainzCache::set_recording_work`).
+    #[serde(default)]
+    pub work: Option<WorkMetadata>,
+}
+
+/// Recording with the work it performs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingWithWork {
+    pub recording_mbid: String,
+    pub title: String,
+    pub work_mbid: Option<String>,
+    pub work: Option<WorkMetadata>,
+}
+
+impl From<RecordingWorkResponse> for RecordingWithWork {
+    fn from(response: RecordingWorkResponse) -> Self {
+        let relations = response.relations.unwrap_or_default();
+        let work_ref = relations
+            .iter()
+            .filter(|rel| rel.relation_type == "performance")
+            .find_map(|rel| rel.work.as_ref());
+
+        let (work_mbid, work) = match work_ref {
+            Some(work_ref) => {
+                // Composer lives on the work; fall back to a recording-level
+                // composer credit when the work carries none.
+                let composer = work_ref
+                    .relations
+                    .iter()
+                    .flatten()
+                    .chain(relations.iter())
+                    .find(|rel| rel.relation_type == "composer")
+                    .and_then(|rel| rel.artist.as_ref())
+                    .map(|artist| artist.name.clone());
+
+                let mut opus = None;
+                let mut catalogue_number = None;
+                let mut key = None;
+                for attr in work_ref.attributes.iter().flatten() {
+                    let kind = attr.attribute_type.to_lowercase();
+                    if kind == "key" {
+                        key.get_or_insert_with(|| attr.value.clone());
+                    } else if kind.contains("opus") {
+                        opus.get_or_insert_with(|| format_opus(&attr.value));
+                    } else if kind.contains("catalog") {
+                        catalogue_number.get_or_insert_with(|| attr.value.clone());
+                    }
+                }
+                let opus = opus.or_else(|| opus_from_title(&work_ref.title));
+
+                (
+                    Some(work_ref.id.clone()),
+                    Some(WorkMetadata {
+                        title: work_ref.title.clone(),
+                        composer,
+                        opus,
+                        catalogue_number,
+                        key,
+                    }),
+                )
+            }
+            None => (None, None),
+        };
+
+        Self {
+            recording_mbid: response.id,
+            title: response.title.unwrap_or_default(),
+            work_mbid,
+            work,
+        }
+    }
+}
+
+/// Normalize a bare opus attribute ("67") to "Op. 67"
+fn format_opus(value: &str) -> String {
+    let value = value.trim();
+    if value.to_lowercase().starts_with("op") {
+        value.to_string()
+    } else {
+        format!("Op. {}", value)
+    }
+}
+
+/// Pull the opus out of a conventional work title, e.g.
+/// "Symphony no. 5 in C minor, op. 67" -> "Op. 67"
+fn opus_from_title(title: &str) -> Option<String> {
+    // ASCII lowercase keeps byte offsets aligned with `title`
+    let lower = title.to_ascii_lowercase();
+    let start = lower
+        .match_indices("op. ")
+        .map(|(i, _)| i)
+        .find(|&i| i == 0 || !lower.as_bytes()[i - 1].is_ascii_alphanumeric())?;
+    let number = title[start + 4..].split(',').next()?.trim();
+    if number.is_empty() {
+        return None;
+    }
+    Some(format!("Op. {}", number))
 }
 
 /// Resolved release (album) with MusicBrainz data
@@ -272,6 +400,9 @@ pub struct ArtistFullResponse {
     pub relations: Option<Vec<Relation>>,
     #[serde(default)]
     pub tags: Option<Vec<Tag>>,
+    /// Free-text annotation (only with `inc=annotation`)
+    #[serde(default)]
+    pub annotation: Option<String>,
 }
 
 /// Release search response
@@ -477,6 +608,12 @@ pub struct ArtistRelationships {
     pub past_members: Vec<RelatedArtist>,
     pub groups: Vec<RelatedArtist>,
     pub collaborators: Vec<RelatedArtist>,
+    /// Artists this artist names as an influence
+    #[serde(default)]
+    pub influenced_by: Vec<RelatedArtist>,
+    /// Artists that name this artist as an influence
+    #[serde(default)]
+    pub influenced: Vec<RelatedArtist>,
 }
 
 impl ArtistRelationships {
@@ -486,6 +623,8 @@ impl ArtistRelationships {
             past_members: Vec::new(),
             groups: Vec::new(),
             collaborators: Vec::new(),
+            influenced_by: Vec::new(),
+            influenced: Vec::new(),
         }
     }
 
@@ -494,6 +633,56 @@ impl ArtistRelationships {
             && self.past_members.is_empty()
             && self.groups.is_empty()
             && self.collaborators.is_empty()
+            && self.influenced_by.is_empty()
+            && self.influenced.is_empty()
+    }
+}
+
+/// Splits the `artist-rels` of an artist lookup. `member of band` and
+/// `influenced by` are directional: backward means the related artist is
+/// the member / the one influenced, forward means it is the group / the
+/// influence.
+impl From<&ArtistFullResponse> for ArtistRelationships {
+    fn from(artist: &ArtistFullResponse) -> Self {
+        let mut result = Self::empty();
+
+        for relation in artist.relations.iter().flatten() {
+            let Some(related_artist) = &relation.artist else {
+                continue;
+            };
+
+            let related = RelatedArtist {
+                mbid: related_artist.id.clone(),
+                name: related_artist.name.clone(),
+                role: relation
+                    .attributes
+                    .as_ref()
+                    .and_then(|a| a.first().cloned()),
+                period: Some(Period {
+                    begin: relation.begin.clone(),
+                    end: relation.end.clone(),
+                }),
+                ended: relation.ended.unwrap_or(false),
+            };
+            let backward = relation.direction.as_deref() == Some("backward");
+
+            match relation.relation_type.as_str() {
+                "member of band" if backward => {
+                    if related.ended {
+                        result.past_members.push(related);
+                    } else {
+                        result.members.push(related);
+                    }
+                }
+                "member of band" => result.groups.push(related),
+                "collaboration" => result.collaborators.push(related),
+                "influenced by" if backward => result.influenced.push(related),
+                "influenced by" => result.influenced_by.push(related),
+                _ => {}
+            }
+        }
+
+        result
     }
 }
 
@@ -651,3 +840,154 @@ pub struct MusicianAppearances {
     pub albums: Vec<AlbumAppearance>,
     pub total: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_with_work_extracts_composer_key_and_opus() {
+        let json = r#"{
+            "id": "rec-1",
+            "title": "Symphony No. 5: I. Allegro con brio",
+            "relations": [
+                {"type": "conductor", "artist": {"id": "a-1", "name": "Carlos Kleiber"}},
+                {
+                    "type": "performance",
+                    "work": {
+                        "id": "work-1",
+                        "title": "Symphony no. 5 in C minor, op. 67",
+                        "attributes": [{"type": "Key", "value": "C minor"}],
+                        "relations": [
+                            {"type": "composer", "artist": {"id": "a-2", "name": "Ludwig van Beethoven"}}
+                        ]
+                    }
+                }
+            ]
+        }"#;
+        let response: RecordingWorkResponse = serde_json::from_str(json).unwrap();
+        let recording = RecordingWithWork::from(response);
+        assert_eq!(recording.work_mbid.as_deref(), Some("work-1"));
+        let work = recording.work.unwrap();
+        assert_eq!(work.composer.as_deref(), Some("Ludwig van Beethoven"));
+        assert_eq!(work.key.as_deref(), Some("C minor"));
+        assert_eq!(work.opus.as_deref(), Some("Op. 67"));
+        assert_eq!(work.catalogue_number, None);
+    }
+
+    #[test]
+    fn artist_relationships_split_members_groups_and_influences() {
+        // Trimmed `/artist/{mbid}?inc=artist-rels&fmt=json` for Radiohead
+        let json = r#"{
+            "id": "a74b1b7f-71a5-4011-9441-d0b5e4122711",
+            "name": "Radiohead",
+            "sort-name": "Radiohead",
+            "type": "Group",
+            "country": "GB",
+            "disambiguation": "",
+            "life-span": {"begin": "1991", "end": null, "ended": false},
+            "relations": [
+                {
+                    "type": "member of band",
+                    "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+                    "direction": "backward",
+                    "begin": "1985",
+                    "end": null,
+                    "ended": false,
+                    "attributes": ["lead vocals", "guitar"],
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "8bfac288-ccc5-448d-9573-c33ea2aa5c30",
+                        "name": "Thom Yorke",
+                        "sort-name": "Yorke, Thom",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "member of band",
+                    "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+                    "direction": "backward",
+                    "begin": "1985",
+                    "end": "1986",
+                    "ended": true,
+                    "attributes": [],
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "00000000-0000-0000-0000-000000000001",
+                        "name": "Former Member",
+                        "sort-name": "Member, Former",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "influenced by",
+                    "type-id": "ad462279-14b0-4180-9b58-571d0eef7c51",
+                    "direction": "forward",
+                    "begin": null,
+                    "end": null,
+                    "ended": false,
+                    "attributes": [],
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "cc2c9c3c-b7bc-4b8b-84d8-4fbd8779e493",
+                        "name": "Pixies",
+                        "sort-name": "Pixies",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "influenced by",
+                    "type-id": "ad462279-14b0-4180-9b58-571d0eef7c51",
+                    "direction": "backward",
+                    "begin": null,
+                    "end": null,
+                    "ended": false,
+                    "attributes": [],
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "9c9f1380-2516-4fc9-a3e6-f9f61941d090",
+                        "name": "Muse",
+                        "sort-name": "Muse",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "tribute",
+                    "type-id": "a6f62641-2f58-470e-b02b-88d7b984dc9f",
+                    "direction": "backward",
+                    "ended": false,
+                    "attributes": [],
+                    "target-type": "artist",
+                    "artist": {"id": "x", "name": "Tribute Band"}
+                }
+            ]
+        }"#;
+        let response: ArtistFullResponse = serde_json::from_str(json).unwrap();
+        let rels = ArtistRelationships::from(&response);
+
+        assert_eq!(rels.members.len(), 1);
+        assert_eq!(rels.members[0].name, "Thom Yorke");
+        assert_eq!(rels.members[0].role.as_deref(), Some("lead vocals"));
+        assert_eq!(rels.past_members.len(), 1);
+        assert!(rels.past_members[0].ended);
+        assert!(rels.groups.is_empty());
+        assert_eq!(rels.influenced_by.len(), 1);
+        assert_eq!(rels.influenced_by[0].name, "Pixies");
+        assert_eq!(rels.influenced.len(), 1);
+        assert_eq!(rels.influenced[0].name, "Muse");
+
+        // Entries cached before the influence lists existed still load
+        let cached = r#"{"members":[],"past_members":[],"groups":[],"collaborators":[]}"#;
+        let old: ArtistRelationships = serde_json::from_str(cached).unwrap();
+        assert!(old.is_empty());
+    }
+
+    #[test]
+    fn recording_without_work_has_no_metadata() {
+        let json = r#"{"id": "rec-2", "title": "Song", "relations": []}"#;
+        let response: RecordingWorkResponse = serde_json::from_str(json).unwrap();
+        let recording = RecordingWithWork::from(response);
+        assert!(recording.work.is_none());
+        assert_eq!(opus_from_title("Cello Suite No. 1, BWV 1007"), None);
+    }
+}
@@ -75,14 +75,100 @@ impl CueParser {
             Ok::<String, std::io::Error>(bytes.iter().map(|&b| b as char).collect())
         })?;
 
-        Self::parse_content(&content, cue_path)
+        Self::parse_content(&content, cue_path, None)
     }
 
-    /// Parse CUE content
-    fn parse_content(content: &str, cue_path: &Path) -> Result<CueSheet, LibraryError> {
+    /// Parse a CUE sheet embedded in `audio_path` (the CUESHEET tag rippers
+    /// write into single-file FLAC images). Its FILE directive names the
+    /// original image, so the sheet always points at `audio_path` instead,
+    /// and `file_path` is the audio file itself.
+    pub fn parse_embedded(content: &str, audio_path: &Path) -> Result<CueSheet, LibraryError> {
+        Self::parse_content(content, audio_path, Some(audio_path))
+    }
+
+    /// Parse a native FLAC CUESHEET metadata block (`block` is the block body,
+    /// without its header). Track offsets are in samples, so `sample_rate`
+    /// comes from the file's STREAMINFO. The block carries no titles or
+    /// performers; tracks are named "Track N" and the caller fills in the
+    /// album from the file's tags. The lead-out track is dropped.
+    pub fn parse_flac_cuesheet(
+        block: &[u8],
+        sample_rate: u32,
+        audio_path: &Path,
+    ) -> Result<CueSheet, LibraryError> {
+        // catalog number (128) + lead-in (8) + flags/reserved (259)
+        const HEADER_LEN: usize = 395;
+        // offset (8) + number (1) + ISRC (12) + flags/reserved (14)
+        const TRACK_LEN: usize = 35;
+        // offset (8) + number (1) + reserved (3)
+        const INDEX_LEN: usize = 12;
+
+        let truncated = || LibraryError::CueParse("Truncated CUESHEET block".to_string());
+        if sample_rate == 0 {
+            return Err(LibraryError::CueParse(
+                "CUESHEET block without a sample rate".to_string(),
+            ));
+        }
+        let read_u64 = |at: usize| -> Option<u64> {
+            let bytes: [u8; 8] = block.get(at..at + 8)?.try_into().ok()?;
+            Some(u64::from_be_bytes(bytes))
+        };
+
+        let count = *block.get(HEADER_LEN).ok_or_else(truncated)?;
+        let mut pos = HEADER_LEN + 1;
+        let mut tracks = Vec::new();
+        for _ in 0..count {
+            let offset = read_u64(pos).ok_or_else(truncated)?;
+            let number = *block.get(pos + 8).ok_or_else(truncated)?;
+            let indexes = *block.get(pos + TRACK_LEN).ok_or_else(truncated)? as usize;
+            let mut start = None;
+            for i in 0..indexes {
+                let at = pos + TRACK_LEN + 1 + i * INDEX_LEN;
+                let index_offset = read_u64(at).ok_or_else(truncated)?;
+                if *block.get(at + 8).ok_or_else(truncated)? == 1 {
+                    start = Some(offset + index_offset);
+                }
+            }
+            pos += TRACK_LEN + 1 + indexes * INDEX_LEN;
+
+            // 170 (CD-DA) and 255 mark the lead-out.
+            if number == 170 || number == 255 {
+                continue;
+            }
+            tracks.push(CueTrack {
+                number: number as u32,
+                title: format!("Track {}", number),
+                performer: None,
+                start_secs: start.unwrap_or(offset) as f64 / sample_rate as f64,
+            });
+        }
+
+        if tracks.is_empty() {
+            return Err(LibraryError::CueParse(
+                "No tracks found in CUE sheet".to_string(),
+            ));
+        }
+        let path = audio_path.to_string_lossy().to_string();
+        Ok(CueSheet {
+            file_path: path.clone(),
+            audio_file: path,
+            title: None,
+            performer: None,
+            tracks,
+        })
+    }
+
+    /// Parse CUE content. `embedded_in` overrides the FILE directive.
+    fn parse_content(
+        content: &str,
+        cue_path: &Path,
+        embedded_in: Option<&Path>,
+    ) -> Result<CueSheet, LibraryError> {
         let mut sheet = CueSheet {
             file_path: cue_path.to_string_lossy().to_string(),
-            audio_file: String::new(),
+            audio_file: embedded_in
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default(),
             title: None,
             performer: None,
             tracks: Vec::new(),
@@ -101,6 +187,9 @@ impl CueParser {
 
             // Parse FILE "name" TYPE
             if line.to_uppercase().starts_with("FILE ") {
+                if embedded_in.is_some() {
+                    continue;
+                }
                 if let Some(filename) = Self::extract_quoted(line) {
                     // Resolve path relative to CUE file
                     if let Some(parent) = cue_path.parent() {
@@ -306,6 +395,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_embedded_sheet_points_at_its_host_file() {
+        let content = r#"PERFORMER "Pink Floyd"
+TITLE "Wish You Were Here"
+FILE "CDImage.wav" WAVE
+  TRACK 01 AUDIO
+    TITLE "Shine On You Crazy Diamond (Parts I-V)"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Welcome to the Machine"
+    INDEX 00 13:30:10
+    INDEX 01 13:32:00
+"#;
+        let flac = Path::new("/music/Pink Floyd/Wish You Were Here.flac");
+        let sheet = CueParser::parse_embedded(content, flac).unwrap();
+        assert_eq!(sheet.audio_file, flac.to_string_lossy());
+        assert_eq!(sheet.file_path, sheet.audio_file);
+        assert_eq!(sheet.title.as_deref(), Some("Wish You Were Here"));
+        assert_eq!(sheet.tracks.len(), 2);
+        assert!((sheet.tracks[1].start_secs - 812.0).abs() < 0.01);
+
+        // A sidecar sheet still requires its FILE directive.
+        let no_file = "TRACK 01 AUDIO\nINDEX 01 00:00:00\n";
+        assert!(CueParser::parse_content(no_file, Path::new("/m/a.cue"), None).is_err());
+        assert!(CueParser::parse_embedded(no_file, flac).is_ok());
+    }
+
+    #[test]
+    fn test_native_flac_cuesheet_block() {
+        // Two tracks at 0 s and 2 s (INDEX 01 of track 2 after a 1 s
+        // pregap) plus the CD lead-out, at 44.1 kHz.
+        let track = |offset: u64, number: u8, indexes: &[(u64, u8)]| {
+            let mut t = offset.to_be_bytes().to_vec();
+            t.push(number);
+            t.extend([0u8; 26]);
+            t.push(indexes.len() as u8);
+            for (offset, number) in indexes {
+                t.extend(offset.to_be_bytes());
+                t.push(*number);
+                t.extend([0u8; 3]);
+            }
+            t
+        };
+        let mut block = vec![0u8; 395];
+        block.push(3);
+        block.extend(track(0, 1, &[(0, 1)]));
+        block.extend(track(44_100, 2, &[(0, 0), (44_100, 1)]));
+        block.extend(track(441_000, 170, &[]));
+
+        let flac = Path::new("/music/image.flac");
+        let sheet = CueParser::parse_flac_cuesheet(&block, 44_100, flac).unwrap();
+        assert_eq!(sheet.audio_file, flac.to_string_lossy());
+        assert_eq!(sheet.tracks.len(), 2);
+        assert_eq!(sheet.tracks[1].title, "Track 2");
+        assert!((sheet.tracks[1].start_secs - 2.0).abs() < 0.001);
+
+        assert!(CueParser::parse_flac_cuesheet(&block[..400], 44_100, flac).is_err());
+    }
+
     #[test]
     fn test_extract_track_number() {
         assert_eq!(CueParser::extract_track_number("TRACK 01 AUDIO"), Some(1));
@@ -3,7 +3,10 @@
 use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
 
-use crate::{AudioFormat, FolderTreeEntry, LibraryError, LocalAlbum, LocalArtist, LocalTrack};
+use crate::{
+    AudioFormat, FolderTreeEntry, LibraryError, LocalAlbum, LocalArtist, LocalTrack,
+    MetadataExtractor,
+};
 
 #[derive(Debug, Clone)]
 pub struct AlbumTrackUpdate {
@@ -56,6 +59,9 @@ impl LibraryDatabase {
         // captured opportunistically while online. Idempotent.
         crate::qobuz_playlist_snapshot::init_schema(&db.conn)
             .map_err(|e| LibraryError::Database(format!("qobuz_playlist_snapshot schema: {}", e)))?;
+        // Discogs collection links (album group key -> release). Idempotent.
+        crate::discogs_collection::init_schema(&db.conn)
+            .map_err(|e| LibraryError::Database(format!("discogs_collection schema: {}", e)))?;
         Ok(db)
     }
 
@@ -229,6 +235,14 @@ impl LibraryDatabase {
 
             CREATE INDEX IF NOT EXISTS idx_artist_images_fetched ON artist_images(fetched_at);
 
+            -- Artist biographies cache (MusicBrainz annotations; NULL bio = none found)
+            CREATE TABLE IF NOT EXISTS artist_bios (
+                artist_name TEXT PRIMARY KEY,
+                mbid TEXT,
+                bio TEXT,
+                fetched_at INTEGER NOT NULL
+            );
+
             -- Custom album covers (user-uploaded covers for Qobuz albums)
             CREATE TABLE IF NOT EXISTS custom_album_covers (
                 album_id TEXT PRIMARY KEY,
@@ -253,6 +267,33 @@ impl LibraryDatabase {
                 key TEXT PRIMARY KEY,
                 value TEXT NOT NULL
             );
+
+            -- Files kept out of the library on purpose: duplicates merged
+            -- into `kept_path`. Scans skip them so a merge sticks.
+            CREATE TABLE IF NOT EXISTS ignored_track_paths (
+                file_path TEXT PRIMARY KEY,
+                kept_path TEXT,
+                ignored_at INTEGER NOT NULL
+            );
+
+            -- Album a file was folded into by a split-album merge. Inserts
+            -- apply it over the file's tags so a rescan keeps the merge.
+            CREATE TABLE IF NOT EXISTS album_merge_overrides (
+                file_path TEXT PRIMARY KEY,
+                album_group_key TEXT NOT NULL,
+                album_title TEXT NOT NULL,
+                disc_number INTEGER NOT NULL
+            );
+
+            -- Qobuz track streamed when a local file can't be opened. Keyed
+            -- by path (cue_start_secs -1 for whole files) rather than row id
+            -- so the link outlives a rescan or a library rebuild.
+            CREATE TABLE IF NOT EXISTS stream_fallbacks (
+                file_path TEXT NOT NULL,
+                cue_start_secs REAL NOT NULL,
+                qobuz_track_id INTEGER NOT NULL,
+                PRIMARY KEY (file_path, cue_start_secs)
+            );
         "#,
             )
             .map_err(|e| LibraryError::Database(format!("Failed to create schema: {}", e)))?;
@@ -466,6 +507,26 @@ impl LibraryDatabase {
                 .map_err(|e| LibraryError::Database(format!("Migration failed: {}", e)))?;
         }
 
+        // Migration: Add transient flag (one-time network share scans)
+        let has_folder_transient: bool = self
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('library_folders') WHERE name = 'transient'",
+                [],
+                |row| row.get::<_, i32>(0),
+            )
+            .map(|count| count > 0)
+            .unwrap_or(false);
+
+        if !has_folder_transient {
+            log::info!("Running migration: adding transient flag to library_folders");
+            self.conn
+                .execute_batch(
+                    "ALTER TABLE library_folders ADD COLUMN transient INTEGER NOT NULL DEFAULT 0;",
+                )
+                .map_err(|e| LibraryError::Database(format!("Migration failed: {}", e)))?;
+        }
+
         // Migration: Add is_favorite column to playlist_settings
         let has_is_favorite: bool = self.conn
             .query_row(
@@ -726,6 +787,24 @@ impl LibraryDatabase {
                 .map_err(|e| LibraryError::Database(format!("Migration failed: {}", e)))?;
         }
 
+        // Migration: Add isrc to local_tracks. NULL = not looked up yet; ''
+        // = looked up, none found (see update_track_isrc).
+        let has_isrc: bool = self.conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('local_tracks') WHERE name = 'isrc'",
+                [],
+                |row| row.get::<_, i32>(0),
+            )
+            .map(|count| count > 0)
+            .unwrap_or(false);
+
+        if !has_isrc {
+            log::info!("Running migration: adding isrc to local_tracks");
+            self.conn
+                .execute_batch("ALTER TABLE local_tracks ADD COLUMN isrc TEXT;")
+                .map_err(|e| LibraryError::Database(format!("Migration failed: {}", e)))?;
+        }
+
         // Migration: Add canonical_name column to artist_images for artist name normalization
         let has_canonical_name: bool = self.conn
             .query_row(
@@ -893,6 +972,36 @@ impl LibraryDatabase {
         Ok(id)
     }
 
+    /// Add a transient folder: a network share scanned once without becoming
+    /// a permanent library folder. It shows up as a "Network" folder until a
+    /// scan finds the share unreachable, which drops it and its tracks from
+    /// the index. A path that is already registered keeps its existing row.
+    pub fn add_transient_folder(
+        &self,
+        path: &str,
+        network_fs_type: Option<&str>,
+    ) -> Result<i64, LibraryError> {
+        let name = std::path::Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+        self.conn
+            .execute(
+                "INSERT OR IGNORE INTO library_folders (path, alias, is_network, network_fs_type, transient)
+                 VALUES (?, ?, 1, ?, 1)",
+                params![path, format!("Network — {}", name), network_fs_type],
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        self.conn
+            .query_row(
+                "SELECT id FROM library_folders WHERE path = ?",
+                params![path],
+                |row| row.get(0),
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))
+    }
+
     /// Remove a folder from the library
     pub fn remove_folder(&self, path: &str) -> Result<(), LibraryError> {
         self.conn
@@ -942,7 +1051,7 @@ impl LibraryDatabase {
         let mut stmt = self
             .conn
             .prepare(
-                "SELECT id, path, alias, enabled, is_network, network_fs_type, user_override_network, last_scan
+                "SELECT id, path, alias, enabled, is_network, network_fs_type, user_override_network, last_scan, transient
                  FROM library_folders ORDER BY path"
             )
             .map_err(|e| LibraryError::Database(e.to_string()))?;
@@ -958,6 +1067,7 @@ impl LibraryDatabase {
                     network_fs_type: row.get(5)?,
                     user_override_network: row.get::<_, i32>(6).unwrap_or(0) != 0,
                     last_scan: row.get(7)?,
+                    transient: row.get::<_, i32>(8).unwrap_or(0) != 0,
                 })
             })
             .map_err(|e| LibraryError::Database(e.to_string()))?;
@@ -974,7 +1084,7 @@ impl LibraryDatabase {
         let result = self
             .conn
             .query_row(
-                "SELECT id, path, alias, enabled, is_network, network_fs_type, user_override_network, last_scan
+                "SELECT id, path, alias, enabled, is_network, network_fs_type, user_override_network, last_scan, transient
                  FROM library_folders WHERE id = ?",
                 params![id],
                 |row| {
@@ -987,6 +1097,7 @@ impl LibraryDatabase {
                         network_fs_type: row.get(5)?,
                         user_override_network: row.get::<_, i32>(6).unwrap_or(0) != 0,
                         last_scan: row.get(7)?,
+                        transient: row.get::<_, i32>(8).unwrap_or(0) != 0,
                     })
                 },
             )
@@ -1128,23 +1239,81 @@ impl LibraryDatabase {
             std::path::Path::new(&track.file_path),
         );
 
+        // A file folded into a merged multi-disc album keeps that album
+        // (and its disc) over whatever its tags say.
+        let merged: Option<(String, String, u32)> = self
+            .conn
+            .query_row(
+                "SELECT album_group_key, album_title, disc_number
+                 FROM album_merge_overrides WHERE file_path = ?1",
+                params![track.file_path],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        let (album, disc_number, group_key, group_title) = match &merged {
+            Some((key, title, disc)) => (title, Some(*disc), key, title),
+            None => (
+                &track.album,
+                track.disc_number,
+                &track.album_group_key,
+                &track.album_group_title,
+            ),
+        };
+
+        // An upsert rather than INSERT OR REPLACE: a rescan refreshes the
+        // scanned columns in place, so the row keeps its id and whatever was
+        // stored on it after indexing (ISRC, stream fallback link). The ISRC
+        // was looked up from title + artist, so it is reset to NULL (queued
+        // for a new lookup) when a retag changes either, or when the path now
+        // holds a different recording (another duration). Artwork set after
+        // indexing (a fetched cover) isn't in the file either, so it is only
+        // replaced when the scan found some. CUE tracks conflict on
+        // (file_path, cue_start_secs), whole files on the
+        // idx_tracks_file_nocue partial index.
+        const RESCAN_SET: &str = r#"
+                title = excluded.title, artist = excluded.artist, album = excluded.album,
+                album_artist = excluded.album_artist, track_number = excluded.track_number,
+                disc_number = excluded.disc_number, year = excluded.year,
+                genre = excluded.genre, catalog_number = excluded.catalog_number,
+                duration_secs = excluded.duration_secs, format = excluded.format,
+                bit_depth = excluded.bit_depth, sample_rate = excluded.sample_rate,
+                channels = excluded.channels, file_size_bytes = excluded.file_size_bytes,
+                cue_file_path = excluded.cue_file_path, cue_end_secs = excluded.cue_end_secs,
+                artwork_path = COALESCE(NULLIF(excluded.artwork_path, ''), local_tracks.artwork_path),
+                last_modified = excluded.last_modified, indexed_at = excluded.indexed_at,
+                album_group_key = excluded.album_group_key,
+                album_group_title = excluded.album_group_title, source = excluded.source,
+                is_network_mount = excluded.is_network_mount,
+                isrc = CASE
+                    WHEN excluded.title IS local_tracks.title
+                     AND excluded.artist IS local_tracks.artist
+                     AND excluded.duration_secs IS local_tracks.duration_secs
+                    THEN local_tracks.isrc
+                END"#;
+
         self.conn
-            .execute(
-                r#"INSERT OR REPLACE INTO local_tracks
+            .query_row(
+                &format!(
+                    r#"INSERT INTO local_tracks
                (file_path, title, artist, album, album_artist, track_number,
                 disc_number, year, genre, catalog_number, duration_secs, format, bit_depth,
                 sample_rate, channels, file_size_bytes, cue_file_path,
                 cue_start_secs, cue_end_secs, artwork_path, last_modified, indexed_at,
                 album_group_key, album_group_title, source, is_network_mount)
-               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+               ON CONFLICT(file_path, cue_start_secs) DO UPDATE SET {RESCAN_SET}
+               ON CONFLICT(file_path) WHERE cue_file_path IS NULL DO UPDATE SET {RESCAN_SET}
+               RETURNING id"#
+                ),
                 params![
                     track.file_path,
                     track.title,
                     track.artist,
-                    track.album,
+                    album,
                     track.album_artist,
                     track.track_number,
-                    track.disc_number,
+                    disc_number,
                     track.year,
                     track.genre,
                     track.catalog_number,
@@ -1160,15 +1329,14 @@ impl LibraryDatabase {
                     track.artwork_path,
                     track.last_modified,
                     track.indexed_at,
-                    track.album_group_key,
-                    track.album_group_title,
+                    group_key,
+                    group_title,
                     source,
                     is_network_mount as i64,
                 ],
+                |row| row.get(0),
             )
-            .map_err(|e| LibraryError::Database(e.to_string()))?;
-
-        Ok(self.conn.last_insert_rowid())
+            .map_err(|e| LibraryError::Database(e.to_string()))
     }
 
     /// Get a track by ID
@@ -1262,7 +1430,29 @@ impl LibraryDatabase {
         self.delete_tracks_in_folder_prefixed(path)
     }
 
-    /// Clear all LOCAL library tracks (preserves Qobuz downloads)
+    /// Drop every transient folder whose share can't be read any more, with
+    /// its indexed tracks (the files are not touched). Returns the number of
+    /// tracks removed.
+    pub fn remove_unreachable_transient_folders(&self) -> Result<usize, LibraryError> {
+        let mut removed = 0;
+        for folder in self.get_folders_with_metadata()? {
+            if !folder.transient || std::fs::read_dir(&folder.path).is_ok() {
+                continue;
+            }
+            let tracks = self.remove_folder_with_tracks(&folder.path)?;
+            log::info!(
+                "Transient folder {} is unreachable: removed it and {} tracks",
+                folder.path,
+                tracks
+            );
+            removed += tracks;
+        }
+        Ok(removed)
+    }
+
+    /// Clear all LOCAL library tracks (preserves Qobuz downloads). Paths
+    /// ignored by a duplicate merge, split-album merges and stream fallback
+    /// links are forgotten too, so the next scan starts from scratch.
     pub fn clear_all_tracks(&self) -> Result<(), LibraryError> {
         self.conn
             .execute(
@@ -1270,6 +1460,13 @@ impl LibraryDatabase {
                 [],
             )
             .map_err(|e| LibraryError::Database(e.to_string()))?;
+        self.conn
+            .execute_batch(
+                "DELETE FROM ignored_track_paths;
+                 DELETE FROM album_merge_overrides;
+                 DELETE FROM stream_fallbacks;",
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
         Ok(())
     }
 
@@ -1293,6 +1490,146 @@ impl LibraryDatabase {
         Ok(paths)
     }
 
+    /// Audio files whose embedded CUE sheet was expanded into virtual tracks
+    /// (their rows carry the file itself as `cue_file_path`). A rescan skips
+    /// them instead of re-adding the whole file.
+    pub fn get_embedded_cue_paths(&self) -> Result<Vec<String>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT DISTINCT file_path FROM local_tracks WHERE cue_file_path = file_path")
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let mut paths = Vec::new();
+        for row in rows {
+            paths.push(row.map_err(|e| LibraryError::Database(e.to_string()))?);
+        }
+        Ok(paths)
+    }
+
+    /// Files a duplicate merge removed from the library. A rescan skips them
+    /// instead of re-adding the duplicate.
+    pub fn get_ignored_track_paths(&self) -> Result<Vec<String>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT file_path FROM ignored_track_paths")
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let mut paths = Vec::new();
+        for row in rows {
+            paths.push(row.map_err(|e| LibraryError::Database(e.to_string()))?);
+        }
+        Ok(paths)
+    }
+
+    /// Up to `limit` tracks whose ISRC was never looked up, oldest first.
+    /// Tracks without a real artist can't be matched and are left out.
+    pub fn get_tracks_without_isrc(&self, limit: usize) -> Result<Vec<LocalTrack>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {} FROM local_tracks
+                 WHERE isrc IS NULL AND artist != '' AND artist != 'Unknown Artist'
+                 ORDER BY id LIMIT ?",
+                Self::TRACK_COLUMNS
+            ))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map(params![limit as i64], |row| Self::row_to_track(row))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let mut tracks = Vec::new();
+        for row in rows {
+            tracks.push(row.map_err(|e| LibraryError::Database(e.to_string()))?);
+        }
+        Ok(tracks)
+    }
+
+    /// Store a track's ISRC. An empty `isrc` records that the lookup found
+    /// none, so [`Self::get_tracks_without_isrc`] stops returning the track.
+    pub fn update_track_isrc(&self, track_id: i64, isrc: &str) -> Result<(), LibraryError> {
+        self.conn
+            .execute(
+                "UPDATE local_tracks SET isrc = ? WHERE id = ?",
+                params![isrc, track_id],
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Link a local track to the Qobuz track that plays in its place when
+    /// the file can't be opened (unmounted drive, moved or corrupt file).
+    /// The link is stored against the track's file path, so it survives the
+    /// row being re-created by a rescan.
+    pub fn set_stream_fallback(
+        &self,
+        track_id: i64,
+        qobuz_track_id: u64,
+    ) -> Result<(), LibraryError> {
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO stream_fallbacks (file_path, cue_start_secs, qobuz_track_id)
+                 SELECT file_path, COALESCE(cue_start_secs, -1), ?1
+                 FROM local_tracks WHERE id = ?2",
+                params![qobuz_track_id as i64, track_id],
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        Ok(())
+    }
+
+    /// The Qobuz track linked with [`Self::set_stream_fallback`], if any.
+    pub fn get_stream_fallback(&self, track_id: i64) -> Result<Option<u64>, LibraryError> {
+        self.conn
+            .query_row(
+                "SELECT f.qobuz_track_id FROM local_tracks t
+                 JOIN stream_fallbacks f
+                   ON f.file_path = t.file_path
+                  AND f.cue_start_secs = COALESCE(t.cue_start_secs, -1)
+                 WHERE t.id = ?",
+                params![track_id],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .map(|id| id.map(|id| id as u64))
+            .map_err(|e| LibraryError::Database(e.to_string()))
+    }
+
+    /// Every image path the database still points at: track artwork and the
+    /// custom images of artists, albums, playlists and folders.
+    pub fn get_referenced_image_paths(&self) -> Result<Vec<String>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT artwork_path FROM local_tracks WHERE artwork_path IS NOT NULL
+                 UNION SELECT custom_image_path FROM artist_images
+                     WHERE custom_image_path IS NOT NULL
+                 UNION SELECT custom_image_path FROM custom_album_covers
+                 UNION SELECT custom_image_path FROM playlist_folders
+                     WHERE custom_image_path IS NOT NULL
+                 UNION SELECT custom_artwork_path FROM playlist_settings
+                     WHERE custom_artwork_path IS NOT NULL",
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let mut paths = Vec::new();
+        for row in rows {
+            paths.push(row.map_err(|e| LibraryError::Database(e.to_string()))?);
+        }
+        Ok(paths)
+    }
+
     /// Delete tracks by their IDs
     pub fn delete_tracks_by_ids(&self, ids: &[i64]) -> Result<usize, LibraryError> {
         if ids.is_empty() {
@@ -1316,6 +1653,21 @@ impl LibraryDatabase {
         Ok(count)
     }
 
+    /// Swap track `track_id` for `tracks` in one transaction, so a failed
+    /// insert leaves the original row in place (embedded-CUE expansion).
+    pub fn replace_track(&self, track_id: i64, tracks: &[LocalTrack]) -> Result<(), LibraryError> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        self.delete_tracks_by_ids(&[track_id])?;
+        for track in tracks {
+            self.insert_track(track)?;
+        }
+        tx.commit()
+            .map_err(|e| LibraryError::Database(e.to_string()))
+    }
+
     // === Query Methods ===
 
     /// Get all albums with optional hidden filter
@@ -2635,6 +2987,27 @@ impl LibraryDatabase {
         Ok(albums)
     }
 
+    /// Set the artwork of the tracks of album group `group_key` (the key
+    /// `get_albums_without_artwork` returns) that have none, leaving tracks
+    /// with their own artwork alone. Returns how many tracks were updated.
+    pub fn fill_album_artwork(
+        &self,
+        group_key: &str,
+        artwork_path: &str,
+    ) -> Result<usize, LibraryError> {
+        self.conn
+            .execute(
+                r#"
+            UPDATE local_tracks
+            SET artwork_path = ?
+            WHERE COALESCE(album_group_key, album || '|' || COALESCE(album_artist, artist)) = ?
+              AND (artwork_path IS NULL OR artwork_path = '')
+        "#,
+                params![artwork_path, group_key],
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))
+    }
+
     /// Update artwork path for all tracks in an album
     pub fn update_album_artwork(
         &self,
@@ -3143,6 +3516,10 @@ pub struct LibraryFolder {
     pub network_fs_type: Option<String>,
     pub user_override_network: bool,
     pub last_scan: Option<i64>,
+    /// One-time network share scan (not a permanent library folder); removed
+    /// with its tracks once a scan finds the share unreachable.
+    #[serde(default)]
+    pub transient: bool,
 }
 
 /// Playlist local settings (enhances remote Qobuz playlists)
@@ -5323,6 +5700,52 @@ impl LibraryDatabase {
         Ok(())
     }
 
+    // === Artist Biographies ===
+
+    /// Get the cached biography of an artist, fresh or not
+    pub fn get_artist_bio(
+        &self,
+        artist_name: &str,
+    ) -> Result<Option<crate::ArtistBioInfo>, LibraryError> {
+        self.conn
+            .query_row(
+                "SELECT artist_name, mbid, bio, fetched_at FROM artist_bios WHERE artist_name = ?1",
+                params![artist_name],
+                |row| {
+                    Ok(crate::ArtistBioInfo {
+                        artist_name: row.get(0)?,
+                        mbid: row.get(1)?,
+                        bio: row.get(2)?,
+                        fetched_at: row.get(3)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(|e| LibraryError::Database(format!("Failed to get artist bio: {}", e)))
+    }
+
+    /// Cache an artist's biography (`None` records that none was found)
+    pub fn cache_artist_bio(
+        &self,
+        artist_name: &str,
+        mbid: Option<&str>,
+        bio: Option<&str>,
+    ) -> Result<(), LibraryError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO artist_bios (artist_name, mbid, bio, fetched_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![artist_name, mbid, bio, now],
+            )
+            .map_err(|e| LibraryError::Database(format!("Failed to cache artist bio: {}", e)))?;
+        Ok(())
+    }
+
     // === Custom Album Covers ===
 
     /// Set a custom album cover
@@ -5718,40 +6141,437 @@ impl LibraryDatabase {
     }
 }
 
-#[cfg(test)]
-mod metadata_grouping_tests {
-    use super::*;
-    use tempfile::TempDir;
+/// Durations (seconds) within this of each other count as the same recording.
+const DUPLICATE_DURATION_TOLERANCE_SECS: u64 = 2;
 
-    fn fresh_db() -> (TempDir, LibraryDatabase) {
-        let tmp = TempDir::new().unwrap();
-        let path = tmp.path().join("library.db");
-        let db = LibraryDatabase::open(&path).unwrap();
-        (tmp, db)
-    }
+/// Local tracks that are the same recording: same title, artist and album
+/// (case- and whitespace-insensitive) and durations within
+/// [`DUPLICATE_DURATION_TOLERANCE_SECS`]. Always at least two tracks.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DuplicateGroup {
+    pub tracks: Vec<LocalTrack>,
+}
 
-    fn insert_track_for_test(
-        db: &LibraryDatabase,
-        file_path: &str,
-        album: Option<&str>,
-        album_artist: Option<&str>,
-        artist: &str,
-        album_group_key: &str,
-    ) {
-        let mut t = LocalTrack::default();
-        t.file_path = file_path.to_string();
-        t.title = format!("Track at {}", file_path);
-        t.album = album.unwrap_or("").to_string();
-        t.album_artist = album_artist.map(String::from);
-        t.artist = artist.to_string();
-        t.album_group_key = album_group_key.to_string();
-        t.album_group_title = album.unwrap_or("").to_string();
-        db.insert_track(&t).unwrap();
-    }
+/// Which track of a [`DuplicateGroup`] survives a merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Lossless over lossy, then bit depth, sample rate and file size.
+    KeepHighestQuality,
+    /// Newest file modification time.
+    KeepMostRecent,
+    /// Alphabetically first file path.
+    KeepFirstPath,
+}
 
-    #[test]
-    fn metadata_group_merges_tracks_across_folders_with_same_album() {
-        let (_tmp, db) = fresh_db();
+/// Outcome of [`LibraryDatabase::merge_duplicates`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct MergeSummary {
+    pub groups_merged: usize,
+    pub tracks_removed: usize,
+    /// Playlist entries re-pointed at a surviving track.
+    pub playlist_entries_moved: usize,
+}
+
+fn duplicate_key(track: &LocalTrack) -> (String, String, String) {
+    let norm = |s: &str| {
+        s.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    };
+    (norm(&track.artist), norm(&track.title), norm(&track.album))
+}
+
+/// Quality rank for [`MergeStrategy::KeepHighestQuality`]; larger is better.
+fn quality_rank(track: &LocalTrack) -> (bool, u32, u64, u64) {
+    let lossless = !matches!(track.format, AudioFormat::Mp3 | AudioFormat::Unknown);
+    (
+        lossless,
+        track.bit_depth.unwrap_or(0),
+        track.sample_rate as u64,
+        track.file_size_bytes,
+    )
+}
+
+/// The track of `tracks` that `strategy` keeps. Ties go to the first path.
+fn pick_survivor(tracks: &[LocalTrack], strategy: MergeStrategy) -> Option<&LocalTrack> {
+    let by_path = |a: &&LocalTrack, b: &&LocalTrack| b.file_path.cmp(&a.file_path);
+    match strategy {
+        MergeStrategy::KeepHighestQuality => tracks
+            .iter()
+            .max_by(|a, b| quality_rank(a).cmp(&quality_rank(b)).then(by_path(a, b))),
+        MergeStrategy::KeepMostRecent => tracks
+            .iter()
+            .max_by(|a, b| a.last_modified.cmp(&b.last_modified).then(by_path(a, b))),
+        MergeStrategy::KeepFirstPath => tracks.iter().min_by(|a, b| a.file_path.cmp(&b.file_path)),
+    }
+}
+
+impl LibraryDatabase {
+    // === Duplicate merging ===
+
+    /// Group the user's local tracks into duplicate sets. CUE tracks (many
+    /// tracks per file) and Qobuz downloads are left out.
+    pub fn find_duplicates(&self) -> Result<Vec<DuplicateGroup>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {} FROM local_tracks
+                 WHERE cue_file_path IS NULL AND (source IS NULL OR source = 'user')",
+                Self::TRACK_COLUMNS
+            ))
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        let tracks = stmt
+            .query_map([], |row| Self::row_to_track(row))
+            .map_err(|e| LibraryError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let mut by_key: std::collections::BTreeMap<_, Vec<LocalTrack>> = Default::default();
+        for track in tracks {
+            by_key.entry(duplicate_key(&track)).or_default().push(track);
+        }
+
+        // Within a key, split where consecutive durations drift apart
+        // (an edit vs. the album version).
+        let mut groups = Vec::new();
+        for (_, mut tracks) in by_key {
+            if tracks.len() < 2 {
+                continue;
+            }
+            tracks.sort_by_key(|t| t.duration_secs);
+            let mut current: Vec<LocalTrack> = Vec::new();
+            for track in tracks {
+                let split = current.last().is_some_and(|last| {
+                    track.duration_secs - last.duration_secs > DUPLICATE_DURATION_TOLERANCE_SECS
+                });
+                if split {
+                    let done = std::mem::take(&mut current);
+                    if done.len() > 1 {
+                        groups.push(DuplicateGroup { tracks: done });
+                    }
+                }
+                current.push(track);
+            }
+            if current.len() > 1 {
+                groups.push(DuplicateGroup { tracks: current });
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Keep one track per group and drop the rest from the library. The
+    /// removed tracks' playlist entries (Qobuz-playlist sidecars, custom
+    /// order and local playlists) move to the survivor; entries the
+    /// survivor already has are dropped. Files on disk are never touched;
+    /// the removed paths are recorded as ignored so a rescan doesn't bring
+    /// them back. All groups merge in one transaction.
+    pub fn merge_duplicates(
+        &mut self,
+        groups: &[DuplicateGroup],
+        strategy: MergeStrategy,
+    ) -> Result<MergeSummary, LibraryError> {
+        let tx = self
+            .conn
+            .transaction()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        let mut summary = MergeSummary::default();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        for group in groups {
+            if group.tracks.len() < 2 {
+                continue;
+            }
+            let Some(survivor) = pick_survivor(&group.tracks, strategy) else {
+                continue;
+            };
+            for track in group.tracks.iter().filter(|t| t.id != survivor.id) {
+                let moved = tx
+                    .execute(
+                        "UPDATE OR IGNORE playlist_local_tracks SET local_track_id = ?1
+                         WHERE local_track_id = ?2",
+                        params![survivor.id, track.id],
+                    )
+                    .map_err(|e| LibraryError::Database(e.to_string()))?;
+                tx.execute(
+                    "DELETE FROM playlist_local_tracks WHERE local_track_id = ?1",
+                    params![track.id],
+                )
+                .map_err(|e| LibraryError::Database(e.to_string()))?;
+                tx.execute(
+                    "UPDATE OR IGNORE playlist_track_custom_order SET track_id = ?1
+                     WHERE track_id = ?2 AND is_local = 1",
+                    params![survivor.id, track.id],
+                )
+                .map_err(|e| LibraryError::Database(e.to_string()))?;
+                tx.execute(
+                    "DELETE FROM playlist_track_custom_order WHERE track_id = ?1 AND is_local = 1",
+                    params![track.id],
+                )
+                .map_err(|e| LibraryError::Database(e.to_string()))?;
+                // Local playlists have no uniqueness constraint to lean on:
+                // drop the duplicate's rows from playlists that already list
+                // the survivor and close the gaps, then repoint the rest.
+                let collided: Vec<String> = {
+                    let mut stmt = tx
+                        .prepare(
+                            "SELECT DISTINCT playlist_id FROM local_playlist_tracks
+                             WHERE source = 'local' AND local_path = ?2
+                               AND playlist_id IN (SELECT playlist_id FROM local_playlist_tracks
+                                                   WHERE source = 'local' AND local_path = ?1)",
+                        )
+                        .map_err(|e| LibraryError::Database(e.to_string()))?;
+                    let rows = stmt
+                        .query_map(params![survivor.file_path, track.file_path], |row| {
+                            row.get(0)
+                        })
+                        .map_err(|e| LibraryError::Database(e.to_string()))?;
+                    rows.collect::<Result<_, _>>()
+                        .map_err(|e| LibraryError::Database(e.to_string()))?
+                };
+                for playlist_id in &collided {
+                    tx.execute(
+                        "DELETE FROM local_playlist_tracks
+                         WHERE playlist_id = ?1 AND source = 'local' AND local_path = ?2",
+                        params![playlist_id, track.file_path],
+                    )
+                    .map_err(|e| LibraryError::Database(e.to_string()))?;
+                    tx.execute(
+                        "UPDATE local_playlist_tracks SET position =
+                             (SELECT COUNT(*) FROM local_playlist_tracks AS o
+                              WHERE o.playlist_id = local_playlist_tracks.playlist_id
+                                AND o.position < local_playlist_tracks.position)
+                         WHERE playlist_id = ?1",
+                        params![playlist_id],
+                    )
+                    .map_err(|e| LibraryError::Database(e.to_string()))?;
+                }
+                let moved_local = tx
+                    .execute(
+                        "UPDATE local_playlist_tracks SET local_path = ?1
+                         WHERE source = 'local' AND local_path = ?2",
+                        params![survivor.file_path, track.file_path],
+                    )
+                    .map_err(|e| LibraryError::Database(e.to_string()))?;
+                summary.tracks_removed += tx
+                    .execute("DELETE FROM local_tracks WHERE id = ?1", params![track.id])
+                    .map_err(|e| LibraryError::Database(e.to_string()))?;
+                tx.execute(
+                    "INSERT OR REPLACE INTO ignored_track_paths (file_path, kept_path, ignored_at)
+                     VALUES (?1, ?2, ?3)",
+                    params![track.file_path, survivor.file_path, now],
+                )
+                .map_err(|e| LibraryError::Database(e.to_string()))?;
+                summary.playlist_entries_moved += moved + moved_local;
+            }
+            summary.groups_merged += 1;
+        }
+
+        tx.commit()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        Ok(summary)
+    }
+}
+
+/// One album of a [`SplitAlbumGroup`]: a local album holding a single disc
+/// of the release.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SplitAlbumPart {
+    pub group_key: String,
+    /// Album tag as indexed, disc suffix included.
+    pub title: String,
+    pub disc_number: u32,
+    pub track_count: usize,
+}
+
+/// Local albums that are the discs of one release indexed separately: same
+/// album artist and the same title once a disc suffix ("(Disc 2)", "CD2") is
+/// stripped, each holding a different disc. Always at least two parts,
+/// ordered by disc.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SplitAlbumGroup {
+    pub artist: String,
+    /// Title without the disc suffix; the default merged title.
+    pub title: String,
+    pub parts: Vec<SplitAlbumPart>,
+}
+
+impl LibraryDatabase {
+    // === Split album merging ===
+
+    /// Find multi-disc releases that were indexed as one album per disc. A
+    /// part's disc comes from its title suffix, else from its tracks when
+    /// they agree on one; albums already spanning several discs are left
+    /// out, as are groups where two parts claim the same disc (likely two
+    /// editions rather than two discs).
+    pub fn find_split_albums(&self) -> Result<Vec<SplitAlbumGroup>, LibraryError> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                r#"
+                SELECT group_key, MIN(title), MIN(artist), MIN(disc_number), MAX(disc_number),
+                       COUNT(*)
+                FROM (
+                    SELECT
+                        COALESCE(album_group_key, album || '|' || COALESCE(album_artist, artist)) as group_key,
+                        album as title,
+                        COALESCE(album_artist, artist) as artist,
+                        disc_number
+                    FROM local_tracks
+                    WHERE cue_file_path IS NULL AND (source IS NULL OR source = 'user')
+                )
+                GROUP BY group_key
+                "#,
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<u32>>(3)?,
+                    row.get::<_, Option<u32>>(4)?,
+                    row.get::<_, usize>(5)?,
+                ))
+            })
+            .map_err(|e| LibraryError::Database(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+
+        let norm = |s: &str| {
+            s.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        };
+        let mut by_release: std::collections::BTreeMap<_, SplitAlbumGroup> = Default::default();
+        for (group_key, title, artist, min_disc, max_disc, track_count) in rows {
+            let from_tracks = min_disc.filter(|d| Some(*d) == max_disc && *d > 0);
+            let Some(disc_number) =
+                MetadataExtractor::disc_number_from_name(&title).or(from_tracks)
+            else {
+                continue;
+            };
+            let base = MetadataExtractor::strip_disc_suffix(&title);
+            let group = by_release
+                .entry((norm(&artist), norm(&base)))
+                .or_insert_with(|| SplitAlbumGroup {
+                    artist: artist.clone(),
+                    title: base,
+                    parts: Vec::new(),
+                });
+            group.parts.push(SplitAlbumPart {
+                group_key,
+                title,
+                disc_number,
+                track_count,
+            });
+        }
+
+        Ok(by_release
+            .into_values()
+            .filter_map(|mut group| {
+                group.parts.sort_by_key(|p| p.disc_number);
+                let distinct = group
+                    .parts
+                    .windows(2)
+                    .all(|w| w[0].disc_number != w[1].disc_number);
+                (group.parts.len() > 1 && distinct).then_some(group)
+            })
+            .collect())
+    }
+
+    /// Fold the parts of `group` into one album titled `canonical_title`
+    /// (the group's stripped title when blank): every track moves under the
+    /// first part's group key and takes its part's disc number, so the
+    /// merged album lists one disc per former album. The merge is recorded
+    /// per file so rescans re-apply it. Returns the number of tracks
+    /// updated. Files on disk are never touched.
+    pub fn merge_split_albums(
+        &mut self,
+        group: &SplitAlbumGroup,
+        canonical_title: &str,
+    ) -> Result<usize, LibraryError> {
+        let Some(canonical_key) = group.parts.first().map(|p| p.group_key.clone()) else {
+            return Ok(0);
+        };
+        let title = match canonical_title.trim() {
+            "" => group.title.trim(),
+            title => title,
+        };
+        let tx = self
+            .conn
+            .transaction()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        let mut updated = 0;
+        for part in &group.parts {
+            tx.execute(
+                r#"
+                INSERT OR REPLACE INTO album_merge_overrides
+                    (file_path, album_group_key, album_title, disc_number)
+                SELECT file_path, ?1, ?2, ?3
+                FROM local_tracks
+                WHERE COALESCE(album_group_key, album || '|' || COALESCE(album_artist, artist)) = ?4
+                "#,
+                params![canonical_key, title, part.disc_number, part.group_key],
+            )
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+            updated += tx
+                .execute(
+                    r#"
+                    UPDATE local_tracks
+                    SET album_group_key = ?1, album_group_title = ?2, album = ?2, disc_number = ?3
+                    WHERE COALESCE(album_group_key, album || '|' || COALESCE(album_artist, artist)) = ?4
+                    "#,
+                    params![canonical_key, title, part.disc_number, part.group_key],
+                )
+                .map_err(|e| LibraryError::Database(e.to_string()))?;
+        }
+        tx.commit()
+            .map_err(|e| LibraryError::Database(e.to_string()))?;
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod metadata_grouping_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, LibraryDatabase) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("library.db");
+        let db = LibraryDatabase::open(&path).unwrap();
+        (tmp, db)
+    }
+
+    fn insert_track_for_test(
+        db: &LibraryDatabase,
+        file_path: &str,
+        album: Option<&str>,
+        album_artist: Option<&str>,
+        artist: &str,
+        album_group_key: &str,
+    ) {
+        let mut t = LocalTrack::default();
+        t.file_path = file_path.to_string();
+        t.title = format!("Track at {}", file_path);
+        t.album = album.unwrap_or("").to_string();
+        t.album_artist = album_artist.map(String::from);
+        t.artist = artist.to_string();
+        t.album_group_key = album_group_key.to_string();
+        t.album_group_title = album.unwrap_or("").to_string();
+        db.insert_track(&t).unwrap();
+    }
+
+    #[test]
+    fn metadata_group_merges_tracks_across_folders_with_same_album() {
+        let (_tmp, db) = fresh_db();
         // Two folders, same album metadata -> one metadata group.
         insert_track_for_test(
             &db,
@@ -6739,3 +7559,481 @@ mod sidecar_position_tests {
         assert!(db.heal_playlist_sidecar_positions(7, 5).unwrap().is_empty());
     }
 }
+
+#[cfg(test)]
+mod duplicate_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, LibraryDatabase) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("library.db");
+        let db = LibraryDatabase::open(&path).unwrap();
+        (tmp, db)
+    }
+
+    fn track(path: &str, title: &str, duration_secs: u64, format: AudioFormat) -> LocalTrack {
+        let mut t = LocalTrack::default();
+        t.file_path = path.to_string();
+        t.title = title.to_string();
+        t.artist = "Artist".into();
+        t.album = "Album".into();
+        t.duration_secs = duration_secs;
+        t.format = format;
+        t
+    }
+
+    #[test]
+    fn duplicates_group_by_tags_and_close_durations() {
+        let (_tmp, db) = fresh_db();
+        db.insert_track(&track("/a/song.flac", "Song", 200, AudioFormat::Flac))
+            .unwrap();
+        db.insert_track(&track("/b/song.mp3", "  song ", 201, AudioFormat::Mp3))
+            .unwrap();
+        // Same tags, but a different edit.
+        db.insert_track(&track("/c/song-edit.flac", "Song", 180, AudioFormat::Flac))
+            .unwrap();
+        db.insert_track(&track("/a/other.flac", "Other", 200, AudioFormat::Flac))
+            .unwrap();
+
+        let groups = db.find_duplicates().unwrap();
+        assert_eq!(groups.len(), 1);
+        let mut paths: Vec<&str> = groups[0]
+            .tracks
+            .iter()
+            .map(|t| t.file_path.as_str())
+            .collect();
+        paths.sort();
+        assert_eq!(paths, ["/a/song.flac", "/b/song.mp3"]);
+    }
+
+    #[test]
+    fn survivor_follows_the_strategy() {
+        let mut lossy = track("/a/song.mp3", "Song", 200, AudioFormat::Mp3);
+        lossy.last_modified = 20;
+        lossy.file_size_bytes = 90_000_000;
+        let mut hires = track("/b/song.flac", "Song", 200, AudioFormat::Flac);
+        hires.bit_depth = Some(24);
+        hires.last_modified = 10;
+        let mut cd = track("/c/song.flac", "Song", 200, AudioFormat::Flac);
+        cd.bit_depth = Some(16);
+        cd.last_modified = 5;
+        let tracks = [lossy, hires, cd];
+
+        let pick = |s| pick_survivor(&tracks, s).unwrap().file_path.as_str();
+        assert_eq!(pick(MergeStrategy::KeepHighestQuality), "/b/song.flac");
+        assert_eq!(pick(MergeStrategy::KeepMostRecent), "/a/song.mp3");
+        assert_eq!(pick(MergeStrategy::KeepFirstPath), "/a/song.mp3");
+    }
+
+    #[test]
+    fn merge_moves_playlist_entries_and_keeps_one_track() {
+        let (_tmp, mut db) = fresh_db();
+        let keep = db
+            .insert_track(&track("/a/song.flac", "Song", 200, AudioFormat::Flac))
+            .unwrap();
+        let drop = db
+            .insert_track(&track("/b/song.mp3", "Song", 200, AudioFormat::Mp3))
+            .unwrap();
+        // Playlist 1 holds only the duplicate; playlist 2 holds both.
+        db.add_local_track_to_playlist(1, drop, 0).unwrap();
+        db.add_local_track_to_playlist(2, keep, 0).unwrap();
+        db.add_local_track_to_playlist(2, drop, 1).unwrap();
+        let local = db
+            .with_connection(|conn| crate::local_playlists::create(conn, "Mix", None, false))
+            .unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO local_playlist_tracks (playlist_id, position, source, local_path, added_at)
+                 VALUES (?1, 0, 'local', '/b/song.mp3', 0)",
+                params![local],
+            )
+            .unwrap();
+
+        let groups = db.find_duplicates().unwrap();
+        let summary = db
+            .merge_duplicates(&groups, MergeStrategy::KeepHighestQuality)
+            .unwrap();
+        assert_eq!(summary.groups_merged, 1);
+        assert_eq!(summary.tracks_removed, 1);
+        assert_eq!(summary.playlist_entries_moved, 2);
+
+        assert!(db.get_track(drop).unwrap().is_none());
+        assert!(db.get_track(keep).unwrap().is_some());
+        for playlist in [1, 2] {
+            let ids: Vec<i64> = db
+                .get_playlist_local_tracks(playlist)
+                .unwrap()
+                .iter()
+                .map(|t| t.id)
+                .collect();
+            assert_eq!(ids, [keep]);
+        }
+        let path: String = db
+            .conn
+            .query_row(
+                "SELECT local_path FROM local_playlist_tracks WHERE playlist_id = ?1",
+                params![local],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(path, "/a/song.flac");
+        assert!(db.find_duplicates().unwrap().is_empty());
+
+        // The merged-away file stays out of later scans until the library
+        // is cleared.
+        assert_eq!(db.get_ignored_track_paths().unwrap(), ["/b/song.mp3"]);
+        db.clear_all_tracks().unwrap();
+        assert!(db.get_ignored_track_paths().unwrap().is_empty());
+    }
+
+    #[test]
+    fn merge_drops_local_playlist_rows_the_survivor_already_has() {
+        let (_tmp, mut db) = fresh_db();
+        db.insert_track(&track("/a/song.flac", "Song", 200, AudioFormat::Flac))
+            .unwrap();
+        db.insert_track(&track("/b/song.mp3", "Song", 200, AudioFormat::Mp3))
+            .unwrap();
+        let local = db
+            .with_connection(|conn| crate::local_playlists::create(conn, "Mix", None, false))
+            .unwrap();
+        for (position, path) in ["/a/song.flac", "/b/song.mp3", "/c/other.flac"]
+            .iter()
+            .enumerate()
+        {
+            db.conn
+                .execute(
+                    "INSERT INTO local_playlist_tracks (playlist_id, position, source, local_path, added_at)
+                     VALUES (?1, ?2, 'local', ?3, 0)",
+                    params![local, position as i64, path],
+                )
+                .unwrap();
+        }
+
+        let groups = db.find_duplicates().unwrap();
+        let summary = db
+            .merge_duplicates(&groups, MergeStrategy::KeepHighestQuality)
+            .unwrap();
+        assert_eq!(summary.playlist_entries_moved, 0);
+
+        let rows: Vec<(i64, String)> = {
+            let mut stmt = db
+                .conn
+                .prepare(
+                    "SELECT position, local_path FROM local_playlist_tracks
+                     WHERE playlist_id = ?1 ORDER BY position",
+                )
+                .unwrap();
+            stmt.query_map(params![local], |r| Ok((r.get(0)?, r.get(1)?)))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap()
+        };
+        assert_eq!(
+            rows,
+            [
+                (0, "/a/song.flac".to_string()),
+                (1, "/c/other.flac".to_string())
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod split_album_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, LibraryDatabase) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("library.db");
+        let db = LibraryDatabase::open(&path).unwrap();
+        (tmp, db)
+    }
+
+    fn track(path: &str, album: &str, group_key: &str, disc: Option<u32>) -> LocalTrack {
+        let mut t = LocalTrack::default();
+        t.file_path = path.to_string();
+        t.title = path.to_string();
+        t.artist = "Artist".into();
+        t.album = album.to_string();
+        t.album_group_key = group_key.to_string();
+        t.album_group_title = MetadataExtractor::strip_disc_suffix(album);
+        t.disc_number = disc;
+        t
+    }
+
+    #[test]
+    fn split_albums_group_by_stripped_title_and_disc() {
+        let (_tmp, db) = fresh_db();
+        db.insert_track(&track("/m/a1/01.flac", "Opus (Disc 1)", "/m/a1", None))
+            .unwrap();
+        db.insert_track(&track("/m/a2/01.flac", "Opus", "/m/a2", Some(2)))
+            .unwrap();
+        db.insert_track(&track("/m/a2/02.flac", "Opus", "/m/a2", Some(2)))
+            .unwrap();
+        // Two editions both claiming disc 1 are not a split release.
+        db.insert_track(&track("/m/b1/01.flac", "Other CD1", "/m/b1", None))
+            .unwrap();
+        db.insert_track(&track("/m/b2/01.flac", "Other", "/m/b2", Some(1)))
+            .unwrap();
+
+        let groups = db.find_split_albums().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].title, "Opus");
+        let parts: Vec<(&str, u32, usize)> = groups[0]
+            .parts
+            .iter()
+            .map(|p| (p.group_key.as_str(), p.disc_number, p.track_count))
+            .collect();
+        assert_eq!(parts, [("/m/a1", 1, 1), ("/m/a2", 2, 2)]);
+    }
+
+    #[test]
+    fn merge_folds_parts_into_one_multi_disc_album() {
+        let (_tmp, mut db) = fresh_db();
+        db.insert_track(&track("/m/a1/01.flac", "Opus (Disc 1)", "/m/a1", Some(1)))
+            .unwrap();
+        db.insert_track(&track("/m/a2/01.flac", "Opus (Disc 2)", "/m/a2", Some(1)))
+            .unwrap();
+
+        let groups = db.find_split_albums().unwrap();
+        assert_eq!(db.merge_split_albums(&groups[0], "Opus").unwrap(), 2);
+
+        let tracks = db.get_album_tracks("/m/a1").unwrap();
+        let discs: Vec<Option<u32>> = tracks.iter().map(|t| t.disc_number).collect();
+        assert_eq!(discs, [Some(1), Some(2)]);
+        assert!(tracks.iter().all(|t| t.album == "Opus"));
+        assert!(db.get_album_tracks("/m/a2").unwrap().is_empty());
+        assert!(db.find_split_albums().unwrap().is_empty());
+
+        // A rescan re-inserts the file with its original tags; the merge
+        // holds.
+        db.insert_track(&track("/m/a2/01.flac", "Opus (Disc 2)", "/m/a2", Some(1)))
+            .unwrap();
+        let tracks = db.get_album_tracks("/m/a1").unwrap();
+        let discs: Vec<Option<u32>> = tracks.iter().map(|t| t.disc_number).collect();
+        assert_eq!(discs, [Some(1), Some(2)]);
+        assert!(tracks.iter().all(|t| t.album == "Opus"));
+        assert!(db.get_album_tracks("/m/a2").unwrap().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod transient_folder_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn unreachable_transient_folders_are_dropped_with_their_tracks() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let share = tmp.path().join("share");
+        std::fs::create_dir(&share).unwrap();
+        let share = share.to_string_lossy().to_string();
+        let gone = tmp.path().join("gone").to_string_lossy().to_string();
+
+        let id = db.add_transient_folder(&share, Some("nfs")).unwrap();
+        db.add_transient_folder(&gone, None).unwrap();
+        db.add_folder(&tmp.path().join("permanent").to_string_lossy())
+            .unwrap();
+        for path in [format!("{share}/a.flac"), format!("{gone}/b.flac")] {
+            let mut t = LocalTrack::default();
+            t.file_path = path;
+            t.title = "Song".into();
+            db.insert_track(&t).unwrap();
+        }
+
+        let folder = db.get_folder_by_id(id).unwrap().unwrap();
+        assert!(folder.transient && folder.is_network);
+        assert_eq!(folder.alias.as_deref(), Some("Network — share"));
+
+        assert_eq!(db.remove_unreachable_transient_folders().unwrap(), 1);
+        let paths: Vec<String> = db
+            .get_folders_with_metadata()
+            .unwrap()
+            .into_iter()
+            .map(|f| f.path)
+            .collect();
+        assert_eq!(paths.len(), 2, "{paths:?}");
+        assert!(paths.contains(&share) && !paths.contains(&gone));
+        let tracks = db.get_all_track_paths().unwrap();
+        assert_eq!(tracks.len(), 1);
+        assert!(tracks[0].1.starts_with(&share));
+    }
+}
+
+#[cfg(test)]
+mod artwork_fill_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn fetched_artwork_survives_a_rescan() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/a.flac".into();
+        t.title = "Song".into();
+        t.album = "Album".into();
+        t.album_group_key = "/m".into();
+        db.insert_track(&t).unwrap();
+        let filled = db.fill_album_artwork("/m", "/thumbs/fetched.jpg").unwrap();
+        assert_eq!(filled, 1);
+
+        // The rescan finds no artwork in the file: the fetched cover stays.
+        let id = db.insert_track(&t).unwrap();
+        let track = db.get_track(id).unwrap().unwrap();
+        assert_eq!(track.artwork_path.as_deref(), Some("/thumbs/fetched.jpg"));
+
+        // Artwork the scan does find wins.
+        t.artwork_path = Some("/thumbs/embedded.jpg".into());
+        let id = db.insert_track(&t).unwrap();
+        let track = db.get_track(id).unwrap().unwrap();
+        assert_eq!(track.artwork_path.as_deref(), Some("/thumbs/embedded.jpg"));
+    }
+}
+
+#[cfg(test)]
+mod isrc_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn looked_up_tracks_leave_the_isrc_queue() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut ids = Vec::new();
+        for (path, artist) in [
+            ("/m/a.flac", "Nina Simone"),
+            ("/m/b.flac", "Unknown Artist"),
+        ] {
+            let mut t = LocalTrack::default();
+            t.file_path = path.into();
+            t.title = "Song".into();
+            t.artist = artist.into();
+            ids.push(db.insert_track(&t).unwrap());
+        }
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/c.flac".into();
+        t.title = "Other".into();
+        t.artist = "Nina Simone".into();
+        ids.push(db.insert_track(&t).unwrap());
+
+        let queued: Vec<i64> = db
+            .get_tracks_without_isrc(10)
+            .unwrap()
+            .iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(queued, vec![ids[0], ids[2]]);
+        assert_eq!(db.get_tracks_without_isrc(1).unwrap().len(), 1);
+
+        db.update_track_isrc(ids[0], "USRC17607839").unwrap();
+        db.update_track_isrc(ids[2], "").unwrap();
+        assert!(db.get_tracks_without_isrc(10).unwrap().is_empty());
+        let isrc: Option<String> = db
+            .conn
+            .query_row(
+                "SELECT isrc FROM local_tracks WHERE id = ?",
+                params![ids[0]],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(isrc.as_deref(), Some("USRC17607839"));
+    }
+
+    #[test]
+    fn isrc_survives_a_rescan() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/a.flac".into();
+        t.title = "Song".into();
+        t.artist = "Nina Simone".into();
+        let id = db.insert_track(&t).unwrap();
+        db.update_track_isrc(id, "USRC17607839").unwrap();
+
+        // The same file rescanned updates the row in place: same id, ISRC
+        // kept.
+        t.genre = Some("Jazz".into());
+        assert_eq!(db.insert_track(&t).unwrap(), id);
+        let track = db.get_track(id).unwrap().unwrap();
+        assert_eq!(track.genre.as_deref(), Some("Jazz"));
+        assert!(db.get_tracks_without_isrc(10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn isrc_is_cleared_by_a_rescan_after_a_retag() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/a.flac".into();
+        t.title = "Song".into();
+        t.artist = "Nina Simone".into();
+        let id = db.insert_track(&t).unwrap();
+        db.update_track_isrc(id, "USRC17607839").unwrap();
+
+        // Retitled: the looked-up ISRC no longer applies, so the track is
+        // queued for a new lookup.
+        t.title = "Song (Live)".into();
+        assert_eq!(db.insert_track(&t).unwrap(), id);
+        let queued: Vec<i64> = db
+            .get_tracks_without_isrc(10)
+            .unwrap()
+            .iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(queued, vec![id]);
+
+        // Same for a different artist.
+        db.update_track_isrc(id, "USRC17607839").unwrap();
+        t.artist = "Nina Simone & Friends".into();
+        db.insert_track(&t).unwrap();
+        assert_eq!(db.get_tracks_without_isrc(10).unwrap().len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod stream_fallback_tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn stream_fallback_round_trips() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/a.flac".into();
+        t.title = "Song".into();
+        let id = db.insert_track(&t).unwrap();
+
+        assert_eq!(db.get_stream_fallback(id).unwrap(), None);
+        db.set_stream_fallback(id, 59954869).unwrap();
+        assert_eq!(db.get_stream_fallback(id).unwrap(), Some(59954869));
+        db.set_stream_fallback(id, 12345).unwrap();
+        assert_eq!(db.get_stream_fallback(id).unwrap(), Some(12345));
+        assert_eq!(db.get_stream_fallback(id + 1).unwrap(), None);
+    }
+
+    #[test]
+    fn stream_fallback_survives_the_row_being_recreated() {
+        let tmp = TempDir::new().unwrap();
+        let db = LibraryDatabase::open(&tmp.path().join("library.db")).unwrap();
+        let mut t = LocalTrack::default();
+        t.file_path = "/m/a.flac".into();
+        t.title = "Song".into();
+        let id = db.insert_track(&t).unwrap();
+        db.set_stream_fallback(id, 59954869).unwrap();
+        let mut other = LocalTrack::default();
+        other.file_path = "/m/b.flac".into();
+        db.insert_track(&other).unwrap();
+
+        // The row goes away (file removed, library rebuilt) and comes back
+        // under a new id: the link follows the path.
+        db.delete_tracks_by_ids(&[id]).unwrap();
+        let new_id = db.insert_track(&t).unwrap();
+        assert_ne!(new_id, id);
+        assert_eq!(db.get_stream_fallback(new_id).unwrap(), Some(59954869));
+    }
+}
@@ -0,0 +1,239 @@
+//! Links between local albums and the user's Discogs collection.
+//!
+//! A collection sync pages through the user's Discogs collection (the
+//! frontend owns the HTTP side) and matches each release against the local
+//! albums by artist, title and year with [`AlbumMatcher`]. The matches are
+//! stored here keyed by album group key, each sync replacing the previous
+//! set, so an album leaves the collection when its release does.
+//!
+//! Discogs disambiguates artist names with a numeric suffix ("Nirvana (2)")
+//! and titles often carry an edition note ("(Remastered)"), so both sides
+//! drop trailing parenthesised groups, punctuation and a leading "The"
+//! before comparing. The year is the release's pressing year, which for a
+//! reissue differs from the album's, so it only breaks ties.
+//!
+//! All functions take `&Connection` (the local_playlists idiom): no Tauri
+//! state, no async runtime — testable with in-memory SQLite.
+
+use rusqlite::{params, Connection, OptionalExtension, Result};
+
+use crate::LocalAlbum;
+
+/// A local album matched to a release of the user's collection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionLink {
+    pub album_group_key: String,
+    pub release_id: u64,
+    /// First listed format ("Vinyl", "CD", ...).
+    pub format: Option<String>,
+}
+
+/// Outcome of a collection sync.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CollectionSyncSummary {
+    pub releases: usize,
+    pub matched: usize,
+    /// "Artist - Title" of the releases no local album matched.
+    pub unmatched: Vec<String>,
+}
+
+fn now_ms() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+/// Create the collection table. Idempotent (`IF NOT EXISTS`), run by
+/// `LibraryDatabase::open` next to the rest of the schema.
+pub fn init_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS discogs_collection (
+            album_group_key TEXT PRIMARY KEY,
+            release_id INTEGER NOT NULL,
+            format TEXT,
+            synced_at INTEGER NOT NULL
+        );
+        "#,
+    )
+}
+
+/// Replace the stored links with `links` in one transaction. Returns how
+/// many were written.
+pub fn replace_links(conn: &mut Connection, links: &[CollectionLink]) -> Result<usize> {
+    let now = now_ms();
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM discogs_collection", [])?;
+    let mut written = 0;
+    for link in links {
+        written += tx.execute(
+            "INSERT OR REPLACE INTO discogs_collection
+             (album_group_key, release_id, format, synced_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![link.album_group_key, link.release_id, link.format, now],
+        )?;
+    }
+    tx.commit()?;
+    Ok(written)
+}
+
+/// The collection release linked to an album, if any.
+pub fn link_for_album(conn: &Connection, album_group_key: &str) -> Result<Option<CollectionLink>> {
+    conn.query_row(
+        "SELECT album_group_key, release_id, format FROM discogs_collection
+         WHERE album_group_key = ?1",
+        params![album_group_key],
+        |row| {
+            Ok(CollectionLink {
+                album_group_key: row.get(0)?,
+                release_id: row.get(1)?,
+                format: row.get(2)?,
+            })
+        },
+    )
+    .optional()
+}
+
+/// Lowercased alphanumerics of `value` without trailing `(...)` / `[...]`
+/// groups or a leading "the".
+fn normalize(value: &str) -> String {
+    let mut trimmed = value.trim();
+    while let Some(close) = trimmed.chars().last().filter(|c| matches!(*c, ')' | ']')) {
+        let open = if close == ')' { '(' } else { '[' };
+        match trimmed.rfind(open) {
+            Some(start) if start > 0 => trimmed = trimmed[..start].trim_end(),
+            _ => break,
+        }
+    }
+    let words: Vec<String> = trimmed
+        .split_whitespace()
+        .map(|w| {
+            w.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    let words = match words.split_first() {
+        Some((first, rest)) if first == "the" && !rest.is_empty() => rest,
+        _ => &words[..],
+    };
+    words.concat()
+}
+
+/// Matches collection releases against a snapshot of the local albums.
+pub struct AlbumMatcher {
+    /// `(group_key, normalized artists, normalized title, year)`.
+    albums: Vec<(String, Vec<String>, String, Option<u32>)>,
+}
+
+impl AlbumMatcher {
+    pub fn new(albums: &[LocalAlbum]) -> Self {
+        let albums = albums
+            .iter()
+            .map(|album| {
+                let mut artists = vec![normalize(&album.artist)];
+                artists.extend(album.all_artists.split(',').map(normalize));
+                artists.retain(|a| !a.is_empty());
+                (
+                    album.id.clone(),
+                    artists,
+                    normalize(&album.title),
+                    album.year,
+                )
+            })
+            .collect();
+        Self { albums }
+    }
+
+    /// Group key of the local album that is `artist` - `title`, preferring
+    /// the one from `year` when several match.
+    pub fn match_release(&self, artist: &str, title: &str, year: Option<u32>) -> Option<&str> {
+        let artist = normalize(artist);
+        let title = normalize(title);
+        if artist.is_empty() || title.is_empty() {
+            return None;
+        }
+        let mut candidates = self
+            .albums
+            .iter()
+            .filter(|(_, artists, t, _)| *t == title && artists.contains(&artist));
+        let first = candidates.next()?;
+        let best = std::iter::once(first)
+            .chain(candidates)
+            .find(|(_, _, _, y)| year.is_some() && *y == year)
+            .unwrap_or(first);
+        Some(best.0.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn album(key: &str, artist: &str, title: &str, year: Option<u32>) -> LocalAlbum {
+        LocalAlbum {
+            id: key.to_string(),
+            title: title.to_string(),
+            artist: artist.to_string(),
+            all_artists: artist.to_string(),
+            year,
+            catalog_number: None,
+            artwork_path: None,
+            track_count: 10,
+            total_duration_secs: 2400,
+            format: crate::AudioFormat::Flac,
+            bit_depth: Some(16),
+            sample_rate: 44100.0,
+            directory_path: key.to_string(),
+            source_folders: None,
+            source: "user".to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_through_discogs_naming() {
+        let matcher = AlbumMatcher::new(&[
+            album("/m/beatles/abbey", "The Beatles", "Abbey Road", Some(1969)),
+            album("/m/nirvana/nev91", "Nirvana", "Nevermind", Some(1991)),
+            album(
+                "/m/nirvana/nev11",
+                "Nirvana",
+                "Nevermind [Deluxe]",
+                Some(2011),
+            ),
+        ]);
+        assert_eq!(
+            matcher.match_release("The Beatles", "Abbey Road (Remastered)", Some(2019)),
+            Some("/m/beatles/abbey")
+        );
+        assert_eq!(
+            matcher.match_release("Nirvana (2)", "Nevermind", Some(2011)),
+            Some("/m/nirvana/nev11")
+        );
+        assert_eq!(
+            matcher.match_release("Nirvana", "Nevermind", None),
+            Some("/m/nirvana/nev91")
+        );
+        assert_eq!(matcher.match_release("Nirvana", "In Utero", None), None);
+    }
+
+    #[test]
+    fn sync_replaces_previous_links() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+        let link = |key: &str, id: u64| CollectionLink {
+            album_group_key: key.to_string(),
+            release_id: id,
+            format: Some("Vinyl".to_string()),
+        };
+        replace_links(&mut conn, &[link("a", 1), link("b", 2)]).unwrap();
+        assert_eq!(replace_links(&mut conn, &[link("b", 3)]).unwrap(), 1);
+
+        assert_eq!(link_for_album(&conn, "a").unwrap(), None);
+        assert_eq!(link_for_album(&conn, "b").unwrap(), Some(link("b", 3)));
+    }
+}
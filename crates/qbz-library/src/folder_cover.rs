@@ -0,0 +1,230 @@
+//! `folder.jpg` covers written from embedded artwork.
+//!
+//! File managers and most other players show an album directory's
+//! `folder.jpg`, which many rips only carry inside the audio files. These
+//! helpers pick the largest embedded picture among an album's tracks and
+//! save it, fitted within 600x600, as `folder.jpg` next to them. A directory
+//! that already has one is left alone.
+//!
+//! Everything here reads and writes files only: callers collect the track
+//! paths from the database first, so no connection is held while the
+//! artwork is decoded.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use image::imageops::FilterType;
+use image::{ImageFormat, ImageReader};
+
+use crate::{LibraryError, MetadataExtractor};
+
+/// File name the cover is written under.
+pub const FOLDER_COVER_NAME: &str = "folder.jpg";
+
+/// Largest width/height of a written cover. Smaller artwork keeps its size.
+const FOLDER_COVER_SIZE: u32 = 600;
+
+/// Pixel count of an encoded image, read from its header only.
+fn pixel_count(bytes: &[u8]) -> Option<u64> {
+    let (width, height) = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .ok()?
+        .into_dimensions()
+        .ok()?;
+    Some(width as u64 * height as u64)
+}
+
+/// The highest-resolution embedded picture among `track_paths`.
+fn largest_embedded_artwork(track_paths: &[&Path]) -> Option<Vec<u8>> {
+    track_paths
+        .iter()
+        .filter_map(|path| MetadataExtractor::extract_artwork_bytes(path))
+        .filter_map(|bytes| Some((pixel_count(&bytes)?, bytes)))
+        .max_by_key(|(pixels, _)| *pixels)
+        .map(|(_, bytes)| bytes)
+}
+
+/// Decode `artwork`, fit it within [`FOLDER_COVER_SIZE`] and save it as
+/// JPEG to `target`.
+fn save_cover(artwork: &[u8], target: &Path) -> Result<(), LibraryError> {
+    let img = ImageReader::new(Cursor::new(artwork))
+        .with_guessed_format()
+        .map_err(|e| LibraryError::Other(format!("Failed to guess image format: {}", e)))?
+        .decode()
+        .map_err(|e| LibraryError::Other(format!("Failed to decode image: {}", e)))?;
+    let img = if img.width() > FOLDER_COVER_SIZE || img.height() > FOLDER_COVER_SIZE {
+        img.resize(FOLDER_COVER_SIZE, FOLDER_COVER_SIZE, FilterType::Lanczos3)
+    } else {
+        img
+    };
+    // JPEG has no alpha channel.
+    img.to_rgb8()
+        .save_with_format(target, ImageFormat::Jpeg)
+        .map_err(|e| LibraryError::Other(format!("Failed to save {}: {}", target.display(), e)))
+}
+
+/// Whether `dir` already holds a `folder.jpg`, in any letter case
+/// (`Folder.jpg`, `FOLDER.JPG`, ...), so case-sensitive file systems don't
+/// get a second copy next to it.
+fn has_folder_cover(dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.eq_ignore_ascii_case(FOLDER_COVER_NAME))
+    })
+}
+
+/// Directories of `track_paths` without a `folder.jpg`, each with its
+/// tracks (CUE tracks sharing a file count once).
+fn dirs_missing_cover(track_paths: &[PathBuf]) -> BTreeMap<PathBuf, Vec<&Path>> {
+    let mut seen = HashSet::new();
+    let mut dirs: BTreeMap<PathBuf, Vec<&Path>> = BTreeMap::new();
+    for path in track_paths {
+        if !seen.insert(path) {
+            continue;
+        }
+        let Some(dir) = path.parent().filter(|d| d.is_dir()) else {
+            continue;
+        };
+        if has_folder_cover(dir) {
+            continue;
+        }
+        dirs.entry(dir.to_path_buf()).or_default().push(path);
+    }
+    dirs
+}
+
+/// Write `folder.jpg` for one album: the largest embedded picture among
+/// all its tracks goes into every track directory (disc subfolders
+/// included) that has none. Returns the number of files written.
+pub fn write_album_folder_cover(track_paths: &[PathBuf]) -> Result<u32, LibraryError> {
+    let dirs = dirs_missing_cover(track_paths);
+    if dirs.is_empty() {
+        return Ok(0);
+    }
+    let tracks: Vec<&Path> = dirs.values().flatten().copied().collect();
+    let Some(artwork) = largest_embedded_artwork(&tracks) else {
+        return Ok(0);
+    };
+    let mut written = 0;
+    for dir in dirs.keys() {
+        save_cover(&artwork, &dir.join(FOLDER_COVER_NAME))?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Write `folder.jpg` in every directory of `track_paths` that lacks one,
+/// each from the largest picture embedded in that directory's tracks. A
+/// directory that fails is logged and skipped. Returns the number of files
+/// written.
+pub fn write_missing_folder_covers(track_paths: &[PathBuf]) -> u32 {
+    let mut written = 0;
+    for (dir, tracks) in dirs_missing_cover(track_paths) {
+        let Some(artwork) = largest_embedded_artwork(&tracks) else {
+            continue;
+        };
+        match save_cover(&artwork, &dir.join(FOLDER_COVER_NAME)) {
+            Ok(()) => written += 1,
+            Err(e) => log::warn!("folder cover for {}: {}", dir.display(), e),
+        }
+    }
+    written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::minimal_flac;
+    use lofty::config::WriteOptions;
+    use lofty::picture::{MimeType, Picture, PictureType};
+    use lofty::tag::{Tag, TagExt, TagType};
+
+    fn flac_with_cover(path: &Path, size: u32) {
+        std::fs::write(path, minimal_flac()).unwrap();
+        let mut png = Vec::new();
+        image::RgbImage::from_pixel(size, size, image::Rgb([200, 40, 40]))
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        let mut tag = Tag::new(TagType::VorbisComments);
+        tag.push_picture(Picture::new_unchecked(
+            PictureType::CoverFront,
+            Some(MimeType::Png),
+            None,
+            png,
+        ));
+        tag.save_to_path(path, WriteOptions::default()).unwrap();
+    }
+
+    #[test]
+    fn album_cover_uses_the_largest_picture_in_every_disc_folder() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (cd1, cd2, done) = (
+            tmp.path().join("CD1"),
+            tmp.path().join("CD2"),
+            tmp.path().join("Done"),
+        );
+        for dir in [&cd1, &cd2, &done] {
+            std::fs::create_dir(dir).unwrap();
+        }
+        let paths = vec![
+            cd1.join("01.flac"),
+            cd1.join("02.flac"),
+            cd2.join("01.flac"),
+            done.join("01.flac"),
+        ];
+        flac_with_cover(&paths[0], 300);
+        flac_with_cover(&paths[1], 1000);
+        std::fs::write(&paths[2], minimal_flac()).unwrap();
+        flac_with_cover(&paths[3], 300);
+        std::fs::write(done.join(FOLDER_COVER_NAME), b"keep").unwrap();
+
+        assert_eq!(write_album_folder_cover(&paths).unwrap(), 2);
+        for dir in [&cd1, &cd2] {
+            let cover = image::open(dir.join(FOLDER_COVER_NAME)).unwrap();
+            assert_eq!((cover.width(), cover.height()), (600, 600));
+        }
+        assert_eq!(
+            std::fs::read(done.join(FOLDER_COVER_NAME)).unwrap(),
+            b"keep"
+        );
+        assert_eq!(write_album_folder_cover(&paths).unwrap(), 0);
+    }
+
+    #[test]
+    fn missing_covers_come_from_each_folders_own_tracks() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let (art, bare) = (tmp.path().join("Art"), tmp.path().join("Bare"));
+        std::fs::create_dir(&art).unwrap();
+        std::fs::create_dir(&bare).unwrap();
+        let paths = vec![art.join("01.flac"), bare.join("01.flac")];
+        flac_with_cover(&paths[0], 200);
+        std::fs::write(&paths[1], minimal_flac()).unwrap();
+
+        assert_eq!(write_missing_folder_covers(&paths), 1);
+        let cover = image::open(art.join(FOLDER_COVER_NAME)).unwrap();
+        assert_eq!((cover.width(), cover.height()), (200, 200));
+        assert!(!bare.join(FOLDER_COVER_NAME).exists());
+    }
+
+    #[test]
+    fn an_existing_cover_in_any_case_is_kept() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("01.flac");
+        flac_with_cover(&path, 200);
+        std::fs::write(tmp.path().join("Folder.JPG"), b"keep").unwrap();
+
+        assert_eq!(write_missing_folder_covers(&[path]), 0);
+        let names: Vec<_> = std::fs::read_dir(tmp.path())
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.file_name())
+            .collect();
+        assert_eq!(names.len(), 2);
+    }
+}
@@ -31,37 +31,49 @@
 pub mod album_grouping;
 mod cue_parser;
 mod database;
+pub mod discogs_collection;
 pub mod ephemeral;
+pub mod folder_cover;
 pub mod local_playlists;
 pub mod qobuz_playlist_snapshot;
 mod errors;
 mod metadata;
 mod models;
 mod mount_info;
+mod mount_monitor;
 mod scan;
 mod scanner;
 mod tag_writer;
 mod tag_sidecar;
+#[cfg(test)]
+mod test_fixtures;
 mod thumbnails;
 
 // Re-exports
 pub use cue_parser::{cue_to_tracks, CueParser, CueSheet, CueTime, CueTrack};
 pub use database::{
-    AlbumTrackUpdate, LibraryDatabase, LibraryFolder, LibraryStats, LocalContentStatus,
-    PlaylistFolder, PlaylistSettings, PlaylistStats, TrackMetadataUpdateFull,
+    AlbumTrackUpdate, DuplicateGroup, LibraryDatabase, LibraryFolder, LibraryStats,
+    LocalContentStatus, MergeStrategy, MergeSummary, PlaylistFolder, PlaylistSettings,
+    PlaylistStats, SplitAlbumGroup, SplitAlbumPart, TrackMetadataUpdateFull,
 };
 pub use errors::LibraryError;
 pub use metadata::MetadataExtractor;
 pub use models::*;
 pub use mount_info::{is_network_path, network_fs_label};
-pub use scan::{scan_with_progress, ScanEvent};
+pub use mount_monitor::{
+    check_mount_accessibility, MountEvent, MountMonitor, WatchHandle, MOUNT_POLL_INTERVAL,
+};
+pub use scan::{expand_embedded_cue, register_scan_folder, scan_with_progress, ScanEvent};
 pub use tag_writer::{
-    compute_track_artist_match, write_album_tags_to_files, AlbumTagWrite, TrackTagWrite,
+    album_replaygain, compute_track_artist_match, parse_replaygain_value,
+    write_album_tags_to_files, write_replaygain_to_file, AlbumTagWrite, GainImportSummary,
+    ReplayGainTags, ReplayGainWrite, TrackTagWrite, WriteGainSummary, REPLAYGAIN_REFERENCE_LUFS,
 };
 pub use scanner::{LibraryScanner, ScanResult};
 pub use thumbnails::{
-    clear_thumbnails, generate_thumbnail, generate_thumbnail_from_bytes, get_cache_size,
-    get_or_generate_thumbnail, get_thumbnail_path, get_thumbnails_dir, thumbnail_exists,
+    cleanup_orphan_thumbnails, clear_thumbnails, generate_thumbnail, generate_thumbnail_from_bytes,
+    get_cache_size, get_or_generate_thumbnail, get_thumbnail_path, get_thumbnails_dir,
+    thumbnail_exists, ThumbnailCleanupSummary,
 };
 
 // Re-export database module for backwards compatibility
@@ -8,7 +8,10 @@ use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::thumbnails::{generate_thumbnail, generate_thumbnail_from_bytes};
-use crate::{AudioFormat, AudioProperties, LibraryError, LocalTrack};
+use crate::{
+    parse_replaygain_value, AudioFormat, AudioProperties, CueParser, CueSheet, LibraryError,
+    LocalTrack, ReplayGainTags,
+};
 
 /// Metadata extractor using lofty
 pub struct MetadataExtractor;
@@ -111,7 +114,7 @@ impl MetadataExtractor {
         trimmed.to_string()
     }
 
-    fn strip_disc_suffix(title: &str) -> String {
+    pub(crate) fn strip_disc_suffix(title: &str) -> String {
         let trimmed = title.trim();
 
         for (open, close) in [("(", ")"), ("[", "]")] {
@@ -239,7 +242,7 @@ impl MetadataExtractor {
         !has_extra_words
     }
 
-    fn disc_number_from_name(name: &str) -> Option<u32> {
+    pub(crate) fn disc_number_from_name(name: &str) -> Option<u32> {
         let lower = name.to_lowercase();
         let tokens: Vec<&str> = lower
             .split(|c: char| !c.is_ascii_alphanumeric())
@@ -762,6 +765,113 @@ impl MetadataExtractor {
         })
     }
 
+    /// Read the file's ReplayGain tags (any tag type, primary first).
+    pub fn extract_replaygain(file_path: &Path) -> Result<ReplayGainTags, LibraryError> {
+        let tagged_file = Probe::open(file_path)
+            .map_err(|e| LibraryError::Metadata(format!("Failed to open file: {}", e)))?
+            .read()
+            .map_err(|e| LibraryError::Metadata(format!("Failed to read file: {}", e)))?;
+        let value = |key: ItemKey| {
+            Self::string_across_tags(&tagged_file, &key)
+                .as_deref()
+                .and_then(parse_replaygain_value)
+        };
+
+        Ok(ReplayGainTags {
+            track_gain_db: value(ItemKey::ReplayGainTrackGain),
+            track_peak: value(ItemKey::ReplayGainTrackPeak),
+            album_gain_db: value(ItemKey::ReplayGainAlbumGain),
+            album_peak: value(ItemKey::ReplayGainAlbumPeak),
+        })
+    }
+
+    /// The CUE sheet embedded in a FLAC file, pointed at the file itself:
+    /// the CUESHEET comment when there is one, else the native CUESHEET
+    /// metadata block (titled from the file's album tags). `None` for other
+    /// formats and for FLAC files without either; a sheet that doesn't parse
+    /// is an error.
+    pub fn extract_embedded_cue(file_path: &Path) -> Result<Option<CueSheet>, LibraryError> {
+        use lofty::config::ParseOptions;
+        use lofty::flac::FlacFile;
+
+        if Self::detect_format(file_path) != AudioFormat::Flac {
+            return Ok(None);
+        }
+        let mut file = fs::File::open(file_path)?;
+        let flac = FlacFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| LibraryError::Metadata(format!("Failed to read file: {}", e)))?;
+        let comments = flac.vorbis_comments();
+        if let Some(content) = comments
+            .and_then(|comments| comments.get("CUESHEET"))
+            .filter(|content| !content.trim().is_empty())
+        {
+            return CueParser::parse_embedded(content, file_path).map(Some);
+        }
+
+        let Some((block, sample_rate)) = Self::flac_cuesheet_block(file_path)? else {
+            return Ok(None);
+        };
+        let mut sheet = CueParser::parse_flac_cuesheet(&block, sample_rate, file_path)?;
+        let tag =
+            |key: &str| Self::normalize_field(comments.and_then(|comments| comments.get(key)));
+        sheet.title = tag("ALBUM");
+        sheet.performer = tag("ALBUMARTIST").or_else(|| tag("ARTIST"));
+        Ok(Some(sheet))
+    }
+
+    /// Walk a FLAC file's metadata blocks for its CUESHEET block (type 5).
+    /// Returns the block body with the STREAMINFO sample rate its sample
+    /// offsets are counted in.
+    fn flac_cuesheet_block(file_path: &Path) -> Result<Option<(Vec<u8>, u32)>, LibraryError> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let mut file = fs::File::open(file_path)?;
+        let mut marker = [0u8; 4];
+        file.read_exact(&mut marker)?;
+        if &marker[..3] == b"ID3" {
+            // Skip a leading ID3v2 tag: 10-byte header, syncsafe size.
+            let mut rest = [0u8; 6];
+            file.read_exact(&mut rest)?;
+            let size = rest[2..]
+                .iter()
+                .fold(0u64, |acc, b| (acc << 7) | (*b as u64 & 0x7f));
+            file.seek(SeekFrom::Current(size as i64))?;
+            file.read_exact(&mut marker)?;
+        }
+        if &marker != b"fLaC" {
+            return Ok(None);
+        }
+
+        let mut sample_rate = 0;
+        loop {
+            let mut header = [0u8; 4];
+            file.read_exact(&mut header)?;
+            let last = header[0] & 0x80 != 0;
+            let kind = header[0] & 0x7f;
+            let len = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+            match kind {
+                0 | 5 => {
+                    let mut body = vec![0u8; len];
+                    file.read_exact(&mut body)?;
+                    if kind == 5 {
+                        return Ok(Some((body, sample_rate)));
+                    }
+                    // 20-bit sample rate after the block/frame size fields.
+                    if let Some(b) = body.get(10..13) {
+                        sample_rate =
+                            ((b[0] as u32) << 12) | ((b[1] as u32) << 4) | ((b[2] as u32) >> 4);
+                    }
+                }
+                _ => {
+                    file.seek(SeekFrom::Current(len as i64))?;
+                }
+            }
+            if last {
+                return Ok(None);
+            }
+        }
+    }
+
     /// Determine AudioFormat from file extension
     pub fn detect_format(path: &Path) -> AudioFormat {
         match path
@@ -783,17 +893,23 @@ impl MetadataExtractor {
 
     /// Extract and save artwork as thumbnail to cache directory
     pub fn extract_artwork(file_path: &Path, _cache_dir: &Path) -> Option<String> {
+        let artwork = Self::extract_artwork_bytes(file_path)?;
+        let cache_key = file_path.to_string_lossy().to_string();
+
+        match generate_thumbnail_from_bytes(&artwork, &cache_key) {
+            Ok(thumbnail_path) => Some(thumbnail_path.to_string_lossy().to_string()),
+            Err(e) => {
+                log::warn!("Failed to generate thumbnail for {:?}: {}", file_path, e);
+                None
+            }
+        }
+    }
+
+    /// The first embedded picture of a file, as stored (not resized).
+    pub fn extract_artwork_bytes(file_path: &Path) -> Option<Vec<u8>> {
         if qbz_dsd::is_dsd_path(file_path) {
             let demux = qbz_dsd::open_dsd(file_path).ok()?;
-            let art = demux.info().tags.artwork.clone()?;
-            let cache_key = file_path.to_string_lossy().to_string();
-            return match generate_thumbnail_from_bytes(&art, &cache_key) {
-                Ok(thumbnail_path) => Some(thumbnail_path.to_string_lossy().to_string()),
-                Err(e) => {
-                    log::warn!("Failed to generate DSD thumbnail for {:?}: {}", file_path, e);
-                    None
-                }
-            };
+            return demux.info().tags.artwork.clone();
         }
 
         let tagged_file = Probe::open(file_path).ok()?.read().ok()?;
@@ -801,17 +917,7 @@ impl MetadataExtractor {
             .primary_tag()
             .or_else(|| tagged_file.first_tag())?;
 
-        let picture = tag.pictures().first()?;
-
-        let cache_key = file_path.to_string_lossy().to_string();
-
-        match generate_thumbnail_from_bytes(picture.data(), &cache_key) {
-            Ok(thumbnail_path) => Some(thumbnail_path.to_string_lossy().to_string()),
-            Err(e) => {
-                log::warn!("Failed to generate thumbnail for {:?}: {}", file_path, e);
-                None
-            }
-        }
+        tag.pictures().first().map(|picture| picture.data().to_vec())
     }
 
     /// Generate thumbnail from an existing artwork file
@@ -295,3 +295,23 @@ pub struct ArtistImageInfo {
     pub custom_image_path: Option<String>,
     pub canonical_name: Option<String>,
 }
+
+/// How long a cached artist biography is served before it is re-fetched.
+pub const ARTIST_BIO_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// A cached artist biography. `bio` is `None` when the lookup found nothing,
+/// so misses are cached too.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtistBioInfo {
+    pub artist_name: String,
+    pub mbid: Option<String>,
+    pub bio: Option<String>,
+    pub fetched_at: i64,
+}
+
+impl ArtistBioInfo {
+    /// Whether the entry is younger than [`ARTIST_BIO_TTL_SECS`] at `now`.
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now - self.fetched_at < ARTIST_BIO_TTL_SECS
+    }
+}
@@ -0,0 +1,204 @@
+//! Accessibility monitoring for network-mounted library folders.
+//!
+//! [`mount_info`](crate::mount_info) says whether a folder lives on a network
+//! filesystem; this watches such folders while the app runs. A background
+//! thread probes every watched path each poll interval and reports
+//! transitions only — [`MountEvent::Lost`] when a reachable path stops
+//! answering, [`MountEvent::Restored`] when it comes back. The first probe runs
+//! immediately against the state the caller already knows, so a share that
+//! went down (or came back) while no watch ran is reported straight away.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How often [`MountMonitor::watch`] probes each path.
+pub const MOUNT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a single `read_dir` probe may take. A stale NFS/SMB handle
+/// blocks instead of failing, so a probe that doesn't answer in time counts
+/// as inaccessible.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(6);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MountEvent {
+    Lost(PathBuf),
+    Restored(PathBuf),
+}
+
+/// Whether `path` exists and its directory listing can be read within
+/// [`PROBE_TIMEOUT`]. The probe runs on a throwaway thread so a hung mount
+/// can't wedge the caller (the thread is left to finish on its own).
+pub fn check_mount_accessibility(path: &Path) -> bool {
+    probe_unless_in_flight(path, &Arc::new(AtomicBool::new(false)))
+}
+
+/// [`check_mount_accessibility`] guarded by the path's `in_flight` flag: while
+/// an earlier probe is still blocked on the mount, no new thread is spawned
+/// and the path counts as inaccessible. Without this a stale mount collects
+/// one stuck thread per poll.
+fn probe_unless_in_flight(path: &Path, in_flight: &Arc<AtomicBool>) -> bool {
+    if in_flight.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    let (tx, rx) = mpsc::channel();
+    let probe = path.to_path_buf();
+    let done = in_flight.clone();
+    std::thread::spawn(move || {
+        let ok = probe.exists() && std::fs::read_dir(&probe).is_ok();
+        done.store(false, Ordering::SeqCst);
+        let _ = tx.send(ok);
+    });
+    rx.recv_timeout(PROBE_TIMEOUT).unwrap_or(false)
+}
+
+/// The event for a path whose accessibility went from `was` to `now`.
+fn transition(path: &Path, was: bool, now: bool) -> Option<MountEvent> {
+    match (was, now) {
+        (true, false) => Some(MountEvent::Lost(path.to_path_buf())),
+        (false, true) => Some(MountEvent::Restored(path.to_path_buf())),
+        _ => None,
+    }
+}
+
+pub struct MountMonitor;
+
+impl MountMonitor {
+    /// Watch `paths` on a background thread, polling every
+    /// [`MOUNT_POLL_INTERVAL`]. Each path starts out in the given state
+    /// (`true` = accessible), so only changes from what the caller knows are
+    /// reported. The thread exits when the handle is stopped or dropped, or
+    /// when the receiver hangs up.
+    pub fn watch(paths: Vec<(PathBuf, bool)>, tx: Sender<MountEvent>) -> WatchHandle {
+        Self::watch_with(paths, MOUNT_POLL_INTERVAL, tx, probe_unless_in_flight)
+    }
+
+    fn watch_with(
+        paths: Vec<(PathBuf, bool)>,
+        interval: Duration,
+        tx: Sender<MountEvent>,
+        probe: fn(&Path, &Arc<AtomicBool>) -> bool,
+    ) -> WatchHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+        let thread = std::thread::spawn(move || {
+            let mut accessible: HashMap<PathBuf, bool> = paths.iter().cloned().collect();
+            let in_flight: HashMap<PathBuf, Arc<AtomicBool>> = paths
+                .iter()
+                .map(|(p, _)| (p.clone(), Arc::new(AtomicBool::new(false))))
+                .collect();
+            while !stop_flag.load(Ordering::SeqCst) {
+                for (path, flag) in &in_flight {
+                    let now = probe(path, flag);
+                    let was = accessible.insert(path.clone(), now).unwrap_or(true);
+                    if let Some(event) = transition(path, was, now) {
+                        log::info!("[MountMonitor] {:?}", event);
+                        if tx.send(event).is_err() {
+                            return;
+                        }
+                    }
+                }
+                // Sleep in short slices so stop() takes effect promptly.
+                let mut slept = Duration::ZERO;
+                while slept < interval && !stop_flag.load(Ordering::SeqCst) {
+                    let slice = Duration::from_millis(200).min(interval - slept);
+                    std::thread::sleep(slice);
+                    slept += slice;
+                }
+            }
+        });
+        WatchHandle {
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Keeps a [`MountMonitor`] thread alive; stopping or dropping it ends the
+/// watch.
+pub struct WatchHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WatchHandle {
+    /// Stop watching. Returns without waiting for an in-flight probe.
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        self.stop();
+        // Detach rather than join: a probe stuck on a dead mount would
+        // otherwise block whoever drops the handle.
+        drop(self.thread.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_transitions_are_reported() {
+        let path = Path::new("/mnt/nas");
+        assert_eq!(transition(path, true, true), None);
+        assert_eq!(transition(path, false, false), None);
+        assert_eq!(
+            transition(path, true, false),
+            Some(MountEvent::Lost(path.to_path_buf()))
+        );
+        assert_eq!(
+            transition(path, false, true),
+            Some(MountEvent::Restored(path.to_path_buf()))
+        );
+    }
+
+    #[test]
+    fn accessibility_follows_the_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(check_mount_accessibility(dir.path()));
+        assert!(!check_mount_accessibility(&dir.path().join("missing")));
+    }
+
+    #[test]
+    fn no_probe_starts_while_one_is_in_flight() {
+        let dir = tempfile::tempdir().unwrap();
+        let in_flight = Arc::new(AtomicBool::new(true));
+        assert!(!probe_unless_in_flight(dir.path(), &in_flight));
+        assert!(in_flight.load(Ordering::SeqCst));
+
+        in_flight.store(false, Ordering::SeqCst);
+        assert!(probe_unless_in_flight(dir.path(), &in_flight));
+        assert!(!in_flight.load(Ordering::SeqCst));
+    }
+
+    static ONLINE: AtomicBool = AtomicBool::new(false);
+
+    fn fake_probe(_: &Path, _: &Arc<AtomicBool>) -> bool {
+        ONLINE.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn watch_reports_lost_then_restored() {
+        let (tx, rx) = mpsc::channel();
+        let path = PathBuf::from("/mnt/nas/music");
+        let handle = MountMonitor::watch_with(
+            vec![(path.clone(), true)],
+            Duration::from_millis(10),
+            tx,
+            fake_probe,
+        );
+        let wait = Duration::from_secs(5);
+        assert_eq!(rx.recv_timeout(wait), Ok(MountEvent::Lost(path.clone())));
+        ONLINE.store(true, Ordering::SeqCst);
+        assert_eq!(rx.recv_timeout(wait), Ok(MountEvent::Restored(path)));
+        handle.stop();
+    }
+}
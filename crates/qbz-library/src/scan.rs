@@ -4,11 +4,12 @@
 //! `v2_library_scan` / `v2_library_scan_folder` into the core crate so any
 //! frontend (Slint, TUI) can drive it. The Tauri side polled an
 //! `Arc<Mutex<ScanProgress>>` over the IPC boundary; in-process callers get
-//! the same information pushed through `on_event` and check `cancel` at every
-//! file boundary. The per-file logic (CUE-first, sidecar override, embedded →
+//! the same information pushed through `on_event` and check `cancel` (and
+//! `paused`) at every file boundary. The per-file logic (CUE-first, sidecar
+//! override, embedded →
 //! folder artwork, insert, missing-file cleanup) is replicated exactly.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -28,6 +29,11 @@ pub enum ScanEvent {
     FileStarted { path: String },
     /// A file finished (processed/total advanced).
     FileDone { processed: u32, total: u32 },
+    /// The scan is holding at a file boundary because every folder it has
+    /// left is paused (e.g. their network mounts went offline).
+    Paused,
+    /// A paused folder came back; the scan carries on.
+    Resumed,
     /// Entering the missing-file cleanup phase.
     Cleanup,
     /// Terminal: Complete / Cancelled / Error, with any per-file errors.
@@ -41,6 +47,70 @@ fn normalize_path(path: &Path) -> PathBuf {
     std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
 }
 
+/// One target folder's share of a scan. Resumable at any file boundary, so
+/// a folder whose mount is lost can be set aside while the others go on.
+struct FolderWork<'a> {
+    folder: &'a crate::LibraryFolder,
+    /// Filled on the folder's first turn.
+    listing: Option<FolderListing>,
+    /// Files already handled: CUE sheets first, then audio files.
+    next: usize,
+}
+
+struct FolderListing {
+    root: PathBuf,
+    cue_files: Vec<PathBuf>,
+    audio_files: Vec<PathBuf>,
+    skip_paths: HashSet<String>,
+    folder_artwork: HashMap<PathBuf, Option<String>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Gate {
+    /// Process the next file of this folder.
+    Go,
+    /// This folder is paused; move on to another and come back later.
+    Defer,
+    Cancel,
+}
+
+/// File-boundary gate for the folder at `root`, with `queued` the folders
+/// still waiting their turn. A paused folder is set aside while a queued
+/// one can go on; when none can, the scan holds (announcing the pause and
+/// the resume) until one of them is back. Cancelling also releases a held
+/// scan.
+fn gate(
+    root: &str,
+    queued: &[String],
+    cancel: &AtomicBool,
+    paused: &(dyn Fn(&str) -> bool + Send + Sync),
+    on_event: &(dyn Fn(ScanEvent) + Send + Sync),
+) -> Gate {
+    let all_paused = || paused(root) && queued.iter().all(|q| paused(q));
+    if cancel.load(Ordering::Relaxed) {
+        return Gate::Cancel;
+    }
+    if !paused(root) {
+        return Gate::Go;
+    }
+    if !all_paused() {
+        return Gate::Defer;
+    }
+    on_event(ScanEvent::Paused);
+    while all_paused() && !cancel.load(Ordering::Relaxed) {
+        std::thread::sleep(std::time::Duration::from_millis(250));
+    }
+    if cancel.load(Ordering::Relaxed) {
+        return Gate::Cancel;
+    }
+    on_event(ScanEvent::Resumed);
+    if paused(root) {
+        Gate::Defer
+    } else {
+        Gate::Go
+    }
+}
+
 fn now_secs() -> i64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -126,13 +196,79 @@ fn process_cue_file(
     Ok(())
 }
 
+/// Replace a whole-file FLAC track with the virtual tracks of its embedded
+/// CUE sheet (the `v2_library_scan_embedded_cue` port). The virtual tracks
+/// keep the row's year, genre, catalog number and artwork, and seek by their
+/// CUE start times like sidecar-CUE tracks. Returns how many were added:
+/// 0 when the file has no embedded sheet or the row is already a CUE track.
+pub fn expand_embedded_cue(db: &LibraryDatabase, track_id: i64) -> Result<usize, LibraryError> {
+    let track = db
+        .get_track(track_id)?
+        .ok_or_else(|| LibraryError::Other(format!("Track {} not found", track_id)))?;
+    if track.cue_file_path.is_some() || track.cue_start_secs.is_some() {
+        return Ok(0);
+    }
+    let audio_path = normalize_path(Path::new(&track.file_path));
+    let Some(cue) = MetadataExtractor::extract_embedded_cue(&audio_path)? else {
+        return Ok(0);
+    };
+
+    let properties = MetadataExtractor::extract_properties(&audio_path)?;
+    let format = MetadataExtractor::detect_format(&audio_path);
+    let mut tracks = cue_to_tracks(&cue, properties.duration_secs, format, &properties);
+    for t in tracks.iter_mut() {
+        t.year = track.year;
+        t.genre = track.genre.clone();
+        t.catalog_number = track.catalog_number.clone();
+        t.artwork_path = track.artwork_path.clone();
+    }
+
+    db.replace_track(track_id, &tracks)?;
+    log::info!(
+        "Expanded embedded CUE of {} into {} tracks",
+        audio_path.display(),
+        tracks.len()
+    );
+    Ok(tracks.len())
+}
+
+/// Register `path` as a scan target and return its folder id, ready for
+/// `scan_with_progress(Some(&[id]))`. `permanent = true` adds a regular
+/// library folder (network type auto-detected); `false` adds a transient
+/// "Network" folder for a one-time share scan, dropped from the index (not
+/// from disk) once a later scan finds the share unreachable.
+pub fn register_scan_folder(
+    db: &LibraryDatabase,
+    path: &str,
+    permanent: bool,
+) -> Result<i64, LibraryError> {
+    let p = Path::new(path);
+    if std::fs::read_dir(p).is_err() {
+        return Err(LibraryError::Other(format!("{} is not accessible", path)));
+    }
+    let is_net = crate::mount_info::is_network_path(p);
+    let fs = if is_net {
+        crate::mount_info::network_fs_label(p)
+    } else {
+        None
+    };
+    if permanent {
+        db.add_folder_with_network_info(path, is_net, fs.as_deref())
+    } else {
+        db.add_transient_folder(path, fs.as_deref())
+    }
+}
+
 /// Scan the library (or a single folder set) with progress + cancellation.
 ///
 /// `folder_ids = None` scans every ENABLED folder (full scan); `Some(&[id])`
 /// scans only those enabled folders (single/per-folder parity). `artwork_cache`
 /// is the cache dir for extracted/copied covers. `cancel` is checked at every
 /// file boundary; on cancel the scan returns early with `Finished{Cancelled}`
-/// and does NOT run cleanup (Tauri parity). `on_event` receives each step.
+/// and does NOT run cleanup (Tauri parity). `paused(path)` says whether a
+/// folder (by its configured path) is unreachable right now: its files are
+/// set aside at the next file boundary and picked up again once it is back,
+/// while the other folders carry on. `on_event` receives each step.
 ///
 /// Improvements over the Tauri loop (both verified against source):
 /// - the full scan updates each folder's `last_scan` on success (Tauri only
@@ -144,6 +280,7 @@ pub fn scan_with_progress(
     folder_ids: Option<&[i64]>,
     artwork_cache: &Path,
     cancel: &AtomicBool,
+    paused: &(dyn Fn(&str) -> bool + Send + Sync),
     on_event: &(dyn Fn(ScanEvent) + Send + Sync),
 ) -> Result<(), LibraryError> {
     let all = db.get_folders_with_metadata()?;
@@ -191,74 +328,125 @@ pub fn scan_with_progress(
     let mut total: u32 = 0;
     let mut processed: u32 = 0;
 
-    for folder in &targets {
-        // The folder's own normalized path, for the untagged-artist root
-        // clamp (an album dir directly under THIS root must not inherit the
-        // root's name as the artist — spec §C).
-        let folder_root = normalize_path(Path::new(&folder.path));
-        let scan_result = match scanner.scan_directory(Path::new(&folder.path)) {
-            Ok(r) => r,
-            Err(e) => {
-                all_errors.push(ScanError {
-                    file_path: folder.path.clone(),
-                    error: e.to_string(),
-                });
-                continue;
-            }
-        };
+    let cancelled = |errors: &mut Vec<ScanError>| {
+        on_event(ScanEvent::Finished {
+            status: ScanStatus::Cancelled,
+            errors: std::mem::take(errors),
+        });
+    };
+    let mut queue: VecDeque<FolderWork> = targets
+        .iter()
+        .map(|folder| FolderWork {
+            folder,
+            listing: None,
+            next: 0,
+        })
+        .collect();
 
-        total += (scan_result.audio_files.len() + scan_result.cue_files.len()) as u32;
-        on_event(ScanEvent::TotalsAdded { total });
+    while let Some(mut work) = queue.pop_front() {
+        let folder = work.folder;
+        let queued = |queue: &VecDeque<FolderWork>| {
+            queue
+                .iter()
+                .map(|w| w.folder.path.clone())
+                .collect::<Vec<_>>()
+        };
 
-        // CUE files first (one file -> several virtual tracks).
-        for cue_path in &scan_result.cue_files {
-            if cancel.load(Ordering::Relaxed) {
-                on_event(ScanEvent::Finished {
-                    status: ScanStatus::Cancelled,
-                    errors: std::mem::take(&mut all_errors),
-                });
-                return Ok(());
-            }
-            on_event(ScanEvent::FileStarted {
-                path: cue_path.to_string_lossy().to_string(),
-            });
-            if let Err(e) = process_cue_file(db, cue_path, artwork_cache) {
-                all_errors.push(ScanError {
-                    file_path: cue_path.to_string_lossy().to_string(),
-                    error: e,
-                });
+        if work.listing.is_none() {
+            match gate(&folder.path, &queued(&queue), cancel, paused, on_event) {
+                Gate::Go => {}
+                Gate::Defer => {
+                    queue.push_back(work);
+                    continue;
+                }
+                Gate::Cancel => {
+                    cancelled(&mut all_errors);
+                    return Ok(());
+                }
             }
-            processed += 1;
-            on_event(ScanEvent::FileDone { processed, total });
-        }
+            let scan_result = match scanner.scan_directory(Path::new(&folder.path)) {
+                Ok(r) => r,
+                Err(e) => {
+                    all_errors.push(ScanError {
+                        file_path: folder.path.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            total += (scan_result.audio_files.len() + scan_result.cue_files.len()) as u32;
+            on_event(ScanEvent::TotalsAdded { total });
 
-        // Audio files (skipping any referenced by a CUE sheet).
-        let cue_audio_files: HashSet<String> = scan_result
-            .cue_files
-            .iter()
-            .filter_map(|p| {
-                CueParser::parse(p).ok().map(|cue| {
-                    normalize_path(Path::new(&cue.audio_file))
-                        .to_string_lossy()
-                        .to_string()
+            // Audio files referenced by a CUE sheet, sidecar or
+            // already-expanded embedded one are skipped, as are files a
+            // duplicate merge removed from the library.
+            let mut skip_paths: HashSet<String> = scan_result
+                .cue_files
+                .iter()
+                .filter_map(|p| {
+                    CueParser::parse(p).ok().map(|cue| {
+                        normalize_path(Path::new(&cue.audio_file))
+                            .to_string_lossy()
+                            .to_string()
+                    })
                 })
-            })
-            .collect();
+                .collect();
+            skip_paths.extend(db.get_embedded_cue_paths().unwrap_or_default());
+            skip_paths.extend(db.get_ignored_track_paths().unwrap_or_default());
 
-        let mut folder_artwork_cache: HashMap<PathBuf, Option<String>> = HashMap::new();
+            work.listing = Some(FolderListing {
+                // The folder's own normalized path, for the untagged-artist
+                // root clamp (an album dir directly under THIS root must not
+                // inherit the root's name as the artist — spec §C).
+                root: normalize_path(Path::new(&folder.path)),
+                cue_files: scan_result.cue_files,
+                audio_files: scan_result.audio_files,
+                skip_paths,
+                folder_artwork: HashMap::new(),
+            });
+        }
+        let Some(listing) = work.listing.as_mut() else {
+            continue;
+        };
+
+        // CUE files first (one file -> several virtual tracks), then audio
+        // files; `work.next` is where a set-aside folder picks up again.
+        let mut deferred = false;
+        while work.next < listing.cue_files.len() + listing.audio_files.len() {
+            match gate(&folder.path, &queued(&queue), cancel, paused, on_event) {
+                Gate::Go => {}
+                Gate::Defer => {
+                    deferred = true;
+                    break;
+                }
+                Gate::Cancel => {
+                    cancelled(&mut all_errors);
+                    return Ok(());
+                }
+            }
+            let index = work.next;
+            work.next += 1;
 
-        for audio_path in &scan_result.audio_files {
-            if cancel.load(Ordering::Relaxed) {
-                on_event(ScanEvent::Finished {
-                    status: ScanStatus::Cancelled,
-                    errors: std::mem::take(&mut all_errors),
+            if let Some(cue_path) = listing.cue_files.get(index) {
+                on_event(ScanEvent::FileStarted {
+                    path: cue_path.to_string_lossy().to_string(),
                 });
-                return Ok(());
+                if let Err(e) = process_cue_file(db, cue_path, artwork_cache) {
+                    all_errors.push(ScanError {
+                        file_path: cue_path.to_string_lossy().to_string(),
+                        error: e,
+                    });
+                }
+                processed += 1;
+                on_event(ScanEvent::FileDone { processed, total });
+                continue;
             }
+            let audio_path = &listing.audio_files[index - listing.cue_files.len()];
 
             let canonical = normalize_path(audio_path);
             let path_str = canonical.to_string_lossy().to_string();
-            if cue_audio_files.contains(&path_str) {
+            if listing.skip_paths.contains(&path_str) {
                 processed += 1;
                 on_event(ScanEvent::FileDone { processed, total });
                 continue;
@@ -270,7 +458,7 @@ pub fn scan_with_progress(
 
             match MetadataExtractor::extract_with_roots(
                 &canonical,
-                std::slice::from_ref(&folder_root),
+                std::slice::from_ref(&listing.root),
             ) {
                 Ok(mut track) => {
                     apply_sidecar_override(&mut track, &mut sidecar_cache);
@@ -285,7 +473,8 @@ pub fn scan_with_progress(
                             .parent()
                             .map(|p| p.to_path_buf())
                             .unwrap_or_else(|| canonical.clone());
-                        let cached = folder_artwork_cache
+                        let cached = listing
+                            .folder_artwork
                             .entry(folder_dir)
                             .or_insert_with(|| {
                                 MetadataExtractor::find_folder_artwork(
@@ -319,6 +508,9 @@ pub fn scan_with_progress(
             processed += 1;
             on_event(ScanEvent::FileDone { processed, total });
         }
+        if deferred {
+            queue.push_back(work);
+        }
     }
 
     // Cleanup: remove tracks whose files no longer exist. Full scan checks the
@@ -330,6 +522,11 @@ pub fn scan_with_progress(
     // condition (e.g. a reboot where the share didn't auto-mount). Those
     // subtrees are skipped; they rehabilitate on the next scan after remount.
     on_event(ScanEvent::Cleanup);
+    // Transient (one-time network share) folders leave the index once the
+    // share is gone, instead of being held like a permanent network folder.
+    if let Err(e) = db.remove_unreachable_transient_folders() {
+        log::warn!("Transient folder cleanup failed: {}", e);
+    }
     let folder_prefix = |path: &str| {
         if path.ends_with('/') {
             path.to_string()
@@ -386,3 +583,47 @@ pub fn scan_with_progress(
     });
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn gate_sets_a_paused_folder_aside_while_another_can_run() {
+        let cancel = AtomicBool::new(false);
+        let paused = |root: &str| root == "/mnt/nas";
+        let events = Mutex::new(0);
+        let on_event = |_: ScanEvent| *events.lock().unwrap() += 1;
+        let queued = vec!["/home/music".to_string()];
+        assert_eq!(
+            gate("/home/music", &[], &cancel, &paused, &on_event),
+            Gate::Go
+        );
+        assert_eq!(
+            gate("/mnt/nas", &queued, &cancel, &paused, &on_event),
+            Gate::Defer
+        );
+        assert_eq!(*events.lock().unwrap(), 0);
+        cancel.store(true, Ordering::Relaxed);
+        assert_eq!(
+            gate("/home/music", &[], &cancel, &paused, &on_event),
+            Gate::Cancel
+        );
+    }
+
+    #[test]
+    fn gate_holds_until_the_last_paused_folder_returns() {
+        let cancel = AtomicBool::new(false);
+        let online = AtomicBool::new(false);
+        let paused = |_: &str| !online.load(Ordering::Relaxed);
+        let events = Mutex::new(Vec::new());
+        let on_event = |e: ScanEvent| {
+            events.lock().unwrap().push(matches!(e, ScanEvent::Paused));
+            online.store(true, Ordering::Relaxed);
+        };
+        assert_eq!(gate("/mnt/nas", &[], &cancel, &paused, &on_event), Gate::Go);
+        // Paused, then Resumed.
+        assert_eq!(*events.lock().unwrap(), vec![true, false]);
+    }
+}
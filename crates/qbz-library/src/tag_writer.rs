@@ -3,11 +3,19 @@
 //! frontends both call this so the lofty logic lives in one place. Progress is
 //! reported through an `on_progress` closure (no Tauri event bus); the caller
 //! orchestrates the DB update + sidecar removal.
+//!
+//! Also writes ReplayGain tags ([`write_replaygain_to_file`]) computed from
+//! QBZ's own loudness measurements, so other players get the same gain.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
 
 use crate::{LibraryError, LocalTrack};
 
+/// ReplayGain 2.0 reference loudness: gains are relative to -18 LUFS.
+pub const REPLAYGAIN_REFERENCE_LUFS: f32 = -18.0;
+
 /// Album-level fields written into every file's embedded tags. A `None`
 /// (or blank) field REMOVES that tag (direct write is destructive, unlike the
 /// override-only sidecar).
@@ -28,10 +36,12 @@ pub struct TrackTagWrite {
 }
 
 /// Write embedded tags to each file. Dedups by `file_path` keeping the FIRST
-/// occurrence (order preserved). `on_progress(current, total)` is called
-/// BEFORE each file write (1-based; total = deduped count). Partial-failure
-/// unsafe by design: returns `Err` on the first failing file with prior files
-/// already modified. Does NOT touch the DB or the sidecar.
+/// occurrence (order preserved). A multi-disc album also gets the disc total
+/// (the highest disc number), so players see "2/3" rather than a bare "2".
+/// `on_progress(current, total)` is called BEFORE each file write (1-based;
+/// total = deduped count). Partial-failure unsafe by design: returns `Err` on
+/// the first failing file with prior files already modified. Does NOT touch
+/// the DB or the sidecar.
 pub fn write_album_tags_to_files(
     album: &AlbumTagWrite,
     tracks: &[TrackTagWrite],
@@ -48,6 +58,11 @@ pub fn write_album_tags_to_files(
         .filter(|t| seen.insert(t.file_path.clone()))
         .collect();
     let total = unique.len();
+    let disc_total = unique
+        .iter()
+        .filter_map(|t| t.disc_number)
+        .max()
+        .filter(|n| *n > 1);
 
     for (i, track) in unique.iter().enumerate() {
         on_progress(i + 1, total);
@@ -85,9 +100,7 @@ pub fn write_album_tags_to_files(
             if let Some(no) = track.track_number {
                 tag.set_track(no);
             }
-            if let Some(disc) = track.disc_number {
-                tag.set_disk(disc);
-            }
+            set_disc(tag, track.disc_number, disc_total);
 
             // Album artist (not part of the Accessor trait).
             if album.album_artist.trim().is_empty() {
@@ -145,6 +158,21 @@ pub fn write_album_tags_to_files(
     Ok(())
 }
 
+/// Disc number and total in the tag's native form: DISCNUMBER/DISCTOTAL
+/// (Vorbis), TPOS "2/3" (ID3v2), `disk` (2, 3) (MP4). The total is only
+/// written with a disc number; `None`s leave the existing values alone.
+fn set_disc(tag: &mut lofty::tag::Tag, disc_number: Option<u32>, disc_total: Option<u32>) {
+    use lofty::prelude::*;
+
+    let Some(disc) = disc_number else {
+        return;
+    };
+    tag.set_disk(disc);
+    if let Some(total) = disc_total {
+        tag.set_disk_total(total);
+    }
+}
+
 /// Returns `Some(v)` iff every non-blank track shares one
 /// `album_artist ?? artist`, else `None`. Empty / all-blank => `None`.
 /// Port of the Tauri `library_compute_track_artist_match`.
@@ -166,3 +194,294 @@ pub fn compute_track_artist_match(tracks: &[LocalTrack]) -> Option<String> {
     }
     artists.into_iter().next()
 }
+
+/// ReplayGain values for one file. Gains are dB against
+/// [`REPLAYGAIN_REFERENCE_LUFS`]; peaks are linear sample peaks. `None`
+/// fields are left as they are in the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayGainWrite {
+    pub file_path: String,
+    pub track_gain_db: f32,
+    pub track_peak: Option<f32>,
+    pub album_gain_db: Option<f32>,
+    pub album_peak: Option<f32>,
+}
+
+/// Outcome of a batch ReplayGain write. `skipped` tracks had nothing to
+/// write (no measurement, not a local file); `failed` lists the files that
+/// could not be written, with the reason.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteGainSummary {
+    pub written: usize,
+    pub skipped: usize,
+    pub failed: Vec<(String, String)>,
+}
+
+/// ReplayGain tags read from a file; each is `None` when absent or
+/// unparseable.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplayGainTags {
+    pub track_gain_db: Option<f32>,
+    pub track_peak: Option<f32>,
+    pub album_gain_db: Option<f32>,
+    pub album_peak: Option<f32>,
+}
+
+impl ReplayGainTags {
+    /// Tag names (REPLAYGAIN_*) this file lacks.
+    pub fn missing(&self) -> Vec<String> {
+        [
+            ("REPLAYGAIN_TRACK_GAIN", self.track_gain_db.is_none()),
+            ("REPLAYGAIN_TRACK_PEAK", self.track_peak.is_none()),
+            ("REPLAYGAIN_ALBUM_GAIN", self.album_gain_db.is_none()),
+            ("REPLAYGAIN_ALBUM_PEAK", self.album_peak.is_none()),
+        ]
+        .into_iter()
+        .filter(|(_, missing)| *missing)
+        .map(|(name, _)| name.to_string())
+        .collect()
+    }
+}
+
+/// Outcome of a batch ReplayGain import. `imported` tracks had a track gain
+/// tag; `missing` lists, per file, the tags it lacks (a file without a
+/// track gain is not imported); `failed` lists unreadable files.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GainImportSummary {
+    pub imported: usize,
+    pub skipped: usize,
+    pub missing: Vec<(String, Vec<String>)>,
+    pub failed: Vec<(String, String)>,
+}
+
+/// Parse a ReplayGain tag value: "-6.52 dB", "+3.00 dB" or a bare number.
+pub fn parse_replaygain_value(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let number = value
+        .strip_suffix("dB")
+        .or_else(|| value.strip_suffix("db"))
+        .or_else(|| value.strip_suffix("DB"))
+        .unwrap_or(value);
+    number.trim().parse::<f32>().ok().filter(|v| v.is_finite())
+}
+
+/// Album gain from its tracks' gains: the tracks' loudness averaged by
+/// energy (equal weight per track), expressed against the same reference.
+/// None for an empty album.
+pub fn album_replaygain(track_gains_db: &[f32]) -> Option<f32> {
+    if track_gains_db.is_empty() {
+        return None;
+    }
+    let mean_energy = track_gains_db
+        .iter()
+        .map(|gain| 10f64.powf(f64::from(REPLAYGAIN_REFERENCE_LUFS - gain) / 10.0))
+        .sum::<f64>()
+        / track_gains_db.len() as f64;
+    let album_lufs = 10.0 * mean_energy.log10();
+    Some(REPLAYGAIN_REFERENCE_LUFS - album_lufs as f32)
+}
+
+fn format_gain(db: f32) -> String {
+    format!("{:+.2} dB", db)
+}
+
+fn format_peak(peak: f32) -> String {
+    format!("{:.6}", peak)
+}
+
+/// Embed ReplayGain tags in one file. The write goes to a temporary copy
+/// next to the file, which then replaces it with a rename, so a crash or a
+/// full disk never leaves a half-written file behind.
+pub fn write_replaygain_to_file(gain: &ReplayGainWrite) -> Result<(), LibraryError> {
+    let path = Path::new(&gain.file_path);
+    if !path.is_file() {
+        return Err(LibraryError::Metadata(format!(
+            "{} was not found on disk.",
+            gain.file_path
+        )));
+    }
+    let temp = replaygain_temp_path(path);
+    std::fs::copy(path, &temp)?;
+    let result = tag_replaygain(&temp, gain)
+        .and_then(|()| std::fs::rename(&temp, path).map_err(LibraryError::from));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    result
+}
+
+fn tag_replaygain(path: &Path, gain: &ReplayGainWrite) -> Result<(), LibraryError> {
+    use lofty::config::WriteOptions;
+    use lofty::prelude::*;
+    use lofty::tag::{ItemKey, Tag};
+
+    let mut tagged_file = lofty::read_from_path(path)
+        .map_err(|e| LibraryError::Metadata(format!("Failed to read tags: {}", e)))?;
+    let primary_type = tagged_file.primary_tag_type();
+    if tagged_file.primary_tag_mut().is_none() {
+        tagged_file.insert_tag(Tag::new(primary_type));
+    }
+    let tag = tagged_file
+        .primary_tag_mut()
+        .ok_or_else(|| LibraryError::Metadata("Failed to access tags.".to_string()))?;
+
+    tag.insert_text(
+        ItemKey::ReplayGainTrackGain,
+        format_gain(gain.track_gain_db),
+    );
+    if let Some(peak) = gain.track_peak {
+        tag.insert_text(ItemKey::ReplayGainTrackPeak, format_peak(peak));
+    }
+    if let Some(album_gain) = gain.album_gain_db {
+        tag.insert_text(ItemKey::ReplayGainAlbumGain, format_gain(album_gain));
+    }
+    if let Some(peak) = gain.album_peak {
+        tag.insert_text(ItemKey::ReplayGainAlbumPeak, format_peak(peak));
+    }
+
+    tagged_file
+        .save_to_path(path, WriteOptions::default())
+        .map_err(|e| LibraryError::Metadata(format!("Failed to write tags: {}", e)))
+}
+
+/// Hidden sibling of `path` for the atomic write: same directory (so the
+/// rename stays on one filesystem) and same extension (lofty picks the
+/// format from it).
+fn replaygain_temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".qbz-rg-{}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::minimal_flac;
+    use lofty::prelude::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("qbz-tag-writer-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Three silent MPEG-1 Layer III frames (128 kbps, 44.1 kHz).
+    fn minimal_mp3() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for _ in 0..3 {
+            bytes.extend([0xFF, 0xFB, 0x90, 0x64]);
+            bytes.extend([0; 413]);
+        }
+        bytes
+    }
+
+    fn album() -> AlbumTagWrite {
+        AlbumTagWrite {
+            album_title: "Mellon Collie".to_string(),
+            album_artist: "The Smashing Pumpkins".to_string(),
+            year: Some(1995),
+            genre: None,
+            catalog_number: None,
+        }
+    }
+
+    fn write_discs(dir: &Path, ext: &str, bytes: &[u8]) -> Vec<PathBuf> {
+        let paths: Vec<PathBuf> = (1..=3)
+            .map(|disc| {
+                let path = dir.join(format!("{disc}-01.{ext}"));
+                std::fs::write(&path, bytes).unwrap();
+                path
+            })
+            .collect();
+        let tracks: Vec<TrackTagWrite> = paths
+            .iter()
+            .zip(1..)
+            .map(|(path, disc)| TrackTagWrite {
+                file_path: path.to_string_lossy().into_owned(),
+                title: format!("Track on disc {disc}"),
+                track_number: Some(1),
+                disc_number: Some(disc),
+            })
+            .collect();
+        write_album_tags_to_files(&album(), &tracks, |_, _| {}).unwrap();
+        paths
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn flac_round_trip_keeps_each_disc_number() {
+        let dir = temp_dir("flac");
+        let paths = write_discs(&dir, "flac", &minimal_flac());
+
+        let second = crate::MetadataExtractor::extract(&paths[1]).unwrap();
+        assert_eq!(second.disc_number, Some(2));
+        assert_eq!(second.track_number, Some(1));
+        let raw = std::fs::read(&paths[1]).unwrap();
+        assert!(contains(&raw, b"DISCNUMBER=2"));
+        assert!(contains(&raw, b"DISCTOTAL=3"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn mp3_round_trip_writes_tpos_with_total() {
+        let dir = temp_dir("mp3");
+        let paths = write_discs(&dir, "mp3", &minimal_mp3());
+
+        let second = crate::MetadataExtractor::extract(&paths[1]).unwrap();
+        assert_eq!(second.disc_number, Some(2));
+        let tagged = lofty::read_from_path(&paths[1]).unwrap();
+        assert_eq!(tagged.primary_tag().unwrap().disk_total(), Some(3));
+        let raw = std::fs::read(&paths[1]).unwrap();
+        assert!(contains(&raw, b"TPOS"));
+        assert!(contains(&raw, b"2/3"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn mp4_disk_atom_carries_number_and_total() {
+        let mut tag = lofty::tag::Tag::new(lofty::tag::TagType::Mp4Ilst);
+        set_disc(&mut tag, Some(2), Some(3));
+        let ilst = lofty::mp4::Ilst::from(tag);
+        assert_eq!(ilst.disk(), Some(2));
+        assert_eq!(ilst.disk_total(), Some(3));
+
+        // No disc number: the total is not written on its own.
+        let mut tag = lofty::tag::Tag::new(lofty::tag::TagType::Mp4Ilst);
+        set_disc(&mut tag, None, Some(3));
+        assert_eq!(tag.disk_total(), None);
+    }
+
+    #[test]
+    fn album_gain_averages_loudness_by_energy() {
+        assert_eq!(album_replaygain(&[]), None);
+        let single = album_replaygain(&[-4.5]).unwrap();
+        assert!((single + 4.5).abs() < 1e-4);
+        // -14 and -24 LUFS tracks: the loud one dominates the energy mean.
+        let album = album_replaygain(&[-4.0, 6.0]).unwrap();
+        assert!((album + 1.4).abs() < 0.05, "album gain {album}");
+    }
+
+    #[test]
+    fn tag_values_use_the_replaygain_text_format() {
+        assert_eq!(format_gain(-6.5234), "-6.52 dB");
+        assert_eq!(format_gain(3.0), "+3.00 dB");
+        assert_eq!(format_peak(0.98854), "0.988540");
+        assert_eq!(parse_replaygain_value(&format_gain(-6.52)), Some(-6.52));
+        assert_eq!(parse_replaygain_value(" +3.00 dB"), Some(3.0));
+        assert_eq!(parse_replaygain_value("0.988540"), Some(0.98854));
+        assert_eq!(parse_replaygain_value("loud"), None);
+        assert_eq!(
+            replaygain_temp_path(Path::new("/music/a/01 Song.flac")),
+            PathBuf::from("/music/a/.qbz-rg-01 Song.flac")
+        );
+    }
+}
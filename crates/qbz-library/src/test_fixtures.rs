@@ -0,0 +1,15 @@
+//! Audio fixtures shared by the crate's unit tests.
+
+/// A FLAC stream with only a STREAMINFO block (44.1 kHz, stereo, 16-bit,
+/// no samples).
+pub(crate) fn minimal_flac() -> Vec<u8> {
+    let mut bytes = b"fLaC".to_vec();
+    bytes.extend([0x80, 0, 0, 34]);
+    bytes.extend(4096u16.to_be_bytes());
+    bytes.extend(4096u16.to_be_bytes());
+    bytes.extend([0; 6]);
+    let packed: u64 = (44_100 << 44) | (1 << 41) | (15 << 36);
+    bytes.extend(packed.to_be_bytes());
+    bytes.extend([0; 16]);
+    bytes
+}
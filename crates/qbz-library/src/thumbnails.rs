@@ -2,16 +2,30 @@
 
 use image::imageops::FilterType;
 use image::ImageReader;
+use std::collections::HashSet;
 use std::fs;
 use std::io::Cursor;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
-use crate::LibraryError;
+use crate::{LibraryDatabase, LibraryError};
 
 /// Default thumbnail size (width and height)
 /// 500px is a good balance for UI display while keeping file size reasonable
 const THUMBNAIL_SIZE: u32 = 500;
 
+/// Thumbnails younger than this are never treated as orphans: a running scan
+/// writes the file before the track row that points at it.
+const ORPHAN_MIN_AGE: Duration = Duration::from_secs(60);
+
+/// Outcome of [`cleanup_orphan_thumbnails`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ThumbnailCleanupSummary {
+    pub checked: usize,
+    pub removed: usize,
+    pub bytes_freed: u64,
+}
+
 /// Get the thumbnails directory path
 pub fn get_thumbnails_dir() -> Result<PathBuf, LibraryError> {
     let data_dir = dirs::data_local_dir()
@@ -166,3 +180,144 @@ pub fn get_cache_size() -> Result<u64, LibraryError> {
 
     Ok(total_size)
 }
+
+/// Delete thumbnails nothing references any more (tracks that left the
+/// library, replaced custom images). The thumbnails directory is shared by
+/// every user on the machine and by ephemeral folder sessions, so the
+/// referenced set is built from all of `db_paths` (each user's library.db)
+/// plus `extra_referenced` (paths held in memory, e.g. the open ephemeral
+/// folder); a database that can't be read aborts the cleanup rather than
+/// treat its thumbnails as orphans. Thumbnail names are hashes of their
+/// source, so a file is an orphan when no referenced path has its name.
+/// Files modified in the last minute are kept, as they may belong to a scan
+/// in progress.
+pub fn cleanup_orphan_thumbnails(
+    db_paths: &[PathBuf],
+    extra_referenced: &[String],
+) -> Result<ThumbnailCleanupSummary, LibraryError> {
+    cleanup_in(
+        &get_thumbnails_dir()?,
+        db_paths,
+        extra_referenced,
+        ORPHAN_MIN_AGE,
+    )
+}
+
+fn cleanup_in(
+    dir: &Path,
+    db_paths: &[PathBuf],
+    extra_referenced: &[String],
+    min_age: Duration,
+) -> Result<ThumbnailCleanupSummary, LibraryError> {
+    let mut referenced = extra_referenced.to_vec();
+    for path in db_paths {
+        let db = LibraryDatabase::open(path)?;
+        referenced.extend(db.get_referenced_image_paths()?);
+    }
+    remove_orphans_in(dir, &referenced, min_age)
+}
+
+fn remove_orphans_in(
+    dir: &Path,
+    referenced: &[String],
+    min_age: Duration,
+) -> Result<ThumbnailCleanupSummary, LibraryError> {
+    let referenced: HashSet<&std::ffi::OsStr> = referenced
+        .iter()
+        .filter_map(|p| Path::new(p).file_name())
+        .collect();
+    let now = SystemTime::now();
+    let mut summary = ThumbnailCleanupSummary::default();
+
+    for entry in fs::read_dir(dir)
+        .map_err(|e| LibraryError::Other(format!("Failed to read thumbnails directory: {}", e)))?
+        .flatten()
+    {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        summary.checked += 1;
+        if referenced.contains(entry.file_name().as_os_str()) {
+            continue;
+        }
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok());
+        if !age.is_some_and(|age| age >= min_age) {
+            continue;
+        }
+        match fs::remove_file(entry.path()) {
+            Ok(()) => {
+                summary.removed += 1;
+                summary.bytes_freed += metadata.len();
+            }
+            Err(e) => log::warn!("Failed to remove thumbnail {:?}: {}", entry.path(), e),
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_only_unreferenced_old_thumbnails() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["kept.jpg", "orphan.jpg"] {
+            fs::write(dir.path().join(name), b"jpeg").unwrap();
+        }
+        let referenced = vec![dir.path().join("kept.jpg").to_string_lossy().to_string()];
+
+        // Everything is brand new: nothing is old enough to go.
+        let summary = remove_orphans_in(dir.path(), &referenced, ORPHAN_MIN_AGE).unwrap();
+        assert_eq!((summary.checked, summary.removed), (2, 0));
+
+        let summary = remove_orphans_in(dir.path(), &referenced, Duration::ZERO).unwrap();
+        assert_eq!((summary.removed, summary.bytes_freed), (1, 4));
+        assert!(dir.path().join("kept.jpg").exists());
+        assert!(!dir.path().join("orphan.jpg").exists());
+    }
+
+    #[test]
+    fn keeps_thumbnails_any_user_or_the_ephemeral_session_references() {
+        let thumbs = tempfile::tempdir().unwrap();
+        let users = tempfile::tempdir().unwrap();
+        for name in ["alice.jpg", "bob.jpg", "ephemeral.jpg", "orphan.jpg"] {
+            fs::write(thumbs.path().join(name), b"jpeg").unwrap();
+        }
+        let thumb = |name: &str| thumbs.path().join(name).to_string_lossy().to_string();
+
+        let mut db_paths = Vec::new();
+        for (user, art) in [("1", "alice.jpg"), ("2", "bob.jpg")] {
+            let path = users.path().join(user).join("library.db");
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            let db = LibraryDatabase::open(&path).unwrap();
+            let track = crate::LocalTrack {
+                file_path: format!("/music/{user}.flac"),
+                artwork_path: Some(thumb(art)),
+                ..Default::default()
+            };
+            db.insert_track(&track).unwrap();
+            db_paths.push(path);
+        }
+
+        let summary = cleanup_in(
+            thumbs.path(),
+            &db_paths,
+            &[thumb("ephemeral.jpg")],
+            Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!((summary.checked, summary.removed), (4, 1));
+        for kept in ["alice.jpg", "bob.jpg", "ephemeral.jpg"] {
+            assert!(thumbs.path().join(kept).exists(), "{kept} was removed");
+        }
+        assert!(!thumbs.path().join("orphan.jpg").exists());
+    }
+}
@@ -0,0 +1,166 @@
+//! Panic crash reports: the panic message plus the last log lines, saved as
+//! `~/.local/share/qbz/crash-{timestamp}.json` so the context survives the
+//! process.
+//!
+//! The hook runs on the panicking thread, possibly while that thread holds a
+//! logging lock, so it never logs and never blocks: the ring is read with
+//! `try_lock` (an empty tail beats a deadlock) and the report goes out in one
+//! `write_all` on a freshly created file, then the previous hook runs (the
+//! usual stderr message). A hard fault (SIGSEGV in native code) does not
+//! unwind and leaves no report; the startup crash-chain probe covers that.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::ring;
+
+/// Log lines captured into each report.
+pub const CRASH_LOG_LINES: usize = 100;
+
+/// Reports kept on disk; older ones are deleted at install.
+const MAX_REPORTS: usize = 10;
+
+const PREFIX: &str = "crash-";
+const SUFFIX: &str = ".json";
+
+/// Directory the reports are written to (`~/.local/share/qbz`).
+pub fn crash_dir() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join("qbz"))
+}
+
+/// Chain the crash-report writer in front of the current panic hook. Call
+/// once, after [`crate::install`] (so the ring is filling).
+pub fn install_panic_hook() {
+    let Some(dir) = crash_dir() else { return };
+    prune(&dir);
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = match info.payload().downcast_ref::<&str>() {
+            Some(s) => (*s).to_string(),
+            None => info
+                .payload()
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| "<non-string panic payload>".to_string()),
+        };
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+        let thread = std::thread::current()
+            .name()
+            .unwrap_or("<unnamed>")
+            .to_string();
+        write_report(&dir, &message, &location, &thread);
+        previous(info);
+    }));
+}
+
+fn write_report(dir: &Path, message: &str, location: &str, thread: &str) {
+    let now = chrono::Local::now();
+    let lines: Vec<String> = ring::try_tail(CRASH_LOG_LINES)
+        .unwrap_or_default()
+        .iter()
+        .map(|line| {
+            format!(
+                "{} {} {} {}",
+                line.format_ts(),
+                line.level_str(),
+                line.target,
+                line.message
+            )
+        })
+        .collect();
+    let report = serde_json::json!({
+        "timestamp": now.to_rfc3339(),
+        "thread": thread,
+        "location": location,
+        "panic_message": crate::redact(message),
+        "last_log_lines": lines,
+    });
+    let path = dir.join(format!(
+        "{PREFIX}{}{SUFFIX}",
+        now.format("%Y%m%d-%H%M%S%.3f")
+    ));
+    if let Ok(mut file) = std::fs::File::create(path) {
+        let _ = file.write_all(report.to_string().as_bytes());
+    }
+}
+
+/// Saved reports, newest first.
+pub fn crash_reports() -> Vec<PathBuf> {
+    crash_dir().map(|dir| list(&dir)).unwrap_or_default()
+}
+
+/// The newest report's JSON text, if any.
+pub fn latest_crash_report() -> Option<String> {
+    let path = crash_reports().into_iter().next()?;
+    std::fs::read_to_string(path).ok()
+}
+
+/// Delete every saved report (the user has seen / shared them).
+pub fn clear_crash_reports() {
+    for path in crash_reports() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn list(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut reports: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(PREFIX) && n.ends_with(SUFFIX))
+        })
+        .collect();
+    // The timestamp in the name sorts lexicographically.
+    reports.sort();
+    reports.reverse();
+    reports
+}
+
+fn prune(dir: &Path) {
+    for path in list(dir).into_iter().skip(MAX_REPORTS) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_is_listed_newest_first_and_pruned() {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("qbz-log-crash-{nonce}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        for i in 0..(MAX_REPORTS + 2) {
+            std::fs::write(
+                dir.join(format!("crash-20260101-0000{i:02}.000.json")),
+                "{}",
+            )
+            .unwrap();
+        }
+        std::fs::write(dir.join("unrelated.json"), "{}").unwrap();
+
+        write_report(&dir, "boom", "src/main.rs:1", "main");
+        let reports = list(&dir);
+        assert_eq!(reports.len(), MAX_REPORTS + 3);
+        let newest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&reports[0]).unwrap()).unwrap();
+        assert_eq!(newest["panic_message"], "boom");
+        assert!(newest["last_log_lines"].is_array());
+
+        prune(&dir);
+        assert_eq!(list(&dir).len(), MAX_REPORTS);
+        assert!(dir.join("unrelated.json").exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
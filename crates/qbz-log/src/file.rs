@@ -0,0 +1,175 @@
+//! The on-disk file sink with size-based rotation.
+//!
+//! Writes `qbz.log` in the log directory. When a write would push it past the
+//! size cap the file is rotated: `qbz.log` → `qbz.log.1`, `qbz.log.1` →
+//! `qbz.log.2`, ... and the oldest beyond `max_files` is deleted. Each run also
+//! starts on a fresh `qbz.log` (the previous run's file becomes `qbz.log.1`),
+//! so "the log of the run that just crashed" is always one file away.
+//!
+//! Every failure is swallowed after the file is open: a full disk or a failed
+//! rename must never take logging (or the app) down with it.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Current-run file name inside the log directory.
+pub const LOG_FILE_NAME: &str = "qbz.log";
+
+/// Default size cap per file.
+pub const DEFAULT_MAX_SIZE_MB: u32 = 10;
+
+/// Default number of rotated files kept next to `qbz.log`.
+pub const DEFAULT_MAX_FILES: u32 = 5;
+
+/// Bytes in one MB of the size cap.
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+pub struct FileLogger {
+    dir: PathBuf,
+    max_bytes: u64,
+    max_files: u32,
+    writer: BufWriter<File>,
+    written: u64,
+}
+
+impl FileLogger {
+    /// Open a fresh `qbz.log` in `log_dir` (rotating any previous one). Keeps
+    /// at most `max_files` rotated files (`qbz.log.1` newest) of up to
+    /// `max_size_mb` MB each; `max_size_mb = 0` disables size rotation.
+    pub fn new(log_dir: &Path, max_size_mb: u32, max_files: u32) -> Result<Self, String> {
+        std::fs::create_dir_all(log_dir)
+            .map_err(|e| format!("Failed to create log directory: {}", e))?;
+        let mut logger = Self {
+            dir: log_dir.to_path_buf(),
+            max_bytes: u64::from(max_size_mb) * BYTES_PER_MB,
+            max_files,
+            writer: BufWriter::new(open_fresh(&log_dir.join(LOG_FILE_NAME), max_files)?),
+            written: 0,
+        };
+        logger.prune();
+        Ok(logger)
+    }
+
+    /// Path of the current-run file.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(LOG_FILE_NAME)
+    }
+
+    /// Append one line (a trailing newline is added), rotating first if it
+    /// would overflow the size cap.
+    pub fn write_line(&mut self, line: &str) {
+        let len = line.len() as u64 + 1;
+        if self.max_bytes > 0 && self.written > 0 && self.written + len > self.max_bytes {
+            self.rotate();
+        }
+        if writeln!(self.writer, "{line}").is_ok() {
+            self.written += len;
+        }
+    }
+
+    pub fn flush(&mut self) {
+        let _ = self.writer.flush();
+    }
+
+    /// Close the current file, shift the numbered files up and reopen. On
+    /// failure the current file just keeps growing.
+    fn rotate(&mut self) {
+        let _ = self.writer.flush();
+        match open_fresh(&self.path(), self.max_files) {
+            Ok(file) => {
+                self.writer = BufWriter::new(file);
+                self.written = 0;
+            }
+            Err(e) => eprintln!("[qbz-log] log rotation failed: {e}"),
+        }
+    }
+
+    /// Delete numbered files beyond `max_files` (left over from a run with a
+    /// larger limit).
+    fn prune(&self) {
+        let mut n = self.max_files + 1;
+        while rotated_path(&self.path(), n).exists() {
+            let _ = std::fs::remove_file(rotated_path(&self.path(), n));
+            n += 1;
+        }
+    }
+}
+
+fn rotated_path(path: &Path, n: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+/// Shift `path` → `path.1` → ... → `path.{max_files}` (dropping the oldest)
+/// and create an empty `path`. With `max_files = 0` the old file is simply
+/// truncated.
+fn open_fresh(path: &Path, max_files: u32) -> Result<File, String> {
+    if max_files > 0 && path.exists() {
+        let _ = std::fs::remove_file(rotated_path(path, max_files));
+        for n in (1..max_files).rev() {
+            let from = rotated_path(path, n);
+            if from.exists() {
+                let _ = std::fs::rename(&from, rotated_path(path, n + 1));
+            }
+        }
+        let _ = std::fs::rename(path, rotated_path(path, 1));
+    }
+    File::create(path).map_err(|e| format!("Failed to create log file: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_test_dir(name: &str) -> PathBuf {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("qbz-log-{name}-{}-{nonce}", std::process::id()))
+    }
+
+    #[test]
+    fn rotates_by_size_and_keeps_at_most_max_files() {
+        let dir = unique_test_dir("rotate");
+        let mut logger = FileLogger::new(&dir, 1, 2).expect("open");
+        // Force a tiny cap so a handful of lines triggers rotation.
+        const TINY_CAP: u64 = 64;
+        logger.max_bytes = TINY_CAP;
+        for i in 0..20 {
+            logger.write_line(&format!("line {i:02} padding padding"));
+        }
+        logger.flush();
+
+        assert!(dir.join("qbz.log").exists());
+        assert!(dir.join("qbz.log.1").exists());
+        assert!(dir.join("qbz.log.2").exists());
+        assert!(!dir.join("qbz.log.3").exists());
+        let current = std::fs::read_to_string(dir.join("qbz.log")).unwrap();
+        assert!(current.contains("line 19"));
+        assert!(current.len() as u64 <= TINY_CAP);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn each_run_starts_a_fresh_file() {
+        let dir = unique_test_dir("fresh");
+        {
+            let mut first =
+                FileLogger::new(&dir, DEFAULT_MAX_SIZE_MB, DEFAULT_MAX_FILES).expect("open");
+            first.write_line("first run");
+            first.flush();
+        }
+        let _second =
+            FileLogger::new(&dir, DEFAULT_MAX_SIZE_MB, DEFAULT_MAX_FILES).expect("reopen");
+
+        assert_eq!(std::fs::read_to_string(dir.join("qbz.log")).unwrap(), "");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("qbz.log.1")).unwrap(),
+            "first run\n"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
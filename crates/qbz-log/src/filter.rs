@@ -0,0 +1,122 @@
+//! Runtime per-module level overrides layered over the `env_logger` filter.
+//!
+//! The inner `env_logger` filter is fixed once built, so a module whose level
+//! changes at runtime is tracked here instead: [`crate::tee::TeeLogger`] asks
+//! [`module_level`] first and only falls back to the inner filter when no
+//! override matches. A module matches its own target and every `::` child
+//! (`qbz_audio` covers `qbz_audio::pipewire`); the longest match wins.
+//!
+//! `log`'s global max level is a cheap pre-filter applied before the logger is
+//! even consulted, so it is kept at the most verbose of the base level and
+//! every override — otherwise raising one module to `debug` would be a no-op.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+use log::LevelFilter;
+
+static OVERRIDES: RwLock<Vec<(String, LevelFilter)>> = RwLock::new(Vec::new());
+
+/// The base (non-override) max level, as a `LevelFilter` discriminant.
+static BASE_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+
+/// Set (or replace) the level for `module` and its children. `LevelFilter::Off`
+/// silences the module; use [`clear_module_level`] to hand it back to the base
+/// filter.
+pub fn set_module_level(module: &str, level: LevelFilter) {
+    let module = module.trim().to_string();
+    if module.is_empty() {
+        return;
+    }
+    {
+        let mut overrides = OVERRIDES.write().unwrap_or_else(|e| e.into_inner());
+        match overrides.iter_mut().find(|(m, _)| *m == module) {
+            Some(entry) => entry.1 = level,
+            None => overrides.push((module, level)),
+        }
+    }
+    apply_max_level();
+}
+
+/// Drop the override for `module` (its level follows the base filter again).
+pub fn clear_module_level(module: &str) {
+    OVERRIDES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|(m, _)| m != module.trim());
+    apply_max_level();
+}
+
+/// The current overrides, in the order they were first set.
+pub fn module_levels() -> Vec<(String, LevelFilter)> {
+    OVERRIDES.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// The override governing `target`, if any (longest matching module wins).
+pub fn module_level(target: &str) -> Option<LevelFilter> {
+    let overrides = OVERRIDES.read().unwrap_or_else(|e| e.into_inner());
+    overrides
+        .iter()
+        .filter(|(module, _)| covers(module, target))
+        .max_by_key(|(module, _)| module.len())
+        .map(|(_, level)| *level)
+}
+
+/// Record the base max level and re-derive `log`'s global max level.
+pub(crate) fn set_base_level(level: LevelFilter) {
+    BASE_LEVEL.store(level as usize, Ordering::Relaxed);
+    apply_max_level();
+}
+
+fn base_level() -> LevelFilter {
+    LevelFilter::iter()
+        .find(|l| *l as usize == BASE_LEVEL.load(Ordering::Relaxed))
+        .unwrap_or(LevelFilter::Info)
+}
+
+fn apply_max_level() {
+    let overrides = OVERRIDES.read().unwrap_or_else(|e| e.into_inner());
+    let max = overrides
+        .iter()
+        .map(|(_, level)| *level)
+        .fold(base_level(), LevelFilter::max);
+    log::set_max_level(max);
+}
+
+fn covers(module: &str, target: &str) -> bool {
+    target == module
+        || target
+            .strip_prefix(module)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_module_prefix_wins_on_path_boundaries() {
+        set_module_level("qbz_test_audio", LevelFilter::Debug);
+        set_module_level("qbz_test_audio::pipewire", LevelFilter::Trace);
+
+        assert_eq!(module_level("qbz_test_audio"), Some(LevelFilter::Debug));
+        assert_eq!(
+            module_level("qbz_test_audio::alsa"),
+            Some(LevelFilter::Debug)
+        );
+        assert_eq!(
+            module_level("qbz_test_audio::pipewire::stream"),
+            Some(LevelFilter::Trace)
+        );
+        // A shared name prefix is not a module boundary.
+        assert_eq!(module_level("qbz_test_audiox"), None);
+
+        clear_module_level("qbz_test_audio::pipewire");
+        assert_eq!(
+            module_level("qbz_test_audio::pipewire"),
+            Some(LevelFilter::Debug)
+        );
+        clear_module_level("qbz_test_audio");
+        assert_eq!(module_level("qbz_test_audio"), None);
+    }
+}
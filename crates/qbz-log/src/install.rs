@@ -1,12 +1,12 @@
-//! One-shot logger installation + the on-disk file sink (open / rotate).
+//! One-shot logger installation + where the on-disk file sink lives.
 
-use std::fs::File;
-use std::io::BufWriter;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 
-use crate::tee::TeeLogger;
+use crate::file::{FileLogger, DEFAULT_MAX_FILES, DEFAULT_MAX_SIZE_MB, LOG_FILE_NAME};
+use crate::filter;
+use crate::tee::{LineFormat, TeeLogger};
 
 static INSTALLED: AtomicBool = AtomicBool::new(false);
 
@@ -16,6 +16,17 @@ static INSTALLED: AtomicBool = AtomicBool::new(false);
 /// opens/rotates the on-disk file, then sets the boxed logger + max level. Idempotent:
 /// a second call is a guarded no-op (it neither rotates the file again nor panics).
 pub fn install(default_level: &str) {
+    install_with(default_level, LineFormat::Text);
+}
+
+/// [`install`], but stderr and the file sink emit one JSON object per line
+/// (`{"ts","level","module","msg"}`, see [`crate::LogLine::to_json`]). `filter` is an
+/// `env_logger` filter spec (`info`, `info,qbz_audio=debug`, ...); `RUST_LOG` still wins.
+pub fn install_structured(filter: &str) {
+    install_with(filter, LineFormat::Json);
+}
+
+fn install_with(default_level: &str, format: LineFormat) {
     // True one-shot guard: avoid re-rotating the log file or fighting an already-set logger.
     if INSTALLED.swap(true, Ordering::SeqCst) {
         return;
@@ -37,34 +48,44 @@ pub fn install(default_level: &str) {
     let file = open_log_file();
 
     // Ignore the Err if a logger was somehow already set elsewhere.
-    if log::set_boxed_logger(Box::new(TeeLogger { inner, file })).is_ok() {
-        log::set_max_level(level);
+    if log::set_boxed_logger(Box::new(TeeLogger {
+        inner,
+        file,
+        format,
+    }))
+    .is_ok()
+    {
+        filter::set_base_level(level);
     }
 }
 
-/// Runtime log-level toggle (e.g. info <-> debug) with no restart.
+/// Runtime log-level toggle (e.g. info <-> debug) with no restart. Per-module
+/// overrides ([`filter::set_module_level`]) keep the max level raised as needed.
 pub fn set_level(level: log::LevelFilter) {
-    log::set_max_level(level);
+    filter::set_base_level(level);
+}
+
+/// The log directory (`~/.local/share/qbz/logs`), if a data dir exists. Holds
+/// `qbz.log` plus its rotated `qbz.log.N` siblings.
+pub fn log_dir() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join("qbz").join("logs"))
 }
 
 /// Path to the current-run log file (`~/.local/share/qbz/logs/qbz.log`), if a data dir exists.
 pub fn log_file_path() -> Option<PathBuf> {
-    Some(dirs::data_dir()?.join("qbz").join("logs").join("qbz.log"))
+    Some(log_dir()?.join(LOG_FILE_NAME))
 }
 
-/// Open the log file for this run, rotating any previous one to `qbz.log.prev`.
-/// Returns `None` (file sink disabled, gracefully) on any filesystem error.
-fn open_log_file() -> Option<Mutex<BufWriter<File>>> {
-    let path = log_file_path()?;
-    let dir = path.parent()?;
-    std::fs::create_dir_all(dir).ok()?;
-
-    if path.exists() {
-        let prev = dir.join("qbz.log.prev");
-        // Best-effort rotation; a failure here must not disable logging.
-        let _ = std::fs::rename(&path, &prev);
+/// Open the rotating file sink for this run (the previous run's file becomes
+/// `qbz.log.1`). Returns `None` (file sink disabled, gracefully) on any
+/// filesystem error.
+fn open_log_file() -> Option<Mutex<FileLogger>> {
+    let dir = log_dir()?;
+    match FileLogger::new(&dir, DEFAULT_MAX_SIZE_MB, DEFAULT_MAX_FILES) {
+        Ok(file) => Some(Mutex::new(file)),
+        Err(e) => {
+            eprintln!("[qbz-log] file sink disabled: {e}");
+            None
+        }
     }
-
-    let file = File::create(&path).ok()?;
-    Some(Mutex::new(BufWriter::new(file)))
 }
@@ -4,7 +4,14 @@
 //! `env_logger`'s built `Logger` and fans every record out to three sinks:
 //!   1. **stderr** (redacted text, same line format as the file sink),
 //!   2. a bounded **in-memory ring** ([`ring`], cap [`ring::RING_CAP`]), and
-//!   3. an **on-disk file** (`~/.local/share/qbz/logs/qbz.log`, prev-rotated at startup).
+//!   3. an **on-disk file** (`~/.local/share/qbz/logs/qbz.log`, [`file`]: rotated at
+//!      startup and by size, keeping a bounded set of `qbz.log.N` files).
+//!
+//! Lines render as text or, via [`install_structured`], one JSON object per line;
+//! [`filter`] layers runtime per-module levels over the startup filter.
+//!
+//! A panic hook ([`crash`]) saves the panic message plus the ring's tail as a JSON
+//! crash report for the next start to surface.
 //!
 //! Secret **redaction** ([`redact`]) is applied once at the single write choke point,
 //! so every downstream consumer (stderr, ring, file, clipboard, paste upload) gets clean text.
@@ -12,6 +19,9 @@
 //! This crate is network-free and UI-free: no `reqwest`, no `tokio`, no `slint`.
 
 pub mod bundle;
+pub mod crash;
+pub mod file;
+pub mod filter;
 pub mod install;
 pub mod line;
 pub mod redact;
@@ -19,6 +29,7 @@ pub mod ring;
 pub mod tee;
 
 pub use bundle::{format_diagnostics_bundle, DiagFields};
-pub use install::{install, set_level};
+pub use filter::{clear_module_level, set_module_level};
+pub use install::{install, install_structured, set_level};
 pub use line::LogLine;
 pub use redact::{redact, register_secret};
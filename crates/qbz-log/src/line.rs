@@ -38,4 +38,44 @@ impl LogLine {
             None => self.ts.to_string(),
         }
     }
+
+    /// One structured JSON object for this line:
+    /// `{"ts":"<RFC 3339 local>","level":"INFO","module":"<target>","msg":"..."}`.
+    /// `msg` is the (already redacted) message; JSON escaping is serde's.
+    pub fn to_json(&self) -> String {
+        use chrono::{Local, SecondsFormat, TimeZone};
+        let ts = match Local.timestamp_millis_opt(self.ts).single() {
+            Some(dt) => dt.to_rfc3339_opts(SecondsFormat::Millis, false),
+            None => self.ts.to_string(),
+        };
+        serde_json::json!({
+            "ts": ts,
+            "level": self.level_str(),
+            "module": self.target,
+            "msg": self.message,
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_line_has_the_four_fields_and_escapes_the_message() {
+        let line = LogLine {
+            ts: 0,
+            level: Level::Warn,
+            target: "qbz_audio::pipewire".into(),
+            message: "quote \" and\nnewline".into(),
+        };
+        let json = line.to_json();
+        assert!(!json.contains('\n'), "one object per line: {json}");
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["level"], "WARN");
+        assert_eq!(v["module"], "qbz_audio::pipewire");
+        assert_eq!(v["msg"], "quote \" and\nnewline");
+        assert!(v["ts"].is_string());
+    }
 }
@@ -36,6 +36,19 @@ pub fn snapshot() -> Vec<LogLine> {
     guard.iter().cloned().collect()
 }
 
+/// The newest `n` lines (oldest first) WITHOUT blocking: `None` if the ring is
+/// locked right now. For the panic hook, which may fire on a thread that holds
+/// the lock — waiting there would deadlock the crashing process.
+pub fn try_tail(n: usize) -> Option<Vec<LogLine>> {
+    let guard = match ring().try_lock() {
+        Ok(guard) => guard,
+        Err(std::sync::TryLockError::Poisoned(p)) => p.into_inner(),
+        Err(std::sync::TryLockError::WouldBlock) => return None,
+    };
+    let start = guard.len().saturating_sub(n);
+    Some(guard.iter().skip(start).cloned().collect())
+}
+
 /// Empty the ring (the "Clear" action). Does not touch the on-disk file.
 pub fn clear() {
     let r = ring();
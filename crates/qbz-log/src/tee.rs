@@ -1,20 +1,31 @@
 //! The composite [`log::Log`] that fans every record to stderr, the ring, and the file.
 
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::sync::Mutex;
 
 use log::{Log, Metadata, Record};
 
+use crate::file::FileLogger;
 use crate::line::LogLine;
-use crate::{redact, ring};
+use crate::{filter, redact, ring};
+
+/// How stderr and the file sink render a line. The ring always stores the
+/// structured [`LogLine`]; consumers pick their own rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineFormat {
+    /// `{ts} {LEVEL} {target} {message}` — the default, human-greppable.
+    Text,
+    /// One JSON object per line ([`LogLine::to_json`]) for log aggregators.
+    Json,
+}
 
 /// Wraps `env_logger`'s built `Logger` and tees every record to the in-memory ring and
 /// (optionally) the on-disk log file, with secret redaction applied once at this single
 /// write choke point. **All** sinks (ring, file, and stderr) receive the redacted text.
 pub struct TeeLogger {
     pub(crate) inner: env_logger::Logger,
-    pub(crate) file: Option<Mutex<BufWriter<File>>>,
+    pub(crate) file: Option<Mutex<FileLogger>>,
+    pub(crate) format: LineFormat,
 }
 
 fn now_epoch_ms() -> i64 {
@@ -32,14 +43,27 @@ fn format_line(line: &LogLine) -> String {
     )
 }
 
+impl TeeLogger {
+    fn render(&self, line: &LogLine) -> String {
+        match self.format {
+            LineFormat::Text => format_line(line),
+            LineFormat::Json => line.to_json(),
+        }
+    }
+}
+
 impl Log for TeeLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        self.inner.enabled(metadata)
+        // A runtime per-module override beats the inner (startup) filter.
+        match filter::module_level(metadata.target()) {
+            Some(level) => metadata.level() <= level,
+            None => self.inner.enabled(metadata),
+        }
     }
 
     fn log(&self, record: &Record) {
-        // Honor the inner logger's filter so the ring matches what stderr would show.
-        if !self.inner.enabled(record.metadata()) {
+        // Honor the filter so the ring matches what stderr would show.
+        if !self.enabled(record.metadata()) {
             return;
         }
 
@@ -52,25 +76,26 @@ impl Log for TeeLogger {
             message: msg.clone(),
         };
 
-        ring::push(line.clone());
+        let rendered = self.render(&line);
+        ring::push(line);
 
         if let Some(file) = &self.file {
             if let Ok(mut writer) = file.lock() {
-                let _ = writeln!(writer, "{}", format_line(&line));
+                writer.write_line(&rendered);
             }
         }
 
         // stderr: write the redacted line ourselves. Delegating to
         // `self.inner.log(record)` would reprint the *original* Record args
         // and bypass redaction (terminal transcripts, CI logs, support dumps).
-        let _ = writeln!(std::io::stderr(), "{}", format_line(&line));
+        let _ = writeln!(std::io::stderr(), "{rendered}");
     }
 
     fn flush(&self) {
         self.inner.flush();
         if let Some(file) = &self.file {
             if let Ok(mut writer) = file.lock() {
-                let _ = writer.flush();
+                writer.flush();
             }
         }
         let _ = std::io::stderr().flush();
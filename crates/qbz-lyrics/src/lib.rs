@@ -21,8 +21,8 @@
 //! - [`service`] — the Qobuz-first orchestrator
 //!   (spec `qbz-nix-docs/lyrics/2026-06-10-lyrics-slint-port-spec.md` §1.1):
 //!   cache probe (plain-only = soft miss) -> Qobuz primary -> LRCLIB ->
-//!   lyrics.ovh, offline cache-only mode, in-flight dedupe (F6), stale-guard
-//!   echo (F2 support).
+//!   lyrics.ovh, offline cache-only mode, instrumental short-circuit,
+//!   in-flight dedupe (F6), stale-guard echo (F2 support).
 //! - [`sync`] — sync-engine pure functions (spec §4.2): active-line binary
 //!   search, per-line progress with the 0.99 snap, the word-anchored karaoke
 //!   clip fraction (Q2).
@@ -46,8 +46,8 @@ pub use model::{
 };
 pub use providers::LyricsData;
 pub use service::{
-    HttpLyricsProviders, LyricsOutcome, LyricsProviders, LyricsRequest, LyricsResponse,
-    LyricsResult, LyricsService, LyricsSourceKind,
+    is_instrumental, ExternalLyricsProvider, HttpLyricsProviders, LyricsOutcome, LyricsProviders,
+    LyricsRequest, LyricsResponse, LyricsResult, LyricsService, LyricsSourceKind,
 };
 pub use sync::{find_active_line_index, line_fill_fraction, line_progress};
 pub use wsync::{QobuzWsync, QobuzWsyncLine, QobuzWsyncWord};
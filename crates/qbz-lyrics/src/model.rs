@@ -12,14 +12,17 @@ use serde::{Deserialize, Serialize};
 use crate::lrc;
 
 /// Lyrics source provider. Tauri's enum (`src-tauri/src/lyrics/mod.rs:29-50`)
-/// plus the new first-party `Qobuz` variant; serialized lowercase
-/// (`'lrclib' | 'ovh' | 'qobuz'` on the JS side).
+/// plus the first-party `Qobuz` variant and the `NetEase`/`Genius` external
+/// fallbacks; serialized lowercase
+/// (`'lrclib' | 'ovh' | 'qobuz' | 'netease' | 'genius'` on the JS side).
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum LyricsProvider {
     Lrclib,
     Ovh,
     Qobuz,
+    NetEase,
+    Genius,
 }
 
 impl LyricsProvider {
@@ -28,6 +31,8 @@ impl LyricsProvider {
             Self::Lrclib => "lrclib",
             Self::Ovh => "ovh",
             Self::Qobuz => "qobuz",
+            Self::NetEase => "netease",
+            Self::Genius => "genius",
         }
     }
 
@@ -38,6 +43,8 @@ impl LyricsProvider {
         match value {
             "ovh" => Self::Ovh,
             "qobuz" => Self::Qobuz,
+            "netease" => Self::NetEase,
+            "genius" => Self::Genius,
             _ => Self::Lrclib,
         }
     }
@@ -271,6 +278,8 @@ mod tests {
             LyricsProvider::Lrclib,
             LyricsProvider::Ovh,
             LyricsProvider::Qobuz,
+            LyricsProvider::NetEase,
+            LyricsProvider::Genius,
         ] {
             assert_eq!(LyricsProvider::from_str(provider.as_str()), provider);
         }
@@ -1,14 +1,21 @@
 //! External fallback lyrics providers.
 //!
-//! Ported VERBATIM from `src-tauri/src/lyrics/providers.rs` (request shapes
-//! byte-identical: endpoints, query params, User-Agent, 10s timeout). The
-//! only change is `eprintln!`/`println!` -> `log::` macros (crate
-//! convention); the transport/miss contract (`Ok(None)` vs `Err`) is
-//! unchanged — only `Err` triggers the orchestrator's single retry.
+//! LRCLIB and lyrics.ovh are ported VERBATIM from
+//! `src-tauri/src/lyrics/providers.rs` (request shapes byte-identical:
+//! endpoints, query params, User-Agent, 10s timeout). The only change is
+//! `eprintln!`/`println!` -> `log::` macros (crate convention); the
+//! transport/miss contract (`Ok(None)` vs `Err`) is unchanged — only `Err`
+//! triggers the orchestrator's single retry.
+//!
+//! NetEase (synced LRC) and Genius (plain, page scrape) are Slint-era
+//! additions on the same contract: NetEase mirrors LRCLIB's `Result`, Genius
+//! mirrors lyrics.ovh's plain-only `Option`. Genius is a website, not an
+//! API, so its requests are spaced [`GENIUS_MIN_INTERVAL`] apart.
 
 use reqwest::Client;
 use serde::Deserialize;
-use std::time::Duration;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use urlencoding::encode;
 
 use crate::model::{normalize, LyricsProvider};
@@ -292,6 +299,339 @@ pub(crate) fn pick_best_match(
     best.map(|(_, item)| item.clone())
 }
 
+#[derive(Debug, Deserialize)]
+struct NeteaseSearchResponse {
+    result: Option<NeteaseSearchResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NeteaseSearchResult {
+    #[serde(default)]
+    songs: Vec<NeteaseSong>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NeteaseSong {
+    id: u64,
+    name: String,
+    #[serde(default)]
+    artists: Vec<NeteaseArtist>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NeteaseArtist {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NeteaseLyricResponse {
+    lrc: Option<NeteaseLyric>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NeteaseLyric {
+    lyric: Option<String>,
+}
+
+/// Fetch synced lyrics from NetEase Cloud Music (search, then lyric by id).
+///
+/// Only a candidate whose title AND one of its artists match (normalized) is
+/// accepted — NetEase search is fuzzy and happily returns covers.
+///
+/// Returns `Ok(None)` on no match, `Err` on transport failure.
+pub async fn fetch_netease(title: &str, artist: &str) -> Result<Option<LyricsData>, String> {
+    let client = build_client()?;
+    let query = format!("{} {}", title, artist);
+
+    let response = client
+        .get("https://music.163.com/api/search/get/web")
+        .header("Referer", "https://music.163.com/")
+        .query(&[("s", query.as_str()), ("type", "1"), ("limit", "10")])
+        .send()
+        .await
+        .map_err(|e| format!("NetEase search request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+    let search: NeteaseSearchResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("NetEase search response parse failed: {}", e))?;
+
+    let normalized_title = normalize(title);
+    let normalized_artist = normalize(artist);
+    let Some(song) = search.result.and_then(|r| {
+        r.songs.into_iter().find(|song| {
+            normalize(&song.name) == normalized_title
+                && song
+                    .artists
+                    .iter()
+                    .any(|a| normalize(&a.name) == normalized_artist)
+        })
+    }) else {
+        return Ok(None);
+    };
+
+    let response = client
+        .get("https://music.163.com/api/song/lyric")
+        .header("Referer", "https://music.163.com/")
+        .query(&[("id", song.id.to_string().as_str()), ("lv", "1")])
+        .send()
+        .await
+        .map_err(|e| format!("NetEase lyric request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+    let lyric: NeteaseLyricResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("NetEase lyric response parse failed: {}", e))?;
+
+    let Some(synced) = lyric.lrc.and_then(|l| l.lyric).and_then(clean_lyrics) else {
+        return Ok(None);
+    };
+    let lines = crate::lrc::parse_lrc(&synced);
+    if lines.is_empty() {
+        // Untimed text in the lrc field — serve it as plain
+        return Ok(Some(LyricsData {
+            plain: Some(synced),
+            synced_lrc: None,
+            provider: LyricsProvider::NetEase,
+        }));
+    }
+    let plain = lines
+        .iter()
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(Some(LyricsData {
+        plain: clean_lyrics(plain),
+        synced_lrc: Some(synced),
+        provider: LyricsProvider::NetEase,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusSearchResponse {
+    response: GeniusSearchSections,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusSearchSections {
+    #[serde(default)]
+    sections: Vec<GeniusSection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusSection {
+    #[serde(default)]
+    hits: Vec<GeniusHit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusHit {
+    result: GeniusSong,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusSong {
+    url: String,
+    title: String,
+    primary_artist: GeniusArtist,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeniusArtist {
+    name: String,
+}
+
+/// Minimum spacing between two Genius requests (search or page), process-wide.
+pub const GENIUS_MIN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// When the last Genius request was (or is scheduled to be) sent.
+static GENIUS_LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Claim the next request slot after `last` and return how long to wait for
+/// it. Slots are handed out in call order, so concurrent lookups queue up
+/// instead of bursting.
+fn reserve_slot(last: &mut Option<Instant>, now: Instant, interval: Duration) -> Duration {
+    let slot = match *last {
+        Some(prev) if prev + interval > now => prev + interval,
+        _ => now,
+    };
+    *last = Some(slot);
+    slot - now
+}
+
+/// Wait for this caller's Genius request slot.
+async fn genius_throttle() {
+    let wait = match GENIUS_LAST_REQUEST.lock() {
+        Ok(mut last) => reserve_slot(&mut last, Instant::now(), GENIUS_MIN_INTERVAL),
+        Err(_) => Duration::ZERO,
+    };
+    if !wait.is_zero() {
+        log::debug!("[Lyrics] Genius rate limit: waiting {}ms", wait.as_millis());
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Fetch plain lyrics from Genius: public search API, then scrape the song
+/// page's lyrics containers. Plain-only; any failure is a miss. Both requests
+/// go through the [`GENIUS_MIN_INTERVAL`] throttle.
+pub async fn fetch_genius(title: &str, artist: &str) -> Option<LyricsData> {
+    let client = match build_client() {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("[Lyrics] {}", e);
+            return None;
+        }
+    };
+    let query = format!("{} {}", artist, title);
+
+    genius_throttle().await;
+    let search: GeniusSearchResponse = match client
+        .get("https://genius.com/api/search/song")
+        .query(&[("q", query.as_str()), ("per_page", "5")])
+        .send()
+        .await
+    {
+        Ok(r) if r.status().is_success() => match r.json().await {
+            Ok(data) => data,
+            Err(e) => {
+                log::warn!("[Lyrics] Genius search response parse failed: {}", e);
+                return None;
+            }
+        },
+        Ok(_) => return None,
+        Err(e) => {
+            log::warn!("[Lyrics] Genius search request failed: {}", e);
+            return None;
+        }
+    };
+
+    let normalized_title = normalize(title);
+    let normalized_artist = normalize(artist);
+    let song = search
+        .response
+        .sections
+        .into_iter()
+        .flat_map(|section| section.hits)
+        .map(|hit| hit.result)
+        .find(|song| {
+            normalize(&song.title) == normalized_title
+                && normalize(&song.primary_artist.name) == normalized_artist
+        })?;
+
+    genius_throttle().await;
+    let html = match client.get(&song.url).send().await {
+        Ok(r) if r.status().is_success() => r.text().await.ok()?,
+        Ok(_) => return None,
+        Err(e) => {
+            log::warn!("[Lyrics] Genius page request failed: {}", e);
+            return None;
+        }
+    };
+
+    let plain = extract_genius_lyrics(&html)?;
+    Some(LyricsData {
+        plain: Some(plain),
+        synced_lrc: None,
+        provider: LyricsProvider::Genius,
+    })
+}
+
+/// Pull the lyrics text out of a Genius song page: every
+/// `data-lyrics-container="true"` div, minus the blocks Genius marks
+/// `data-exclude-from-selection` (the "N Contributors / Title Lyrics"
+/// header, inline ads), `<br>` as newline, other tags stripped, common
+/// entities decoded.
+pub(crate) fn extract_genius_lyrics(html: &str) -> Option<String> {
+    const MARKER: &str = "data-lyrics-container=\"true\"";
+    let mut blocks = Vec::new();
+    let mut rest = html;
+    while let Some(found) = rest.find(MARKER) {
+        let after_marker = &rest[found + MARKER.len()..];
+        let Some(open_end) = after_marker.find('>') else {
+            break;
+        };
+        let body = &after_marker[open_end + 1..];
+        let end = div_content_end(body);
+        blocks.push(html_to_text(&strip_excluded(&body[..end])));
+        rest = &body[end..];
+    }
+    clean_lyrics(blocks.join("\n"))
+}
+
+/// Length of a div's content, given the text right after its opening tag:
+/// the offset of its own `</div>` (nested divs skipped), or the whole text
+/// when it is never closed.
+fn div_content_end(body: &str) -> usize {
+    let mut depth = 1;
+    let mut pos = 0;
+    while depth > 0 {
+        let next_open = body[pos..].find("<div");
+        let Some(next_close) = body[pos..].find("</div>") else {
+            return body.len();
+        };
+        match next_open {
+            Some(open) if open < next_close => {
+                depth += 1;
+                pos += open + 4;
+            }
+            _ => {
+                depth -= 1;
+                pos += next_close + if depth == 0 { 0 } else { 6 };
+            }
+        }
+    }
+    pos
+}
+
+/// Drop every `<div data-exclude-from-selection="true">` subtree.
+fn strip_excluded(fragment: &str) -> String {
+    const EXCLUDE: &str = "data-exclude-from-selection=\"true\"";
+    let mut kept = String::with_capacity(fragment.len());
+    let mut rest = fragment;
+    while let Some(found) = rest.find(EXCLUDE) {
+        let Some(tag_start) = rest[..found].rfind("<div") else {
+            break;
+        };
+        let Some(open_end) = rest[found..].find('>') else {
+            break;
+        };
+        kept.push_str(&rest[..tag_start]);
+        let body = &rest[found + open_end + 1..];
+        let end = div_content_end(body);
+        rest = body[end..].strip_prefix("</div>").unwrap_or(&body[end..]);
+    }
+    kept.push_str(rest);
+    kept
+}
+
+fn html_to_text(fragment: &str) -> String {
+    let mut text = String::with_capacity(fragment.len());
+    let mut rest = fragment;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        if tag.starts_with("br") {
+            text.push('\n');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+    text.replace("&amp;", "&")
+        .replace("&#x27;", "'")
+        .replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+}
+
 fn clean_lyrics(value: String) -> Option<String> {
     let trimmed = value.trim().to_string();
     if trimmed.is_empty() {
@@ -407,6 +747,45 @@ mod tests {
         assert!(pick_best_match(&[], "S", "A", None).is_none());
     }
 
+    #[test]
+    fn genius_extraction_joins_containers_and_strips_markup() {
+        let html = r#"<html><div class="x" data-lyrics-container="true">[Verse 1]<br/><a href="/a"><span>Don&#x27;t stop</span></a><br><div data-exclude-from-selection="true"><div class="ad">ad</div></div>me now</div><p>noise</p><div data-lyrics-container="true">Rock &amp; roll</div></html>"#;
+        assert_eq!(
+            extract_genius_lyrics(html).as_deref(),
+            Some("[Verse 1]\nDon't stop\nme now\nRock & roll")
+        );
+        assert!(extract_genius_lyrics("<html>no lyrics</html>").is_none());
+    }
+
+    #[test]
+    fn genius_extraction_reads_a_song_page() {
+        // Trimmed from a real song page: the contributors header and the
+        // sidebar must not leak into the lyrics.
+        let html = r#"<!DOCTYPE html><html><head><title>Queen – Bohemian Rhapsody Lyrics | Genius Lyrics</title></head><body><main><div id="lyrics-root" class="Lyrics__Root-sc-1ynbvzw-0"><div data-lyrics-container="true" class="Lyrics__Container-sc-1ynbvzw-1 kUgSbL"><div data-exclude-from-selection="true" class="LyricsHeader__Container-sc-5e4b7146-1"><div class="ContributorsCreditSong__Container"><span class="ContributorsCreditSong__Label">1,234 Contributors</span></div><h2 class="LyricsHeader__Title">Bohemian Rhapsody Lyrics</h2></div>[Intro]<br/><a href="/1063" class="ReferentFragmentdesktop__ClickTarget-sc-110r0d9-0"><span class="ReferentFragmentdesktop__Highlight-sc-110r0d9-1">Is this the real life?<br/>Is this just fantasy?</span></a><br/>Caught in a landslide<br/>No escape from reality</div><div class="RightSidebar__Container"><div class="InreadContainer">Advertisement</div></div><div data-lyrics-container="true" class="Lyrics__Container-sc-1ynbvzw-1 kUgSbL">[Verse 1]<br/>I&#x27;m just a poor boy, I need no sympathy</div><div class="LyricsFooter__Container">How to Format Lyrics</div></div></main></body></html>"#;
+        assert_eq!(
+            extract_genius_lyrics(html).as_deref(),
+            Some(
+                "[Intro]\nIs this the real life?\nIs this just fantasy?\nCaught in a landslide\n\
+                 No escape from reality\n[Verse 1]\nI'm just a poor boy, I need no sympathy"
+            )
+        );
+    }
+
+    #[test]
+    fn genius_slots_are_spaced_by_the_interval() {
+        let interval = Duration::from_secs(2);
+        let start = Instant::now();
+        let mut last = None;
+        assert_eq!(reserve_slot(&mut last, start, interval), Duration::ZERO);
+        // Two more callers right away queue behind the first, 2s apart.
+        assert_eq!(reserve_slot(&mut last, start, interval), interval);
+        assert_eq!(reserve_slot(&mut last, start, interval), interval * 2);
+        // Once the queue has drained, the next request goes straight out.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(reserve_slot(&mut last, later, interval), Duration::ZERO);
+        assert_eq!(last, Some(later));
+    }
+
     #[test]
     fn clean_lyrics_trims_and_drops_whitespace_only() {
         assert_eq!(clean_lyrics("  hi  ".into()).as_deref(), Some("hi"));
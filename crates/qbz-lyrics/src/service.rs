@@ -7,19 +7,27 @@
 //! ├─ 1. CACHE probe: by track_id, then by cache_key.
 //! │       synced hit -> serve. plain-only hit -> SOFT MISS, continue
 //! │       (uniform for ALL providers including qobuz — Q4).
+//! ├─ 1b. INSTRUMENTAL? (title marker, < 30s, or the caller's flag)
+//! │       -> Instrumental, no network round-trip.
 //! ├─ 2. QOBUZ (PRIMARY) — only for Qobuz-source tracks with a real track_id:
 //! │       wsync -> SYNCED, provider=qobuz -> upsert (+native wsync json,
 //! │       amended Q5), serve. DONE.
 //! │       plain -> HELD as candidate while LRCLIB is probed for synced
 //! │       (the no-sync-regression rule, §1.5).
 //! │       miss / any error -> silent degradation, continue.
-//! ├─ 3. LRCLIB: search-first + scorer, exactly 1 retry on transport error.
-//! │       synced -> serve (provider=lrclib).
-//! │       plain-only with a held qobuz-plain -> prefer the QOBUZ plain.
-//! ├─ 4. lyrics.ovh (plain-only): only if nothing held so far.
+//! ├─ 3. SYNCED-CAPABLE EXTERNALS (LRCLIB, NetEase) in the user's order,
+//! │       concurrently with step 2. LRCLIB: search-first + scorer, exactly
+//! │       1 retry on transport error. First synced -> serve.
+//! │       plain-only -> held; a held qobuz-plain is preferred over it.
+//! ├─ 4. PLAIN-ONLY EXTERNALS (Genius, lyrics.ovh) in the user's order:
+//! │       only if nothing held so far.
 //! └─ 5. UPSERT whatever was served; nothing -> NotFound.
 //! ```
 //!
+//! The external order (and which externals run at all) is user-configurable
+//! via [`LyricsService::set_provider_order`]; it orders providers WITHIN
+//! steps 3 and 4, so a plain-only source can never pre-empt a synced one.
+//!
 //! Fix-forwards baked in: in-flight dedupe keyed by request (F6), request-key
 //! echo on every response for the caller's stale guard (F2), typed offline
 //! status instead of a hardcoded string (F3), explicit raw-title-to-providers
@@ -41,7 +49,7 @@ use crate::cache::{CachedLyrics, LyricsCacheDb, LyricsCacheStats};
 use crate::model::{
     build_cache_key, derive_has_translation, LyricsDoc, LyricsPayload, LyricsProvider,
 };
-use crate::providers::{fetch_lrclib, fetch_lyrics_ovh, LyricsData};
+use crate::providers::{fetch_genius, fetch_lrclib, fetch_lyrics_ovh, fetch_netease, LyricsData};
 use crate::wsync::{translated_from_content, QobuzWsync};
 
 /// What kind of source the playing track comes from. The Qobuz primary step
@@ -53,6 +61,55 @@ pub enum LyricsSourceKind {
     NonQobuz,
 }
 
+/// External fallback provider the user can reorder or switch off. Qobuz is
+/// not one of them: it stays the primary for Qobuz tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalLyricsProvider {
+    Lrclib,
+    NetEase,
+    Genius,
+    Ovh,
+}
+
+impl ExternalLyricsProvider {
+    /// Every external provider in the default order, synced-capable first.
+    /// The opt-in ones ([`Self::is_opt_in`]) are listed but not queried
+    /// until the user enables them.
+    pub const ALL: [Self; 4] = [Self::Lrclib, Self::NetEase, Self::Genius, Self::Ovh];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lrclib => "lrclib",
+            Self::NetEase => "netease",
+            Self::Genius => "genius",
+            Self::Ovh => "ovh",
+        }
+    }
+
+    /// Parse a stored id; unknown ids are dropped by the caller.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.as_str() == value)
+    }
+
+    /// Off by default: NetEase is an unofficial API and Genius is scraped
+    /// from its song pages, so neither is used without the user's say-so.
+    pub fn is_opt_in(&self) -> bool {
+        matches!(self, Self::NetEase | Self::Genius)
+    }
+
+    /// The default chain: [`Self::ALL`] without the opt-in providers.
+    pub fn defaults() -> Vec<Self> {
+        Self::ALL.into_iter().filter(|p| !p.is_opt_in()).collect()
+    }
+
+    /// Whether the provider can return synced LRC (chain step 3) rather than
+    /// plain text only (step 4).
+    pub fn can_sync(&self) -> bool {
+        matches!(self, Self::Lrclib | Self::NetEase)
+    }
+}
+
 /// One lyrics lookup request. `offline` is data, not a lookup — the crate
 /// never reaches into a frontend's offline store (spec §2.2.4); the glue
 /// passes the engine verdict.
@@ -71,6 +128,27 @@ pub struct LyricsRequest {
     /// served document. Only the Qobuz provider is affected — the external
     /// fallback chain never sees it.
     pub language: Option<String>,
+    /// The caller already knows the track has no vocals (e.g. from track
+    /// metadata). One of the [`is_instrumental`] signals.
+    pub instrumental: bool,
+}
+
+/// Title markers of a vocal-free track, matched case-insensitively.
+const INSTRUMENTAL_MARKERS: [&str; 4] = ["instrumental", "reprise", "interlude", "(inst.)"];
+
+/// Tracks shorter than this are skits/intros; not worth a lookup.
+const INSTRUMENTAL_MAX_SECS: u64 = 30;
+
+/// Whether `request` is for a track that won't have lyrics: an instrumental
+/// title marker, a known duration under 30s, or the caller's
+/// [`LyricsRequest::instrumental`] flag.
+pub fn is_instrumental(request: &LyricsRequest) -> bool {
+    let title = request.title.to_lowercase();
+    request.instrumental
+        || INSTRUMENTAL_MARKERS.iter().any(|m| title.contains(m))
+        || request
+            .duration_secs
+            .is_some_and(|secs| secs < INSTRUMENTAL_MAX_SECS)
 }
 
 /// Served lyrics: the wire-compatible payload plus the parsed document
@@ -88,6 +166,8 @@ pub enum LyricsOutcome {
     /// Offline and nothing cached — the UI maps this to a translated string
     /// (fix F3; never a hardcoded message).
     NotAvailableOffline,
+    /// [`is_instrumental`] matched and nothing was cached; no provider ran.
+    Instrumental,
 }
 
 /// Response envelope. `request_track_id`/`request_key` echo the request so
@@ -125,6 +205,13 @@ pub trait LyricsProviders: Send + Sync {
 
     /// lyrics.ovh, plain-only.
     async fn ovh(&self, title: &str, artist: &str) -> Option<LyricsData>;
+
+    /// NetEase Cloud Music (synced). `Ok(None)` = no match; `Err` =
+    /// transport error (no retry — it is never the only synced source).
+    async fn netease(&self, title: &str, artist: &str) -> Result<Option<LyricsData>, String>;
+
+    /// Genius page scrape, plain-only.
+    async fn genius(&self, title: &str, artist: &str) -> Option<LyricsData>;
 }
 
 /// Production providers: Qobuz via the shared client, externals via the
@@ -164,11 +251,21 @@ impl LyricsProviders for HttpLyricsProviders {
     async fn ovh(&self, title: &str, artist: &str) -> Option<LyricsData> {
         fetch_lyrics_ovh(title, artist).await
     }
+
+    async fn netease(&self, title: &str, artist: &str) -> Result<Option<LyricsData>, String> {
+        fetch_netease(title, artist).await
+    }
+
+    async fn genius(&self, title: &str, artist: &str) -> Option<LyricsData> {
+        fetch_genius(title, artist).await
+    }
 }
 
 struct ServiceInner {
     providers: Arc<dyn LyricsProviders>,
     db: Mutex<Option<LyricsCacheDb>>,
+    /// Enabled external providers, in the order they are tried.
+    order: StdMutex<Vec<ExternalLyricsProvider>>,
 }
 
 type SharedLyricsFuture =
@@ -188,6 +285,7 @@ impl LyricsService {
             inner: Arc::new(ServiceInner {
                 providers,
                 db: Mutex::new(None),
+                order: StdMutex::new(ExternalLyricsProvider::defaults()),
             }),
             inflight: StdMutex::new(HashMap::new()),
         }
@@ -231,6 +329,30 @@ impl LyricsService {
         db.stats()
     }
 
+    /// Set the external providers to try and their order. Providers left
+    /// out are disabled; duplicates keep their first position. Applies to
+    /// the next resolution (cached entries are served as before).
+    pub fn set_provider_order(&self, order: Vec<ExternalLyricsProvider>) {
+        let mut deduped: Vec<ExternalLyricsProvider> = Vec::with_capacity(order.len());
+        for provider in order {
+            if !deduped.contains(&provider) {
+                deduped.push(provider);
+            }
+        }
+        if let Ok(mut guard) = self.inner.order.lock() {
+            *guard = deduped;
+        }
+    }
+
+    /// Current external provider order.
+    pub fn provider_order(&self) -> Vec<ExternalLyricsProvider> {
+        self.inner
+            .order
+            .lock()
+            .map(|order| order.clone())
+            .unwrap_or_else(|_| ExternalLyricsProvider::defaults())
+    }
+
     /// Resolve lyrics for a request through the §1.1 chain. Concurrent calls
     /// for the same request join one in-flight resolution (F6).
     pub async fn get(&self, request: LyricsRequest) -> Result<LyricsResponse, String> {
@@ -320,6 +442,36 @@ fn cached_result(cached: CachedLyrics, requested_lang: Option<&str>) -> LyricsRe
     }
 }
 
+/// LRCLIB with exactly 1 retry on transport error (parity:
+/// legacy_compat.rs:519-536 — Ok(None) is a miss, NOT a retry).
+async fn lrclib_with_retry(
+    inner: &ServiceInner,
+    title: &str,
+    artist: &str,
+    duration_secs: Option<u64>,
+) -> Option<LyricsData> {
+    match inner.providers.lrclib(title, artist, duration_secs).await {
+        Ok(data) => data,
+        Err(e) => {
+            log::warn!("[Lyrics] LRCLIB attempt 1 failed: {}, retrying…", e);
+            match inner.providers.lrclib(title, artist, duration_secs).await {
+                Ok(data) => data,
+                Err(e2) => {
+                    log::warn!("[Lyrics] LRCLIB attempt 2 failed: {}, falling back", e2);
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn has_synced_lrc(data: &LyricsData) -> bool {
+    data.synced_lrc
+        .as_ref()
+        .map(|s| !s.trim().is_empty())
+        .unwrap_or(false)
+}
+
 async fn run_chain(
     inner: Arc<ServiceInner>,
     request: LyricsRequest,
@@ -363,7 +515,7 @@ async fn run_chain(
     // 1. Cache probe: by track_id first, then by key. A plain-only entry is a
     //    SOFT MISS and falls through the chain — uniform for every provider
     //    including 'qobuz' (Q4: preserves the synced-upgrade path).
-    {
+    let soft_miss = {
         let guard = inner.db.lock().await;
         let db = guard.as_ref().ok_or(NO_SESSION)?;
         let cached = match request.track_id {
@@ -387,8 +539,22 @@ async fn run_chain(
             }
             // plain-only cache — or a synced entry without a translation for
             // the requested language: fall through to re-fetch (spec §A.5).
+            Some(cached)
+        } else {
+            None
         }
-    } // lock released before any network round-trip
+    }; // lock released before any network round-trip
+
+    // 1b. Instrumental -> no lookup. Whatever is cached (even a plain-only
+    //     soft miss, e.g. a vocal "Reprise") is served instead of the verdict.
+    if is_instrumental(&request) {
+        return Ok(respond(match soft_miss {
+            Some(cached) => {
+                LyricsOutcome::Found(cached_result(cached, request.language.as_deref()))
+            }
+            None => LyricsOutcome::Instrumental,
+        }));
+    }
 
     let base_payload = |provider: LyricsProvider| LyricsPayload {
         track_id: request.track_id,
@@ -402,14 +568,15 @@ async fn run_chain(
         cached: false,
     };
 
-    // 2+3. QOBUZ primary + LRCLIB run CONCURRENTLY. Running them in parallel
-    //       means a Qobuz MISS adds NO latency before LRCLIB (matching Tauri's
-    //       speed, which has no Qobuz step) while still preferring Qobuz wsync
-    //       when present. The SELECTION below is unchanged from the sequential
-    //       version: Qobuz wsync wins outright; otherwise an LRCLIB *synced*
-    //       result beats a held Qobuz-plain (no-sync-regression), which beats
-    //       an LRCLIB plain-only / miss; lyrics.ovh is the last resort. Qobuz
-    //       only runs for Qobuz-source tracks with a real track_id.
+    // 2+3. QOBUZ primary + the synced-capable externals run CONCURRENTLY.
+    //       Running them in parallel means a Qobuz MISS adds NO latency
+    //       before LRCLIB (matching Tauri's speed, which has no Qobuz step)
+    //       while still preferring Qobuz wsync when present. The SELECTION
+    //       below is unchanged from the sequential version: Qobuz wsync wins
+    //       outright; otherwise an external *synced* result beats a held
+    //       Qobuz-plain (no-sync-regression), which beats an external
+    //       plain-only / miss; the plain-only externals are the last resort.
+    //       Qobuz only runs for Qobuz-source tracks with a real track_id.
     let qobuz_fut = async {
         if request.source == LyricsSourceKind::Qobuz {
             if let Some(track_id) = request.track_id {
@@ -430,27 +597,46 @@ async fn run_chain(
         }
         None
     };
-    // LRCLIB with exactly 1 retry on transport error (parity:
-    // legacy_compat.rs:519-536 — Ok(None) is a miss, NOT a retry).
-    let lrclib_fut = async {
-        match inner.providers.lrclib(&title, &artist, request.duration_secs).await {
-            Ok(data) => data,
-            Err(e) => {
-                log::warn!("[Lyrics] LRCLIB attempt 1 failed: {}, retrying…", e);
-                match inner.providers.lrclib(&title, &artist, request.duration_secs).await {
-                    Ok(data) => data,
-                    Err(e2) => {
-                        log::warn!("[Lyrics] LRCLIB attempt 2 failed: {}, falling back", e2);
-                        None
+    let order = inner
+        .order
+        .lock()
+        .map(|order| order.clone())
+        .unwrap_or_else(|_| ExternalLyricsProvider::defaults());
+    // Synced-capable externals in the user's order: the first synced result
+    // ends the walk, the first plain-only one is held in case none syncs.
+    let external_fut = async {
+        let mut held_plain: Option<LyricsData> = None;
+        for provider in order.iter().filter(|p| p.can_sync()) {
+            let data = match provider {
+                ExternalLyricsProvider::Lrclib => {
+                    lrclib_with_retry(&inner, &title, &artist, request.duration_secs).await
+                }
+                ExternalLyricsProvider::NetEase => {
+                    match inner.providers.netease(&title, &artist).await {
+                        Ok(data) => data,
+                        Err(e) => {
+                            log::warn!("[Lyrics] NetEase failed: {}, falling back", e);
+                            None
+                        }
                     }
                 }
+                ExternalLyricsProvider::Genius | ExternalLyricsProvider::Ovh => None,
+            };
+            if let Some(data) = data {
+                if has_synced_lrc(&data) {
+                    return Some(data);
+                }
+                if held_plain.is_none() {
+                    held_plain = Some(data);
+                }
             }
         }
+        held_plain
     };
-    let (qobuz_doc, lrclib_data) = tokio::join!(qobuz_fut, lrclib_fut);
+    let (qobuz_doc, external_data) = tokio::join!(qobuz_fut, external_fut);
 
     // Apply the Qobuz result: wsync ends the chain; plain is HELD as a
-    // candidate while we consider an LRCLIB synced result below.
+    // candidate while we consider an external synced result below.
     let mut qobuz_plain_candidate: Option<LyricsResult> = None;
     if let Some(document) = qobuz_doc {
         if let Some(wsync) = QobuzWsync::from_document(&document) {
@@ -493,15 +679,10 @@ async fn run_chain(
         }
     }
 
-    if let Some(data) = lrclib_data {
-        let has_synced = data
-            .synced_lrc
-            .as_ref()
-            .map(|s| !s.trim().is_empty())
-            .unwrap_or(false);
-        // Plain-only LRCLIB while a qobuz-plain candidate is held -> prefer
+    if let Some(data) = external_data {
+        // Plain-only external while a qobuz-plain candidate is held -> prefer
         // the QOBUZ plain (first-party, line-split natively — §1.1 step 3).
-        if has_synced || qobuz_plain_candidate.is_none() {
+        if has_synced_lrc(&data) || qobuz_plain_candidate.is_none() {
             let mut payload = base_payload(data.provider);
             payload.plain = data.plain;
             payload.synced_lrc = data.synced_lrc;
@@ -515,8 +696,9 @@ async fn run_chain(
         }
     }
 
-    // Held qobuz-plain candidate wins over an LRCLIB plain-only result and
-    // over an LRCLIB miss; lyrics.ovh runs only when nothing is held (§1.1).
+    // Held qobuz-plain candidate wins over an external plain-only result and
+    // over an external miss; the plain-only externals run only when nothing
+    // is held (§1.1).
     if let Some(result) = qobuz_plain_candidate {
         let guard = inner.db.lock().await;
         let db = guard.as_ref().ok_or(NO_SESSION)?;
@@ -524,8 +706,16 @@ async fn run_chain(
         return Ok(respond(LyricsOutcome::Found(result)));
     }
 
-    // 4. lyrics.ovh (plain-only fallback #2).
-    if let Some(data) = inner.providers.ovh(&title, &artist).await {
+    // 4. Plain-only externals (Genius, lyrics.ovh) in the user's order.
+    for provider in order.iter().filter(|p| !p.can_sync()) {
+        let data = match provider {
+            ExternalLyricsProvider::Genius => inner.providers.genius(&title, &artist).await,
+            ExternalLyricsProvider::Ovh => inner.providers.ovh(&title, &artist).await,
+            ExternalLyricsProvider::Lrclib | ExternalLyricsProvider::NetEase => None,
+        };
+        let Some(data) = data else {
+            continue;
+        };
         let mut payload = base_payload(data.provider);
         payload.plain = data.plain;
         payload.synced_lrc = data.synced_lrc;
@@ -555,9 +745,13 @@ mod tests {
         qobuz_queue: StdMutex<VecDeque<Result<Option<QobuzLyricsDocument>, String>>>,
         lrclib_queue: StdMutex<VecDeque<Result<Option<LyricsData>, String>>>,
         ovh_queue: StdMutex<VecDeque<Option<LyricsData>>>,
+        netease_queue: StdMutex<VecDeque<Result<Option<LyricsData>, String>>>,
+        genius_queue: StdMutex<VecDeque<Option<LyricsData>>>,
         qobuz_calls: AtomicUsize,
         lrclib_calls: AtomicUsize,
         ovh_calls: AtomicUsize,
+        netease_calls: AtomicUsize,
+        genius_calls: AtomicUsize,
         /// Language argument observed on each qobuz call (threading proof).
         qobuz_languages: StdMutex<Vec<Option<String>>>,
         delay_ms: Option<u64>,
@@ -603,6 +797,24 @@ mod tests {
             self.ovh_calls.fetch_add(1, Ordering::SeqCst);
             self.ovh_queue.lock().unwrap().pop_front().unwrap_or(None)
         }
+
+        async fn netease(&self, _title: &str, _artist: &str) -> Result<Option<LyricsData>, String> {
+            self.netease_calls.fetch_add(1, Ordering::SeqCst);
+            self.netease_queue
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or(Ok(None))
+        }
+
+        async fn genius(&self, _title: &str, _artist: &str) -> Option<LyricsData> {
+            self.genius_calls.fetch_add(1, Ordering::SeqCst);
+            self.genius_queue
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or(None)
+        }
     }
 
     fn wsync_document() -> QobuzLyricsDocument {
@@ -695,6 +907,7 @@ mod tests {
             duration_secs: Some(200),
             offline: false,
             language: None,
+            instrumental: false,
         }
     }
 
@@ -899,6 +1112,101 @@ mod tests {
         assert_eq!(providers.lrclib_calls.load(Ordering::SeqCst), 2);
     }
 
+    // ---------- external provider order ----------
+
+    fn netease_synced() -> LyricsData {
+        LyricsData {
+            plain: Some("ne one".into()),
+            synced_lrc: Some("[00:01.00] ne one".into()),
+            provider: LyricsProvider::NetEase,
+        }
+    }
+
+    #[tokio::test]
+    async fn lrclib_plain_keeps_looking_for_a_synced_external() {
+        let providers = Arc::new(FakeProviders::default());
+        providers
+            .lrclib_queue
+            .lock()
+            .unwrap()
+            .push_back(Ok(Some(lrclib_plain())));
+        providers
+            .netease_queue
+            .lock()
+            .unwrap()
+            .push_back(Ok(Some(netease_synced())));
+        let (service, _dir) = service_with(providers.clone()).await;
+        service.set_provider_order(ExternalLyricsProvider::ALL.to_vec());
+
+        let response = service.get(request()).await.unwrap();
+        let result = found(&response);
+        assert_eq!(result.payload.provider, LyricsProvider::NetEase);
+        assert!(result.doc.synced);
+        assert_eq!(providers.genius_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn opt_in_providers_are_off_by_default() {
+        let providers = Arc::new(FakeProviders::default());
+        providers
+            .netease_queue
+            .lock()
+            .unwrap()
+            .push_back(Ok(Some(netease_synced())));
+        providers.ovh_queue.lock().unwrap().push_back(Some(ovh_plain()));
+        let (service, _dir) = service_with(providers.clone()).await;
+
+        let response = service.get(request()).await.unwrap();
+        assert_eq!(found(&response).payload.provider, LyricsProvider::Ovh);
+        assert_eq!(providers.netease_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(providers.genius_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn provider_order_is_respected_and_omitted_providers_never_run() {
+        let providers = Arc::new(FakeProviders::default());
+        providers
+            .netease_queue
+            .lock()
+            .unwrap()
+            .push_back(Ok(Some(netease_synced())));
+        let (service, _dir) = service_with(providers.clone()).await;
+        service.set_provider_order(vec![
+            ExternalLyricsProvider::NetEase,
+            ExternalLyricsProvider::Ovh,
+            ExternalLyricsProvider::NetEase,
+        ]);
+        assert_eq!(
+            service.provider_order(),
+            vec![ExternalLyricsProvider::NetEase, ExternalLyricsProvider::Ovh]
+        );
+
+        let response = service.get(request()).await.unwrap();
+        assert_eq!(found(&response).payload.provider, LyricsProvider::NetEase);
+        assert_eq!(providers.lrclib_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(providers.ovh_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn plain_only_externals_follow_the_order() {
+        let providers = Arc::new(FakeProviders::default());
+        providers.ovh_queue.lock().unwrap().push_back(Some(ovh_plain()));
+        providers.genius_queue.lock().unwrap().push_back(Some(LyricsData {
+            plain: Some("genius plain".into()),
+            synced_lrc: None,
+            provider: LyricsProvider::Genius,
+        }));
+        let (service, _dir) = service_with(providers.clone()).await;
+        service.set_provider_order(vec![
+            ExternalLyricsProvider::Ovh,
+            ExternalLyricsProvider::Genius,
+        ]);
+
+        let response = service.get(request()).await.unwrap();
+        assert_eq!(found(&response).payload.provider, LyricsProvider::Ovh);
+        assert_eq!(providers.genius_calls.load(Ordering::SeqCst), 0);
+    }
+
     // ---------- translation (v10) ----------
 
     #[tokio::test]
@@ -1159,6 +1467,51 @@ mod tests {
         assert_eq!(providers.ovh_calls.load(Ordering::SeqCst), 0);
     }
 
+    // ---------- instrumental ----------
+
+    #[test]
+    fn instrumental_signals() {
+        assert!(!is_instrumental(&request()));
+        for title in [
+            "Song (Instrumental)",
+            "Theme - Reprise",
+            "INTERLUDE",
+            "Song (Inst.)",
+        ] {
+            let mut r = request();
+            r.title = title.into();
+            assert!(is_instrumental(&r), "{title}");
+        }
+        let mut short = request();
+        short.duration_secs = Some(29);
+        assert!(is_instrumental(&short));
+        short.duration_secs = None;
+        assert!(!is_instrumental(&short));
+        let mut flagged = request();
+        flagged.instrumental = true;
+        assert!(is_instrumental(&flagged));
+    }
+
+    #[tokio::test]
+    async fn instrumental_skips_every_provider_but_not_the_cache() {
+        let providers = Arc::new(FakeProviders::default());
+        providers.ovh_queue.lock().unwrap().push_back(Some(ovh_plain()));
+        let (service, _dir) = service_with(providers.clone()).await;
+
+        let mut interlude = request();
+        interlude.title = "Interlude".into();
+        let response = service.get(interlude.clone()).await.unwrap();
+        assert_eq!(response.outcome, LyricsOutcome::Instrumental);
+        assert_eq!(providers.qobuz_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(providers.lrclib_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(providers.ovh_calls.load(Ordering::SeqCst), 0);
+
+        // Once something is cached for the track it wins over the heuristic.
+        service.get(request()).await.unwrap();
+        let response = service.get(interlude).await.unwrap();
+        assert!(matches!(response.outcome, LyricsOutcome::Found(_)));
+    }
+
     // ---------- gating & validation ----------
 
     #[tokio::test]
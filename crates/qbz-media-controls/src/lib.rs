@@ -13,7 +13,7 @@
 use std::sync::Arc;
 
 mod types;
-pub use types::{MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
+pub use types::{LoopMode, MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
 
 pub mod notify;
 pub use notify::{show_track_notification, NotificationMeta};
@@ -52,3 +52,30 @@ pub fn spawn(
         None
     }
 }
+
+/// Qobuz track id from a [`MediaEvent::AddTrack`] URI: the app's own
+/// `qobuzapp://track/<id>` scheme or an open/play.qobuz.com track link.
+pub fn track_id_from_uri(uri: &str) -> Option<u64> {
+    let rest = ["qobuzapp://", "https://open.qobuz.com/", "https://play.qobuz.com/"]
+        .iter()
+        .find_map(|prefix| uri.strip_prefix(prefix))?;
+    let id = rest.strip_prefix("track/")?;
+    id.split(['?', '#', '/']).next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_id_from_uri_accepts_qobuz_track_links_only() {
+        assert_eq!(track_id_from_uri("qobuzapp://track/12345"), Some(12345));
+        assert_eq!(
+            track_id_from_uri("https://open.qobuz.com/track/59954869?utm=x"),
+            Some(59954869)
+        );
+        assert_eq!(track_id_from_uri("https://play.qobuz.com/track/7/"), Some(7));
+        assert_eq!(track_id_from_uri("https://open.qobuz.com/album/kq3s910v1qufc"), None);
+        assert_eq!(track_id_from_uri("file:///music/a.flac"), None);
+    }
+}
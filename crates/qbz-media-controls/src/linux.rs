@@ -8,6 +8,12 @@
 //! souvlaki never sets it, so GNOME shows no icon. (KDE is lenient and works
 //! either way.) `mpris:artUrl` is album art — separate and unaffected.
 //!
+//! The server also publishes `org.mpris.MediaPlayer2.TrackList` (current track
+//! plus the upcoming queue) so remote controllers (KDE Media Controller,
+//! playerctl) can browse and edit the queue. Track ids there are positional
+//! (`.../tracklist/<n>`, 0 = current) and the whole list is replaced on every
+//! queue change, so a stale id from a client simply misses.
+//!
 //! The server runs on a dedicated thread with its own current-thread tokio
 //! runtime (the workspace forces zbus 4's `tokio` feature via qbz-audio, so a
 //! tokio context must be present); state updates arrive over an async channel.
@@ -20,15 +26,19 @@ use std::sync::{
 use mpris_server::zbus::{self, fdo};
 use mpris_server::{
     LoopStatus, Metadata, PlaybackRate, PlaybackStatus as MprisStatus, PlayerInterface, Property,
-    RootInterface, Server, Time, TrackId, Volume,
+    RootInterface, Server, Time, TrackId, TrackListInterface, TrackListProperty, Uri, Volume,
 };
 
 use crate::inhibit::SleepInhibitor;
-use crate::types::{MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
+use crate::types::{LoopMode, MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
 
 const BUS_SUFFIX: &str = "com.blitzfc.qbz";
 const DESKTOP_ENTRY: &str = "com.blitzfc.qbz";
 const IDENTITY: &str = "QBZ";
+const TRACKLIST_PREFIX: &str = "/com/blitzfc/qbz/tracklist/";
+/// Cap on published TrackList entries: a several-thousand-track queue would
+/// turn every queue edit into a large D-Bus payload for no client benefit.
+const TRACKLIST_MAX: usize = 200;
 
 /// Monotonic counter so each track gets a distinct `mpris:trackid` object path
 /// (helps clients detect track changes).
@@ -44,6 +54,10 @@ struct State {
     status: MprisStatus,
     volume: Volume,
     position: Time,
+    /// Published TrackList: current track first, then upcoming
+    tracks: Vec<Metadata>,
+    loop_status: LoopStatus,
+    shuffle: bool,
 }
 
 /// Update commands sent from the app to the server thread.
@@ -54,6 +68,9 @@ enum Update {
         position: Option<Time>,
     },
     Volume(Volume),
+    TrackList(Vec<Metadata>),
+    LoopStatus(LoopStatus),
+    Shuffle(bool),
 }
 
 /// The cloneable handle returned to the app. Pushing state is a non-blocking
@@ -77,6 +94,24 @@ impl MediaIntegration for LinuxHandle {
     fn set_volume(&self, vol: f64) {
         let _ = self.tx.try_send(Update::Volume(vol.clamp(0.0, 1.0)));
     }
+
+    fn set_track_list(&self, tracks: &[TrackMeta]) {
+        let list = tracks
+            .iter()
+            .take(TRACKLIST_MAX)
+            .enumerate()
+            .map(|(position, meta)| metadata_with_id(meta, tracklist_id(position)))
+            .collect();
+        let _ = self.tx.try_send(Update::TrackList(list));
+    }
+
+    fn set_loop_status(&self, mode: LoopMode) {
+        let _ = self.tx.try_send(Update::LoopStatus(to_mpris_loop(mode)));
+    }
+
+    fn set_shuffle(&self, enabled: bool) {
+        let _ = self.tx.try_send(Update::Shuffle(enabled));
+    }
 }
 
 fn map_status(s: PlaybackStatus) -> MprisStatus {
@@ -87,11 +122,41 @@ fn map_status(s: PlaybackStatus) -> MprisStatus {
     }
 }
 
+fn to_mpris_loop(mode: LoopMode) -> LoopStatus {
+    match mode {
+        LoopMode::None => LoopStatus::None,
+        LoopMode::Track => LoopStatus::Track,
+        LoopMode::Playlist => LoopStatus::Playlist,
+    }
+}
+
+fn from_mpris_loop(status: LoopStatus) -> LoopMode {
+    match status {
+        LoopStatus::None => LoopMode::None,
+        LoopStatus::Track => LoopMode::Track,
+        LoopStatus::Playlist => LoopMode::Playlist,
+    }
+}
+
 fn build_metadata(meta: &TrackMeta) -> Metadata {
     let seq = TRACK_SEQ.fetch_add(1, Ordering::Relaxed);
     let trackid = TrackId::try_from(format!("/com/blitzfc/qbz/track/{seq}"))
         .unwrap_or(TrackId::NO_TRACK);
+    metadata_with_id(meta, trackid)
+}
+
+/// TrackList object path for a queue position (0 = current track).
+fn tracklist_id(position: usize) -> TrackId {
+    TrackId::try_from(format!("{TRACKLIST_PREFIX}{position}")).unwrap_or(TrackId::NO_TRACK)
+}
 
+/// Queue position of a TrackList object path; `None` for foreign ids and
+/// `/org/mpris/MediaPlayer2/TrackList/NoTrack`.
+fn tracklist_position(id: &TrackId) -> Option<usize> {
+    id.as_str().strip_prefix(TRACKLIST_PREFIX)?.parse().ok()
+}
+
+fn metadata_with_id(meta: &TrackMeta, trackid: TrackId) -> Metadata {
     let mut b = Metadata::builder().trackid(trackid).title(meta.title.clone());
     if !meta.artist.is_empty() {
         b = b.artist([meta.artist.clone()]);
@@ -146,7 +211,7 @@ impl RootInterface for QbzMpris {
         Ok(true)
     }
     async fn has_track_list(&self) -> fdo::Result<bool> {
-        Ok(false)
+        Ok(true)
     }
     async fn identity(&self) -> fdo::Result<String> {
         Ok(IDENTITY.to_string())
@@ -203,9 +268,12 @@ impl PlayerInterface for QbzMpris {
         Ok(self.state.lock().unwrap().status)
     }
     async fn loop_status(&self) -> fdo::Result<LoopStatus> {
-        Ok(LoopStatus::None)
+        Ok(self.state.lock().unwrap().loop_status)
     }
-    async fn set_loop_status(&self, _loop_status: LoopStatus) -> zbus::Result<()> {
+    /// Forwarded to the app; the property itself only changes once the app
+    /// echoes the new mode back through `set_loop_status`.
+    async fn set_loop_status(&self, loop_status: LoopStatus) -> zbus::Result<()> {
+        self.emit(MediaEvent::SetLoopStatus(from_mpris_loop(loop_status)));
         Ok(())
     }
     async fn rate(&self) -> fdo::Result<PlaybackRate> {
@@ -215,9 +283,10 @@ impl PlayerInterface for QbzMpris {
         Ok(())
     }
     async fn shuffle(&self) -> fdo::Result<bool> {
-        Ok(false)
+        Ok(self.state.lock().unwrap().shuffle)
     }
-    async fn set_shuffle(&self, _shuffle: bool) -> zbus::Result<()> {
+    async fn set_shuffle(&self, shuffle: bool) -> zbus::Result<()> {
+        self.emit(MediaEvent::SetShuffle(shuffle));
         Ok(())
     }
     async fn metadata(&self) -> fdo::Result<Metadata> {
@@ -259,6 +328,54 @@ impl PlayerInterface for QbzMpris {
     }
 }
 
+impl TrackListInterface for QbzMpris {
+    async fn get_tracks_metadata(&self, track_ids: Vec<TrackId>) -> fdo::Result<Vec<Metadata>> {
+        let st = self.state.lock().unwrap();
+        Ok(track_ids
+            .iter()
+            .filter_map(|id| st.tracks.get(tracklist_position(id)?).cloned())
+            .collect())
+    }
+    async fn add_track(
+        &self,
+        uri: Uri,
+        after_track: TrackId,
+        set_as_current: bool,
+    ) -> fdo::Result<()> {
+        // The queue can only insert after the current track or append:
+        // NoTrack (list start) and the current track map to "next", any
+        // later anchor appends.
+        let next = matches!(tracklist_position(&after_track), None | Some(0));
+        self.emit(MediaEvent::AddTrack {
+            uri,
+            next,
+            play_now: set_as_current,
+        });
+        Ok(())
+    }
+    async fn remove_track(&self, track_id: TrackId) -> fdo::Result<()> {
+        match tracklist_position(&track_id) {
+            // The current track is not removable from the queue
+            Some(0) | None => {}
+            Some(position) => self.emit(MediaEvent::RemoveUpcoming(position - 1)),
+        }
+        Ok(())
+    }
+    async fn go_to(&self, track_id: TrackId) -> fdo::Result<()> {
+        if let Some(position) = tracklist_position(&track_id).filter(|p| *p > 0) {
+            self.emit(MediaEvent::PlayUpcoming(position - 1));
+        }
+        Ok(())
+    }
+    async fn tracks(&self) -> fdo::Result<Vec<TrackId>> {
+        let len = self.state.lock().unwrap().tracks.len();
+        Ok((0..len).map(tracklist_id).collect())
+    }
+    async fn can_edit_tracks(&self) -> fdo::Result<bool> {
+        Ok(true)
+    }
+}
+
 async fn apply(server: &Server<QbzMpris>, state: &Arc<Mutex<State>>, update: Update) {
     match update {
         Update::Metadata(m) => {
@@ -266,21 +383,42 @@ async fn apply(server: &Server<QbzMpris>, state: &Arc<Mutex<State>>, update: Upd
             let _ = server.properties_changed([Property::Metadata(m)]).await;
         }
         Update::Playback { status, position } => {
-            {
+            let changed = {
                 let mut st = state.lock().unwrap();
-                st.status = status;
                 if let Some(p) = position {
                     st.position = p;
                 }
+                std::mem::replace(&mut st.status, status) != status
+            };
+            // Position is polled by clients, not signalled; periodic
+            // position refreshes must not re-announce an unchanged status.
+            if changed {
+                let _ = server
+                    .properties_changed([Property::PlaybackStatus(status)])
+                    .await;
             }
-            let _ = server
-                .properties_changed([Property::PlaybackStatus(status)])
-                .await;
         }
         Update::Volume(v) => {
             state.lock().unwrap().volume = v;
             let _ = server.properties_changed([Property::Volume(v)]).await;
         }
+        Update::LoopStatus(l) => {
+            state.lock().unwrap().loop_status = l;
+            let _ = server.properties_changed([Property::LoopStatus(l)]).await;
+        }
+        Update::Shuffle(on) => {
+            state.lock().unwrap().shuffle = on;
+            let _ = server.properties_changed([Property::Shuffle(on)]).await;
+        }
+        Update::TrackList(list) => {
+            let ids: Vec<TrackId> = (0..list.len()).map(tracklist_id).collect();
+            let current = ids.first().cloned().unwrap_or(TrackId::NO_TRACK);
+            state.lock().unwrap().tracks = list;
+            let _ = server
+                .track_list_properties_changed([TrackListProperty::Tracks(ids.clone())])
+                .await;
+            let _ = server.track_list_replaced(ids, current).await;
+        }
     }
 }
 
@@ -309,12 +447,15 @@ pub fn spawn(on_event: EventCb) -> Option<LinuxHandle> {
                     status: MprisStatus::Stopped,
                     volume: 1.0,
                     position: Time::ZERO,
+                    tracks: Vec::new(),
+                    loop_status: LoopStatus::None,
+                    shuffle: false,
                 }));
                 let imp = QbzMpris {
                     on_event,
                     state: state.clone(),
                 };
-                let server = match Server::new(BUS_SUFFIX, imp).await {
+                let server = match Server::new_with_track_list(BUS_SUFFIX, imp).await {
                     Ok(s) => s,
                     Err(e) => {
                         log::error!("[mpris] failed to register org.mpris.MediaPlayer2.{BUS_SUFFIX}: {e}");
@@ -349,3 +490,22 @@ pub fn spawn(on_event: EventCb) -> Option<LinuxHandle> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loop_status_round_trips_both_directions() {
+        for mode in [LoopMode::None, LoopMode::Track, LoopMode::Playlist] {
+            assert_eq!(from_mpris_loop(to_mpris_loop(mode)), mode);
+        }
+    }
+
+    #[test]
+    fn tracklist_ids_map_back_to_positions() {
+        assert_eq!(tracklist_position(&tracklist_id(0)), Some(0));
+        assert_eq!(tracklist_position(&tracklist_id(42)), Some(42));
+        assert_eq!(tracklist_position(&TrackId::NO_TRACK), None);
+    }
+}
@@ -22,6 +22,14 @@ pub enum PlaybackStatus {
     Stopped,
 }
 
+/// Repeat mode as the OS media controls model it (MPRIS `LoopStatus`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    None,
+    Track,
+    Playlist,
+}
+
 /// Inbound events from the OS media controls (media keys, GNOME/KDE widget,
 /// macOS Now Playing, Windows SMTC). Delivered to the callback passed to
 /// [`crate::spawn`]. All time values are MICROSECONDS.
@@ -41,6 +49,21 @@ pub enum MediaEvent {
     SetVolume(f64),
     Raise,
     Quit,
+    /// Jump to the upcoming track at this index (MPRIS TrackList `GoTo`).
+    PlayUpcoming(usize),
+    /// Remove the upcoming track at this index (MPRIS TrackList `RemoveTrack`).
+    RemoveUpcoming(usize),
+    /// Add a track by URI (MPRIS TrackList `AddTrack`). `next` inserts it right
+    /// after the current track instead of appending; `play_now` jumps to it.
+    AddTrack {
+        uri: String,
+        next: bool,
+        play_now: bool,
+    },
+    /// Set the repeat mode (MPRIS `LoopStatus` write).
+    SetLoopStatus(LoopMode),
+    /// Enable/disable shuffle (MPRIS `Shuffle` write).
+    SetShuffle(bool),
 }
 
 /// A live handle to the OS media-controls integration. Cloneable callers hold
@@ -50,4 +73,12 @@ pub trait MediaIntegration: Send + Sync {
     fn set_metadata(&self, meta: &TrackMeta);
     fn set_playback(&self, status: PlaybackStatus, position: Option<Duration>);
     fn set_volume(&self, vol: f64);
+    /// Publish the play queue: the current track first, then the upcoming
+    /// tracks in play order. Only the Linux MPRIS backend exposes a track
+    /// list; elsewhere this is a no-op.
+    fn set_track_list(&self, _tracks: &[TrackMeta]) {}
+    /// Reflect the app's repeat mode. MPRIS only; no-op elsewhere.
+    fn set_loop_status(&self, _mode: LoopMode) {}
+    /// Reflect the app's shuffle state. MPRIS only; no-op elsewhere.
+    fn set_shuffle(&self, _enabled: bool) {}
 }
@@ -5,7 +5,7 @@
 
 use serde::Serialize;
 
-use crate::playback::{PlaybackState, PlaybackStatus, QueueState, QueueTrack};
+use crate::playback::{PlaybackState, PlaybackStatus, QueueState, QueueStats, QueueTrack};
 use crate::types::{Playlist, SearchResults, UserSession};
 
 /// All events emitted by QBZ core to frontends
@@ -41,6 +41,9 @@ pub enum CoreEvent {
     /// Queue state changed (tracks added/removed/reordered)
     QueueUpdated { state: QueueState },
 
+    /// Queue totals changed (tracks set/added/removed)
+    QueueStatsChanged { stats: QueueStats },
+
     /// Shuffle mode changed
     ShuffleChanged { enabled: bool },
 
@@ -105,12 +108,25 @@ pub enum CoreEvent {
     /// Audio device changed
     AudioDeviceChanged { device_name: String },
 
+    /// ALSA hot-plug: a playback device (card number) appeared or vanished.
+    /// `device_lost` is set when it was the active output and playback was
+    /// moved to the default device.
+    AudioDeviceHotPlug {
+        card: String,
+        connected: bool,
+        device_lost: bool,
+    },
+
     /// Audio backend changed
     AudioBackendChanged {
         /// Backend name: "pipewire", "alsa", "pulse"
         backend: String,
     },
 
+    /// Background loudness analysis cached a gain for a track; its next
+    /// play starts normalized at this adjustment
+    LoudnessAnalyzed { track_id: u64, gain_db: f32 },
+
     /// Audio system diagnostic info
     AudioDiagnostic { message: String },
 
@@ -43,7 +43,9 @@ pub mod types;
 pub use error::{QbzError, QbzResult};
 pub use events::CoreEvent;
 pub use lenient::{parse_items_array, parse_items_lenient};
-pub use playback::{PlaybackState, PlaybackStatus, QueueState, QueueTrack, RepeatMode};
+pub use playback::{
+    PlaybackState, PlaybackStatus, QueueState, QueueStats, QueueTrack, RepeatMode,
+};
 pub use source::{plex_thumb_url, ArtworkRef, PlaybackSource, TrackOriginTag};
 pub use traits::{FrontendAdapter, LoggingAdapter, NoOpAdapter};
 pub use types::{
@@ -120,6 +122,7 @@ pub use types::{
     PurchaseResponse,
     PurchaseTrack,
     PlaylistDuplicateResult,
+    PlaylistExportSummary,
     PlaylistGenre,
     PlaylistOwner,
     PlaylistTag,
@@ -146,4 +149,5 @@ pub use types::{
     TrackToAnalyse,
     TracksContainer,
     UserSession,
+    WorkMetadata,
 };
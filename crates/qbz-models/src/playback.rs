@@ -4,6 +4,7 @@
 //! - Queue track representation
 //! - Repeat mode
 //! - Queue state snapshots
+//! - Queue stats (length and format breakdown)
 //! - Playback state
 
 use serde::{Deserialize, Serialize};
@@ -102,6 +103,21 @@ pub struct QueueState {
     pub stop_after_track_id: Option<u64>,
 }
 
+/// Whole-queue totals: length plus a format/source breakdown. `hires_count`,
+/// `cd_count` and `local_count` partition the queue: library files are
+/// counted as local (their `hires` flag is not a Qobuz format), every other
+/// track splits by its `hires` flag. `qobuz_count` counts the Qobuz catalog
+/// tracks among them (Plex tracks are not).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct QueueStats {
+    pub total_tracks: usize,
+    pub total_duration_secs: u64,
+    pub hires_count: usize,
+    pub cd_count: usize,
+    pub local_count: usize,
+    pub qobuz_count: usize,
+}
+
 // ============ Playback State ============
 
 /// Current playback state
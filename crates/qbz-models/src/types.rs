@@ -657,6 +657,17 @@ pub struct PlaylistDuplicateResult {
     pub duplicate_track_ids: HashSet<u64>,
 }
 
+/// Result of saving the queue as a new Qobuz playlist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaylistExportSummary {
+    /// The created playlist, for navigating to it
+    pub playlist_id: u64,
+    pub exported: usize,
+    /// Local and Plex tracks left out (Qobuz playlists only hold Qobuz
+    /// tracks)
+    pub skipped_local: usize,
+}
+
 // ============ Metadata Types ============
 
 /// Label model
@@ -666,6 +677,25 @@ pub struct Label {
     pub name: String,
 }
 
+/// Classical work a recording performs, resolved from MusicBrainz.
+/// Lets the UI render "Beethoven: Symphony No. 5" instead of the bare
+/// movement title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct WorkMetadata {
+    pub title: String,
+    #[serde(default)]
+    pub composer: Option<String>,
+    /// Opus number, e.g. "Op. 67"
+    #[serde(default)]
+    pub opus: Option<String>,
+    /// Non-opus catalogue number, e.g. "BWV 1007" or "K. 525"
+    #[serde(default)]
+    pub catalogue_number: Option<String>,
+    /// Musical key, e.g. "C minor"
+    #[serde(default)]
+    pub key: Option<String>,
+}
+
 // ============ Label Page Types (/label/page) ============
 
 /// Top-level response from /label/page
@@ -5,7 +5,8 @@
 //! `src-tauri` (ADR-006). Accepts URLs from Qobuz, Spotify, Apple Music, Tidal,
 //! Deezer, song.link, and album.link. For non-Qobuz tracks/albums it identifies
 //! the content (direct platform API fast-path, else the Odesli API) and searches
-//! Qobuz by title+artist to find the equivalent. For playlists it returns
+//! Qobuz by title+artist to find the equivalent; content that is not on Qobuz
+//! comes back with its links on the other platforms. For playlists it returns
 //! `PlaylistDetected` so the frontend can redirect to its importer.
 //!
 //! The Qobuz search itself is decoupled via the [`QobuzSearchBridge`] trait —
@@ -24,7 +25,7 @@ mod qobuz_search;
 pub use bridge::QobuzSearchBridge;
 pub use detection::{detect_music_resource, MusicProvider, MusicResource};
 pub use errors::MusicLinkError;
-pub use odesli::{ContentType, ShareError, SongLinkClient};
+pub use odesli::{ContentType, OdesliResponse, PlatformLink, ShareError, SongLinkClient};
 pub use qobuz_search::MusicLinkResult;
 
 // Re-export the native Qobuz parser so frontends can do native parsing too,
@@ -50,8 +51,9 @@ pub async fn resolve_music_link(
         return Err(MusicLinkError::EmptyUrl);
     }
 
-    // 1. Try Qobuz native resolve first (sync, no network)
-    if let Ok(resolved) = qbz_qobuz::resolve_link(&url) {
+    // 1. Try Qobuz native resolve first (no network unless it's a
+    //    qobuz.page.link short link, which is followed to its target)
+    if let Ok(resolved) = qbz_qobuz::resolve_link_following(&url).await {
         return Ok(MusicLinkResult::Resolved {
             link: resolved,
             provider: None,
@@ -102,8 +104,10 @@ pub async fn resolve_music_link(
 /// Identify a cross-platform music URL and search Qobuz for the equivalent.
 ///
 /// Fast path: for Tidal/Deezer calls the platform API directly; for Spotify
-/// scrapes the embed page to get title+artist. Fallback: uses Odesli API (~2-3s).
-/// Then searches Qobuz with progressively simpler queries.
+/// scrapes the embed page to get title+artist. Fallback: uses Odesli API (~2-3s),
+/// whose own Qobuz link (when it has one) is used as-is. Then searches Qobuz
+/// with progressively simpler queries. When nothing matches, the result carries
+/// every platform link Odesli knows for the content.
 async fn resolve_via_odesli_and_search(
     songlink: &SongLinkClient,
     url: &str,
@@ -114,56 +118,74 @@ async fn resolve_via_odesli_and_search(
     let provider_name = provider.map(|p| format!("{:?}", p));
 
     // 1. Get title + artist: try direct platform API first (fast), fall back to Odesli
-    let (title, artist) = if let Some(prov) = provider {
-        match fast_path::try_direct_platform_metadata(url, prov, is_track).await {
-            Some(meta) => {
-                log::info!(
-                    "Link resolver: direct API resolved '{}' by '{}'",
-                    meta.0,
-                    meta.1
-                );
-                meta
-            }
-            None => {
+    let direct = match provider {
+        Some(prov) => fast_path::try_direct_platform_metadata(url, prov, is_track).await,
+        None => None,
+    };
+    let (title, artist, odesli) = match direct {
+        Some((title, artist)) => {
+            log::info!(
+                "Link resolver: direct API resolved '{}' by '{}'",
+                title,
+                artist
+            );
+            (title, artist, None)
+        }
+        None => {
+            if provider.is_some() {
                 log::info!("Link resolver: direct API failed, falling back to Odesli");
-                fetch_metadata_via_odesli(songlink, url).await?
             }
+            let response = resolve_via_odesli(songlink, url).await?;
+            if let Some(result) = qobuz_link_from_odesli(&response, &provider_name) {
+                return Ok(result);
+            }
+            let (title, artist) = response.title_and_artist();
+            (
+                title.unwrap_or_default().trim().to_string(),
+                artist.unwrap_or_default().trim().to_string(),
+                Some(response),
+            )
         }
-    } else {
-        // No provider (song.link URLs) — use Odesli
-        fetch_metadata_via_odesli(songlink, url).await?
     };
 
-    if title.is_empty() {
-        return Ok(MusicLinkResult::NotOnQobuz {
-            provider: provider_name,
-        });
-    }
-
     // 2. Search Qobuz with progressively simpler queries
-    if let Some(result) =
-        qobuz_search::search_qobuz_smart(bridge, &title, &artist, is_track, &provider_name).await?
-    {
-        return Ok(result);
+    if !title.is_empty() {
+        if let Some(result) =
+            qobuz_search::search_qobuz_smart(bridge, &title, &artist, is_track, &provider_name)
+                .await?
+        {
+            return Ok(result);
+        }
+        log::info!(
+            "Link resolver: '{}' by '{}' not found on Qobuz",
+            title,
+            artist
+        );
     }
 
-    log::info!(
-        "Link resolver: '{}' by '{}' not found on Qobuz",
-        title,
-        artist
-    );
+    // 3. Not on Qobuz: hand back the other platforms instead. The fast path
+    //    skipped Odesli, so ask it now (best effort).
+    let links = match odesli {
+        Some(response) => response.platform_urls(),
+        None => songlink
+            .resolve(url)
+            .await
+            .map(|response| response.platform_urls())
+            .unwrap_or_default(),
+    };
     Ok(MusicLinkResult::NotOnQobuz {
         provider: provider_name,
+        links,
     })
 }
 
-/// Fetch metadata from Odesli API (with one retry for transient errors).
-async fn fetch_metadata_via_odesli(
+/// Resolve through the Odesli API (with one retry for transient errors).
+async fn resolve_via_odesli(
     songlink: &SongLinkClient,
     url: &str,
-) -> Result<(String, String), MusicLinkError> {
-    let response = match songlink.get_by_url(url, ContentType::Track).await {
-        Ok(r) => r,
+) -> Result<OdesliResponse, MusicLinkError> {
+    match songlink.resolve(url).await {
+        Ok(r) => Ok(r),
         Err(first_err) => {
             log::warn!(
                 "Link resolver: Odesli first attempt failed: {}, retrying...",
@@ -171,13 +193,55 @@ async fn fetch_metadata_via_odesli(
             );
             tokio::time::sleep(std::time::Duration::from_millis(500)).await;
             songlink
-                .get_by_url(url, ContentType::Track)
+                .resolve(url)
                 .await
-                .map_err(|e| MusicLinkError::Internal(format!("Odesli API error: {}", e)))?
+                .map_err(|e| MusicLinkError::Internal(format!("Odesli API error: {}", e)))
         }
-    };
+    }
+}
+
+/// Odesli's own Qobuz match, parsed natively, if it has one.
+fn qobuz_link_from_odesli(
+    response: &OdesliResponse,
+    provider_name: &Option<String>,
+) -> Option<MusicLinkResult> {
+    let link = qbz_qobuz::resolve_link(response.qobuz_url()?).ok()?;
+    log::info!("Link resolver: Odesli returned a Qobuz link");
+    Some(MusicLinkResult::Resolved {
+        link,
+        provider: provider_name.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(links: &str) -> OdesliResponse {
+        serde_json::from_str(&format!(
+            r#"{{ "pageUrl": "https://song.link/s/x", "linksByPlatform": {links} }}"#
+        ))
+        .expect("valid Odesli response")
+    }
+
+    #[test]
+    fn odesli_qobuz_link_resolves_natively() {
+        let response = response(
+            r#"{ "qobuz": { "url": "https://open.qobuz.com/track/12345" },
+                 "tidal": { "url": "https://tidal.com/browse/track/9" } }"#,
+        );
+        match qobuz_link_from_odesli(&response, &Some("Tidal".to_string())) {
+            Some(MusicLinkResult::Resolved { link, provider }) => {
+                assert_eq!(link, ResolvedLink::OpenTrack(12345));
+                assert_eq!(provider.as_deref(), Some("Tidal"));
+            }
+            other => panic!("expected a resolved link, got {other:?}"),
+        }
+    }
 
-    let title = response.title.unwrap_or_default().trim().to_string();
-    let artist = response.artist.unwrap_or_default().trim().to_string();
-    Ok((title, artist))
+    #[test]
+    fn no_qobuz_link_falls_through_to_search() {
+        let response = response(r#"{ "tidal": { "url": "https://tidal.com/browse/track/9" } }"#);
+        assert!(qobuz_link_from_odesli(&response, &None).is_none());
+    }
 }
@@ -55,6 +55,39 @@ pub struct OdesliResponse {
     pub entities_by_unique_id: HashMap<String, Entity>,
 }
 
+impl OdesliResponse {
+    /// Platform name → URL for every platform Odesli matched.
+    pub fn platform_urls(&self) -> HashMap<String, String> {
+        self.links_by_platform
+            .iter()
+            .map(|(platform, link)| (platform.clone(), link.url.clone()))
+            .collect()
+    }
+
+    /// The Qobuz link, when Odesli matched the entity on Qobuz.
+    pub fn qobuz_url(&self) -> Option<&str> {
+        self.links_by_platform
+            .get("qobuz")
+            .map(|link| link.url.as_str())
+    }
+
+    /// The entity the request resolved to (`entity_unique_id`), else any
+    /// entity Odesli returned.
+    pub fn input_entity(&self) -> Option<&Entity> {
+        self.entity_unique_id
+            .as_ref()
+            .and_then(|id| self.entities_by_unique_id.get(id))
+            .or_else(|| self.entities_by_unique_id.values().next())
+    }
+
+    /// Title and artist of the [`input_entity`](Self::input_entity).
+    pub fn title_and_artist(&self) -> (Option<String>, Option<String>) {
+        self.input_entity()
+            .map(|e| (e.title.clone(), e.artist_name.clone()))
+            .unwrap_or((None, None))
+    }
+}
+
 /// Link info for a specific platform
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -232,6 +265,16 @@ impl SongLinkClient {
             return Ok(cached);
         }
 
+        let odesli = self.resolve(url).await?;
+        let result = self.convert_response(odesli, url.to_string(), content_type)?;
+
+        self.store_in_cache(cache_key, result.clone());
+        Ok(result)
+    }
+
+    /// Resolve any supported music URL to the raw Odesli response, with every
+    /// platform link Odesli knows for it. Not cached.
+    pub async fn resolve(&self, url: &str) -> Result<OdesliResponse, ShareError> {
         log::info!("Fetching song.link for URL: {}", url);
 
         let response = self
@@ -257,11 +300,7 @@ impl SongLinkClient {
             )));
         }
 
-        let odesli: OdesliResponse = response.json().await?;
-        let result = self.convert_response(odesli, url.to_string(), content_type)?;
-
-        self.store_in_cache(cache_key, result.clone());
-        Ok(result)
+        Ok(response.json().await?)
     }
 
     /// Convert Odesli response to our simplified format
@@ -271,11 +310,9 @@ impl SongLinkClient {
         identifier: String,
         content_type: ContentType,
     ) -> Result<SongLinkResponse, ShareError> {
-        // Extract title and artist from the first entity
+        // Extract title and artist from the requested entity
         let (title, artist, thumbnail_url) = response
-            .entities_by_unique_id
-            .values()
-            .next()
+            .input_entity()
             .map(|e| {
                 (
                     e.title.clone(),
@@ -344,3 +381,69 @@ impl SongLinkClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(json: &str) -> OdesliResponse {
+        serde_json::from_str(json).expect("valid Odesli response")
+    }
+
+    const TWO_ENTITIES: &str = r#"{
+        "entityUniqueId": "SPOTIFY_SONG::abc",
+        "pageUrl": "https://song.link/s/abc",
+        "linksByPlatform": {
+            "spotify": { "url": "https://open.spotify.com/track/abc", "entityUniqueId": "SPOTIFY_SONG::abc" },
+            "qobuz": { "url": "https://open.qobuz.com/track/12345", "entityUniqueId": "QOBUZ_SONG::12345" }
+        },
+        "entitiesByUniqueId": {
+            "QOBUZ_SONG::12345": { "id": 12345, "type": "song", "title": "Other Title", "artistName": "Other Artist" },
+            "SPOTIFY_SONG::abc": { "id": "abc", "type": "song", "title": "Requested Title", "artistName": "Requested Artist" }
+        }
+    }"#;
+
+    #[test]
+    fn title_and_artist_come_from_the_requested_entity() {
+        let response = response(TWO_ENTITIES);
+        assert_eq!(
+            response.title_and_artist(),
+            (
+                Some("Requested Title".to_string()),
+                Some("Requested Artist".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn title_and_artist_fall_back_to_any_entity() {
+        let response = response(
+            r#"{
+                "pageUrl": "https://song.link/s/abc",
+                "entitiesByUniqueId": {
+                    "DEEZER_SONG::1": { "id": 1, "title": "Only", "artistName": "One" }
+                }
+            }"#,
+        );
+        assert_eq!(
+            response.title_and_artist(),
+            (Some("Only".to_string()), Some("One".to_string()))
+        );
+        assert_eq!(response.qobuz_url(), None);
+    }
+
+    #[test]
+    fn platform_urls_and_qobuz_url() {
+        let response = response(TWO_ENTITIES);
+        let urls = response.platform_urls();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(
+            urls.get("spotify").map(String::as_str),
+            Some("https://open.spotify.com/track/abc")
+        );
+        assert_eq!(
+            response.qobuz_url(),
+            Some("https://open.qobuz.com/track/12345")
+        );
+    }
+}
@@ -20,7 +20,12 @@ pub enum MusicLinkResult {
     /// The URL is a playlist — redirect to the Playlist Importer.
     PlaylistDetected { provider: String },
     /// The content exists on the source platform but is not available on Qobuz.
-    NotOnQobuz { provider: Option<String> },
+    /// `links` holds every other platform Odesli matched it on (platform →
+    /// URL); empty when Odesli could not be reached.
+    NotOnQobuz {
+        provider: Option<String>,
+        links: std::collections::HashMap<String, String>,
+    },
 }
 
 /// Search Qobuz with progressively simpler queries until a match is found.
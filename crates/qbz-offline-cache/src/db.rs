@@ -4,7 +4,8 @@ use rusqlite::{params, Connection};
 use std::path::Path;
 
 use crate::types::{
-    CachedTrackInfo, OfflineCacheStats, OfflineCacheStatus, ReadyTrackForSync, TrackCacheInfo,
+    CachedTrackInfo, DownloadEntry, DownloadStatus, OfflineCacheStats, OfflineCacheStatus,
+    ReadyTrackForSync, TrackCacheInfo,
 };
 
 /// Maps a `cached_tracks` row (with the canonical 17-column SELECT used by
@@ -90,6 +91,21 @@ impl OfflineCacheDb {
             CREATE INDEX IF NOT EXISTS idx_track_id ON cached_tracks(track_id);
             CREATE INDEX IF NOT EXISTS idx_status ON cached_tracks(status);
             CREATE INDEX IF NOT EXISTS idx_last_accessed ON cached_tracks(last_accessed_at);
+
+            CREATE TABLE IF NOT EXISTS resumable_downloads (
+                track_id INTEGER NOT NULL,
+                format_id INTEGER NOT NULL,
+                album_id TEXT,
+                url TEXT NOT NULL,
+                part_path TEXT NOT NULL,
+                target_path TEXT NOT NULL,
+                bytes_written INTEGER NOT NULL DEFAULT 0,
+                total_bytes INTEGER,
+                status TEXT NOT NULL DEFAULT 'pending',
+                error_message TEXT,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (track_id, format_id)
+            );
             ",
             )
             .map_err(|e| format!("Failed to initialize database schema: {}", e))?;
@@ -661,6 +677,139 @@ impl OfflineCacheDb {
 
         Ok((ids, bytes as u64))
     }
+
+    /// Insert or replace the resumable-download row for
+    /// `(entry.track_id, entry.format_id)`.
+    pub fn upsert_download(&self, entry: &DownloadEntry) -> Result<(), String> {
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO resumable_downloads (
+                    track_id, format_id, album_id, url, part_path, target_path,
+                    bytes_written, total_bytes, status, error_message, updated_at
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, datetime('now'))",
+                params![
+                    entry.track_id as i64,
+                    entry.format_id as i64,
+                    entry.album_id,
+                    entry.url,
+                    entry.part_path,
+                    entry.target_path,
+                    entry.bytes_written as i64,
+                    entry.total_bytes.map(|v| v as i64),
+                    entry.status.as_str(),
+                    entry.error_message,
+                ],
+            )
+            .map_err(|e| format!("Failed to save download {}: {}", entry.track_id, e))?;
+        Ok(())
+    }
+
+    /// Record how far a resumable download has got.
+    pub fn update_download_progress(
+        &self,
+        track_id: u64,
+        format_id: u32,
+        bytes_written: u64,
+        total_bytes: Option<u64>,
+    ) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE resumable_downloads SET bytes_written = ?1, total_bytes = ?2, updated_at = datetime('now')
+                 WHERE track_id = ?3 AND format_id = ?4",
+                params![
+                    bytes_written as i64,
+                    total_bytes.map(|v| v as i64),
+                    track_id as i64,
+                    format_id as i64
+                ],
+            )
+            .map_err(|e| format!("Failed to update download progress: {}", e))?;
+        Ok(())
+    }
+
+    /// Update a resumable download's status (and error message).
+    pub fn set_download_status(
+        &self,
+        track_id: u64,
+        format_id: u32,
+        status: DownloadStatus,
+        error: Option<&str>,
+    ) -> Result<(), String> {
+        self.conn
+            .execute(
+                "UPDATE resumable_downloads SET status = ?1, error_message = ?2, updated_at = datetime('now')
+                 WHERE track_id = ?3 AND format_id = ?4",
+                params![status.as_str(), error, track_id as i64, format_id as i64],
+            )
+            .map_err(|e| format!("Failed to update download status: {}", e))?;
+        Ok(())
+    }
+
+    /// The resumable-download row for `(track_id, format_id)`, if any.
+    pub fn get_download(
+        &self,
+        track_id: u64,
+        format_id: u32,
+    ) -> Result<Option<DownloadEntry>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "{DOWNLOAD_SELECT} WHERE track_id = ?1 AND format_id = ?2"
+            ))
+            .map_err(|e| format!("Failed to prepare query: {}", e))?;
+        let mut rows = stmt
+            .query_map(
+                params![track_id as i64, format_id as i64],
+                row_to_download_entry,
+            )
+            .map_err(|e| format!("Failed to query download: {}", e))?;
+        rows.next()
+            .transpose()
+            .map_err(|e| format!("Failed to read download: {}", e))
+    }
+
+    /// Every resumable-download row, most recently updated first.
+    pub fn get_downloads(&self) -> Result<Vec<DownloadEntry>, String> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("{DOWNLOAD_SELECT} ORDER BY updated_at DESC"))
+            .map_err(|e| format!("Failed to prepare query: {}", e))?;
+        let rows = stmt
+            .query_map([], row_to_download_entry)
+            .map_err(|e| format!("Failed to query downloads: {}", e))?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(|e| format!("Failed to read downloads: {}", e))
+    }
+
+    /// Forget a resumable download (the `.part` file is the caller's).
+    pub fn delete_download(&self, track_id: u64, format_id: u32) -> Result<(), String> {
+        self.conn
+            .execute(
+                "DELETE FROM resumable_downloads WHERE track_id = ?1 AND format_id = ?2",
+                params![track_id as i64, format_id as i64],
+            )
+            .map_err(|e| format!("Failed to delete download: {}", e))?;
+        Ok(())
+    }
+}
+
+const DOWNLOAD_SELECT: &str = "SELECT track_id, format_id, album_id, url, part_path, target_path,
+    bytes_written, total_bytes, status, error_message, updated_at FROM resumable_downloads";
+
+fn row_to_download_entry(row: &rusqlite::Row) -> rusqlite::Result<DownloadEntry> {
+    Ok(DownloadEntry {
+        track_id: row.get::<_, i64>(0)? as u64,
+        format_id: row.get::<_, i64>(1)? as u32,
+        album_id: row.get(2)?,
+        url: row.get(3)?,
+        part_path: row.get(4)?,
+        target_path: row.get(5)?,
+        bytes_written: row.get::<_, i64>(6)? as u64,
+        total_bytes: row.get::<_, Option<i64>>(7)?.map(|v| v as u64),
+        status: DownloadStatus::from_str(&row.get::<_, String>(8)?),
+        error_message: row.get(9)?,
+        updated_at: row.get(10)?,
+    })
 }
 
 /// Raw snapshot of the v2 bundle columns for a cached track.
@@ -0,0 +1,536 @@
+//! Resumable downloads for purchased tracks.
+//!
+//! Every purchase download streams into its `.part` file while a row in the
+//! offline index (`resumable_downloads`, keyed by track + REQUESTED format)
+//! records the part/target paths, the bytes written so far and the total
+//! size. When a download is interrupted — a network drop, or the app quitting
+//! mid-album — the next attempt for the same track+format finds the row and
+//! the `.part` on disk and continues with an HTTP `Range` request instead of
+//! starting over. Rows left `active` by a process that died, and `failed`
+//! rows whose `.part` was kept, are what [`DownloadManager::interrupted`]
+//! returns for resume-on-restart.
+//!
+//! A `206` is only appended to the `.part` when its `Content-Range` starts
+//! exactly at the part's size on disk and reports the total the row
+//! recorded; otherwise the part is truncated and the download restarts from
+//! byte zero in the same attempt.
+//!
+//! Signed CDN URLs expire, so the stored `url` is informational: a resume
+//! always fetches a fresh URL for the same track+format and only reuses the
+//! bytes. A `.part` with no matching row (or one that no longer agrees with
+//! it) is discarded and the download starts from zero, as before.
+//!
+//! The manager shares the offline cache's `index.db` connection. With no
+//! index open (offline cache not activated) downloads still work; they just
+//! are not resumable.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use reqwest::StatusCode;
+use tokio::sync::Mutex;
+
+use crate::db::OfflineCacheDb;
+use crate::types::{DownloadEntry, DownloadStatus};
+
+/// Persist progress at most once per this many bytes.
+const PROGRESS_STEP_BYTES: u64 = 1024 * 1024;
+
+/// Live progress callback: `(track_id, percent)`, `percent` `None` while the
+/// size is unknown. Called on every persisted progress step.
+pub type ProgressObserver = Arc<dyn Fn(u64, Option<u8>) + Send + Sync>;
+
+/// What to download and where: the identity of one resumable row.
+#[derive(Debug, Clone)]
+pub struct DownloadJob {
+    pub track_id: u64,
+    /// The REQUESTED format id (not the one the CDN ends up serving).
+    pub format_id: u32,
+    pub album_id: Option<String>,
+    pub part_path: PathBuf,
+    pub target_path: PathBuf,
+}
+
+impl DownloadJob {
+    /// Rebuild the job a stored row describes (resume-on-restart).
+    pub fn from_entry(entry: &DownloadEntry) -> Self {
+        Self {
+            track_id: entry.track_id,
+            format_id: entry.format_id,
+            album_id: entry.album_id.clone(),
+            part_path: PathBuf::from(&entry.part_path),
+            target_path: PathBuf::from(&entry.target_path),
+        }
+    }
+}
+
+pub struct DownloadManager {
+    db: Arc<Mutex<Option<OfflineCacheDb>>>,
+    observer: Option<ProgressObserver>,
+}
+
+/// How one transfer attempt ended (short of an error).
+enum Attempt {
+    /// The `.part` is complete; its size.
+    Done(u64),
+    /// The server's `206` does not continue the `.part` we have.
+    Restart(String),
+}
+
+impl DownloadManager {
+    /// A manager over the offline cache's index connection
+    /// (`OfflineCacheState::db`).
+    pub fn new(db: Arc<Mutex<Option<OfflineCacheDb>>>) -> Self {
+        Self { db, observer: None }
+    }
+
+    /// Report live progress to `observer` (e.g. a per-track percentage in
+    /// the UI) as well as to the ledger.
+    pub fn on_progress(mut self, observer: ProgressObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// A manager with no ledger: downloads work but never resume.
+    pub fn detached() -> Self {
+        Self::new(Arc::new(Mutex::new(None)))
+    }
+
+    /// Every tracked download (active, pending, failed and completed), most
+    /// recently updated first, for progress listings.
+    pub async fn entries(&self) -> Vec<DownloadEntry> {
+        let guard = self.db.lock().await;
+        match guard.as_ref().map(|db| db.get_downloads()) {
+            Some(Ok(entries)) => entries,
+            Some(Err(e)) => {
+                log::warn!("[Downloads] listing failed: {}", e);
+                Vec::new()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Downloads a previous run left unfinished (still `active`/`pending`,
+    /// or `failed` with the `.part` kept for a retry) whose `.part` file is
+    /// still on disk.
+    pub async fn interrupted(&self) -> Vec<DownloadEntry> {
+        self.entries()
+            .await
+            .into_iter()
+            .filter(|entry| {
+                entry.status != DownloadStatus::Completed && Path::new(&entry.part_path).is_file()
+            })
+            .collect()
+    }
+
+    /// Stop tracking a download (after it was given up on).
+    pub async fn forget(&self, track_id: u64, format_id: u32) {
+        if let Some(db) = self.db.lock().await.as_ref() {
+            if let Err(e) = db.delete_download(track_id, format_id) {
+                log::warn!("[Downloads] forget {} failed: {}", track_id, e);
+            }
+        }
+    }
+
+    /// Download `url` into `job.part_path`, continuing a previous partial
+    /// download of the same track+format when possible. Returns the size of
+    /// the complete `.part`; the caller renames it into place and then calls
+    /// [`Self::mark_completed`].
+    ///
+    /// On failure the `.part` and its row are kept (status `failed`) so the
+    /// next attempt resumes.
+    pub async fn fetch(&self, job: &DownloadJob, url: &str) -> Result<u64, String> {
+        let previous = self.load(job).await;
+        let part_len = std::fs::metadata(&job.part_path).ok().map(|m| m.len());
+        let offset = resume_offset(previous.as_ref(), job, part_len);
+
+        self.save(&DownloadEntry {
+            track_id: job.track_id,
+            format_id: job.format_id,
+            album_id: job.album_id.clone(),
+            url: url.to_string(),
+            part_path: job.part_path.to_string_lossy().to_string(),
+            target_path: job.target_path.to_string_lossy().to_string(),
+            bytes_written: offset,
+            total_bytes: previous.as_ref().and_then(|p| p.total_bytes),
+            status: DownloadStatus::Active,
+            error_message: None,
+            updated_at: String::new(),
+        })
+        .await;
+
+        let expected_total = previous.as_ref().and_then(|p| p.total_bytes);
+        let attempt = match self.try_fetch(job, url, offset, expected_total).await {
+            Ok(Attempt::Restart(reason)) => {
+                log::warn!(
+                    "[Downloads] track {}: {}; restarting from byte 0",
+                    job.track_id,
+                    reason
+                );
+                match std::fs::File::create(&job.part_path) {
+                    Ok(_) => {
+                        self.record_progress(job, 0, None).await;
+                        self.try_fetch(job, url, 0, None).await
+                    }
+                    Err(e) => Err(format!("Failed to truncate temporary file: {}", e)),
+                }
+            }
+            other => other,
+        };
+        match attempt {
+            Ok(Attempt::Done(size)) => Ok(size),
+            Ok(Attempt::Restart(reason)) => {
+                // A from-zero request never sends Range, so this can't repeat.
+                self.set_status(job, DownloadStatus::Failed, Some(&reason))
+                    .await;
+                Err(reason)
+            }
+            Err(e) => {
+                self.set_status(job, DownloadStatus::Failed, Some(&e)).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Mark the download finished (the `.part` has been renamed into place).
+    pub async fn mark_completed(&self, job: &DownloadJob) {
+        self.set_status(job, DownloadStatus::Completed, None).await;
+    }
+
+    async fn try_fetch(
+        &self,
+        job: &DownloadJob,
+        url: &str,
+        offset: u64,
+        expected_total: Option<u64>,
+    ) -> Result<Attempt, String> {
+        // Force HTTP/1.1 — the Qobuz CDN resets large HTTP/2 transfers (see
+        // `QobuzClient::download_audio`). No total timeout: an album track can
+        // take minutes.
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .http1_only()
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let mut request = client.get(url).header("User-Agent", "Mozilla/5.0");
+        if offset > 0 {
+            log::info!(
+                "[Downloads] resuming track {} at byte {}",
+                job.track_id,
+                offset
+            );
+            request = request.header("Range", format!("bytes={}-", offset));
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch audio: {}", e))?;
+
+        let status = response.status();
+        if status == StatusCode::RANGE_NOT_SATISFIABLE && offset > 0 {
+            // The part already holds the whole file (the previous run died
+            // between the last byte and the rename).
+            let total = response
+                .headers()
+                .get("content-range")
+                .and_then(|v| v.to_str().ok())
+                .and_then(content_range_total);
+            if total == Some(offset) {
+                self.record_progress(job, offset, total).await;
+                return Ok(Attempt::Done(offset));
+            }
+            // The part no longer matches the file; start over next time.
+            let _ = std::fs::remove_file(&job.part_path);
+            return Err(format!("HTTP error: {}", status));
+        }
+        if !status.is_success() {
+            return Err(format!("HTTP error: {}", status));
+        }
+
+        // 206 continues the part — but only the part we actually have; a 200
+        // (Range ignored) starts over.
+        let (mut written, total, mut file) = if status == StatusCode::PARTIAL_CONTENT {
+            let range = response
+                .headers()
+                .get("content-range")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&job.part_path)
+                .map_err(|e| format!("Failed to open temporary file: {}", e))?;
+            let on_disk = file
+                .metadata()
+                .map_err(|e| format!("Failed to open temporary file: {}", e))?
+                .len();
+            if let Err(reason) =
+                check_partial_range(range.as_deref(), offset, on_disk, expected_total)
+            {
+                return Ok(Attempt::Restart(reason));
+            }
+            let total = range
+                .as_deref()
+                .and_then(content_range_total)
+                .or_else(|| response.content_length().map(|len| offset + len));
+            (offset, total, file)
+        } else {
+            let file = std::fs::File::create(&job.part_path)
+                .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+            (0, response.content_length(), file)
+        };
+        self.record_progress(job, written, total).await;
+
+        let mut last_saved = written;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
+            file.write_all(&chunk)
+                .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+            written += chunk.len() as u64;
+            if written - last_saved >= PROGRESS_STEP_BYTES {
+                // Flush first so a crash never records bytes the file lacks.
+                file.flush()
+                    .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+                self.record_progress(job, written, total).await;
+                last_saved = written;
+            }
+        }
+        file.flush()
+            .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+        self.record_progress(job, written, total).await;
+
+        if let Some(total) = total {
+            if written != total {
+                return Err(format!(
+                    "Download incomplete: {} of {} bytes",
+                    written, total
+                ));
+            }
+        }
+        Ok(Attempt::Done(written))
+    }
+
+    async fn load(&self, job: &DownloadJob) -> Option<DownloadEntry> {
+        let guard = self.db.lock().await;
+        guard
+            .as_ref()?
+            .get_download(job.track_id, job.format_id)
+            .unwrap_or_else(|e| {
+                log::warn!("[Downloads] lookup {} failed: {}", job.track_id, e);
+                None
+            })
+    }
+
+    async fn save(&self, entry: &DownloadEntry) {
+        if let Some(db) = self.db.lock().await.as_ref() {
+            if let Err(e) = db.upsert_download(entry) {
+                log::warn!("[Downloads] save {} failed: {}", entry.track_id, e);
+            }
+        }
+    }
+
+    async fn record_progress(&self, job: &DownloadJob, written: u64, total: Option<u64>) {
+        if let Some(db) = self.db.lock().await.as_ref() {
+            if let Err(e) = db.update_download_progress(job.track_id, job.format_id, written, total)
+            {
+                log::warn!("[Downloads] progress {} failed: {}", job.track_id, e);
+            }
+        }
+        if let Some(observer) = &self.observer {
+            let percent = total
+                .filter(|total| *total > 0)
+                .map(|total| ((written.min(total) * 100) / total) as u8);
+            observer(job.track_id, percent);
+        }
+    }
+
+    async fn set_status(&self, job: &DownloadJob, status: DownloadStatus, error: Option<&str>) {
+        if let Some(db) = self.db.lock().await.as_ref() {
+            if let Err(e) = db.set_download_status(job.track_id, job.format_id, status, error) {
+                log::warn!("[Downloads] status {} failed: {}", job.track_id, e);
+            }
+        }
+    }
+}
+
+/// Where to continue a download from: the `.part` length, but only when a
+/// row for the same track+format points at the same files and the part is
+/// not larger than the recorded total. Anything else restarts from zero.
+fn resume_offset(
+    previous: Option<&DownloadEntry>,
+    job: &DownloadJob,
+    part_len: Option<u64>,
+) -> u64 {
+    let (Some(previous), Some(part_len)) = (previous, part_len) else {
+        return 0;
+    };
+    let same_files = Path::new(&previous.part_path) == job.part_path
+        && Path::new(&previous.target_path) == job.target_path;
+    let fits = previous.total_bytes.is_none_or(|total| part_len <= total);
+    if same_files && fits && previous.status != DownloadStatus::Completed {
+        part_len
+    } else {
+        0
+    }
+}
+
+/// Whether a `206` continues our `.part`: its `Content-Range` must start at
+/// the requested offset, the part on disk must still be exactly that long,
+/// and the total must match what the row recorded (when it recorded one).
+/// `Err` carries why not.
+fn check_partial_range(
+    range: Option<&str>,
+    offset: u64,
+    on_disk: u64,
+    expected_total: Option<u64>,
+) -> Result<(), String> {
+    let range = range.ok_or("206 without a Content-Range")?;
+    let start = content_range_start(range)
+        .ok_or_else(|| format!("unreadable Content-Range {:?}", range))?;
+    if start != offset {
+        return Err(format!(
+            "206 starts at byte {} instead of {}",
+            start, offset
+        ));
+    }
+    if on_disk != offset {
+        return Err(format!(
+            "the .part holds {} bytes, the resume asked for {}",
+            on_disk, offset
+        ));
+    }
+    if let (Some(expected), Some(total)) = (expected_total, content_range_total(range)) {
+        if expected != total {
+            return Err(format!(
+                "the file is now {} bytes, the .part was for {}",
+                total, expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The first byte of a `Content-Range` header (`bytes 100-199/200` -> 100);
+/// `None` for the `bytes */200` form or a malformed value.
+fn content_range_start(value: &str) -> Option<u64> {
+    let spec = value.trim().strip_prefix("bytes")?.trim_start();
+    spec.split_once('-')?.0.trim().parse().ok()
+}
+
+/// The total size from a `Content-Range` header (`bytes 100-199/200` or
+/// `bytes */200`); `None` when it is `*` or malformed.
+fn content_range_total(value: &str) -> Option<u64> {
+    value.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job() -> DownloadJob {
+        DownloadJob {
+            track_id: 7,
+            format_id: 27,
+            album_id: Some("alb".to_string()),
+            part_path: PathBuf::from("/music/A/B/01 - T.flac.part"),
+            target_path: PathBuf::from("/music/A/B/01 - T.flac"),
+        }
+    }
+
+    fn entry(status: DownloadStatus, written: u64, total: Option<u64>) -> DownloadEntry {
+        DownloadEntry {
+            track_id: 7,
+            format_id: 27,
+            album_id: Some("alb".to_string()),
+            url: "https://cdn.example/expired".to_string(),
+            part_path: "/music/A/B/01 - T.flac.part".to_string(),
+            target_path: "/music/A/B/01 - T.flac".to_string(),
+            bytes_written: written,
+            total_bytes: total,
+            status,
+            error_message: None,
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn resumes_from_part_length_only_when_the_row_matches() {
+        let active = entry(DownloadStatus::Active, 400, Some(1000));
+        assert_eq!(resume_offset(Some(&active), &job(), Some(512)), 512);
+        assert_eq!(
+            resume_offset(
+                Some(&entry(DownloadStatus::Failed, 10, None)),
+                &job(),
+                Some(64)
+            ),
+            64
+        );
+
+        // No row, no part, a part larger than the file, or a finished row.
+        assert_eq!(resume_offset(None, &job(), Some(512)), 0);
+        assert_eq!(resume_offset(Some(&active), &job(), None), 0);
+        assert_eq!(resume_offset(Some(&active), &job(), Some(1001)), 0);
+        let done = entry(DownloadStatus::Completed, 1000, Some(1000));
+        assert_eq!(resume_offset(Some(&done), &job(), Some(1000)), 0);
+
+        // Same track+format downloaded to a different destination.
+        let mut moved = job();
+        moved.part_path = PathBuf::from("/elsewhere/01 - T.flac.part");
+        assert_eq!(resume_offset(Some(&active), &moved, Some(512)), 0);
+    }
+
+    #[test]
+    fn content_range_total_parses_both_forms() {
+        assert_eq!(content_range_total("bytes 100-199/200"), Some(200));
+        assert_eq!(content_range_total("bytes */4096"), Some(4096));
+        assert_eq!(content_range_total("bytes 0-9/*"), None);
+        assert_eq!(content_range_total("garbage"), None);
+    }
+
+    #[test]
+    fn partial_content_must_continue_the_part_on_disk() {
+        let ok = Some("bytes 512-999/1000");
+        assert_eq!(check_partial_range(ok, 512, 512, Some(1000)), Ok(()));
+        assert_eq!(check_partial_range(ok, 512, 512, None), Ok(()));
+
+        // Server started elsewhere, the part changed under us, the file
+        // changed size, or no usable header at all.
+        assert!(check_partial_range(Some("bytes 0-999/1000"), 512, 512, Some(1000)).is_err());
+        assert!(check_partial_range(ok, 512, 600, Some(1000)).is_err());
+        assert!(check_partial_range(Some("bytes 512-1999/2000"), 512, 512, Some(1000)).is_err());
+        assert!(check_partial_range(None, 512, 512, None).is_err());
+        assert!(check_partial_range(Some("bytes */1000"), 512, 512, None).is_err());
+
+        assert_eq!(content_range_start("bytes 100-199/200"), Some(100));
+        assert_eq!(content_range_start("bytes */200"), None);
+    }
+
+    #[test]
+    fn ledger_round_trips_and_reports_percentages() {
+        let tmp = tempfile::tempdir().unwrap();
+        let db = OfflineCacheDb::new(&tmp.path().join("index.db")).unwrap();
+        db.upsert_download(&entry(DownloadStatus::Active, 0, None))
+            .unwrap();
+        db.update_download_progress(7, 27, 250, Some(1000)).unwrap();
+
+        let manager = DownloadManager::new(Arc::new(Mutex::new(Some(db))));
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let entries = rt.block_on(manager.entries());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].bytes_written, 250);
+        assert_eq!(entries[0].progress_percent(), 25);
+        // The part file is not on disk, so nothing to resume at startup.
+        assert!(rt.block_on(manager.interrupted()).is_empty());
+
+        rt.block_on(manager.mark_completed(&job()));
+        let entries = rt.block_on(manager.entries());
+        assert_eq!(entries[0].status, DownloadStatus::Completed);
+        assert_eq!(entries[0].progress_percent(), 100);
+    }
+}
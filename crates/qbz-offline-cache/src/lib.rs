@@ -16,6 +16,7 @@
 
 pub mod cmaf_store;
 pub mod db;
+pub mod download_manager;
 pub mod downloader;
 pub mod event;
 pub mod maintenance;
@@ -30,6 +31,7 @@ pub mod state;
 pub mod types;
 
 pub use db::{CmafBundleRow, OfflineCacheDb};
+pub use download_manager::{DownloadJob, DownloadManager};
 pub use downloader::{spawn_track_cache_download, StreamFetcher};
 pub use event::{CacheEvent, CacheEventSink, CacheFormat};
 pub use metadata::{sanitize_filename, CompleteTrackMetadata};
@@ -41,6 +43,6 @@ pub use migration::{
 pub use path_validator::{is_offline_root_available, validate_path, PathStatus};
 pub use playback::{load_cmaf_bundle, load_cmaf_bundle_with_ui_events};
 pub use types::{
-    CacheProgress, CachedTrackInfo, OfflineCacheStats, OfflineCacheStatus, ReadyTrackForSync,
-    TrackCacheInfo,
+    CacheProgress, CachedTrackInfo, DownloadEntry, DownloadStatus, OfflineCacheStats,
+    OfflineCacheStatus, ReadyTrackForSync, TrackCacheInfo,
 };
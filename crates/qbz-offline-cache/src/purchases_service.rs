@@ -25,10 +25,18 @@
 //! helpers `target_path` (§7.3 `v2_purchase_target_path`) and
 //! `purchase_extension` (§7.1.5 `v2_purchase_extension`). The album loop, cancel,
 //! and per-track progress live in the `qbz-slint` controller (Slice 7), not here
-//! — this crate only exposes the single-track primitive.
+//! — this crate only exposes the single-track primitive. The CDN bytes stream
+//! through `download_manager::DownloadManager`, so an interrupted download
+//! resumes from its `.part` instead of starting over.
+//!
+//! Album ZIP export: `download_album_as_zip` (`v2_download_album_as_zip`)
+//! runs every track of a purchased album through the same primitive into a
+//! throwaway staging folder, retags it from the album payload and stores the
+//! lot in `Artist - Album (year) [quality].zip`. Nothing is registered — the
+//! archive is an export, not a download the purchases view tracks.
 
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use qbz_library::LibraryDatabase;
 use qbz_models::{
@@ -37,7 +45,11 @@ use qbz_models::{
 use qbz_qobuz::QobuzClient;
 use qbz_qobuz::Result as QobuzResult;
 
-use crate::metadata::sanitize_filename;
+use crate::download_manager::{DownloadJob, DownloadManager};
+use crate::metadata::{
+    embed_artwork, sanitize_filename, save_album_artwork, write_flac_tags, CompleteTrackMetadata,
+};
+use crate::types::DownloadEntry;
 
 /// Fetch ONE purchases page, typed by purchase kind (`"albums"` / `"tracks"`,
 /// or `None` for both). Thin pass-through to the client's
@@ -437,43 +449,15 @@ pub fn target_path(
         .join(file_name)
 }
 
-/// I/O tail of the single-track download: given the already-fetched audio
-/// `data` and the resolved track/stream metadata, derive the extension from the
-/// RESPONSE format, build the target path, `create_dir_all`, write the `.part`
-/// file, `fs::rename` to final, then write the registry row with the REQUESTED
-/// `format_id`. Returns the final on-disk path string.
-///
-/// Split out from `download_purchase_track` purely so the filesystem +
-/// registry ordering (Addendum B.1/B.2/B.3) is unit-testable without a live
-/// HTTP client. The behavior is the EXACT concatenation of
-/// `v2_download_purchase_track_impl`'s write tail (`legacy_compat.rs:2681-2701`)
-/// and `v2_purchases_download_track`'s registry write (`:3019`).
-///
-/// Ordering & failure semantics (Addendum B.1 — replicated verbatim):
-///   1. write `target.with_extension("{ext}.part")` → `2. fs::rename` to final
-///      → `3. mark_purchase_downloaded`.
-///   If the file write/rename SUCCEEDS but the registry write FAILS, this
-///   returns `Err` with the file LEFT ON DISK (orphaned, no registry row). Do
-///   NOT roll back the file or treat the registry failure as success.
-///
-/// No collision preflight (Addendum B.3): `.part`→`fs::rename` overwrites any
-/// pre-existing final file or stale `.part` silently.
-/// Filesystem-only tail: derive the extension from the RESPONSE format, build
-/// the target path, `create_dir_all`, write the `.part`, `fs::rename` to final,
-/// and return the final on-disk path string. Does **NOT** touch the registry.
-///
-/// This is the shared write core. The album loop uses it directly (so a registry
-/// failure does NOT mark the track `Failed`; the album loop instead does a
-/// SEPARATE best-effort registry write that swallows the error — Svelte
-/// `markTrackDownloaded(...).catch(()=>{})`, §B.1 album-path semantics). The
-/// single-track bundled path (`write_and_register_track`) wraps this and then
-/// propagates a registry error so the track shows `Failed` (§B.1 single-track
-/// semantics).
-///
-/// Addendum B.2: extension/MIME from the RESPONSE; Addendum B.3: silent overwrite.
+/// Resolve where a purchased track lands: derive the extension from the
+/// RESPONSE format (Addendum B.2), build the target path and `create_dir_all`
+/// its folder. Returns the resumable job for the download manager — the
+/// `.part` sits next to the target (`target.with_extension("{ext}.part")`).
 #[allow(clippy::too_many_arguments)]
-fn write_track_file(
-    data: &[u8],
+fn prepare_track_job(
+    track_id: u64,
+    requested_format_id: u32,
+    album_id: Option<&str>,
     artist_name: &str,
     album_title: &str,
     quality_dir: &str,
@@ -482,7 +466,7 @@ fn write_track_file(
     response_format_id: u32,
     response_mime_type: &str,
     destination: &str,
-) -> Result<String, String> {
+) -> Result<DownloadJob, String> {
     // Addendum B.2: extension derives from the RESPONSE's served format.
     let extension = purchase_extension(response_format_id, response_mime_type);
     let target = target_path(
@@ -500,52 +484,93 @@ fn write_track_file(
             .map_err(|e| format!("Failed to create destination folder: {}", e))?;
     }
 
-    // Addendum B.3: write `.part`, then rename — no overwrite preflight.
-    let temp_path = target.with_extension(format!("{}.part", extension));
-    std::fs::write(&temp_path, data).map_err(|e| format!("Failed to write temporary file: {}", e))?;
-    std::fs::rename(&temp_path, &target).map_err(|e| format!("Failed to finalize file: {}", e))?;
+    Ok(DownloadJob {
+        track_id,
+        format_id: requested_format_id,
+        album_id: album_id.map(str::to_string),
+        part_path: target.with_extension(format!("{}.part", extension)),
+        target_path: target,
+    })
+}
 
-    Ok(target.to_string_lossy().to_string())
+/// Filesystem tail: `fs::rename` the finished `.part` to its final name and
+/// return the final on-disk path string. Does **NOT** touch the registry.
+///
+/// No collision preflight (Addendum B.3): the rename overwrites any
+/// pre-existing final file silently.
+fn finalize_track_file(job: &DownloadJob) -> Result<String, String> {
+    std::fs::rename(&job.part_path, &job.target_path)
+        .map_err(|e| format!("Failed to finalize file: {}", e))?;
+    Ok(job.target_path.to_string_lossy().to_string())
 }
 
-#[allow(clippy::too_many_arguments)]
-fn write_and_register_track(
+/// Registry write for the single-track path, AFTER the file is on disk, with
+/// the REQUESTED format_id (album_id None for single-track downloads).
+///
+/// Ordering & failure semantics (Addendum B.1 — replicated verbatim): if the
+/// file write/rename SUCCEEDED but this FAILS, the caller returns `Err` with
+/// the file LEFT ON DISK (orphaned, no registry row). Do NOT roll back the
+/// file or treat the registry failure as success. The album loop instead does
+/// its own best-effort registry write that swallows the error (§B.1
+/// album-path semantics).
+fn register_track(
     db: &LibraryDatabase,
     track_id: u64,
     requested_format_id: u32,
-    data: &[u8],
-    artist_name: &str,
-    album_title: &str,
-    quality_dir: &str,
-    track_number: u32,
-    track_title: &str,
-    response_format_id: u32,
-    response_mime_type: &str,
+    file_path: &str,
+) -> Result<(), String> {
+    db.mark_purchase_downloaded(track_id as i64, None, file_path, requested_format_id as i64)
+        .map_err(|e| e.to_string())
+}
+
+/// `get_track` → signed `getFileUrl` → resumable CDN fetch into the `.part` →
+/// rename. Shared by the single-track and album paths; returns the final
+/// on-disk path.
+async fn fetch_purchase_track_file(
+    client: &QobuzClient,
+    downloads: &DownloadManager,
+    track_id: u64,
+    format_id: u32,
+    album_id: Option<&str>,
     destination: &str,
+    quality_dir: &str,
 ) -> Result<String, String> {
-    let file_path = write_track_file(
-        data,
-        artist_name,
-        album_title,
+    let track = client
+        .get_track(track_id)
+        .await
+        .map_err(|e| format!("Failed to fetch track {}: {}", track_id, e))?;
+    let stream = client
+        .get_track_file_url_by_format(track_id, format_id)
+        .await
+        .map_err(|e| format!("Failed to get download URL for track {}: {}", track_id, e))?;
+
+    let artist_name = track
+        .performer
+        .as_ref()
+        .map(|artist| artist.name.clone())
+        .unwrap_or_else(|| "Unknown Artist".to_string());
+    let album_title = track
+        .album
+        .as_ref()
+        .map(|album| album.title.clone())
+        .unwrap_or_else(|| "Singles".to_string());
+
+    let job = prepare_track_job(
+        track_id,
+        format_id,
+        album_id,
+        &artist_name,
+        &album_title,
         quality_dir,
-        track_number,
-        track_title,
-        response_format_id,
-        response_mime_type,
+        track.track_number,
+        &track.title,
+        stream.format_id,
+        &stream.mime_type,
         destination,
     )?;
-
-    // Addendum B.1/B.2: registry write AFTER the file is on disk, with the
-    // REQUESTED format_id (album_id None for single-track downloads). A registry
-    // failure here returns Err while the file stays on disk (orphaned).
-    db.mark_purchase_downloaded(
-        track_id as i64,
-        None,
-        &file_path,
-        requested_format_id as i64,
-    )
-    .map_err(|e| e.to_string())?;
-
+    downloads.fetch(&job, &stream.url).await?;
+    let file_path = finalize_track_file(&job)?;
+    downloads.mark_completed(&job).await;
     Ok(file_path)
 }
 
@@ -562,12 +587,14 @@ fn write_and_register_track(
 ///      this crate the caller holds the `QobuzClient` by `&`, so there is no lock
 ///      to drop — the read guard is released by the controller before the
 ///      multi-minute CDN fetch. No behavioral divergence in the bytes path.)
-///   3. `QobuzClient::download_audio(&stream.url)` → `Vec<u8>` (HTTP/1.1-only, no
-///      total timeout — see `qbz-qobuz`).
-///   4. Resolve names: artist = `track.performer.name` else `"Unknown Artist"`;
+///   3. Resolve names: artist = `track.performer.name` else `"Unknown Artist"`;
 ///      album = `track.album.title` else `"Singles"`.
-///   5. Extension from RESPONSE `stream.format_id`/`mime_type` (B.2); path via
-///      `target_path`; `.part`→rename; registry write with REQUESTED `format_id`.
+///   4. Extension from RESPONSE `stream.format_id`/`mime_type` (B.2); path via
+///      `target_path`.
+///   5. `DownloadManager::fetch` streams the CDN bytes into the `.part`
+///      (HTTP/1.1-only, no total timeout), resuming an interrupted earlier
+///      attempt with a `Range` request; then `.part`→rename and the registry
+///      write with REQUESTED `format_id`.
 ///
 /// `quality_dir` is the UI-selected format label with `'/'→'-'` already applied
 /// (§7.5); it becomes the album-folder quality suffix AND the registry's quality
@@ -580,47 +607,24 @@ fn write_and_register_track(
 pub async fn download_purchase_track(
     client: &QobuzClient,
     db: &LibraryDatabase,
+    downloads: &DownloadManager,
     track_id: u64,
     format_id: u32,
     destination: &str,
     quality_dir: &str,
 ) -> Result<String, String> {
-    let track = client
-        .get_track(track_id)
-        .await
-        .map_err(|e| format!("Failed to fetch track {}: {}", track_id, e))?;
-    let stream = client
-        .get_track_file_url_by_format(track_id, format_id)
-        .await
-        .map_err(|e| format!("Failed to get download URL for track {}: {}", track_id, e))?;
-
-    let data = QobuzClient::download_audio(&stream.url).await?;
-
-    let artist_name = track
-        .performer
-        .as_ref()
-        .map(|artist| artist.name.clone())
-        .unwrap_or_else(|| "Unknown Artist".to_string());
-    let album_title = track
-        .album
-        .as_ref()
-        .map(|album| album.title.clone())
-        .unwrap_or_else(|| "Singles".to_string());
-
-    write_and_register_track(
-        db,
+    let file_path = fetch_purchase_track_file(
+        client,
+        downloads,
         track_id,
         format_id,
-        &data,
-        &artist_name,
-        &album_title,
-        quality_dir,
-        track.track_number,
-        &track.title,
-        stream.format_id,
-        &stream.mime_type,
+        None,
         destination,
+        quality_dir,
     )
+    .await?;
+    register_track(db, track_id, format_id, &file_path)?;
+    Ok(file_path)
 }
 
 /// Download-ONLY variant of the single-track primitive: identical CDN fetch +
@@ -638,49 +642,251 @@ pub async fn download_purchase_track(
 ///
 /// The caller is responsible for the best-effort registry write afterwards
 /// (`db.mark_purchase_downloaded(track_id, Some(album_id), &file_path,
-/// format_id)`), ignoring its error. Addendum B.2 (extension from RESPONSE,
-/// registry/qualityDir from REQUESTED), B.3 (silent overwrite), and B.5
-/// (restrictions ignored) all hold identically to `download_purchase_track`.
+/// format_id)`), ignoring its error. `album_id` is recorded on the resumable
+/// row so a resume after restart can do the same registry write. Addendum B.2
+/// (extension from RESPONSE, registry/qualityDir from REQUESTED), B.3 (silent
+/// overwrite), and B.5 (restrictions ignored) all hold identically to
+/// `download_purchase_track`.
 pub async fn download_purchase_track_file_only(
     client: &QobuzClient,
+    downloads: &DownloadManager,
     track_id: u64,
     format_id: u32,
+    album_id: &str,
     destination: &str,
     quality_dir: &str,
 ) -> Result<String, String> {
-    let track = client
-        .get_track(track_id)
-        .await
-        .map_err(|e| format!("Failed to fetch track {}: {}", track_id, e))?;
+    fetch_purchase_track_file(
+        client,
+        downloads,
+        track_id,
+        format_id,
+        Some(album_id),
+        destination,
+        quality_dir,
+    )
+    .await
+}
+
+/// Finish a download a previous run left behind (resume-on-restart): fetch a
+/// fresh signed URL for the row's track + REQUESTED format, continue the
+/// `.part` from where it stopped and rename it to the recorded target. The
+/// registry write is the caller's (best-effort, like the album loop), using
+/// `entry.album_id`. Returns the final on-disk path.
+pub async fn resume_purchase_download(
+    client: &QobuzClient,
+    downloads: &DownloadManager,
+    entry: &DownloadEntry,
+) -> Result<String, String> {
     let stream = client
-        .get_track_file_url_by_format(track_id, format_id)
+        .get_track_file_url_by_format(entry.track_id, entry.format_id)
         .await
-        .map_err(|e| format!("Failed to get download URL for track {}: {}", track_id, e))?;
+        .map_err(|e| {
+            format!(
+                "Failed to get download URL for track {}: {}",
+                entry.track_id, e
+            )
+        })?;
+    let job = DownloadJob::from_entry(entry);
+    downloads.fetch(&job, &stream.url).await?;
+    let file_path = finalize_track_file(&job)?;
+    downloads.mark_completed(&job).await;
+    Ok(file_path)
+}
 
-    let data = QobuzClient::download_audio(&stream.url).await?;
+/// File name of an album ZIP: `Artist - Album (year) [quality].zip`, each
+/// part run through `sanitize_filename` (so the `/` of a `24-bit/96kHz`
+/// label becomes `-`). The year and quality parts are dropped when unknown.
+pub fn album_zip_file_name(
+    artist_name: &str,
+    album_title: &str,
+    year: Option<u32>,
+    quality: &str,
+) -> String {
+    let mut name = format!(
+        "{} - {}",
+        sanitize_filename(artist_name),
+        sanitize_filename(album_title)
+    );
+    if let Some(year) = year {
+        name.push_str(&format!(" ({})", year));
+    }
+    if !quality.is_empty() {
+        name.push_str(&format!(" [{}]", sanitize_filename(quality)));
+    }
+    name.push_str(".zip");
+    name
+}
 
-    let artist_name = track
-        .performer
-        .as_ref()
-        .map(|artist| artist.name.clone())
-        .unwrap_or_else(|| "Unknown Artist".to_string());
-    let album_title = track
-        .album
+/// Tags for a track of `album`, built from the album payload so packaging an
+/// album costs no extra metadata requests.
+fn album_track_metadata(album: &Album, track: &qbz_models::Track) -> CompleteTrackMetadata {
+    CompleteTrackMetadata {
+        track_id: track.id,
+        title: track.title.clone(),
+        artist: track
+            .performer
+            .as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| album.artist.name.clone()),
+        album: album.title.clone(),
+        album_artist: Some(album.artist.name.clone()),
+        track_number: Some(track.track_number),
+        disc_number: track.media_number,
+        year: album_year(album),
+        genre: album.genre.as_ref().map(|g| g.name.clone()),
+        isrc: track.isrc.clone(),
+        label: album.label.as_ref().map(|l| l.name.clone()),
+        copyright: None,
+        composer: track.composer.as_ref().map(|c| c.name.clone()),
+        duration_secs: track.duration as u64,
+        artwork_url: album.image.large.clone(),
+    }
+}
+
+fn album_year(album: &Album) -> Option<u32> {
+    album
+        .release_date_original
+        .as_deref()
+        .and_then(|date| date.split('-').next())
+        .and_then(|year| year.parse().ok())
+}
+
+/// Download a purchased album into a single ZIP in `dest_dir` (the
+/// `v2_download_album_as_zip` port). Each track goes through the same
+/// getFileUrl → CDN pipeline as the album download, into a private staging
+/// folder under the system temp dir; FLAC tags are rewritten from the album
+/// payload and the cover embedded (both best-effort; MP3 files keep the tags
+/// they were served with), then everything is stored — audio is already
+/// compressed — under one `Artist - Album` folder in the archive, next to
+/// `cover.jpg`. The staging folder is removed afterwards, also on failure.
+///
+/// An existing archive of the same name is never overwritten: the call
+/// fails up front, and the archive is written to a temp file in `dest_dir`
+/// that is only renamed into place once complete.
+///
+/// Unlike the album loop, one failed track fails the whole archive (a ZIP
+/// with holes is worse than none), and the downloads are not resumable: the
+/// staging files are throwaway and must not be picked up by
+/// resume-on-restart or written to the purchases registry.
+///
+/// `on_progress(done, total)` runs after each track. Returns the ZIP path.
+pub async fn download_album_as_zip(
+    client: &QobuzClient,
+    album_id: &str,
+    format_id: u32,
+    quality_label: &str,
+    dest_dir: &Path,
+    mut on_progress: impl FnMut(u32, u32),
+) -> Result<PathBuf, String> {
+    let album = client
+        .get_album(album_id)
+        .await
+        .map_err(|e| format!("Failed to fetch album {}: {}", album_id, e))?;
+    let tracks = album
+        .tracks
         .as_ref()
-        .map(|album| album.title.clone())
-        .unwrap_or_else(|| "Singles".to_string());
+        .map(|container| container.items.clone())
+        .unwrap_or_default();
+    if tracks.is_empty() {
+        return Err(format!("Album {} has no tracks", album_id));
+    }
 
-    write_track_file(
-        &data,
-        &artist_name,
-        &album_title,
-        quality_dir,
-        track.track_number,
-        &track.title,
-        stream.format_id,
-        &stream.mime_type,
-        destination,
-    )
+    let zip_name = album_zip_file_name(
+        &album.artist.name,
+        &album.title,
+        album_year(&album),
+        quality_label,
+    );
+    let zip_path = dest_dir.join(&zip_name);
+    if zip_path.exists() {
+        return Err(format!("{} already exists", zip_path.display()));
+    }
+    // Removed on drop, whatever the outcome.
+    let staging_dir = tempfile::Builder::new()
+        .prefix("qbz-album-zip-")
+        .tempdir()
+        .map_err(|e| format!("Failed to create staging folder: {}", e))?;
+    let staging = staging_dir.path();
+
+    let downloads = DownloadManager::detached();
+    let destination = staging.to_string_lossy().to_string();
+    let total = tracks.len() as u32;
+    let mut files = Vec::with_capacity(tracks.len());
+    for (index, track) in tracks.iter().enumerate() {
+        let file_path = fetch_purchase_track_file(
+            client,
+            &downloads,
+            track.id,
+            format_id,
+            None,
+            &destination,
+            "",
+        )
+        .await?;
+        let metadata = album_track_metadata(&album, track);
+        let is_flac = Path::new(&file_path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("flac"));
+        if is_flac {
+            if let Err(e) = write_flac_tags(&file_path, &metadata) {
+                log::warn!("[Purchases] zip: tagging {} failed: {}", file_path, e);
+            }
+        }
+        if let Some(url) = metadata.artwork_url.as_deref() {
+            if let Err(e) = embed_artwork(&file_path, url).await {
+                log::warn!("[Purchases] zip: artwork for {} failed: {}", file_path, e);
+            }
+        }
+        files.push(PathBuf::from(file_path));
+        on_progress(index as u32 + 1, total);
+    }
+    if let Some(url) = album.image.large.as_deref() {
+        if save_album_artwork(staging, url).await.is_ok() {
+            files.push(staging.join("cover.jpg"));
+        }
+    }
+
+    let folder = zip_name.trim_end_matches(".zip");
+    write_zip(&zip_path, folder, &files)?;
+    Ok(zip_path)
+}
+
+/// Store `files` (no compression) under `folder/` in a new archive at
+/// `zip_path`. The archive is built in a temp file next to `zip_path` and
+/// renamed into place only when complete, failing rather than replacing an
+/// existing file; on error the temp file is dropped and `zip_path` is never
+/// touched.
+fn write_zip(zip_path: &Path, folder: &str, files: &[PathBuf]) -> Result<(), String> {
+    use zip::write::SimpleFileOptions;
+
+    let dir = zip_path.parent().unwrap_or_else(|| Path::new("."));
+    let temp = tempfile::Builder::new()
+        .prefix(".qbz-zip-")
+        .suffix(".part")
+        .tempfile_in(dir)
+        .map_err(|e| format!("Failed to create {}: {}", zip_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(std::io::BufWriter::new(temp));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for path in files {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        zip.start_file(format!("{}/{}", folder, name.to_string_lossy()), options)
+            .map_err(|e| format!("Failed to add {}: {}", path.display(), e))?;
+        let mut source = std::fs::File::open(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        std::io::copy(&mut source, &mut zip)
+            .map_err(|e| format!("Failed to add {}: {}", path.display(), e))?;
+    }
+    let temp = zip
+        .finish()
+        .map_err(|e| format!("Failed to finish {}: {}", zip_path.display(), e))?
+        .into_inner()
+        .map_err(|e| format!("Failed to finish {}: {}", zip_path.display(), e.error()))?;
+    temp.persist_noclobber(zip_path)
+        .map_err(|e| format!("Failed to save {}: {}", zip_path.display(), e.error))?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1088,6 +1294,43 @@ mod tests {
 
     // ── Slice 5: target_path ─────────────────────────────────────────────
 
+    #[test]
+    fn album_zip_file_name_template() {
+        assert_eq!(
+            album_zip_file_name(
+                "Miles Davis",
+                "Kind of Blue",
+                Some(1959),
+                "FLAC 24-bit/96kHz"
+            ),
+            "Miles Davis - Kind of Blue (1959) [FLAC 24-bit-96kHz].zip"
+        );
+        assert_eq!(
+            album_zip_file_name("AC/DC", "Back in Black", None, ""),
+            "AC-DC - Back in Black.zip"
+        );
+    }
+
+    #[test]
+    fn write_zip_never_replaces_an_existing_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let track = dir.path().join("01 - Song.flac");
+        std::fs::write(&track, b"audio").unwrap();
+        let zip_path = dir.path().join("Album.zip");
+
+        write_zip(&zip_path, "Album", std::slice::from_ref(&track)).unwrap();
+        let archive = zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+        assert_eq!(names, ["Album/01 - Song.flac"]);
+
+        // A second write fails and leaves the first archive as it was, with
+        // no temp file behind.
+        let before = std::fs::read(&zip_path).unwrap();
+        assert!(write_zip(&zip_path, "Other", &[track]).is_err());
+        assert_eq!(std::fs::read(&zip_path).unwrap(), before);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
     #[test]
     fn target_path_full_template_with_quality_and_track_number() {
         // {dest}/{artist}/{album [quality]}/{NN - title.ext}; quality joined by a
@@ -1141,6 +1384,43 @@ mod tests {
         LibraryDatabase::open(&dir.join("library.db")).expect("open temp library db")
     }
 
+    /// The single-track I/O tail with the CDN fetch replaced by writing
+    /// `data` into the `.part`: prepare → `.part` → rename → registry.
+    #[allow(clippy::too_many_arguments)]
+    fn write_and_register_track(
+        db: &LibraryDatabase,
+        track_id: u64,
+        requested_format_id: u32,
+        data: &[u8],
+        artist_name: &str,
+        album_title: &str,
+        quality_dir: &str,
+        track_number: u32,
+        track_title: &str,
+        response_format_id: u32,
+        response_mime_type: &str,
+        destination: &str,
+    ) -> Result<String, String> {
+        let job = prepare_track_job(
+            track_id,
+            requested_format_id,
+            None,
+            artist_name,
+            album_title,
+            quality_dir,
+            track_number,
+            track_title,
+            response_format_id,
+            response_mime_type,
+            destination,
+        )?;
+        std::fs::write(&job.part_path, data)
+            .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+        let file_path = finalize_track_file(&job)?;
+        register_track(db, track_id, requested_format_id, &file_path)?;
+        Ok(file_path)
+    }
+
     #[test]
     fn write_and_register_writes_part_then_renames_and_records_requested_format() {
         let tmp = tempfile::tempdir().unwrap();
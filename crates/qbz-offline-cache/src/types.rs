@@ -145,3 +145,66 @@ pub struct TrackCacheInfo {
     pub bit_depth: Option<u32>,
     pub sample_rate: Option<f64>,
 }
+
+/// Lifecycle of a resumable (purchase) download row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadStatus {
+    Pending,
+    Active,
+    Completed,
+    Failed,
+}
+
+impl DownloadStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "pending" => Self::Pending,
+            "active" => Self::Active,
+            "completed" => Self::Completed,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// One row of the resumable-download ledger (`resumable_downloads`)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadEntry {
+    pub track_id: u64,
+    /// The REQUESTED Qobuz format id (the registry's quality dimension).
+    pub format_id: u32,
+    pub album_id: Option<String>,
+    /// The last signed CDN URL used. Informational: signed URLs expire, so a
+    /// resume always asks for a fresh one.
+    pub url: String,
+    pub part_path: String,
+    pub target_path: String,
+    pub bytes_written: u64,
+    pub total_bytes: Option<u64>,
+    pub status: DownloadStatus,
+    pub error_message: Option<String>,
+    pub updated_at: String,
+}
+
+impl DownloadEntry {
+    /// 0-100; 0 while the size is unknown, 100 once completed.
+    pub fn progress_percent(&self) -> u8 {
+        if self.status == DownloadStatus::Completed {
+            return 100;
+        }
+        match self.total_bytes {
+            Some(total) if total > 0 => ((self.bytes_written.min(total) * 100) / total) as u8,
+            _ => 0,
+        }
+    }
+}
@@ -35,6 +35,7 @@ pub mod player;
 pub mod queue;
 
 // Re-export main types
+pub use player::format_chain::{FormatChain, FormatStep};
 pub use player::{
     BufferWriter, BufferedMediaSource, IncrementalStreamingSource, PlaybackEvent, PlaybackState,
     Player, SharedState, StreamingConfig,
@@ -0,0 +1,271 @@
+//! The audio processing chain of the current stream, stage by stage.
+//!
+//! Answers "is this bit-perfect, and if not, where does it stop being so?"
+//! for the developer panel. Every stage the audio thread can insert is
+//! listed in pipeline order (see `wrap_premix` / `wrap_postmix`), each with
+//! the format it receives and hands on and whether it is engaged; a stage
+//! that is off passes its input through untouched. The chain is rebuilt
+//! from the live [`SharedState`] and the current settings, so it describes
+//! what the player is doing now, not what the next track will get.
+
+use qbz_audio::{dsp, AudioBackendType, AudioSettings, BitPerfectMode};
+use serde::Serialize;
+
+use super::SharedState;
+
+/// One stage of the chain.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FormatStep {
+    pub name: String,
+    pub input_format: String,
+    pub output_format: String,
+    /// False when the stage is bypassed (input passes through unchanged).
+    pub active: bool,
+}
+
+/// The whole chain, decoder first. Empty when nothing is loaded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FormatChain {
+    pub steps: Vec<FormatStep>,
+}
+
+impl FormatChain {
+    /// True when no engaged stage alters the samples between the decoder
+    /// and a bit-perfect output.
+    pub fn is_bit_perfect(&self) -> bool {
+        !self.steps.is_empty()
+            && self
+                .steps
+                .iter()
+                .filter(|s| s.name != "Decoder" && !s.name.starts_with("Output"))
+                .all(|s| !s.active)
+    }
+}
+
+/// "44.1kHz", "192kHz".
+fn rate_label(hz: u32) -> String {
+    if hz % 1000 == 0 {
+        format!("{}kHz", hz / 1000)
+    } else {
+        format!("{:.1}kHz", hz as f64 / 1000.0)
+    }
+}
+
+fn step(name: impl Into<String>, input: &str, output: &str, active: bool) -> FormatStep {
+    FormatStep {
+        name: name.into(),
+        input_format: input.to_string(),
+        output_format: output.to_string(),
+        active,
+    }
+}
+
+/// Name of the output stage for the stream's bit-perfect mode.
+fn output_name(mode: Option<BitPerfectMode>, backend: Option<AudioBackendType>) -> &'static str {
+    match mode {
+        Some(BitPerfectMode::DirectHardware) | Some(BitPerfectMode::DoP) => "ALSA Direct",
+        Some(BitPerfectMode::PluginFallback) => "ALSA plughw",
+        _ => match backend {
+            Some(AudioBackendType::PipeWire) => "PipeWire",
+            Some(AudioBackendType::Pulse) => "PulseAudio",
+            Some(AudioBackendType::Jack) => "JACK",
+            Some(AudioBackendType::Alsa) => "ALSA",
+            Some(AudioBackendType::SystemDefault) | None => "System output",
+        },
+    }
+}
+
+/// Build the chain of the current stream (the `v2_get_playback_format_chain`
+/// port). `settings` are the player's live audio settings.
+pub fn get_format_chain(player_state: &SharedState, settings: &AudioSettings) -> FormatChain {
+    let rate = player_state.get_sample_rate();
+    if !player_state.has_loaded_audio() || rate == 0 {
+        return FormatChain::default();
+    }
+    let mode = player_state.get_bit_perfect_mode();
+    let output = output_name(mode, settings.backend_type);
+    let device_format = player_state
+        .get_output_format()
+        .map(|f| f.label())
+        .unwrap_or("float32");
+
+    // DSD direct bypasses the PCM pipeline entirely.
+    if player_state.is_dsd_direct() {
+        let packing = match player_state.dsd_mode() {
+            1 => "DoP int32",
+            2 => "DSD_U32_BE",
+            _ => "DSD_U32_LE",
+        };
+        let source = format!("DSD {}", rate_label(rate));
+        let out = format!("{} {}", packing, rate_label(rate));
+        return FormatChain {
+            steps: vec![
+                step("Decoder", &source, &source, true),
+                step(
+                    format!("Output: {} ({})", output, packing),
+                    &out,
+                    &out,
+                    true,
+                ),
+            ],
+        };
+    }
+
+    let source = format!("{}-bit {}", player_state.get_bit_depth(), rate_label(rate));
+    let float = format!("float32 {}", rate_label(rate));
+    let mut steps = vec![step("Decoder", &source, &float, true)];
+
+    let silence_skip = settings.podcast_mode && settings.skip_silence_threshold_ms > 0;
+    steps.push(step(
+        if silence_skip {
+            format!(
+                "SilenceSkip (gaps > {} ms)",
+                settings.skip_silence_threshold_ms
+            )
+        } else {
+            "SilenceSkip (off)".to_string()
+        },
+        &float,
+        &float,
+        silence_skip,
+    ));
+    steps.push(step(
+        if settings.podcast_mode {
+            format!("TimeStretch ({:.2}x)", player_state.playback_speed())
+        } else {
+            "TimeStretch (off)".to_string()
+        },
+        &float,
+        &float,
+        settings.podcast_mode,
+    ));
+
+    let normalized = player_state.get_normalization_gain().is_some();
+    steps.push(step(
+        if normalized {
+            format!(
+                "DynamicAmplify (EBU R128 {} LUFS)",
+                settings.normalization_target_lufs
+            )
+        } else {
+            "DynamicAmplify (off)".to_string()
+        },
+        &float,
+        &float,
+        normalized,
+    ));
+    let limiter = settings.true_peak_limit_dbfs.filter(|_| normalized);
+    steps.push(step(
+        match limiter {
+            Some(ceiling) => format!("TruePeakLimiter ({:.1} dBFS TP)", ceiling),
+            None => "TruePeakLimiter (off)".to_string(),
+        },
+        &float,
+        &float,
+        limiter.is_some(),
+    ));
+
+    let crossfade_ms = settings.crossfade_ms.max(settings.repeat_crossfade_ms);
+    let crossfade = crossfade_ms > 0 && !player_state.is_crossfade_suppressed();
+    steps.push(step(
+        if crossfade {
+            format!("Crossfade ({} ms)", crossfade_ms)
+        } else {
+            "Crossfade (off)".to_string()
+        },
+        &float,
+        &float,
+        crossfade,
+    ));
+    let eq = dsp::global_chain().is_active();
+    steps.push(step(
+        if eq {
+            "Parametric EQ"
+        } else {
+            "Parametric EQ (off)"
+        },
+        &float,
+        &float,
+        eq,
+    ));
+
+    // Only the direct hw: paths guarantee the device runs at the source
+    // rate; plughw and the shared servers may convert it.
+    let resampler = match mode {
+        Some(BitPerfectMode::DirectHardware) => None,
+        Some(BitPerfectMode::PluginFallback) => Some("Resampler (ALSA plughw, if needed)"),
+        Some(BitPerfectMode::Disabled) => Some("Resampler (sound server, if needed)"),
+        Some(BitPerfectMode::DoP) | None => None,
+    };
+    steps.push(step(
+        resampler.unwrap_or("Resampler (off)"),
+        &float,
+        &float,
+        resampler.is_some(),
+    ));
+    // Conversion to the device's integer format truncates; there is no
+    // dither stage.
+    steps.push(step("Dither (none)", &float, &float, false));
+
+    let device = format!("{} {}", device_format, rate_label(rate));
+    steps.push(step(
+        format!("Output: {} ({})", output, device),
+        &float,
+        &device,
+        true,
+    ));
+
+    FormatChain { steps }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untouched_stream_on_direct_hardware_is_bit_perfect() {
+        let state = SharedState::new();
+        let settings = AudioSettings::default();
+        assert!(get_format_chain(&state, &settings).steps.is_empty());
+
+        state.set_loaded_audio(true);
+        state.set_stream_quality(192_000, 24);
+        state.set_bit_perfect_mode(Some(BitPerfectMode::DirectHardware));
+        state.set_output_format(Some(qbz_audio::SampleFormat::Int32));
+        let settings = AudioSettings {
+            crossfade_ms: 0,
+            repeat_crossfade_ms: 0,
+            podcast_mode: false,
+            ..AudioSettings::default()
+        };
+        let chain = get_format_chain(&state, &settings);
+        assert_eq!(chain.steps[0].input_format, "24-bit 192kHz");
+        assert_eq!(
+            chain.steps.last().unwrap().name,
+            "Output: ALSA Direct (int32 192kHz)"
+        );
+        assert!(chain.is_bit_perfect());
+
+        // Normalization gain engages the amplifier and the limiter.
+        state.set_normalization_gain(Some(0.8));
+        let chain = get_format_chain(&state, &settings);
+        assert!(!chain.is_bit_perfect());
+        let limiter = chain
+            .steps
+            .iter()
+            .find(|s| s.name.starts_with("TruePeakLimiter"))
+            .unwrap();
+        assert_eq!(
+            limiter.active,
+            settings.true_peak_limit_dbfs.is_some(),
+            "{limiter:?}"
+        );
+    }
+
+    #[test]
+    fn rate_labels() {
+        assert_eq!(rate_label(44_100), "44.1kHz");
+        assert_eq!(rate_label(192_000), "192kHz");
+        assert_eq!(rate_label(88_200), "88.2kHz");
+    }
+}
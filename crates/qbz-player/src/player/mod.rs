@@ -10,6 +10,7 @@
 //! Uses a dedicated audio thread since rodio's OutputStream is not Send.
 //! Supports both rodio (PipeWire/Pulse) and direct ALSA (hw: devices).
 
+pub mod format_chain;
 mod playback_engine;
 mod streaming_source;
 
@@ -24,6 +25,7 @@ use rodio::cpal::{
     BufferSize, SampleFormat, StreamConfig, SupportedBufferSize, SupportedStreamConfig,
 };
 use rodio::{Decoder, DeviceSinkBuilder, MixerDeviceSink, Source};
+use std::cell::RefCell;
 use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
 use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, Ordering};
@@ -41,11 +43,13 @@ use symphonia::core::probe::Hint;
 use symphonia::default::{get_codecs, get_probe};
 
 use playback_engine::PlaybackEngine;
+use qbz_audio::podcast::clamp_speed;
 use qbz_audio::{
-    calculate_gain_factor, db_to_linear, extract_replaygain, AnalyzerMessage, AnalyzerTap,
-    AudioBackendType, AudioDiagnostic, AudioSettings, BackendConfig, BackendManager,
-    BitPerfectMode, DiagnosticSource, DynamicAmplify, LoudnessAnalyzer, LoudnessCache,
-    TappedSource, VisualizerTap,
+    calculate_gain_factor, db_to_linear, dsp, eq_chain, extract_replaygain, AnalyzerMessage,
+    AnalyzerTap, AudioBackendType, AudioDiagnostic, AudioSettings, BackendConfig, BackendManager,
+    BitPerfectMode, CrossfadeOut, CrossfadeSlot, DiagnosticSource, DspSource, DynamicAmplify,
+    LoudnessAnalyzer, LoudnessCache, SilenceSkip, TappedSource, TimeStretch, TruePeakLimiter,
+    VisualizerTap,
 };
 use qbz_models::{AssetOrigin, ExternalStreamAsset, Quality, StreamQualityInfo};
 use qbz_qobuz::QobuzClient;
@@ -124,6 +128,10 @@ struct GaplessPending {
     duration_secs: u64,
     data: Vec<u8>,
     normalization_gain: Option<f32>,
+    /// The crossfade hand-off this track was mixed in through (None = plain
+    /// gapless cut). Its measured overlap is how much of this track already
+    /// played when it becomes current.
+    crossfade: Option<CrossfadeSlot>,
 }
 
 struct CursorMediaSource {
@@ -795,6 +803,9 @@ fn compute_needs_new_stream(
     !has_stream || format_changed || coreaudio_shared_rate_mismatch
 }
 
+/// Bit depth format negotiation should preserve (hi-res masters are 24-bit)
+const PREFERRED_OUTPUT_BIT_DEPTH: u8 = 24;
+
 /// Try to create output stream using the backend system (if configured)
 /// Returns None if backend system is not configured (backend_type = None)
 ///
@@ -839,6 +850,32 @@ fn try_init_stream_with_backend(
         return Some(Err(msg));
     }
 
+    // Negotiate the sample format for ALSA card ids opened through CPAL
+    // (int32 before float). ALSA Direct hw: ids pick their own format at
+    // open; shared servers mix in float whatever we ask for.
+    let mut negotiation_log = Vec::new();
+    let negotiated_format = match audio_settings.output_device.as_deref() {
+        Some(device)
+            if backend_type == AudioBackendType::Alsa
+                && !qbz_audio::AlsaDirectStream::is_hw_device(device) =>
+        {
+            match qbz_audio::negotiate_format_traced(
+                device,
+                PREFERRED_OUTPUT_BIT_DEPTH,
+                &mut negotiation_log,
+            ) {
+                Ok(format) => Some(format),
+                Err(e) => {
+                    log::warn!("Format negotiation failed, keeping float output: {}", e);
+                    negotiation_log.push("Negotiation failed: keeping float32".to_string());
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    state.set_negotiation_log(negotiation_log);
+
     // Build backend config
     let config = BackendConfig {
         backend_type,
@@ -849,6 +886,7 @@ fn try_init_stream_with_backend(
         alsa_plugin: audio_settings.alsa_plugin,
         pw_force_bitperfect: audio_settings.pw_force_bitperfect,
         skip_sink_switch: audio_settings.skip_sink_switch,
+        negotiated_format,
     };
 
     // For ALSA backend with hw: devices, try direct ALSA first (Linux only)
@@ -868,6 +906,7 @@ fn try_init_stream_with_backend(
                         return Some(result.map(|(stream, mode)| {
                             log::info!("ALSA Direct stream created with mode: {:?}", mode);
                             state.set_bit_perfect_mode(Some(mode));
+                            state.set_output_format(Some(stream.sample_format()));
                             StreamType::AlsaDirect(Arc::new(stream))
                         }));
                     }
@@ -878,14 +917,21 @@ fn try_init_stream_with_backend(
 
     // JACK (#263 Tier 3): create the JACK client/stream directly (not via the
     // MixerDeviceSink trait). Opt-in routing-freedom mode, NOT bit-perfect.
+    // If the JACK server went away since the backend was chosen, fall through
+    // to the CPAL default stream below: the ALSA "default" PCM routes to
+    // PipeWire on every distro that ships pipewire-jack, so playback keeps
+    // going instead of failing the track.
     #[cfg(target_os = "linux")]
     if backend_type == AudioBackendType::Jack {
         match qbz_audio::JackStream::new(config.channels) {
             Ok(stream) => {
                 state.set_bit_perfect_mode(Some(qbz_audio::BitPerfectMode::Disabled));
+                state.set_output_format(Some(qbz_audio::SampleFormat::Float32));
                 return Some(Ok(StreamType::Jack(Arc::new(stream))));
             }
-            Err(e) => return Some(Err(format!("JACK backend unavailable: {e}"))),
+            Err(e) => log::warn!(
+                "JACK backend unavailable, falling back to PipeWire/system default: {e}"
+            ),
         }
     }
 
@@ -900,6 +946,13 @@ fn try_init_stream_with_backend(
                 output_sample_rate
             );
             state.set_bit_perfect_mode(Some(BitPerfectMode::Disabled));
+            // Packed 24-bit has no CPAL counterpart; the backend opened float
+            state.set_output_format(Some(
+                config
+                    .negotiated_format
+                    .filter(|f| f.to_cpal().is_some())
+                    .unwrap_or(qbz_audio::SampleFormat::Float32),
+            ));
             #[cfg(target_os = "macos")]
             let stream = if backend_type == AudioBackendType::SystemDefault {
                 StreamType::Rodio {
@@ -950,6 +1003,10 @@ pub struct PlaybackEvent {
     /// (pipewire/pulse/cpal) where bit-perfect is not guaranteed.
     #[serde(default)]
     pub bit_perfect_mode: Option<BitPerfectMode>,
+    /// Sample format the output stream was opened with. None when no stream
+    /// is active.
+    #[serde(default)]
+    pub output_format: Option<qbz_audio::SampleFormat>,
     /// Streaming buffer progress (0.0..1.0). `None` when not streaming or
     /// the track is fully buffered — drives the seek-bar cache overlay.
     #[serde(default)]
@@ -981,6 +1038,12 @@ pub struct SharedState {
     playback_start_millis: Arc<AtomicU64>,
     /// Position when playback was started/resumed (in seconds)
     position_at_start: Arc<AtomicU64>,
+    /// Media seconds per wall-clock second (f32 bits): the podcast-mode
+    /// speed while podcast mode is on, 1.0 otherwise. Read live by the
+    /// `TimeStretch` stage and by the position derivation.
+    playback_speed: Arc<AtomicU32>,
+    /// Media milliseconds dropped by `SilenceSkip` since the last anchor.
+    skipped_ms: Arc<AtomicU64>,
     /// Current output device name
     current_device: Arc<std::sync::RwLock<Option<String>>>,
     /// Stream error flag (set when ALSA/audio errors are detected)
@@ -1003,13 +1066,27 @@ pub struct SharedState {
     buffer_progress: Arc<AtomicU32>,
     /// Current bit-perfect mode encoded as u8 (see `bit_perfect_mode_from_u8`).
     /// 0 = Unknown (no stream active yet), 1 = Disabled (CPAL/Rodio / shared
-    /// system path), 2 = DirectHardware (ALSA hw:), 3 = PluginFallback (plughw:).
+    /// system path), 2 = DirectHardware (ALSA hw:), 3 = PluginFallback (plughw:),
+    /// 4 = DoP.
     bit_perfect_mode: Arc<AtomicU8>,
+    /// Output stream sample format encoded as u8: 0 = no stream, otherwise
+    /// 1 + index into `SampleFormat::PREFERENCE`.
+    output_format: Arc<AtomicU8>,
+    /// Formats tried by the last sample-format negotiation and whether the
+    /// device accepted them. Empty when the stream wasn't negotiated.
+    negotiation_log: Arc<Mutex<Vec<String>>>,
     /// Monotonic play generation (PR #583). Bumped by `Player::begin_play` on
     /// every new play intent. Lives in the shared state so the audio thread
     /// can detect that a queued `PlayStreaming` was superseded by a newer play
     /// and stop waiting on its initial buffer instead of blocking ~60s (#591).
     play_generation: Arc<AtomicU64>,
+    /// True while crossfade must not arm (repeat-one: fading a track into
+    /// itself is never wanted). Set by the queue owner.
+    crossfade_suppressed: Arc<AtomicBool>,
+    /// True when the upcoming gapless track is the repeat-all wrap back to
+    /// the start of the queue, so the hand-off uses `repeat_crossfade_ms`.
+    /// Set by the queue owner before `play_next`.
+    next_is_repeat_wrap: Arc<AtomicBool>,
 }
 
 impl Default for SharedState {
@@ -1030,6 +1107,8 @@ impl SharedState {
             volume: Arc::new(AtomicU32::new(0.75f32.to_bits())),
             playback_start_millis: Arc::new(AtomicU64::new(0)),
             position_at_start: Arc::new(AtomicU64::new(0)),
+            playback_speed: Arc::new(AtomicU32::new(1.0f32.to_bits())),
+            skipped_ms: Arc::new(AtomicU64::new(0)),
             current_device: Arc::new(std::sync::RwLock::new(None)),
             stream_error: Arc::new(AtomicBool::new(false)),
             stream_error_message: Arc::new(std::sync::RwLock::new(None)),
@@ -1040,7 +1119,11 @@ impl SharedState {
             gapless_next_track_id: Arc::new(AtomicU64::new(0)),
             buffer_progress: Arc::new(AtomicU32::new(0)),
             bit_perfect_mode: Arc::new(AtomicU8::new(0)),
+            output_format: Arc::new(AtomicU8::new(0)),
+            negotiation_log: Arc::new(Mutex::new(Vec::new())),
             play_generation: Arc::new(AtomicU64::new(0)),
+            crossfade_suppressed: Arc::new(AtomicBool::new(false)),
+            next_is_repeat_wrap: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -1127,6 +1210,7 @@ impl SharedState {
             Some(BitPerfectMode::Disabled) => 1,
             Some(BitPerfectMode::DirectHardware) => 2,
             Some(BitPerfectMode::PluginFallback) => 3,
+            Some(BitPerfectMode::DoP) => 4,
         };
         self.bit_perfect_mode.store(code, Ordering::SeqCst);
     }
@@ -1139,10 +1223,40 @@ impl SharedState {
             1 => Some(BitPerfectMode::Disabled),
             2 => Some(BitPerfectMode::DirectHardware),
             3 => Some(BitPerfectMode::PluginFallback),
+            4 => Some(BitPerfectMode::DoP),
             _ => None,
         }
     }
 
+    /// Record the sample format the output stream was opened with.
+    pub fn set_output_format(&self, format: Option<qbz_audio::SampleFormat>) {
+        let code = format
+            .and_then(|f| qbz_audio::SampleFormat::PREFERENCE.iter().position(|p| *p == f))
+            .map(|i| i as u8 + 1)
+            .unwrap_or(0);
+        self.output_format.store(code, Ordering::SeqCst);
+    }
+
+    pub fn get_output_format(&self) -> Option<qbz_audio::SampleFormat> {
+        let code = self.output_format.load(Ordering::SeqCst) as usize;
+        code.checked_sub(1)
+            .and_then(|i| qbz_audio::SampleFormat::PREFERENCE.get(i).copied())
+    }
+
+    /// Record the trace of the format negotiation behind the current stream.
+    pub fn set_negotiation_log(&self, log: Vec<String>) {
+        if let Ok(mut current) = self.negotiation_log.lock() {
+            *current = log;
+        }
+    }
+
+    pub fn get_negotiation_log(&self) -> Vec<String> {
+        self.negotiation_log
+            .lock()
+            .map(|log| log.clone())
+            .unwrap_or_default()
+    }
+
     pub fn get_sample_rate(&self) -> u32 {
         self.sample_rate.load(Ordering::SeqCst)
     }
@@ -1197,6 +1311,22 @@ impl SharedState {
         self.current_device.read().ok().and_then(|d| d.clone())
     }
 
+    pub fn set_crossfade_suppressed(&self, suppressed: bool) {
+        self.crossfade_suppressed.store(suppressed, Ordering::SeqCst);
+    }
+
+    pub fn is_crossfade_suppressed(&self) -> bool {
+        self.crossfade_suppressed.load(Ordering::SeqCst)
+    }
+
+    pub fn set_next_is_repeat_wrap(&self, wraps: bool) {
+        self.next_is_repeat_wrap.store(wraps, Ordering::SeqCst);
+    }
+
+    pub fn is_next_repeat_wrap(&self) -> bool {
+        self.next_is_repeat_wrap.load(Ordering::SeqCst)
+    }
+
     pub fn set_gapless_ready(&self, ready: bool) {
         self.gapless_ready.store(ready, Ordering::SeqCst);
     }
@@ -1229,7 +1359,7 @@ impl SharedState {
             .unwrap_or_default()
             .as_millis() as u64;
 
-        let elapsed_secs = (now_millis.saturating_sub(start_millis)) / 1000;
+        let elapsed_secs = self.media_elapsed_ms(now_millis.saturating_sub(start_millis)) / 1000;
         let position_at_start = self.position_at_start.load(Ordering::SeqCst);
         let duration = self.duration.load(Ordering::SeqCst);
 
@@ -1247,7 +1377,8 @@ impl SharedState {
     /// resolution); semantics mirror `current_position` line by line:
     /// paused / no anchor → stored coarse position ×1000; playing →
     /// `position_at_start*1000 + (now_ms - start_millis)`, clamped to
-    /// `duration*1000`.
+    /// `duration*1000`. In podcast mode the elapsed part is scaled by the
+    /// speed and skipped silence is added (see `media_elapsed_ms`).
     pub fn current_position_ms(&self) -> u64 {
         if !self.is_playing.load(Ordering::SeqCst) {
             return self.position.load(Ordering::SeqCst).saturating_mul(1000);
@@ -1263,7 +1394,7 @@ impl SharedState {
             .unwrap_or_default()
             .as_millis() as u64;
 
-        let elapsed_ms = now_millis.saturating_sub(start_millis);
+        let elapsed_ms = self.media_elapsed_ms(now_millis.saturating_sub(start_millis));
         let position_at_start_ms = self
             .position_at_start
             .load(Ordering::SeqCst)
@@ -1274,6 +1405,38 @@ impl SharedState {
         position_at_start_ms.saturating_add(elapsed_ms).min(duration_ms)
     }
 
+    /// Media time covered by `wall_ms` of playback since the anchor: the
+    /// wall-clock time at the current speed plus any silence skipped.
+    fn media_elapsed_ms(&self, wall_ms: u64) -> u64 {
+        let played = (wall_ms as f64 * self.playback_speed() as f64) as u64;
+        played.saturating_add(self.skipped_ms.load(Ordering::SeqCst))
+    }
+
+    /// Effective playback speed (1.0 unless podcast mode is on).
+    pub fn playback_speed(&self) -> f32 {
+        f32::from_bits(self.playback_speed.load(Ordering::SeqCst))
+    }
+
+    /// Change the effective speed. While playing, the anchors move to the
+    /// current position first so time already played keeps its old rate;
+    /// the sub-second remainder is carried in the start time.
+    fn set_effective_speed(&self, speed: f32) {
+        if self.is_playing() && self.playback_start_millis.load(Ordering::SeqCst) != 0 {
+            let position_ms = self.current_position_ms();
+            let now_millis = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let carried = ((position_ms % 1000) as f64 / speed as f64) as u64;
+            self.skipped_ms.store(0, Ordering::SeqCst);
+            self.position_at_start
+                .store(position_ms / 1000, Ordering::SeqCst);
+            self.playback_start_millis
+                .store(now_millis.saturating_sub(carried), Ordering::SeqCst);
+        }
+        self.playback_speed.store(speed.to_bits(), Ordering::SeqCst);
+    }
+
     /// Mark playback as started/resumed at current position
     fn start_playback_timer(&self, position: u64) {
         let now_millis = std::time::SystemTime::now()
@@ -1281,6 +1444,7 @@ impl SharedState {
             .unwrap_or_default()
             .as_millis() as u64;
 
+        self.skipped_ms.store(0, Ordering::SeqCst);
         self.playback_start_millis
             .store(now_millis, Ordering::SeqCst);
         self.position_at_start.store(position, Ordering::SeqCst);
@@ -1359,6 +1523,12 @@ impl Player {
     ) -> Self {
         let (tx, rx) = mpsc::channel::<AudioCommand>();
         let state = SharedState::new();
+        state.set_effective_speed(effective_speed(
+            audio_settings.podcast_mode,
+            audio_settings.playback_speed,
+        ));
+
+        dsp::global_chain().replace(eq_chain(&audio_settings.eq_bands));
         let thread_state = state.clone();
 
         // Clone settings for thread
@@ -1387,22 +1557,63 @@ impl Player {
             let _analyzer_handle = LoudnessAnalyzer::spawn(analyzer_rx, loudness_cache.clone());
             let analyzer_enabled = Arc::new(AtomicBool::new(false));
 
+            // Crossfade hand-off of the track currently at the head of the
+            // pipeline (None = crossfade off, or the source has no known
+            // duration). Replaced every time a source is wrapped.
+            let crossfade_slot: RefCell<Option<CrossfadeSlot>> = RefCell::new(None);
+
             // Helper to wrap source with visualizer tap, normalization, and diagnostic capture
             // Pipeline order (normalization ON):
-            //   Diagnostic (raw) → AnalyzerTap → DynamicAmplify → Visualizer
-            // Pipeline order (normalization OFF — bit-perfect):
-            //   Diagnostic (raw) → Visualizer
-            let wrap_source = |source: Box<dyn Source<Item = f32> + Send>,
+            //   Diagnostic (raw) → AnalyzerTap → DynamicAmplify → TruePeakLimiter → Crossfade → DSP → Visualizer
+            // Podcast mode inserts SilenceSkip → TimeStretch right after
+            // Diagnostic, so the gain stages see the stretched signal.
+            // Pipeline order (normalization OFF, crossfade off, empty DSP chain — bit-perfect):
+            //   Diagnostic (raw) → DSP (pass-through) → Visualizer
+            //
+            // Split in two halves so a crossfade can mix the next track in
+            // BEFORE the shared DSP chain and visualizer tap: per-track
+            // stages (pre-mix) run once per decoder, post-mix stages run once
+            // on the mixed signal.
+            let wrap_premix = |source: Box<dyn Source<Item = f32> + Send>,
                                normalization_gain: Option<f32>,
                                gain_atomic: Option<Arc<AtomicU32>>,
                                analyzer_tx: &SyncSender<AnalyzerMessage>,
                                analyzer_enabled: &Arc<AtomicBool>|
-             -> Box<dyn Source<Item = f32> + Send> {
+             -> (Box<dyn Source<Item = f32> + Send>, Option<CrossfadeSlot>) {
                 // Diagnostic tap (innermost — captures raw decoded samples)
                 let source: Box<dyn Source<Item = f32> + Send> =
                     Box::new(DiagnosticSource::new(source, thread_diagnostic.clone()));
 
+                // Podcast mode: shorten pauses, then time-stretch to the
+                // live speed. Off = not in the chain at all.
+                let podcast = thread_settings
+                    .lock()
+                    .ok()
+                    .filter(|s| s.podcast_mode)
+                    .map(|s| s.skip_silence_threshold_ms);
+                let source: Box<dyn Source<Item = f32> + Send> = match podcast {
+                    Some(gap_ms) => {
+                        log::info!(
+                            "Audio thread: podcast mode at {:.2}x (silence gap {}ms)",
+                            thread_state.playback_speed(),
+                            gap_ms
+                        );
+                        let source: Box<dyn Source<Item = f32> + Send> = if gap_ms > 0 {
+                            Box::new(SilenceSkip::new(
+                                source,
+                                gap_ms,
+                                thread_state.skipped_ms.clone(),
+                            ))
+                        } else {
+                            source
+                        };
+                        Box::new(TimeStretch::new(source, thread_state.playback_speed.clone()))
+                    }
+                    None => source,
+                };
+
                 // Normalization: dynamic (Phase 2) > static (Phase 1 fallback) > none (bit-perfect)
+                let normalized = gain_atomic.is_some();
                 let source: Box<dyn Source<Item = f32> + Send> =
                     if let Some(gain_atomic) = gain_atomic {
                         let initial_gain = normalization_gain.unwrap_or(1.0);
@@ -1425,6 +1636,53 @@ impl Player {
                         source
                     };
 
+                // True-peak limiter: only behind normalization gain (the
+                // bit-perfect path never gets one), catches the inter-sample
+                // overs the gain can create.
+                let true_peak_limit = if normalization_gain.is_some() || normalized {
+                    thread_settings
+                        .lock()
+                        .ok()
+                        .and_then(|s| s.true_peak_limit_dbfs)
+                } else {
+                    None
+                };
+                let source: Box<dyn Source<Item = f32> + Send> = match true_peak_limit {
+                    Some(threshold) => {
+                        log::info!("Audio thread: true-peak limiter at {:.1} dBTP", threshold);
+                        Box::new(TruePeakLimiter::new(source, threshold))
+                    }
+                    None => source,
+                };
+
+                // Crossfade out: only when enabled, not suppressed by
+                // repeat-one, and the track length is known (the fade window
+                // is scheduled from the end). Whether the next track is the
+                // repeat-all wrap is only known at hand-off, so the window is
+                // the longer of the two fades; the offer trims it.
+                let crossfade_ms = thread_settings
+                    .lock()
+                    .map(|s| s.crossfade_ms.max(s.repeat_crossfade_ms))
+                    .unwrap_or(0);
+                if crossfade_ms > 0
+                    && !thread_state.is_crossfade_suppressed()
+                    && source.total_duration().is_some()
+                {
+                    let out = CrossfadeOut::new(source, Duration::from_millis(crossfade_ms as u64));
+                    let slot = out.slot();
+                    (Box::new(out), Some(slot))
+                } else {
+                    (source, None)
+                }
+            };
+
+            let wrap_postmix = |source: Box<dyn Source<Item = f32> + Send>|
+             -> Box<dyn Source<Item = f32> + Send> {
+                // Software DSP chain (parametric EQ). Always wrapped so a band
+                // edit applies mid-track; an empty chain is a lock-free bypass.
+                let source: Box<dyn Source<Item = f32> + Send> =
+                    Box::new(DspSource::new(source, dsp::global_chain().clone()));
+
                 // Visualizer tap (outermost)
                 if let Some(ref tap) = thread_viz_tap {
                     Box::new(TappedSource::new(
@@ -1437,6 +1695,23 @@ impl Player {
                 }
             };
 
+            let wrap_source = |source: Box<dyn Source<Item = f32> + Send>,
+                               normalization_gain: Option<f32>,
+                               gain_atomic: Option<Arc<AtomicU32>>,
+                               analyzer_tx: &SyncSender<AnalyzerMessage>,
+                               analyzer_enabled: &Arc<AtomicBool>|
+             -> Box<dyn Source<Item = f32> + Send> {
+                let (source, slot) = wrap_premix(
+                    source,
+                    normalization_gain,
+                    gain_atomic,
+                    analyzer_tx,
+                    analyzer_enabled,
+                );
+                *crossfade_slot.borrow_mut() = slot;
+                wrap_postmix(source)
+            };
+
             // Get the audio host
             let host = rodio::cpal::default_host();
 
@@ -2768,6 +3043,7 @@ impl Player {
                                 thread_state.set_loaded_audio(true);
                                 thread_state.set_stream_error(false);
                                 thread_state.set_stream_quality(dsd_rate, 1);
+                                thread_state.set_bit_perfect_mode(Some(BitPerfectMode::DoP));
                                 thread_state.duration.store(duration, Ordering::SeqCst);
                                 thread_state.set_dsd_mode(1);
                                 thread_state.is_playing.store(true, Ordering::SeqCst);
@@ -2989,6 +3265,7 @@ impl Player {
                                             duration_secs: duration,
                                             data: Vec::new(),
                                             normalization_gain: None,
+                                            crossfade: None,
                                         });
                                         thread_state.set_gapless_next_track_id(track_id);
                                         thread_state.set_gapless_ready(false);
@@ -3621,14 +3898,50 @@ impl Player {
                                     (None, None)
                                 };
 
-                            // Wrap source with normalization/visualizer pipeline
-                            let source = wrap_source(
+                            // Wrap source with normalization/visualizer pipeline.
+                            // With crossfade armed on the current track, the next
+                            // track's pre-mix source is handed to the current
+                            // track's slot and the engine queues the tail that
+                            // continues it after the overlap.
+                            let previous_slot = crossfade_slot.borrow_mut().take();
+                            let (premixed, next_slot) = wrap_premix(
                                 source,
                                 normalization,
                                 gain_atomic,
                                 &analyzer_tx,
                                 &analyzer_enabled,
                             );
+                            *crossfade_slot.borrow_mut() = next_slot;
+                            let wraps = thread_state.is_next_repeat_wrap();
+                            let fade_ms = match previous_slot {
+                                Some(_) => thread_settings
+                                    .lock()
+                                    .map(|s| {
+                                        if wraps {
+                                            s.repeat_crossfade_ms
+                                        } else {
+                                            s.crossfade_ms
+                                        }
+                                    })
+                                    .unwrap_or(0),
+                                None => 0,
+                            };
+                            let source = match previous_slot.clone() {
+                                Some(slot) => {
+                                    log::info!(
+                                        "Crossfade: mixing track {} into the current track's tail ({}ms{})",
+                                        track_id,
+                                        fade_ms,
+                                        if wraps { ", repeat wrap" } else { "" }
+                                    );
+                                    slot.offer_with_fade(
+                                        premixed,
+                                        Duration::from_millis(fade_ms as u64),
+                                    );
+                                    wrap_postmix(Box::new(slot.tail()))
+                                }
+                                None => wrap_postmix(premixed),
+                            };
 
                             // Append to existing Sink (gapless queue).
                             // Late-gapless race: this append can arrive AFTER
@@ -3669,6 +3982,7 @@ impl Player {
                                 duration_secs: actual_duration,
                                 data,
                                 normalization_gain: normalization,
+                                crossfade: previous_slot,
                             });
                             thread_state.set_gapless_next_track_id(track_id);
                             thread_state.set_gapless_ready(false); // Request fulfilled
@@ -3794,7 +4108,15 @@ impl Player {
                                         thread_state
                                             .duration
                                             .store(pending.duration_secs, Ordering::SeqCst);
-                                        thread_state.start_playback_timer(0);
+                                        // The overlap is final by now: the outgoing
+                                        // track has run out. A late hand-off mixes
+                                        // less than the configured fade.
+                                        let start_offset_secs = pending
+                                            .crossfade
+                                            .as_ref()
+                                            .map(|slot| slot.overlap().as_secs_f64().round() as u64)
+                                            .unwrap_or(0);
+                                        thread_state.start_playback_timer(start_offset_secs);
                                         current_audio_data = Some(pending.data.clone());
                                         current_normalization_gain = pending.normalization_gain;
                                         thread_state
@@ -3858,15 +4180,25 @@ impl Player {
                                 // for this, just plumb it through
                                 // AudioSettings and read here.
                                 const GAPLESS_LEAD_SECS: u64 = 10;
-                                let gapless_enabled = thread_settings
+                                // Crossfade needs the next track pre-queued
+                                // too, so it arms the same request — and
+                                // early enough that the next track is decoded
+                                // before the fade window opens, or a long
+                                // crossfade would be cut short.
+                                let (gapless_enabled, crossfade_ms) = thread_settings
                                     .lock()
                                     .ok()
-                                    .map(|s| s.gapless_enabled)
-                                    .unwrap_or(false);
+                                    .map(|s| {
+                                        let fade = s.crossfade_ms.max(s.repeat_crossfade_ms);
+                                        (s.gapless_enabled || fade > 0, fade)
+                                    })
+                                    .unwrap_or((false, 0));
+                                let lead_secs =
+                                    GAPLESS_LEAD_SECS + (crossfade_ms as u64).div_ceil(1000);
                                 if gapless_enabled
                                     && !transition_consumed_pending
                                     && dur > 0
-                                    && pos + GAPLESS_LEAD_SECS >= dur
+                                    && pos + lead_secs >= dur
                                     && gapless_pending.is_none()
                                     && !gapless_request_armed
                                     && !thread_state.is_gapless_ready()
@@ -4099,6 +4431,10 @@ impl Player {
             .map(|s| s.streaming_only)
             .unwrap_or(false);
 
+        // Going to the network: cap the tier to what the link sustains
+        // (a no-op unless the user set bandwidth thresholds).
+        let quality = client.bandwidth_capped_quality(quality).await;
+
         // Try CMAF streaming pipeline first.
         // Only the init segment is fetched synchronously; audio segments
         // stream in a background task.
@@ -4329,6 +4665,7 @@ impl Player {
         }
 
         self.audio_cache.mark_fetching(track_id);
+        let quality = client.bandwidth_capped_quality(quality).await;
         log::info!("[PREFETCH] Prefetching track {track_id} at {quality:?}");
 
         // Try CMAF full download first (Akamai CDN), legacy full download
@@ -4419,6 +4756,7 @@ impl Player {
 
         // CMAF full download (Akamai CDN), legacy full download as
         // fallback. Warm L1 so a re-gapless / replay skips the network.
+        let quality = client.bandwidth_capped_quality(quality).await;
         let downloaded = match qbz_qobuz::cmaf::download_full(client, track_id, quality).await {
             Ok(data) => Some(data),
             Err(e) => {
@@ -4501,6 +4839,7 @@ impl Player {
         }
 
         // Cold: CMAF full download (Akamai CDN) -> decrypted FLAC.
+        let quality = client.bandwidth_capped_quality(quality).await;
         match qbz_qobuz::cmaf::download_full_with_quality(client, track_id, quality).await {
             Ok((bytes, q)) => {
                 log::info!(
@@ -4876,7 +5215,7 @@ impl Player {
                             .send(AudioCommand::PlayDsdDop { path, track_id })
                             .map_err(|e| format!("Failed to send DoP play command: {}", e));
                     }
-                    log::info!(
+                    log::warn!(
                         "Player: DoP selected but device lacks the {} Hz carrier — converting to PCM",
                         carrier
                     );
@@ -5289,15 +5628,119 @@ impl Player {
             .map_err(|e| format!("Failed to send release command: {}", e))
     }
 
+    /// Hot-plug: a playback device was removed. If it was the one we're
+    /// streaming to, drop it and reopen on the default device so a yanked DAC
+    /// doesn't leave the audio thread writing to a dead handle. Returns true
+    /// when the active device was lost.
+    pub fn handle_device_removed(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        {
+            let Some(device) = self.state.current_device() else {
+                return false;
+            };
+            if qbz_audio::is_device_present(&device) != Some(false) {
+                return false;
+            }
+            log::warn!(
+                "Player: active output '{}' disappeared — reopening on the default device",
+                device
+            );
+            if let Err(e) = self.reinit_device(None) {
+                log::error!("Player: reinit after device loss failed: {}", e);
+            }
+            true
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            false
+        }
+    }
+
+    /// Normalization target (LUFS), or None while normalization is off
+    pub fn normalization_target_lufs(&self) -> Option<f32> {
+        self.audio_settings
+            .lock()
+            .ok()
+            .filter(|s| s.normalization_enabled)
+            .map(|s| s.normalization_target_lufs)
+    }
+
+    /// Turn podcast mode on or off. The stretcher lives in the per-track
+    /// chain, so a loaded track is re-seeked in place to rebuild it.
+    pub fn set_podcast_mode(&self, enabled: bool) -> Result<(), String> {
+        let speed = {
+            let mut settings = self
+                .audio_settings
+                .lock()
+                .map_err(|_| "Failed to lock audio settings".to_string())?;
+            if settings.podcast_mode == enabled {
+                return Ok(());
+            }
+            settings.podcast_mode = enabled;
+            settings.playback_speed
+        };
+        self.state.set_effective_speed(effective_speed(enabled, speed));
+        if self.state.has_loaded_audio() {
+            self.rebuild_chain()?;
+        }
+        Ok(())
+    }
+
+    /// Set the podcast-mode speed (clamped to 0.5–3.0×). Applies mid-track
+    /// while podcast mode is on; otherwise it is only remembered.
+    pub fn set_playback_speed(&self, speed: f32) -> Result<(), String> {
+        let speed = clamp_speed(speed);
+        let enabled = {
+            let mut settings = self
+                .audio_settings
+                .lock()
+                .map_err(|_| "Failed to lock audio settings".to_string())?;
+            settings.playback_speed = speed;
+            settings.podcast_mode
+        };
+        if enabled {
+            self.state.set_effective_speed(speed);
+        }
+        Ok(())
+    }
+
+    /// Re-seek the loaded track to where it is, which re-wraps its source
+    /// with the current per-track stages. DSD-direct streams can't seek and
+    /// never pass through the f32 chain anyway.
+    fn rebuild_chain(&self) -> Result<(), String> {
+        if self.state.is_dsd_direct() {
+            return Ok(());
+        }
+        self.seek(self.state.current_position())
+    }
+
+    /// Whether local files should skip their leading/trailing silence.
+    pub fn skip_silence_enabled(&self) -> bool {
+        self.audio_settings
+            .lock()
+            .map(|s| s.skip_silence)
+            .unwrap_or(false)
+    }
+
     /// Reload audio settings from fresh config (e.g., after database update)
     /// Call this before reinit_device() to ensure Player uses latest settings
     pub fn reload_settings(&self, settings: AudioSettings) -> Result<(), String> {
-        if let Ok(mut current_settings) = self.audio_settings.lock() {
-            *current_settings = settings;
-            Ok(())
+        // The EQ is live: re-install the chain so band edits apply mid-track
+        // without a device reinit.
+        dsp::global_chain().replace(eq_chain(&settings.eq_bands));
+        let podcast_mode = settings.podcast_mode;
+        let speed = settings.playback_speed;
+        let was_podcast_mode = if let Ok(mut current_settings) = self.audio_settings.lock() {
+            std::mem::replace(&mut *current_settings, settings).podcast_mode
         } else {
-            Err("Failed to lock audio settings".to_string())
+            return Err("Failed to lock audio settings".to_string());
+        };
+        self.state
+            .set_effective_speed(effective_speed(podcast_mode, speed));
+        if podcast_mode != was_podcast_mode && self.state.has_loaded_audio() {
+            self.rebuild_chain()?;
         }
+        Ok(())
     }
 
     /// Get current playback state with real-time position
@@ -5311,6 +5754,15 @@ impl Player {
         })
     }
 
+    /// The processing chain of the current stream (decoder to device), for
+    /// the developer panel.
+    pub fn format_chain(&self) -> format_chain::FormatChain {
+        match self.audio_settings.lock() {
+            Ok(settings) => format_chain::get_format_chain(&self.state, &settings),
+            Err(_) => format_chain::FormatChain::default(),
+        }
+    }
+
     /// Get playback event for emitting to frontend
     pub fn get_playback_event(&self) -> PlaybackEvent {
         let sample_rate = self.state.get_sample_rate();
@@ -5333,11 +5785,21 @@ impl Player {
             gapless_ready: self.state.is_gapless_ready(),
             gapless_next_track_id: self.state.get_gapless_next_track_id(),
             bit_perfect_mode: self.state.get_bit_perfect_mode(),
+            output_format: self.state.get_output_format(),
             buffer_progress: self.state.get_buffer_progress(),
         }
     }
 }
 
+/// Media seconds per wall-clock second for the given podcast settings.
+fn effective_speed(podcast_mode: bool, speed: f32) -> f32 {
+    if podcast_mode {
+        clamp_speed(speed)
+    } else {
+        1.0
+    }
+}
+
 /// Playback state snapshot
 #[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct PlaybackState {
@@ -5580,6 +6042,38 @@ mod tests {
         assert_eq!(state.current_position_ms(), 300_000);
     }
 
+    #[test]
+    fn podcast_speed_and_skipped_silence_advance_the_position() {
+        use std::sync::atomic::Ordering;
+
+        let state = super::SharedState::new();
+        state.duration.store(3_600, Ordering::SeqCst);
+        state.is_playing.store(true, Ordering::SeqCst);
+        state.set_effective_speed(2.0);
+        state.start_playback_timer(10);
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        // 1.5s of wall clock at 2x, plus 700ms of skipped pauses.
+        state
+            .playback_start_millis
+            .store(now_ms - 1_500, Ordering::SeqCst);
+        state.skipped_ms.store(700, Ordering::SeqCst);
+        let pos = state.current_position_ms();
+        assert!(
+            (13_650..=14_100).contains(&pos),
+            "expected ~13700ms, got {pos}"
+        );
+
+        // A speed change keeps the position where it was.
+        state.set_effective_speed(1.0);
+        let after = state.current_position_ms();
+        assert!(after.abs_diff(pos) < 100, "{pos} -> {after}");
+        assert_eq!(state.skipped_ms.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn external_content_type_prefers_server_mime() {
         assert_eq!(external_content_type("audio/flac", 7), "audio/flac");
@@ -8,9 +8,10 @@
 //! - Play history for going back
 
 use std::collections::VecDeque;
+use std::path::Path;
 use std::sync::Mutex;
 
-use qbz_models::{QueueState, QueueTrack, RepeatMode};
+use qbz_models::{PlaybackSource, QueueState, QueueStats, QueueTrack, RepeatMode};
 
 #[derive(Debug, PartialEq, Eq)]
 enum QueueMoveDirection {
@@ -451,6 +452,28 @@ impl QueueManager {
         removed
     }
 
+    /// Remove every upcoming track matching `pred`, in play order. Like
+    /// `remove_upcoming_after` this works in upcoming space and peels matches
+    /// off the tail inward, so the positions still to visit never shift.
+    /// Returns the removed tracks in play order.
+    pub fn remove_upcoming_where(&self, pred: impl Fn(&QueueTrack) -> bool) -> Vec<QueueTrack> {
+        let positions: Vec<usize> = self
+            .get_state_full()
+            .upcoming
+            .iter()
+            .enumerate()
+            .filter(|(_, track)| pred(track))
+            .map(|(pos, _)| pos)
+            .collect();
+        let mut removed: Vec<QueueTrack> = positions
+            .into_iter()
+            .rev()
+            .filter_map(|pos| self.remove_upcoming_track(pos))
+            .collect();
+        removed.reverse();
+        removed
+    }
+
     /// Remove all tracks at indices greater than `index`. The track at
     /// `index` is preserved. Returns the number of tracks removed.
     /// If the marker referenced a track in the removed range, the marker
@@ -634,6 +657,22 @@ impl QueueManager {
         next_idx.and_then(|idx| state.tracks.get(idx).cloned())
     }
 
+    /// True when the next track is the repeat-all wrap from the end of the
+    /// queue (or shuffle order) back to its start.
+    pub fn next_wraps_around(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        if state.tracks.is_empty() || state.repeat != RepeatMode::All {
+            return false;
+        }
+        if state.shuffle {
+            state.shuffle_position + 1 >= state.shuffle_order.len()
+        } else {
+            state
+                .current_index
+                .is_some_and(|idx| idx + 1 >= state.tracks.len())
+        }
+    }
+
     /// Get multiple upcoming tracks without advancing
     pub fn peek_upcoming(&self, count: usize) -> Vec<QueueTrack> {
         let state = self.state.lock().unwrap();
@@ -970,6 +1009,32 @@ impl QueueManager {
         }
     }
 
+    /// Totals over the whole queue, in one pass under the lock.
+    pub fn get_stats(&self) -> QueueStats {
+        let state = self.state.lock().unwrap();
+        let mut stats = QueueStats {
+            total_tracks: state.tracks.len(),
+            ..QueueStats::default()
+        };
+        for track in &state.tracks {
+            stats.total_duration_secs += track.duration_secs;
+            let source = PlaybackSource::from_source_str(track.source.as_deref());
+            if track.is_local || matches!(source, PlaybackSource::Local) {
+                stats.local_count += 1;
+                continue;
+            }
+            if matches!(source, PlaybackSource::Qobuz | PlaybackSource::OfflineCache) {
+                stats.qobuz_count += 1;
+            }
+            if track.hires {
+                stats.hires_count += 1;
+            } else {
+                stats.cd_count += 1;
+            }
+        }
+        stats
+    }
+
     /// Get queue state for frontend
     pub fn get_state(&self) -> QueueState {
         let state = self.state.lock().unwrap();
@@ -1029,6 +1094,60 @@ impl QueueManager {
         (state.tracks.clone(), state.current_index)
     }
 
+    /// Write every queued track — played, current and upcoming, in play order
+    /// — to `path` as an extended M3U8 playlist. `local_path` maps a local
+    /// track to its file on disk (the queue only knows ids). Streaming tracks
+    /// become `# QOBUZ: track_id=...` comment lines, and local tracks
+    /// `local_path` can't place become `# LOCAL: track_id=...`, so nothing
+    /// silently drops out of the list. Returns the number of playable entries.
+    pub fn export_as_m3u(
+        &self,
+        path: &Path,
+        local_path: impl Fn(&QueueTrack) -> Option<String>,
+    ) -> Result<usize, String> {
+        // Snapshot first: resolving paths hits the library DB, which must
+        // not happen under the queue lock.
+        let tracks = self.play_order_snapshot();
+        let (contents, playable) = render_m3u(&tracks, local_path);
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(playable)
+    }
+
+    /// The queue's Qobuz track ids in play order (de-duplicated), ready to
+    /// become a Qobuz playlist, plus the number of tracks left out: local
+    /// files and Plex items, which Qobuz playlists can't hold. Offline-cache
+    /// copies keep their Qobuz id and are exported. The playlist itself is
+    /// created by `QbzCore::export_queue_as_qobuz_playlist`.
+    pub fn export_as_playlist(&self) -> (Vec<u64>, usize) {
+        let mut ids: Vec<u64> = Vec::new();
+        let mut skipped_local = 0;
+        for track in self.play_order_snapshot() {
+            let source = PlaybackSource::from_source_str(track.source.as_deref());
+            let is_qobuz = matches!(source, PlaybackSource::Qobuz | PlaybackSource::OfflineCache);
+            if track.is_local || !is_qobuz {
+                skipped_local += 1;
+            } else if !ids.contains(&track.id) {
+                ids.push(track.id);
+            }
+        }
+        (ids, skipped_local)
+    }
+
+    /// Every queued track in play order (shuffle-aware), cloned out of the lock.
+    fn play_order_snapshot(&self) -> Vec<QueueTrack> {
+        let state = self.state.lock().unwrap();
+        if state.shuffle && state.shuffle_order.len() == state.tracks.len() {
+            state
+                .shuffle_order
+                .iter()
+                .filter_map(|&i| state.tracks.get(i).cloned())
+                .collect()
+        } else {
+            state.tracks.clone()
+        }
+    }
+
     /// Get the full queue state without the upcoming/history caps applied by
     /// `get_state()`. Used by clients that paginate the upcoming list (e.g.
     /// the Queue sidebar's "UP NEXT" paginator) and need the complete history.
@@ -1234,6 +1353,41 @@ impl QueueManager {
     }
 }
 
+/// The M3U8 text for `tracks` plus its count of playable (path) entries.
+fn render_m3u(
+    tracks: &[QueueTrack],
+    local_path: impl Fn(&QueueTrack) -> Option<String>,
+) -> (String, usize) {
+    // EXTINF titles end at the line; a stray newline would split the entry.
+    let one_line = |s: &str| s.replace(['\r', '\n'], " ");
+    let mut out = String::from("#EXTM3U\n");
+    let mut playable = 0;
+    for track in tracks {
+        let label = one_line(&format!("{} - {}", track.artist, track.title));
+        let file = if track.is_local {
+            local_path(track)
+        } else {
+            None
+        };
+        match file {
+            Some(file) => {
+                out.push_str(&format!(
+                    "#EXTINF:{},{}\n{}\n",
+                    track.duration_secs, label, file
+                ));
+                playable += 1;
+            }
+            None if track.is_local => {
+                out.push_str(&format!("# LOCAL: track_id={} {}\n", track.id, label));
+            }
+            None => {
+                out.push_str(&format!("# QOBUZ: track_id={} {}\n", track.id, label));
+            }
+        }
+    }
+    (out, playable)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1263,6 +1417,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn next_wraps_around_only_from_the_last_track_under_repeat_all() {
+        let queue = QueueManager::new();
+        queue.set_queue((1..=3).map(create_test_track).collect(), Some(2));
+        assert!(!queue.next_wraps_around());
+
+        queue.set_repeat(RepeatMode::All);
+        assert!(queue.next_wraps_around());
+        assert_eq!(queue.peek_next().map(|t| t.id), Some(1));
+
+        queue.play_index(1);
+        assert!(!queue.next_wraps_around());
+
+        queue.set_repeat(RepeatMode::One);
+        queue.play_index(2);
+        assert!(!queue.next_wraps_around());
+    }
+
+    #[test]
+    fn get_stats_sums_duration_and_splits_formats() {
+        let queue = QueueManager::new();
+        assert_eq!(queue.get_stats(), QueueStats::default());
+
+        let mut hires = create_test_track(1);
+        hires.hires = true;
+        hires.source = Some("qobuz".to_string());
+        let mut local = create_test_track(2);
+        local.is_local = true;
+        local.hires = true;
+        local.source = Some("local".to_string());
+        local.duration_secs = 240;
+        let mut plex = create_test_track(3);
+        plex.source = Some("plex".to_string());
+        let mut cached = create_test_track(4);
+        cached.source = Some("qobuz_download".to_string());
+        queue.set_queue(vec![hires, local, plex, cached], Some(0));
+
+        assert_eq!(
+            queue.get_stats(),
+            QueueStats {
+                total_tracks: 4,
+                total_duration_secs: 180 * 3 + 240,
+                hires_count: 1,
+                cd_count: 2,
+                local_count: 1,
+                qobuz_count: 2,
+            }
+        );
+    }
+
     #[test]
     fn test_clear_without_current_track() {
         let queue = QueueManager::new();
@@ -1649,6 +1853,24 @@ mod tests {
         assert_eq!(after_shuffle.len(), 7);
     }
 
+    #[test]
+    fn test_remove_upcoming_where_keeps_current_and_order() {
+        let queue = QueueManager::new();
+        for i in 1..=6 {
+            queue.add_track(create_test_track(i));
+        }
+        queue.play_index(1);
+
+        let removed = queue.remove_upcoming_where(|t| t.id % 2 == 1 || t.id == 2);
+        let removed_ids: Vec<u64> = removed.iter().map(|t| t.id).collect();
+        assert_eq!(removed_ids, vec![3, 5]);
+
+        let state = queue.get_state_full();
+        assert_eq!(state.current_track.map(|t| t.id), Some(2));
+        let upcoming: Vec<u64> = state.upcoming.iter().map(|t| t.id).collect();
+        assert_eq!(upcoming, vec![4, 6]);
+    }
+
     #[test]
     fn test_enabling_shuffle_keeps_all_remaining_tracks_upcoming() {
         let queue = QueueManager::new();
@@ -2343,4 +2565,62 @@ mod tests {
 
         assert_eq!(state.stop_after_track_id, None);
     }
+
+    #[test]
+    fn test_export_as_m3u_lists_every_track_in_play_order() {
+        let queue = QueueManager::new();
+        let mut local = create_test_track(1);
+        local.is_local = true;
+        let mut unplaceable = create_test_track(2);
+        unplaceable.is_local = true;
+        queue.set_queue(vec![local, unplaceable, create_test_track(3)], Some(1));
+        queue.next();
+
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("qbz-queue-export-{nonce}.m3u8"));
+        let playable = queue
+            .export_as_m3u(&path, |t| {
+                (t.id == 1).then(|| "/music/one.flac".to_string())
+            })
+            .unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(playable, 1);
+        assert_eq!(
+            contents,
+            "#EXTM3U\n\
+             #EXTINF:180,Artist - Track 1\n/music/one.flac\n\
+             # LOCAL: track_id=2 Artist - Track 2\n\
+             # QOBUZ: track_id=3 Artist - Track 3\n"
+        );
+    }
+
+    #[test]
+    fn test_export_as_playlist_skips_local_tracks() {
+        let queue = QueueManager::new();
+        let mut local = create_test_track(2);
+        local.is_local = true;
+        // Plex items carry no Qobuz id; an offline-cache copy does.
+        let mut plex = create_test_track(4);
+        plex.source = Some("plex".to_string());
+        let mut cached = create_test_track(5);
+        cached.source = Some("qobuz_download".to_string());
+        queue.set_queue(
+            vec![
+                create_test_track(1),
+                local,
+                create_test_track(3),
+                plex,
+                cached,
+                create_test_track(1),
+            ],
+            Some(0),
+        );
+
+        assert_eq!(queue.export_as_playlist(), (vec![1, 3, 5], 2));
+    }
 }
@@ -0,0 +1,280 @@
+//! CSV / TSV track-list import.
+//!
+//! Reads a spreadsheet export with a header row; [`CsvColumnMap`] names the
+//! header of each column the matcher cares about. The delimiter is a tab for
+//! `.tsv` / `.tab` files and otherwise whichever of tab or comma the header
+//! row uses more. A UTF-8 BOM (what Excel writes) is stripped before parsing
+//! so it can't end up glued to the first header name.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::PlaylistImportError;
+use crate::models::{ImportPlaylist, ImportProvider, ImportTrack};
+
+/// Header names (matched case-insensitively, surrounding spaces ignored) of
+/// the columns to import.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CsvColumnMap {
+    pub title_col: String,
+    pub artist_col: String,
+    pub album_col: Option<String>,
+    pub isrc_col: Option<String>,
+}
+
+impl CsvColumnMap {
+    /// Guess the mapping from a header row using the common names
+    /// ("Track Name", "Artist", "ISRC", ...). `None` without both a title
+    /// and an artist column.
+    pub fn from_headers(headers: &[String]) -> Option<Self> {
+        let find = |candidates: &[&str]| {
+            headers
+                .iter()
+                .find(|h| candidates.contains(&normalize(h).as_str()))
+                .cloned()
+        };
+        Some(Self {
+            title_col: find(&[
+                "title",
+                "track",
+                "track name",
+                "track title",
+                "song",
+                "name",
+            ])?,
+            artist_col: find(&[
+                "artist",
+                "artists",
+                "artist name",
+                "artist name(s)",
+                "performer",
+            ])?,
+            album_col: find(&["album", "album name", "album title", "release"]),
+            isrc_col: find(&["isrc"]),
+        })
+    }
+}
+
+/// The local file a pasted source points at, when it is a CSV/TSV path
+/// (plain or `file://`).
+pub fn csv_file_path(source: &str) -> Option<PathBuf> {
+    let source = source.trim();
+    let path = source.strip_prefix("file://").unwrap_or(source);
+    if path.is_empty() || path.contains("://") {
+        return None;
+    }
+    let lower = path.to_ascii_lowercase();
+    [".csv", ".tsv", ".tab"]
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+        .then(|| PathBuf::from(path))
+}
+
+fn normalize(header: &str) -> String {
+    header.trim().to_lowercase()
+}
+
+/// Read the file, minus a leading UTF-8 BOM.
+fn read_text(path: &Path) -> Result<String, PlaylistImportError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| PlaylistImportError::Parse(format!("{}: {}", path.display(), e)))?;
+    Ok(match text.strip_prefix('\u{feff}') {
+        Some(rest) => rest.to_string(),
+        None => text,
+    })
+}
+
+fn delimiter(path: &Path, text: &str) -> u8 {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    if matches!(ext.as_deref(), Some("tsv" | "tab")) {
+        return b'\t';
+    }
+    let header = text.lines().next().unwrap_or("");
+    if header.matches('\t').count() > header.matches(',').count() {
+        b'\t'
+    } else {
+        b','
+    }
+}
+
+fn reader<'a>(path: &Path, text: &'a str) -> csv::Reader<&'a [u8]> {
+    csv::ReaderBuilder::new()
+        .delimiter(delimiter(path, text))
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes())
+}
+
+/// The header row, for building (or guessing) a [`CsvColumnMap`].
+pub fn csv_headers(path: &Path) -> Result<Vec<String>, PlaylistImportError> {
+    let text = read_text(path)?;
+    let mut reader = reader(path, &text);
+    let headers = reader
+        .headers()
+        .map_err(|e| PlaylistImportError::Parse(e.to_string()))?;
+    Ok(headers.iter().map(|h| h.to_string()).collect())
+}
+
+/// Parse a CSV/TSV track list into an [`ImportPlaylist`] named after the
+/// file. Rows without a title or an artist are skipped.
+pub fn parse_csv_playlist(
+    path: &Path,
+    column_map: &CsvColumnMap,
+) -> Result<ImportPlaylist, PlaylistImportError> {
+    let text = read_text(path)?;
+    let mut reader = reader(path, &text);
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| PlaylistImportError::Parse(e.to_string()))?
+        .iter()
+        .map(normalize)
+        .collect();
+
+    let column = |name: &str| headers.iter().position(|h| *h == normalize(name));
+    let required = |name: &str| {
+        column(name)
+            .ok_or_else(|| PlaylistImportError::Parse(format!("Column '{}' not found", name)))
+    };
+    let title_idx = required(&column_map.title_col)?;
+    let artist_idx = required(&column_map.artist_col)?;
+    let album_idx = column_map.album_col.as_deref().and_then(column);
+    let isrc_idx = column_map.isrc_col.as_deref().and_then(column);
+
+    let mut tracks = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| PlaylistImportError::Parse(e.to_string()))?;
+        let field = |idx: Option<usize>| {
+            idx.and_then(|i| record.get(i))
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string())
+        };
+        let (Some(title), Some(artist)) = (field(Some(title_idx)), field(Some(artist_idx))) else {
+            continue;
+        };
+        tracks.push(ImportTrack {
+            title,
+            artist,
+            album: field(album_idx),
+            duration_ms: None,
+            isrc: field(isrc_idx).map(|isrc| isrc.to_uppercase()),
+            provider_id: None,
+            provider_url: None,
+        });
+    }
+
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Imported Playlist")
+        .to_string();
+
+    Ok(ImportPlaylist {
+        provider: ImportProvider::Csv,
+        provider_id: path.display().to_string(),
+        name,
+        description: None,
+        tracks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(name: &str, contents: &str) -> std::path::PathBuf {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("qbz-csv-import-{nonce}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn column_map() -> CsvColumnMap {
+        CsvColumnMap {
+            title_col: "Track Name".to_string(),
+            artist_col: "Artist".to_string(),
+            album_col: Some("Album".to_string()),
+            isrc_col: Some("ISRC".to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_bom_prefixed_csv_with_quoted_fields() {
+        let path = write_temp(
+            "Road Trip.csv",
+            "\u{feff}Track Name,Artist,Album,ISRC\n\
+             \"Paranoid Android\",Radiohead,\"OK Computer\",gbayE9700014\n\
+             ,Nobody,,\n\
+             \"Hello, Goodbye\",The Beatles,,\n",
+        );
+        let playlist = parse_csv_playlist(&path, &column_map()).unwrap();
+        assert_eq!(playlist.provider, ImportProvider::Csv);
+        assert_eq!(playlist.name, "Road Trip");
+        assert_eq!(playlist.tracks.len(), 2);
+        assert_eq!(playlist.tracks[0].album.as_deref(), Some("OK Computer"));
+        assert_eq!(playlist.tracks[0].isrc.as_deref(), Some("GBAYE9700014"));
+        assert_eq!(playlist.tracks[1].title, "Hello, Goodbye");
+        assert_eq!(playlist.tracks[1].album, None);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn parses_tsv_and_reports_missing_columns() {
+        let path = write_temp("list.txt", "track name\tartist\nReckoner\tRadiohead\n");
+        let map = CsvColumnMap {
+            album_col: Some("Missing".to_string()),
+            ..column_map()
+        };
+        let playlist = parse_csv_playlist(&path, &map).unwrap();
+        assert_eq!(playlist.tracks.len(), 1);
+        assert_eq!(playlist.tracks[0].artist, "Radiohead");
+        assert_eq!(playlist.tracks[0].album, None);
+
+        let bad = CsvColumnMap {
+            title_col: "Song".to_string(),
+            ..column_map()
+        };
+        assert!(parse_csv_playlist(&path, &bad).is_err());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn column_map_is_guessed_from_common_headers() {
+        let headers: Vec<String> = ["#", "Song", "Artist Name(s)", "Album Name", "ISRC"]
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+        assert_eq!(
+            CsvColumnMap::from_headers(&headers),
+            Some(CsvColumnMap {
+                title_col: "Song".to_string(),
+                artist_col: "Artist Name(s)".to_string(),
+                album_col: Some("Album Name".to_string()),
+                isrc_col: Some("ISRC".to_string()),
+            })
+        );
+        assert_eq!(CsvColumnMap::from_headers(&["Title".to_string()]), None);
+    }
+
+    #[test]
+    fn csv_file_path_accepts_local_sheets_only() {
+        assert_eq!(
+            csv_file_path(" file:///home/me/List.CSV "),
+            Some(PathBuf::from("/home/me/List.CSV"))
+        );
+        assert_eq!(
+            csv_file_path("/tmp/tracks.tsv"),
+            Some(PathBuf::from("/tmp/tracks.tsv"))
+        );
+        assert_eq!(csv_file_path("https://example.com/list.csv"), None);
+        assert_eq!(csv_file_path("/tmp/tracks.json"), None);
+    }
+}
@@ -17,4 +17,7 @@ pub enum PlaylistImportError {
     Parse(String),
     #[error("Qobuz error: {0}")]
     Qobuz(String),
+    /// The sink reported [`crate::ImportProgressSink::is_cancelled`].
+    #[error("Import cancelled")]
+    Cancelled,
 }
@@ -1,14 +1,17 @@
 //! Orchestrates playlist import
 
+use std::path::Path;
 use std::sync::Arc;
 
 use qbz_qobuz::QobuzClient;
 
+use crate::csv_import::{parse_csv_playlist, CsvColumnMap};
 use crate::errors::PlaylistImportError;
 use crate::match_qobuz::match_tracks;
 use crate::models::{ImportPlaylist, ImportProgress, ImportSummary};
 use crate::providers::{detect_provider, fetch_playlist};
 use crate::sink::{ImportEvent, ImportPhase, ImportProgressSink};
+use crate::xspf::parse_xspf;
 
 const ADD_CHUNK_SIZE: usize = 50;
 const QOBUZ_PLAYLIST_TRACK_LIMIT: usize = 2000;
@@ -26,7 +29,64 @@ pub async fn import_public_playlist(
     progress: Arc<dyn ImportProgressSink>,
 ) -> Result<ImportSummary, PlaylistImportError> {
     let playlist = preview_public_playlist(url).await?;
+    import_playlist(playlist, client, name_override, is_public, progress).await
+}
+
+/// Parse a local CSV/TSV track list for the preview step. Reads the file on
+/// a blocking thread.
+pub async fn preview_csv_playlist(
+    path: &Path,
+    column_map: &CsvColumnMap,
+) -> Result<ImportPlaylist, PlaylistImportError> {
+    let (path, column_map) = (path.to_path_buf(), column_map.clone());
+    tokio::task::spawn_blocking(move || parse_csv_playlist(&path, &column_map))
+        .await
+        .map_err(|e| PlaylistImportError::Parse(e.to_string()))?
+}
 
+/// Import a local CSV/TSV track list — same matching and playlist creation
+/// as [`import_public_playlist`].
+pub async fn import_csv_playlist(
+    path: &Path,
+    column_map: &CsvColumnMap,
+    client: &QobuzClient,
+    name_override: Option<&str>,
+    is_public: bool,
+    progress: Arc<dyn ImportProgressSink>,
+) -> Result<ImportSummary, PlaylistImportError> {
+    let playlist = preview_csv_playlist(path, column_map).await?;
+    import_playlist(playlist, client, name_override, is_public, progress).await
+}
+
+/// Parse a local XSPF playlist for the preview step. Reads the file on a
+/// blocking thread.
+pub async fn preview_xspf_playlist(path: &Path) -> Result<ImportPlaylist, PlaylistImportError> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || parse_xspf(&path))
+        .await
+        .map_err(|e| PlaylistImportError::Parse(e.to_string()))?
+}
+
+/// Import a local XSPF playlist — same matching and playlist creation as
+/// [`import_public_playlist`].
+pub async fn import_xspf_playlist(
+    path: &Path,
+    client: &QobuzClient,
+    name_override: Option<&str>,
+    is_public: bool,
+    progress: Arc<dyn ImportProgressSink>,
+) -> Result<ImportSummary, PlaylistImportError> {
+    let playlist = preview_xspf_playlist(path).await?;
+    import_playlist(playlist, client, name_override, is_public, progress).await
+}
+
+async fn import_playlist(
+    playlist: ImportPlaylist,
+    client: &QobuzClient,
+    name_override: Option<&str>,
+    is_public: bool,
+    progress: Arc<dyn ImportProgressSink>,
+) -> Result<ImportSummary, PlaylistImportError> {
     // Phase: matching
     progress.emit(ImportEvent::Phase(ImportPhase::Matching));
     let matches = match_tracks(client, &playlist.tracks, Arc::clone(&progress)).await?;
@@ -61,6 +121,9 @@ pub async fn import_public_playlist(
         let total_parts = parts.len();
 
         for (part_idx, part_tracks) in parts.iter().enumerate() {
+            if progress.is_cancelled() {
+                return Err(PlaylistImportError::Cancelled);
+            }
             // Phase: creating (per part)
             progress.emit(ImportEvent::Phase(ImportPhase::Creating));
 
@@ -95,6 +158,9 @@ pub async fn import_public_playlist(
             let total_chunks = chunks.len() as u32;
 
             for (i, chunk) in chunks.iter().enumerate() {
+                if progress.is_cancelled() {
+                    return Err(PlaylistImportError::Cancelled);
+                }
                 client
                     .add_tracks_to_playlist(created.id, chunk)
                     .await
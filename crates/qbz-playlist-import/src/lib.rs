@@ -16,41 +16,60 @@
 //! - Deezer: single public API call, no pagination — truncates around 400
 //!   tracks. TODO: paginate `tracks.data`.
 //! - Apple Music: scrapes `serialized-server-data` from the playlist page —
-//!   the most fragile parser of the four.
+//!   the most fragile parser of the five.
+//! - YouTube Music: fetched through the credential proxy; ISRC only when the
+//!   proxy has one (rarely, for user uploads never).
 //! - Tidal: fetches a fresh proxy token per playlist fetch (no caching/expiry
 //!   handling). TODO: cache the token until expiry.
+//! - CSV/TSV: local files with a header row, mapped by [`CsvColumnMap`];
+//!   no duration, so matching leans on ISRC when the sheet has one.
+//! - XSPF: local playlist files; ISRC only from `isrc:` identifiers, which
+//!   few exporters write.
 //! - Scrapers send no browser User-Agent (reqwest default) — TODO if any
 //!   provider starts gating on UA.
 
+pub mod csv_import;
 pub mod errors;
 pub mod importer;
 pub mod match_qobuz;
 pub mod models;
 pub mod providers;
 pub mod sink;
+pub mod xspf;
 
 mod http;
 
+pub use csv_import::{csv_file_path, csv_headers, parse_csv_playlist, CsvColumnMap};
 pub use errors::PlaylistImportError;
-pub use importer::{import_public_playlist, preview_public_playlist};
+pub use importer::{
+    import_csv_playlist, import_public_playlist, import_xspf_playlist, preview_csv_playlist,
+    preview_public_playlist, preview_xspf_playlist,
+};
 pub use models::{
     ImportPlaylist, ImportProgress, ImportProvider, ImportSummary, ImportTrack, TrackMatch,
 };
 pub use providers::{detect_music_resource, MusicProvider, MusicResource};
 pub use sink::{ImportEvent, ImportPhase, ImportProgressSink};
+pub use xspf::{parse_xspf, xspf_file_path};
 
 /// Cloudflare Workers proxy that holds the third-party API credentials.
 /// Hoisted from the Tidal provider so the future link-resolver port shares
 /// the one constant instead of duplicating it.
 pub const QBZ_PROXY_BASE: &str = "https://qbz-api-proxy.blitzkriegfc.workers.dev";
 
-/// Provider key for the UI gate ("spotify" | "apple" | "tidal" | "deezer").
+/// Provider key for the UI gate ("spotify" | "apple" | "tidal" | "deezer" |
+/// "youtube_music" | "csv" | "xspf").
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProviderKey {
     Spotify,
     Apple,
     Tidal,
     Deezer,
+    YouTubeMusic,
+    /// A local CSV/TSV file path (see [`csv_file_path`]).
+    Csv,
+    /// A local XSPF playlist path (see [`xspf_file_path`]).
+    Xspf,
 }
 
 impl ProviderKey {
@@ -60,6 +79,9 @@ impl ProviderKey {
             ProviderKey::Apple => "apple",
             ProviderKey::Tidal => "tidal",
             ProviderKey::Deezer => "deezer",
+            ProviderKey::YouTubeMusic => "youtube_music",
+            ProviderKey::Csv => "csv",
+            ProviderKey::Xspf => "xspf",
         }
     }
 }
@@ -87,6 +109,15 @@ pub fn detect_provider_key(url: &str) -> Option<ProviderKey> {
     if url.contains("deezer.com/") && url.contains("/playlist/") {
         return Some(ProviderKey::Deezer);
     }
+    if url.contains("music.youtube.com/") && url.contains("list=") {
+        return Some(ProviderKey::YouTubeMusic);
+    }
+    if csv_file_path(url).is_some() {
+        return Some(ProviderKey::Csv);
+    }
+    if xspf_file_path(url).is_some() {
+        return Some(ProviderKey::Xspf);
+    }
 
     None
 }
@@ -129,6 +160,16 @@ mod tests {
                 Some(ProviderKey::Deezer),
             ),
             ("https://www.deezer.com/en/album/1234567", None),
+            // YouTube Music: needs a list= parameter
+            (
+                "https://music.youtube.com/playlist?list=PLabc",
+                Some(ProviderKey::YouTubeMusic),
+            ),
+            ("https://music.youtube.com/watch?v=abc", None),
+            // Local track lists
+            ("/home/me/tracks.csv", Some(ProviderKey::Csv)),
+            ("file:///home/me/tracks.tsv", Some(ProviderKey::Csv)),
+            ("/home/me/mix.xspf", Some(ProviderKey::Xspf)),
             // Rejects
             ("https://open.spotify.com/track/abc", None),
             ("https://example.com/playlist/1", None),
@@ -154,5 +195,8 @@ mod tests {
         assert_eq!(ProviderKey::Apple.as_str(), "apple");
         assert_eq!(ProviderKey::Tidal.as_str(), "tidal");
         assert_eq!(ProviderKey::Deezer.as_str(), "deezer");
+        assert_eq!(ProviderKey::YouTubeMusic.as_str(), "youtube_music");
+        assert_eq!(ProviderKey::Csv.as_str(), "csv");
+        assert_eq!(ProviderKey::Xspf.as_str(), "xspf");
     }
 }
@@ -46,6 +46,9 @@ pub async fn match_tracks(
             let results = Arc::clone(&results);
 
             async move {
+                if progress.is_cancelled() {
+                    return;
+                }
                 let query = format!("{} {}", track.artist, track.title);
                 let search_result = client.search_tracks(&query, SEARCH_LIMIT, 0, None).await;
 
@@ -63,6 +66,11 @@ pub async fn match_tracks(
                                         .performer
                                         .as_ref()
                                         .map(|a| a.name.clone()),
+                                    qobuz_album_id: candidate
+                                        .album
+                                        .as_ref()
+                                        .map(|a| a.id.clone()),
+                                    qobuz_artist_id: candidate.performer.as_ref().map(|a| a.id),
                                     score,
                                 }
                             }
@@ -71,6 +79,8 @@ pub async fn match_tracks(
                                 qobuz_track_id: None,
                                 qobuz_title: None,
                                 qobuz_artist: None,
+                                qobuz_album_id: None,
+                                qobuz_artist_id: None,
                                 score,
                             },
                         }
@@ -87,6 +97,8 @@ pub async fn match_tracks(
                             qobuz_track_id: None,
                             qobuz_title: None,
                             qobuz_artist: None,
+                            qobuz_album_id: None,
+                            qobuz_artist_id: None,
                             score: 0.0,
                         }
                     }
@@ -116,14 +128,13 @@ pub async fn match_tracks(
         .collect::<Vec<()>>()
         .await;
 
-    // Extract results in order
+    // Extract results in order; a slot is only empty when the search was
+    // skipped by a cancel.
     let locked = results.lock().await;
-    let ordered: Vec<TrackMatch> = locked
+    locked
         .iter()
-        .map(|slot| slot.clone().expect("All slots should be filled"))
-        .collect();
-
-    Ok(ordered)
+        .map(|slot| slot.clone().ok_or(PlaylistImportError::Cancelled))
+        .collect()
 }
 
 fn select_best_match<'a>(track: &ImportTrack, candidates: &'a [Track]) -> (Option<&'a Track>, f32) {
@@ -550,4 +561,26 @@ mod tests {
         let none = qobuz_track(3, "x", "y");
         assert_eq!(quality_score(&none), 0.0);
     }
+
+    // ── cancellation ──
+
+    struct CancelledSink;
+
+    impl ImportProgressSink for CancelledSink {
+        fn emit(&self, _event: ImportEvent) {
+            panic!("a cancelled import must not search or report progress");
+        }
+
+        fn is_cancelled(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn cancelled_sink_skips_every_search() {
+        let client = QobuzClient::new().expect("client construction is local-only");
+        let tracks = [import_track("hey jude", "the beatles")];
+        let result = match_tracks(&client, &tracks, Arc::new(CancelledSink)).await;
+        assert!(matches!(result, Err(PlaylistImportError::Cancelled)));
+    }
 }
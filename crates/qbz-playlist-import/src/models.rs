@@ -8,6 +8,11 @@ pub enum ImportProvider {
     AppleMusic,
     Tidal,
     Deezer,
+    YouTubeMusic,
+    /// A local CSV/TSV track list (`csv_import`).
+    Csv,
+    /// A local XSPF playlist file (`xspf`).
+    Xspf,
 }
 
 impl ImportProvider {
@@ -17,6 +22,9 @@ impl ImportProvider {
             ImportProvider::AppleMusic => "apple_music",
             ImportProvider::Tidal => "tidal",
             ImportProvider::Deezer => "deezer",
+            ImportProvider::YouTubeMusic => "youtube_music",
+            ImportProvider::Csv => "csv",
+            ImportProvider::Xspf => "xspf",
         }
     }
 }
@@ -47,6 +55,12 @@ pub struct TrackMatch {
     pub qobuz_track_id: Option<u64>,
     pub qobuz_title: Option<String>,
     pub qobuz_artist: Option<String>,
+    /// Catalog ids of the match, for callers that log taste events
+    /// (history imports) rather than build playlists.
+    #[serde(default)]
+    pub qobuz_album_id: Option<String>,
+    #[serde(default)]
+    pub qobuz_artist_id: Option<u64>,
     pub score: f32,
 }
 
@@ -3,7 +3,9 @@
 pub mod apple;
 pub mod deezer;
 pub mod spotify;
+pub mod spotify_history;
 pub mod tidal;
+pub mod youtube_music;
 
 use serde::{Deserialize, Serialize};
 
@@ -95,6 +97,9 @@ pub enum ProviderKind {
     Deezer {
         playlist_id: String,
     },
+    YouTubeMusic {
+        playlist_id: String,
+    },
 }
 
 pub fn detect_provider(url: &str) -> Result<ProviderKind, PlaylistImportError> {
@@ -113,6 +118,9 @@ pub fn detect_provider(url: &str) -> Result<ProviderKind, PlaylistImportError> {
     if let Some(id) = deezer::parse_playlist_id(url) {
         return Ok(ProviderKind::Deezer { playlist_id: id });
     }
+    if let Some(id) = youtube_music::parse_playlist_id(url) {
+        return Ok(ProviderKind::YouTubeMusic { playlist_id: id });
+    }
 
     Err(PlaylistImportError::UnsupportedProvider(url.to_string()))
 }
@@ -130,6 +138,9 @@ pub async fn fetch_playlist(kind: ProviderKind) -> Result<ImportPlaylist, Playli
         // their edge — the Tauri original read TIDAL_COUNTRY_CODE here).
         ProviderKind::Tidal { playlist_id } => tidal::fetch_playlist(&playlist_id, None).await,
         ProviderKind::Deezer { playlist_id } => deezer::fetch_playlist(&playlist_id).await,
+        ProviderKind::YouTubeMusic { playlist_id } => {
+            youtube_music::fetch_playlist(&playlist_id).await
+        }
     }
 }
 
@@ -190,6 +201,14 @@ mod tests {
             }
         );
 
+        // YouTube Music
+        assert_eq!(
+            detect_provider("https://music.youtube.com/playlist?list=PLabc123").unwrap(),
+            ProviderKind::YouTubeMusic {
+                playlist_id: "PLabc123".to_string()
+            }
+        );
+
         // Rejects
         assert!(detect_provider("https://example.com/playlist/1").is_err());
         assert!(detect_provider("https://open.spotify.com/track/abc").is_err());
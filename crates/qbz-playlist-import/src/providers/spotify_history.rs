@@ -0,0 +1,228 @@
+//! Spotify streaming-history export ("Download your data").
+//!
+//! Reads the `StreamingHistory_music_*.json` files of the account-data
+//! export, and the `Streaming_History_Audio_*.json` files of the extended
+//! export. Not a playlist provider: the listens feed the history import
+//! into recommendations, matched on Qobuz by artist + title (the export
+//! carries no ISRC).
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDateTime};
+use serde::Deserialize;
+
+use crate::errors::PlaylistImportError;
+
+/// Plays of the same song this close together count as one listen
+/// (Spotify logs a new entry on every resume).
+pub const DEDUP_WINDOW_SECS: i64 = 30;
+
+/// Plays shorter than this were skipped rather than listened to (the
+/// account-data export has no skip flag, so play time is all there is).
+pub const MIN_PLAYED_MS: u64 = 30_000;
+
+/// One listen from the export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpotifyListen {
+    pub artist: String,
+    pub title: String,
+    /// When playback ended (Unix seconds, UTC)
+    pub ended_at: i64,
+    pub ms_played: u64,
+}
+
+/// Entry in either export flavor; the unused half stays `None`.
+#[derive(Deserialize)]
+struct RawEntry {
+    // Account-data export
+    #[serde(rename = "endTime")]
+    end_time: Option<String>,
+    #[serde(rename = "artistName")]
+    artist_name: Option<String>,
+    #[serde(rename = "trackName")]
+    track_name: Option<String>,
+    #[serde(rename = "msPlayed")]
+    ms_played_short: Option<u64>,
+    // Extended streaming history (podcast entries have null track fields)
+    ts: Option<String>,
+    master_metadata_album_artist_name: Option<String>,
+    master_metadata_track_name: Option<String>,
+    ms_played: Option<u64>,
+    skipped: Option<bool>,
+}
+
+impl RawEntry {
+    fn into_listen(self) -> Option<SpotifyListen> {
+        let artist = self
+            .artist_name
+            .or(self.master_metadata_album_artist_name)?;
+        let title = self.track_name.or(self.master_metadata_track_name)?;
+        if artist.trim().is_empty() || title.trim().is_empty() {
+            return None;
+        }
+        let ms_played = self.ms_played_short.or(self.ms_played).unwrap_or(0);
+        if self.skipped == Some(true) || ms_played < MIN_PLAYED_MS {
+            return None;
+        }
+        // Account-data export times are UTC, minute precision
+        let ended_at = match (self.end_time, self.ts) {
+            (Some(end), _) => NaiveDateTime::parse_from_str(&end, "%Y-%m-%d %H:%M")
+                .ok()?
+                .and_utc()
+                .timestamp(),
+            (None, Some(ts)) => DateTime::parse_from_rfc3339(&ts).ok()?.timestamp(),
+            (None, None) => return None,
+        };
+        Some(SpotifyListen {
+            artist,
+            title,
+            ended_at,
+            ms_played,
+        })
+    }
+}
+
+/// Whether a file name is one of the export's music-history files
+fn is_history_file(name: &str) -> bool {
+    (name.starts_with("StreamingHistory_music") || name.starts_with("Streaming_History_Audio"))
+        && name.ends_with(".json")
+}
+
+/// Parse one history file's JSON array. Entries that aren't songs
+/// (podcasts, malformed rows) or were skipped are dropped.
+fn parse_entries(json: &str) -> Result<Vec<SpotifyListen>, PlaylistImportError> {
+    let entries: Vec<RawEntry> =
+        serde_json::from_str(json).map_err(|e| PlaylistImportError::Parse(e.to_string()))?;
+    Ok(entries
+        .into_iter()
+        .filter_map(RawEntry::into_listen)
+        .collect())
+}
+
+/// Drop repeat plays of the same song within [`DEDUP_WINDOW_SECS`].
+/// Expects `listens` sorted oldest-first.
+pub fn dedupe(listens: Vec<SpotifyListen>) -> Vec<SpotifyListen> {
+    let mut last_seen: HashMap<(String, String), i64> = HashMap::new();
+    listens
+        .into_iter()
+        .filter(|listen| {
+            let key = (
+                listen.artist.trim().to_lowercase(),
+                listen.title.trim().to_lowercase(),
+            );
+            let repeat = last_seen
+                .get(&key)
+                .is_some_and(|&prev| listen.ended_at - prev <= DEDUP_WINDOW_SECS);
+            last_seen.insert(key, listen.ended_at);
+            !repeat
+        })
+        .collect()
+}
+
+/// Parse a Spotify export: either one history JSON file or the unpacked
+/// export folder (every `StreamingHistory_music_*.json` in it). Returns the
+/// listens oldest-first with repeat plays collapsed.
+pub fn parse(path: &Path) -> Result<Vec<SpotifyListen>, PlaylistImportError> {
+    let read = |file: &Path| {
+        std::fs::read_to_string(file)
+            .map_err(|e| PlaylistImportError::Parse(format!("{}: {}", file.display(), e)))
+    };
+
+    let mut listens = Vec::new();
+    if path.is_dir() {
+        let mut files: Vec<_> = std::fs::read_dir(path)
+            .map_err(|e| PlaylistImportError::Parse(format!("{}: {}", path.display(), e)))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|file| {
+                file.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(is_history_file)
+            })
+            .collect();
+        if files.is_empty() {
+            return Err(PlaylistImportError::Parse(
+                "No StreamingHistory_music files in folder".to_string(),
+            ));
+        }
+        files.sort();
+        for file in files {
+            listens.extend(parse_entries(&read(&file)?)?);
+        }
+    } else {
+        listens = parse_entries(&read(path)?)?;
+    }
+
+    listens.sort_by_key(|listen| listen.ended_at);
+    Ok(dedupe(listens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_both_export_flavors() {
+        let account = r#"[
+            {"endTime": "2024-03-01 18:04", "artistName": "Radiohead", "trackName": "Reckoner", "msPlayed": 290000},
+            {"endTime": "2024-03-01 18:10", "artistName": "", "trackName": "Unknown", "msPlayed": 1000}
+        ]"#;
+        let listens = parse_entries(account).unwrap();
+        assert_eq!(listens.len(), 1);
+        assert_eq!(listens[0].ended_at, 1_709_316_240);
+        assert_eq!(listens[0].ms_played, 290_000);
+
+        let extended = r#"[
+            {"ts": "2024-03-01T18:04:00Z", "master_metadata_album_artist_name": "Radiohead",
+             "master_metadata_track_name": "Reckoner", "ms_played": 290000},
+            {"ts": "2024-03-01T19:00:00Z", "master_metadata_album_artist_name": null,
+             "master_metadata_track_name": null, "ms_played": 60000}
+        ]"#;
+        assert_eq!(parse_entries(extended).unwrap(), listens);
+    }
+
+    #[test]
+    fn drops_skipped_plays() {
+        let account = r#"[
+            {"endTime": "2024-03-01 18:04", "artistName": "Radiohead", "trackName": "Reckoner", "msPlayed": 290000},
+            {"endTime": "2024-03-01 18:05", "artistName": "Radiohead", "trackName": "Nude", "msPlayed": 4000}
+        ]"#;
+        let titles: Vec<String> = parse_entries(account)
+            .unwrap()
+            .into_iter()
+            .map(|l| l.title)
+            .collect();
+        assert_eq!(titles, vec!["Reckoner"]);
+
+        let extended = r#"[
+            {"ts": "2024-03-01T18:04:00Z", "master_metadata_album_artist_name": "Radiohead",
+             "master_metadata_track_name": "Reckoner", "ms_played": 290000, "skipped": null},
+            {"ts": "2024-03-01T18:09:00Z", "master_metadata_album_artist_name": "Radiohead",
+             "master_metadata_track_name": "Nude", "ms_played": 120000, "skipped": true}
+        ]"#;
+        let titles: Vec<String> = parse_entries(extended)
+            .unwrap()
+            .into_iter()
+            .map(|l| l.title)
+            .collect();
+        assert_eq!(titles, vec!["Reckoner"]);
+    }
+
+    #[test]
+    fn dedupe_collapses_plays_within_window() {
+        let listen = |title: &str, ended_at: i64| SpotifyListen {
+            artist: "Radiohead".to_string(),
+            title: title.to_string(),
+            ended_at,
+            ms_played: 1000,
+        };
+        let kept = dedupe(vec![
+            listen("Reckoner", 1000),
+            listen("reckoner ", 1020),
+            listen("Nude", 1025),
+            listen("Reckoner", 1100),
+        ]);
+        let times: Vec<i64> = kept.iter().map(|l| l.ended_at).collect();
+        assert_eq!(times, vec![1000, 1025, 1100]);
+    }
+}
@@ -0,0 +1,204 @@
+//! YouTube Music playlist import
+//!
+//! YouTube Music has no public playlist API, so the fetch goes through the
+//! QBZ credential proxy (`/youtube/playlist/{id}`), which holds the Data API
+//! key and flattens the playlist items into title/artist rows. YouTube
+//! carries no ISRC for most uploads; it is passed through when the proxy has
+//! one.
+
+use serde_json::Value;
+
+use crate::errors::PlaylistImportError;
+use crate::http::http;
+use crate::models::{ImportPlaylist, ImportProvider, ImportTrack};
+
+/// Playlist id from a `music.youtube.com` URL — the `list` query parameter
+/// (`/playlist?list=PL...`, also present on `/watch?v=...&list=...`).
+/// Only `[A-Za-z0-9_-]` ids are accepted, since the id is spliced into the
+/// proxy URL path.
+pub fn parse_playlist_id(url: &str) -> Option<String> {
+    if !url.contains("music.youtube.com/") {
+        return None;
+    }
+
+    let query = url.split_once('?')?.1;
+    let query = query.split('#').next().unwrap_or(query);
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("list="))
+        .filter(|id| {
+            !id.is_empty()
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        })
+        .map(|id| id.to_string())
+}
+
+pub async fn fetch_playlist(playlist_id: &str) -> Result<ImportPlaylist, PlaylistImportError> {
+    let url = format!("{}/youtube/playlist/{}", crate::QBZ_PROXY_BASE, playlist_id);
+    let response = http()
+        .get(&url)
+        .header(reqwest::header::USER_AGENT, crate::http::USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| PlaylistImportError::Http(e.to_string()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(PlaylistImportError::Http(format!(
+            "YouTube Music playlist fetch failed: {} - {}",
+            status, body
+        )));
+    }
+
+    let data: Value = response
+        .json()
+        .await
+        .map_err(|e| PlaylistImportError::Parse(e.to_string()))?;
+
+    parse_playlist(playlist_id, &data)
+}
+
+/// Map the proxy's JSON (`{ title, description, tracks: [{ videoId, title,
+/// artist, album, durationMs, isrc }] }`) to an [`ImportPlaylist`].
+fn parse_playlist(playlist_id: &str, data: &Value) -> Result<ImportPlaylist, PlaylistImportError> {
+    let name = data
+        .get("title")
+        .and_then(|v| v.as_str())
+        .unwrap_or("YouTube Music Playlist")
+        .to_string();
+    let description = data
+        .get("description")
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+        .filter(|v| !v.is_empty());
+
+    let items = data
+        .get("tracks")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| PlaylistImportError::Parse("YouTube Music tracks missing".to_string()))?;
+
+    let mut tracks = Vec::new();
+    for item in items {
+        let title = item
+            .get("title")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let artist = item
+            .get("artist")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let album = item
+            .get("album")
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .filter(|v| !v.is_empty());
+        let duration_ms = item.get("durationMs").and_then(|v| v.as_u64());
+        let isrc = item
+            .get("isrc")
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .filter(|v| !v.is_empty());
+        let provider_id = item
+            .get("videoId")
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string());
+        let provider_url = provider_id
+            .as_ref()
+            .map(|id| format!("https://music.youtube.com/watch?v={}", id));
+
+        tracks.push(ImportTrack {
+            title,
+            artist,
+            album,
+            duration_ms,
+            isrc,
+            provider_id,
+            provider_url,
+        });
+    }
+
+    Ok(ImportPlaylist {
+        provider: ImportProvider::YouTubeMusic,
+        provider_id: playlist_id.to_string(),
+        name,
+        description,
+        tracks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_playlist_id_table() {
+        let cases: &[(&str, Option<&str>)] = &[
+            (
+                "https://music.youtube.com/playlist?list=PLrAl6rYgs4IvGFBDEaVGFXt6k2GiOFWpX",
+                Some("PLrAl6rYgs4IvGFBDEaVGFXt6k2GiOFWpX"),
+            ),
+            (
+                "https://music.youtube.com/playlist?list=PLabc&si=xyz",
+                Some("PLabc"),
+            ),
+            (
+                "https://music.youtube.com/watch?v=dQw4w9WgXcQ&list=RDAMVM123",
+                Some("RDAMVM123"),
+            ),
+            ("https://music.youtube.com/playlist?list=", None),
+            (
+                "https://music.youtube.com/playlist?list=PL_a-b",
+                Some("PL_a-b"),
+            ),
+            ("https://music.youtube.com/playlist?list=../admin", None),
+            ("https://music.youtube.com/playlist?list=PL%2Fabc", None),
+            ("https://music.youtube.com/watch?v=dQw4w9WgXcQ", None),
+            ("https://www.youtube.com/playlist?list=PLabc", None),
+            ("https://example.com/playlist?list=PLabc", None),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(parse_playlist_id(url).as_deref(), *expected, "url: {}", url);
+        }
+    }
+
+    #[test]
+    fn parse_playlist_maps_proxy_json() {
+        let data = serde_json::json!({
+            "title": "Road Trip",
+            "description": "",
+            "tracks": [
+                {
+                    "videoId": "abc123",
+                    "title": "Song A",
+                    "artist": "Artist A",
+                    "album": "Album A",
+                    "durationMs": 201000,
+                    "isrc": "USRC17607839"
+                },
+                { "title": "Song B", "artist": "Artist B" }
+            ]
+        });
+
+        let playlist = parse_playlist("PLabc", &data).expect("parsed");
+        assert_eq!(playlist.provider, ImportProvider::YouTubeMusic);
+        assert_eq!(playlist.name, "Road Trip");
+        assert_eq!(playlist.description, None);
+        assert_eq!(playlist.tracks.len(), 2);
+        assert_eq!(playlist.tracks[0].isrc.as_deref(), Some("USRC17607839"));
+        assert_eq!(
+            playlist.tracks[0].provider_url.as_deref(),
+            Some("https://music.youtube.com/watch?v=abc123")
+        );
+        assert_eq!(playlist.tracks[1].isrc, None);
+        assert_eq!(playlist.tracks[1].provider_id, None);
+
+        assert!(parse_playlist("PLabc", &serde_json::json!({})).is_err());
+    }
+}
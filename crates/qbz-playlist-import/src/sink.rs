@@ -38,6 +38,14 @@ pub enum ImportEvent {
 /// Receives progress events from [`crate::import_public_playlist`].
 pub trait ImportProgressSink: Send + Sync {
     fn emit(&self, event: ImportEvent);
+
+    /// Polled before each track search and each playlist write; `true`
+    /// stops the import with [`crate::PlaylistImportError::Cancelled`].
+    /// Searches already in flight finish, and parts created before the
+    /// cancel are kept.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
 }
 
 impl<F: Fn(ImportEvent) + Send + Sync> ImportProgressSink for F {
@@ -0,0 +1,257 @@
+//! XSPF playlist import — the counterpart of the M3U export.
+//!
+//! Reads the `<trackList>` of an XSPF 1.0 file (VLC, Rhythmbox, foobar2000
+//! and most web exporters write one). Only the elements directly under a
+//! `<track>` count, so application `<extension>` blocks (VLC nests its own
+//! ids there) can't leak into the fields. An `<identifier>` of the form
+//! `isrc:CC-XXX-YY-NNNNN` becomes the track's ISRC; other identifiers
+//! (`file://`, `urn:`...) are ignored.
+
+use std::path::{Path, PathBuf};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::errors::PlaylistImportError;
+use crate::models::{ImportPlaylist, ImportProvider, ImportTrack};
+
+/// The local file a pasted source points at, when it is an XSPF path
+/// (plain or `file://`).
+pub fn xspf_file_path(source: &str) -> Option<PathBuf> {
+    let source = source.trim();
+    let path = source.strip_prefix("file://").unwrap_or(source);
+    if path.is_empty() || path.contains("://") {
+        return None;
+    }
+    path.to_ascii_lowercase()
+        .ends_with(".xspf")
+        .then(|| PathBuf::from(path))
+}
+
+/// Parse an XSPF file into an [`ImportPlaylist`] named after its `<title>`
+/// (the file name when it has none). Tracks without a title or a creator
+/// are skipped.
+pub fn parse_xspf(path: &Path) -> Result<ImportPlaylist, PlaylistImportError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| PlaylistImportError::Parse(format!("{}: {}", path.display(), e)))?;
+    let mut playlist = parse_xspf_str(&text)?;
+    if playlist.name.is_empty() {
+        playlist.name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Imported Playlist")
+            .to_string();
+    }
+    playlist.provider_id = path.display().to_string();
+    Ok(playlist)
+}
+
+/// Fields of the `<track>` being read.
+#[derive(Default)]
+struct TrackFields {
+    title: String,
+    creator: String,
+    album: String,
+    duration: String,
+    isrc: Option<String>,
+}
+
+impl TrackFields {
+    fn into_track(self) -> Option<ImportTrack> {
+        let non_empty = |v: String| {
+            let v = v.trim();
+            (!v.is_empty()).then(|| v.to_string())
+        };
+        Some(ImportTrack {
+            title: non_empty(self.title)?,
+            artist: non_empty(self.creator)?,
+            album: non_empty(self.album),
+            duration_ms: self.duration.trim().parse().ok().filter(|ms| *ms > 0),
+            isrc: self.isrc,
+            provider_id: None,
+            provider_url: None,
+        })
+    }
+}
+
+/// The ISRC of an `isrc:` identifier, uppercased and without hyphens.
+fn isrc_from_identifier(identifier: &str) -> Option<String> {
+    let identifier = identifier.trim();
+    let prefix = identifier.get(..5)?;
+    if !prefix.eq_ignore_ascii_case("isrc:") {
+        return None;
+    }
+    let isrc: String = identifier[5..]
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    (isrc.len() == 12).then_some(isrc)
+}
+
+fn parse_xspf_str(text: &str) -> Result<ImportPlaylist, PlaylistImportError> {
+    let parse_err = |e: quick_xml::Error| PlaylistImportError::Parse(e.to_string());
+    let mut reader = Reader::from_str(text);
+    reader.config_mut().trim_text(true);
+
+    // Local names of the open elements, outermost first.
+    let mut path: Vec<String> = Vec::new();
+    let mut name = String::new();
+    let mut description = String::new();
+    let mut current: Option<TrackFields> = None;
+    let mut tracks = Vec::new();
+    let mut seen_playlist = false;
+
+    loop {
+        let content = match reader.read_event().map_err(parse_err)? {
+            Event::Start(e) => {
+                let local = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                seen_playlist |= path.is_empty() && local == "playlist";
+                if local == "track" && path.last().map(String::as_str) == Some("trackList") {
+                    current = Some(TrackFields::default());
+                }
+                path.push(local);
+                continue;
+            }
+            Event::End(_) => {
+                if path.pop().as_deref() == Some("track")
+                    && path.last().map(String::as_str) == Some("trackList")
+                {
+                    tracks.extend(current.take().and_then(TrackFields::into_track));
+                }
+                continue;
+            }
+            Event::Text(t) => t.unescape().map_err(parse_err)?.into_owned(),
+            Event::CData(c) => String::from_utf8_lossy(&c.into_inner()).into_owned(),
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let (Some(field), Some(parent)) = (path.last(), path.len().checked_sub(2)) else {
+            continue;
+        };
+        match (path[parent].as_str(), field.as_str(), current.as_mut()) {
+            ("playlist", "title", _) if parent == 0 => name.push_str(&content),
+            ("playlist", "annotation", _) if parent == 0 => description.push_str(&content),
+            ("track", "title", Some(track)) => track.title.push_str(&content),
+            ("track", "creator", Some(track)) => track.creator.push_str(&content),
+            ("track", "album", Some(track)) => track.album.push_str(&content),
+            ("track", "duration", Some(track)) => track.duration.push_str(&content),
+            ("track", "identifier", Some(track)) if track.isrc.is_none() => {
+                track.isrc = isrc_from_identifier(&content);
+            }
+            _ => {}
+        }
+    }
+
+    if !seen_playlist {
+        return Err(PlaylistImportError::Parse(
+            "Not an XSPF playlist (no <playlist> root)".to_string(),
+        ));
+    }
+
+    let description = description.trim();
+    Ok(ImportPlaylist {
+        provider: ImportProvider::Xspf,
+        provider_id: String::new(),
+        name: name.trim().to_string(),
+        description: (!description.is_empty()).then(|| description.to_string()),
+        tracks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VLC: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<playlist xmlns="http://xspf.org/ns/0/" xmlns:vlc="http://www.videolan.org/vlc/playlist/ns/0/" version="1">
+	<title>Playlist</title>
+	<trackList>
+		<track>
+			<location>file:///home/me/Music/Radiohead/OK%20Computer/02%20Paranoid%20Android.flac</location>
+			<title>Paranoid Android</title>
+			<creator>Radiohead</creator>
+			<album>OK Computer</album>
+			<trackNum>2</trackNum>
+			<duration>383493</duration>
+			<extension application="http://www.videolan.org/vlc/playlist/0">
+				<vlc:id>0</vlc:id>
+				<vlc:option>title=Not the title</vlc:option>
+			</extension>
+		</track>
+		<track>
+			<location>file:///home/me/Music/untagged.mp3</location>
+			<duration>1000</duration>
+			<extension application="http://www.videolan.org/vlc/playlist/0">
+				<vlc:id>1</vlc:id>
+			</extension>
+		</track>
+	</trackList>
+	<extension application="http://www.videolan.org/vlc/playlist/0">
+			<vlc:item tid="0"/>
+			<vlc:item tid="1"/>
+	</extension>
+</playlist>
+"#;
+
+    const RHYTHMBOX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<playlist version="1" xmlns="http://xspf.org/ns/0/">
+  <trackList>
+    <track>
+      <location>file:///home/me/Music/Simon%20%26%20Garfunkel/01.ogg</location>
+      <identifier>isrc:usSM1-69-00001</identifier>
+      <title>The Boxer</title>
+      <creator>Simon &amp; Garfunkel</creator>
+      <album><![CDATA[Bridge Over Troubled Water]]></album>
+      <trackNum>1</trackNum>
+      <duration>308000</duration>
+    </track>
+  </trackList>
+</playlist>
+"#;
+
+    #[test]
+    fn parses_vlc_export() {
+        let playlist = parse_xspf_str(VLC).unwrap();
+        assert_eq!(playlist.provider, ImportProvider::Xspf);
+        assert_eq!(playlist.name, "Playlist");
+        // The untagged file has no title or creator.
+        assert_eq!(playlist.tracks.len(), 1);
+        let track = &playlist.tracks[0];
+        assert_eq!(track.title, "Paranoid Android");
+        assert_eq!(track.artist, "Radiohead");
+        assert_eq!(track.album.as_deref(), Some("OK Computer"));
+        assert_eq!(track.duration_ms, Some(383493));
+        assert_eq!(track.isrc, None);
+    }
+
+    #[test]
+    fn parses_rhythmbox_export_named_after_the_file() {
+        let dir = std::env::temp_dir().join(format!("qbz-xspf-import-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Sunday Morning.xspf");
+        std::fs::write(&path, RHYTHMBOX).unwrap();
+
+        let playlist = parse_xspf(&path).unwrap();
+        assert_eq!(playlist.name, "Sunday Morning");
+        assert_eq!(playlist.provider_id, path.display().to_string());
+        let track = &playlist.tracks[0];
+        assert_eq!(track.artist, "Simon & Garfunkel");
+        assert_eq!(track.album.as_deref(), Some("Bridge Over Troubled Water"));
+        assert_eq!(track.duration_ms, Some(308000));
+        assert_eq!(track.isrc.as_deref(), Some("USSM16900001"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rejects_non_xspf_and_detects_paths() {
+        assert!(parse_xspf_str("<rss><channel/></rss>").is_err());
+        assert_eq!(
+            xspf_file_path("file:///home/me/Mix.XSPF"),
+            Some(PathBuf::from("/home/me/Mix.XSPF"))
+        );
+        assert_eq!(xspf_file_path("https://example.com/mix.xspf"), None);
+        assert_eq!(xspf_file_path("/home/me/mix.m3u"), None);
+    }
+}
@@ -0,0 +1,163 @@
+//! On-disk cache for slow-changing API responses.
+//!
+//! One JSON file per key under the regenerable cache dir
+//! (`~/.cache/qbz/api_cache/`), each holding the payload and the unix time it
+//! was fetched. Readers pass their own TTL; a stale entry is still returned
+//! by [`ApiCache::get_any`] so callers can serve it while offline or when the
+//! live request fails. Like the bundle-token cache, every I/O error degrades
+//! to a miss — the cache never fails a request on its own.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long a cached artist biography is served without re-fetching.
+pub const ARTIST_BIOGRAPHY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Cache key of an artist's biography.
+pub fn artist_biography_key(artist_id: u64) -> String {
+    format!("artist:{}:bio", artist_id)
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    fetched_at: i64,
+    data: T,
+}
+
+pub struct ApiCache {
+    dir: PathBuf,
+}
+
+impl ApiCache {
+    /// The cache in the user's cache dir; `None` when the platform has none.
+    pub fn open() -> Option<Self> {
+        Some(Self::at(dirs::cache_dir()?.join("qbz").join("api_cache")))
+    }
+
+    /// A cache rooted at `dir` (created lazily on the first write).
+    pub fn at(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}.json", name))
+    }
+
+    fn read<T: DeserializeOwned>(&self, key: &str) -> Option<Entry<T>> {
+        let data = std::fs::read(self.path(key)).ok()?;
+        match serde_json::from_slice(&data) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("[ApiCache] Ignoring unreadable entry {}: {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// The entry for `key` if it was fetched less than `ttl` ago.
+    pub fn get_fresh<T: DeserializeOwned>(&self, key: &str, ttl: Duration) -> Option<T> {
+        let entry = self.read::<T>(key)?;
+        let age = now_unix().saturating_sub(entry.fetched_at);
+        (age >= 0 && (age as u64) < ttl.as_secs()).then_some(entry.data)
+    }
+
+    /// The entry for `key` regardless of age.
+    pub fn get_any<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.read::<T>(key).map(|entry| entry.data)
+    }
+
+    /// Store `data` under `key`, stamped with the current time.
+    pub fn put<T: Serialize>(&self, key: &str, data: &T) {
+        let _ = std::fs::create_dir_all(&self.dir);
+        let entry = Entry {
+            fetched_at: now_unix(),
+            data,
+        };
+        match serde_json::to_vec(&entry) {
+            Ok(bytes) => {
+                if let Err(e) = std::fs::write(self.path(key), bytes) {
+                    log::warn!("[ApiCache] Failed to write {}: {}", key, e);
+                }
+            }
+            Err(e) => log::warn!("[ApiCache] Failed to serialize {}: {}", key, e),
+        }
+    }
+
+    /// Remove every cached artist biography, leaving other entries alone.
+    /// Returns how many were removed.
+    pub fn clear_artist_biographies(&self) -> usize {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return 0;
+        };
+        entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with("artist_") && name.ends_with("_bio.json")
+            })
+            .filter(|entry| std::fs::remove_file(entry.path()).is_ok())
+            .count()
+    }
+}
+
+fn now_unix() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> ApiCache {
+        let dir =
+            std::env::temp_dir().join(format!("qbz-api-cache-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ApiCache::at(dir)
+    }
+
+    #[test]
+    fn fresh_and_stale_lookups() {
+        let cache = temp_cache("ttl");
+        let key = artist_biography_key(42);
+        assert_eq!(cache.get_any::<String>(&key), None);
+
+        cache.put(&key, &"bio".to_string());
+        assert_eq!(
+            cache.get_fresh::<String>(&key, ARTIST_BIOGRAPHY_TTL),
+            Some("bio".to_string())
+        );
+
+        // Age the entry past the TTL: only the stale lookup still serves it.
+        let old = Entry {
+            fetched_at: now_unix() - ARTIST_BIOGRAPHY_TTL.as_secs() as i64 - 1,
+            data: "old bio",
+        };
+        std::fs::write(cache.path(&key), serde_json::to_vec(&old).unwrap()).unwrap();
+        assert_eq!(cache.get_fresh::<String>(&key, ARTIST_BIOGRAPHY_TTL), None);
+        assert_eq!(cache.get_any::<String>(&key), Some("old bio".to_string()));
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn clear_only_removes_biographies() {
+        let cache = temp_cache("clear");
+        cache.put(&artist_biography_key(1), &"a");
+        cache.put(&artist_biography_key(2), &"b");
+        cache.put("artist:1:similar", &"keep");
+
+        assert_eq!(cache.clear_artist_biographies(), 2);
+        assert_eq!(cache.get_any::<String>(&artist_biography_key(1)), None);
+        assert_eq!(
+            cache.get_any::<String>("artist:1:similar"),
+            Some("keep".to_string())
+        );
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+}
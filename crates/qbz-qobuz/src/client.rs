@@ -5,6 +5,7 @@ use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use super::api_cache::{self, ApiCache};
 use super::auth::{
     get_timestamp, parse_login_response, sign_file_url, sign_get_favorites, sign_get_file_url,
     sign_request, sign_search, sign_session_start,
@@ -281,6 +282,15 @@ impl QobuzClient {
 
     /// Login with email and password
     pub async fn login(&self, email: &str, password: &str) -> Result<UserSession> {
+        let session = self.authenticate(email, password).await?;
+        *self.session.write().await = Some(session.clone());
+        Ok(session)
+    }
+
+    /// Sign in with email and password without adopting the session — the
+    /// active one is left untouched. Used to check a saved account's
+    /// credentials (and learn its user id) while another account is signed in.
+    pub async fn authenticate(&self, email: &str, password: &str) -> Result<UserSession> {
         let url = endpoints::build_url(paths::USER_LOGIN);
         // Auth exemption: raw client, bypasses the offline gate (sign-in is
         // explicit user intent to reach Qobuz; the gate governs services).
@@ -295,9 +305,7 @@ impl QobuzClient {
         match response.status() {
             StatusCode::OK => {
                 let json: Value = response.json().await?;
-                let session = parse_login_response(&json)?;
-                *self.session.write().await = Some(session.clone());
-                Ok(session)
+                parse_login_response(&json)
             }
             StatusCode::UNAUTHORIZED => Err(ApiError::AuthenticationError(
                 "Invalid credentials".to_string(),
@@ -1660,6 +1668,50 @@ impl QobuzClient {
         Ok(serde_json::from_value(response)?)
     }
 
+    /// Artist biography, served from the API cache (`artist:{id}:bio`) when
+    /// fetched within the last 24 hours. Offline, or when the live request
+    /// fails, a cached copy of any age is returned instead of the error.
+    pub async fn get_artist_biography(&self, artist_id: u64) -> Result<ArtistBiography> {
+        let cache = ApiCache::open();
+        let key = api_cache::artist_biography_key(artist_id);
+        if let Some(bio) = cache
+            .as_ref()
+            .and_then(|c| c.get_fresh(&key, api_cache::ARTIST_BIOGRAPHY_TTL))
+        {
+            return Ok(bio);
+        }
+
+        match self.get_artist_basic(artist_id).await {
+            Ok(artist) => {
+                let bio = artist.biography.unwrap_or(ArtistBiography {
+                    summary: None,
+                    content: None,
+                    source: None,
+                });
+                if let Some(cache) = &cache {
+                    cache.put(&key, &bio);
+                }
+                Ok(bio)
+            }
+            Err(e) => match cache.as_ref().and_then(|c| c.get_any(&key)) {
+                Some(bio) => {
+                    log::debug!(
+                        "[API] get_artist_biography({}) serving cached copy: {}",
+                        artist_id,
+                        e
+                    );
+                    Ok(bio)
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Drop every cached artist biography. Returns how many were removed.
+    pub fn clear_artist_biography_cache(&self) -> usize {
+        ApiCache::open().map_or(0, |c| c.clear_artist_biographies())
+    }
+
     /// Get artist by ID
     pub async fn get_artist(&self, artist_id: u64, with_albums: bool) -> Result<Artist> {
         self.get_artist_with_pagination(artist_id, with_albums, None, None)
@@ -2501,6 +2553,40 @@ impl QobuzClient {
         Err(ApiError::NoQualityAvailable)
     }
 
+    /// Lower `preferred` when the measured link is below the user's
+    /// bandwidth thresholds (see [`crate::network`]). A no-op — no probe —
+    /// while both thresholds are 0 or the probe fails. Playback paths run
+    /// their tier through this; downloads (offline cache, purchases) keep
+    /// the quality they asked for.
+    pub async fn bandwidth_capped_quality(&self, preferred: Quality) -> Quality {
+        let thresholds = crate::network::thresholds();
+        if thresholds.is_disabled() {
+            return preferred;
+        }
+        let Ok(http) = self.http() else {
+            return preferred;
+        };
+        let Some(mbps) = crate::network::cached_bandwidth(http).await else {
+            return preferred;
+        };
+        let capped = crate::network::cap_quality(preferred, mbps, thresholds);
+        if capped != preferred {
+            log::info!(
+                "Bandwidth {:.1} Mb/s below threshold: {:?} -> {:?}",
+                mbps,
+                preferred,
+                capped
+            );
+        }
+        capped
+    }
+
+    /// Run a fresh bandwidth probe (Settings "Measure" button). Refreshes the
+    /// cached value playback uses.
+    pub async fn measure_bandwidth(&self) -> Result<f64> {
+        crate::network::measure_bandwidth(self.http()?).await
+    }
+
     /// Get user favorites (requires auth + signature)
     pub async fn get_favorites(&self, fav_type: &str, limit: u32, offset: u32) -> Result<Value> {
         // Back off before the network if the 403 breaker is open (issue #637).
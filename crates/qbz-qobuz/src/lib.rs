@@ -30,6 +30,7 @@
 //! let stream_url = client.get_stream_url(track_id, quality).await?;
 //! ```
 
+pub mod api_cache;
 pub mod auth;
 pub mod bundle;
 pub mod client;
@@ -39,6 +40,7 @@ pub mod error;
 pub mod forbidden_breaker;
 pub mod link_resolver;
 pub mod lyrics;
+pub mod network;
 pub mod offline_gate;
 pub mod performers;
 pub mod purchases;
@@ -56,7 +58,7 @@ pub use cmaf::{
     CmafRawBundle, CmafStreamingInfo, CMAF_PREFETCH_CONCURRENCY,
 };
 pub use error::{ApiError, Result};
-pub use link_resolver::{resolve_link, LinkResolverError, ResolvedLink};
+pub use link_resolver::{resolve_link, resolve_link_following, LinkResolverError, ResolvedLink};
 pub use lyrics::{
     merge_translation_into, QobuzLyricsContent, QobuzLyricsDocument, QobuzLyricsLine,
     QobuzLyricsPlainLine, QobuzLyricsPublisher, QobuzLyricsTranslation, QobuzLyricsUrls,
@@ -2,10 +2,20 @@
 //!
 //! Parses Qobuz URLs (both `qobuzapp://` scheme and `https://play.qobuz.com/`)
 //! into typed navigation actions. Pure function, no I/O, no Tauri dependency.
+//!
+//! Pasted links are unwrapped first: Discord shows URLs as `<https://...>`
+//! (embed suppressed) and may prefix a `> ` quote marker. The one exception
+//! to "no I/O" is [`resolve_link_following`], which follows `qobuz.page.link`
+//! short links before resolving.
+
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Redirects followed for a `qobuz.page.link` short link before giving up.
+pub const MAX_SHORT_LINK_HOPS: usize = 3;
+
 /// A resolved Qobuz link — tells the frontend which view to navigate to.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "id")]
@@ -33,6 +43,8 @@ pub enum LinkResolverError {
     UnknownEntityType(String),
     #[error("invalid ID: {0}")]
     InvalidId(String),
+    #[error("short link could not be followed: {0}")]
+    ShortLink(String),
 }
 
 /// Resolve a Qobuz URL into a navigation action.
@@ -46,9 +58,11 @@ pub enum LinkResolverError {
 /// - `http://play.qobuz.com/album/<id>` (auto-upgraded)
 /// - Same patterns for track, artist, playlist
 ///
-/// Query parameters, fragments, and trailing slashes are stripped.
+/// Query parameters, fragments, and trailing slashes are stripped, as are
+/// Discord's `<...>` wrapper and `> ` quote prefix. Short links
+/// (`qobuz.page.link`) need [`resolve_link_following`].
 pub fn resolve_link(url: &str) -> Result<ResolvedLink, LinkResolverError> {
-    let url = url.trim();
+    let url = unwrap_pasted(url);
     if url.is_empty() {
         return Err(LinkResolverError::EmptyInput);
     }
@@ -64,6 +78,73 @@ pub fn resolve_link(url: &str) -> Result<ResolvedLink, LinkResolverError> {
     build_resolved_link(&entity_type, &raw_id)
 }
 
+/// [`resolve_link`], first following a `qobuz.page.link` short link (up to
+/// [`MAX_SHORT_LINK_HOPS`] redirects) to the URL it points at. Any other
+/// input resolves without touching the network.
+pub async fn resolve_link_following(url: &str) -> Result<ResolvedLink, LinkResolverError> {
+    let url = unwrap_pasted(url);
+    if !is_short_link(url) {
+        return resolve_link(url);
+    }
+    let target = follow_redirects(url, MAX_SHORT_LINK_HOPS)
+        .await
+        .map_err(LinkResolverError::ShortLink)?;
+    resolve_link(&target)
+}
+
+/// Whether `url` is a Qobuz short link (`https://qobuz.page.link/<code>`).
+pub fn is_short_link(url: &str) -> bool {
+    let lowered = unwrap_pasted(url).to_ascii_lowercase();
+    ["https://qobuz.page.link/", "http://qobuz.page.link/"]
+        .iter()
+        .any(|prefix| lowered.starts_with(prefix) && lowered.len() > prefix.len())
+}
+
+/// Peel what chat clients put around a pasted link: surrounding whitespace,
+/// Discord quote markers (`> `, `>>> `) and the `<...>` embed suppressor.
+fn unwrap_pasted(input: &str) -> &str {
+    let mut s = input.trim();
+    while let Some(rest) = s.strip_prefix('>') {
+        s = rest.trim_start();
+    }
+    if let Some(inner) = s.strip_prefix('<') {
+        s = inner.strip_suffix('>').unwrap_or(inner);
+    }
+    s.trim()
+}
+
+/// Walk the redirect chain from `url` by hand, stopping at the first
+/// location [`resolve_link`] understands. Errors when a hop doesn't redirect
+/// or the chain is longer than `max_hops`.
+async fn follow_redirects(url: &str, max_hops: usize) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut current = reqwest::Url::parse(url).map_err(|e| e.to_string())?;
+    for _ in 0..max_hops {
+        let response = client
+            .get(current.clone())
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if !response.status().is_redirection() {
+            return Err(format!("{current} answered {}", response.status()));
+        }
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| format!("{current} redirected without a location"))?;
+        current = current.join(location).map_err(|e| e.to_string())?;
+        if resolve_link(current.as_str()).is_ok() {
+            return Ok(current.into());
+        }
+    }
+    Err(format!("more than {max_hops} redirects"))
+}
+
 /// Strip `https://play.qobuz.com/` or `http://play.qobuz.com/` prefix.
 /// Also accepts `https://open.qobuz.com/` variant.
 fn strip_web_prefix(url: &str) -> Option<&str> {
@@ -241,6 +322,80 @@ mod tests {
         assert_eq!(result, Ok(ResolvedLink::OpenAlbum("999".into())));
     }
 
+    // ── Pasted from chat ──
+
+    #[test]
+    fn test_discord_angle_brackets() {
+        let result = resolve_link("<https://play.qobuz.com/album/0060254728933>");
+        assert_eq!(result, Ok(ResolvedLink::OpenAlbum("0060254728933".into())));
+        let result = resolve_link(" <https://open.qobuz.com/track/42?ref=discord> ");
+        assert_eq!(result, Ok(ResolvedLink::OpenTrack(42)));
+    }
+
+    #[test]
+    fn test_discord_quote_prefix() {
+        let result = resolve_link("> https://play.qobuz.com/artist/56789");
+        assert_eq!(result, Ok(ResolvedLink::OpenArtist(56789)));
+        let result = resolve_link(">>> <https://play.qobuz.com/playlist/200>");
+        assert_eq!(result, Ok(ResolvedLink::OpenPlaylist(200)));
+    }
+
+    #[test]
+    fn test_short_link_detection() {
+        assert!(is_short_link("https://qobuz.page.link/AbCd123"));
+        assert!(is_short_link("<https://qobuz.page.link/AbCd123>"));
+        assert!(!is_short_link("https://qobuz.page.link/"));
+        assert!(!is_short_link("https://play.qobuz.com/album/123"));
+        // The pure resolver doesn't know where a short link points.
+        assert_eq!(
+            resolve_link("https://qobuz.page.link/AbCd123"),
+            Err(LinkResolverError::UnsupportedScheme)
+        );
+    }
+
+    /// Serve `responses` (raw HTTP) one connection at a time on localhost.
+    fn redirect_server(responses: Vec<String>) -> String {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    fn redirect_to(location: &str) -> String {
+        format!(
+            "HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+        )
+    }
+
+    #[tokio::test]
+    async fn test_short_link_redirect_chain() {
+        let base = redirect_server(vec![
+            redirect_to("/hop"),
+            redirect_to("https://play.qobuz.com/album/0060254728933?utm_source=share"),
+        ]);
+        let target = follow_redirects(&format!("{base}/AbCd123"), MAX_SHORT_LINK_HOPS)
+            .await
+            .unwrap();
+        assert_eq!(
+            resolve_link(&target),
+            Ok(ResolvedLink::OpenAlbum("0060254728933".into()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_short_link_hop_limit() {
+        let base = redirect_server(vec![redirect_to("/a"), redirect_to("/b")]);
+        assert!(follow_redirects(&format!("{base}/x"), 2).await.is_err());
+    }
+
     // ── Error cases ──
 
     #[test]
@@ -0,0 +1,188 @@
+//! Network bandwidth probe for adaptive stream quality.
+//!
+//! On a slow link a 24/192 FLAC stream can't keep up with playback, so the
+//! client can downgrade the requested tier before asking Qobuz for a URL.
+//! The decision is driven by two user thresholds (`hires_min_mbps`,
+//! `cd_min_mbps` in the audio settings); both default to 0, which disables
+//! the probe entirely — no extra request is made unless the user opts in.
+//!
+//! Like the offline gate, the thresholds and the last measurement are
+//! process-global: there is exactly one Qobuz client per process and the
+//! values must survive the client being rebuilt on re-login.
+
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use qbz_models::Quality;
+use reqwest::Client;
+
+use crate::error::{ApiError, Result};
+
+/// Fixed-size probe object. Cloudflare's speed-test endpoint serves exactly
+/// the requested number of bytes from the nearest edge, uncompressed.
+const PROBE_URL: &str = "https://speed.cloudflare.com/__down?bytes=1000000";
+
+/// Hard ceiling for one probe. A link too slow to finish in time is scored
+/// on the bytes it did receive.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// How long a measurement is reused before the next stream request re-probes.
+pub const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Minimum link speed (Mb/s) for each tier. 0 disables that check.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BandwidthThresholds {
+    /// Below this, Hi-Res requests are capped at CD quality.
+    pub hires_min_mbps: f32,
+    /// Below this, CD (and Hi-Res) requests are capped at MP3.
+    pub cd_min_mbps: f32,
+}
+
+impl BandwidthThresholds {
+    /// No threshold configured — the probe never runs.
+    pub fn is_disabled(&self) -> bool {
+        self.hires_min_mbps <= 0.0 && self.cd_min_mbps <= 0.0
+    }
+}
+
+static THRESHOLDS: RwLock<BandwidthThresholds> = RwLock::new(BandwidthThresholds {
+    hires_min_mbps: 0.0,
+    cd_min_mbps: 0.0,
+});
+
+/// Last probe: when it ran and its result (None = the probe failed).
+static LAST_MEASUREMENT: Mutex<Option<(Instant, Option<f64>)>> = Mutex::new(None);
+
+/// Replace the process thresholds. Called by the host whenever the audio
+/// settings are (re)loaded.
+pub fn set_thresholds(thresholds: BandwidthThresholds) {
+    *THRESHOLDS.write().unwrap_or_else(|e| e.into_inner()) = thresholds;
+}
+
+/// The thresholds currently in force.
+pub fn thresholds() -> BandwidthThresholds {
+    *THRESHOLDS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Download the probe and return the observed throughput in Mb/s.
+pub async fn measure_bandwidth(http: &Client) -> Result<f64> {
+    let started = Instant::now();
+    let mut response = http
+        .get(PROBE_URL)
+        .timeout(PROBE_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let mut bytes: u64 = 0;
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => bytes += chunk.len() as u64,
+            Ok(None) => break,
+            // Timed out mid-body: score what arrived rather than failing.
+            Err(e) if e.is_timeout() && bytes > 0 => break,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    if bytes == 0 || elapsed <= 0.0 {
+        return Err(ApiError::ApiResponse(
+            "bandwidth probe returned no data".to_string(),
+        ));
+    }
+    let mbps = bytes as f64 * 8.0 / 1_000_000.0 / elapsed;
+    record(Some(mbps));
+    log::info!(
+        "[Bandwidth] {:.1} Mb/s ({} bytes in {:.2}s)",
+        mbps,
+        bytes,
+        elapsed
+    );
+    Ok(mbps)
+}
+
+/// The last measurement if it is younger than [`CACHE_TTL`], otherwise a
+/// fresh probe. A failed probe is cached too (as `None`) so a dead probe host
+/// doesn't add a timeout to every track start.
+pub async fn cached_bandwidth(http: &Client) -> Option<f64> {
+    if let Some((at, mbps)) = *LAST_MEASUREMENT.lock().unwrap_or_else(|e| e.into_inner()) {
+        if at.elapsed() < CACHE_TTL {
+            return mbps;
+        }
+    }
+    match measure_bandwidth(http).await {
+        Ok(mbps) => Some(mbps),
+        Err(e) => {
+            log::warn!("[Bandwidth] probe failed, not adapting quality: {}", e);
+            record(None);
+            None
+        }
+    }
+}
+
+fn record(mbps: Option<f64>) {
+    *LAST_MEASUREMENT.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), mbps));
+}
+
+/// Cap `preferred` to the best tier the measured link sustains. Never
+/// upgrades: a request for MP3 stays MP3 on any link.
+pub fn cap_quality(preferred: Quality, mbps: f64, thresholds: BandwidthThresholds) -> Quality {
+    let below = |min: f32| min > 0.0 && mbps < min as f64;
+    let ceiling = if below(thresholds.cd_min_mbps) {
+        Quality::Mp3
+    } else if below(thresholds.hires_min_mbps) {
+        Quality::Lossless
+    } else {
+        return preferred;
+    };
+    let order = Quality::fallback_order();
+    let rank = |q: Quality| order.iter().position(|o| *o == q).unwrap_or(0);
+    if rank(preferred) < rank(ceiling) {
+        ceiling
+    } else {
+        preferred
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOTH: BandwidthThresholds = BandwidthThresholds {
+        hires_min_mbps: 20.0,
+        cd_min_mbps: 3.0,
+    };
+
+    #[test]
+    fn fast_link_keeps_the_requested_tier() {
+        assert_eq!(
+            cap_quality(Quality::UltraHiRes, 50.0, BOTH),
+            Quality::UltraHiRes
+        );
+    }
+
+    #[test]
+    fn slow_link_caps_hires_then_cd() {
+        assert_eq!(
+            cap_quality(Quality::UltraHiRes, 10.0, BOTH),
+            Quality::Lossless
+        );
+        assert_eq!(cap_quality(Quality::HiRes, 10.0, BOTH), Quality::Lossless);
+        assert_eq!(
+            cap_quality(Quality::Lossless, 10.0, BOTH),
+            Quality::Lossless
+        );
+        assert_eq!(cap_quality(Quality::HiRes, 1.5, BOTH), Quality::Mp3);
+    }
+
+    #[test]
+    fn never_upgrades_and_zero_disables() {
+        assert_eq!(cap_quality(Quality::Mp3, 100.0, BOTH), Quality::Mp3);
+        assert_eq!(
+            cap_quality(Quality::UltraHiRes, 0.5, BandwidthThresholds::default()),
+            Quality::UltraHiRes
+        );
+        assert!(BandwidthThresholds::default().is_disabled());
+    }
+}
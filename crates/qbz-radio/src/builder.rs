@@ -19,6 +19,10 @@ pub struct BuildRadioOptions {
     pub min_pool_size_for_second_degree: u32,
     pub second_degree_artist_limit: u32,
     pub second_degree_tracks_limit: u32,
+
+    /// Artists never added to the pool (the user's dislikes). The seed
+    /// track of a track radio is kept regardless.
+    pub excluded_artist_ids: Vec<u64>,
 }
 
 impl Default for BuildRadioOptions {
@@ -38,6 +42,8 @@ impl Default for BuildRadioOptions {
             min_pool_size_for_second_degree: 80,
             second_degree_artist_limit: 3,
             second_degree_tracks_limit: 30,
+
+            excluded_artist_ids: Vec::new(),
         }
     }
 }
@@ -74,6 +80,10 @@ impl<'a> RadioPoolBuilder<'a> {
         track.streamable && track.duration > 0 && track.album.is_some()
     }
 
+    fn is_excluded(&self, artist_id: u64) -> bool {
+        self.options.excluded_artist_ids.contains(&artist_id)
+    }
+
     fn derive_rng_seed(default_seed: u64, salt: u64) -> u64 {
         if default_seed != 0 {
             return default_seed;
@@ -168,6 +178,9 @@ impl<'a> RadioPoolBuilder<'a> {
                     continue;
                 }
                 let artist_id = Self::track_artist_id(&t, seed_artist_id);
+                if self.is_excluded(artist_id) {
+                    continue;
+                }
                 self.db
                     .insert_pool_track(session_id, t.id, artist_id, "curated_playlist", 1)?;
             }
@@ -185,6 +198,9 @@ impl<'a> RadioPoolBuilder<'a> {
                 continue;
             }
             let artist_id = Self::track_artist_id(&t, seed_artist_id);
+            if self.is_excluded(artist_id) {
+                continue;
+            }
             self.db
                 .insert_pool_track(session_id, t.id, artist_id, "seed_tracks", 0)?;
         }
@@ -198,7 +214,8 @@ impl<'a> RadioPoolBuilder<'a> {
 
         let mut first_degree_artist_ids: Vec<u64> =
             similar.items.into_iter().map(|a| a.id).collect();
-        first_degree_artist_ids.retain(|id| *id != 0 && *id != seed_artist_id);
+        first_degree_artist_ids
+            .retain(|id| *id != 0 && *id != seed_artist_id && !self.is_excluded(*id));
         first_degree_artist_ids.sort();
         first_degree_artist_ids.dedup();
 
@@ -213,6 +230,9 @@ impl<'a> RadioPoolBuilder<'a> {
                     continue;
                 }
                 let artist_id = Self::track_artist_id(&t, artist_id);
+                if self.is_excluded(artist_id) {
+                    continue;
+                }
                 self.db
                     .insert_pool_track(session_id, t.id, artist_id, "similar_artist", 1)?;
             }
@@ -240,7 +260,11 @@ impl<'a> RadioPoolBuilder<'a> {
                     if added >= self.options.second_degree_artist_limit {
                         break;
                     }
-                    if a.id == 0 || a.id == seed_artist_id || a.id == base_artist_id {
+                    if a.id == 0
+                        || a.id == seed_artist_id
+                        || a.id == base_artist_id
+                        || self.is_excluded(a.id)
+                    {
                         continue;
                     }
 
@@ -256,6 +280,9 @@ impl<'a> RadioPoolBuilder<'a> {
                             continue;
                         }
                         let artist_id = Self::track_artist_id(&t, a.id);
+                        if self.is_excluded(artist_id) {
+                            continue;
+                        }
                         self.db.insert_pool_track(
                             session_id,
                             t.id,
@@ -15,7 +15,7 @@ use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 
 use qbz_integrations::musicbrainz::cache::MusicBrainzCache;
-use qbz_integrations::musicbrainz::{ArtistFullResponse, ArtistRelationships, Period, RelatedArtist};
+use qbz_integrations::musicbrainz::ArtistRelationships;
 use qbz_integrations::MusicBrainzClient;
 use qbz_qobuz::QobuzClient;
 
@@ -23,6 +23,9 @@ use crate::sparse_vector::SparseVector;
 use crate::store::ArtistVectorStore;
 use crate::weights::RelationshipWeights;
 
+/// Store source of the links between artists of one play-history batch.
+const COOCCURRENCE_SOURCE: &str = "cooccurrence";
+
 /// Builder for constructing artist vectors from multiple data sources
 pub struct ArtistVectorBuilder {
     /// Vector store for persistence
@@ -52,6 +55,19 @@ pub struct BuildResult {
     pub sources: Vec<String>,
 }
 
+/// One artist of an [`ArtistVectorBuilder::build_batch`] batch
+#[derive(Debug, Clone)]
+pub struct BatchArtist {
+    /// MusicBrainz id
+    pub mbid: String,
+    /// Display name
+    pub name: String,
+    /// Qobuz artist id, for the Qobuz-similar component
+    pub qobuz_id: Option<u64>,
+    /// Rebuild even when a stored vector exists
+    pub rebuild: bool,
+}
+
 impl ArtistVectorBuilder {
     /// Create a new builder with the given dependencies
     pub fn new(
@@ -190,7 +206,7 @@ impl ArtistVectorBuilder {
                 .map_err(|e| e.to_string())?;
 
             // Extract relationships from raw response
-            let extracted = extract_relationships(&response);
+            let extracted = ArtistRelationships::from(&response);
 
             // Cache it
             {
@@ -287,6 +303,76 @@ impl ArtistVectorBuilder {
         Ok((vector, count))
     }
 
+    /// Rebuild the vectors for a batch of artists (the user's play history)
+    /// and link the artists that share relation targets.
+    ///
+    /// Artists marked `rebuild`, or with no stored vector, are rebuilt with
+    /// [`Self::build_vector`]; the rest reuse their stored relation vectors,
+    /// so a batch that grew by a few artists only fetches those. Then every
+    /// pair in the batch whose vectors share targets (a band, a collaborator,
+    /// a Qobuz-similar artist) is linked under the `cooccurrence` source; see
+    /// [`cooccurrence_vectors`]. Artists that fail to build are skipped.
+    /// Returns how many vectors were built.
+    pub async fn build_batch(&self, artists: &[BatchArtist]) -> Result<usize, String> {
+        let mut vectors: Vec<(String, SparseVector)> = Vec::new();
+        let mut missing: Vec<&BatchArtist> = Vec::new();
+        {
+            let guard__ = self.store.lock().await;
+            let store = guard__
+                .as_ref()
+                .ok_or("No active session - please log in")?;
+            for artist in artists {
+                let stored = if artist.rebuild {
+                    None
+                } else {
+                    store.get_vector_excluding(&artist.mbid, COOCCURRENCE_SOURCE)
+                };
+                match stored {
+                    Some(vector) => vectors.push((artist.mbid.clone(), vector)),
+                    None => missing.push(artist),
+                }
+            }
+        }
+
+        let mut built = 0;
+        for artist in missing {
+            match self
+                .build_vector(&artist.mbid, Some(&artist.name), artist.qobuz_id)
+                .await
+            {
+                Ok(result) => {
+                    vectors.push((artist.mbid.clone(), result.vector));
+                    built += 1;
+                }
+                Err(e) => {
+                    log::warn!(
+                        "[VectorBuilder] Failed to build vector for {}: {}",
+                        artist.mbid,
+                        e
+                    );
+                }
+            }
+        }
+
+        let mut guard__ = self.store.lock().await;
+        let store = guard__
+            .as_mut()
+            .ok_or("No active session - please log in")?;
+
+        let mut indexed = Vec::with_capacity(vectors.len());
+        for (mbid, vector) in &vectors {
+            indexed.push((store.get_or_create_idx(mbid, None)?, vector));
+        }
+        let linked = cooccurrence_vectors(&indexed, self.weights.user_affinity);
+
+        // Written even when empty so links from a previous batch are cleared.
+        for ((mbid, _), vector) in vectors.iter().zip(&linked) {
+            store.set_vector(mbid, vector, COOCCURRENCE_SOURCE)?;
+        }
+
+        Ok(built)
+    }
+
     /// Ensure a vector exists and is fresh, building if necessary.
     ///
     /// Returns true if the vector was built/updated, false if an existing fresh
@@ -331,62 +417,33 @@ impl ArtistVectorBuilder {
     }
 }
 
-/// Extract ArtistRelationships from a raw MusicBrainz response (verbatim port —
-/// the only two relation types production uses: `member of band` + `collaboration`).
-fn extract_relationships(artist: &ArtistFullResponse) -> ArtistRelationships {
-    let mut members = Vec::new();
-    let mut past_members = Vec::new();
-    let mut groups = Vec::new();
-    let mut collaborators = Vec::new();
-
-    if let Some(relations) = &artist.relations {
-        for relation in relations {
-            let Some(related_artist) = &relation.artist else {
+/// Co-occurrence links between the artists of a batch, one vector per input
+/// (same order). Two artists are linked when their vectors share targets,
+/// each pointing at the other with `weight * shared / min(len_a, len_b)` — an
+/// overlap coefficient, so a small vector fully contained in a large one still
+/// counts as a strong link.
+fn cooccurrence_vectors(artists: &[(u32, &SparseVector)], weight: f32) -> Vec<SparseVector> {
+    let mut linked = vec![SparseVector::new(); artists.len()];
+    for (i, &(idx_a, vec_a)) in artists.iter().enumerate() {
+        for (j, &(idx_b, vec_b)) in artists.iter().enumerate().skip(i + 1) {
+            let smaller = vec_a.nnz().min(vec_b.nnz());
+            if smaller == 0 {
+                continue;
+            }
+            let shared = vec_a
+                .indices()
+                .iter()
+                .filter(|idx| vec_b.get(**idx) != 0.0)
+                .count();
+            if shared == 0 {
                 continue;
-            };
-
-            let related = RelatedArtist {
-                mbid: related_artist.id.clone(),
-                name: related_artist.name.clone(),
-                role: relation
-                    .attributes
-                    .as_ref()
-                    .and_then(|a| a.first().cloned()),
-                period: Some(Period {
-                    begin: relation.begin.clone(),
-                    end: relation.end.clone(),
-                }),
-                ended: relation.ended.unwrap_or(false),
-            };
-
-            match relation.relation_type.as_str() {
-                "member of band" => {
-                    if relation.direction.as_deref() == Some("backward") {
-                        // We're viewing a BAND, the related artist is a MEMBER
-                        if related.ended {
-                            past_members.push(related);
-                        } else {
-                            members.push(related);
-                        }
-                    } else {
-                        // We're viewing a PERSON, the related artist is a BAND/GROUP
-                        groups.push(related);
-                    }
-                }
-                "collaboration" => {
-                    collaborators.push(related);
-                }
-                _ => {}
             }
+            let score = weight * shared as f32 / smaller as f32;
+            linked[i].set(idx_b, score);
+            linked[j].set(idx_a, score);
         }
     }
-
-    ArtistRelationships {
-        members,
-        past_members,
-        groups,
-        collaborators,
-    }
+    linked
 }
 
 #[cfg(test)]
@@ -402,4 +459,18 @@ mod tests {
         assert!(weights.member_of_band > weights.collaboration);
         assert!(weights.collaboration > weights.shared_tag);
     }
+
+    #[test]
+    fn cooccurrence_links_artists_sharing_targets() {
+        let a = SparseVector::from_parts(vec![10, 11, 12], vec![1.0, 0.8, 0.7]);
+        let b = SparseVector::from_parts(vec![11, 12], vec![0.9, 0.7]);
+        let c = SparseVector::from_parts(vec![20], vec![1.0]);
+        let linked = cooccurrence_vectors(&[(1, &a), (2, &b), (3, &c)], 0.5);
+
+        // b's two targets are both in a: full overlap.
+        assert_eq!(linked[0].get(2), 0.5);
+        assert_eq!(linked[1].get(1), 0.5);
+        assert!(linked[2].is_empty());
+        assert_eq!(linked[0].nnz(), 1);
+    }
 }
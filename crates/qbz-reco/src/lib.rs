@@ -14,7 +14,7 @@ mod store;
 mod suggestions;
 mod weights;
 
-pub use builder::{ArtistVectorBuilder, BuildResult};
+pub use builder::{ArtistVectorBuilder, BatchArtist, BuildResult};
 pub use sparse_vector::SparseVector;
 pub use store::{ArtistVectorStore, SimilarArtist, VECTOR_TTL_SECS};
 pub use suggestions::{
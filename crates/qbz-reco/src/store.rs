@@ -230,15 +230,28 @@ impl ArtistVectorStore {
 
     /// Get the combined vector for an artist (all sources merged)
     pub fn get_vector(&self, mbid: &str) -> Option<SparseVector> {
+        self.merged_vector(mbid, None)
+    }
+
+    /// Get the combined vector for an artist, leaving out one source.
+    pub fn get_vector_excluding(&self, mbid: &str, source: &str) -> Option<SparseVector> {
+        self.merged_vector(mbid, Some(source))
+    }
+
+    fn merged_vector(&self, mbid: &str, exclude_source: Option<&str>) -> Option<SparseVector> {
         let artist_idx = self.get_idx(mbid)?;
 
         let mut stmt = self
             .conn
-            .prepare("SELECT target_idx, SUM(weight) FROM vector_entries WHERE artist_idx = ?1 GROUP BY target_idx")
+            .prepare(
+                "SELECT target_idx, SUM(weight) FROM vector_entries
+                 WHERE artist_idx = ?1 AND (?2 IS NULL OR source != ?2)
+                 GROUP BY target_idx",
+            )
             .ok()?;
 
         let rows = stmt
-            .query_map(params![artist_idx], |row| {
+            .query_map(params![artist_idx, exclude_source], |row| {
                 Ok((row.get::<_, u32>(0)?, row.get::<_, f32>(1)?))
             })
             .ok()?;
@@ -489,6 +502,32 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn vector_excluding_a_source() {
+        let dir = unique_test_dir("exclude");
+        let mut store = ArtistVectorStore::open_at(&dir).unwrap();
+        let b = store.get_or_create_idx("mbid-b", None).unwrap();
+        let c = store.get_or_create_idx("mbid-c", None).unwrap();
+
+        let mut relations = SparseVector::new();
+        relations.set(b, 1.0);
+        store
+            .set_vector("mbid-a", &relations, "musicbrainz")
+            .unwrap();
+        let mut links = SparseVector::new();
+        links.set(b, 0.5);
+        links.set(c, 0.5);
+        store.set_vector("mbid-a", &links, "cooccurrence").unwrap();
+
+        assert_eq!(store.get_vector("mbid-a").unwrap().get(b), 1.5);
+        let own = store
+            .get_vector_excluding("mbid-a", "cooccurrence")
+            .unwrap();
+        assert_eq!(own.get(b), 1.0);
+        assert_eq!(own.nnz(), 1);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[test]
     fn related_artists_rank_by_summed_weight() {
         let dir = unique_test_dir("related");
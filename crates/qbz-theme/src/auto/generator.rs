@@ -17,7 +17,7 @@
 
 use super::{PaletteColor, SystemColorScheme, ThemePalette};
 use crate::colors::{alpha_ramp, ThemeColors};
-use crate::color::Rgba;
+use crate::color::{contrast_ratio, Rgba};
 
 /// Legacy card shadow (`rgba(0,0,0,0.4)`), identical to the registry constant so
 /// generated themes drop the same shadow as static ones.
@@ -314,6 +314,77 @@ pub fn theme_from_scheme(scheme: &SystemColorScheme) -> ThemeColors {
     )
 }
 
+// --- accessibility report ----------------------------------------------------
+
+/// WCAG 2.x AA threshold for body text.
+pub const WCAG_AA: f64 = 4.5;
+/// WCAG 2.x AAA threshold for body text.
+pub const WCAG_AAA: f64 = 7.0;
+
+/// Text/background token pairs the report checks, as `(text, background)`
+/// token names (Slint `ThemeColors` spelling). Muted/disabled text is left
+/// out: it is intentionally held to 3:1 (a visual cue), so it would always
+/// "fail" AA.
+pub const CONTRAST_PAIRS: &[(&str, &str)] = &[
+    ("text-primary", "surface-main"),
+    ("text-secondary", "surface-main"),
+    ("text-primary", "surface-card"),
+    ("text-secondary", "surface-card"),
+    ("text-primary", "surface-elevated"),
+    ("accent-text", "accent"),
+    ("accent-text", "accent-hover"),
+];
+
+/// Look up one [`CONTRAST_PAIRS`] token on a theme.
+fn token(theme: &ThemeColors, name: &str) -> Option<Rgba> {
+    Some(match name {
+        "surface-main" => theme.surface_main,
+        "surface-card" => theme.surface_card,
+        "surface-elevated" => theme.surface_elevated,
+        "text-primary" => theme.text_primary,
+        "text-secondary" => theme.text_secondary,
+        "accent" => theme.accent,
+        "accent-hover" => theme.accent_hover,
+        "accent-text" => theme.accent_text,
+        _ => return None,
+    })
+}
+
+/// Contrast failures of a generated theme. Each entry is
+/// `("<text> on <background>", ratio)`; a pair below AA is listed in BOTH
+/// vectors (it fails AAA too).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThemeAccessibilityReport {
+    pub variables_failing_aa: Vec<(String, f64)>,
+    pub variables_failing_aaa: Vec<(String, f64)>,
+}
+
+impl ThemeAccessibilityReport {
+    /// Whether every checked pair clears AA.
+    pub fn passes_aa(&self) -> bool {
+        self.variables_failing_aa.is_empty()
+    }
+}
+
+/// Check every [`CONTRAST_PAIRS`] entry against the WCAG AA/AAA thresholds.
+pub fn check_accessibility(theme: &ThemeColors) -> ThemeAccessibilityReport {
+    let mut report = ThemeAccessibilityReport::default();
+    for (text, background) in CONTRAST_PAIRS {
+        let (Some(fg), Some(bg)) = (token(theme, text), token(theme, background)) else {
+            continue;
+        };
+        let ratio = contrast_ratio(fg, bg);
+        let label = format!("{text} on {background}");
+        if ratio < WCAG_AA {
+            report.variables_failing_aa.push((label.clone(), ratio));
+        }
+        if ratio < WCAG_AAA {
+            report.variables_failing_aaa.push((label, ratio));
+        }
+    }
+    report
+}
+
 // --- contrast helpers (ported 1:1 from auto_theme::generator) ---------------
 
 /// Pick the best foreground for text on the accent triplet (base, hover, active),
@@ -455,6 +526,41 @@ mod tests {
         assert_eq!(c.success_hover.a, c.danger_hover.a);
     }
 
+    #[test]
+    fn contrast_pairs_resolve_to_tokens() {
+        let c = theme_from_palette(&dark_palette());
+        for (text, background) in CONTRAST_PAIRS {
+            assert!(token(&c, text).is_some(), "{text}");
+            assert!(token(&c, background).is_some(), "{background}");
+        }
+    }
+
+    #[test]
+    fn accessibility_report_flags_low_contrast_pairs() {
+        // The generator enforces AA on primary text.
+        let mut c = theme_from_palette(&dark_palette());
+        let clean = check_accessibility(&c);
+        assert!(!clean
+            .variables_failing_aa
+            .iter()
+            .any(|(l, _)| l == "text-primary on surface-main"));
+
+        c.text_secondary = Rgba::rgb(70, 70, 75);
+        let report = check_accessibility(&c);
+        assert!(!report.passes_aa());
+        let (_, ratio) = report
+            .variables_failing_aa
+            .iter()
+            .find(|(l, _)| l == "text-secondary on surface-main")
+            .expect("secondary text flagged");
+        assert!(*ratio < WCAG_AA);
+        // An AA failure is an AAA failure too.
+        assert!(report
+            .variables_failing_aaa
+            .iter()
+            .any(|(l, _)| l == "text-secondary on surface-main"));
+    }
+
     #[test]
     fn deterministic_for_fixed_seed() {
         let a = theme_from_palette(&dark_palette());
@@ -17,7 +17,9 @@ pub mod system;
 
 use serde::{Deserialize, Serialize};
 
-pub use generator::{theme_from_palette, theme_from_scheme};
+pub use generator::{
+    check_accessibility, theme_from_palette, theme_from_scheme, ThemeAccessibilityReport,
+};
 pub use system::{
     detect_desktop_environment, get_system_accent_color, get_system_color_scheme,
     get_system_wallpaper, DesktopEnvironment,
@@ -66,6 +66,19 @@ impl Rgba {
     pub fn to_hex(self) -> String {
         format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
     }
+
+    /// Per-channel linear blend towards `to` (`t` clamped to 0.0..=1.0), alpha
+    /// included. Drives the animated theme transition.
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self::rgba(
+            mix(self.r, to.r),
+            mix(self.g, to.g),
+            mix(self.b, to.b),
+            mix(self.a, to.a),
+        )
+    }
 }
 
 /// sRGB 0..=255 channel -> linear-light 0.0..=1.0 (WCAG 2.x transfer function).
@@ -120,6 +120,59 @@ impl ThemeColors {
         }
         self.alpha[best]
     }
+
+    /// Every token blended towards `to` at `t` (0.0 = `self`, 1.0 = `to`).
+    /// The frame-by-frame palette of an animated theme change.
+    pub fn lerp(&self, to: &ThemeColors, t: f32) -> ThemeColors {
+        let m = |a: Rgba, b: Rgba| a.lerp(b, t);
+        let mut alpha = self.alpha;
+        for (slot, target) in alpha.iter_mut().zip(to.alpha.iter()) {
+            *slot = m(*slot, *target);
+        }
+        ThemeColors {
+            surface_main: m(self.surface_main, to.surface_main),
+            surface_card: m(self.surface_card, to.surface_card),
+            surface_elevated: m(self.surface_elevated, to.surface_elevated),
+            surface_hover: m(self.surface_hover, to.surface_hover),
+            bg_hover: m(self.bg_hover, to.bg_hover),
+
+            text_primary: m(self.text_primary, to.text_primary),
+            text_secondary: m(self.text_secondary, to.text_secondary),
+            text_muted: m(self.text_muted, to.text_muted),
+            text_disabled: m(self.text_disabled, to.text_disabled),
+
+            accent: m(self.accent, to.accent),
+            accent_hover: m(self.accent_hover, to.accent_hover),
+            accent_pressed: m(self.accent_pressed, to.accent_pressed),
+            accent_text: m(self.accent_text, to.accent_text),
+
+            danger: m(self.danger, to.danger),
+            danger_bg: m(self.danger_bg, to.danger_bg),
+            danger_border: m(self.danger_border, to.danger_border),
+            danger_hover: m(self.danger_hover, to.danger_hover),
+
+            warning: m(self.warning, to.warning),
+            warning_bg: m(self.warning_bg, to.warning_bg),
+            warning_border: m(self.warning_border, to.warning_border),
+            warning_hover: m(self.warning_hover, to.warning_hover),
+
+            success: m(self.success, to.success),
+            success_bg: m(self.success_bg, to.success_bg),
+            success_border: m(self.success_border, to.success_border),
+            success_hover: m(self.success_hover, to.success_hover),
+
+            border_subtle: m(self.border_subtle, to.border_subtle),
+            border_muted: m(self.border_muted, to.border_muted),
+            border_strong: m(self.border_strong, to.border_strong),
+
+            focus_ring: m(self.focus_ring, to.focus_ring),
+
+            favorite: m(self.favorite, to.favorite),
+            card_shadow: m(self.card_shadow, to.card_shadow),
+
+            alpha,
+        }
+    }
 }
 
 /// Build the 24-tier alpha ramp for a theme of the given polarity.
@@ -160,6 +213,18 @@ mod tests {
         assert_eq!(light[alpha_index(8).unwrap()], Rgba::rgba(0, 0, 0, 0x14));
     }
 
+    #[test]
+    fn lerp_hits_both_ends_and_the_midpoint() {
+        let dark = crate::palette(crate::ThemeId::Dark);
+        let light = crate::palette(crate::ThemeId::Light);
+        assert_eq!(dark.lerp(&light, 0.0), dark);
+        assert_eq!(dark.lerp(&light, 1.0), light);
+        assert_eq!(
+            Rgba::rgb(0, 100, 255).lerp(Rgba::rgba(255, 200, 255, 0), 0.5),
+            Rgba::rgba(128, 150, 255, 128)
+        );
+    }
+
     #[test]
     fn alpha_count_is_24() {
         assert_eq!(ALPHA_COUNT, 24);
@@ -0,0 +1,117 @@
+//! Settings > Integrations > QOBUZ ACCOUNTS and the header account switcher.
+//!
+//! Thin controller over the per-account credential store in
+//! `qbz_credentials` (`save_qobuz_credentials_for` / `list_saved_accounts`).
+//! The switch itself lives in [`crate::auth::switch_account`]; main.rs owns
+//! the session teardown around it, exactly as for logout.
+
+use std::sync::Arc;
+
+use slint::{ComponentHandle, Model, ModelRc, VecModel};
+
+use qbz_app::shell::AppRuntime;
+
+use crate::adapter::SlintAdapter;
+use crate::{AccountsState, AppWindow, SavedAccountItem};
+
+/// Re-read the saved accounts into `AccountsState` — the section's `load()`
+/// callback, also run once at startup for the header menu.
+pub fn load(weak: slint::Weak<AppWindow>, handle: &tokio::runtime::Handle) {
+    handle.spawn(async move {
+        let accounts = tokio::task::spawn_blocking(qbz_credentials::list_saved_accounts)
+            .await
+            .unwrap_or_default();
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            let items: Vec<SavedAccountItem> = accounts
+                .into_iter()
+                .map(|account| SavedAccountItem {
+                    id: account.account_id.into(),
+                    email: account.email.into(),
+                })
+                .collect();
+            w.global::<AccountsState>()
+                .set_accounts(ModelRc::new(VecModel::from(items)));
+        });
+    });
+}
+
+/// Store the "add account" form fields — the section's `save()` callback.
+/// UI thread. The credentials are checked with Qobuz first and stored under
+/// the account's Qobuz user id, so saving an account that is already stored
+/// (under any spelling of its email) replaces its entry.
+pub fn save(
+    window: &AppWindow,
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    handle: &tokio::runtime::Handle,
+) {
+    let state = window.global::<AccountsState>();
+    let email = state.get_new_email().trim().to_string();
+    let password = state.get_new_password().to_string();
+    if email.is_empty() || password.is_empty() {
+        return;
+    }
+    let weak = window.as_weak();
+    let reload = handle.clone();
+    handle.spawn(async move {
+        let account_id = match crate::auth::saved_account_id(&runtime, &email, &password).await {
+            Ok(id) => id,
+            Err(e) => {
+                log::warn!("[qbz-slint] saved account sign-in check failed: {e}");
+                crate::toast::error_weak(
+                    &weak,
+                    qbz_i18n::t("Could not sign in with that email and password"),
+                );
+                return;
+            }
+        };
+        let result = tokio::task::spawn_blocking(move || {
+            qbz_credentials::save_qobuz_credentials_for(&account_id, &email, &password)
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        match result {
+            Ok(()) => {
+                let _ = weak.upgrade_in_event_loop(|w| {
+                    let state = w.global::<AccountsState>();
+                    state.set_new_email("".into());
+                    state.set_new_password("".into());
+                });
+                crate::toast::success_weak(&weak, qbz_i18n::t("Account saved"));
+                load(weak, &reload);
+            }
+            Err(e) => {
+                log::error!("[qbz-slint] saving account failed: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Failed to save account"));
+            }
+        }
+    });
+}
+
+/// Forget one saved account — the row's Remove button.
+pub fn remove(weak: slint::Weak<AppWindow>, handle: &tokio::runtime::Handle, account_id: String) {
+    let reload = handle.clone();
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            qbz_credentials::remove_qobuz_credentials_for(&account_id)
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        if let Err(e) = result {
+            log::error!("[qbz-slint] removing account failed: {e}");
+            crate::toast::error_weak(&weak, qbz_i18n::t("Failed to remove account"));
+        }
+        load(weak, &reload);
+    });
+}
+
+/// Email of a saved account, for marking it as the signed-in one after a
+/// switch. UI thread.
+pub fn email_for(window: &AppWindow, account_id: &str) -> String {
+    window
+        .global::<AccountsState>()
+        .get_accounts()
+        .iter()
+        .find(|account| account.id == account_id)
+        .map(|account| account.email.to_string())
+        .unwrap_or_default()
+}
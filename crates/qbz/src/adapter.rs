@@ -2,8 +2,8 @@
 //!
 //! `SlintAdapter` implements `FrontendAdapter` so `QbzCore` can emit events
 //! toward the Slint UI. It holds a weak handle to the window to post updates
-//! onto the Slint event loop. The auth milestone only logs events; later
-//! milestones route them into UI models.
+//! onto the Slint event loop; events with a UI surface are routed to their
+//! controller, the rest are only logged.
 
 use async_trait::async_trait;
 use qbz_core::{CoreEvent, FrontendAdapter};
@@ -11,7 +11,6 @@ use qbz_core::{CoreEvent, FrontendAdapter};
 use crate::AppWindow;
 
 pub struct SlintAdapter {
-    #[allow(dead_code)] // used by later milestones to push UI model updates
     window: slint::Weak<AppWindow>,
 }
 
@@ -25,6 +24,17 @@ impl SlintAdapter {
 impl FrontendAdapter for SlintAdapter {
     async fn on_event(&self, event: CoreEvent) {
         log::debug!("[qbz-slint] core event: {:?}", event);
+        match &event {
+            CoreEvent::QueueUpdated { state } => crate::media_controls::publish_queue(state),
+            CoreEvent::QueueStatsChanged { stats } => {
+                crate::queue::publish_stats(&self.window, *stats)
+            }
+            CoreEvent::RepeatModeChanged { mode } => crate::media_controls::publish_repeat(*mode),
+            CoreEvent::ShuffleChanged { enabled } => {
+                crate::media_controls::publish_shuffle(*enabled)
+            }
+            _ => {}
+        }
     }
 
     async fn on_ready(&self) {
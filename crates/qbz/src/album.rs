@@ -134,6 +134,17 @@ where
     Ok(map_album(album))
 }
 
+/// ISRCs of the album's classical tracks (the ones Qobuz tags with a work),
+/// i.e. the tracks Track Info looks a MusicBrainz work up for.
+pub fn work_isrcs(tracks: &[Track]) -> Vec<String> {
+    tracks
+        .iter()
+        .filter(|t| t.work.as_deref().is_some_and(|w| !w.is_empty()))
+        .filter_map(|t| t.isrc.clone())
+        .filter(|isrc| !isrc.is_empty())
+        .collect()
+}
+
 /// Localize an ISO `YYYY-MM-DD` release date to a short readable form
 /// ("Feb 19, 2026"), via the active locale. Empty when absent or unparseable
 /// (the header simply omits the date segment, as before). Mirrors the
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use qbz_app::shell::AppRuntime;
 use qbz_core::FrontendAdapter;
 use qbz_models::{
-    ArtistStoryItem, PageArtistRelease, PageArtistResponse, PageArtistTrack,
+    ArtistBiography, ArtistStoryItem, PageArtistRelease, PageArtistResponse, PageArtistTrack,
 };
 use slint::{ComponentHandle, Model, ModelRc, VecModel};
 
@@ -122,7 +122,9 @@ fn title_case(key: &str) -> String {
     }
 }
 
-/// Fetch and map an artist page by id.
+/// Fetch and map an artist page by id. The biography comes from the
+/// cached `get_artist_biography` (24h, stale copy when offline); the page's
+/// own biography is only the fallback when that yields nothing.
 pub async fn load_artist<A>(
     runtime: &Arc<AppRuntime<A>>,
     artist_id: &str,
@@ -133,12 +135,20 @@ where
     let id: u64 = artist_id
         .parse()
         .map_err(|_| format!("invalid artist id: {artist_id}"))?;
-    let page = runtime
-        .core()
-        .get_artist_page(id, None)
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(map_artist(page))
+    let core = runtime.core();
+    let (page, biography) = tokio::join!(
+        core.get_artist_page(id, None),
+        core.get_artist_biography(id)
+    );
+    let page = page.map_err(|e| e.to_string())?;
+    let biography = match biography {
+        Ok(bio) => Some(bio),
+        Err(e) => {
+            log::warn!("[qbz-slint] artist biography load failed: {e}");
+            None
+        }
+    };
+    Ok(map_artist(page, biography))
 }
 
 /// Fetch one more page of an artist's releases for a given bucket via
@@ -258,15 +268,26 @@ pub fn apply_stories(window: &AppWindow, stories: Vec<StoryData>) -> Vec<Artwork
     jobs
 }
 
-fn map_artist(page: PageArtistResponse) -> ArtistData {
+fn map_artist(page: PageArtistResponse, cached_bio: Option<ArtistBiography>) -> ArtistData {
     let name = page.name.display;
 
-    // Biography: content (HTML-stripped) + source name (when present). The
-    // /artist/page biography.source is a raw JSON value because Qobuz
-    // sometimes returns a string and sometimes an object; we only care
-    // about the string form.
-    let (bio, bio_source) = match page.biography {
-        Some(biography) => {
+    // Biography: content (HTML-stripped) + source name (when present),
+    // preferring the cached biography. The /artist/page biography.source
+    // is a raw JSON value because Qobuz sometimes returns a string and
+    // sometimes an object; we only care about the string form.
+    let cached_bio = cached_bio.filter(|b| b.content.is_some());
+    let (bio, bio_source) = match (cached_bio, page.biography) {
+        (Some(cached), _) => (
+            cached
+                .content
+                .map(|c| crate::strip_html::strip_html(&c))
+                .unwrap_or_default(),
+            cached
+                .source
+                .map(|s| crate::strip_html::decode_html_entities(&s))
+                .unwrap_or_default(),
+        ),
+        (_, Some(biography)) => {
             let content = biography
                 .content
                 .map(|c| crate::strip_html::strip_html(&c))
@@ -279,7 +300,7 @@ fn map_artist(page: PageArtistResponse) -> ArtistData {
                 .unwrap_or_default();
             (content, source)
         }
-        None => (String::new(), String::new()),
+        (_, None) => (String::new(), String::new()),
     };
     let bio_short = truncate_words(&bio, 360);
     let bio_truncated = bio_short != bio;
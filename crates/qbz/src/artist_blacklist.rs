@@ -25,6 +25,7 @@ use std::sync::Mutex;
 use qbz_app::settings::artist_blacklist::{
     BlacklistService, BlacklistedAlbum, BlacklistedArtist, DB_FILE_NAME,
 };
+use qbz_models::QueueTrack;
 
 /// Per-user blacklist service. `None` outside an active session (online or
 /// offline); pure fail-open behavior in that window.
@@ -182,6 +183,23 @@ pub fn is_track_blacklisted(
     stamp_row(source, &[performer.as_str(), composer.as_str()], album_id)
 }
 
+/// [`is_track_blacklisted`] for an already-built `QueueTrack`. The queue entry
+/// carries `source` + `artist_id` (performer) but no composer id, so this leg
+/// is performer-only; a missing `source` is a Qobuz row.
+pub fn is_queue_track_blacklisted(track: &QueueTrack) -> bool {
+    let source = track.source.as_deref().unwrap_or("qobuz");
+    is_track_blacklisted(source, track.artist_id, None, track.album_id.as_deref())
+}
+
+/// Split queue entries into `(kept, removed)` by
+/// [`is_queue_track_blacklisted`], preserving order on both sides. Used to
+/// retro-apply a blacklist change to tracks that were queued before it.
+pub fn filter_queue(tracks: Vec<QueueTrack>) -> (Vec<QueueTrack>, Vec<QueueTrack>) {
+    tracks
+        .into_iter()
+        .partition(|track| !is_queue_track_blacklisted(track))
+}
+
 /// True when the blacklist feature is enabled. Default-enabled (`true`) when no
 /// session is bound.
 pub fn is_enabled() -> bool {
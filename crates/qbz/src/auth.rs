@@ -119,6 +119,31 @@ where
     // Emit LoggedIn through the core (idempotent set_session).
     core.set_session(session).await.map_err(|e| e.to_string())?;
 
+    activate_user(runtime, user_id).await?;
+
+    // Persist the token so the next launch restores the session silently.
+    if let Err(e) = qbz_credentials::save_oauth_token(&token) {
+        log::warn!("[qbz-slint] failed to persist OAuth token: {e}");
+    }
+
+    log::info!("[qbz-slint] login complete for user {user_id}");
+    Ok(SessionInfo {
+        user_id,
+        display_name,
+        subscription,
+    })
+}
+
+/// Bring up everything per-user after a successful sign-in: the session
+/// dirs/store, the offline cache, the per-user stores, and the D4 valid
+/// verdict + D2 recovery (a completed login ends any offline session).
+/// Shared by every login path so they can't drift apart.
+async fn activate_user<A>(runtime: &Arc<AppRuntime<A>>, user_id: u64) -> Result<(), String>
+where
+    A: FrontendAdapter + Send + Sync + 'static,
+{
+    let core = runtime.core();
+
     // Activate the per-user session (creates dirs, opens the session store).
     runtime.activate(user_id).await?;
 
@@ -147,8 +172,9 @@ where
         // Playlist Suggested Songs: open the per-user artist-vector store on
         // the core (the suggestions engine reads/writes it).
         if let Ok(store) = qbz_reco::ArtistVectorStore::open_at(&dir) {
-            runtime.core().set_artist_vectors(store).await;
+            core.set_artist_vectors(store).await;
         }
+        crate::musicbrainz_cache::init_for_user(core, &dir);
         crate::discover_prefs::init_for_user(&dir);
         crate::artist_blacklist::init_for_user(&dir);
         crate::pinned::init_for_user(&dir);
@@ -161,15 +187,70 @@ where
     }
     // Lyrics cache (per-user, shared lyrics.db with Tauri).
     crate::lyrics::init_for_user(core.client(), user_id);
+    // Finish purchase downloads the previous run was cut off in the middle of.
+    let client = core.client().read().await.as_ref().cloned();
+    if let Some(client) = client {
+        tokio::spawn(crate::purchases::resume_interrupted_downloads(client));
+    }
     crate::offline_mode::subscription_mark_valid();
     crate::offline_mode::engine().set_offline_session(false);
+    Ok(())
+}
+
+/// Check a saved account's email+password with Qobuz (the current session
+/// stays signed in) and return the account id to store them under.
+pub async fn saved_account_id<A>(
+    runtime: &Arc<AppRuntime<A>>,
+    email: &str,
+    password: &str,
+) -> Result<String, String>
+where
+    A: FrontendAdapter + Send + Sync + 'static,
+{
+    let core = runtime.core();
+    ensure_api_initialized(core).await?;
+    let user_id = core
+        .verify_credentials(email, password)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(qbz_credentials::account_id_for_user(user_id))
+}
+
+/// Switch to a saved account: log the current one out, sign in with the
+/// stored email+password, and persist the new session token so the next
+/// launch restores the account switched to.
+pub async fn switch_account<A>(
+    runtime: &Arc<AppRuntime<A>>,
+    account_id: &str,
+) -> Result<SessionInfo, String>
+where
+    A: FrontendAdapter + Send + Sync + 'static,
+{
+    let credentials = qbz_credentials::load_qobuz_credentials_for(account_id)?
+        .ok_or_else(|| format!("No saved credentials for account {account_id}"))?;
+
+    logout(runtime).await?;
+
+    let core = runtime.core();
+    ensure_api_initialized(core).await?;
+    let session = core
+        .login(&credentials.email, &credentials.password)
+        .await
+        .map_err(|e| e.to_string())?;
+    let user_id = session.user_id;
+    let display_name = session.display_name.clone();
+    let subscription = session.subscription_label.clone();
+    let token = session.user_auth_token.clone();
+    qbz_log::register_secret(token.clone());
+
+    core.set_session(session).await.map_err(|e| e.to_string())?;
+    activate_user(runtime, user_id).await?;
 
-    // Persist the token so the next launch restores the session silently.
     if let Err(e) = qbz_credentials::save_oauth_token(&token) {
-        log::warn!("[qbz-slint] failed to persist OAuth token: {e}");
+        log::warn!("[qbz-slint] failed to persist session token: {e}");
     }
 
-    log::info!("[qbz-slint] login complete for user {user_id}");
+    log::info!("[qbz-slint] switched to account {account_id} (user {user_id})");
     Ok(SessionInfo {
         user_id,
         display_name,
@@ -262,45 +343,7 @@ where
             let display_name = session.display_name.clone();
             let subscription = session.subscription_label.clone();
             core.set_session(session).await.map_err(|e| e.to_string())?;
-            runtime.activate(user_id).await?;
-            crate::offline::activate(user_id).await;
-            crate::offline_cache::load_cached_ids().await;
-            // Offline-MODE per-user binding + D4 valid verdict + D2 recovery
-            // (same ordering as login_via_system_browser).
-            if let Some(dir) = crate::offline_mode::user_data_dir(user_id) {
-                crate::offline_mode::init_for_user(&dir);
-                crate::fav_cache::init_for_user(&dir);
-                // Reco-scoped "Not interested" dismissal store (reco rails
-                // only).
-                crate::reco_dismiss::init_for_user(&dir);
-                // Recommendation event store (shared events.db with Tauri).
-                // Train after init (off-thread) so the seeds reflect this
-                // session's events.
-                crate::reco::init_for_user(&dir);
-                crate::reco::train_async();
-                // External-recommendations resolution + per-week Weekly cache
-                // (online-path parity — see login_via_system_browser; without
-                // it the reco cache is never bound online).
-                crate::external_reco::init_for_user(&dir);
-                // Playlist Suggested Songs: open the per-user artist-vector
-                // store on the core (the suggestions engine reads/writes it).
-                if let Ok(store) = qbz_reco::ArtistVectorStore::open_at(&dir) {
-                    runtime.core().set_artist_vectors(store).await;
-                }
-                crate::discover_prefs::init_for_user(&dir);
-                crate::artist_blacklist::init_for_user(&dir);
-                crate::pinned::init_for_user(&dir);
-                crate::local_favorites::init_for_user(&dir);
-                // Intelligent Search (cache + ranking), seeded from the pref.
-                crate::search_service::init(&dir, crate::ui_prefs::load().intelligent_search);
-                // Session persistence (queue + playback): open the per-user
-                // session.db and seed the persist/resume gates.
-                crate::session_persist::init_for_user(&dir);
-            }
-            // Lyrics cache (per-user, shared lyrics.db with Tauri).
-            crate::lyrics::init_for_user(core.client(), user_id);
-            crate::offline_mode::subscription_mark_valid();
-            crate::offline_mode::engine().set_offline_session(false);
+            activate_user(runtime, user_id).await?;
             log::info!("[qbz-slint] restored saved session for user {user_id}");
             Ok(Some(SessionInfo {
                 user_id,
@@ -345,6 +388,7 @@ where
     crate::reco_dismiss::teardown();
     crate::reco::teardown();
     runtime.core().clear_artist_vectors().await;
+    runtime.core().close_musicbrainz_cache();
     crate::discover_prefs::teardown();
     crate::artist_blacklist::teardown();
     crate::pinned::teardown();
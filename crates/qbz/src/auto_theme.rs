@@ -10,12 +10,74 @@
 //! Deviation vs Tauri: Tauri regenerated the wallpaper theme reactively; here v1
 //! regenerates on activation, on source change, on image pick, and via the
 //! explicit "Regenerate" button — there is no live wallpaper file-watcher.
+//!
+//! A regenerated palette cross-fades in over the persisted
+//! `GraphicsSettings::theme_transition_ms` (0 = instant); the startup apply is
+//! always instant so the first paint is already final.
+//!
+//! Every generated palette is checked against WCAG AA
+//! (`qbz_theme::auto::check_accessibility`); failures surface as a warning row
+//! under the auto-theme controls.
 
 use crate::AppWindow;
 use crate::AppearanceState;
 use qbz_theme::AutoSource;
 use slint::ComponentHandle;
 
+/// Transition lengths behind the Settings "Color transition" options, in
+/// dropdown order (Off / 200 / 400 / 800 ms).
+const TRANSITION_OPTIONS_MS: [u32; 4] = [0, 200, 400, 800];
+
+/// Default cross-fade (`GraphicsSettings::default().theme_transition_ms`).
+const DEFAULT_TRANSITION_MS: u32 = 400;
+
+/// Persisted cross-fade length; unreadable settings fall back to the default.
+/// Blocking SQLite read — keep it off the hot UI path.
+fn stored_transition_ms() -> u32 {
+    qbz_app::settings::graphics::GraphicsSettingsStore::new()
+        .and_then(|store| store.get_settings())
+        .map(|settings| settings.theme_transition_ms)
+        .unwrap_or(DEFAULT_TRANSITION_MS)
+}
+
+/// Milliseconds for a "Color transition" dropdown index (out of range →
+/// default).
+pub fn transition_ms_for_index(index: i32) -> u32 {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| TRANSITION_OPTIONS_MS.get(i).copied())
+        .unwrap_or(DEFAULT_TRANSITION_MS)
+}
+
+/// Dropdown index closest to a stored duration (hand-edited values snap to
+/// the nearest option).
+fn transition_index_for_ms(ms: u32) -> i32 {
+    TRANSITION_OPTIONS_MS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, option)| option.abs_diff(ms))
+        .map(|(i, _)| i as i32)
+        .unwrap_or(2)
+}
+
+/// One-line warning for the Settings row, or empty when every checked pair
+/// clears AA.
+fn contrast_warning(colors: &qbz_theme::ThemeColors) -> String {
+    let report = qbz_theme::auto::check_accessibility(colors);
+    if report.passes_aa() {
+        return String::new();
+    }
+    let pairs: Vec<String> = report
+        .variables_failing_aa
+        .iter()
+        .map(|(pair, ratio)| format!("{pair} ({ratio:.1}:1)"))
+        .collect();
+    qbz_i18n::t_args(
+        "Some text may be hard to read with these colors: {}",
+        &[&pairs.join(", ")],
+    )
+}
+
 /// Build an [`AutoSource`] from the persisted preferences.
 fn source_from_prefs(prefs: &crate::ui_prefs::UiPrefs) -> AutoSource {
     match prefs.auto_theme_source.as_str() {
@@ -45,6 +107,19 @@ pub fn seed_state(window: &AppWindow) {
     state.set_auto_theme_custom_path(prefs.auto_theme_image_path.clone().into());
     state.set_auto_theme_detected_de(detected_de().into());
     state.set_auto_theme_generating(false);
+    state.set_auto_theme_transition_index(transition_index_for_ms(stored_transition_ms()));
+}
+
+/// Persist the cross-fade length (0 disables it). The next regenerated palette
+/// picks it up.
+pub fn set_transition_duration(ms: u32, handle: tokio::runtime::Handle) {
+    handle.spawn_blocking(move || {
+        let result = qbz_app::settings::graphics::GraphicsSettingsStore::new()
+            .and_then(|store| store.set_theme_transition_ms(ms));
+        if let Err(e) = result {
+            log::warn!("[qbz-slint] failed to persist theme transition: {e}");
+        }
+    });
 }
 
 /// Synchronous startup apply: generate from the persisted source and push the
@@ -57,6 +132,9 @@ pub fn apply_startup(window: &AppWindow) {
     match qbz_theme::generate_auto_theme(&source) {
         Ok(colors) => {
             crate::theme::push_colors(window, &colors, false, false);
+            window
+                .global::<AppearanceState>()
+                .set_auto_theme_contrast_warning(contrast_warning(&colors).into());
             log::info!(
                 "[qbz-slint] applied auto theme (source={})",
                 prefs.auto_theme_source
@@ -72,8 +150,9 @@ pub fn apply_startup(window: &AppWindow) {
     }
 }
 
-/// Regenerate the auto theme off-thread and push the result on the event loop.
-/// Toggles `auto-theme-generating` around the work and toasts on failure.
+/// Regenerate the auto theme off-thread and cross-fade the result in on the
+/// event loop. Toggles `auto-theme-generating` around the work and toasts on
+/// failure.
 pub fn regenerate(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle) {
     // Through the event loop, NOT a direct upgrade: regenerate() is also
     // called from tokio contexts (select_image), where a plain upgrade()
@@ -85,15 +164,21 @@ pub fn regenerate(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle)
     handle.spawn(async move {
         let prefs = crate::ui_prefs::load();
         let source = source_from_prefs(&prefs);
-        let result = tokio::task::spawn_blocking(move || qbz_theme::generate_auto_theme(&source))
-            .await
-            .unwrap_or_else(|e| Err(format!("auto theme task panicked: {e}")));
+        let result = tokio::task::spawn_blocking(move || {
+            qbz_theme::generate_auto_theme(&source).map(|colors| (colors, stored_transition_ms()))
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("auto theme task panicked: {e}")));
 
         let _ = weak.upgrade_in_event_loop(move |w| {
             w.global::<AppearanceState>()
                 .set_auto_theme_generating(false);
             match result {
-                Ok(colors) => crate::theme::push_colors(&w, &colors, false, false),
+                Ok((colors, transition_ms)) => {
+                    w.global::<AppearanceState>()
+                        .set_auto_theme_contrast_warning(contrast_warning(&colors).into());
+                    crate::theme::transition_colors(&w, &colors, false, false, transition_ms);
+                }
                 Err(e) => {
                     log::warn!("[qbz-slint] auto theme regeneration failed: {e}");
                     crate::toast::error(&w, qbz_i18n::t("Auto theme generation failed"));
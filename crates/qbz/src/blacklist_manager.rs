@@ -218,6 +218,9 @@ pub fn toggle_enabled(w: &AppWindow) {
                 qbz_i18n::t("Blacklist disabled")
             };
             crate::toast::info(w, msg);
+            if new_state {
+                crate::playback::apply_blacklist_to_queue();
+            }
         }
         Err(e) => {
             log::error!("[qbz-slint] blacklist toggle-enabled failed: {e}");
@@ -285,6 +288,7 @@ pub fn block_album(w: &AppWindow, id: String, title: String, artist: String, cov
             }
             push(w);
             crate::toast::success(w, qbz_i18n::t_args("Album \"{}\" blocked", &[&title]));
+            crate::playback::apply_blacklist_to_queue();
         }
         Err(e) => {
             log::error!("[qbz-slint] album block failed: {e}");
@@ -336,7 +340,8 @@ pub fn clear_all_albums(w: &AppWindow) {
 
 /// Undo one "Not interested" dismissal (optimistic — the re-push drops the row
 /// immediately) + toast. The artist becomes eligible for the Recommendations
-/// rails again on their next paint (the §B filter reads the store).
+/// rails again on their next paint (the §B filter reads the store), and the
+/// reco dislike logged with the dismissal is withdrawn.
 pub fn remove_dismissed(w: &AppWindow, artist_id: i32) {
     // Capture the name before removing, for the toast (falls back to the
     // generic "Artist" for rows persisted without a resolved name).
@@ -347,6 +352,13 @@ pub fn remove_dismissed(w: &AppWindow, artist_id: i32) {
         .filter(|n| !n.is_empty())
         .unwrap_or_else(|| qbz_i18n::t("Artist"));
     crate::reco_dismiss::remove(artist_id as u64);
+    let item_id = artist_id.to_string();
+    tokio::task::spawn_blocking(move || {
+        crate::reco::clear_dislike(
+            qbz_app::settings::reco_store::RecoItemType::Artist,
+            &item_id,
+        );
+    });
     push(w);
     crate::toast::success(w, qbz_i18n::t_args("{} restored to Recommendations", &[&name]));
 }
@@ -0,0 +1,104 @@
+//! Per-track position bookmarks — the now-playing bar glue.
+//!
+//! Bookmarks persist in the per-user session store (`session.db`, through
+//! [`crate::session_persist`]), so they survive restarts and are independent
+//! of the `persist_session` toggle. This module adds one at the live position
+//! (the `playback.bookmark` shortcut), removes one (right-click on its mark),
+//! and pushes the current track's marks to the seek bar as 0..1 fractions.
+//! Marks are keyed by the track's queue source as well as its id, since a
+//! local library row id can equal a Qobuz track id.
+
+use std::sync::{Arc, Mutex};
+
+use qbz_app::shell::AppRuntime;
+use slint::{ComponentHandle, ModelRc, VecModel};
+
+use crate::adapter::SlintAdapter;
+use crate::{AppWindow, NowPlayingState};
+
+type Runtime = Arc<AppRuntime<SlintAdapter>>;
+
+/// Marks currently on the seek bar: `(source, track_id, duration_secs,
+/// bookmark ids in bar order)`, so `remove-bookmark(index)` maps back to a
+/// row and a new bookmark knows the playing track's source.
+static SHOWN: Mutex<(String, u64, u64, Vec<i64>)> = Mutex::new((String::new(), 0, 0, Vec::new()));
+
+/// Bookmark key for a queue source. Offline-cache copies play under their
+/// Qobuz catalog id, so they share the streamed track's bookmarks.
+fn bookmark_source(source: &str) -> &str {
+    match source {
+        "qobuz_download" => "qobuz",
+        other => other,
+    }
+}
+
+/// Push the bookmarks of `track_id` from queue `source` to the seek bar.
+/// `track_id == 0` (or an unknown duration) clears the marks.
+pub fn push(weak: &slint::Weak<AppWindow>, source: &str, track_id: u64, duration_secs: u64) {
+    let source = bookmark_source(source);
+    let bookmarks = if track_id != 0 && duration_secs > 0 {
+        crate::session_persist::bookmarks(source, track_id)
+    } else {
+        Vec::new()
+    };
+    let duration_ms = (duration_secs * 1000) as f32;
+    let marks: Vec<f32> = bookmarks
+        .iter()
+        .map(|b| (b.position_ms as f32 / duration_ms).clamp(0.0, 1.0))
+        .collect();
+    if let Ok(mut shown) = SHOWN.lock() {
+        *shown = (
+            source.to_string(),
+            track_id,
+            duration_secs,
+            bookmarks.iter().map(|b| b.id).collect(),
+        );
+    }
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        w.global::<NowPlayingState>()
+            .set_bookmarks(ModelRc::new(VecModel::from(marks)));
+    });
+}
+
+/// Bookmark the live position of the playing track.
+pub fn add_at_position(runtime: &Runtime, weak: &slint::Weak<AppWindow>) {
+    let state = runtime.core().get_playback_state();
+    let track_id = state.track_id;
+    if track_id == 0 || state.duration == 0 {
+        return;
+    }
+    // The marks pushed on this track's change carry its source.
+    let source = match SHOWN.lock() {
+        Ok(shown) if shown.1 == track_id => shown.0.clone(),
+        _ => return,
+    };
+    let position_ms = state.position * 1000;
+    match crate::session_persist::set_bookmark(&source, track_id, position_ms, None) {
+        Ok(()) => {
+            log::info!("[qbz-slint] bookmark: {source} track {track_id} at {position_ms}ms");
+            push(weak, &source, track_id, state.duration);
+            crate::toast::show_weak(
+                weak,
+                qbz_i18n::t("Position bookmarked"),
+                crate::ToastKind::Info,
+            );
+        }
+        Err(e) => log::warn!("[qbz-slint] bookmark: save failed: {e}"),
+    }
+}
+
+/// Remove the bookmark behind the seek bar's `index`-th mark.
+pub fn remove(weak: &slint::Weak<AppWindow>, index: usize) {
+    let (source, track_id, duration_secs, id) = match SHOWN.lock() {
+        Ok(shown) => match shown.3.get(index) {
+            Some(id) => (shown.0.clone(), shown.1, shown.2, *id),
+            None => return,
+        },
+        Err(_) => return,
+    };
+    if let Err(e) = crate::session_persist::delete_bookmark(&source, track_id, id) {
+        log::warn!("[qbz-slint] bookmark: delete failed: {e}");
+        return;
+    }
+    push(weak, &source, track_id, duration_secs);
+}
@@ -86,6 +86,66 @@ impl CastProtocol {
     }
 }
 
+/// One renderer the fallback chain can (re)connect to. Carries the whole
+/// discovery record: the picker stops discovery when it closes, so a
+/// mid-session fallback cannot look the device up again.
+#[derive(Clone, Debug)]
+enum CastDeviceRef {
+    Chromecast(DiscoveredDevice),
+    Dlna(DiscoveredDlnaDevice),
+}
+
+impl CastDeviceRef {
+    fn id(&self) -> &str {
+        match self {
+            CastDeviceRef::Chromecast(d) => &d.id,
+            CastDeviceRef::Dlna(d) => &d.id,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            CastDeviceRef::Chromecast(d) => &d.name,
+            CastDeviceRef::Dlna(d) => &d.name,
+        }
+    }
+}
+
+/// Renderers to walk when a track cannot be cast (opt-in via
+/// `UiPrefs::cast_fallback`). Snapshotted from discovery at connect time:
+/// Chromecast before DLNA, the connected device first, DLNA devices without
+/// an AVTransport service left out (they cannot play).
+#[derive(Clone, Debug, Default)]
+struct CastFallbackChain {
+    devices: Vec<CastDeviceRef>,
+}
+
+impl CastFallbackChain {
+    fn new(
+        connected_id: &str,
+        chromecast: Vec<DiscoveredDevice>,
+        dlna: Vec<DiscoveredDlnaDevice>,
+    ) -> Self {
+        let mut devices: Vec<CastDeviceRef> = chromecast
+            .into_iter()
+            .map(CastDeviceRef::Chromecast)
+            .chain(
+                dlna.into_iter()
+                    .filter(|d| d.has_av_transport)
+                    .map(CastDeviceRef::Dlna),
+            )
+            .collect();
+        // Stable: only lifts the connected device, protocol order is kept.
+        devices.sort_by_key(|d| d.id() != connected_id);
+        Self { devices }
+    }
+
+    /// The devices to fall back to once `failed_id` has given up, in order.
+    fn after<'a>(&'a self, failed_id: &'a str) -> impl Iterator<Item = &'a CastDeviceRef> {
+        self.devices.iter().filter(move |d| d.id() != failed_id)
+    }
+}
+
 /// What `register_*` learned about the asset it just registered (#638 fix 1):
 /// the MIME for the renderer, the measured STREAMINFO probe of the bytes
 /// actually served (None = non-FLAC / local file), where those bytes came
@@ -138,6 +198,9 @@ struct CastInner {
     // the picker hides the cap row for that device.
     connected_device_id: Option<String>,
     connected_cap_key: Option<String>,
+    // Renderers to fall back to when a track cannot be cast (snapshotted
+    // at connect time, while the picker's discovery is still running).
+    fallback_chain: CastFallbackChain,
     // ONE shared lazy media server for both protocols.
     media_server: Option<MediaServer>,
     // Playback mirror.
@@ -303,6 +366,17 @@ impl SlintCastService {
             let mut inner = self.inner.lock().await;
             inner.protocol = Some(proto);
             inner.connected_device_ip = Some(device_ip);
+            let chromecast = inner
+                .chromecast_discovery
+                .as_ref()
+                .map(|d| d.get_discovered_devices())
+                .unwrap_or_default();
+            let dlna = inner
+                .dlna_discovery
+                .as_ref()
+                .map(|d| d.get_discovered_devices())
+                .unwrap_or_default();
+            inner.fallback_chain = CastFallbackChain::new(&device_id, chromecast, dlna);
             inner.track_end_detected = false;
             inner.cast_saw_playing = false;
             inner.cast_max_position = 0.0;
@@ -322,9 +396,11 @@ impl SlintCastService {
 
         // Re-cast the current track at its position, passing the REAL source
         // (fixes the Tauri resume-source bug where Plex re-cast as Qobuz).
+        // Same failure policy as any other cast: network retry, then the
+        // fallback chain when enabled.
         if was_playing {
             if let Some(track) = snapshot_track {
-                if let Err(e) = self.cast_track(&track).await {
+                if let Err(e) = self.cast_track_with_fallback(&track).await {
                     log::warn!("[Cast] resume re-cast failed: {e}");
                 } else if resume_pos > 5 {
                     // Deferred seek to the prior position (renderer needs the
@@ -350,6 +426,10 @@ impl SlintCastService {
                 .and_then(|d| d.get_device(device_id))
                 .ok_or_else(|| format!("Chromecast device not found: {device_id}"))?
         };
+        self.connect_chromecast_device(device).await
+    }
+
+    async fn connect_chromecast_device(&self, device: DiscoveredDevice) -> Result<String, String> {
         let handle = ChromecastHandle::new();
         handle
             .connect(device.ip.clone(), device.port)
@@ -384,6 +464,10 @@ impl SlintCastService {
                 .and_then(|d| d.get_device(device_id))
                 .ok_or_else(|| format!("DLNA device not found: {device_id}"))?
         };
+        self.connect_dlna_device(device).await
+    }
+
+    async fn connect_dlna_device(&self, device: DiscoveredDlnaDevice) -> Result<String, String> {
         let ip = device.ip.clone();
         let name = device.name.clone();
         let udn = device.id.clone();
@@ -419,6 +503,7 @@ impl SlintCastService {
             inner.connected_device_name = None;
             inner.connected_device_id = None;
             inner.connected_cap_key = None;
+            inner.fallback_chain = CastFallbackChain::default();
             // Release the served track buffers with the session (#550); the
             // server itself stays up for the next connect.
             if let Some(server) = inner.media_server.as_ref() {
@@ -578,6 +663,108 @@ impl SlintCastService {
         Ok(())
     }
 
+    /// Cast `track`, honoring the failure policy. A network-class error
+    /// (connection, I/O, timeout) is retried once on the same renderer
+    /// before it counts as that renderer failing. With `cast_fallback` off
+    /// that is the end of it (fail fast); with it on, the session moves to
+    /// each remaining renderer of the fallback chain in order until one
+    /// plays the track. When the whole chain is exhausted the picker shows
+    /// the all-failed error and the session stays on the last renderer
+    /// tried.
+    pub async fn cast_track_with_fallback(
+        self: &Arc<Self>,
+        track: &QueueTrack,
+    ) -> Result<(), String> {
+        let first_err = match self.cast_track_retrying(track).await {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        if !crate::ui_prefs::load().cast_fallback {
+            return Err(first_err);
+        }
+        let (chain, failed_id) = {
+            let inner = self.inner.lock().await;
+            (
+                inner.fallback_chain.clone(),
+                inner.connected_device_id.clone().unwrap_or_default(),
+            )
+        };
+        for device in chain.after(&failed_id) {
+            log::info!("[Cast] falling back to {} ({})", device.name(), device.id());
+            if let Err(e) = self.switch_device(device).await {
+                log::warn!("[Cast] fallback connect to {} failed: {e}", device.name());
+                continue;
+            }
+            match self.cast_track_retrying(track).await {
+                Ok(()) => return Ok(()),
+                Err(e) => log::warn!("[Cast] fallback cast on {} failed: {e}", device.name()),
+            }
+        }
+        log::warn!(
+            "[Cast] every renderer in the fallback chain failed for track {}",
+            track.id
+        );
+        let weak = self.window.clone();
+        let _ = weak.upgrade_in_event_loop(|w| {
+            use slint::ComponentHandle;
+            w.global::<CastState>()
+                .set_error(qbz_i18n::t("Casting failed on every available device").into());
+        });
+        Err(first_err)
+    }
+
+    /// `cast_track` with one retry when the failure looks like the network
+    /// (a sleeping device, a Wi-Fi hiccup) rather than the renderer
+    /// rejecting the stream.
+    async fn cast_track_retrying(self: &Arc<Self>, track: &QueueTrack) -> Result<(), String> {
+        match self.cast_track(track).await {
+            Err(e) if is_network_error(&e) => {
+                log::info!(
+                    "[Cast] cast of track {} hit a network error ({e}); retrying once",
+                    track.id
+                );
+                self.cast_track(track).await
+            }
+            result => result,
+        }
+    }
+
+    /// Move the live session to `device` for a fallback: drop the current
+    /// renderer connection (QConnect stays suspended, the media server and
+    /// the fallback chain are kept) and connect the next one in its place.
+    async fn switch_device(self: &Arc<Self>, device: &CastDeviceRef) -> Result<(), String> {
+        let _ = self.stop_renderer().await;
+        {
+            let mut inner = self.inner.lock().await;
+            if let Some(h) = inner.chromecast.take() {
+                let _ = h.disconnect();
+            }
+            if let Some(mut c) = inner.dlna.take() {
+                let _ = c.disconnect();
+            }
+            inner.connected_cap_key = None;
+            inner.connected_device_id = None;
+        }
+        let (proto, device_ip) = match device {
+            CastDeviceRef::Chromecast(d) => (
+                CastProtocol::Chromecast,
+                self.connect_chromecast_device(d.clone()).await?,
+            ),
+            CastDeviceRef::Dlna(d) => (
+                CastProtocol::Dlna,
+                self.connect_dlna_device(d.clone()).await?,
+            ),
+        };
+        {
+            let mut inner = self.inner.lock().await;
+            inner.protocol = Some(proto);
+            inner.connected_device_ip = Some(device_ip);
+        }
+        self.push_connection_state().await;
+        self.push_device_cap_row().await;
+        Ok(())
+    }
+
     /// qobuz: resolve via the shared core API (cache -> offline -> network),
     /// probe the served bytes' STREAMINFO, register them. Returns the asset
     /// info for the picker/badge publish.
@@ -982,8 +1169,8 @@ impl SlintCastService {
             }
             CastProtocol::Dlna => {
                 let info: Option<DlnaPositionInfo> = {
-                    let inner = self.inner.lock().await;
-                    match inner.dlna.as_ref() {
+                    let mut inner = self.inner.lock().await;
+                    match inner.dlna.as_mut() {
                         Some(c) => c.get_position_info().await.ok(),
                         None => None,
                     }
@@ -1190,6 +1377,7 @@ impl SlintCastService {
         inner.connected_device_name = None;
         inner.connected_device_id = None;
         inner.connected_cap_key = None;
+        inner.fallback_chain = CastFallbackChain::default();
         inner.current_track_id = None;
         inner.is_playing = false;
     }
@@ -1453,6 +1641,13 @@ impl SlintCastService {
 
 // ---- Free helpers -----------------------------------------------------------
 
+/// Whether a stringified `CastError` / `DlnaError` is worth one retry on the
+/// same renderer: connection and I/O failures and timeouts are transient,
+/// a renderer rejecting the media is not.
+fn is_network_error(err: &str) -> bool {
+    err.starts_with("Connection error") || err.starts_with("IO error") || err.contains("timed out")
+}
+
 /// Dropdown index for the stored cap of `cap_key` (0 follow · 1 hires ·
 /// 2 cd · 3 mp3). Unknown stored tiers read as "follow" so a hand-edited
 /// value degrades to the no-cap default instead of showing a wrong cap.
@@ -1604,3 +1799,62 @@ fn trim_khz(khz: f64) -> String {
         format!("{:.1}", khz)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chromecast(id: &str) -> DiscoveredDevice {
+        DiscoveredDevice {
+            id: id.to_string(),
+            name: id.to_string(),
+            model: String::new(),
+            ip: "192.168.1.10".to_string(),
+            port: 8009,
+            id_is_stable: true,
+        }
+    }
+
+    fn dlna(id: &str, has_av_transport: bool) -> DiscoveredDlnaDevice {
+        DiscoveredDlnaDevice {
+            id: id.to_string(),
+            name: id.to_string(),
+            manufacturer: String::new(),
+            model: String::new(),
+            ip: "192.168.1.20".to_string(),
+            url: String::new(),
+            has_av_transport,
+            has_rendering_control: true,
+        }
+    }
+
+    #[test]
+    fn fallback_chain_orders_connected_then_chromecast_then_dlna() {
+        let chain = CastFallbackChain::new(
+            "dlna-b",
+            vec![chromecast("cc-a"), chromecast("cc-b")],
+            vec![
+                dlna("dlna-a", true),
+                dlna("dlna-b", true),
+                dlna("no-transport", false),
+            ],
+        );
+        let ids: Vec<&str> = chain.devices.iter().map(|d| d.id()).collect();
+        assert_eq!(ids, ["dlna-b", "cc-a", "cc-b", "dlna-a"]);
+        let next: Vec<&str> = chain.after("dlna-b").map(|d| d.id()).collect();
+        assert_eq!(next, ["cc-a", "cc-b", "dlna-a"]);
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        assert!(is_network_error(
+            "Connection error: Thread communication error"
+        ));
+        assert!(is_network_error("IO error: connection reset by peer"));
+        assert!(is_network_error("Playback error: Play action timed out"));
+        assert!(!is_network_error(
+            "Playback error: Device has no AVTransport service"
+        ));
+        assert!(!is_network_error("Media error: unsupported content type"));
+    }
+}
@@ -6,8 +6,8 @@
 //! (`qbz_app::diagnostics`), snapshots the core player for the Playback rows, and
 //! reads the LIVE Qobuz Connect session for the QConnect rows — then pushes all
 //! seven per-section `[DiagRow]` models in one event-loop hop. Cast is the only
-//! on-demand section: `cast-scan()` reuses the existing `CastService` discovery
-//! and reads the populated `CastState`. Export serializes the cached snapshot
+//! on-demand section: `cast-scan()` runs a one-shot `qbz_cast::discover_all`
+//! of both protocols. Export serializes the cached snapshot
 //! (camelCase, matching the Tauri DiagnosticsPanel export) to the clipboard.
 //!
 //! 1:1 port of `src/lib/components/DiagnosticsPanel.svelte` (the row builders),
@@ -16,10 +16,10 @@
 use std::sync::{Arc, Mutex};
 
 use serde_json::{json, Value};
-use slint::{ComponentHandle, Model, ModelRc, VecModel};
+use slint::{ComponentHandle, ModelRc, VecModel};
 
 use crate::adapter::SlintAdapter;
-use crate::{AppWindow, CastState, DiagRow, DiagnosticsState};
+use crate::{AppWindow, DiagRow, DiagnosticsState};
 
 type Runtime = Arc<qbz_app::shell::AppRuntime<SlintAdapter>>;
 
@@ -125,6 +125,7 @@ impl DiagController {
         // (b) async core snapshot for the Playback section.
         let pb = self.runtime.core().get_playback_state();
         let track = self.runtime.core().current_track().await;
+        let chain = self.runtime.core().player().format_chain();
 
         // (c) LIVE QConnect snapshot (no discovery; default when not running).
         let qc = match crate::qconnect_service::service() {
@@ -132,10 +133,12 @@ impl DiagController {
             None => Default::default(),
         };
 
-        // (d) build the seven row vectors (1:1 with the Tauri row builders).
+        // (d) build the row vectors (1:1 with the Tauri row builders, plus the
+        //     format chain).
         let system_rows = build_system_rows(&sys);
         let playback_rows = build_playback_rows(&pb, track.as_ref());
         let qconnect_rows = build_qconnect_rows(&qc);
+        let format_chain_rows = build_format_chain_rows(&chain);
         let audio_rows = build_audio_rows(
             &runtime_diag,
             active_output.as_deref(),
@@ -168,19 +171,24 @@ impl DiagController {
         );
         map.insert("playback".to_string(), playback_json);
         map.insert("qconnect".to_string(), qconnect_json);
+        map.insert(
+            "formatChain".to_string(),
+            serde_json::to_value(&chain).unwrap_or(Value::Null),
+        );
         if let Ok(mut g) = self.export.lock() {
             *g = Some(Value::Object(map));
         }
 
         let app_version = runtime_diag.app_version.clone();
 
-        // (f) one event-loop hop: push all seven models + version + flags.
+        // (f) one event-loop hop: push all the models + version + flags.
         let weak = self.weak.clone();
         let _ = weak.upgrade_in_event_loop(move |w| {
             let d = w.global::<DiagnosticsState>();
             d.set_system_rows(ModelRc::new(VecModel::from(system_rows)));
             d.set_playback_rows(ModelRc::new(VecModel::from(playback_rows)));
             d.set_qconnect_rows(ModelRc::new(VecModel::from(qconnect_rows)));
+            d.set_format_chain_rows(ModelRc::new(VecModel::from(format_chain_rows)));
             d.set_audio_rows(ModelRc::new(VecModel::from(audio_rows)));
             d.set_graphics_rows(ModelRc::new(VecModel::from(graphics_rows)));
             d.set_env_rows(ModelRc::new(VecModel::from(env_rows)));
@@ -221,9 +229,9 @@ impl DiagController {
         });
     }
 
-    /// On-demand Cast discovery scan: reuse the existing `CastService`, wait 10s,
-    /// then read the populated `CastState` to build the cast rows. Guarded against
-    /// re-entrancy; stops discovery afterwards only if the picker isn't using it.
+    /// On-demand Cast discovery scan: a one-shot `qbz_cast::discover_all`
+    /// (Chromecast + DLNA side by side for `DEFAULT_SCAN_SECS`), independent
+    /// of the picker's discovery. Guarded against re-entrancy.
     fn cast_scan(&self) {
         if let Some(w) = self.weak.upgrade() {
             let st = w.global::<DiagnosticsState>();
@@ -235,60 +243,18 @@ impl DiagController {
 
         let this = self.clone();
         self.handle.spawn(async move {
-            let Some(svc) = crate::cast_service::service() else {
-                let _ = this.weak.upgrade_in_event_loop(|w| {
-                    w.global::<DiagnosticsState>().set_cast_scanning(false);
-                });
-                return;
-            };
-
-            svc.start_discovery().await;
-            tokio::time::sleep(std::time::Duration::from_secs(10)).await;
-            svc.refresh_devices().await;
-
-            // Read the live CastState on the UI thread, build the rows, and report
-            // the picker-open flag back so we can gate the discovery stop.
-            let (tx, rx) = tokio::sync::oneshot::channel::<bool>();
-            let last_cast = this.last_cast.clone();
+            let snapshot =
+                qbz_cast::discover_all(std::time::Duration::from_secs(qbz_cast::DEFAULT_SCAN_SECS))
+                    .await;
+            let rows = build_cast_rows(&snapshot);
+            if let Ok(mut g) = this.last_cast.lock() {
+                *g = Some(build_cast_json(&snapshot));
+            }
             let _ = this.weak.upgrade_in_event_loop(move |w| {
-                let cs = w.global::<CastState>();
-                let cc = cs.get_chromecast_count();
-                let dl = cs.get_dlna_count();
-                let devices = cs.get_devices();
-
-                let mut rows: Vec<DiagRow> = Vec::new();
-                rows.push(row("Chromecast devices", "—", &cc.to_string(), 0));
-                rows.push(row("DLNA devices", "—", &dl.to_string(), 0));
-                let mut device_json: Vec<Value> = Vec::new();
-                for i in 0..devices.row_count() {
-                    if let Some(dev) = devices.row_data(i) {
-                        let protocol = dev.protocol.to_string();
-                        let name = dev.name.to_string();
-                        rows.push(row(&format!("• {protocol}"), "—", &name, 0));
-                        device_json.push(json!({ "name": name, "protocol": protocol }));
-                    }
-                }
-
-                if let Ok(mut g) = last_cast.lock() {
-                    *g = Some(json!({
-                        "chromecastCount": cc,
-                        "dlnaCount": dl,
-                        "devices": device_json,
-                    }));
-                }
-
                 let d = w.global::<DiagnosticsState>();
                 d.set_cast_rows(ModelRc::new(VecModel::from(rows)));
                 d.set_cast_scanning(false);
-
-                let _ = tx.send(cs.get_picker_open());
             });
-
-            // Stop discovery only when the picker isn't relying on it (otherwise
-            // we'd kill the picker's live device list).
-            if let Ok(false) = rx.await {
-                svc.stop_discovery().await;
-            }
         });
     }
 }
@@ -1085,6 +1051,72 @@ fn build_playback_rows(
     ]
 }
 
+fn build_cast_rows(scan: &qbz_cast::AllDevicesSnapshot) -> Vec<DiagRow> {
+    let mut rows = vec![
+        row(
+            "Chromecast devices",
+            "—",
+            &scan.chromecast.len().to_string(),
+            0,
+        ),
+        row("DLNA devices", "—", &scan.dlna.len().to_string(), 0),
+    ];
+    for device in &scan.chromecast {
+        rows.push(row("• chromecast", "—", &device.name, 0));
+    }
+    for device in &scan.dlna {
+        rows.push(row("• dlna", "—", &device.name, 0));
+    }
+    rows
+}
+
+/// Export shape of a Cast scan. Device ids and addresses are left out.
+fn build_cast_json(scan: &qbz_cast::AllDevicesSnapshot) -> Value {
+    let devices: Vec<Value> = scan
+        .chromecast
+        .iter()
+        .map(|d| json!({ "name": d.name, "protocol": "chromecast" }))
+        .chain(
+            scan.dlna
+                .iter()
+                .map(|d| json!({ "name": d.name, "protocol": "dlna" })),
+        )
+        .collect();
+    json!({
+        "chromecastCount": scan.chromecast.len(),
+        "dlnaCount": scan.dlna.len(),
+        "devices": devices,
+    })
+}
+
+/// One row per processing stage (input → output), then a bit-perfect
+/// verdict. Empty when nothing is loaded.
+fn build_format_chain_rows(chain: &qbz_player::FormatChain) -> Vec<DiagRow> {
+    if chain.steps.is_empty() {
+        return Vec::new();
+    }
+    let mut rows: Vec<DiagRow> = chain
+        .steps
+        .iter()
+        .map(|s| {
+            let output = if s.active {
+                s.output_format.clone()
+            } else {
+                format!("{} (bypassed)", s.output_format)
+            };
+            row(&s.name, &s.input_format, &output, 0)
+        })
+        .collect();
+    let bit_perfect = chain.is_bit_perfect();
+    rows.push(row(
+        "Bit-perfect",
+        "—",
+        yn(bit_perfect),
+        if bit_perfect { 1 } else { 2 },
+    ));
+    rows
+}
+
 fn build_qconnect_rows(q: &crate::qconnect_service::QconnectDiagSnapshot) -> Vec<DiagRow> {
     let role = if q.role.is_empty() { "none" } else { q.role };
     let last_error = q
@@ -4,13 +4,14 @@
 //! `qbz-integrations`) and wires it to:
 //!   - the opt-in toggle (Settings › Integrations), persisted in `ui_prefs`;
 //!   - playback track-change + play/pause transitions, which push the
-//!     "now listening" activity;
+//!     "now listening" activity, and the queue running out, which clears it;
 //!   - the shell session lifecycle: [`init`] runs AFTER the session is active
 //!     (the PR #477 fix — never at early boot), [`clear`] on logout / exit.
 //!
 //! The IPC is blocking, so every Discord call runs inside `spawn_blocking`.
 //! All calls are no-ops when the user has not opted in.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock};
 
 use qbz_app::shell::AppRuntime;
@@ -24,6 +25,11 @@ type Runtime = Arc<AppRuntime<SlintAdapter>>;
 /// `set_enabled(false)` tears the connection down so the presence disappears.
 static DISCORD: LazyLock<DiscordRpc> = LazyLock::new(DiscordRpc::default);
 
+/// Bumped by [`stopped`]. A [`push`] spawned before the stop sees a newer
+/// generation and drops its (now stale) "Paused" activity instead of
+/// re-creating the presence the stop just cleared.
+static STOP_GEN: AtomicU64 = AtomicU64::new(0);
+
 /// Apply the persisted opt-in. Called from `init_shell_for_user` — i.e. AFTER
 /// the session is active, for BOTH the online and offline entry paths. This is
 /// the persistence fix from PR #477: initializing here (not at early boot,
@@ -56,6 +62,17 @@ pub fn clear(handle: &tokio::runtime::Handle) {
     });
 }
 
+/// Playback stopped for good (the queue ran out): drop the activity rather
+/// than leave a "Paused at 00:00" presence behind. The next track change or
+/// play edge pushes a fresh one.
+pub fn stopped(handle: &tokio::runtime::Handle) {
+    if !DISCORD.is_enabled() {
+        return;
+    }
+    STOP_GEN.fetch_add(1, Ordering::SeqCst);
+    clear(handle);
+}
+
 /// Build the "now listening" snapshot from the live queue + playback state and
 /// push it to Discord. No-op when disabled (cheap early return — nothing is
 /// fetched). Called on track change and play/pause, mirroring the Tauri
@@ -65,6 +82,7 @@ pub fn push(runtime: &Runtime, handle: &tokio::runtime::Handle) {
         return;
     }
     let runtime = runtime.clone();
+    let generation = STOP_GEN.load(Ordering::SeqCst);
     handle.spawn(async move {
         let state = runtime.core().get_queue_state().await;
         let Some(track) = state.current_track else {
@@ -92,6 +110,9 @@ pub fn push(runtime: &Runtime, handle: &tokio::runtime::Handle) {
             duration: track.duration_secs as f64,
             cover_url,
         };
+        if STOP_GEN.load(Ordering::SeqCst) != generation {
+            return;
+        }
         let _ = tokio::task::spawn_blocking(move || DISCORD.update(&meta)).await;
     });
 }
@@ -0,0 +1,71 @@
+//! Split single-file FLAC images on their embedded CUE sheet.
+//!
+//! Some rippers store the CUE sheet inside the FLAC (a CUESHEET comment or
+//! the native CUESHEET metadata block) instead of next to it, so the scan
+//! indexes the whole image as one track. The local album header can expand
+//! it: `qbz_library::expand_embedded_cue` replaces the row with one virtual
+//! track per CUE entry, exactly like a sidecar `.cue` would have produced,
+//! and later rescans leave them alone.
+
+use slint::{ComponentHandle, Weak};
+
+use crate::AppWindow;
+
+/// Album-header action: expand every whole-file track of the open album
+/// that carries an embedded CUE sheet, then refresh the album and the
+/// library browse models.
+pub fn expand_album(
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    image_cache: crate::artwork::ImageCache,
+    track_ids: Vec<i64>,
+) {
+    if track_ids.is_empty() {
+        return;
+    }
+    let handle2 = handle.clone();
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            crate::library_db::with_db(|db| {
+                let mut added = 0;
+                for id in &track_ids {
+                    added += qbz_library::expand_embedded_cue(db, *id)?;
+                }
+                Ok(added)
+            })
+        })
+        .await
+        .ok()
+        .flatten();
+        let added = match result {
+            Some(added) => added,
+            None => {
+                crate::toast::error_weak(
+                    &weak,
+                    qbz_i18n::t("Could not read the embedded CUE sheet"),
+                );
+                return;
+            }
+        };
+        if added == 0 {
+            crate::toast::info_weak(&weak, qbz_i18n::t("No embedded CUE sheet found"));
+            return;
+        }
+        crate::toast::success_weak(
+            &weak,
+            qbz_i18n::tf(
+                "Split into {} track",
+                "Split into {} tracks",
+                added as i64,
+                &[&added.to_string()],
+            ),
+        );
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            let id = w.global::<crate::LocalAlbumState>().get_id().to_string();
+            if !id.is_empty() {
+                crate::local_library::open_local_album(w.as_weak(), handle2, image_cache, id);
+            }
+            crate::local_library::reset_browse_models(&w);
+        });
+    });
+}
@@ -50,6 +50,16 @@ pub fn tracks_snapshot() -> Vec<LocalTrack> {
     STATE.tracks_snapshot()
 }
 
+/// Artwork paths the current session's tracks point at (thumbnail
+/// housekeeping must not treat them as orphans).
+pub fn artwork_paths() -> Vec<String> {
+    STATE
+        .tracks_snapshot()
+        .into_iter()
+        .filter_map(|t| t.artwork_path)
+        .collect()
+}
+
 /// The tracks of one album group (matched on `album_group_key`, with the same
 /// `album|||album_artist` fallback the scanner uses), in scan order.
 pub fn album_tracks(group_key: &str) -> Vec<LocalTrack> {
@@ -0,0 +1,92 @@
+//! `folder.jpg` generation from embedded artwork.
+//!
+//! The local album header writes one for the open album; the Local Library
+//! maintenance row does every indexed folder without one. The work is in
+//! `qbz_library::folder_cover`; this module gathers the paths, runs it off
+//! the UI thread and reports the count.
+
+use std::path::PathBuf;
+
+use slint::{ComponentHandle, Weak};
+
+use crate::{AppWindow, LibraryFoldersState};
+
+/// Album-header action: write `folder.jpg` in the directories of
+/// `track_paths` that have none, from the album's largest embedded picture.
+pub fn write_album(
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    track_paths: Vec<PathBuf>,
+) {
+    if track_paths.is_empty() {
+        return;
+    }
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            qbz_library::folder_cover::write_album_folder_cover(&track_paths)
+        })
+        .await;
+        match result {
+            Ok(Ok(0)) => crate::toast::info_weak(
+                &weak,
+                qbz_i18n::t("No folder.jpg written: it exists or no track has artwork"),
+            ),
+            Ok(Ok(written)) => crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Wrote folder.jpg in {} folder",
+                    "Wrote folder.jpg in {} folders",
+                    written as i64,
+                    &[&written.to_string()],
+                ),
+            ),
+            Ok(Err(e)) => {
+                log::warn!("[qbz-slint] folder cover: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Could not write folder.jpg"));
+            }
+            Err(e) => log::warn!("[qbz-slint] folder cover task failed: {e}"),
+        }
+    });
+}
+
+/// Maintenance action: write `folder.jpg` in every indexed folder that has
+/// none, each from the artwork embedded in its own tracks.
+pub fn write_all(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_writing_folder_covers() {
+            return;
+        }
+        s.set_writing_folder_covers(true);
+    }
+    handle.spawn(async move {
+        let written = tokio::task::spawn_blocking(|| {
+            let paths: Vec<PathBuf> = crate::library_db::with_db(|db| db.get_all_track_paths())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, path)| PathBuf::from(path))
+                .collect();
+            qbz_library::folder_cover::write_missing_folder_covers(&paths)
+        })
+        .await
+        .unwrap_or(0);
+        log::info!("[qbz-slint] folder cover: wrote {written} folder.jpg");
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>()
+                .set_writing_folder_covers(false);
+        });
+        if written == 0 {
+            crate::toast::info_weak(&weak, qbz_i18n::t("No folder needed a folder.jpg"));
+        } else {
+            crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Wrote folder.jpg in {} folder",
+                    "Wrote folder.jpg in {} folders",
+                    written as i64,
+                    &[&written.to_string()],
+                ),
+            );
+        }
+    });
+}
@@ -25,7 +25,8 @@
 //! Backed sections: Release Watch (get_release_watch), Recently Played
 //! Tracks / Albums (local play-history), Your Top Artists (favorites),
 //! Artists to Follow (similar artists seeded from favorites), Rediscover
-//! + Radio (favorite albums), More From Your Library (album/suggest),
+//! + Radio (favorite albums), More From Your Library (reco co-plays +
+//! album/suggest),
 //! Spotlight (a rotated favorite artist's page).
 
 use std::collections::HashSet;
@@ -45,6 +46,8 @@ use crate::{AlbumCardItem, AppWindow, DiscoverSection, ForYouState, SlimItem};
 const ARTIST_SEEDS: usize = 4;
 const SIMILAR_PER_SEED: u32 = 10;
 const FOLLOW_MAX: usize = 18;
+/// Co-played albums leading the More From Your Library rail.
+const CO_PLAYED_MAX: usize = 6;
 
 #[derive(Clone)]
 pub struct RadioSeed {
@@ -196,30 +199,43 @@ where
     if album_id.is_empty() {
         return Vec::new();
     }
-    match runtime.core().get_album_suggest(album_id).await {
-        Ok(resp) => {
-            // T8: similar-albums (flat Album). Filter-then-truncate: drop
-            // blacklisted BEFORE take(18) (Tauri parity — may yield fewer
-            // than the limit, no backfill).
-            let (bl, abl) = if crate::artist_blacklist::is_enabled() {
-                (
-                    crate::artist_blacklist::ids_snapshot(),
-                    crate::artist_blacklist::album_ids_snapshot(),
-                )
-            } else {
-                Default::default()
-            };
-            resp.albums
-                .map(|p| p.items)
-                .unwrap_or_default()
-                .into_iter()
-                .filter(|a| !qbz_core::core::album_blacklisted(a, &bl, &abl))
-                .take(18)
-                .map(map_album)
-                .collect()
-        }
+    let (bl, abl) = if crate::artist_blacklist::is_enabled() {
+        (
+            crate::artist_blacklist::ids_snapshot(),
+            crate::artist_blacklist::album_ids_snapshot(),
+        )
+    } else {
+        Default::default()
+    };
+    // reco: lead with the albums the user plays alongside the seed (own
+    // listening history), resolved concurrently; Qobuz's suggestions fill
+    // the rest of the rail.
+    let co_played_ids = crate::reco::similar_album_ids(album_id, CO_PLAYED_MAX).unwrap_or_default();
+    let co_played: Vec<Album> = join_all(
+        co_played_ids
+            .iter()
+            .map(|id| async move { runtime.core().get_album(id).await.ok() }),
+    )
+    .await
+    .into_iter()
+    .flatten()
+    .collect();
+    let suggested = match runtime.core().get_album_suggest(album_id).await {
+        Ok(resp) => resp.albums.map(|p| p.items).unwrap_or_default(),
         Err(_) => Vec::new(),
-    }
+    };
+    // T8: similar-albums (flat Album). Filter-then-truncate: drop
+    // blacklisted BEFORE take(18) (Tauri parity — may yield fewer than the
+    // limit, no backfill).
+    let mut seen = HashSet::new();
+    co_played
+        .into_iter()
+        .chain(suggested)
+        .filter(|a| !qbz_core::core::album_blacklisted(a, &bl, &abl))
+        .filter(|a| seen.insert(a.id.clone()))
+        .take(18)
+        .map(map_album)
+        .collect()
 }
 
 /// Artists to Follow — similar artists seeded from up to `ARTIST_SEEDS`
@@ -976,13 +992,13 @@ pub fn spawn_for_you<A>(
                 apply_most_played_albums(&weak, &cache, most_played_album_cards());
                 // reco: backfill genres for the resolved favorite albums so the
                 // engine's top-genres has data (plays alone carry no genre).
-                let genre_entries: Vec<(String, u64, String)> = fav_albums
+                let genre_entries: Vec<(String, u64, String, Option<u64>)> = fav_albums
                     .iter()
                     .filter_map(|a| {
-                        a.genre
-                            .as_ref()
-                            .filter(|g| g.id > 0)
-                            .map(|g| (a.id.clone(), g.id, g.name.clone()))
+                        a.genre.as_ref().filter(|g| g.id > 0).map(|g| {
+                            let root = g.path.as_ref().and_then(|p| p.first().copied());
+                            (a.id.clone(), g.id, g.name.clone(), root)
+                        })
                     })
                     .collect();
                 if !genre_entries.is_empty() {
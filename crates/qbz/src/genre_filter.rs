@@ -235,6 +235,14 @@ where
         }
     };
     parents.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    // Genres the user has been playing lately lead; the rest stay
+    // alphabetical (the sort is stable). Subgenre plays already count
+    // toward their top-level genre.
+    if let Some(affinity) = crate::reco::genre_affinity_scores() {
+        let affinity: HashMap<u64, f64> = affinity.into_iter().collect();
+        let score = |g: &GenreItem| affinity.get(&g.id).copied().unwrap_or(0.0);
+        parents.sort_by(|a, b| score(b).total_cmp(&score(a)));
+    }
 
     // Keep persisted selections as-is — they may reference child genres
     // not yet loaded (advanced view), so validating against parents only
@@ -12,7 +12,7 @@ use std::sync::Arc;
 use chrono::NaiveDate;
 use qbz_app::shell::AppRuntime;
 use qbz_core::FrontendAdapter;
-use qbz_models::{Album, Track};
+use qbz_models::{Album, Track, WorkMetadata};
 use qbz_qobuz::performers::{format_role_label, group_credits_ordered, parse_performers};
 use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
 
@@ -85,6 +85,26 @@ pub struct AlbumCreditsData {
 // Formatting helpers (mirror the Tauri modal helpers).
 // ---------------------------------------------------------------------------
 
+/// "Beethoven: Symphony No. 5 in C minor, Op. 67" — the opus (or
+/// catalogue number) is appended only when the work title lacks it.
+fn format_work(work: &WorkMetadata) -> String {
+    let mut line = match work.composer.as_deref() {
+        Some(composer) => format!("{composer}: {}", work.title),
+        None => work.title.clone(),
+    };
+    let title = work.title.to_lowercase();
+    if let Some(number) = work
+        .opus
+        .as_deref()
+        .or(work.catalogue_number.as_deref())
+        .filter(|n| !title.contains(&n.to_lowercase()))
+    {
+        line.push_str(", ");
+        line.push_str(number);
+    }
+    line
+}
+
 /// "Title (Version)" when a non-empty version exists, else "Title".
 fn format_title(title: &str, version: Option<&str>) -> String {
     let title = title.trim();
@@ -399,6 +419,7 @@ fn apply_track_info(window: &AppWindow, data: TrackInfoData) {
     st.set_duration(data.duration.into());
     st.set_quality(data.quality.into());
     st.set_isrc(data.isrc.into());
+    st.set_work(SharedString::new());
     st.set_label(data.label.into());
     st.set_label_id(data.label_id.into());
     st.set_copyright(data.copyright.into());
@@ -477,11 +498,33 @@ fn spawn_track_info<A>(
     handle.spawn(async move {
         match runtime.core().get_track(track_id).await {
             Ok(track) => {
+                // Only classical tracks (Qobuz tags them with a work) are
+                // worth the MusicBrainz round trip.
+                let work_isrc = track
+                    .isrc
+                    .clone()
+                    .filter(|isrc| track.work.is_some() && !isrc.is_empty());
                 let data = map_track_info(track);
                 let _ = weak.upgrade_in_event_loop(move |w| {
                     apply_track_info(&w, data);
                     w.global::<TrackInfoState>().set_loading(false);
                 });
+                if let Some(isrc) = work_isrc {
+                    match runtime.core().musicbrainz_track_work(&isrc).await {
+                        Ok(Some(work)) => {
+                            let line = format_work(&work);
+                            let _ = weak.upgrade_in_event_loop(move |w| {
+                                let st = w.global::<TrackInfoState>();
+                                // Another track may have been opened meanwhile.
+                                if st.get_isrc() == isrc.as_str() {
+                                    st.set_work(line.into());
+                                }
+                            });
+                        }
+                        Ok(None) => {}
+                        Err(e) => log::debug!("[qbz-slint] track-info work lookup failed: {e}"),
+                    }
+                }
             }
             Err(e) => {
                 log::error!("[qbz-slint] track-info load failed: {e}");
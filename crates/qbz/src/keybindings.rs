@@ -90,6 +90,7 @@ pub const ACTIONS: &[ActionDef] = &[
     ActionDef { id: "playback.toggle", label_en: "Play / Pause", category: Category::Playback, default: "Space", context: Context::None },
     ActionDef { id: "playback.next", label_en: "Next Track", category: Category::Playback, default: "Ctrl+ArrowRight", context: Context::None },
     ActionDef { id: "playback.prev", label_en: "Previous Track", category: Category::Playback, default: "Ctrl+ArrowLeft", context: Context::None },
+    ActionDef { id: "playback.bookmark", label_en: "Bookmark Position", category: Category::Playback, default: "Shift+B", context: Context::None },
     // Navigation
     ActionDef { id: "nav.back", label_en: "Go Back", category: Category::Navigation, default: "Alt+ArrowLeft", context: Context::None },
     ActionDef { id: "nav.forward", label_en: "Go Forward", category: Category::Navigation, default: "Alt+ArrowRight", context: Context::None },
@@ -504,6 +505,7 @@ fn run_action(window: &AppWindow, id: &str) {
         "playback.toggle" => window.global::<NowPlayingState>().invoke_toggle_play(),
         "playback.next" => window.global::<NowPlayingState>().invoke_next(),
         "playback.prev" => window.global::<NowPlayingState>().invoke_previous(),
+        "playback.bookmark" => window.global::<NowPlayingState>().invoke_add_bookmark(),
         "nav.back" => window.global::<NavState>().invoke_request_back(),
         "nav.forward" => window.global::<NavState>().invoke_request_forward(),
         "nav.search" => focus_search(window),
@@ -559,6 +561,7 @@ fn open_link_modal(window: &AppWindow) {
     s.set_error("".into());
     s.set_playlist_detected(false);
     s.set_playlist_provider("".into());
+    s.set_other_links(slint::ModelRc::default());
     s.set_resolving(false);
     s.set_open(true);
 }
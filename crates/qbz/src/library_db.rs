@@ -38,13 +38,25 @@ pub fn current_user_id() -> Option<u64> {
 /// per-user path so the local organization data is shared.
 fn db_path() -> Option<PathBuf> {
     let uid = user_id()?;
-    Some(
-        dirs::data_dir()?
-            .join("qbz")
-            .join("users")
-            .join(uid.to_string())
-            .join("library.db"),
-    )
+    Some(users_dir()?.join(uid.to_string()).join("library.db"))
+}
+
+fn users_dir() -> Option<PathBuf> {
+    Some(dirs::data_dir()?.join("qbz").join("users"))
+}
+
+/// Every user's library.db on this machine, active user or not. The caches
+/// under `<data_dir>/qbz` (thumbnails) are shared, so housekeeping that
+/// decides what is still referenced has to consult all of them.
+pub fn all_user_db_paths() -> Vec<PathBuf> {
+    let Some(Ok(entries)) = users_dir().map(std::fs::read_dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path().join("library.db"))
+        .filter(|path| path.is_file())
+        .collect()
 }
 
 /// Open the per-user library database, creating the directory if
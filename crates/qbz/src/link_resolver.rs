@@ -7,6 +7,7 @@
 //! navigation on a `Resolved` result is wired in `main.rs` (where the
 //! `navigate_*` helpers live).
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -20,7 +21,10 @@ use crate::adapter::SlintAdapter;
 /// Mirrors the Tauri `detectPlatform`. Returns "" when unknown.
 pub fn detect_platform(url: &str) -> &'static str {
     let lower = url.trim().to_ascii_lowercase();
-    if lower.contains("qobuz.com/") || lower.starts_with("qobuzapp://") {
+    if lower.contains("qobuz.com/")
+        || lower.contains("qobuz.page.link/")
+        || lower.starts_with("qobuzapp://")
+    {
         "qobuz"
     } else if lower.contains("spotify.com/") || lower.starts_with("spotify:") {
         "spotify"
@@ -40,6 +44,44 @@ pub fn detect_platform(url: &str) -> &'static str {
     }
 }
 
+/// Display name for an Odesli platform key ("appleMusic" → "Apple Music").
+/// Unknown keys pass through unchanged.
+pub fn platform_label(key: &str) -> String {
+    match key {
+        "spotify" => "Spotify",
+        "appleMusic" => "Apple Music",
+        "itunes" => "iTunes",
+        "youtube" => "YouTube",
+        "youtubeMusic" => "YouTube Music",
+        "tidal" => "Tidal",
+        "deezer" => "Deezer",
+        "amazonMusic" => "Amazon Music",
+        "amazonStore" => "Amazon",
+        "soundcloud" => "SoundCloud",
+        "pandora" => "Pandora",
+        "napster" => "Napster",
+        "audiomack" => "Audiomack",
+        "anghami" => "Anghami",
+        "boomplay" => "Boomplay",
+        "yandex" => "Yandex Music",
+        "bandcamp" => "Bandcamp",
+        "audius" => "Audius",
+        "spinrilla" => "Spinrilla",
+        other => return other.to_string(),
+    }
+    .to_string()
+}
+
+/// The not-on-Qobuz platform links as (label, URL) pairs, sorted by label.
+pub fn other_links(links: &HashMap<String, String>) -> Vec<(String, String)> {
+    let mut out: Vec<(String, String)> = links
+        .iter()
+        .map(|(key, url)| (platform_label(key), url.clone()))
+        .collect();
+    out.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    out
+}
+
 /// `QobuzSearchBridge` over the live `QbzCore` (the smart-search fallback the
 /// cross-platform resolver uses when an Odesli match must be found on Qobuz).
 struct CoreSearchBridge {
@@ -74,8 +116,8 @@ impl QobuzSearchBridge for CoreSearchBridge {
 }
 
 /// Resolve a pasted URL to a Qobuz entity (or a playlist / not-found verdict).
-/// Native Qobuz links resolve offline; cross-platform links hit Odesli + a
-/// smart Qobuz search.
+/// Native Qobuz links resolve offline (`qobuz.page.link` short links follow
+/// their redirect); cross-platform links hit Odesli + a smart Qobuz search.
 pub async fn resolve(
     runtime: Arc<AppRuntime<SlintAdapter>>,
     url: String,
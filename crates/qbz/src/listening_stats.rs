@@ -0,0 +1,74 @@
+//! Listening statistics — the Settings > Playback LISTENING group glue.
+//!
+//! The totals are recorded by [`crate::session_persist`] into the per-user
+//! session store. This module reads them back for the period picked in the
+//! panel (last 7 days / 30 days / 12 months) and pushes the total plus the
+//! top artists and genres onto `ListeningStatsState`.
+
+use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
+
+use qbz_app::session_store::ListeningStatEntry;
+
+use crate::{AppWindow, ListeningStatsState};
+
+/// `get_listening_stats` periods, parallel to `ListeningStatsState.periods`.
+const PERIODS: &[&str] = &["week", "month", "year"];
+
+fn fmt_listened(secs: u64) -> String {
+    let mins = secs / 60;
+    if mins >= 60 {
+        qbz_i18n::t_args(
+            "{} h {} min",
+            &[&(mins / 60).to_string(), &(mins % 60).to_string()],
+        )
+    } else {
+        qbz_i18n::t_args("{} min", &[&mins.to_string()])
+    }
+}
+
+fn entry_rows(entries: &[ListeningStatEntry]) -> ModelRc<SharedString> {
+    let rows: Vec<SharedString> = entries
+        .iter()
+        .map(|e| format!("{} — {}", e.name, fmt_listened(e.secs)).into())
+        .collect();
+    ModelRc::new(VecModel::from(rows))
+}
+
+/// Read the stats for period `index` and push them to the panel. Fired by
+/// the panel's `init` and by the period dropdown. Before login (no store)
+/// the group shows empty.
+pub fn load(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle, index: usize) {
+    let period = PERIODS.get(index).copied().unwrap_or(PERIODS[0]);
+    handle.spawn(async move {
+        let stats = match tokio::task::spawn_blocking(move || {
+            crate::session_persist::listening_stats(period)
+        })
+        .await
+        {
+            Ok(Ok(stats)) => Some(stats),
+            Ok(Err(e)) => {
+                log::warn!("[qbz-slint] listening stats read failed: {e}");
+                None
+            }
+            Err(e) => {
+                log::error!("[qbz-slint] listening stats task failed: {e}");
+                None
+            }
+        };
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            let st = w.global::<ListeningStatsState>();
+            match stats {
+                Some(stats) => {
+                    st.set_total(fmt_listened(stats.total_secs).into());
+                    st.set_top_artists(entry_rows(&stats.top_artists));
+                    st.set_top_genres(entry_rows(&stats.top_genres));
+                }
+                None => {
+                    st.set_total(SharedString::new());
+                    st.set_top_artists(ModelRc::default());
+                    st.set_top_genres(ModelRc::default());
+                }
+            }
+        });
+    });
+}
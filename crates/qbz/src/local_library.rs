@@ -1815,16 +1815,18 @@ pub fn open_local_album(
         s.set_versions(ModelRc::new(VecModel::from(Vec::<crate::LocalAlbumVersion>::new())));
         s.set_version_index(0);
         s.set_cover(slint::Image::default());
+        s.set_discogs_url("".into());
+        s.set_discogs_format("".into());
     });
     let gk = group_key.clone();
     let hydrate_handle = handle.clone();
     handle.spawn(async move {
-        let tracks = tokio::task::spawn_blocking(move || {
+        let (tracks, discogs) = tokio::task::spawn_blocking(move || {
             let mut t = fetch_album_tracks_blocking(&gk);
             // Backfill covers from cover.jpg/folder.jpg on disk (the DB may not
             // have an artwork_path even when a cover sits in the folder).
             crate::playback::fill_missing_covers(&mut t);
-            t
+            (t, discogs_link_blocking(&gk))
         })
         .await
         .unwrap_or_default();
@@ -1883,6 +1885,10 @@ pub fn open_local_album(
             s.set_versions(ModelRc::new(VecModel::from(vlist)));
             s.set_loading(false);
             s.set_cover_url(album_cover.clone().into());
+            if let Some((url, format)) = discogs {
+                s.set_discogs_url(url.into());
+                s.set_discogs_format(format.into());
+            }
             apply_album_version(&w, 0);
             // Plex quality hydration (slice 6): if this is a Plex album, the
             // cached rows may carry NULL/incomplete quality (the bulk `/all`
@@ -1914,6 +1920,23 @@ pub fn open_local_album(
     });
 }
 
+/// The Discogs release page and format of the collection copy the last
+/// collection sync linked to `group_key`. None when unlinked (or Plex).
+fn discogs_link_blocking(group_key: &str) -> Option<(String, String)> {
+    if group_key.starts_with("plex:") {
+        return None;
+    }
+    let link = crate::library_db::with_db(|db| {
+        db.with_connection(|conn| qbz_library::discogs_collection::link_for_album(conn, group_key))
+            .map_err(|e| qbz_library::LibraryError::Database(e.to_string()))
+    })
+    .flatten()?;
+    Some((
+        format!("https://www.discogs.com/release/{}", link.release_id),
+        link.format.unwrap_or_default(),
+    ))
+}
+
 /// Apply version `index` of the open album to LocalAlbumState (tracks, header,
 /// quality). Reads the cached versions; no DB round-trip. The cover is
 /// album-level (set once by `open_local_album`), so it is NOT touched here.
@@ -3793,8 +3816,62 @@ pub fn fetch_missing_artist_images(
     });
 }
 
+/// Biography of a local artist (the `v2_library_fetch_artist_biography`
+/// equivalent, keyed by name: local artists have no id). Served from the
+/// library's `artist_bios` cache for 30 days; otherwise the name resolves to
+/// a MusicBrainz artist whose annotation is fetched and cached, misses
+/// included. A stale entry is still returned when MusicBrainz is disabled or
+/// the fetch fails.
+pub async fn fetch_artist_biography(
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    name: String,
+) -> Result<Option<String>, String> {
+    let key = name.clone();
+    let cached = tokio::task::spawn_blocking(move || {
+        crate::library_db::with_db(|db| db.get_artist_bio(&key)).flatten()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now().timestamp();
+    if let Some(entry) = cached.as_ref().filter(|c| c.is_fresh(now)) {
+        return Ok(entry.bio.clone());
+    }
+    let stale = cached.and_then(|c| c.bio);
+    if !runtime.core().musicbrainz_is_enabled().await {
+        return Ok(stale);
+    }
+
+    let fetched = async {
+        let Some(artist) = runtime.core().musicbrainz_resolve_artist(&name).await? else {
+            return Ok((None, None));
+        };
+        let bio = runtime
+            .core()
+            .musicbrainz_get_artist_biography(&artist.mbid)
+            .await?;
+        Ok::<_, qbz_core::CoreError>((Some(artist.mbid), bio))
+    }
+    .await;
+    let (mbid, bio) = match fetched {
+        Ok(found) => found,
+        Err(e) if stale.is_some() => {
+            log::debug!("[locallibrary] artist bio refresh failed for {name}: {e}");
+            return Ok(stale);
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+    let stored = bio.clone();
+    let _ = tokio::task::spawn_blocking(move || {
+        crate::library_db::with_db(|db| {
+            db.cache_artist_bio(&name, mbid.as_deref(), stored.as_deref())
+        })
+    })
+    .await;
+    Ok(bio)
+}
+
 /// Select an artist: filter their albums (in place, from the cached album
-/// set) into the right pane and kick cover loads.
+/// set) into the right pane, kick cover loads and fetch the biography.
 pub fn select_local_artist(
     weak: slint::Weak<AppWindow>,
     handle: tokio::runtime::Handle,
@@ -3814,9 +3891,29 @@ pub fn select_local_artist(
                 .map(|a| a.display_name.to_string())
                 .unwrap_or_else(|| name.clone());
             s.set_artists_selected_display(display.into());
+            s.set_artists_selected_bio("".into());
             s.set_artists_selected_loading(true);
         }
     });
+    if let Some(runtime) = crate::myqbz_detail::global_runtime() {
+        let weak = weak.clone();
+        let name = name.clone();
+        handle.spawn(async move {
+            let bio = match fetch_artist_biography(runtime, name.clone()).await {
+                Ok(bio) => bio.unwrap_or_default(),
+                Err(e) => {
+                    log::debug!("[locallibrary] artist bio fetch failed for {name}: {e}");
+                    return;
+                }
+            };
+            let _ = weak.upgrade_in_event_loop(move |w| {
+                let s = w.global::<LocalLibraryState>();
+                if s.get_artists_selected_name().as_str() == name {
+                    s.set_artists_selected_bio(bio.into());
+                }
+            });
+        });
+    }
     handle.spawn(async move {
         let cards = tokio::task::spawn_blocking(move || {
             let albums = ARTIST_ALBUMS
@@ -2,7 +2,9 @@
 //!
 //! Hosts the folder-management surface that Tauri renders inline in the
 //! browse view's gear panel: the folder list (add / remove / edit / enable /
-//! alias / network override), maintenance (cleanup missing files), and the
+//! alias / network override, one-time network share scans), maintenance
+//! (cleanup missing files and thumbnails, merge duplicate tracks and split
+//! albums, Discogs collection sync, missing artwork, ISRCs), and the
 //! two-step danger-zone clear. The scan engine + progress live in Slice B.
 //!
 //! All DB access goes through the frontend-agnostic `qbz_library` crate via
@@ -14,6 +16,7 @@
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 
+use qbz_library::discogs_collection::{self, AlbumMatcher, CollectionLink, CollectionSyncSummary};
 use slint::{ComponentHandle, ModelRc, VecModel, Weak};
 
 use crate::{
@@ -32,6 +35,7 @@ struct FolderData {
     network_fs_type: Option<String>,
     user_override_network: bool,
     last_scan: Option<i64>,
+    transient: bool,
     accessible: bool,
     selected: bool,
 }
@@ -92,6 +96,7 @@ fn to_item(f: &FolderData) -> LibraryFolderItem {
         user_override_network: f.user_override_network,
         last_scan: f.last_scan.unwrap_or(0) as i32,
         last_scan_label: last_scan_label(f.last_scan).into(),
+        transient: f.transient,
         accessible: f.accessible,
         selected: f.selected,
     }
@@ -163,6 +168,7 @@ pub fn load_folders(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
                 network_fs_type: f.network_fs_type,
                 user_override_network: f.user_override_network,
                 last_scan: f.last_scan,
+                transient: f.transient,
             })
             .collect();
 
@@ -182,12 +188,109 @@ pub fn load_folders(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
             derive(&w);
         });
 
+        watch_mounts(&weak2, &network);
         for (id, path) in network {
             check_accessible(weak2.clone(), check_handle.clone(), id, path);
         }
     });
 }
 
+/// The running mount watch and the paths it covers, so a folder reload that
+/// doesn't change the network set keeps the existing watch.
+static MOUNT_WATCH: LazyLock<Mutex<Option<(Vec<String>, qbz_library::WatchHandle)>>> =
+    LazyLock::new(|| Mutex::new(None));
+/// Watched folders currently unreachable. A running scan sets their files
+/// aside until they are back (see [`mount_lost`]).
+static OFFLINE_MOUNTS: LazyLock<Mutex<std::collections::HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(std::collections::HashSet::new()));
+
+/// Drop removed folders from [`OFFLINE_MOUNTS`] right away, without waiting
+/// for the folder reload to restart the watch.
+fn forget_mounts(paths: &[String]) {
+    let mut offline = OFFLINE_MOUNTS.lock().unwrap_or_else(|e| e.into_inner());
+    for path in paths {
+        offline.remove(path);
+    }
+}
+
+/// (Re)start the background accessibility watch over the network folders.
+/// A lost mount flags the folder unavailable, pauses a running scan's work
+/// on that folder and warns the user; the scan picks the folder up again
+/// once its mount is back.
+fn watch_mounts(weak: &Weak<AppWindow>, network: &[(i64, String)]) {
+    let paths: Vec<String> = network.iter().map(|(_, p)| p.clone()).collect();
+    let mut watch = MOUNT_WATCH.lock().unwrap_or_else(|e| e.into_inner());
+    if watch.as_ref().map(|(p, _)| p == &paths).unwrap_or(false) {
+        return;
+    }
+    // Dropping the old handle ends its thread; forget mounts no longer watched.
+    *watch = None;
+    // The new watch starts from what is already known and probes straight
+    // away, so a mount that came back (or went away) meanwhile is reported.
+    let known: Vec<(std::path::PathBuf, bool)> = {
+        let mut offline = OFFLINE_MOUNTS.lock().unwrap_or_else(|e| e.into_inner());
+        offline.retain(|p| paths.contains(p));
+        paths
+            .iter()
+            .map(|p| (std::path::PathBuf::from(p), !offline.contains(p)))
+            .collect()
+    };
+    if paths.is_empty() {
+        return;
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = qbz_library::MountMonitor::watch(known, tx);
+    *watch = Some((paths, handle));
+
+    let weak = weak.clone();
+    std::thread::spawn(move || {
+        // Ends when the watch is replaced (its sender drops).
+        for event in rx {
+            let (path, accessible) = match event {
+                qbz_library::MountEvent::Lost(p) => (p, false),
+                qbz_library::MountEvent::Restored(p) => (p, true),
+            };
+            let path = path.to_string_lossy().to_string();
+            {
+                let mut offline = OFFLINE_MOUNTS.lock().unwrap_or_else(|e| e.into_inner());
+                if accessible {
+                    offline.remove(&path);
+                } else {
+                    offline.insert(path.clone());
+                }
+            }
+
+            let found = folders_lock()
+                .iter()
+                .find(|f| f.path == path)
+                .map(|f| (f.id, display_name(f)));
+            let name = match found {
+                Some((id, name)) => {
+                    update_accessible(&weak, id, accessible);
+                    name
+                }
+                None => path,
+            };
+            if accessible {
+                crate::toast::info_weak(
+                    &weak,
+                    qbz_i18n::t_args("Library folder is back online: {}", &[&name]),
+                );
+            } else {
+                crate::toast::show_weak(
+                    &weak,
+                    qbz_i18n::t_args(
+                        "Library folder is unreachable: {}. Check the network share.",
+                        &[&name],
+                    ),
+                    crate::ToastKind::Warning,
+                );
+            }
+        }
+    });
+}
+
 /// Update one folder's accessibility in the static + UI (and the open modal).
 fn update_accessible(weak: &Weak<AppWindow>, id: i64, accessible: bool) {
     {
@@ -309,6 +412,7 @@ pub fn remove_folders(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
         .ok()
         .flatten()
         .unwrap_or_default();
+        forget_mounts(&paths);
         crate::recently::prune_albums(&keys);
         crate::toast::success_weak(
             &weak,
@@ -347,6 +451,7 @@ pub fn remove_folder(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, id:
         .ok()
         .flatten();
         let (n, keys) = result.unwrap_or((0, Vec::new()));
+        forget_mounts(std::slice::from_ref(&path));
         crate::recently::prune_albums(&keys);
         let tracks_label = qbz_i18n::tf("{} track", "{} tracks", n as i64, &[&n.to_string()]);
         crate::toast::success_weak(
@@ -600,6 +705,704 @@ pub fn cleanup_missing(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
     });
 }
 
+/// Merge duplicate local tracks (same tags, near-equal duration) into one
+/// library entry each. `strategy` is the Settings select index: 0 highest
+/// quality, 1 most recent, 2 first path. Playlist entries move to the kept
+/// track; audio files are untouched. Confirms with the removal count first.
+pub fn deduplicate(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, strategy: i32) {
+    let strategy = match strategy {
+        1 => qbz_library::MergeStrategy::KeepMostRecent,
+        2 => qbz_library::MergeStrategy::KeepFirstPath,
+        _ => qbz_library::MergeStrategy::KeepHighestQuality,
+    };
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_deduplicating() {
+            return;
+        }
+        s.set_deduplicating(true);
+    }
+    let finish = |weak: &Weak<AppWindow>| {
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>().set_deduplicating(false);
+        });
+    };
+    let h = handle.clone();
+    handle.spawn(async move {
+        let groups = tokio::task::spawn_blocking(|| {
+            crate::library_db::with_db(|db| db.find_duplicates())
+        })
+        .await
+        .ok()
+        .flatten();
+        let Some(groups) = groups else {
+            finish(&weak);
+            crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't merge duplicate tracks"));
+            return;
+        };
+        let removable: usize = groups.iter().map(|g| g.tracks.len() - 1).sum();
+        if removable == 0 {
+            finish(&weak);
+            crate::toast::info_weak(&weak, qbz_i18n::t("No duplicate tracks found"));
+            return;
+        }
+
+        let confirmed = rfd::AsyncMessageDialog::new()
+            .set_title(&qbz_i18n::t("Merge duplicate tracks?"))
+            .set_description(&qbz_i18n::tf(
+                "{} duplicate track will be removed from the library. Your audio files are NOT deleted.",
+                "{} duplicate tracks will be removed from the library. Your audio files are NOT deleted.",
+                removable as i64,
+                &[&removable.to_string()],
+            ))
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show()
+            .await
+            == rfd::MessageDialogResult::Yes;
+        if !confirmed {
+            finish(&weak);
+            return;
+        }
+
+        // The library may have changed while the dialog was open (a scan,
+        // a removed folder): merge what is duplicated now, not the groups
+        // counted for the prompt.
+        let summary = tokio::task::spawn_blocking(move || {
+            crate::library_db::with_db_mut(|db| {
+                let groups = db.find_duplicates()?;
+                db.merge_duplicates(&groups, strategy)
+            })
+        })
+        .await
+        .ok()
+        .flatten();
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>().set_deduplicating(false);
+            crate::local_library::reset_browse_models(&w);
+        });
+        match summary {
+            Some(summary) => {
+                log::info!(
+                    "[qbz-slint] dedup: {} groups merged, {} tracks removed, {} playlist entries moved",
+                    summary.groups_merged,
+                    summary.tracks_removed,
+                    summary.playlist_entries_moved
+                );
+                let n = summary.tracks_removed;
+                crate::toast::success_weak(
+                    &weak,
+                    qbz_i18n::tf(
+                        "Removed {} duplicate track",
+                        "Removed {} duplicate tracks",
+                        n as i64,
+                        &[&n.to_string()],
+                    ),
+                );
+            }
+            None => {
+                crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't merge duplicate tracks"))
+            }
+        }
+        load_folders(weak, h);
+    });
+}
+
+/// Delete cached thumbnails no library row points at any more (the
+/// `v2_library_cleanup_orphan_thumbnails` equivalent) and toast the space
+/// freed. The directory is shared, so every user's library and the open
+/// ephemeral folder count as references.
+pub fn cleanup_orphan_thumbnails(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_cleaning_thumbnails() {
+            return;
+        }
+        s.set_cleaning_thumbnails(true);
+    }
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(|| {
+            let db_paths = crate::library_db::all_user_db_paths();
+            let in_memory = crate::ephemeral::artwork_paths();
+            match qbz_library::cleanup_orphan_thumbnails(&db_paths, &in_memory) {
+                Ok(summary) => Some(summary),
+                Err(e) => {
+                    log::error!("[qbz-slint] thumbnail cleanup failed: {e}");
+                    None
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten();
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>()
+                .set_cleaning_thumbnails(false);
+        });
+        match result {
+            Some(summary) if summary.removed > 0 => {
+                log::info!(
+                    "[qbz-slint] thumbnails: removed {} of {} ({} bytes)",
+                    summary.removed,
+                    summary.checked,
+                    summary.bytes_freed
+                );
+                crate::toast::success_weak(
+                    &weak,
+                    qbz_i18n::t_args(
+                        "Freed {}",
+                        &[&crate::offline_manager::human_size(summary.bytes_freed)],
+                    ),
+                );
+            }
+            Some(_) => crate::toast::info_weak(&weak, qbz_i18n::t("No unused thumbnails found")),
+            None => crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't clean up thumbnails")),
+        }
+    });
+}
+
+/// Outcome of [`fetch_missing_artwork`].
+#[derive(Debug, Default)]
+struct ArtworkFetchSummary {
+    albums: usize,
+    from_qobuz: usize,
+    from_cover_art_archive: usize,
+    not_found: usize,
+    failed: usize,
+}
+
+/// Minimum spacing of the artwork lookups' HTTP requests (2 a second).
+const ARTWORK_REQUEST_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Fetch covers for the albums whose tracks have no artwork (the
+/// `v2_library_auto_fetch_missing_artwork` equivalent, over every such album
+/// — local albums have no integer id to select by). Each album is looked up
+/// on Qobuz by artist + title first, then on MusicBrainz with the cover from
+/// the Cover Art Archive. A found cover is thumbnailed like embedded artwork
+/// and set on the album's tracks that have none. The maintenance row shows
+/// progress every 5 albums.
+pub fn fetch_missing_artwork(
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    runtime: Arc<qbz_app::shell::AppRuntime<crate::adapter::SlintAdapter>>,
+) {
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_fetching_artwork() {
+            return;
+        }
+        s.set_fetching_artwork(true);
+    }
+    let set_status = |weak: &Weak<AppWindow>, status: String| {
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            w.global::<LibraryFoldersState>()
+                .set_artwork_fetch_status(status.into());
+        });
+    };
+    handle.spawn(async move {
+        let albums = tokio::task::spawn_blocking(|| {
+            crate::library_db::with_db(|db| db.get_albums_without_artwork())
+        })
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+        // Bounded so one stalled server can't hang the whole run.
+        let http = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(20))
+            .connect_timeout(std::time::Duration::from_secs(5))
+            .build()
+            .unwrap_or_default();
+        let mut ticker = tokio::time::interval(ARTWORK_REQUEST_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let musicbrainz = runtime.core().musicbrainz_is_enabled().await;
+        let mut summary = ArtworkFetchSummary {
+            albums: albums.len(),
+            ..Default::default()
+        };
+        for (index, (group_key, title, artist)) in albums.into_iter().enumerate() {
+            match find_album_artwork(&runtime, &http, &mut ticker, &title, &artist, musicbrainz)
+                .await
+            {
+                Some((bytes, from_qobuz)) => {
+                    let key = group_key.clone();
+                    let stored = tokio::task::spawn_blocking(move || {
+                        let thumbnail = qbz_library::generate_thumbnail_from_bytes(
+                            &bytes,
+                            &format!("fetched-artwork:{key}"),
+                        )
+                        .map_err(|e| log::warn!("[qbz-slint] artwork: {key}: {e}"))
+                        .ok()?;
+                        crate::library_db::with_db(|db| {
+                            db.fill_album_artwork(&key, &thumbnail.to_string_lossy())
+                        })
+                    })
+                    .await
+                    .ok()
+                    .flatten();
+                    match stored {
+                        Some(_) if from_qobuz => summary.from_qobuz += 1,
+                        Some(_) => summary.from_cover_art_archive += 1,
+                        None => summary.failed += 1,
+                    }
+                }
+                None => summary.not_found += 1,
+            }
+            let done = index + 1;
+            if done % 5 == 0 || done == summary.albums {
+                set_status(
+                    &weak,
+                    qbz_i18n::t_args(
+                        "{} of {} albums checked",
+                        &[&done.to_string(), &summary.albums.to_string()],
+                    ),
+                );
+            }
+        }
+
+        log::info!("[qbz-slint] artwork: {:?}", summary);
+        let _ = weak.upgrade_in_event_loop(|w| {
+            let s = w.global::<LibraryFoldersState>();
+            s.set_fetching_artwork(false);
+            s.set_artwork_fetch_status("".into());
+            crate::local_library::reset_browse_models(&w);
+        });
+        let found = summary.from_qobuz + summary.from_cover_art_archive;
+        if summary.albums == 0 {
+            crate::toast::info_weak(&weak, qbz_i18n::t("Every album already has artwork"));
+            return;
+        }
+        if found > 0 {
+            crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Found artwork for {} album",
+                    "Found artwork for {} albums",
+                    found as i64,
+                    &[&found.to_string()],
+                ),
+            );
+        }
+        let missing = summary.not_found + summary.failed;
+        if missing > 0 {
+            crate::toast::info_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "No artwork found for {} album",
+                    "No artwork found for {} albums",
+                    missing as i64,
+                    &[&missing.to_string()],
+                ),
+            );
+        }
+    });
+}
+
+/// Cover image bytes for `title` by `artist`, and whether they came from
+/// Qobuz (else the Cover Art Archive). Every request waits for `ticker`.
+async fn find_album_artwork(
+    runtime: &qbz_app::shell::AppRuntime<crate::adapter::SlintAdapter>,
+    http: &reqwest::Client,
+    ticker: &mut tokio::time::Interval,
+    title: &str,
+    artist: &str,
+    musicbrainz: bool,
+) -> Option<(Vec<u8>, bool)> {
+    ticker.tick().await;
+    match runtime.core().find_album_cover_url(title, artist).await {
+        Ok(Some(url)) => {
+            ticker.tick().await;
+            if let Some(bytes) = download_image(http, &url).await {
+                return Some((bytes, true));
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log::debug!("[qbz-slint] artwork: Qobuz lookup for {title:?} failed: {e}"),
+    }
+    if !musicbrainz {
+        return None;
+    }
+    ticker.tick().await;
+    let release = match runtime.core().musicbrainz_find_release(title, artist).await {
+        Ok(release) => release?,
+        Err(e) => {
+            log::debug!("[qbz-slint] artwork: MusicBrainz lookup for {title:?} failed: {e}");
+            return None;
+        }
+    };
+    ticker.tick().await;
+    let url = format!("https://coverartarchive.org/release/{release}/front-500");
+    download_image(http, &url).await.map(|bytes| (bytes, false))
+}
+
+async fn download_image(http: &reqwest::Client, url: &str) -> Option<Vec<u8>> {
+    let response = http.get(url).send().await.ok()?.error_for_status().ok()?;
+    response.bytes().await.ok().map(|bytes| bytes.to_vec())
+}
+
+/// Outcome of [`enrich_isrcs_from_musicbrainz`].
+#[derive(Debug, Default)]
+struct IsrcEnrichmentSummary {
+    tracks: usize,
+    matched: usize,
+    unmatched: usize,
+    errors: usize,
+}
+
+/// Tracks looked up per ISRC enrichment run (about two minutes of requests).
+const ISRC_BATCH: usize = 100;
+
+/// MusicBrainz allows one request a second.
+const MUSICBRAINZ_REQUEST_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Look up the ISRC of up to [`ISRC_BATCH`] tracks that have none (the
+/// `v2_library_find_tracks_without_isrc` +
+/// `v2_library_enrich_isrcs_from_musicbrainz` equivalent). Each track is
+/// searched on MusicBrainz by artist + title; the first ISRC of the matched
+/// recording is stored. A track with no match is marked as looked up so the
+/// next run moves on; one whose lookup failed is retried next time.
+pub fn enrich_isrcs_from_musicbrainz(
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    runtime: Arc<qbz_app::shell::AppRuntime<crate::adapter::SlintAdapter>>,
+) {
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_enriching_isrcs() {
+            return;
+        }
+        s.set_enriching_isrcs(true);
+    }
+    let set_status = |weak: &Weak<AppWindow>, status: String| {
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            w.global::<LibraryFoldersState>()
+                .set_isrc_enrichment_status(status.into());
+        });
+    };
+    handle.spawn(async move {
+        let finish = |weak: &Weak<AppWindow>| {
+            let _ = weak.upgrade_in_event_loop(|w| {
+                let s = w.global::<LibraryFoldersState>();
+                s.set_enriching_isrcs(false);
+                s.set_isrc_enrichment_status("".into());
+            });
+        };
+        if !runtime.core().musicbrainz_is_enabled().await {
+            finish(&weak);
+            crate::toast::info_weak(&weak, qbz_i18n::t("Enable MusicBrainz to look up ISRCs"));
+            return;
+        }
+        let tracks = tokio::task::spawn_blocking(|| {
+            crate::library_db::with_db(|db| db.get_tracks_without_isrc(ISRC_BATCH))
+        })
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+        let mut ticker = tokio::time::interval(MUSICBRAINZ_REQUEST_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut summary = IsrcEnrichmentSummary {
+            tracks: tracks.len(),
+            ..Default::default()
+        };
+        for (index, track) in tracks.into_iter().enumerate() {
+            match find_track_isrc(&runtime, &mut ticker, &track.title, &track.artist).await {
+                Ok(isrc) => {
+                    let found = isrc.is_some();
+                    let stored = tokio::task::spawn_blocking(move || {
+                        crate::library_db::with_db(|db| {
+                            db.update_track_isrc(track.id, isrc.as_deref().unwrap_or(""))
+                        })
+                    })
+                    .await
+                    .ok()
+                    .flatten();
+                    match stored {
+                        Some(()) if found => summary.matched += 1,
+                        Some(()) => summary.unmatched += 1,
+                        None => summary.errors += 1,
+                    }
+                }
+                Err(e) => {
+                    log::debug!("[qbz-slint] isrc: lookup for {:?} failed: {e}", track.title);
+                    summary.errors += 1;
+                }
+            }
+            let done = index + 1;
+            if done % 5 == 0 || done == summary.tracks {
+                set_status(
+                    &weak,
+                    qbz_i18n::t_args(
+                        "{} of {} tracks checked",
+                        &[&done.to_string(), &summary.tracks.to_string()],
+                    ),
+                );
+            }
+        }
+
+        log::info!("[qbz-slint] isrc: {:?}", summary);
+        finish(&weak);
+        if summary.tracks == 0 {
+            crate::toast::info_weak(&weak, qbz_i18n::t("Every track has been checked"));
+            return;
+        }
+        crate::toast::success_weak(
+            &weak,
+            qbz_i18n::t_args(
+                "ISRCs found for {} of {} tracks",
+                &[&summary.matched.to_string(), &summary.tracks.to_string()],
+            ),
+        );
+        if summary.errors > 0 {
+            crate::toast::info_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "{} lookup failed and will be retried",
+                    "{} lookups failed and will be retried",
+                    summary.errors as i64,
+                    &[&summary.errors.to_string()],
+                ),
+            );
+        }
+    });
+}
+
+/// First ISRC of the MusicBrainz recording that is `title` by `artist`.
+/// Every request waits for `ticker`.
+async fn find_track_isrc(
+    runtime: &qbz_app::shell::AppRuntime<crate::adapter::SlintAdapter>,
+    ticker: &mut tokio::time::Interval,
+    title: &str,
+    artist: &str,
+) -> Result<Option<String>, String> {
+    ticker.tick().await;
+    let Some((mbid, isrcs)) = runtime
+        .core()
+        .musicbrainz_find_recording(title, artist)
+        .await
+        .map_err(|e| e.to_string())?
+    else {
+        return Ok(None);
+    };
+    if let Some(isrc) = isrcs.into_iter().next() {
+        return Ok(Some(isrc));
+    }
+    // Search results don't always carry the ISRCs; the lookup does.
+    ticker.tick().await;
+    let isrcs = runtime
+        .core()
+        .musicbrainz_recording_isrcs(&mbid)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(isrcs.into_iter().next())
+}
+
+/// Fold multi-disc releases that were indexed as one album per disc into a
+/// single album each (the `v2_library_detect_multi_disc_albums` +
+/// `v2_library_merge_albums` equivalent). Each release keeps its title
+/// without the disc suffix. Confirms with the album count first.
+pub fn merge_split_albums(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_merging_split_albums() {
+            return;
+        }
+        s.set_merging_split_albums(true);
+    }
+    let finish = |weak: &Weak<AppWindow>| {
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>()
+                .set_merging_split_albums(false);
+        });
+    };
+    handle.spawn(async move {
+        let groups = tokio::task::spawn_blocking(|| {
+            crate::library_db::with_db(|db| db.find_split_albums())
+        })
+        .await
+        .ok()
+        .flatten();
+        let Some(groups) = groups else {
+            finish(&weak);
+            crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't merge split albums"));
+            return;
+        };
+        if groups.is_empty() {
+            finish(&weak);
+            crate::toast::info_weak(&weak, qbz_i18n::t("No split albums found"));
+            return;
+        }
+
+        let parts: usize = groups.iter().map(|g| g.parts.len()).sum();
+        let confirmed = rfd::AsyncMessageDialog::new()
+            .set_title(&qbz_i18n::t("Merge split albums?"))
+            .set_description(&qbz_i18n::tf(
+                "{} album will be combined into multi-disc releases. Your audio files are NOT changed.",
+                "{} albums will be combined into multi-disc releases. Your audio files are NOT changed.",
+                parts as i64,
+                &[&parts.to_string()],
+            ))
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show()
+            .await
+            == rfd::MessageDialogResult::Yes;
+        if !confirmed {
+            finish(&weak);
+            return;
+        }
+
+        let merged = tokio::task::spawn_blocking(move || {
+            crate::library_db::with_db_mut(|db| {
+                for group in &groups {
+                    db.merge_split_albums(group, &group.title)?;
+                }
+                Ok(groups.len())
+            })
+        })
+        .await
+        .ok()
+        .flatten();
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>().set_merging_split_albums(false);
+            crate::local_library::reset_browse_models(&w);
+        });
+        match merged {
+            Some(n) => {
+                log::info!("[qbz-slint] split albums: {} releases merged", n);
+                crate::toast::success_weak(
+                    &weak,
+                    qbz_i18n::tf(
+                        "Merged {} multi-disc album",
+                        "Merged {} multi-disc albums",
+                        n as i64,
+                        &[&n.to_string()],
+                    ),
+                );
+            }
+            None => crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't merge split albums")),
+        }
+    });
+}
+
+/// Delay between Discogs collection pages: unauthenticated requests are
+/// limited to 25 a minute.
+const DISCOGS_PAGE_DELAY_MS: u64 = 2_500;
+
+/// Match a public Discogs collection to the local albums (the
+/// `v2_discogs_sync_collection` equivalent): page through the collection,
+/// match each release by artist, title and year, and replace the stored
+/// album links with the result. The username is remembered for next time.
+pub fn sync_discogs_collection(
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    username: String,
+) {
+    let username = username.trim().to_string();
+    if username.is_empty() {
+        return;
+    }
+    if let Some(w) = weak.upgrade() {
+        let s = w.global::<LibraryFoldersState>();
+        if s.get_discogs_syncing() {
+            return;
+        }
+        s.set_discogs_syncing(true);
+    }
+    crate::locallibrary_prefs::save_discogs_username(&username);
+    handle.spawn(async move {
+        let client = qbz_integrations::DiscogsClient::new();
+        let mut items = Vec::new();
+        let mut page = 1;
+        let fetched = loop {
+            match client.get_collection_items(&username, page).await {
+                Ok(collection) => {
+                    items.extend(collection.releases);
+                    if page >= collection.pagination.pages {
+                        break Ok(());
+                    }
+                    page += 1;
+                    tokio::time::sleep(std::time::Duration::from_millis(DISCOGS_PAGE_DELAY_MS))
+                        .await;
+                }
+                Err(e) => break Err(e),
+            }
+        };
+
+        let summary = match fetched {
+            Ok(()) => tokio::task::spawn_blocking(move || {
+                crate::library_db::with_db_mut(|db| match_discogs_collection(db, &items))
+            })
+            .await
+            .ok()
+            .flatten(),
+            Err(e) => {
+                log::warn!("[qbz-slint] discogs: collection of {username} failed: {e}");
+                None
+            }
+        };
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<LibraryFoldersState>().set_discogs_syncing(false);
+        });
+        match summary {
+            Some(summary) => {
+                log::info!(
+                    "[qbz-slint] discogs: {} of {} releases matched",
+                    summary.matched,
+                    summary.releases
+                );
+                crate::toast::success_weak(
+                    &weak,
+                    qbz_i18n::tf(
+                        "Matched {} of {} Discogs release",
+                        "Matched {} of {} Discogs releases",
+                        summary.releases as i64,
+                        &[&summary.matched.to_string(), &summary.releases.to_string()],
+                    ),
+                );
+            }
+            None => {
+                crate::toast::error_weak(&weak, qbz_i18n::t("Couldn't sync the Discogs collection"))
+            }
+        }
+    });
+}
+
+/// Match collection `items` against the library's albums and store the
+/// links. Several releases (a vinyl and a CD) can match one album; the
+/// first one is linked.
+fn match_discogs_collection(
+    db: &mut qbz_library::LibraryDatabase,
+    items: &[qbz_integrations::discogs::CollectionItem],
+) -> Result<CollectionSyncSummary, qbz_library::LibraryError> {
+    let albums = db.get_albums(true)?;
+    let matcher = AlbumMatcher::new(&albums);
+    let mut summary = CollectionSyncSummary {
+        releases: items.len(),
+        ..Default::default()
+    };
+    let mut links: Vec<CollectionLink> = Vec::new();
+    for item in items {
+        let artist = item.artist().unwrap_or_default();
+        let title = &item.basic_information.title;
+        let Some(key) = matcher.match_release(artist, title, item.year()) else {
+            summary.unmatched.push(format!("{artist} - {title}"));
+            continue;
+        };
+        summary.matched += 1;
+        if links.iter().all(|l| l.album_group_key != key) {
+            links.push(CollectionLink {
+                album_group_key: key.to_string(),
+                release_id: item.basic_information.id,
+                format: item.format().map(str::to_string),
+            });
+        }
+    }
+    db.with_connection_mut(|conn| discogs_collection::replace_links(conn, &links))
+        .map_err(|e| qbz_library::LibraryError::Database(e.to_string()))?;
+    Ok(summary)
+}
+
 /// Two-step danger-zone clear of all indexed tracks (audio files untouched).
 pub fn clear_library(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
     let h = handle.clone();
@@ -660,6 +1463,16 @@ pub fn set_filter(weak: Weak<AppWindow>) {
 /// at every file boundary.
 static SCAN_CANCEL: LazyLock<Arc<AtomicBool>> = LazyLock::new(|| Arc::new(AtomicBool::new(false)));
 
+/// Pause gate for the running scan: whether the library folder at `path` is
+/// a watched network folder whose mount is lost (see [`watch_mounts`]). The
+/// core loop sets such a folder aside at the next file boundary.
+fn mount_lost(path: &str) -> bool {
+    OFFLINE_MOUNTS
+        .lock()
+        .map(|offline| offline.contains(path))
+        .unwrap_or(false)
+}
+
 fn basename(path: &str) -> String {
     path.trim_end_matches('/')
         .rsplit('/')
@@ -736,6 +1549,14 @@ fn run_scan(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, ids: Option<V
                         });
                     }
                 }
+                Paused => {
+                    let _ = weak_sink.upgrade_in_event_loop(|w| {
+                        w.global::<LibraryScanState>().set_current_file(
+                            qbz_i18n::t("Paused — waiting for the network folder...").into(),
+                        );
+                    });
+                }
+                Resumed => {}
                 Cleanup => {
                     let _ = weak_sink.upgrade_in_event_loop(|w| {
                         w.global::<LibraryScanState>()
@@ -780,7 +1601,14 @@ fn run_scan(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, ids: Option<V
 
         let ids_ref = ids.as_deref();
         let _ = crate::library_db::with_db(|db| {
-            qbz_library::scan_with_progress(db, ids_ref, &artwork_cache, &cancel, &sink)
+            qbz_library::scan_with_progress(
+                db,
+                ids_ref,
+                &artwork_cache,
+                &cancel,
+                &mount_lost,
+                &sink,
+            )
         });
 
         // Post-scan: refresh the folder list (last_scan labels) + reset the
@@ -810,6 +1638,36 @@ pub fn scan_folder(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, id: i6
     run_scan(weak, handle, Some(vec![id]));
 }
 
+/// One-time scan of a network share: pick the mounted share, register it as
+/// a transient "Network" folder (not a permanent library folder) and scan
+/// it. The next scan that finds the share unreachable drops it again.
+pub fn scan_network_share(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    let h = handle.clone();
+    handle.spawn(async move {
+        let Some(dir) = rfd::AsyncFileDialog::new()
+            .set_title(&qbz_i18n::t("Select network share"))
+            .pick_folder()
+            .await
+        else {
+            return;
+        };
+        let path = dir.path().to_string_lossy().to_string();
+        let id = tokio::task::spawn_blocking(move || {
+            crate::library_db::with_db(|db| qbz_library::register_scan_folder(db, &path, false))
+        })
+        .await
+        .ok()
+        .flatten();
+
+        let Some(id) = id else {
+            crate::toast::error_weak(&weak, qbz_i18n::t("Network share is not accessible"));
+            return;
+        };
+        load_folders(weak.clone(), h.clone());
+        run_scan(weak, h, Some(vec![id]));
+    });
+}
+
 /// Request cancellation of the running scan.
 pub fn stop_scan() {
     SCAN_CANCEL.store(true, Ordering::SeqCst);
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use slint::ComponentHandle;
 
-use crate::{AppWindow, LocalLibraryState};
+use crate::{AppWindow, LibraryFoldersState, LocalLibraryState};
 
 #[derive(Serialize, Deserialize)]
 struct Prefs {
@@ -24,6 +24,9 @@ struct Prefs {
     // no ephemeral session is active.
     #[serde(default)]
     ephemeral_folder: Option<String>,
+    // Discogs user whose collection was last synced (Settings > Local Library).
+    #[serde(default)]
+    discogs_username: Option<String>,
 }
 
 impl Default for Prefs {
@@ -33,6 +36,7 @@ impl Default for Prefs {
             tracks_sort: d_default(),
             albums_id_mode: d_folder(),
             ephemeral_folder: None,
+            discogs_username: None,
         }
     }
 }
@@ -70,6 +74,9 @@ pub fn load(window: &AppWindow) {
     s.set_tracks_group_mode(p.tracks_group.into());
     s.set_tracks_sort(p.tracks_sort.into());
     s.set_albums_id_mode(p.albums_id_mode.into());
+    window
+        .global::<LibraryFoldersState>()
+        .set_discogs_username(p.discogs_username.unwrap_or_default().into());
 }
 
 fn write(p: &Prefs) {
@@ -106,3 +113,10 @@ pub fn save_ephemeral_path(path: Option<&str>) {
     p.ephemeral_folder = path.map(|s| s.to_string());
     write(&p);
 }
+
+/// Persist the Discogs username of the last collection sync (read-modify-write).
+pub fn save_discogs_username(username: &str) {
+    let mut p = read();
+    p.discogs_username = Some(username.to_string()).filter(|s| !s.is_empty());
+    write(&p);
+}
@@ -5,9 +5,14 @@
 //! ring, applies the level + search filters, caps to the last 1000 rows, and
 //! pushes `[LogRow]`. `clear` empties the ring; `set-level` / `set-search`
 //! re-filter; `auto-tail` re-runs `refresh` every 1.5s via a `slint::Timer`.
+//! `set-module-level` sets or drops a runtime per-module level override
+//! (`qbz_log::filter`) and echoes the active overrides in `module-levels`.
 //! `copy-all` copies the currently-filtered rows; `copy-bundle` builds a
 //! GitHub-ready diagnostics bundle; `upload` POSTs that bundle to paste.rs and
-//! surfaces the returned URL; `open-log-file` opens the on-disk log.
+//! surfaces the returned URL; `open-log-file` opens the on-disk log and
+//! `open-log-folder` its directory (where the rotated `qbz.log.N` files sit).
+//! When a panic crash report exists (`qbz_log::crash`), `include-crash-report`
+//! appends the newest one to the bundle / upload.
 //!
 //! All log text is redacted at the ring's write choke point; clipboard/upload
 //! paths redact again defensively.
@@ -39,6 +44,9 @@ thread_local! {
 /// diagnostics report (system + live audio + graphics + playback + qconnect).
 pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Handle) {
     let state = window.global::<LogViewerState>();
+    if let Some(path) = qbz_log::install::log_file_path() {
+        state.set_log_path(path.display().to_string().into());
+    }
 
     {
         let weak = window.as_weak();
@@ -51,6 +59,14 @@ pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Han
             rebuild(&weak);
         });
     }
+    {
+        let weak = window.as_weak();
+        // rebuild re-reads the crash dir, hiding the crash-report row.
+        state.on_clear_crash_reports(move || {
+            qbz_log::crash::clear_crash_reports();
+            rebuild(&weak);
+        });
+    }
     {
         let weak = window.as_weak();
         // The new value is already stored in the in-out `filter-level`; rebuild
@@ -93,10 +109,11 @@ pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Han
         let handle = handle.clone();
         let runtime = runtime.clone();
         state.on_copy_bundle(move || {
+            let include_crash = include_crash_report(&weak);
             let weak = weak.clone();
             let runtime = runtime.clone();
             handle.spawn(async move {
-                let bundle = build_share_text(&runtime).await;
+                let bundle = build_share_text(&runtime, include_crash).await;
                 crate::share::copy_to_clipboard(bundle);
                 let _ = weak.upgrade_in_event_loop(|w| {
                     w.global::<LogViewerState>().set_copied(true);
@@ -116,11 +133,12 @@ pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Han
             if let Some(w) = weak.upgrade() {
                 w.global::<LogViewerState>().set_uploading(true);
             }
+            let include_crash = include_crash_report(&weak);
             let weak = weak.clone();
             let runtime = runtime.clone();
             handle.spawn(async move {
-                let bundle = build_share_text(&runtime).await;
-                let url = match reqwest::Client::new()
+                let bundle = build_share_text(&runtime, include_crash).await;
+                let url = match qbz_app::settings::proxy::client()
                     .post("https://paste.rs/")
                     .body(bundle)
                     .send()
@@ -149,6 +167,41 @@ pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Han
             }
         });
     }
+    {
+        state.on_open_log_folder(move || {
+            if let Some(dir) = qbz_log::install::log_dir() {
+                if let Err(e) = open::that(dir) {
+                    log::warn!("[qbz-slint] open log folder failed: {e}");
+                }
+            }
+        });
+    }
+    state.set_module_levels(module_levels_summary().into());
+    {
+        let weak = window.as_weak();
+        state.on_set_module_level(move |module, level| {
+            let module = module.trim().to_string();
+            if module.is_empty() {
+                return;
+            }
+            if level == "default" {
+                qbz_log::filter::clear_module_level(&module);
+            } else {
+                match level.parse::<log::LevelFilter>() {
+                    Ok(level) => qbz_log::filter::set_module_level(&module, level),
+                    Err(_) => {
+                        log::warn!("[qbz-slint] unknown log level {level:?} for {module}");
+                        return;
+                    }
+                }
+            }
+            log::info!("[qbz-slint] log level for {module}: {level}");
+            if let Some(w) = weak.upgrade() {
+                w.global::<LogViewerState>()
+                    .set_module_levels(module_levels_summary().into());
+            }
+        });
+    }
     {
         let weak = window.as_weak();
         state.on_copy_url(move || {
@@ -162,6 +215,15 @@ pub fn install(window: &AppWindow, runtime: Runtime, handle: tokio::runtime::Han
     }
 }
 
+/// The active per-module overrides as `module=level, …` ("" = none).
+fn module_levels_summary() -> String {
+    qbz_log::filter::module_levels()
+        .into_iter()
+        .map(|(module, level)| format!("{module}={}", level.as_str().to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Whether `line` passes the level + search filters currently set on the global.
 /// `level` is the lowercased `filter-level` ("all" = no level filter); `search`
 /// is the lowercased query (empty = no search filter), matched over target +
@@ -174,6 +236,16 @@ fn line_matches(line: &qbz_log::LogLine, level: &str, search: &str) -> bool {
     level_ok && search_ok
 }
 
+/// Whether the user opted to attach the newest crash report. UI thread.
+fn include_crash_report(weak: &slint::Weak<AppWindow>) -> bool {
+    weak.upgrade()
+        .map(|w| {
+            let st = w.global::<LogViewerState>();
+            st.get_crash_report_available() && st.get_include_crash_report()
+        })
+        .unwrap_or(false)
+}
+
 /// Snapshot + filter the ring, cap to the last [`MAX_VIEW_ROWS`], and push the
 /// rows + counters onto `LogViewerState`. Runs on the UI thread.
 fn rebuild(weak: &slint::Weak<AppWindow>) {
@@ -181,6 +253,7 @@ fn rebuild(weak: &slint::Weak<AppWindow>) {
         return;
     };
     let st = w.global::<LogViewerState>();
+    st.set_crash_report_available(!qbz_log::crash::crash_reports().is_empty());
     let level = st.get_filter_level().to_string().to_lowercase();
     let search = st.get_search().to_string().to_lowercase();
 
@@ -256,8 +329,9 @@ fn flash_copied(weak: &slint::Weak<AppWindow>, handle: &tokio::runtime::Handle)
 /// active audio device + graphics + playback + qconnect) followed by the last 200
 /// redacted log lines. This is what makes the uploaded paste complete rather than
 /// "just logs". All log lines are already redacted at the ring's write choke
-/// point; `qbz_log::redact` is applied again defensively.
-async fn build_share_text(runtime: &Runtime) -> String {
+/// point; `qbz_log::redact` is applied again defensively. `include_crash`
+/// appends the newest panic crash report.
+async fn build_share_text(runtime: &Runtime, include_crash: bool) -> String {
     let report = crate::diagnostics::build_full_report(runtime).await;
 
     let lines = qbz_log::ring::snapshot();
@@ -273,5 +347,18 @@ async fn build_share_text(runtime: &Runtime) -> String {
         ));
     }
 
-    format!("{report}\n\n## Recent logs\n\n```log\n{logs}```\n")
+    let crash = if include_crash {
+        qbz_log::crash::latest_crash_report()
+            .map(|json| {
+                format!(
+                    "\n## Latest crash report\n\n```json\n{}\n```\n",
+                    qbz_log::redact(&json)
+                )
+            })
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    format!("{report}\n\n## Recent logs\n\n```log\n{logs}```\n{crash}")
 }
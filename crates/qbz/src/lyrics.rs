@@ -48,8 +48,8 @@ use std::sync::{Arc, Mutex, OnceLock};
 use slint::{ComponentHandle, ModelRc, VecModel};
 
 use qbz_lyrics::{
-    build_cache_key, LyricsData, LyricsDoc, LyricsOutcome, LyricsProvider, LyricsProviders,
-    LyricsRequest, LyricsResponse, LyricsService, LyricsSourceKind,
+    build_cache_key, ExternalLyricsProvider, LyricsData, LyricsDoc, LyricsOutcome, LyricsProvider,
+    LyricsProviders, LyricsRequest, LyricsResponse, LyricsService, LyricsSourceKind,
 };
 use qbz_models::QueueTrack;
 use qbz_qobuz::{QobuzClient, QobuzLyricsDocument};
@@ -64,11 +64,16 @@ pub(crate) const STATUS_READY: i32 = 2;
 const STATUS_NOT_FOUND: i32 = 3;
 const STATUS_ERROR: i32 = 4;
 const STATUS_OFFLINE: i32 = 5;
+const STATUS_INSTRUMENTAL: i32 = 6;
 
 /// Process-global lyrics service. Installed on the first session activation;
 /// the per-user cache handle re-binds via `init_at` on every activation.
 static SERVICE: OnceLock<Arc<LyricsService>> = OnceLock::new();
 
+/// The user's external provider order (`lyrics_prefs`), held here so an
+/// order seeded before the service is installed still applies on install.
+static PROVIDER_ORDER: Mutex<Option<Vec<ExternalLyricsProvider>>> = Mutex::new(None);
+
 /// The shared core client lock, kept for the translation "Auto" resolution
 /// (the account `language_code` lives on the client's session). Installed
 /// alongside [`SERVICE`] on the first session activation.
@@ -153,6 +158,14 @@ impl LyricsProviders for SharedClientProviders {
     async fn ovh(&self, title: &str, artist: &str) -> Option<LyricsData> {
         qbz_lyrics::providers::fetch_lyrics_ovh(title, artist).await
     }
+
+    async fn netease(&self, title: &str, artist: &str) -> Result<Option<LyricsData>, String> {
+        qbz_lyrics::providers::fetch_netease(title, artist).await
+    }
+
+    async fn genius(&self, title: &str, artist: &str) -> Option<LyricsData> {
+        qbz_lyrics::providers::fetch_genius(title, artist).await
+    }
 }
 
 /// Bind the per-user lyrics cache — the SAME `lyrics/lyrics.db` under the
@@ -174,6 +187,9 @@ pub fn init_for_user(client: Arc<tokio::sync::RwLock<Option<QobuzClient>>>, user
             })))
         })
         .clone();
+    if let Some(order) = PROVIDER_ORDER.lock().ok().and_then(|guard| guard.clone()) {
+        service.set_provider_order(order);
+    }
     let Some(cache_dir) = dirs::cache_dir().map(|d| {
         d.join("qbz")
             .join("users")
@@ -378,6 +394,7 @@ pub fn enable_translation(weak: slint::Weak<AppWindow>, track: &QueueTrack) {
         offline: crate::offline_mode::engine().is_offline(),
         // Resolved inside the task (the session read is async).
         language: None,
+        instrumental: false,
     };
     tokio::spawn(async move {
         let Some(target) = resolve_target_language().await else {
@@ -478,10 +495,33 @@ fn request_identity(track_id: Option<u64>, cache_key: &str) -> String {
     }
 }
 
+/// Apply the user's external provider order (enabled providers only).
+/// Called by `lyrics_prefs` on seed and on every Settings edit.
+pub fn set_provider_order(order: Vec<ExternalLyricsProvider>) {
+    if let Ok(mut guard) = PROVIDER_ORDER.lock() {
+        *guard = Some(order.clone());
+    }
+    if let Some(service) = SERVICE.get() {
+        service.set_provider_order(order);
+    }
+}
+
+/// Settings row label for an external provider.
+pub(crate) fn external_provider_label(provider: ExternalLyricsProvider) -> &'static str {
+    match provider {
+        ExternalLyricsProvider::Lrclib => "LRCLIB",
+        ExternalLyricsProvider::NetEase => "NetEase",
+        ExternalLyricsProvider::Genius => "Genius",
+        ExternalLyricsProvider::Ovh => "lyrics.ovh",
+    }
+}
+
 fn provider_label(provider: LyricsProvider) -> &'static str {
     match provider {
         LyricsProvider::Lrclib => "LRCLIB",
         LyricsProvider::Ovh => "lyrics.ovh",
+        LyricsProvider::NetEase => "NetEase",
+        LyricsProvider::Genius => "Genius",
         // First-party — no attribution needed (spec §3.5).
         LyricsProvider::Qobuz => "",
     }
@@ -516,6 +556,7 @@ pub fn prefetch_lyrics(track: &QueueTrack) {
         // Prefetch warms the original-only entry; the translation toggle
         // (UI task) triggers the refetch-with-language when needed.
         language: None,
+        instrumental: false,
     };
     tokio::spawn(async move {
         // Resolution upserts into lyrics.db; the result is discarded (no UI).
@@ -559,6 +600,9 @@ pub fn on_track_changed(weak: slint::Weak<AppWindow>, track: &QueueTrack) {
         // above; enabling it refetches WITH a language via
         // [`enable_translation`].
         language: None,
+        // No vocal/instrumental flag in the queue metadata; the engine's
+        // title/duration heuristic still applies.
+        instrumental: false,
     };
     let key = request_identity(
         request.track_id,
@@ -720,6 +764,12 @@ fn apply_result(weak: slint::Weak<AppWindow>, result: Result<LyricsResponse, Str
                 }
                 (STATUS_OFFLINE, Vec::new(), false, "", "", String::new(), false)
             }
+            LyricsOutcome::Instrumental => {
+                if let Ok(mut doc) = CURRENT_DOC.lock() {
+                    *doc = None;
+                }
+                (STATUS_INSTRUMENTAL, Vec::new(), false, "", "", String::new(), false)
+            }
         },
         Err(e) => {
             if let Ok(mut doc) = CURRENT_DOC.lock() {
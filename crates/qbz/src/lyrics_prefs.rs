@@ -23,14 +23,19 @@
 //!    [`persist_from_ui`] reads the in-out props back and saves.
 //!  - `LyricsState.reset-prefs()` → [`reset`] re-seeds the defaults and
 //!    persists them (Tauri `resetLyricsDisplay`).
+//!  - The external provider order (Settings > Offline > LYRICS) rides the
+//!    same file: [`move_source_up`] / [`set_source_enabled`] edit
+//!    `LyricsState.sources`, then persist and push the enabled order into
+//!    the lyrics service.
 
 use std::path::PathBuf;
 use std::sync::{LazyLock, Mutex};
 
+use qbz_lyrics::ExternalLyricsProvider;
 use serde::{Deserialize, Serialize};
-use slint::ComponentHandle;
+use slint::{ComponentHandle, Model, ModelRc, VecModel};
 
-use crate::{AppWindow, LyricsState};
+use crate::{AppWindow, LyricsSourceItem, LyricsState};
 
 /// The active user id, set by `init_for_user`. `None` before login (the
 /// store degrades to defaults).
@@ -60,6 +65,14 @@ pub struct LyricsPrefs {
     /// fallback) or an explicit ISO 639-1 code from [`TRANSLATION_LANGS`].
     #[serde(default = "d_translation_lang")]
     pub translation_language: String,
+    /// External lyrics provider ids in chain order (Qobuz always runs
+    /// first). Sanitized on load: unknown ids dropped, missing ones appended.
+    #[serde(default = "d_provider_order")]
+    pub provider_order: Vec<String>,
+    /// Provider ids switched off — never queried. The opt-in providers
+    /// start out here.
+    #[serde(default = "d_disabled_providers")]
+    pub disabled_providers: Vec<String>,
 }
 
 fn d_true() -> bool {
@@ -77,6 +90,19 @@ fn d_dimming() -> String {
 fn d_translation_lang() -> String {
     "auto".to_string()
 }
+fn d_provider_order() -> Vec<String> {
+    ExternalLyricsProvider::ALL
+        .iter()
+        .map(|p| p.as_str().to_string())
+        .collect()
+}
+fn d_disabled_providers() -> Vec<String> {
+    ExternalLyricsProvider::ALL
+        .iter()
+        .filter(|p| p.is_opt_in())
+        .map(|p| p.as_str().to_string())
+        .collect()
+}
 
 impl Default for LyricsPrefs {
     /// Tauri defaults (`lyricsDisplayStore.ts:37-44`).
@@ -90,6 +116,8 @@ impl Default for LyricsPrefs {
             uppercase: false,
             lite_fill: false,
             translation_language: d_translation_lang(),
+            provider_order: d_provider_order(),
+            disabled_providers: d_disabled_providers(),
         }
     }
 }
@@ -131,8 +159,44 @@ impl LyricsPrefs {
         if !TRANSLATION_LANGS.contains(&self.translation_language.as_str()) {
             self.translation_language = d_translation_lang();
         }
+        let mut order: Vec<String> = Vec::with_capacity(ExternalLyricsProvider::ALL.len());
+        for id in std::mem::take(&mut self.provider_order) {
+            if ExternalLyricsProvider::from_str(&id).is_some() && !order.contains(&id) {
+                order.push(id);
+            }
+        }
+        let mut disabled: Vec<String> = Vec::new();
+        for id in std::mem::take(&mut self.disabled_providers) {
+            if ExternalLyricsProvider::from_str(&id).is_some() && !disabled.contains(&id) {
+                disabled.push(id);
+            }
+        }
+        // Providers missing from a stored order are appended; an opt-in one
+        // arrives switched off.
+        for provider in ExternalLyricsProvider::ALL {
+            let id = provider.as_str().to_string();
+            if order.contains(&id) {
+                continue;
+            }
+            if provider.is_opt_in() && !disabled.contains(&id) {
+                disabled.push(id.clone());
+            }
+            order.push(id);
+        }
+        self.provider_order = order;
+        self.disabled_providers = disabled;
         self
     }
+
+    /// The providers the service should query, in order (disabled ones
+    /// omitted).
+    pub fn enabled_providers(&self) -> Vec<ExternalLyricsProvider> {
+        self.provider_order
+            .iter()
+            .filter(|id| !self.disabled_providers.contains(id))
+            .filter_map(|id| ExternalLyricsProvider::from_str(id))
+            .collect()
+    }
 }
 
 /// `<data_dir>/qbz/users/<user_id>/lyrics_prefs.json` for the active user.
@@ -242,6 +306,22 @@ pub fn apply_to_ui(window: &AppWindow, prefs: &LyricsPrefs) {
     }
     state.set_lite_fill(prefs.lite_fill);
     state.set_translation_language_index(translation_lang_index(&prefs.translation_language));
+    let sources: Vec<LyricsSourceItem> = prefs
+        .provider_order
+        .iter()
+        .filter_map(|id| ExternalLyricsProvider::from_str(id))
+        .map(|provider| LyricsSourceItem {
+            id: provider.as_str().into(),
+            label: crate::lyrics::external_provider_label(provider).into(),
+            synced: provider.can_sync(),
+            enabled: !prefs
+                .disabled_providers
+                .iter()
+                .any(|id| id == provider.as_str()),
+        })
+        .collect();
+    state.set_sources(ModelRc::new(VecModel::from(sources)));
+    crate::lyrics::set_provider_order(prefs.enabled_providers());
 }
 
 /// Read the in-out props back into a pref set and persist — the
@@ -277,10 +357,50 @@ pub fn persist_from_ui(window: &AppWindow) {
             .copied()
             .unwrap_or("auto")
             .to_string(),
-    };
+        provider_order: state
+            .get_sources()
+            .iter()
+            .map(|source| source.id.to_string())
+            .collect(),
+        disabled_providers: state
+            .get_sources()
+            .iter()
+            .filter(|source| !source.enabled)
+            .map(|source| source.id.to_string())
+            .collect(),
+    }
+    .sanitized();
+    crate::lyrics::set_provider_order(prefs.enabled_providers());
     save(&prefs);
 }
 
+/// Swap the provider at `index` with the one above it, then persist — the
+/// `source-move-up(int)` handler. UI thread only.
+pub fn move_source_up(window: &AppWindow, index: i32) {
+    let state = window.global::<LyricsState>();
+    let mut sources: Vec<LyricsSourceItem> = state.get_sources().iter().collect();
+    let index = index.max(0) as usize;
+    if index == 0 || index >= sources.len() {
+        return;
+    }
+    sources.swap(index - 1, index);
+    state.set_sources(ModelRc::new(VecModel::from(sources)));
+    persist_from_ui(window);
+}
+
+/// Switch one provider on/off, then persist — the
+/// `source-set-enabled(int, bool)` handler. UI thread only.
+pub fn set_source_enabled(window: &AppWindow, index: i32, enabled: bool) {
+    let state = window.global::<LyricsState>();
+    let sources = state.get_sources();
+    let Some(mut source) = sources.row_data(index.max(0) as usize) else {
+        return;
+    };
+    source.enabled = enabled;
+    sources.set_row_data(index as usize, source);
+    persist_from_ui(window);
+}
+
 /// Restore + persist the Tauri defaults — the `reset-prefs()` handler
 /// (Tauri `resetLyricsDisplay`). UI thread only.
 pub fn reset(window: &AppWindow) {
@@ -303,6 +423,7 @@ mod tests {
         assert_eq!(p.active_color, "");
         assert!(!p.uppercase);
         assert_eq!(p.translation_language, "auto");
+        assert_eq!(p.enabled_providers(), ExternalLyricsProvider::defaults());
     }
 
     #[test]
@@ -322,6 +443,8 @@ mod tests {
             uppercase: true,
             lite_fill: false,
             translation_language: "klingon".into(),
+            provider_order: vec!["ovh".into(), "bogus".into(), "ovh".into()],
+            disabled_providers: vec!["bogus".into(), "genius".into()],
         }
         .sanitized();
         assert!(!p.auto_follow); // bools pass through
@@ -332,6 +455,10 @@ mod tests {
         assert_eq!(p.active_color, "");
         // Unknown translation language falls back to Auto.
         assert_eq!(p.translation_language, "auto");
+        // Unknown + duplicate provider ids dropped, missing ones appended.
+        assert_eq!(p.provider_order, vec!["ovh", "lrclib", "netease", "genius"]);
+        // netease was missing, so it arrives switched off (opt-in).
+        assert_eq!(p.disabled_providers, vec!["genius", "netease"]);
     }
 
     #[test]
@@ -345,6 +472,13 @@ mod tests {
             uppercase: false,
             lite_fill: true,
             translation_language: "it".into(),
+            provider_order: vec![
+                "genius".into(),
+                "lrclib".into(),
+                "ovh".into(),
+                "netease".into(),
+            ],
+            disabled_providers: vec!["ovh".into()],
         }
         .sanitized();
         assert_eq!(p.font, "line-seed-jp");
@@ -352,6 +486,14 @@ mod tests {
         assert_eq!(p.dimming, "off");
         assert_eq!(p.active_color, "#8b5cf6");
         assert_eq!(p.translation_language, "it");
+        assert_eq!(
+            p.enabled_providers(),
+            vec![
+                ExternalLyricsProvider::Genius,
+                ExternalLyricsProvider::Lrclib,
+                ExternalLyricsProvider::NetEase,
+            ]
+        );
     }
 
     #[test]
@@ -17,6 +17,7 @@
 pub use qbz_ui::*;
 
 mod about;
+mod accounts;
 mod adapter;
 mod album;
 mod album_map;
@@ -30,6 +31,7 @@ mod auto_theme;
 mod award;
 mod blacklist_manager;
 mod booklet;
+mod bookmarks;
 mod commands;
 mod custom_artwork;
 mod custom_theme;
@@ -64,6 +66,7 @@ mod link_resolver;
 mod miniplayer;
 mod location_view;
 mod mix;
+mod musicbrainz_cache;
 mod musician;
 mod myqbz;
 mod myqbz_add;
@@ -80,6 +83,7 @@ mod pinned;
 mod pinned_section;
 mod play_history;
 mod playback;
+mod proxy_settings;
 mod qconnect_engine;
 mod cast_service;
 mod qconnect_event_sink;
@@ -87,10 +91,14 @@ mod qconnect_service;
 mod qconnect_transport;
 mod queue;
 mod remote_stream;
+mod replaygain_tags;
 mod drag;
+mod embedded_cue;
 mod ephemeral;
+mod folder_cover;
 mod folders;
 mod library_db;
+mod listening_stats;
 mod local_favorites;
 mod local_library;
 mod local_playlist;
@@ -111,6 +119,7 @@ mod visualizer;
 mod offline_manager;
 mod offline_mode;
 mod playlist;
+mod playlist_audio;
 mod playlist_browse;
 mod playlist_import;
 mod playlist_manager;
@@ -137,6 +146,7 @@ mod shader_underlay;
 mod settings;
 mod share;
 mod sidebar;
+mod stream_fallback;
 mod suggestions;
 mod theme;
 pub use qbz_slint_common::toast;
@@ -145,6 +155,7 @@ mod tray_settings;
 mod ui_prefs;
 mod viewport;
 mod ui_watchdog;
+mod updates;
 mod whats_new;
 
 use std::sync::Arc;
@@ -342,6 +353,28 @@ fn seed_blacklist_status(w: &AppWindow) {
     st.set_enabled(crate::artist_blacklist::is_enabled());
 }
 
+/// Shell-side teardown shared by logout and the account switch, run before
+/// `auth::logout`: drop the Discord activity, park pending deep links until
+/// the next `enter_shell`, forget the previous user's purchases (cache and
+/// download statuses — a cross-account leak otherwise), and end any live
+/// cast session and its position poll.
+async fn leave_shell(handle: &tokio::runtime::Handle) {
+    discord_rpc::clear(handle);
+    deep_link::clear_shell_ctx();
+    purchases::reset_ui_cache();
+    if let Some(cast) = cast_service::service() {
+        cast.shutdown().await;
+    }
+}
+
+/// Back to the login screen with the shell state of the signed-out user
+/// cleared. UI thread.
+fn show_login_screen(w: &AppWindow) {
+    w.global::<NavState>().set_view(ContentView::Home);
+    w.global::<SessionState>().set_user_name("".into());
+    w.set_screen(AppScreen::Login);
+}
+
 /// Reveal the shell and load the Discover / Home view with real data,
 /// then kick off cached artwork downloads.
 async fn enter_shell(
@@ -740,6 +773,8 @@ async fn enter_shell_offline(
         if let Ok(store) = qbz_reco::ArtistVectorStore::open_at(&dir) {
             runtime.core().set_artist_vectors(store).await;
         }
+        // Per-user MusicBrainz cache (artist metadata + relationships).
+        crate::musicbrainz_cache::init_for_user(runtime.core(), &dir);
         crate::discover_prefs::init_for_user(&dir);
         // D-FIX-a: bind the blacklist offline too — Tauri never initialized it
         // in offline mode, so blacklisted artists leaked into offline surfaces.
@@ -2485,6 +2520,7 @@ fn navigate_album(
                 // Carousel inputs, captured before `data` is moved into apply.
                 let carousel_artist_id = data.artist_id.clone();
                 let carousel_artist_name = data.artist.clone();
+                let work_isrcs = album::work_isrcs(&data.raw_tracks);
                 // A user-set custom cover (keyed by album id) wins and is the
                 // only image source for albums with no Qobuz cover. Same bug
                 // class as the artist portrait fix.
@@ -2494,6 +2530,22 @@ fn navigate_album(
                     w.global::<AlbumState>().set_loading(false);
                 });
 
+                // Classical album: resolve every work-tagged track to its
+                // MusicBrainz recording in one batched pass, so Track Info's
+                // work lookups on this album skip the per-track ISRC search.
+                if !work_isrcs.is_empty() {
+                    let runtime = runtime.clone();
+                    tokio::spawn(async move {
+                        if !runtime.core().musicbrainz_is_enabled().await {
+                            return;
+                        }
+                        if let Err(e) = runtime.core().musicbrainz_resolve_tracks(&work_isrcs).await
+                        {
+                            log::debug!("[qbz-slint] album MusicBrainz batch resolve failed: {e}");
+                        }
+                    });
+                }
+
                 // Polish carousels — "From the same artist" + "Listening
                 // suggestions". Qobuz-only (this is the Qobuz album path; local
                 // albums load through navigate_local_album), best-effort: each
@@ -3017,7 +3069,7 @@ fn navigate_search(
     // Capture a version so a slow, stale load cannot overwrite a newer
     // search's results (the user kept typing).
     let version = search::next_search_version();
-    handle.spawn(async move {
+    let task = handle.spawn(async move {
         let _ = weak.upgrade_in_event_loop(|w| {
             search::reset_search(&w);
             w.global::<NavState>().set_view(ContentView::Search);
@@ -3043,6 +3095,8 @@ fn navigate_search(
             }
         }
     });
+    // A newer search supersedes this one: drop the older request in flight.
+    search::track_live_load(search::LiveSurface::Results, task.abort_handle());
 }
 
 /// Apply a history entry — set the view and re-load entity pages.
@@ -4935,6 +4989,13 @@ fn reseed_i18n_labels(window: &AppWindow) {
         t("Play next"),
         t("Add to queue"),
     ])));
+    state.set_search_debounce_options(ModelRc::new(VecModel::from(vec![
+        t("Default"),
+        t("150 ms"),
+        t("300 ms"),
+        t("500 ms"),
+        t("800 ms"),
+    ])));
     state.set_immersive_default_views(ModelRc::new(VecModel::from(vec![
         t("Remember last"),
         t("Album Reactive"),
@@ -7090,6 +7151,33 @@ fn arm_startup_probe() {
     }
 }
 
+/// Toast once per new crash report: the newest `crash-*.json` left by a
+/// panic in an earlier run that hasn't been announced yet (remembered in
+/// ui_prefs, so a report isn't re-announced every start until cleared).
+fn announce_new_crash_report(window: &AppWindow) {
+    let Some(newest) = qbz_log::crash::crash_reports().into_iter().next() else {
+        return;
+    };
+    let name = newest
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut prefs = crate::ui_prefs::load();
+    if prefs.last_crash_report_seen == name {
+        return;
+    }
+    prefs.last_crash_report_seen = name;
+    crate::ui_prefs::save(&prefs);
+    log::warn!(
+        "[crash] previous run panicked — report at {}",
+        newest.display()
+    );
+    crate::toast::warning(
+        window,
+        qbz_i18n::t("QBZ crashed last time — a crash report was saved (see the log viewer)"),
+    );
+}
+
 fn clear_startup_probe() {
     if let Some(path) = startup_probe_path() {
         let _ = std::fs::remove_file(path);
@@ -8100,10 +8188,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         factor
     };
 
+    // HTTP PROXY — same constraint as the scale preset: the stored proxy is
+    // exported as HTTP(S)_PROXY / NO_PROXY before any thread spawns, so every
+    // reqwest client built afterwards (Qobuz, integrations, cast) uses it.
+    proxy_settings::install_at_startup();
+
     // Composite logger: stderr (unchanged) + a bounded in-memory ring + an on-disk
     // file, all redacted at the write choke point. Feeds the in-app log viewer and
     // the diagnostics bundle. Honours RUST_LOG (default "info").
     qbz_log::install("info");
+    // Panic -> `crash-{timestamp}.json` (message + the ring's tail), surfaced
+    // on the next start and attachable from the log viewer.
+    qbz_log::crash::install_panic_hook();
     if ui_scale_factor != 1.0 {
         log::info!(
             "[ui-scale] preset factor {ui_scale_factor} -> SLINT_SCALE_FACTOR={}",
@@ -8367,6 +8463,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ),
         );
     }
+    announce_new_crash_report(&window);
     install_browser_mouse_nav(&window);
     wire_window_controls(&window);
     // Immersive-exit fullscreen guard: the fullscreen toggle only exists in
@@ -8477,6 +8574,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     window
         .global::<AppearanceState>()
         .set_intelligent_search(crate::ui_prefs::load().intelligent_search);
+    {
+        let debounce_ms = crate::ui_prefs::load().search_debounce_ms;
+        search::set_live_debounce_ms(debounce_ms);
+        window
+            .global::<AppearanceState>()
+            .set_search_debounce_index(crate::ui_prefs::search_debounce_index(debounce_ms));
+    }
     // Appearance toggles that used to be live-only (no persistence): seed the
     // live globals from the persisted prefs so the user's choice survives a
     // restart. Their Rust handlers now persist via on_appearance_bool/select.
@@ -8650,6 +8754,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Inert (tap disabled, no capture / no FFT cost) until the immersive view
     // opens. Must run on the UI thread before window.run().
     visualizer::install(&window, &app_runtime);
+    {
+        let sensitivity = crate::ui_prefs::load().beat_sensitivity;
+        window.global::<AppearanceState>().set_beat_sensitivity_index(
+            crate::ui_prefs::beat_sensitivity_index(&sensitivity),
+        );
+        window
+            .global::<VisualizerState>()
+            .invoke_set_beat_threshold(crate::ui_prefs::beat_threshold(&sensitivity));
+    }
 
     // Prime the FFT tap if we restored straight into Large with the visualizer ON.
     // This MUST run AFTER visualizer::install() — install() registers the
@@ -8702,26 +8815,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         log::info!("[shader] software renderer active — wgpu shader underlay disabled");
     }
 
-    // MusicBrainz cache — opens a SQLite store at
-    // <data-dir>/qbz/cache/musicbrainz_cache.db so artist metadata
-    // and relationships persist across sessions (matches Tauri's
-    // MusicBrainzCache init path). Failure to open just degrades to
-    // direct network calls — the methods skip the cache when none
-    // is set.
-    if let Some(data_dir) = dirs::data_dir() {
-        let cache_dir = data_dir.join("qbz").join("cache");
-        if let Err(e) = std::fs::create_dir_all(&cache_dir) {
-            log::warn!("[qbz-slint] MB cache dir create failed: {e}");
-        } else {
-            let db_path = cache_dir.join("musicbrainz_cache.db");
-            match qbz_integrations::musicbrainz::cache::MusicBrainzCache::new(&db_path) {
-                Ok(cache) => {
-                    app_runtime.core().set_musicbrainz_cache(cache);
-                    log::info!("[qbz-slint] MB cache opened at {db_path:?}");
-                }
-                Err(e) => log::warn!("[qbz-slint] MB cache open failed: {e}"),
-            }
-        }
+    // The MusicBrainz cache is per user: opened on session activation
+    // (musicbrainz_cache::init_for_user), closed on logout.
+    {
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<SettingsState>().on_musicbrainz_clear_cache(move || {
+            let runtime = runtime.clone();
+            let weak = weak.clone();
+            handle.spawn_blocking(move || musicbrainz_cache::clear(runtime.core(), &weak));
+        });
     }
 
     // MusicBrainz opt-out seed — drive the core client's enabled flag from the
@@ -9332,6 +9436,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             // bytes, so a 2-char multibyte query (e.g. CJK) is not rejected.
             if q.chars().count() < 2 {
                 SEARCH_DEBOUNCE.with(|t| t.stop());
+                CORTINILLA_DEBOUNCE.with(|t| t.stop());
+                search::cancel_live_load(search::LiveSurface::Cortinilla);
                 // Below the threshold — close the cortinilla so a backspaced
                 // query does not leave a stale dropdown open.
                 if let Some(w) = weak.upgrade() {
@@ -9381,13 +9487,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     CORTINILLA_DEBOUNCE.with(|t| {
                         t.start(
                             slint::TimerMode::SingleShot,
-                            std::time::Duration::from_millis(220),
+                            search::live_debounce(search::LiveSurface::Cortinilla),
                             move || {
                                 let runtime = runtime.clone();
                                 let weak = weak.clone();
                                 let image_cache = image_cache.clone();
                                 let q = q.clone();
-                                handle.spawn(async move {
+                                let task = handle.spawn(async move {
                                     match search::load_cortinilla(&runtime, &q, expand_local).await {
                                         Ok((data, local_rows)) => {
                                             let jobs = search::cortinilla_artwork_jobs(&data);
@@ -9423,6 +9529,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         }
                                     }
                                 });
+                                search::track_live_load(
+                                    search::LiveSurface::Cortinilla,
+                                    task.abort_handle(),
+                                );
                             },
                         );
                     });
@@ -9442,7 +9552,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 SEARCH_DEBOUNCE.with(|t| t.stop());
                 return;
             }
-            // --- Results page (module OFF): debounce 300 ms, then full search ---
+            // --- Results page (module OFF): debounce (300 ms built-in), then full search ---
             let runtime = runtime.clone();
             let weak = weak.clone();
             let handle = handle.clone();
@@ -9450,7 +9560,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             SEARCH_DEBOUNCE.with(|t| {
                 t.start(
                     slint::TimerMode::SingleShot,
-                    std::time::Duration::from_millis(300),
+                    search::live_debounce(search::LiveSurface::Results),
                     move || {
                         // Record (or replace) the Search history entry so
                         // back/forward returns to this search instead of
@@ -10097,6 +10207,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // query (CJK) is not rejected.
                 if q.chars().count() < 2 {
                     IMMERSIVE_SEARCH_DEBOUNCE.with(|t| t.stop());
+                    search::cancel_live_load(search::LiveSurface::Immersive);
                     // Below the threshold — close the dropdown so a backspaced
                     // query does not leave a stale one open.
                     w.global::<ImmersiveState>().set_search_open(false);
@@ -10130,13 +10241,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 IMMERSIVE_SEARCH_DEBOUNCE.with(|t| {
                     t.start(
                         slint::TimerMode::SingleShot,
-                        std::time::Duration::from_millis(220),
+                        search::live_debounce(search::LiveSurface::Immersive),
                         move || {
                             let runtime = runtime.clone();
                             let weak = weak.clone();
                             let image_cache = image_cache.clone();
                             let q = q.clone();
-                            handle.spawn(async move {
+                            let task = handle.spawn(async move {
                                 match search::load_immersive_search(&runtime, &q, expand_local).await {
                                     Ok(data) => {
                                         let jobs =
@@ -10174,6 +10285,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     }
                                 }
                             });
+                            search::track_live_load(
+                                search::LiveSurface::Immersive,
+                                task.abort_handle(),
+                            );
                         },
                     );
                 });
@@ -10509,6 +10624,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             });
     }
+    window.global::<LinkResolverActions>().on_open_url(|url| {
+        if let Err(e) = open::that(url.as_str()) {
+            log::warn!("[qbz-slint] open platform link failed ({url}): {e}");
+        }
+    });
     {
         let runtime = app_runtime.clone();
         let weak = window.as_weak();
@@ -10524,6 +10644,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 s.set_resolving(true);
                 s.set_error("".into());
                 s.set_playlist_detected(false);
+                s.set_other_links(slint::ModelRc::default());
             }
             let runtime = runtime.clone();
             let weak = weak.clone();
@@ -10549,10 +10670,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             s.set_playlist_detected(true);
                             s.set_playlist_provider(provider.into());
                         }
-                        Ok(qbz_music_link::MusicLinkResult::NotOnQobuz { .. }) => {
+                        Ok(qbz_music_link::MusicLinkResult::NotOnQobuz { links, .. }) => {
                             s.set_error(
                                 qbz_i18n::t("This content is not available on Qobuz").into(),
                             );
+                            let items: Vec<PlatformLinkItem> = link_resolver::other_links(&links)
+                                .into_iter()
+                                .map(|(label, url)| PlatformLinkItem {
+                                    label: label.into(),
+                                    url: url.into(),
+                                })
+                                .collect();
+                            s.set_other_links(slint::ModelRc::new(slint::VecModel::from(items)));
                         }
                         Err(e) => {
                             log::warn!("[qbz-slint] open-link resolve failed: {e}");
@@ -10607,31 +10736,109 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let weak = window.as_weak();
         let handle = tokio_rt.handle().clone();
         window.on_logout(move || {
-            // Drop any Discord "now listening" activity on logout.
-            discord_rpc::clear(&handle);
-            // Back at the login screen a pending deep link must wait for the
-            // next enter_shell, not fire into the torn-down session.
-            deep_link::clear_shell_ctx();
-            // Clear the per-user purchases cache + download-status store so the
-            // next account never sees the previous user's purchased items or
-            // in-flight download statuses (cross-account data leak).
-            purchases::reset_ui_cache();
             let runtime = runtime.clone();
             let weak = weak.clone();
-            handle.spawn(async move {
-                // Tear down any live cast session + poll (fixes the Tauri
-                // logout leak where the connection + position interval lived on).
-                if let Some(cast) = cast_service::service() {
-                    cast.shutdown().await;
-                }
+            let handle = handle.clone();
+            handle.clone().spawn(async move {
+                leave_shell(&handle).await;
                 if let Err(e) = auth::logout(&runtime).await {
                     log::error!("[qbz-slint] logout failed: {e}");
                 }
-                let _ = weak.upgrade_in_event_loop(|w| {
-                    w.global::<NavState>().set_view(ContentView::Home);
-                    w.global::<SessionState>().set_user_name("".into());
-                    w.set_screen(AppScreen::Login);
-                });
+                let _ = weak.upgrade_in_event_loop(|w| show_login_screen(&w));
+            });
+        });
+    }
+
+    // HTTP proxy fields (Settings > Integrations > NETWORK): seed on section
+    // mount, persist on Save (applied on the next start).
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<ProxyState>()
+            .on_load(move || proxy_settings::load(weak.clone(), &handle));
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<ProxyState>().on_save(move || {
+            if let Some(w) = weak.upgrade() {
+                proxy_settings::save(&w, &handle);
+            }
+        });
+    }
+
+    // Saved Qobuz accounts (Settings > Integrations > QOBUZ ACCOUNTS and the
+    // header account menu). Seeded once now so the menu lists them before
+    // the settings section is ever opened.
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        accounts::load(weak.clone(), &handle);
+        window
+            .global::<AccountsState>()
+            .on_load(move || accounts::load(weak.clone(), &handle));
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<AccountsState>().on_save(move || {
+            if let Some(w) = weak.upgrade() {
+                accounts::save(&w, runtime.clone(), &handle);
+            }
+        });
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<AccountsState>()
+            .on_remove(move |id| accounts::remove(weak.clone(), &handle, id.to_string()));
+    }
+
+    // Account switch: the same teardown as logout, then a password sign-in
+    // as the chosen saved account and a fresh shell entry. A failed sign-in
+    // lands on the login screen with the error, like a failed restore.
+    {
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let image_cache = image_cache.clone();
+        let settings_ctx = settings_ctx.clone();
+        window.global::<AccountsState>().on_switch_to(move |id| {
+            let account_id = id.to_string();
+            let email = match weak.upgrade() {
+                Some(w) => {
+                    w.global::<AccountsState>().set_switching(true);
+                    accounts::email_for(&w, &account_id)
+                }
+                None => return,
+            };
+            let runtime = runtime.clone();
+            let weak = weak.clone();
+            let handle = handle.clone();
+            let image_cache = image_cache.clone();
+            let settings_ctx = settings_ctx.clone();
+            handle.clone().spawn(async move {
+                leave_shell(&handle).await;
+                match auth::switch_account(&runtime, &account_id).await {
+                    Ok(session) => {
+                        let _ = weak.upgrade_in_event_loop(move |w| {
+                            let state = w.global::<AccountsState>();
+                            state.set_switching(false);
+                            state.set_current_email(email.into());
+                            w.global::<NavState>().set_view(ContentView::Home);
+                        });
+                        enter_shell(runtime, weak, image_cache, settings_ctx, session).await;
+                    }
+                    Err(e) => {
+                        log::error!("[qbz-slint] account switch failed: {e}");
+                        let _ = weak.upgrade_in_event_loop(move |w| {
+                            let state = w.global::<AccountsState>();
+                            state.set_switching(false);
+                            state.set_current_email("".into());
+                            let login_state = w.global::<LoginState>();
+                            login_state.set_phase(0);
+                            login_state.set_error(e.into());
+                            show_login_screen(&w);
+                        });
+                    }
+                }
             });
         });
     }
@@ -10734,6 +10941,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    // Settings — Undo last change: restore the most recent audio-settings
+    // checkpoint and re-init the device on it.
+    {
+        let runtime = app_runtime.clone();
+        let settings_ctx = settings_ctx.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.on_settings_rollback(move || {
+            let runtime = runtime.clone();
+            let settings_ctx = settings_ctx.clone();
+            let weak = weak.clone();
+            handle.spawn(async move {
+                settings::handle_rollback(settings_ctx, runtime, weak).await;
+            });
+        });
+    }
+
     // Settings — the output-device refresh/release button: free a device QBZ
     // holds exclusively (ALSA Direct) and re-enumerate, so a freed or
     // hot-plugged DAC reappears without an app restart.
@@ -10752,6 +10976,47 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    // Settings > Audio — the bandwidth "Measure" button: probe the link and
+    // show the result (also refreshes the adaptive-quality cache).
+    {
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<BandwidthActions>().on_measure(move || {
+            let runtime = runtime.clone();
+            let weak = weak.clone();
+            handle.spawn(async move {
+                settings::handle_measure_bandwidth(runtime, weak).await;
+            });
+        });
+    }
+
+    // ALSA hot-plug: watch /dev/snd so a yanked USB DAC moves playback to the
+    // default device instead of leaving the audio thread on a dead handle,
+    // and the output-device list follows plug/unplug. Held for the app
+    // lifetime; dropping the watcher stops notifications.
+    #[cfg(target_os = "linux")]
+    let _device_watcher = {
+        let runtime = app_runtime.clone();
+        let settings_ctx = settings_ctx.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        match qbz_audio::watch_pcm_devices(move |event| {
+            let runtime = runtime.clone();
+            let settings_ctx = settings_ctx.clone();
+            let weak = weak.clone();
+            handle.spawn(async move {
+                settings::handle_device_hotplug(settings_ctx, runtime, weak, event).await;
+            });
+        }) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                log::warn!("[qbz-slint] audio hot-plug detection unavailable: {e}");
+                None
+            }
+        }
+    };
+
     // Settings > Developer — "Export settings…" modal confirm: build the
     // settings bundle via the shared engine, open a native save dialog, write
     // it 0600, and toast the import command (04 §4.2). No new export logic.
@@ -10762,6 +11027,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             settings::export_settings(weak.clone(), handle.clone());
         });
     }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<SettingsExportActions>().on_show_qr(move || {
+            settings::export_settings_qr(weak.clone(), handle.clone());
+        });
+    }
 
     // Settings > Offline MODE — re-seed the toggle states on panel mount
     // (the panel's init fires load), and the status row's "Check now"
@@ -10774,6 +11046,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             offline_mode::seed_settings(weak.clone(), handle.clone());
         });
     }
+    // Settings > Playback LISTENING — re-read the stats on panel mount and
+    // on a period change.
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<ListeningStatsState>()
+            .on_load(move |index| {
+                listening_stats::load(weak.clone(), handle.clone(), index.max(0) as usize);
+            });
+    }
     {
         let weak = window.as_weak();
         let handle = tokio_rt.handle().clone();
@@ -10982,6 +11265,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     crate::ui_prefs::immersive_default_view_for_index(index).to_string();
                 crate::ui_prefs::save(&prefs);
             }
+            "beat-sensitivity" => {
+                // 0 = Low, 1 = Normal, 2 = High. Live: the FFT producer reads
+                // the tap's threshold every frame.
+                let key = crate::ui_prefs::beat_sensitivity_for_index(index);
+                let mut prefs = crate::ui_prefs::load();
+                prefs.beat_sensitivity = key.to_string();
+                crate::ui_prefs::save(&prefs);
+                if let Some(w) = theme_weak.upgrade() {
+                    w.global::<VisualizerState>()
+                        .invoke_set_beat_threshold(crate::ui_prefs::beat_threshold(key));
+                }
+            }
             "app-background" => {
                 // 0 = Off, 1 = Ambient (GPU shader), 2 = Blurred art. The Slint
                 // side already flipped app-background-mode-index; app-shader-mode
@@ -10992,6 +11287,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     crate::ui_prefs::app_background_for_index(index).to_string();
                 crate::ui_prefs::save(&prefs);
             }
+            "search-debounce" => {
+                let ms = crate::ui_prefs::search_debounce_for_index(index);
+                let mut prefs = crate::ui_prefs::load();
+                prefs.search_debounce_ms = ms;
+                crate::ui_prefs::save(&prefs);
+                search::set_live_debounce_ms(ms);
+            }
             "miniplayer-view" => {
                 // 0 = Remember last, 1-5 = micro / compact / artwork / queue /
                 // lyrics. The miniplayer reads this key on open (miniplayer.rs).
@@ -11138,6 +11440,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // persist the key and regenerate from the new source.
                 crate::auto_theme::set_source(index, theme_weak.clone(), theme_handle.clone());
             }
+            "auto-theme-transition" => {
+                // Palette cross-fade length (Off / 200 / 400 / 800 ms):
+                // persisted in graphics settings, used by the next regenerate.
+                crate::auto_theme::set_transition_duration(
+                    crate::auto_theme::transition_ms_for_index(index),
+                    theme_handle.clone(),
+                );
+            }
             "theme-filter" => {
                 // Theme-list filter cycle (0 = All, 1 = Dark, 2 = Light): persist
                 // the choice, then rebuild the dropdown to show only matching
@@ -11922,24 +12232,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     // Tauri-parity resolution (#514): fetch the album to get
                     // its UPC, then UPC -> Deezer -> album.link. The old
                     // URL-only Odesli call never worked for Qobuz input
-                    // (could_not_resolve_entity) — see share.rs.
+                    // (could_not_resolve_entity) — see share.rs. Falls back
+                    // to the first track's Song.link.
                     let album = id.clone();
                     let runtime = runtime.clone();
                     let weak = weak.clone();
                     crate::toast::info_weak(&weak, qbz_i18n::t("Fetching Album.link..."));
                     handle.spawn(async move {
-                        let upc = runtime
-                            .core()
-                            .get_album(&album)
-                            .await
-                            .ok()
-                            .and_then(|a| a.upc);
-                        match share::albumlink_for_album(&album, upc.as_deref()).await {
-                            Some(url) => {
+                        let fetched = runtime.core().get_album(&album).await.ok();
+                        match share::album_share_link(&album, fetched.as_ref()).await {
+                            Some(share::AlbumShareLink::Album(url)) => {
                                 share::copy_to_clipboard(url);
                                 log::info!("[qbz-slint] copied Album.link for album {album}");
                                 crate::toast::success_weak(&weak, qbz_i18n::t("Link copied"));
                             }
+                            Some(share::AlbumShareLink::FirstTrack(url)) => {
+                                share::copy_to_clipboard(url);
+                                log::info!("[qbz-slint] copied first-track Song.link for album {album}");
+                                crate::toast::info_weak(
+                                    &weak,
+                                    qbz_i18n::t("No Album.link found; copied the first track's Song.link"),
+                                );
+                            }
                             None => {
                                 log::warn!("[qbz-slint] Album.link resolution failed for {album}");
                                 crate::toast::error_weak(
@@ -12446,6 +12760,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         format!("{name} is now hidden")
                                     };
                                     crate::toast::success_weak(&weak, msg);
+                                    if !was_blacklisted {
+                                        playback::apply_blacklist_to_queue();
+                                    }
                                 }
                                 Err(e) => {
                                     log::error!(
@@ -12496,6 +12813,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         qbz_i18n::t_args("Album \"{}\" blocked", &[&title])
                                     };
                                     crate::toast::success_weak(&weak, msg);
+                                    if !was_blocked {
+                                        playback::apply_blacklist_to_queue();
+                                    }
                                 }
                                 Err(e) => {
                                     log::error!(
@@ -12610,6 +12930,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // else (search/home/label pages); future paints exclude it via
                 // the §B filter.
                 ("artist", "not-interested") => {
+                    // Also a taste signal: the dislike steers the home seeds
+                    // and keeps the artist out of smart radio pools.
+                    if id.parse::<u64>().is_ok() {
+                        let item_id = id.clone();
+                        handle.spawn_blocking(move || {
+                            crate::reco::log_dislike(
+                                qbz_app::settings::reco_store::RecoItemType::Artist,
+                                &item_id,
+                            );
+                        });
+                    }
                     if let Some(w) = weak.upgrade() {
                         let snapshot =
                             crate::external_reco::apply_artist_dismissal(&w, &image_cache, &id);
@@ -13651,6 +13982,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // from QConnect above; routes by source, never QConnect admission.
     {
         let _cast = cast_service::init_service(app_runtime.clone(), window.as_weak());
+        window
+            .global::<CastState>()
+            .set_fallback_enabled(crate::ui_prefs::load().cast_fallback);
         let handle = tokio_rt.handle().clone();
         window.global::<CastActions>().on_open(move || {
             let Some(svc) = cast_service::service() else {
@@ -13735,6 +14069,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
             });
     }
+    {
+        // Cast failure policy: persist only — the next failed cast reads it.
+        window
+            .global::<CastActions>()
+            .on_set_fallback_enabled(move |enabled| {
+                let mut prefs = crate::ui_prefs::load();
+                prefs.cast_fallback = enabled;
+                crate::ui_prefs::save(&prefs);
+            });
+    }
     {
         let weak = window.as_weak();
         window
@@ -13915,6 +14259,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
             });
     }
+    {
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        window
+            .global::<NowPlayingState>()
+            .on_add_bookmark(move || bookmarks::add_at_position(&runtime, &weak));
+    }
+    {
+        let weak = window.as_weak();
+        window
+            .global::<NowPlayingState>()
+            .on_remove_bookmark(move |index| {
+                if index >= 0 {
+                    bookmarks::remove(&weak, index as usize);
+                }
+            });
+    }
     {
         let runtime = app_runtime.clone();
         let weak = window.as_weak();
@@ -14147,6 +14508,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let c = controller.clone();
             qs.on_save_as_playlist(move || c.save_as_playlist());
         }
+        {
+            let c = controller.clone();
+            qs.on_export_m3u(move || c.export_m3u());
+        }
         {
             let c = controller.clone();
             qs.on_toggle_infinite_play(move || c.toggle_infinite_play());
@@ -14175,6 +14540,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         // Developer panel: in-app log viewer + the full diagnostics panel.
         log_viewer::install(&window, app_runtime.clone(), tokio_rt.handle().clone());
+        playlist_audio::install(&window, app_runtime.clone());
         diagnostics::install(&window, app_runtime.clone(), tokio_rt.handle().clone());
         // Report-an-issue: "Create issue report" opens the GitHub new-issue page.
         window.global::<ReportIssueActions>().on_create_issue(|| {
@@ -14185,6 +14551,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
         // About QBZ (static seed + open-url) and What's New (fetch on open).
         about::install(&window, tokio_rt.handle().clone());
+        updates::install(&window, tokio_rt.handle().clone());
         whats_new::install(&window, tokio_rt.handle().clone());
         {
             let c = controller.clone();
@@ -14308,6 +14675,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             lyrics::clear_cache(&handle, weak.clone());
         });
     }
+    {
+        // Settings > Offline external provider order: reorder / switch off,
+        // persisted with the lyrics prefs and applied to the service live.
+        let weak = window.as_weak();
+        window.global::<LyricsState>().on_source_move_up(move |index| {
+            if let Some(w) = weak.upgrade() {
+                lyrics_prefs::move_source_up(&w, index);
+            }
+        });
+        let weak = window.as_weak();
+        window
+            .global::<LyricsState>()
+            .on_source_set_enabled(move |index, enabled| {
+                if let Some(w) = weak.upgrade() {
+                    lyrics_prefs::set_source_enabled(&w, index, enabled);
+                }
+            });
+    }
 
     // S4 lyrics sync engine — a UI-thread `slint::Timer` driving
     // `LyricsState.active-index` / `line-progress` at ~30Hz while the panel
@@ -16124,6 +16509,37 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 });
             });
     }
+    {
+        // download-zip — folder pick → start_album_zip_download.
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<PurchaseDetailActions>()
+            .on_download_zip(move || {
+                let runtime = runtime.clone();
+                let weak = weak.clone();
+                let handle2 = handle.clone();
+                let album_id = purchases::detail_album_id();
+                let Some(fmt_id) = purchases::detail_selected_format_id() else {
+                    return;
+                };
+                let Some(fmt_label) = purchases::detail_selected_format_label() else {
+                    return;
+                };
+                if album_id.is_empty() {
+                    return;
+                }
+                handle.spawn(async move {
+                    let Some(dest) = purchases::pick_download_folder().await else {
+                        return;
+                    };
+                    purchases::start_album_zip_download(
+                        runtime, weak, handle2, album_id, fmt_id, fmt_label, dest,
+                    );
+                });
+            });
+    }
     {
         // download-track(id) — folder pick → startTrackDownload (§2.2.7 trackId
         // branch). Single-track MERGE into the album state (§A.7).
@@ -16337,7 +16753,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 handle.spawn(async move {
                     let result = runtime
                         .core()
-                        .create_smart_track_radio(tid, aid, track_name)
+                        .create_smart_track_radio(
+                            tid,
+                            aid,
+                            track_name,
+                            crate::reco::disliked_artist_ids(),
+                        )
                         .await;
                     suggestions::set_radio_loading(&weak, false);
                     match result {
@@ -17237,6 +17658,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             });
     }
+    {
+        let runtime = app_runtime.clone();
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<GenreFilterActions>()
+            .on_play_radio(move || {
+                let [genre_id] = genre_filter::selected_ids()[..] else {
+                    return;
+                };
+                // Five seeds: enough spread across the genre's artists
+                // without a long run of album fetches before playback.
+                playback::play_genre_radio(
+                    runtime.clone(),
+                    weak.clone(),
+                    handle.clone(),
+                    genre_id,
+                    5,
+                );
+            });
+    }
     {
         let runtime = app_runtime.clone();
         let weak = window.as_weak();
@@ -17811,6 +18253,81 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 local_library_settings::cleanup_missing(weak.clone(), handle.clone())
             });
     }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_cleanup_thumbnails(move || {
+                local_library_settings::cleanup_orphan_thumbnails(weak.clone(), handle.clone())
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let runtime = app_runtime.clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_fetch_missing_artwork(move || {
+                local_library_settings::fetch_missing_artwork(
+                    weak.clone(),
+                    handle.clone(),
+                    runtime.clone(),
+                )
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let runtime = app_runtime.clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_enrich_isrcs(move || {
+                local_library_settings::enrich_isrcs_from_musicbrainz(
+                    weak.clone(),
+                    handle.clone(),
+                    runtime.clone(),
+                )
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_write_folder_covers(move || folder_cover::write_all(weak.clone(), handle.clone()));
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_deduplicate(move |strategy| {
+                local_library_settings::deduplicate(weak.clone(), handle.clone(), strategy)
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_merge_split_albums(move || {
+                local_library_settings::merge_split_albums(weak.clone(), handle.clone())
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_sync_discogs(move |username| {
+                local_library_settings::sync_discogs_collection(
+                    weak.clone(),
+                    handle.clone(),
+                    username.to_string(),
+                )
+            });
+    }
     {
         let weak = window.as_weak();
         let handle = tokio_rt.handle().clone();
@@ -17842,6 +18359,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 local_library_settings::scan_folder(weak.clone(), handle.clone(), id as i64)
             });
     }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<LibraryManageActions>()
+            .on_scan_network_share(move || {
+                local_library_settings::scan_network_share(weak.clone(), handle.clone())
+            });
+    }
     {
         window
             .global::<LibraryManageActions>()
@@ -18003,6 +18529,55 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .global::<ScrobbleActions>()
             .on_lastfm_disconnect(move || scrobble::lastfm_disconnect(weak.clone()));
     }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let runtime = app_runtime.clone();
+        window
+            .global::<ScrobbleActions>()
+            .on_listenbrainz_import_history(move || {
+                scrobble::listenbrainz_import_history(
+                    runtime.clone(),
+                    weak.clone(),
+                    handle.clone(),
+                    scrobble::LB_IMPORT_MAX_LISTENS,
+                )
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<ScrobbleActions>()
+            .on_listenbrainz_flush_queue(move || {
+                scrobble::listenbrainz_flush_queue(weak.clone(), handle.clone())
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let runtime = app_runtime.clone();
+        window
+            .global::<ScrobbleActions>()
+            .on_spotify_import_history(move || {
+                scrobble::spotify_import_history(
+                    runtime.clone(),
+                    weak.clone(),
+                    handle.clone(),
+                    scrobble::LB_IMPORT_MAX_LISTENS,
+                )
+            });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let runtime = app_runtime.clone();
+        window
+            .global::<ScrobbleActions>()
+            .on_lastfm_sync_loved(move |dry_run| {
+                scrobble::lastfm_sync_loved(runtime.clone(), weak.clone(), handle.clone(), dry_run)
+            });
+    }
     {
         let weak = window.as_weak();
         window
@@ -18147,6 +18722,60 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         });
     }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<LocalAlbumActions>().on_write_replaygain(move || {
+            if let Some(w) = weak.upgrade() {
+                let ids = local_library::current_album_version_tracks(&w)
+                    .iter()
+                    .map(|t| t.id)
+                    .collect();
+                replaygain_tags::write_album(weak.clone(), handle.clone(), ids);
+            }
+        });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<LocalAlbumActions>().on_import_replaygain(move || {
+            if let Some(w) = weak.upgrade() {
+                let ids = local_library::current_album_version_tracks(&w)
+                    .iter()
+                    .map(|t| t.id)
+                    .collect();
+                replaygain_tags::import_album(weak.clone(), handle.clone(), ids);
+            }
+        });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        let image_cache = image_cache.clone();
+        window.global::<LocalAlbumActions>().on_split_embedded_cue(move || {
+            if let Some(w) = weak.upgrade() {
+                let ids = local_library::current_album_version_tracks(&w)
+                    .iter()
+                    .filter(|t| t.cue_file_path.is_none())
+                    .map(|t| t.id)
+                    .collect();
+                embedded_cue::expand_album(weak.clone(), handle.clone(), image_cache.clone(), ids);
+            }
+        });
+    }
+    {
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window.global::<LocalAlbumActions>().on_write_folder_cover(move || {
+            if let Some(w) = weak.upgrade() {
+                let paths = local_library::current_album_version_tracks(&w)
+                    .into_iter()
+                    .map(|t| std::path::PathBuf::from(t.file_path))
+                    .collect();
+                folder_cover::write_album(weak.clone(), handle.clone(), paths);
+            }
+        });
+    }
     {
         let runtime = app_runtime.clone();
         let weak = window.as_weak();
@@ -18230,6 +18859,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             ),
                         }
                     }
+                    "link-qobuz-stream" => {
+                        // Plain local rows only (the menu gates the entry):
+                        // match the row on Qobuz and store the match as the
+                        // stream played when the file can't be opened.
+                        stream_fallback::link_track(
+                            runtime.clone(),
+                            weak.clone(),
+                            handle.clone(),
+                            row.clone(),
+                        );
+                    }
                     "go-to-album" | "go-to-artist" => {
                         // Owner improvement over Tauri — source-routed in
                         // local_row_goto. On this surface "Go to album"
@@ -19715,6 +20355,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if let Some(w) = weak.upgrade() {
                     let st = w.global::<PlaylistPickerState>();
                     st.set_open(false);
+                    st.set_from_queue(false);
                     // Reset the inline-create + filter affordances so the next
                     // open starts clean.
                     st.set_creating_open(false);
@@ -19746,6 +20387,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     return;
                 }
                 let is_local = picker.get_local_mode();
+                let from_queue = picker.get_from_queue();
                 let ids_model = picker.get_track_ids();
                 let track_id_single = picker.get_track_id().to_string();
                 // Local-mode refs (LocalLibrary row ids / "plex:<key>") for the
@@ -19840,6 +20482,53 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     return;
                 }
 
+                // Queue save-as-playlist online ⇒ the core export: it takes the
+                // whole queue, leaves out what a Qobuz playlist can't hold and
+                // cleans up after a failed add. The new playlist opens.
+                if from_queue {
+                    handle.spawn(async move {
+                        let result = runtime.core().export_queue_as_qobuz_playlist(&nm).await;
+                        let r2 = runtime.clone();
+                        let h2 = handle2.clone();
+                        let weak2 = weak.clone();
+                        let _ = weak.upgrade_in_event_loop(move |w| {
+                            let st = w.global::<PlaylistPickerState>();
+                            st.set_creating(false);
+                            let summary = match result {
+                                Ok(summary) => summary,
+                                Err(e) => {
+                                    log::error!("[qbz-slint] queue export failed: {e}");
+                                    crate::toast::error(
+                                        &w,
+                                        qbz_i18n::t("Could not save the queue as a playlist"),
+                                    );
+                                    return;
+                                }
+                            };
+                            st.set_creating_open(false);
+                            st.set_create_name("".into());
+                            st.set_open(false);
+                            st.set_from_queue(false);
+                            toast_added_tracks(&weak2, summary.exported, nm);
+                            if summary.skipped_local > 0 {
+                                crate::toast::info_weak(
+                                    &weak2,
+                                    qbz_i18n::tf(
+                                        "{} track not on Qobuz was left out",
+                                        "{} tracks not on Qobuz were left out",
+                                        summary.skipped_local as i64,
+                                        &[&summary.skipped_local.to_string()],
+                                    ),
+                                );
+                            }
+                            load_sidebar_playlists(r2, weak2, &h2);
+                            w.global::<SidebarActions>()
+                                .invoke_open_playlist(summary.playlist_id.to_string().into());
+                        });
+                    });
+                    return;
+                }
+
                 // Online ⇒ Qobuz playlist, then add the carried tracks.
                 handle.spawn(async move {
                     match runtime.core().create_playlist(&nm, None, false).await {
@@ -21306,6 +21995,34 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 playlist_import::on_name_edited(text.as_str());
             });
     }
+    {
+        let weak = window.as_weak();
+        window.global::<PlaylistImportActions>().on_cancel(move || {
+            if let Some(w) = weak.upgrade() {
+                playlist_import::cancel(&w);
+            }
+        });
+    }
+    {
+        // "Import from file…": the picked path lands in the URL field and
+        // goes through the same detection as a typed one.
+        let weak = window.as_weak();
+        let handle = tokio_rt.handle().clone();
+        window
+            .global::<PlaylistImportActions>()
+            .on_browse_file(move || {
+                let weak = weak.clone();
+                handle.spawn(async move {
+                    let Some(path) = playlist_import::pick_csv_file().await else {
+                        return;
+                    };
+                    let _ = weak.upgrade_in_event_loop(move |w| {
+                        w.global::<PlaylistImportState>().set_url(path.as_str().into());
+                        playlist_import::on_url_edited(&w, &path);
+                    });
+                });
+            });
+    }
     {
         // Step A: fetch the preview (no session needed).
         let weak = window.as_weak();
@@ -21320,7 +22037,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let generation = playlist_import::current_generation();
             let weak = weak.clone();
             handle.spawn(async move {
-                let res = qbz_playlist_import::preview_public_playlist(&url).await;
+                let res = playlist_import::preview(&url).await;
                 let _ = weak.upgrade_in_event_loop(move |w| {
                     if generation != playlist_import::current_generation() {
                         return;
@@ -21371,7 +22088,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     let sink: Arc<dyn qbz_playlist_import::ImportProgressSink> = Arc::new(
                         playlist_import::SlintSink::new(weak.clone(), args.generation),
                     );
-                    let res = qbz_playlist_import::import_public_playlist(
+                    let res = playlist_import::import(
                         &args.url,
                         &client,
                         args.name_override.as_deref(),
@@ -21433,6 +22150,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             });
                         }
+                        Err(qbz_playlist_import::PlaylistImportError::Cancelled) => {
+                            let g = args.generation;
+                            let _ = weak.upgrade_in_event_loop(move |w| {
+                                if g == playlist_import::current_generation() {
+                                    playlist_import::apply_execute_cancelled(&w);
+                                }
+                                toast::show(
+                                    &w,
+                                    qbz_i18n::t("Playlist import cancelled"),
+                                    ToastKind::Info,
+                                );
+                            });
+                        }
                         Err(e) => {
                             let g = args.generation;
                             let msg = e.to_string();
@@ -5,10 +5,16 @@
 //! module owns the process-global handle and bridges inbound control events
 //! (media keys, the GNOME/KDE media widget, macOS Now Playing) to the player.
 //! Playback metadata/state is pushed from `playback.rs` (mirroring the tray).
+//! While playing, a dedicated task also republishes the position at a
+//! configurable interval, so widgets polling MPRIS `Position` see it advance
+//! smoothly instead of jumping between play/pause edges.
 
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-use qbz_media_controls::{MediaEvent, MediaIntegration};
+use qbz_media_controls::{LoopMode, MediaEvent, MediaIntegration, TrackMeta};
+use qbz_models::RepeatMode;
 
 use crate::adapter::SlintAdapter;
 use crate::AppWindow;
@@ -17,6 +23,14 @@ use qbz_app::shell::AppRuntime;
 type Runtime = Arc<AppRuntime<SlintAdapter>>;
 
 static CONTROLS: OnceLock<Box<dyn MediaIntegration>> = OnceLock::new();
+/// How often the position task republishes the playing position (ms).
+static POSITION_UPDATE_INTERVAL_MS: AtomicU32 = AtomicU32::new(DEFAULT_POSITION_UPDATE_MS);
+
+pub const DEFAULT_POSITION_UPDATE_MS: u32 = 1000;
+/// Bounds for [`set_position_update_interval`]: below 100 ms the D-Bus
+/// traffic buys nothing, above 10 s the position is visibly stale.
+const MIN_POSITION_UPDATE_MS: u32 = 100;
+const MAX_POSITION_UPDATE_MS: u32 = 10_000;
 
 /// The live integration, if it started. Playback pushes metadata/state through
 /// it (`set_metadata`/`set_playback`).
@@ -37,11 +51,43 @@ pub fn init(runtime: Runtime, weak: slint::Weak<AppWindow>, handle: tokio::runti
         Some(c) => {
             let _ = CONTROLS.set(c);
             log::info!("[media-controls] integration started");
+            set_position_update_interval(crate::ui_prefs::load().mpris_position_update_ms);
+            handle.spawn(position_updates(runtime.clone()));
         }
         None => log::info!("[media-controls] no integration on this platform"),
     }
 }
 
+/// Set how often the playing position is republished (the
+/// `v2_set_mpris_position_update_interval` equivalent). Clamped to
+/// 100..=10000 ms; takes effect on the next tick. Called at startup with the
+/// persisted value and by the Settings > Playback dropdown.
+pub fn set_position_update_interval(ms: u32) {
+    let ms = ms.clamp(MIN_POSITION_UPDATE_MS, MAX_POSITION_UPDATE_MS);
+    POSITION_UPDATE_INTERVAL_MS.store(ms, Ordering::Relaxed);
+    log::info!("[media-controls] position update interval set to {ms} ms");
+}
+
+/// Republish the local player's position while it plays, independently of
+/// the playback poll loop. Paused/stopped edges are still pushed by
+/// `playback.rs`; this only keeps `Position` fresh in between.
+async fn position_updates(runtime: Runtime) {
+    loop {
+        let ms = POSITION_UPDATE_INTERVAL_MS.load(Ordering::Relaxed);
+        tokio::time::sleep(Duration::from_millis(ms as u64)).await;
+        let Some(mc) = handle() else {
+            continue;
+        };
+        let state = runtime.core().get_playback_state();
+        if state.is_playing {
+            mc.set_playback(
+                qbz_media_controls::PlaybackStatus::Playing,
+                Some(Duration::from_secs(state.position)),
+            );
+        }
+    }
+}
+
 fn dispatch(ev: MediaEvent, rt: Runtime, weak: slint::Weak<AppWindow>, h: tokio::runtime::Handle) {
     match ev {
         // The OS only sends Play when paused and Pause when playing (it reads
@@ -66,9 +112,98 @@ fn dispatch(ev: MediaEvent, rt: Runtime, weak: slint::Weak<AppWindow>, h: tokio:
         MediaEvent::SetVolume(v) => crate::playback::set_volume(rt, weak, h, v as f32),
         MediaEvent::SetPosition(micros) => seek_to_micros(rt, h, micros),
         MediaEvent::SeekBy(delta_micros) => seek_by_micros(rt, h, delta_micros),
+        MediaEvent::PlayUpcoming(index) => {
+            h.spawn(async move {
+                let Some(track) = rt.core().play_upcoming_at(index).await else {
+                    log::warn!("[media-controls] go-to upcoming {index} miss");
+                    return;
+                };
+                crate::playback::after_track_change(&rt, &weak, track.id).await;
+                crate::playback::refresh_sidebar(false);
+            });
+        }
+        MediaEvent::RemoveUpcoming(index) => {
+            h.spawn(async move {
+                rt.core().remove_upcoming_track(index).await;
+                crate::playback::refresh_sidebar(false);
+            });
+        }
+        MediaEvent::SetLoopStatus(mode) => crate::playback::set_repeat(rt, weak, h, repeat_mode(mode)),
+        MediaEvent::SetShuffle(enabled) => crate::playback::set_shuffle(rt, weak, h, enabled),
+        MediaEvent::AddTrack {
+            uri,
+            next,
+            play_now,
+        } => {
+            let Some(track_id) = qbz_media_controls::track_id_from_uri(&uri) else {
+                log::warn!("[media-controls] add-track: unsupported uri {uri}");
+                return;
+            };
+            // SetAsCurrent plays the track straight away, like every other
+            // "play now" entry point.
+            if play_now {
+                crate::playback::play_track_now(rt, weak, h, track_id);
+            } else if next {
+                crate::playback::play_track_next(rt, weak, h, track_id);
+            } else {
+                crate::playback::enqueue_track(rt, weak, h, track_id);
+            }
+        }
     }
 }
 
+/// Reflect a core repeat-mode change in the MPRIS `LoopStatus` property.
+pub fn publish_repeat(mode: RepeatMode) {
+    if let Some(mc) = handle() {
+        mc.set_loop_status(loop_mode(mode));
+    }
+}
+
+/// Reflect a core shuffle change in the MPRIS `Shuffle` property.
+pub fn publish_shuffle(enabled: bool) {
+    if let Some(mc) = handle() {
+        mc.set_shuffle(enabled);
+    }
+}
+
+fn loop_mode(mode: RepeatMode) -> LoopMode {
+    match mode {
+        RepeatMode::Off => LoopMode::None,
+        RepeatMode::One => LoopMode::Track,
+        RepeatMode::All => LoopMode::Playlist,
+    }
+}
+
+fn repeat_mode(mode: LoopMode) -> RepeatMode {
+    match mode {
+        LoopMode::None => RepeatMode::Off,
+        LoopMode::Track => RepeatMode::One,
+        LoopMode::Playlist => RepeatMode::All,
+    }
+}
+
+/// Publish the queue (current + upcoming) as the MPRIS track list. Called
+/// from the adapter on every `QueueUpdated`.
+pub fn publish_queue(state: &qbz_models::QueueState) {
+    let Some(mc) = handle() else {
+        return;
+    };
+    let tracks: Vec<TrackMeta> = state
+        .current_track
+        .iter()
+        .chain(state.upcoming.iter())
+        .map(|t| TrackMeta {
+            title: t.title.clone(),
+            artist: t.artist.clone(),
+            album: t.album.clone(),
+            duration: (t.duration_secs > 0)
+                .then(|| std::time::Duration::from_secs(t.duration_secs)),
+            art_url: t.artwork_url.clone(),
+        })
+        .collect();
+    mc.set_track_list(&tracks);
+}
+
 fn seek_to_micros(rt: Runtime, h: tokio::runtime::Handle, micros: i64) {
     let spawn_h = h.clone();
     h.spawn(async move {
@@ -0,0 +1,49 @@
+//! Per-user MusicBrainz cache.
+//!
+//! The cache lives at `<data_dir>/qbz/users/<user_id>/cache/musicbrainz.db`
+//! and is installed on the core when a user's session activates (online or
+//! offline) and closed on logout, so switching accounts never serves one
+//! user's resolutions to another. It used to be a single global
+//! `<data_dir>/qbz/cache/musicbrainz_cache.db`; that file is left alone and
+//! the per-user cache simply starts empty. Failing to open it just degrades
+//! to direct network calls: the core skips the cache when none is set.
+
+use std::path::Path;
+
+use qbz_core::{FrontendAdapter, QbzCore};
+
+use crate::AppWindow;
+
+/// Open `<user_dir>/cache/musicbrainz.db` and install it on the core.
+pub fn init_for_user<A>(core: &QbzCore<A>, user_dir: &Path)
+where
+    A: FrontendAdapter + Send + Sync + 'static,
+{
+    let cache_dir = user_dir.join("cache");
+    if let Err(e) = std::fs::create_dir_all(&cache_dir) {
+        log::warn!("[qbz-slint] MB cache dir create failed: {e}");
+        return;
+    }
+    let db_path = cache_dir.join("musicbrainz.db");
+    match qbz_integrations::musicbrainz::cache::MusicBrainzCache::new(&db_path) {
+        Ok(cache) => {
+            core.set_musicbrainz_cache(cache);
+            log::info!("[qbz-slint] MB cache opened at {db_path:?}");
+        }
+        Err(e) => log::warn!("[qbz-slint] MB cache open failed: {e}"),
+    }
+}
+
+/// Settings action: empty the current user's cache.
+pub fn clear<A>(core: &QbzCore<A>, weak: &slint::Weak<AppWindow>)
+where
+    A: FrontendAdapter + Send + Sync + 'static,
+{
+    match core.musicbrainz_clear_cache() {
+        Ok(()) => crate::toast::success_weak(weak, qbz_i18n::t("MusicBrainz cache cleared")),
+        Err(e) => {
+            log::error!("[qbz-slint] MB cache clear failed: {e}");
+            crate::toast::error_weak(weak, qbz_i18n::t("Failed to clear MusicBrainz cache"));
+        }
+    }
+}
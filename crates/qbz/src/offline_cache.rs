@@ -11,6 +11,7 @@
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex as StdMutex, OnceLock};
 
+use qbz_app::offline_mode::{detect_network_type, wifi_required};
 use qbz_app::shell::AppRuntime;
 use qbz_offline_cache::{CacheEvent, CacheEventSink, OfflineCacheStatus, TrackCacheInfo};
 
@@ -155,6 +156,30 @@ fn track_cache_info(track: &qbz_models::Track) -> TrackCacheInfo {
     }
 }
 
+/// Wi-Fi-only gate (Settings > Offline): `false`, with a toast, when the
+/// user limited downloads to Wi-Fi and the default route is not a Wi-Fi link
+/// (the `WifiRequired` refusal). Detection shells out to `ip`/`iw`, so it
+/// only runs when the setting is on.
+async fn wifi_allows_download(weak: &slint::Weak<AppWindow>) -> bool {
+    let blocked = tokio::task::spawn_blocking(|| {
+        let wifi_only = crate::offline_mode::engine()
+            .settings()
+            .map(|s| s.wifi_only_download)
+            .unwrap_or(false);
+        wifi_only && wifi_required(wifi_only, detect_network_type())
+    })
+    .await
+    .unwrap_or(false);
+    if blocked {
+        log::info!("[qbz-slint] cache: not on Wi-Fi, download refused (wifi-only)");
+        crate::toast::error_weak(
+            weak,
+            qbz_i18n::t("Downloads are limited to Wi-Fi — connect to Wi-Fi or change this in Settings > Offline"),
+        );
+    }
+    !blocked
+}
+
 /// Cache a single track for offline playback. Fetches the track metadata,
 /// pre-flights the cache limit, inserts the queued row, and spawns the
 /// download (CMAF-first) with a row-updating sink.
@@ -169,6 +194,9 @@ pub fn cache_track(
             crate::toast::error_weak(&weak, qbz_i18n::t("Log in to cache tracks offline"));
             return;
         };
+        if !wifi_allows_download(&weak).await {
+            return;
+        }
         let track = match runtime.core().get_track(id).await {
             Ok(t) => t,
             Err(e) => {
@@ -234,6 +262,9 @@ pub fn cache_tracks(
             crate::toast::error_weak(&weak, qbz_i18n::t("Log in to cache tracks offline"));
             return;
         };
+        if !wifi_allows_download(&weak).await {
+            return;
+        }
         // Pre-flight once for the whole batch (mirrors Tauri).
         {
             let limit = *off.limit_bytes.lock().await;
@@ -406,6 +437,9 @@ pub fn redownload_track(
         let Some(off) = crate::offline::get().await else {
             return;
         };
+        if !wifi_allows_download(&weak).await {
+            return;
+        }
         {
             let guard = off.db.lock().await;
             let Some(db) = guard.as_ref() else {
@@ -448,6 +482,9 @@ pub fn redownload_album(
         let Some(off) = crate::offline::get().await else {
             return;
         };
+        if !wifi_allows_download(&weak).await {
+            return;
+        }
         let targets: Vec<u64> = {
             let guard = off.db.lock().await;
             let Some(db) = guard.as_ref() else {
@@ -18,7 +18,10 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use slint::ComponentHandle;
 
-use qbz_app::offline_mode::{Connectivity, ConnectivityActor, OfflineMode, OfflineModeEngine, OfflineStatus};
+use qbz_app::offline_mode::{
+    detect_network_type, Connectivity, ConnectivityActor, NetworkType, OfflineMode,
+    OfflineModeEngine, OfflineStatus,
+};
 use qbz_app::settings::subscription::SubscriptionStateStore;
 use qbz_app::user_data::UserDataPaths;
 
@@ -83,14 +86,16 @@ pub fn check_now(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle) {
     });
 }
 
-/// Seed the Settings > Offline MODE toggle state from the persisted
-/// engine store. Fired by the panel's `init` (`OfflineModeActions.load`),
+/// Seed the Settings > Offline MODE toggle and the Wi-Fi-only download row
+/// (with the detected connection type) from the persisted engine store. Fired by the panel's `init` (`OfflineModeActions.load`),
 /// so every mount of Settings > Offline re-reads it — the same lazy-load
 /// hook LocalLibrarySettings uses. Best-effort: pre-session reads (no
 /// store bound) keep the defaults.
 pub fn seed_settings(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle) {
     handle.spawn(async move {
-        let settings = match tokio::task::spawn_blocking(|| engine().settings()).await {
+        let read =
+            tokio::task::spawn_blocking(|| engine().settings().map(|s| (s, detect_network_type())));
+        let (settings, network) = match read.await {
             Ok(Ok(s)) => s,
             Ok(Err(e)) => {
                 log::warn!("[qbz-slint] offline mode settings read failed: {e}");
@@ -102,12 +107,23 @@ pub fn seed_settings(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handl
             }
         };
         let _ = weak.upgrade_in_event_loop(move |w| {
-            w.global::<SettingsState>()
-                .set_offline_mode_enabled(settings.manual_offline_mode);
+            let state = w.global::<SettingsState>();
+            state.set_offline_mode_enabled(settings.manual_offline_mode);
+            state.set_wifi_only_download(settings.wifi_only_download);
+            state.set_network_type(network_type_label(network).into());
         });
     });
 }
 
+fn network_type_label(network: NetworkType) -> String {
+    match network {
+        NetworkType::Wifi => qbz_i18n::t("Wi-Fi"),
+        NetworkType::Ethernet => qbz_i18n::t("Ethernet"),
+        NetworkType::Cellular => qbz_i18n::t("Mobile data"),
+        NetworkType::Unknown => qbz_i18n::t("Unknown"),
+    }
+}
+
 /// Mirror every engine status change into the `OfflineState` Slint global
 /// (login affordances + the D2 recovery banner read it). Also seeds
 /// `has-previous-session` once; `enter_shell` refreshes it after a
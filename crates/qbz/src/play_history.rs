@@ -8,6 +8,11 @@
 //! both at once and turns them into the (qobuz_ids, normalized_names)
 //! pair that filters MB candidates and validated Qobuz matches.
 //!
+//! Two more tables track the artist-vector rebuild fed from this history:
+//! `vector_artists` remembers the MusicBrainz id each played artist
+//! resolved to (NULL = no match), and `history_meta` the artist count at the
+//! last rebuild, so the next one only resolves and builds the new artists.
+//!
 //! SQLite is opened lazily once, and every read/write swallows errors
 //! into a `log::warn!`. A fresh user (no DB yet) yields empty sets,
 //! which simply means no exclusion is applied — same default Tauri
@@ -15,6 +20,7 @@
 
 use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Mutex, OnceLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -24,6 +30,12 @@ use qbz_core::normalize_artist_name;
 
 static DB: OnceLock<Mutex<Option<Connection>>> = OnceLock::new();
 
+/// Rows in `artist_names`: counted once at open, then bumped by
+/// [`record_play`] when it adds an artist.
+static ARTIST_COUNT: AtomicUsize = AtomicUsize::new(0);
+/// `history_meta.vector_rebuild_artists`, cached.
+static REBUILD_ARTIST_COUNT: AtomicUsize = AtomicUsize::new(0);
+
 fn db_path() -> Option<PathBuf> {
     Some(dirs::data_dir()?.join("qbz").join("play_history.db"))
 }
@@ -63,11 +75,34 @@ fn open_db() -> Option<Connection> {
             name TEXT NOT NULL,
             updated_at INTEGER NOT NULL
         );
+
+        CREATE TABLE IF NOT EXISTS vector_artists (
+            artist_id INTEGER PRIMARY KEY,
+            mbid TEXT,
+            resolved_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS history_meta (
+            key TEXT PRIMARY KEY,
+            value INTEGER NOT NULL
+        );
         "#,
     ) {
         log::warn!("[qbz-slint] play_history schema failed: {e}");
         return None;
     }
+    let count = |sql: &str| {
+        conn.query_row(sql, [], |row| row.get::<_, i64>(0))
+            .unwrap_or(0)
+            .max(0) as usize
+    };
+    let artists = count("SELECT COUNT(*) FROM artist_names");
+    let at_rebuild = count(
+        "SELECT COALESCE(MAX(value), 0) FROM history_meta
+         WHERE key = 'vector_rebuild_artists'",
+    );
+    ARTIST_COUNT.store(artists, Ordering::Relaxed);
+    REBUILD_ARTIST_COUNT.store(at_rebuild, Ordering::Relaxed);
     Some(conn)
 }
 
@@ -99,16 +134,24 @@ pub fn record_play(artist_id: u64, artist_name: &str) {
         ) {
             log::warn!("[qbz-slint] play_history insert event failed: {e}");
         }
-        if let Err(e) = conn.execute(
-            r#"
-            INSERT INTO artist_names (artist_id, name, updated_at)
-            VALUES (?, ?, ?)
-            ON CONFLICT(artist_id) DO UPDATE SET
-                name = excluded.name,
-                updated_at = excluded.updated_at
-            "#,
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO artist_names (artist_id, name, updated_at) VALUES (?, ?, ?)",
             params![artist_id as i64, artist_name, now],
-        ) {
+        );
+        let result = match inserted {
+            Ok(0) => conn
+                .execute(
+                    "UPDATE artist_names SET name = ?, updated_at = ? WHERE artist_id = ?",
+                    params![artist_name, now, artist_id as i64],
+                )
+                .map(|_| ()),
+            Ok(_) => {
+                ARTIST_COUNT.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
             log::warn!("[qbz-slint] play_history upsert name failed: {e}");
         }
         Some(())
@@ -153,3 +196,79 @@ pub fn known_artists(threshold: u32) -> (HashSet<u64>, HashSet<String>) {
     });
     pair.unwrap_or_default()
 }
+
+/// Number of distinct artists with at least one recorded play (no query:
+/// kept current by [`record_play`]).
+pub fn artist_count() -> usize {
+    with_db(|_| Some(ARTIST_COUNT.load(Ordering::Relaxed))).unwrap_or(0)
+}
+
+/// [`artist_count`] as of the last artist-vector rebuild (0 = never).
+pub fn vector_rebuild_artist_count() -> usize {
+    with_db(|_| Some(REBUILD_ARTIST_COUNT.load(Ordering::Relaxed))).unwrap_or(0)
+}
+
+/// Played artists as `(artist_id, name, mbid)`, most played first, capped at
+/// `limit`. `mbid` is what an earlier rebuild resolved the artist to; `None`
+/// marks an artist not seen by a rebuild yet. Artists a rebuild could not
+/// resolve are left out.
+pub fn top_artists(limit: usize) -> Vec<(u64, String, Option<String>)> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT a.artist_id, a.name, v.mbid
+                FROM artist_names a
+                JOIN (
+                    SELECT artist_id, COUNT(*) AS play_count
+                    FROM play_events
+                    GROUP BY artist_id
+                ) p ON p.artist_id = a.artist_id
+                LEFT JOIN vector_artists v ON v.artist_id = a.artist_id
+                WHERE v.artist_id IS NULL OR v.mbid IS NOT NULL
+                ORDER BY p.play_count DESC
+                LIMIT ?
+                "#,
+            )
+            .ok()?;
+        let rows = stmt
+            .query_map(params![limit as i64], |row| {
+                let id: i64 = row.get(0)?;
+                Ok((
+                    id as u64,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            })
+            .ok()?;
+        Some(rows.flatten().collect())
+    })
+    .unwrap_or_default()
+}
+
+/// Remember a finished artist-vector rebuild: what each newly seen artist
+/// resolved to, and the [`artist_count`] it started from.
+pub fn record_vector_rebuild(resolutions: &[(u64, Option<String>)], artist_count: usize) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    with_db(|conn| {
+        for (artist_id, mbid) in resolutions {
+            if let Err(e) = conn.execute(
+                "INSERT OR REPLACE INTO vector_artists (artist_id, mbid, resolved_at) VALUES (?, ?, ?)",
+                params![*artist_id as i64, mbid, now],
+            ) {
+                log::warn!("[qbz-slint] play_history vector artist save failed: {e}");
+            }
+        }
+        if let Err(e) = conn.execute(
+            "INSERT OR REPLACE INTO history_meta (key, value) VALUES ('vector_rebuild_artists', ?)",
+            params![artist_count as i64],
+        ) {
+            log::warn!("[qbz-slint] play_history rebuild marker save failed: {e}");
+        }
+        REBUILD_ARTIST_COUNT.store(artist_count, Ordering::Relaxed);
+        Some(())
+    });
+}
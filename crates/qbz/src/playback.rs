@@ -77,6 +77,47 @@ pub fn apply_plex_quality_to_queue(updates: Vec<(String, Option<u32>, Option<f64
     });
 }
 
+/// Drop blacklisted tracks from the upcoming queue after a blacklist change
+/// (the `v2_queue_apply_blacklist` equivalent). Queue builders already filter
+/// at play time, but tracks queued before the artist or album was blocked
+/// would still play. The current track and the history are left alone. Toasts
+/// the removed count; silent when nothing matched. No-op before the
+/// controller is registered.
+pub fn apply_blacklist_to_queue() {
+    let Some(controller) = QUEUE_CONTROLLER.get() else {
+        return;
+    };
+    let runtime = controller.runtime().clone();
+    let weak = controller.weak().clone();
+    controller.handle().spawn(async move {
+        let upcoming = runtime.core().get_queue_state_full().await.upcoming;
+        // Check the snapshot first so an unaffected queue isn't write-locked.
+        let (_, blocked) = crate::artist_blacklist::filter_queue(upcoming);
+        if blocked.is_empty() {
+            return;
+        }
+        let removed = runtime
+            .core()
+            .remove_upcoming_where(crate::artist_blacklist::is_queue_track_blacklisted)
+            .await
+            .len();
+        log::info!("[qbz-slint] queue: removed {removed} blacklisted upcoming tracks");
+        if removed == 0 {
+            return;
+        }
+        refresh_sidebar(false);
+        crate::toast::info_weak(
+            &weak,
+            qbz_i18n::tf(
+                "Removed {} blacklisted track from the queue",
+                "Removed {} blacklisted tracks from the queue",
+                removed as i64,
+                &[&removed.to_string()],
+            ),
+        );
+    });
+}
+
 /// Shared post-track-change step: update the now-playing card, record the
 /// play in the recently-played store, and start audio for `track_id`.
 /// Used by the queue controller's play paths.
@@ -317,6 +358,11 @@ static WATCHDOG_RECOVER_TRACK: std::sync::atomic::AtomicU64 = std::sync::atomic:
 static WATCHDOG_RECOVERIES: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
 const MAX_WATCHDOG_RECOVERIES: u32 = 2;
 
+/// Skip-silence end of the current local track: `(track_id, end_ms)`. Set
+/// by `apply_silence_bounds`; the poll loop advances to the next track once
+/// the position passes it. `None` = play to the real end.
+static SILENCE_END: std::sync::Mutex<Option<(u64, u64)>> = std::sync::Mutex::new(None);
+
 /// True when a stringified play error means the track cannot play now or
 /// ever at any quality — as opposed to a transient network/server failure
 /// (those are already retried with backoff inside the client and must NOT
@@ -387,6 +433,16 @@ fn local_track_file_exists(track: &QueueTrack) -> bool {
     }
 }
 
+/// The Qobuz track linked to local library row `row_id` as its stream
+/// fallback, if any. Ephemeral tracks have no library row. Same blocking
+/// caveat as [`local_track_file_exists`]: one indexed library-DB read.
+fn local_stream_fallback(row_id: u64) -> Option<u64> {
+    if crate::ephemeral::is_ephemeral_id(row_id as i64) {
+        return None;
+    }
+    crate::library_db::with_db(|db| db.get_stream_fallback(row_id as i64)).flatten()
+}
+
 /// Decide whether `track` can play under the CURRENT offline status.
 /// Local / ephemeral user files → existence-checked regardless of
 /// online/offline (the library never hides network-folder content — see
@@ -400,7 +456,12 @@ fn local_track_file_exists(track: &QueueTrack) -> bool {
 ///   → offline-cached AND within the D4 subscription grace window
 fn offline_playability(track: &QueueTrack) -> OfflinePlayability {
     if matches!(track.source.as_deref(), Some("local") | Some("ephemeral")) {
-        return if local_track_file_exists(track) {
+        // A missing file with a linked Qobuz stream still plays while online
+        // (`play_stream_fallback`).
+        return if local_track_file_exists(track)
+            || (!crate::offline_mode::engine().is_offline()
+                && local_stream_fallback(track.id).is_some())
+        {
             OfflinePlayability::Playable
         } else {
             OfflinePlayability::FileMissing
@@ -546,7 +607,11 @@ async fn try_infinite_refill(
             return false;
         }
     };
-    match runtime.core().create_smart_artist_radio(artist_id).await {
+    match runtime
+        .core()
+        .create_smart_artist_radio(artist_id, crate::reco::disliked_artist_ids())
+        .await
+    {
         // play_tracks replaces the spent queue and starts the radio; it already
         // drops blacklisted tracks, so its `false` means nothing playable.
         Ok(tracks) if !tracks.is_empty() => play_tracks(
@@ -626,7 +691,7 @@ async fn play_audible(runtime: &Runtime, weak: &slint::Weak<AppWindow>, track_id
     if let Some(cast) = crate::cast_service::service() {
         if cast.is_casting().await {
             if let Some(qt) = runtime.core().current_track().await {
-                if let Err(e) = cast.cast_track(&qt).await {
+                if let Err(e) = cast.cast_track_with_fallback(&qt).await {
                     log::warn!("[Cast] play new track {track_id} failed: {e}");
                     crate::toast::show_weak(weak, qbz_i18n::t("Failed to cast track"), crate::ToastKind::Error);
                 }
@@ -877,6 +942,9 @@ async fn play_local_file_audible(
         .await
         .unwrap_or(false);
         if !exists {
+            if play_stream_fallback(runtime, row_id).await {
+                return;
+            }
             log::error!("[qbz-slint] local play: DSD file not available at {path}");
             crate::toast::show_weak(
                 weak,
@@ -919,6 +987,9 @@ async fn play_local_file_audible(
     .ok()
     .flatten();
     let Some(bytes) = bytes else {
+        if play_stream_fallback(runtime, row_id).await {
+            return;
+        }
         log::error!("[qbz-slint] local play: file not available at {path}");
         crate::toast::show_weak(
             weak,
@@ -930,7 +1001,9 @@ async fn play_local_file_audible(
     };
     if let Err(e) = runtime.core().player().play_data(bytes, row_id) {
         log::error!("[qbz-slint] local play: play_data {row_id} failed: {e}");
-        clear_loading(weak, row_id);
+        if !play_stream_fallback(runtime, row_id).await {
+            clear_loading(weak, row_id);
+        }
         return;
     }
     if let Some(start) = cue {
@@ -938,6 +1011,85 @@ async fn play_local_file_audible(
             tokio::time::sleep(std::time::Duration::from_millis(120)).await;
             let _ = runtime.core().player().seek(start as u64);
         }
+    } else {
+        // CUE tracks share one file, so its silence bounds are not theirs.
+        apply_silence_bounds(runtime, row_id, path).await;
+    }
+}
+
+/// Play the Qobuz track linked to local row `row_id` (see
+/// `LibraryDatabase::set_stream_fallback`) after its file failed to open.
+/// The bytes come through the same tiers as a gapless handoff — player
+/// cache, offline copy, then a CMAF download with the legacy URL as the
+/// last resort — and play under the row id, so the queue cursor, the poll
+/// loop and the now-playing card carry on as if the file had played.
+/// Returns false when offline, when nothing is linked or when every tier
+/// fails; the caller then reports the original failure.
+async fn play_stream_fallback(runtime: &Runtime, row_id: u64) -> bool {
+    if crate::offline_mode::engine().is_offline() {
+        return false;
+    }
+    let Some(qobuz_id) = tokio::task::spawn_blocking(move || local_stream_fallback(row_id))
+        .await
+        .ok()
+        .flatten()
+    else {
+        return false;
+    };
+    let offline = crate::offline::get().await;
+    let Some(bytes) = runtime
+        .core()
+        .fetch_for_gapless_resolved(
+            qobuz_id,
+            local_playback_quality().0,
+            offline.as_deref(),
+            None,
+        )
+        .await
+    else {
+        log::warn!("[qbz-slint] stream fallback: no audio for Qobuz track {qobuz_id}");
+        return false;
+    };
+    match runtime.core().player().play_data(bytes, row_id) {
+        Ok(()) => {
+            log::info!(
+                "[qbz-slint] stream fallback: local track {row_id} unavailable, playing Qobuz track {qobuz_id}"
+            );
+            true
+        }
+        Err(e) => {
+            log::warn!("[qbz-slint] stream fallback: playing Qobuz track {qobuz_id} failed: {e}");
+            false
+        }
+    }
+}
+
+/// Skip-silence for a local file that just started: seek past the leading
+/// silence and arm `SILENCE_END` for the trailing one. A first play scans
+/// the file while it is already playing, so the seek only happens if the
+/// track is still loaded and inside its leading silence.
+async fn apply_silence_bounds(runtime: &Runtime, row_id: u64, path: String) {
+    if let Ok(mut end) = SILENCE_END.lock() {
+        *end = None;
+    }
+    let Some(bounds) = runtime
+        .core()
+        .audio_bounds(row_id, std::path::PathBuf::from(path))
+        .await
+    else {
+        return;
+    };
+    let player = runtime.core().player();
+    if player.state.current_track_id() != row_id {
+        return;
+    }
+    let start_secs = bounds.start_ms / 1000;
+    if start_secs > 0 && player.get_playback_event().position < start_secs {
+        log::info!("[qbz-slint] skip silence: track {row_id} starts at {start_secs}s");
+        let _ = player.seek(start_secs);
+    }
+    if let Ok(mut end) = SILENCE_END.lock() {
+        *end = Some((row_id, bounds.end_ms));
     }
 }
 
@@ -1102,10 +1254,27 @@ pub fn play_local_album(
             Some(tid) => tracks.iter().position(|t| t.id == tid).unwrap_or(0),
             None => 0,
         };
+        queue_local_album_loudness(&runtime, &tracks);
         play_local_tracks_now(&runtime, &weak, tracks, start).await;
     });
 }
 
+/// With normalization on, measure the rest of a local album in the
+/// background so each track starts at its cached gain instead of converging
+/// ~10s in. Only real local files qualify: Plex, ephemeral and offline
+/// copies are keyed or read differently by the player.
+fn queue_local_album_loudness(runtime: &Runtime, tracks: &[qbz_library::LocalTrack]) {
+    let files: Vec<(u64, std::path::PathBuf)> = tracks
+        .iter()
+        .filter(|t| matches!(t.source.as_deref(), None | Some("local")))
+        .map(|t| (t.id as u64, std::path::PathBuf::from(&t.file_path)))
+        .collect();
+    let queued = runtime.core().queue_loudness_analysis(files);
+    if queued > 0 {
+        log::info!("[qbz-slint] loudness: queued {queued} local tracks for analysis");
+    }
+}
+
 /// If the track currently playing is from an ephemeral folder, stop it and
 /// clear the queue + now-playing chrome. Mirrors Tauri's
 /// `wipeEphemeralPlaybackArtifacts`: called when the ephemeral session is
@@ -1923,6 +2092,7 @@ pub(crate) async fn refresh_now_playing_meta(runtime: &Runtime, weak: &slint::We
         // Track -> null resets the lyrics state (Tauri parity,
         // lyricsStore.ts:560-562).
         crate::lyrics::on_track_cleared(weak.clone());
+        crate::bookmarks::push(weak, "", 0, 0);
         let _ = weak.upgrade_in_event_loop(|w| {
             w.global::<NowPlayingState>().set_has_track(false);
         });
@@ -1963,6 +2133,7 @@ pub(crate) async fn refresh_now_playing_meta(runtime: &Runtime, weak: &slint::We
         (Some(kind), Some(id)) => (kind.to_string(), id.to_string()),
         _ => ("album".to_string(), album_id.clone()),
     };
+    crate::playlist_audio::sync_context(runtime, &context_kind, &context_id);
     let track_id_num = track.id;
     let track_id = track.id.to_string();
     // Ephemeral tracks have no DB row → metadata-bound actions (favorite,
@@ -2232,6 +2403,7 @@ pub(crate) async fn refresh_now_playing_meta(runtime: &Runtime, weak: &slint::We
         // peer's track (Q7). Fire-and-forget; the stale-response guard (F2)
         // lives in `lyrics::on_track_changed`.
         crate::lyrics::on_track_changed(weak.clone(), &track);
+        crate::bookmarks::push(weak, &source, track_id_num, duration);
         // Discord Rich Presence: push the new track on this de-duped
         // track-change edge (no-op + no IPC when not opted in). Mirrors the
         // Tauri service's track_id transition push.
@@ -2571,16 +2743,18 @@ async fn record_recent(runtime: &Runtime) {
         album_artwork_url: artwork,
         quality_tier,
         quality_label,
-        genre: meta.genre,
+        genre: meta.genre.clone(),
         release_date: meta.release_date,
         artist_id: track.artist_id,
         source: track.source.clone().unwrap_or_else(|| "qobuz".to_string()),
     });
+    crate::session_persist::begin_listening(&track, &meta.genre);
     // Per-artist play count — feeds the discovery filter "skip
     // artists I already know" (HavingCount > threshold). artist_id
     // is optional on QueueTrack; skip when absent.
     if let Some(artist_id) = track.artist_id {
         crate::play_history::record_play(artist_id, &track.artist);
+        crate::playlist_suggestions::maybe_rebuild_artist_vectors(runtime);
     }
     // reco: log this play for taste scoring. The helper gates to Qobuz-catalog
     // sources only (local/plex/ephemeral ids don't resolve against the Qobuz
@@ -2621,13 +2795,7 @@ async fn record_recent(runtime: &Runtime) {
 /// level via `track_is_blacklisted_full` below, which adds the composer leg
 /// (D-FEAT). Local / Plex / no-id tracks => kept (fail-open).
 fn queue_track_blacklisted(track: &QueueTrack) -> bool {
-    let source = track.source.as_deref().unwrap_or("qobuz");
-    crate::artist_blacklist::is_track_blacklisted(
-        source,
-        track.artist_id,
-        None,
-        track.album_id.as_deref(),
-    )
+    crate::artist_blacklist::is_queue_track_blacklisted(track)
 }
 
 /// Drop blacklisted entries from a freshly-built `QueueTrack` queue. Keeps
@@ -3726,7 +3894,11 @@ pub fn play_smart_artist_radio(
             log::warn!("[qbz-slint] smart radio: bad artist id {artist_id}");
             return;
         };
-        match runtime.core().create_smart_artist_radio(aid).await {
+        match runtime
+            .core()
+            .create_smart_artist_radio(aid, crate::reco::disliked_artist_ids())
+            .await
+        {
             Ok(tracks) => {
                 if !play_radio_response(runtime, weak, tracks) {
                     log::warn!("[qbz-slint] smart artist radio {aid} returned no tracks");
@@ -3784,7 +3956,12 @@ pub fn play_smart_track_radio(
         };
         match runtime
             .core()
-            .create_smart_track_radio(tid, aid, track.title.clone())
+            .create_smart_track_radio(
+                tid,
+                aid,
+                track.title.clone(),
+                crate::reco::disliked_artist_ids(),
+            )
             .await
         {
             Ok(tracks) => {
@@ -3797,6 +3974,48 @@ pub fn play_smart_track_radio(
     });
 }
 
+/// Start a genre radio: a Smart Song Radio seeded by tracks sampled from the
+/// genre's new releases (the `v2_create_genre_radio` equivalent).
+pub fn play_genre_radio(
+    runtime: Runtime,
+    weak: slint::Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    genre_id: u64,
+    seed_count: usize,
+) {
+    handle.spawn(async move {
+        let (blacklist, album_blacklist) = if crate::artist_blacklist::is_enabled() {
+            (
+                crate::artist_blacklist::ids_snapshot(),
+                crate::artist_blacklist::album_ids_snapshot(),
+            )
+        } else {
+            Default::default()
+        };
+        match runtime
+            .core()
+            .create_genre_radio(
+                genre_id,
+                seed_count,
+                crate::reco::disliked_artist_ids(),
+                &blacklist,
+                &album_blacklist,
+            )
+            .await
+        {
+            Ok(tracks) => {
+                if !play_radio_response(runtime, weak, tracks) {
+                    log::warn!("[qbz-slint] genre radio {genre_id} returned no tracks");
+                }
+            }
+            Err(e) => {
+                log::error!("[qbz-slint] genre radio {genre_id} failed: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Could not start genre radio"));
+            }
+        }
+    });
+}
+
 /// Start a Qobuz album radio (`/radio/album`).
 pub fn play_album_radio(
     runtime: Runtime,
@@ -4578,15 +4797,48 @@ pub fn cycle_repeat(
             RepeatMode::All => RepeatMode::One,
             RepeatMode::One => RepeatMode::Off,
         };
-        runtime.core().set_repeat_mode(next).await;
-        let mode: i32 = match next {
-            RepeatMode::Off => 0,
-            RepeatMode::All => 1,
-            RepeatMode::One => 2,
-        };
+        apply_repeat(&runtime, &weak, next).await;
+    });
+}
+
+/// Set an explicit repeat mode (MPRIS `LoopStatus` writes) and reflect it.
+pub fn set_repeat(
+    runtime: Runtime,
+    weak: slint::Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    mode: RepeatMode,
+) {
+    handle.spawn(async move {
+        apply_repeat(&runtime, &weak, mode).await;
+    });
+}
+
+async fn apply_repeat(runtime: &Runtime, weak: &slint::Weak<AppWindow>, mode: RepeatMode) {
+    runtime.core().set_repeat_mode(mode).await;
+    let index: i32 = match mode {
+        RepeatMode::Off => 0,
+        RepeatMode::All => 1,
+        RepeatMode::One => 2,
+    };
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        w.global::<NowPlayingState>().set_repeat_mode(index);
+    });
+}
+
+/// Set shuffle to an explicit state (MPRIS `Shuffle` writes) and reflect it.
+/// Same UP NEXT re-pull as [`toggle_shuffle`].
+pub fn set_shuffle(
+    runtime: Runtime,
+    weak: slint::Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    enabled: bool,
+) {
+    handle.spawn(async move {
+        runtime.core().set_shuffle(enabled).await;
         let _ = weak.upgrade_in_event_loop(move |w| {
-            w.global::<NowPlayingState>().set_repeat_mode(mode);
+            w.global::<NowPlayingState>().set_shuffle(enabled);
         });
+        refresh_sidebar(false);
     });
 }
 
@@ -4886,6 +5138,7 @@ pub fn start_poll_loop(
             // crash keeps a near-current resume point (no-op unless
             // `persist_session` is on; `position` is in seconds).
             save_pos_tick = save_pos_tick.wrapping_add(1);
+            crate::session_persist::observe_listening(track_id, position, is_playing);
             if is_playing && track_id != 0 && save_pos_tick % 11 == 0 {
                 crate::session_persist::save_position(position);
             }
@@ -4953,6 +5206,29 @@ pub fn start_poll_loop(
                 continue;
             }
 
+            // --- Skip-silence end -------------------------------------------
+            // A local track with trailing silence ends at its last audible
+            // moment: advance as if it had finished (position is whole
+            // seconds, so this lands within a tick of `end_ms`).
+            let silence_end = SILENCE_END.lock().ok().and_then(|end| *end);
+            if let Some((end_track, end_ms)) = silence_end {
+                if end_track == track_id
+                    && is_playing
+                    && position * 1000 >= end_ms
+                    && end_ms + 1000 < duration * 1000
+                {
+                    if let Ok(mut end) = SILENCE_END.lock() {
+                        *end = None;
+                    }
+                    log::info!("[qbz-slint] skip silence: track {track_id} ends at {end_ms}ms");
+                    next(runtime.clone(), weak.clone(), tokio::runtime::Handle::current());
+                    last_track_id = track_id;
+                    seen_position = position;
+                    was_playing = is_playing;
+                    continue;
+                }
+            }
+
             // --- Gapless prefetch trigger --------------------------------
             // When the engine signals it wants the next track pre-queued
             // (`gapless_ready`) and nothing is queued yet
@@ -4982,6 +5258,10 @@ pub fn start_poll_loop(
                     // engine gapless-handing into a refused fetch.
                     if next.id != track_id && !next.is_local && offline_track_playable(&next) {
                         gapless_requested_for = track_id;
+                        // The hand-off picks the repeat-all crossfade when the
+                        // next track wraps to the start of the queue.
+                        let wraps = runtime.core().next_wraps_around().await;
+                        runtime.core().player().state.set_next_is_repeat_wrap(wraps);
                         let runtime = runtime.clone();
                         let weak = weak.clone();
                         let next_id = next.id;
@@ -5382,6 +5662,7 @@ pub fn start_poll_loop(
                     // and park the visualizer producer (stop counts as paused).
                     clear_loading(&weak, 0);
                     set_viz_paused(&runtime, true);
+                    crate::discord_rpc::stopped(&tokio::runtime::Handle::current());
                     let _ = weak.upgrade_in_event_loop(|w| {
                         let np = w.global::<NowPlayingState>();
                         np.set_playing(false);
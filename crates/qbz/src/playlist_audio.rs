@@ -0,0 +1,199 @@
+//! Per-playlist audio overrides — the playback glue.
+//!
+//! A playlist can carry its own crossfade / normalization settings
+//! ([`qbz_audio::PlaylistAudioOverride`], stored in `audio_settings.db`). While the
+//! playing track was launched from that playlist, the player runs on the
+//! global settings with the override laid on top; once playback moves to
+//! another context it drops back to the global settings. The override is
+//! edited in the playlist header's "Playlist audio settings" modal.
+
+use std::sync::{Arc, Mutex};
+
+use qbz_app::shell::AppRuntime;
+use qbz_audio::settings::AudioSettingsStore;
+use qbz_audio::{AudioSettings, PlaylistAudioOverride};
+use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
+
+use crate::adapter::SlintAdapter;
+use crate::settings::{crossfade_labels, CROSSFADE_MS};
+use crate::{AppWindow, PlaylistAudioActions, PlaylistAudioState};
+
+type Runtime = Arc<AppRuntime<SlintAdapter>>;
+
+/// Playlist whose override is applied to the player, if any.
+static ACTIVE: Mutex<Option<u64>> = Mutex::new(None);
+
+/// Modal dropdown values. Each dropdown puts "Default" (no override) at
+/// index 0 ahead of these. Crossfade uses the Playback settings' choices.
+const NORMALIZATION: &[bool] = &[true, false];
+const TARGET_LUFS: &[f32] = &[-14.0, -16.0, -18.0, -23.0];
+
+/// Dropdown index of an override value: 0 when unset (or not one of the
+/// choices), else its position after "Default".
+fn choice_index<T: PartialEq>(choices: &[T], value: Option<T>) -> i32 {
+    value
+        .and_then(|v| choices.iter().position(|c| *c == v))
+        .map_or(0, |i| i as i32 + 1)
+}
+
+/// Override value picked at dropdown `index` (`None` for "Default").
+fn choice_value<T: Copy>(choices: &[T], index: i32) -> Option<T> {
+    usize::try_from(index - 1)
+        .ok()
+        .and_then(|i| choices.get(i).copied())
+}
+
+fn options(labels: impl IntoIterator<Item = String>) -> ModelRc<SharedString> {
+    let mut items = vec![SharedString::from(qbz_i18n::t("Default"))];
+    items.extend(labels.into_iter().map(SharedString::from));
+    ModelRc::new(VecModel::from(items))
+}
+
+/// Wire the "Playlist audio settings" modal.
+pub fn install(window: &AppWindow, runtime: Runtime) {
+    let actions = window.global::<PlaylistAudioActions>();
+    {
+        let weak = window.as_weak();
+        actions.on_open(move |id, name| {
+            let Some(w) = weak.upgrade() else { return };
+            let Ok(playlist_id) = id.parse::<u64>() else {
+                return;
+            };
+            let current = AudioSettingsStore::new()
+                .and_then(|store| store.get_playlist_override(playlist_id))
+                .unwrap_or_else(|e| {
+                    log::warn!("[qbz-slint] playlist audio override: {e}");
+                    None
+                });
+            let state = w.global::<PlaylistAudioState>();
+            state.set_id(id);
+            state.set_name(name);
+            state.set_crossfade_options(options(crossfade_labels()));
+            state.set_crossfade_index(choice_index(
+                CROSSFADE_MS,
+                current.and_then(|c| c.crossfade_ms),
+            ));
+            state.set_normalization_options(options(NORMALIZATION.iter().map(|on| {
+                if *on {
+                    qbz_i18n::t("On")
+                } else {
+                    qbz_i18n::t("Off")
+                }
+            })));
+            state.set_normalization_index(choice_index(
+                NORMALIZATION,
+                current.and_then(|c| c.normalization_enabled),
+            ));
+            state.set_target_options(options(
+                TARGET_LUFS.iter().map(|lufs| format!("{lufs} LUFS")),
+            ));
+            state.set_target_index(choice_index(
+                TARGET_LUFS,
+                current.and_then(|c| c.normalization_target_lufs),
+            ));
+            state.set_open(true);
+        });
+    }
+    {
+        let weak = window.as_weak();
+        actions.on_close(move || {
+            if let Some(w) = weak.upgrade() {
+                w.global::<PlaylistAudioState>().set_open(false);
+            }
+        });
+    }
+    {
+        let weak = window.as_weak();
+        actions.on_save(move || {
+            let Some(w) = weak.upgrade() else { return };
+            let state = w.global::<PlaylistAudioState>();
+            let Ok(playlist_id) = state.get_id().parse::<u64>() else {
+                return;
+            };
+            let value = PlaylistAudioOverride {
+                playlist_id,
+                crossfade_ms: choice_value(CROSSFADE_MS, state.get_crossfade_index()),
+                normalization_enabled: choice_value(NORMALIZATION, state.get_normalization_index()),
+                normalization_target_lufs: choice_value(TARGET_LUFS, state.get_target_index()),
+            };
+            if let Err(e) =
+                AudioSettingsStore::new().and_then(|store| store.set_playlist_override(&value))
+            {
+                log::error!("[qbz-slint] save playlist audio override failed: {e}");
+                crate::toast::error(
+                    &w,
+                    qbz_i18n::t("Could not save the playlist audio settings"),
+                );
+                return;
+            }
+            state.set_open(false);
+            // Playing from this playlist right now — apply the change.
+            if ACTIVE.lock().ok().and_then(|a| *a) == Some(playlist_id) {
+                reload(&runtime, Some(playlist_id));
+            }
+        });
+    }
+}
+
+/// Follow the playing track's launch context (`context_kind`/`context_id`
+/// as stamped by `stamp_queue_context`). Called on every now-playing
+/// refresh; the player is only touched when the context changes.
+pub fn sync_context(runtime: &Runtime, context_kind: &str, context_id: &str) {
+    let playlist_id = match context_kind {
+        "playlist" => context_id.parse::<u64>().ok(),
+        _ => None,
+    };
+    match ACTIVE.lock() {
+        Ok(mut active) if *active != playlist_id => *active = playlist_id,
+        _ => return,
+    }
+    reload(runtime, playlist_id);
+}
+
+/// Push the effective settings for `playlist_id` to the player, off the
+/// calling thread.
+fn reload(runtime: &Runtime, playlist_id: Option<u64>) {
+    let player = runtime.core().player();
+    tokio::task::spawn_blocking(move || {
+        let settings = AudioSettingsStore::new().and_then(|store| effective_settings(&store));
+        match settings.and_then(|s| player.reload_settings(s)) {
+            Ok(()) => log::info!("[qbz-slint] playlist audio override: {playlist_id:?}"),
+            Err(e) => log::warn!("[qbz-slint] playlist audio override: {e}"),
+        }
+    });
+}
+
+/// The stored audio settings with the active playlist's override applied.
+/// Settings reloads go through this so a change made while the playlist
+/// plays doesn't drop its override.
+pub fn effective_settings(store: &AudioSettingsStore) -> Result<AudioSettings, String> {
+    let mut settings = store.get_settings()?;
+    let active = ACTIVE.lock().ok().and_then(|a| *a);
+    if let Some(playlist_id) = active {
+        if let Some(value) = store.get_playlist_override(playlist_id)? {
+            value.apply_to(&mut settings);
+        }
+    }
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropdown_index_round_trips_with_default_first() {
+        assert_eq!(choice_index(CROSSFADE_MS, None), 0);
+        assert_eq!(choice_value(CROSSFADE_MS, 0), None);
+        for (i, ms) in CROSSFADE_MS.iter().enumerate() {
+            let index = choice_index(CROSSFADE_MS, Some(*ms));
+            assert_eq!(index, i as i32 + 1);
+            assert_eq!(choice_value(CROSSFADE_MS, index), Some(*ms));
+        }
+        assert_eq!(choice_index(NORMALIZATION, Some(false)), 2);
+        assert_eq!(choice_value(TARGET_LUFS, 1), Some(-14.0));
+        // A value the dropdown doesn't offer shows as "Default".
+        assert_eq!(choice_index(CROSSFADE_MS, Some(4_000)), 0);
+        assert_eq!(choice_value(TARGET_LUFS, 99), None);
+    }
+}
@@ -8,20 +8,23 @@
 //! `url-edited`).
 //!
 //! Close-mid-import semantics (spec §1.8): closing the modal never cancels
-//! the tokio import task. On completion the toast + sidebar refresh still
-//! fire (main.rs arm); navigation happens only while the modal is still
-//! open AND the run's generation is current. [`GENERATION`] is bumped on
-//! every open() and execute(), so a stale run's sink events / completion
-//! can never touch a reopened modal's fresh state.
-
+//! the tokio import task; only the explicit Cancel button does
+//! ([`cancel`]). On completion the toast + sidebar refresh still fire
+//! (main.rs arm); navigation happens only while the modal is still open AND
+//! the run's generation is current. [`GENERATION`] is bumped on every open()
+//! and execute(), so a stale run's sink events / completion can never touch
+//! a reopened modal's fresh state.
+
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{LazyLock, Mutex};
+use std::sync::{Arc, LazyLock, Mutex};
 
 use slint::{ComponentHandle, Model, ModelRc, VecModel};
 
 use qbz_playlist_import::{
-    detect_provider_key, ImportEvent, ImportPhase, ImportPlaylist, ImportProgressSink,
-    ImportProvider, ImportSummary, ProviderKey,
+    csv_file_path, csv_headers, detect_provider_key, xspf_file_path, CsvColumnMap, ImportEvent,
+    ImportPhase, ImportPlaylist, ImportProgressSink, ImportProvider, ImportSummary,
+    PlaylistImportError, ProviderKey,
 };
 
 use crate::{AppWindow, ImportLogEntry, PlaylistImportState, SidebarState};
@@ -61,6 +64,21 @@ fn bump_generation() -> u64 {
     GENERATION.fetch_add(1, Ordering::SeqCst) + 1
 }
 
+/// Generation of the run the user cancelled (0 = none). The running
+/// import's [`SlintSink`] polls it, so the crate stops at its next track
+/// search or playlist write.
+static CANCELLED_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Cancel the running execute. Event-loop thread.
+pub fn cancel(window: &AppWindow) {
+    let state = window.global::<PlaylistImportState>();
+    if !state.get_loading() || !state.get_show_preview() {
+        return;
+    }
+    CANCELLED_GENERATION.store(current_generation(), Ordering::SeqCst);
+    state.set_status_line(qbz_i18n::t("Cancelling...").into());
+}
+
 /// Open the modal fully reset — Tauri remounts the Svelte component on
 /// every open, so nothing persists. Event-loop thread.
 pub fn open(window: &AppWindow) {
@@ -383,6 +401,18 @@ pub fn apply_execute_err(window: &AppWindow, err: &str) {
     let state = window.global::<PlaylistImportState>();
     state.set_error(err.into());
     push_log(window, qbz_i18n::t_args("Import failed: {}", &[err]), "error");
+    finish_execute_err(window);
+}
+
+/// The user cancelled the import — logged as info, no error banner.
+/// Event-loop thread.
+pub fn apply_execute_cancelled(window: &AppWindow) {
+    push_log(window, qbz_i18n::t("Import cancelled."), "info");
+    finish_execute_err(window);
+}
+
+fn finish_execute_err(window: &AppWindow) {
+    let state = window.global::<PlaylistImportState>();
     state.set_has_progress(false);
     state.set_status_line("".into());
     state.set_current_track("".into());
@@ -416,6 +446,10 @@ impl ImportProgressSink for SlintSink {
             }
         });
     }
+
+    fn is_cancelled(&self) -> bool {
+        CANCELLED_GENERATION.load(Ordering::SeqCst) == self.generation
+    }
 }
 
 /// Append one pre-formatted line to the conversion log (append-only
@@ -461,7 +495,94 @@ fn provider_display_name(provider: &ImportProvider) -> &'static str {
         ImportProvider::AppleMusic => "Apple Music",
         ImportProvider::Tidal => "Tidal",
         ImportProvider::Deezer => "Deezer",
+        ImportProvider::YouTubeMusic => "YouTube Music",
+        ImportProvider::Csv => "CSV file",
+        ImportProvider::Xspf => "XSPF file",
+    }
+}
+
+/// Column map for a local track list, guessed from its header row (the
+/// modal has no mapping step).
+fn csv_column_map(path: &Path) -> Result<CsvColumnMap, PlaylistImportError> {
+    let headers = csv_headers(path)?;
+    CsvColumnMap::from_headers(&headers).ok_or_else(|| {
+        PlaylistImportError::Parse(format!(
+            "No title and artist columns in the header ({})",
+            headers.join(", ")
+        ))
+    })
+}
+
+/// Step A fetch: a local CSV/TSV or XSPF path parses the file, anything
+/// else goes to the public-playlist providers.
+pub async fn preview(source: &str) -> Result<ImportPlaylist, PlaylistImportError> {
+    if let Some(path) = xspf_file_path(source) {
+        return qbz_playlist_import::preview_xspf_playlist(&path).await;
+    }
+    match csv_file_path(source) {
+        Some(path) => {
+            let column_map = csv_column_map(&path)?;
+            qbz_playlist_import::preview_csv_playlist(&path, &column_map).await
+        }
+        None => qbz_playlist_import::preview_public_playlist(source).await,
+    }
+}
+
+/// Step B execute, dispatched like [`preview`].
+pub async fn import(
+    source: &str,
+    client: &qbz_qobuz::QobuzClient,
+    name_override: Option<&str>,
+    is_public: bool,
+    progress: Arc<dyn ImportProgressSink>,
+) -> Result<ImportSummary, PlaylistImportError> {
+    if let Some(path) = xspf_file_path(source) {
+        return qbz_playlist_import::import_xspf_playlist(
+            &path,
+            client,
+            name_override,
+            is_public,
+            progress,
+        )
+        .await;
+    }
+    match csv_file_path(source) {
+        Some(path) => {
+            let column_map = csv_column_map(&path)?;
+            qbz_playlist_import::import_csv_playlist(
+                &path,
+                &column_map,
+                client,
+                name_override,
+                is_public,
+                progress,
+            )
+            .await
+        }
+        None => {
+            qbz_playlist_import::import_public_playlist(
+                source,
+                client,
+                name_override,
+                is_public,
+                progress,
+            )
+            .await
+        }
+    }
+}
+
+/// "Import from file…" — pick a CSV/TSV track list or an XSPF playlist and
+/// drop its path into the URL field. Async (rfd portal); `None` on cancel.
+pub async fn pick_csv_file() -> Option<String> {
+    let mut dialog = rfd::AsyncFileDialog::new()
+        .set_title(&qbz_i18n::t("Choose a track list"))
+        .add_filter("CSV / TSV / XSPF", &["csv", "tsv", "tab", "xspf"]);
+    if let Some(docs) = dirs::document_dir() {
+        dialog = dialog.set_directory(docs);
     }
+    let file = dialog.pick_file().await?;
+    Some(file.path().to_string_lossy().to_string())
 }
 
 /// `toLocaleString()` twin for the matching log/status numbers
@@ -27,6 +27,7 @@ pub fn open(window: &AppWindow, track_id: &str) {
     state.set_playlists(ModelRc::new(VecModel::from(Vec::<PlaylistPickItem>::new())));
     state.set_filter_matches(0);
     state.set_local_mode(false);
+    state.set_from_queue(false);
     state.set_loading(true);
     state.set_open(true);
 }
@@ -44,6 +45,7 @@ pub fn open_multi(window: &AppWindow, ids: &[String], local: bool) {
     state.set_playlists(ModelRc::new(VecModel::from(Vec::<PlaylistPickItem>::new())));
     state.set_filter_matches(0);
     state.set_local_mode(local);
+    state.set_from_queue(false);
     state.set_loading(true);
     state.set_open(true);
 }
@@ -19,8 +19,10 @@
 //! and duplicates within the pool itself by the same key.
 
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{LazyLock, Mutex};
 
+use qbz_app::settings::reco_store::RecoItemType;
 use qbz_app::shell::AppRuntime;
 use qbz_models::Track;
 use slint::{ComponentHandle, Model, ModelRc, VecModel};
@@ -40,6 +42,14 @@ const EXPANDED_POOL: usize = 100;
 const MAX_POOL: usize = 200;
 /// Auto-expand the pool once the available (filtered) tracks drop below this.
 const MIN_AVAILABLE_THRESHOLD: usize = 12;
+/// Distinct played artists needed before the vectors are rebuilt from the
+/// user's history; below this the co-occurrence links are too sparse.
+const HISTORY_REBUILD_MIN_ARTISTS: usize = 50;
+/// Most-played artists fed to a history rebuild (each new one costs
+/// MusicBrainz lookups at the 1 req/s rate limit).
+const HISTORY_REBUILD_MAX_ARTISTS: usize = 100;
+/// New distinct artists since the last rebuild that trigger the next one.
+const HISTORY_REBUILD_STEP: usize = 10;
 
 /// Which fetch we are running — drives the merge-vs-replace + error handling.
 #[derive(Clone, Copy, PartialEq)]
@@ -83,6 +93,9 @@ struct Session {
 
 static SESSION: LazyLock<Mutex<Session>> = LazyLock::new(|| Mutex::new(Session::default()));
 
+/// Set while a history rebuild is running.
+static HISTORY_REBUILD_RUNNING: AtomicBool = AtomicBool::new(false);
+
 // --- string helpers (mirror the Svelte normalizeForComparison/makeTrackKey) -
 fn normalize(s: &str) -> String {
     s.to_lowercase().split_whitespace().collect::<Vec<_>>().join(" ")
@@ -614,6 +627,10 @@ pub fn dismiss_track(window: &AppWindow, runtime: Runtime, handle: Handle, track
         crate::playlist_suggestions_dismiss::dismiss(session.playlist_id, tid);
         session.pool.retain(|t| t.track_id != tid);
     }
+    // "Not interested" is also a taste signal for the home seeds.
+    handle.spawn_blocking(move || {
+        crate::reco::log_dislike(RecoItemType::Track, &tid.to_string());
+    });
     project(window);
     maybe_auto_expand(runtime, window.as_weak(), handle);
 }
@@ -681,3 +698,33 @@ pub fn reset(window: &AppWindow) {
     state.set_error("".into());
     state.set_rows(ModelRc::new(VecModel::from(Vec::<PlaylistSuggestionRow>::new())));
 }
+
+/// Rebuild the artist vectors from the play history once it spans
+/// [`HISTORY_REBUILD_MIN_ARTISTS`] distinct artists, and again each time it
+/// has grown by [`HISTORY_REBUILD_STEP`] — in the background, one at a time.
+/// Each rebuild only resolves and builds the artists new since the last one.
+/// Called after each recorded play.
+pub fn maybe_rebuild_artist_vectors(runtime: &Runtime) {
+    let artists = crate::play_history::artist_count();
+    if artists < HISTORY_REBUILD_MIN_ARTISTS
+        || artists < crate::play_history::vector_rebuild_artist_count() + HISTORY_REBUILD_STEP
+        || HISTORY_REBUILD_RUNNING.swap(true, Ordering::AcqRel)
+    {
+        return;
+    }
+    let runtime = runtime.clone();
+    tokio::spawn(async move {
+        let top = crate::play_history::top_artists(HISTORY_REBUILD_MAX_ARTISTS);
+        match runtime.core().rebuild_artist_vectors(top).await {
+            Ok(resolutions) => {
+                crate::play_history::record_vector_rebuild(&resolutions, artists);
+                log::info!(
+                    "[qbz-slint] artist vectors rebuilt from play history ({} new artists)",
+                    resolutions.len()
+                );
+            }
+            Err(e) => log::warn!("[qbz-slint] artist vector rebuild failed: {e}"),
+        }
+        HISTORY_REBUILD_RUNNING.store(false, Ordering::Release);
+    });
+}
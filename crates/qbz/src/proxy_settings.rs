@@ -0,0 +1,74 @@
+//! Settings > Integrations > NETWORK: the HTTP proxy fields.
+//!
+//! Thin controller over `qbz_app::settings::proxy` (global
+//! `proxy_settings.db`). The proxy itself is installed once at startup
+//! ([`install_at_startup`]), so a saved change applies on the next start —
+//! the save toast says so.
+
+use slint::ComponentHandle;
+
+use qbz_app::settings::proxy::{ProxyConfig, ProxySettingsStore};
+
+use crate::{AppWindow, ProxyState};
+
+/// Read the stored proxy and install it for the process. Must run before
+/// any thread spawns (it exports the proxy environment variables).
+pub fn install_at_startup() {
+    match ProxySettingsStore::new().and_then(|store| store.get_config()) {
+        Ok(config) => qbz_app::settings::proxy::install_for_process(config.sanitized()),
+        Err(_) => qbz_app::settings::proxy::install_for_process(ProxyConfig::default()),
+    }
+}
+
+/// Seed `ProxyState` from the store — the section's `load()` callback.
+pub fn load(weak: slint::Weak<AppWindow>, handle: &tokio::runtime::Handle) {
+    handle.spawn(async move {
+        let config = tokio::task::spawn_blocking(|| {
+            ProxySettingsStore::new().and_then(|store| store.get_config())
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        let config = match config {
+            Ok(config) => config,
+            Err(e) => {
+                log::warn!("[qbz-slint] proxy settings load failed: {e}");
+                return;
+            }
+        };
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            let state = w.global::<ProxyState>();
+            state.set_http_proxy(config.http_proxy.unwrap_or_default().into());
+            state.set_https_proxy(config.https_proxy.unwrap_or_default().into());
+            state.set_no_proxy(config.no_proxy.join(", ").into());
+        });
+    });
+}
+
+/// Persist the edited fields — the section's `save()` callback. UI thread.
+pub fn save(window: &AppWindow, handle: &tokio::runtime::Handle) {
+    let state = window.global::<ProxyState>();
+    let config = ProxyConfig {
+        http_proxy: Some(state.get_http_proxy().to_string()),
+        https_proxy: Some(state.get_https_proxy().to_string()),
+        no_proxy: ProxyConfig::parse_no_proxy(&state.get_no_proxy()),
+    }
+    .sanitized();
+    let weak = window.as_weak();
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            ProxySettingsStore::new().and_then(|store| store.set_config(&config))
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+        match result {
+            Ok(()) => crate::toast::success_weak(
+                &weak,
+                qbz_i18n::t("Proxy settings saved. Restart QBZ to apply."),
+            ),
+            Err(e) => {
+                log::error!("[qbz-slint] proxy settings save failed: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Failed to save proxy settings"));
+            }
+        }
+    });
+}
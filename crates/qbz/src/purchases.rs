@@ -39,7 +39,7 @@ use std::sync::{Arc, Mutex, OnceLock};
 
 use qbz_app::shell::AppRuntime;
 use qbz_models::{PurchaseAlbum, PurchaseFormatOption, PurchaseTrack};
-use qbz_offline_cache::purchases_service;
+use qbz_offline_cache::{purchases_service, DownloadManager};
 
 use crate::adapter::SlintAdapter;
 use crate::AppWindow;
@@ -475,6 +475,10 @@ pub struct PurchaseDownloadStore {
     /// the formats in component state). One entry at a time in practice (the
     /// picker is modal); cleared when the picker is consumed.
     picker_formats: HashMap<u64, Vec<PurchaseFormatOption>>,
+    /// `trackId → percent` of the transfer in flight (fed by the
+    /// `DownloadManager` progress observer). Dropped once the track leaves
+    /// `Downloading`.
+    track_progress: HashMap<u64, u8>,
 }
 
 impl PurchaseDownloadStore {
@@ -517,6 +521,15 @@ impl PurchaseDownloadStore {
         self.update_album(album_id, |state| {
             state.track_statuses.insert(track_id, status);
         });
+        if status != TrackDownloadStatus::Downloading {
+            self.track_progress.remove(&track_id);
+        }
+    }
+
+    /// Record a transfer percentage; `true` when it changed (worth a UI
+    /// refresh).
+    fn set_track_progress(&mut self, track_id: u64, percent: u8) -> bool {
+        self.track_progress.insert(track_id, percent) != Some(percent)
     }
 
     /// DESTINATION REWRITE after the first successful track
@@ -677,6 +690,11 @@ pub fn all_track_statuses() -> HashMap<u64, TrackDownloadStatus> {
     })
 }
 
+/// Percent of a track's transfer in flight, when its size is known.
+pub fn track_progress(track_id: u64) -> Option<u8> {
+    with_store(|s| s.track_progress.get(&track_id).copied())
+}
+
 /// Snapshot one album's download state (detail view binds here).
 pub fn album_download_state(album_id: &str) -> Option<AlbumDownloadState> {
     with_store(|s| s.album(album_id))
@@ -840,6 +858,88 @@ async fn snapshot_client(runtime: &Runtime) -> Option<qbz_qobuz::QobuzClient> {
     guard.as_ref().cloned()
 }
 
+/// The resumable-download ledger for purchase downloads: the active offline
+/// cache's `index.db` (per-user), or a detached manager — downloads still run,
+/// they just can't resume — when the offline cache is not up. With a window,
+/// each transfer's percentage lands in the store and re-projects the rows
+/// whenever it moves.
+async fn download_manager(weak: Option<slint::Weak<AppWindow>>) -> DownloadManager {
+    let manager = match crate::offline::get().await {
+        Some(offline) => DownloadManager::new(offline.db.clone()),
+        None => DownloadManager::detached(),
+    };
+    let Some(weak) = weak else {
+        return manager;
+    };
+    manager.on_progress(Arc::new(move |track_id, percent| {
+        let Some(percent) = percent else { return };
+        if with_store(|s| s.set_track_progress(track_id, percent)) {
+            nudge_ui_refresh(&weak);
+        }
+    }))
+}
+
+/// Resume-on-restart: finish every purchase download the previous run left
+/// mid-transfer (its `.part` is still on disk), continuing each with a `Range`
+/// request. Sequential on a dedicated current-thread runtime, like the album
+/// loop. The registry write is best-effort (album-loop semantics); the next
+/// Purchases load picks the finished tracks up from the registry. Call after
+/// the offline cache is activated for the signed-in user.
+pub async fn resume_interrupted_downloads(client: qbz_qobuz::QobuzClient) {
+    let downloads = download_manager(None).await;
+    let pending = downloads.interrupted().await;
+    if pending.is_empty() {
+        return;
+    }
+    log::info!(
+        "[Purchases] resuming {} interrupted download(s)",
+        pending.len()
+    );
+
+    let done = tokio::task::spawn_blocking(move || {
+        let rt = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(rt) => rt,
+            Err(e) => {
+                log::error!("[Purchases] resume runtime build failed: {e}");
+                return;
+            }
+        };
+        rt.block_on(async move {
+            let db = open_owned_library_db();
+            for entry in pending {
+                match purchases_service::resume_purchase_download(&client, &downloads, &entry).await
+                {
+                    Ok(file_path) => {
+                        let Some(db) = db.as_ref() else { continue };
+                        if let Err(e) = db.mark_purchase_downloaded(
+                            entry.track_id as i64,
+                            entry.album_id.as_deref(),
+                            &file_path,
+                            entry.format_id as i64,
+                        ) {
+                            log::warn!(
+                                "[Purchases] registry write for resumed track {} failed: {e}",
+                                entry.track_id
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("[Purchases] resume of track {} failed: {e}", entry.track_id);
+                    }
+                }
+            }
+        });
+    })
+    .await;
+
+    if let Err(e) = done {
+        log::error!("[Purchases] resume thread join failed: {e}");
+    }
+}
+
 /// skip-if-remote guard (§Slice-7 / Tauri `skipIfRemote`): never fire purchase
 /// download I/O while controlling a remote QConnect renderer. Mirrors the
 /// `award.rs` / favorites guard (`svc.is_peer_active().await`).
@@ -917,6 +1017,7 @@ async fn execute_album_download(
     quality_dir: String,
     client: qbz_qobuz::QobuzClient,
 ) {
+    let downloads = download_manager(Some(weak.clone())).await;
     // Move the whole (sequential, `!Send`) loop onto a dedicated thread driving
     // a current-thread tokio runtime. `spawn_blocking` would also work but a
     // fresh thread keeps the blocking pool free for DB/scan work.
@@ -967,8 +1068,10 @@ async fn execute_album_download(
                 // when the best-effort registry write runs (B.2).
                 match purchases_service::download_purchase_track_file_only(
                     &client,
+                    &downloads,
                     track_id,
                     format_id,
+                    &album_id,
                     &destination,
                     &quality_dir,
                 )
@@ -1059,6 +1162,7 @@ pub fn start_track_download(
             return;
         };
 
+        let downloads = download_manager(Some(weak.clone())).await;
         let done = tokio::task::spawn_blocking(move || {
             let rt = match tokio::runtime::Builder::new_current_thread()
                 .enable_all()
@@ -1084,6 +1188,7 @@ pub fn start_track_download(
                 let status = match purchases_service::download_purchase_track(
                     &client,
                     &db,
+                    &downloads,
                     track_id,
                     format_id,
                     &destination,
@@ -1112,6 +1217,75 @@ pub fn start_track_download(
     });
 }
 
+/// Download-as-ZIP circle (`v2_download_album_as_zip`): package the album in
+/// `format_id` into `Artist - Album (year) [quality].zip` under `destination`
+/// via [`purchases_service::download_album_as_zip`]. Independent of the album
+/// download store — the staging files are throwaway and nothing is
+/// registered. `PurchaseDetailState.zipping` drives the circle's spinner and
+/// `zip-progress` its "n / total" tooltip; the outcome is a toast. Same SEND
+/// BOUNDARY thread as the album loop.
+pub fn start_album_zip_download(
+    runtime: Runtime,
+    weak: slint::Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    album_id: String,
+    format_id: u32,
+    quality_label: String,
+    destination: String,
+) {
+    handle.spawn(async move {
+        if is_controlling_remote().await {
+            return;
+        }
+        let Some(client) = snapshot_client(&runtime).await else {
+            crate::toast::error_weak(&weak, qbz_i18n::t("Could not create the ZIP archive"));
+            return;
+        };
+        set_zip_progress(&weak, true, String::new());
+
+        let progress_weak = weak.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| format!("runtime build failed: {e}"))?;
+            rt.block_on(purchases_service::download_album_as_zip(
+                &client,
+                &album_id,
+                format_id,
+                &quality_label,
+                std::path::Path::new(&destination),
+                |done, total| set_zip_progress(&progress_weak, true, format!("{done} / {total}")),
+            ))
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+
+        set_zip_progress(&weak, false, String::new());
+        match result {
+            Ok(path) => {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                crate::toast::success_weak(&weak, qbz_i18n::t_args("Saved {}", &[&name]));
+            }
+            Err(e) => {
+                log::error!("[Purchases] album zip failed: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Could not create the ZIP archive"));
+            }
+        }
+    });
+}
+
+fn set_zip_progress(weak: &slint::Weak<AppWindow>, zipping: bool, progress: String) {
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        let state = w.global::<PurchaseDetailState>();
+        state.set_zipping(zipping);
+        state.set_zip_progress(progress.into());
+    });
+}
+
 /// Refresh the PurchasesView list-row download projection from the store. A
 /// no-op when the window is gone (download still completes; the registry holds
 /// the record for the next open). Used by the download actions to surface live
@@ -2489,6 +2663,12 @@ fn build_detail_rows(
             duration: format_duration(t.duration).into(),
             quality: bare_quality(t.maximum_bit_depth, t.maximum_sampling_rate).into(),
             streamable: t.streamable,
+            dl_progress: match scoped {
+                Some(TrackDownloadStatus::Downloading) => {
+                    track_progress(t.id).map_or(-1, i32::from)
+                }
+                _ => -1,
+            },
             dl_status: dl_status.into(),
             is_downloaded,
             show_download,
@@ -222,7 +222,7 @@ impl QconnectRendererEngine for SlintRendererEngine {
 
 
 async fn download_remote_audio(url: &str) -> Result<Vec<u8>, String> {
-    let response = reqwest::Client::new()
+    let response = qbz_app::settings::proxy::client()
         .get(url)
         .header("User-Agent", "Mozilla/5.0")
         .send()
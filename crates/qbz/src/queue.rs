@@ -16,11 +16,11 @@
 use std::sync::{Arc, Mutex};
 
 use qbz_app::settings::playback::AutoplayMode;
-use qbz_models::QueueTrack;
+use qbz_models::{QueueStats, QueueTrack};
 use slint::{ComponentHandle, Model};
 
 use crate::adapter::SlintAdapter;
-use crate::{AppWindow, ImmersiveState, QueueItem, QueueState};
+use crate::{AppWindow, ImmersiveState, PlaylistPickerState, QueueItem, QueueState};
 
 /// Upcoming tracks shown per paginator page. PAGINATED (not a growing list) to
 /// keep CPU/rendering bounded on huge queues (1000+ tracks) — owner preference.
@@ -96,6 +96,41 @@ fn fmt_duration(secs: u64) -> String {
     format!("{}:{:02}", secs / 60, secs % 60)
 }
 
+/// Sidebar footer summary of the whole queue — `"3h 12m · 30 Hi-Res · 10 CD
+/// · 2 local"`. Empty buckets are left out; an empty queue gives `""`.
+fn stats_line(stats: &QueueStats) -> String {
+    if stats.total_tracks == 0 {
+        return String::new();
+    }
+    let hrs = stats.total_duration_secs / 3600;
+    let mins = (stats.total_duration_secs % 3600) / 60;
+    let mut parts = vec![if hrs > 0 {
+        format!("{hrs}h {mins}m")
+    } else {
+        format!("{mins}m")
+    }];
+    for (count, label) in [
+        (stats.hires_count, qbz_i18n::mark("{} Hi-Res")),
+        (stats.cd_count, qbz_i18n::mark("{} CD")),
+        (stats.local_count, qbz_i18n::mark("{} local")),
+    ] {
+        if count > 0 {
+            parts.push(qbz_i18n::t_args(label, &[&count.to_string()]));
+        }
+    }
+    parts.join(" · ")
+}
+
+/// `CoreEvent::QueueStatsChanged` handler: post the formatted totals to
+/// `QueueState.stats-line`. Called from the core's event task, so it hops
+/// onto the Slint event loop.
+pub fn publish_stats(window: &slint::Weak<AppWindow>, stats: QueueStats) {
+    let line = stats_line(&stats);
+    let _ = window.upgrade_in_event_loop(move |ui| {
+        ui.global::<QueueState>().set_stats_line(line.into());
+    });
+}
+
 /// Title with the Qobuz version suffix appended, matching the Tauri
 /// `formatTrackTitle` behaviour.
 fn display_title(track: &QueueTrack) -> String {
@@ -852,7 +887,8 @@ impl QueueController {
 
     /// Open the Add-to-Playlist picker seeded with the queue's tracks
     /// (current + upcoming, de-duplicated, in play order). The picker's inline
-    /// "Create new playlist" row turns the queue into a named playlist; picking
+    /// "Create new playlist" row turns the queue into a named playlist (online
+    /// through `export_queue_as_qobuz_playlist`, which then opens it); picking
     /// an existing one appends the queue to it. Mirrors Tauri's
     /// handleSaveQueueAsPlaylist (which reuses the add-to-playlist modal).
     pub fn save_as_playlist(&self) {
@@ -876,10 +912,49 @@ impl QueueController {
             let handle = this.handle.clone();
             let _ = this.weak.upgrade_in_event_loop(move |w| {
                 crate::playlist_picker::open_for_ids(&w, runtime, &handle, ids_str, false);
+                w.global::<PlaylistPickerState>().set_from_queue(true);
             });
         });
     }
 
+    /// Export the whole queue (history included) as an M3U8 file picked in a
+    /// save dialog. Local tracks resolve to their files through the library
+    /// DB; streaming tracks are kept as `# QOBUZ:` comments.
+    pub fn export_m3u(&self) {
+        let this = self.clone();
+        self.handle.spawn(async move {
+            let Some(dest) = rfd::AsyncFileDialog::new()
+                .set_title(&qbz_i18n::t("Export queue"))
+                .set_file_name("queue.m3u8")
+                .add_filter("M3U8", &["m3u8", "m3u"])
+                .save_file()
+                .await
+            else {
+                return;
+            };
+            let queue = this.runtime.core().queue();
+            let path = dest.path().to_path_buf();
+            let result = tokio::task::spawn_blocking(move || {
+                queue.blocking_read().export_as_m3u(&path, local_file_path)
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+            match result {
+                Ok(playable) => crate::toast::success_weak(
+                    &this.weak,
+                    qbz_i18n::t_args(
+                        "Queue exported ({} playable tracks)",
+                        &[&playable.to_string()],
+                    ),
+                ),
+                Err(e) => {
+                    log::warn!("[qbz-slint] queue: M3U export failed: {e}");
+                    crate::toast::error_weak(&this.weak, qbz_i18n::t("Could not export the queue"));
+                }
+            }
+        });
+    }
+
     /// Open the Add-to-Playlist picker seeded with a single upcoming row (the
     /// track at page-local `page_index`). Reuses `save_as_playlist`'s picker
     /// handoff with just that one track — matching the per-track "Add to
@@ -1003,6 +1078,20 @@ pub(crate) fn mini_queue_items(state: &qbz_models::QueueState) -> Vec<QueueItem>
     out
 }
 
+/// On-disk file of a local queue track: library files by row id, offline
+/// copies by the row id carried in `source_item_id_hint`. Plex and
+/// ephemeral tracks have no file to point at. Blocking (library DB).
+fn local_file_path(track: &QueueTrack) -> Option<String> {
+    let row_id = match track.source.as_deref() {
+        Some("local") => track.id as i64,
+        Some("qobuz_download") => track.source_item_id_hint.as_deref()?.parse().ok()?,
+        _ => return None,
+    };
+    crate::library_db::with_db(|db| db.get_track(row_id))
+        .flatten()
+        .map(|t| t.file_path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1040,6 +1129,28 @@ mod tests {
         assert_eq!(fmt_duration(3725), "62:05");
     }
 
+    #[test]
+    fn stats_line_skips_empty_buckets() {
+        assert_eq!(stats_line(&QueueStats::default()), "");
+        let stats = QueueStats {
+            total_tracks: 42,
+            total_duration_secs: 3 * 3600 + 12 * 60 + 30,
+            hires_count: 30,
+            cd_count: 10,
+            local_count: 2,
+            qobuz_count: 40,
+        };
+        assert_eq!(stats_line(&stats), "3h 12m · 30 Hi-Res · 10 CD · 2 local");
+        let stats = QueueStats {
+            total_tracks: 3,
+            total_duration_secs: 610,
+            cd_count: 3,
+            qobuz_count: 3,
+            ..QueueStats::default()
+        };
+        assert_eq!(stats_line(&stats), "10m · 3 CD");
+    }
+
     #[test]
     fn display_title_appends_version() {
         assert_eq!(display_title(&track(1, "Song", Some("Live"))), "Song (Live)");
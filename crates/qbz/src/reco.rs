@@ -94,6 +94,54 @@ pub fn log_play_gated(
     false
 }
 
+/// One matched listen from a history import: the Qobuz ids plus when it was
+/// played (Unix seconds).
+pub struct ImportedPlay {
+    pub track_id: u64,
+    pub album_id: Option<String>,
+    pub artist_id: Option<u64>,
+    pub played_at: i64,
+}
+
+/// Log a batch of imported plays at their original times and advance the
+/// `source` import cursor to `cursor` in the same pass. Blocking SQLite —
+/// call from `spawn_blocking`. Returns how many plays were logged; the
+/// cursor only moves when every play went in, so a failed run re-imports.
+pub fn log_imported_plays(source: &str, plays: &[ImportedPlay], cursor: Option<i64>) -> u32 {
+    let Ok(guard) = RECO.lock() else {
+        return 0;
+    };
+    let Some(store) = guard.as_ref() else {
+        return 0;
+    };
+    let mut logged = 0;
+    for play in plays {
+        match store.log_play_event_at(
+            play.track_id,
+            play.album_id.clone(),
+            play.artist_id,
+            None,
+            play.played_at,
+        ) {
+            Ok(()) => logged += 1,
+            Err(e) => log::warn!("[reco] log imported play failed: {e}"),
+        }
+    }
+    if let Some(cursor) = cursor.filter(|_| logged as usize == plays.len()) {
+        if let Err(e) = store.set_import_cursor(source, cursor) {
+            log::warn!("[reco] save {source} import cursor failed: {e}");
+        }
+    }
+    logged
+}
+
+/// Newest timestamp already imported from `source`. `None` when nothing
+/// was imported yet or reco is disabled.
+pub fn import_cursor(source: &str) -> Option<i64> {
+    let guard = RECO.lock().ok()?;
+    guard.as_ref()?.get_import_cursor(source).ok().flatten()
+}
+
 // ---------------------------------------------------------------------------
 // Favorite events (W3) — log ONLY a successful ADD (make==true && network ok);
 // the caller applies that gate. Logging an un-favorite or a failed add would
@@ -180,6 +228,55 @@ pub fn log_playlist_add(playlist_id: Option<u64>, track_ids: Vec<u64>) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Dislike events — "don't recommend this". Scored negatively by train(), so
+// the scores are retrained right away; disliked artists also stay out of the
+// smart radio pools.
+// ---------------------------------------------------------------------------
+
+/// Log a dislike of a Qobuz track / album / artist / genre id.
+pub fn log_dislike(item_type: RecoItemType, item_id: &str) {
+    if let Ok(guard) = RECO.lock() {
+        if let Some(store) = guard.as_ref() {
+            if let Err(e) = store.log_dislike_event(item_type, item_id) {
+                log::warn!("[reco] log_dislike failed: {e}");
+                return;
+            }
+        }
+    }
+    train_async();
+}
+
+/// Withdraw a dislike logged by [`log_dislike`] (e.g. undoing "Not
+/// interested"), retraining when one was removed.
+pub fn clear_dislike(item_type: RecoItemType, item_id: &str) {
+    if let Ok(guard) = RECO.lock() {
+        if let Some(store) = guard.as_ref() {
+            match store.clear_dislike_event(item_type, item_id) {
+                Ok(0) => return,
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!("[reco] clear_dislike failed: {e}");
+                    return;
+                }
+            }
+        }
+    }
+    train_async();
+}
+
+/// Artist ids the user has disliked, for the radio builder. Empty when reco
+/// is cold/disabled.
+pub fn disliked_artist_ids() -> Vec<u64> {
+    let Ok(guard) = RECO.lock() else {
+        return Vec::new();
+    };
+    guard
+        .as_ref()
+        .and_then(|store| store.get_disliked_artist_ids().ok())
+        .unwrap_or_default()
+}
+
 // ---------------------------------------------------------------------------
 // Surfaces (W7/W8)
 // ---------------------------------------------------------------------------
@@ -195,6 +292,17 @@ pub fn forgotten_favorite_album_ids(limit: u32, recency_days: u32) -> Option<Vec
         .ok()
 }
 
+/// Album ids the user tends to play alongside `album_id`, best first (the
+/// `v2_reco_get_similar_albums` equivalent; see
+/// `RecoStore::get_similar_album_candidates`). `None` when reco is disabled
+/// or the read fails; empty when the album has no co-plays yet.
+pub fn similar_album_ids(album_id: &str, limit: usize) -> Option<Vec<String>> {
+    let guard = RECO.lock().ok()?;
+    let store = guard.as_ref()?;
+    let candidates = store.get_similar_album_candidates(album_id, limit).ok()?;
+    Some(candidates.into_iter().map(|c| c.album_id).collect())
+}
+
 /// The reco-scored favorite album ids in taste order (highest first) when the
 /// store is warm (trained); `None` when reco is cold/disabled so the caller
 /// keeps its original ordering. Bounded by `limit`.
@@ -213,19 +321,23 @@ pub fn scored_favorite_album_ids(limit: u32) -> Option<Vec<String>> {
     }
 }
 
-/// Backfill genres `(album_id, genre_id, genre_name)` onto reco events +
-/// album-meta once albums are resolved. Best-effort, blocking SQLite — call
-/// from `spawn_blocking`. Plays carry no genre, so this is what feeds
+/// Backfill genres `(album_id, genre_id, genre_name, root_id)` onto reco
+/// events + album-meta once albums are resolved. `root_id` is the top-level
+/// genre from the Qobuz genre path, when known. Best-effort, blocking SQLite —
+/// call from `spawn_blocking`. Plays carry no genre, so this is what feeds
 /// `get_top_genres`; idempotent (only fills still-NULL event genres).
-pub fn backfill_album_genres(entries: Vec<(String, u64, String)>) {
+pub fn backfill_album_genres(entries: Vec<(String, u64, String, Option<u64>)>) {
     if entries.is_empty() {
         return;
     }
     if let Ok(guard) = RECO.lock() {
         if let Some(store) = guard.as_ref() {
-            for (album_id, genre_id, genre_name) in entries {
+            for (album_id, genre_id, genre_name, root_id) in entries {
                 let _ = store.update_genre_for_album(&album_id, genre_id);
                 let _ = store.set_album_genre_name(&album_id, &genre_name);
+                if let Some(root_id) = root_id {
+                    let _ = store.set_album_genre_root(&album_id, root_id);
+                }
             }
         }
     }
@@ -241,6 +353,14 @@ pub fn known_artist_ids(play_threshold: u32) -> Option<HashSet<u64>> {
     store.get_known_artist_ids(play_threshold).ok()
 }
 
+/// Top-level genre ids ranked by recency-decayed play count (highest first),
+/// for ordering the genre chips. `None` when reco is cold/disabled.
+pub fn genre_affinity_scores() -> Option<Vec<(u64, f64)>> {
+    let guard = RECO.lock().ok()?;
+    let store = guard.as_ref()?;
+    store.get_genre_affinity_scores().ok()
+}
+
 /// Most-recently-played distinct Qobuz track ids (the local "already heard
 /// in-app" set). Kept for the external-reco filters; currently the deep-cut row
 /// filters on album ids, so this is unused for now.
@@ -0,0 +1,289 @@
+//! Write QBZ's loudness measurements into local files as ReplayGain tags.
+//!
+//! The loudness cache holds a normalization gain per local track (keyed by
+//! library track id), measured against the normalization target in effect
+//! at the time and stored with it. Other players can't see it, so the local
+//! album header can embed it: the gain is converted to ReplayGain 2.0
+//! (relative to -18 LUFS) using that stored target and written with lofty
+//! through `qbz_library::write_replaygain_to_file` (temp copy + rename).
+//! Album gain is added when every track of the album has a measurement.
+//! Peaks are only written when the cache has one; the live analyzer doesn't
+//! record them.
+//!
+//! The reverse also works: a pre-tagged album can import its existing
+//! REPLAYGAIN_* tags into the cache (converted to the current target), so
+//! the player applies them right away instead of re-analysing.
+
+use qbz_audio::settings::AudioSettingsStore;
+use qbz_audio::LoudnessCache;
+use qbz_library::{
+    GainImportSummary, LocalTrack, MetadataExtractor, ReplayGainWrite, WriteGainSummary,
+    REPLAYGAIN_REFERENCE_LUFS,
+};
+use slint::Weak;
+
+use crate::AppWindow;
+
+/// Embed the cached gain of `track_ids` in their files. Tracks without a
+/// measurement, CUE tracks (one file, many tracks) and non-local sources are
+/// skipped. Blocking: run on the blocking pool.
+pub fn write_replaygain_to_files(
+    track_ids: &[i64],
+    include_album: bool,
+) -> Result<WriteGainSummary, String> {
+    let cache = LoudnessCache::new()?;
+    let target_lufs = AudioSettingsStore::new()?
+        .get_settings()?
+        .normalization_target_lufs;
+    let tracks: Vec<LocalTrack> = crate::library_db::with_db(|db| {
+        track_ids
+            .iter()
+            .filter_map(|id| db.get_track(*id).transpose())
+            .collect()
+    })
+    .unwrap_or_default();
+
+    // Cached gain = target - loudness; ReplayGain = reference - loudness.
+    // The target is the one the gain was computed against; rows cached
+    // before it was recorded fall back to the current one.
+    let measured: Vec<(&LocalTrack, f32, Option<f32>)> = tracks
+        .iter()
+        .filter(|t| matches!(t.source.as_deref(), None | Some("local")))
+        .filter(|t| t.cue_file_path.is_none() && t.cue_start_secs.is_none())
+        .filter_map(|t| {
+            let cached = cache.get(t.id as u64)?;
+            let target = cached.target_lufs.unwrap_or(target_lufs);
+            let gain = cached.gain_db + REPLAYGAIN_REFERENCE_LUFS - target;
+            Some((t, gain, (cached.peak > 0.0).then_some(cached.peak)))
+        })
+        .collect();
+
+    let whole_album = include_album && !tracks.is_empty() && measured.len() == tracks.len();
+    let (album_gain_db, album_peak) = if whole_album {
+        let gains: Vec<f32> = measured.iter().map(|(_, gain, _)| *gain).collect();
+        let peaks: Option<Vec<f32>> = measured.iter().map(|(_, _, peak)| *peak).collect();
+        (
+            qbz_library::album_replaygain(&gains),
+            peaks.and_then(|p| p.into_iter().reduce(f32::max)),
+        )
+    } else {
+        (None, None)
+    };
+
+    let mut summary = WriteGainSummary {
+        skipped: track_ids.len() - measured.len(),
+        ..Default::default()
+    };
+    for (track, track_gain_db, track_peak) in measured {
+        let write = ReplayGainWrite {
+            file_path: track.file_path.clone(),
+            track_gain_db,
+            track_peak,
+            album_gain_db,
+            album_peak,
+        };
+        match qbz_library::write_replaygain_to_file(&write) {
+            Ok(()) => summary.written += 1,
+            Err(e) => {
+                log::warn!("[qbz-slint] replaygain: {} failed: {e}", track.file_path);
+                summary
+                    .failed
+                    .push((track.file_path.clone(), e.to_string()));
+            }
+        }
+    }
+    log::info!(
+        "[qbz-slint] replaygain: {} written, {} skipped, {} failed",
+        summary.written,
+        summary.skipped,
+        summary.failed.len()
+    );
+    Ok(summary)
+}
+
+/// Store the ReplayGain tags of `track_ids`' files in the loudness cache
+/// (the `v2_library_import_replaygain_from_files` equivalent). Files without
+/// a track gain tag, CUE tracks and non-local sources are skipped. Blocking:
+/// run on the blocking pool.
+pub fn import_replaygain_from_files(track_ids: &[i64]) -> Result<GainImportSummary, String> {
+    let cache = LoudnessCache::new()?;
+    let target_lufs = AudioSettingsStore::new()?
+        .get_settings()?
+        .normalization_target_lufs;
+    let tracks: Vec<LocalTrack> = crate::library_db::with_db(|db| {
+        track_ids
+            .iter()
+            .filter_map(|id| db.get_track(*id).transpose())
+            .collect()
+    })
+    .unwrap_or_default();
+
+    let mut summary = GainImportSummary {
+        skipped: track_ids.len() - tracks.len(),
+        ..Default::default()
+    };
+    for track in &tracks {
+        if !matches!(track.source.as_deref(), None | Some("local"))
+            || track.cue_file_path.is_some()
+            || track.cue_start_secs.is_some()
+        {
+            summary.skipped += 1;
+            continue;
+        }
+        let path = std::path::Path::new(&track.file_path);
+        let tags = match MetadataExtractor::extract_replaygain(path) {
+            Ok(tags) => tags,
+            Err(e) => {
+                log::warn!(
+                    "[qbz-slint] replaygain import: {} failed: {e}",
+                    track.file_path
+                );
+                summary
+                    .failed
+                    .push((track.file_path.clone(), e.to_string()));
+                continue;
+            }
+        };
+        let missing = tags.missing();
+        if !missing.is_empty() {
+            summary.missing.push((track.file_path.clone(), missing));
+        }
+        // ReplayGain = reference - loudness; cached gain = target - loudness.
+        let Some(track_gain_db) = tags.track_gain_db else {
+            summary.skipped += 1;
+            continue;
+        };
+        let gain_db = track_gain_db + target_lufs - REPLAYGAIN_REFERENCE_LUFS;
+        cache.set(
+            track.id as u64,
+            gain_db,
+            tags.track_peak.unwrap_or(0.0),
+            "replaygain",
+            target_lufs,
+        );
+        summary.imported += 1;
+    }
+    log::info!(
+        "[qbz-slint] replaygain import: {} imported, {} skipped, {} with missing tags, {} failed",
+        summary.imported,
+        summary.skipped,
+        summary.missing.len(),
+        summary.failed.len()
+    );
+    Ok(summary)
+}
+
+/// Album-header action: import the album's tags off-thread and report the
+/// outcome as toasts.
+pub fn import_album(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, track_ids: Vec<i64>) {
+    if track_ids.is_empty() {
+        return;
+    }
+    handle.spawn(async move {
+        let result = tokio::task::spawn_blocking(move || import_replaygain_from_files(&track_ids))
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+        let summary = match result {
+            Ok(summary) => summary,
+            Err(e) => {
+                log::error!("[qbz-slint] replaygain import: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Could not import ReplayGain tags"));
+                return;
+            }
+        };
+        if summary.imported > 0 {
+            let n = summary.imported;
+            crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "ReplayGain imported for {} track",
+                    "ReplayGain imported for {} tracks",
+                    n as i64,
+                    &[&n.to_string()],
+                ),
+            );
+        }
+        let untagged = summary.skipped + summary.failed.len();
+        if untagged > 0 {
+            crate::toast::info_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "{} track has no ReplayGain tags",
+                    "{} tracks have no ReplayGain tags",
+                    untagged as i64,
+                    &[&untagged.to_string()],
+                ),
+            );
+        }
+    });
+}
+
+/// Album-header action: confirm, write the album's tags off-thread, and
+/// report the outcome as toasts.
+pub fn write_album(weak: Weak<AppWindow>, handle: tokio::runtime::Handle, track_ids: Vec<i64>) {
+    if track_ids.is_empty() {
+        return;
+    }
+    handle.spawn(async move {
+        let ok = rfd::AsyncMessageDialog::new()
+            .set_title(&qbz_i18n::t("Write ReplayGain tags to audio files?"))
+            .set_description(&qbz_i18n::t(
+                "This modifies your audio files on disk and cannot be undone.",
+            ))
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show()
+            .await
+            == rfd::MessageDialogResult::Yes;
+        if !ok {
+            return;
+        }
+        let result =
+            tokio::task::spawn_blocking(move || write_replaygain_to_files(&track_ids, true))
+                .await
+                .unwrap_or_else(|e| Err(e.to_string()));
+        let summary = match result {
+            Ok(summary) => summary,
+            Err(e) => {
+                log::error!("[qbz-slint] replaygain: {e}");
+                crate::toast::error_weak(&weak, qbz_i18n::t("Could not write ReplayGain tags"));
+                return;
+            }
+        };
+        if summary.written > 0 {
+            let n = summary.written;
+            crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "ReplayGain written to {} file",
+                    "ReplayGain written to {} files",
+                    n as i64,
+                    &[&n.to_string()],
+                ),
+            );
+        }
+        if !summary.failed.is_empty() {
+            let n = summary.failed.len();
+            crate::toast::error_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Could not write {} file",
+                    "Could not write {} files",
+                    n as i64,
+                    &[&n.to_string()],
+                ),
+            );
+        }
+        if summary.skipped > 0 {
+            let n = summary.skipped;
+            crate::toast::info_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "{} track has no loudness measurement yet",
+                    "{} tracks have no loudness measurement yet",
+                    n as i64,
+                    &[&n.to_string()],
+                ),
+            );
+        }
+    });
+}
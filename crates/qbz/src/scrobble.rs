@@ -17,10 +17,13 @@
 //!
 //! Offline behavior: engine offline OR call failure queues the scrobble —
 //! Last.fm into the SHARED per-user `offline_settings.db` `scrobble_queue`
-//! (same rows Tauri queues/flushes), ListenBrainz into the SHARED per-user
-//! `listenbrainz_v2.db` `listen_queue`. A watcher on the offline-mode engine
-//! drains both queues on every offline -> online edge (manual-flag exits
-//! included), plus once at shell entry.
+//! (same rows Tauri queues/flushes). ListenBrainz listens are written ahead
+//! into the SHARED per-user `listenbrainz_v2.db` `listen_queue` BEFORE the
+//! submit and marked sent on success, so a crash or dropped connection
+//! mid-submit never loses one. A watcher on the offline-mode engine drains
+//! both queues on every offline -> online edge (manual-flag exits included),
+//! plus once at shell entry; the ListenBrainz drain retries with exponential
+//! backoff (`qbz_integrations::listenbrainz::flush_retry_delay`).
 //!
 //! Persistence lives in `crate::scrobbler_settings` (the per-user
 //! `scrobbler_settings.db`); the auth flows seed/clear it. ListenBrainz
@@ -28,18 +31,25 @@
 //! credentials row, so the Tauri build sees the same sign-in (and a Tauri
 //! sign-in seeds this build at shell entry).
 
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 
 use slint::{ComponentHandle, Weak};
 
 use qbz_app::offline_mode::OfflineModeStore;
+use qbz_app::shell::AppRuntime;
 use qbz_integrations::listenbrainz::cache::ListenBrainzCache;
-use qbz_integrations::listenbrainz::AdditionalInfo;
+use qbz_integrations::listenbrainz::{flush_retry_delay, AdditionalInfo};
 use qbz_integrations::{LastFmClient, ListenBrainzClient};
+use qbz_playlist_import::match_qobuz::match_tracks;
+use qbz_playlist_import::providers::spotify_history;
+use qbz_playlist_import::{ImportEvent, ImportProgressSink, ImportTrack, TrackMatch};
 
+use crate::adapter::SlintAdapter;
 use crate::scrobbler_settings;
 use crate::{AppWindow, ScrobbleState};
 
@@ -79,6 +89,11 @@ fn rt_handle() -> Option<tokio::runtime::Handle> {
 /// One-shot guard for the engine-watch flush task (lives for the process).
 static FLUSH_WATCHER: OnceLock<()> = OnceLock::new();
 
+/// Serializes ListenBrainz queue flushes. The watcher and "Send now" can
+/// overlap; a second pass must only read the queue after the first has
+/// marked what it sent, or those listens go out twice.
+static LISTENBRAINZ_FLUSH: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
 /// Per-user runtime start, called from `init_shell_for_user` AFTER
 /// `scrobbler_settings::init_for_user`. Captures the tokio handle for the
 /// fire path, seeds ListenBrainz credentials from the SHARED cache when this
@@ -162,6 +177,9 @@ pub fn load(weak: Weak<AppWindow>) {
         s.set_status_text("".into());
         s.set_status_kind(0);
     });
+    if let Some(handle) = rt_handle() {
+        handle.spawn(async move { refresh_listenbrainz_queue_count(&weak).await });
+    }
 }
 
 pub fn enable_toggle(weak: Weak<AppWindow>, enabled: bool) {
@@ -300,6 +318,171 @@ pub fn lastfm_disconnect(weak: Weak<AppWindow>) {
     set_status(&weak, qbz_i18n::t("Last.fm disconnected"), 1);
 }
 
+// --- Last.fm loved tracks -> Qobuz favorites ---------------------------------
+
+/// `user.getLovedTracks` page size (the Last.fm maximum).
+const LOVED_PAGE_SIZE: u32 = 200;
+/// Page cap: 10 000 loved tracks is far past any real list, and keeps a
+/// misbehaving proxy from paging forever.
+const LOVED_MAX_PAGES: u32 = 50;
+
+/// Counts reported by a loved-tracks sync (or its dry run).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncSummary {
+    /// Loved tracks matched to a Qobuz track.
+    pub matched: u32,
+    /// Loved tracks with no confident Qobuz match.
+    pub unmatched: u32,
+    /// Matched tracks newly favorited — or, on a dry run, that would be.
+    pub added: u32,
+}
+
+impl SyncSummary {
+    /// Tally the matcher output. `is_favorite` answers for tracks that are
+    /// already Qobuz favorites (never re-added, never counted as added).
+    fn tally(matches: &[TrackMatch], is_favorite: impl Fn(u64) -> bool) -> (Self, Vec<u64>) {
+        let mut summary = Self::default();
+        let mut to_add = Vec::new();
+        for m in matches {
+            match m.qobuz_track_id {
+                Some(id) => {
+                    summary.matched += 1;
+                    if !is_favorite(id) && !to_add.contains(&id) {
+                        to_add.push(id);
+                    }
+                }
+                None => summary.unmatched += 1,
+            }
+        }
+        summary.added = to_add.len() as u32;
+        (summary, to_add)
+    }
+}
+
+/// One-way sync of the signed-in user's Last.fm loved tracks into Qobuz
+/// favorites. Last.fm carries no ISRCs, so each love is matched by artist +
+/// title through the playlist importer's Qobuz matcher. Existing favorites
+/// are left alone and nothing is ever removed. `dry_run` reports what would
+/// be added without touching favorites.
+pub fn lastfm_sync_loved(
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    dry_run: bool,
+) {
+    let username = scrobbler_settings::get().lastfm_username;
+    if username.is_empty() {
+        set_status(&weak, qbz_i18n::t("Connect Last.fm first"), 3);
+        return;
+    }
+    let set_busy = |weak: &Weak<AppWindow>, busy: bool| {
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            w.global::<ScrobbleState>().set_lastfm_sync_busy(busy);
+        });
+    };
+    set_busy(&weak, true);
+    handle.spawn(async move {
+        let Some(client) = runtime.core().client().read().await.clone() else {
+            set_status(&weak, qbz_i18n::t("Not logged in to Qobuz"), 3);
+            set_busy(&weak, false);
+            return;
+        };
+
+        set_status(&weak, qbz_i18n::t("Fetching loved tracks from Last.fm..."), 1);
+        let lastfm = LastFmClient::new();
+        let mut loved = Vec::new();
+        for page in 1..=LOVED_MAX_PAGES {
+            match lastfm
+                .get_loved_tracks(&username, LOVED_PAGE_SIZE, page)
+                .await
+            {
+                Ok(batch) => {
+                    let last_page = batch.len() < LOVED_PAGE_SIZE as usize;
+                    loved.extend(batch);
+                    if last_page {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    log::warn!("[qbz-slint] Last.fm loved tracks page {page} failed: {e}");
+                    set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e.to_string()]), 3);
+                    set_busy(&weak, false);
+                    return;
+                }
+            }
+        }
+
+        let tracks: Vec<ImportTrack> = loved
+            .into_iter()
+            .map(|t| ImportTrack {
+                title: t.name,
+                artist: t.artist,
+                album: t.album,
+                duration_ms: None,
+                isrc: None,
+                provider_id: t.mbid,
+                provider_url: None,
+            })
+            .collect();
+        set_status(
+            &weak,
+            qbz_i18n::t_args("Matching {} loved tracks on Qobuz...", &[&tracks.len().to_string()]),
+            1,
+        );
+        let sink: Arc<dyn ImportProgressSink> = Arc::new(|_: ImportEvent| {});
+        let matches = match match_tracks(&client, &tracks, sink).await {
+            Ok(m) => m,
+            Err(e) => {
+                set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e.to_string()]), 3);
+                set_busy(&weak, false);
+                return;
+            }
+        };
+
+        let (mut summary, to_add) =
+            SyncSummary::tally(&matches, |id| crate::fav_cache::is_favorite(&id.to_string()));
+        if !dry_run {
+            summary.added = 0;
+            for id in to_add {
+                match runtime.core().add_favorite("track", &id.to_string()).await {
+                    Ok(()) => {
+                        summary.added += 1;
+                        crate::fav_cache::set(id, true);
+                        tokio::task::spawn_blocking(move || {
+                            crate::reco::log_favorite_track(id, None, None)
+                        });
+                    }
+                    Err(e) => log::warn!("[qbz-slint] loved-track favorite {id} failed: {e}"),
+                }
+            }
+        }
+        log::info!(
+            "[qbz-slint] Last.fm loved sync (dry_run={dry_run}): {} matched, {} unmatched, {} added",
+            summary.matched,
+            summary.unmatched,
+            summary.added
+        );
+        let (matched, unmatched, added) = (
+            summary.matched.to_string(),
+            summary.unmatched.to_string(),
+            summary.added.to_string(),
+        );
+        let line = if dry_run {
+            qbz_i18n::t_args(
+                "{} matched, {} not found on Qobuz, {} would be added to favorites",
+                &[&matched, &unmatched, &added],
+            )
+        } else {
+            qbz_i18n::t_args(
+                "{} matched, {} not found on Qobuz, {} added to favorites",
+                &[&matched, &unmatched, &added],
+            )
+        };
+        set_status(&weak, line, 2);
+        set_busy(&weak, false);
+    });
+}
+
 // --- ListenBrainz ------------------------------------------------------------
 
 pub fn listenbrainz_enable_toggle(weak: Weak<AppWindow>, enabled: bool) {
@@ -361,6 +544,362 @@ pub fn listenbrainz_set_token(weak: Weak<AppWindow>, handle: tokio::runtime::Han
     });
 }
 
+/// `GET /user/{user}/listens` page size (the API maximum).
+const LB_HISTORY_PAGE_SIZE: u32 = 1000;
+/// Reco import cursor key for ListenBrainz history.
+const LB_IMPORT_SOURCE: &str = "listenbrainz";
+/// Listens pulled per history import run (ListenBrainz and Spotify). Matches
+/// reco's training window (`TrainParams::max_events`), so anything older
+/// would never score anyway.
+pub const LB_IMPORT_MAX_LISTENS: u32 = 5000;
+
+/// Counts reported by a listening-history import.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ListenImportSummary {
+    /// Listens newer than the previous import that were fetched.
+    pub fetched: u32,
+    /// Listens matched to Qobuz and logged as reco plays.
+    pub imported: u32,
+    /// Listens with no confident Qobuz match (skipped).
+    pub unmatched: u32,
+}
+
+/// Dedup key for matching: the same song scrobbled 300 times is searched
+/// on Qobuz once.
+fn listen_key(artist: &str, title: &str) -> (String, String) {
+    (artist.trim().to_lowercase(), title.trim().to_lowercase())
+}
+
+/// One listen from an external history, normalized across sources.
+struct HistoryListen {
+    artist: String,
+    title: String,
+    /// Unix seconds
+    played_at: i64,
+    recording_mbid: Option<String>,
+}
+
+/// Shared tail of the history imports: match each distinct song on Qobuz by
+/// artist + title, then log the matched listens as reco plays at their
+/// original time and move `source`'s cursor to `new_cursor`. Unmatched
+/// songs are logged and counted.
+async fn import_history_listens(
+    client: &qbz_qobuz::QobuzClient,
+    weak: &Weak<AppWindow>,
+    source: &'static str,
+    listens: &[HistoryListen],
+    new_cursor: Option<i64>,
+) -> Result<ListenImportSummary, String> {
+    let mut summary = ListenImportSummary {
+        fetched: listens.len() as u32,
+        ..Default::default()
+    };
+
+    // Distinct song -> its index in `tracks` (and so in the match results).
+    let mut keys: HashMap<(String, String), usize> = HashMap::new();
+    let mut tracks: Vec<ImportTrack> = Vec::new();
+    for listen in listens {
+        let key = listen_key(&listen.artist, &listen.title);
+        if let Entry::Vacant(slot) = keys.entry(key) {
+            slot.insert(tracks.len());
+            tracks.push(ImportTrack {
+                title: listen.title.clone(),
+                artist: listen.artist.clone(),
+                album: None,
+                duration_ms: None,
+                isrc: None,
+                provider_id: listen.recording_mbid.clone(),
+                provider_url: None,
+            });
+        }
+    }
+    set_status(
+        weak,
+        qbz_i18n::t_args("Matching {} songs on Qobuz...", &[&tracks.len().to_string()]),
+        1,
+    );
+    let sink: Arc<dyn ImportProgressSink> = Arc::new(|_: ImportEvent| {});
+    let matches = match_tracks(client, &tracks, sink)
+        .await
+        .map_err(|e| e.to_string())?;
+    for m in matches.iter().filter(|m| m.qobuz_track_id.is_none()) {
+        log::debug!(
+            "[qbz-slint] {source} import: no Qobuz match for {} - {}",
+            m.source.artist,
+            m.source.title
+        );
+    }
+
+    let plays: Vec<crate::reco::ImportedPlay> = listens
+        .iter()
+        .filter_map(|listen| {
+            let key = listen_key(&listen.artist, &listen.title);
+            let m = matches.get(*keys.get(&key)?)?;
+            Some(crate::reco::ImportedPlay {
+                track_id: m.qobuz_track_id?,
+                album_id: m.qobuz_album_id.clone(),
+                artist_id: m.qobuz_artist_id,
+                played_at: listen.played_at,
+            })
+        })
+        .collect();
+    summary.unmatched = summary.fetched - plays.len() as u32;
+    summary.imported = tokio::task::spawn_blocking(move || {
+        crate::reco::log_imported_plays(source, &plays, new_cursor)
+    })
+    .await
+    .unwrap_or(0);
+    Ok(summary)
+}
+
+/// Import the signed-in user's ListenBrainz history into the reco store so
+/// recommendations are seeded from day one. Walks the history newest-first
+/// and stops at the previous run's cursor (so a re-run only brings in new
+/// listens) or after `max_listens`. Each distinct song is matched on Qobuz
+/// by artist + title; matched listens are logged as plays at their original
+/// time, unmatched ones are counted and skipped.
+pub fn listenbrainz_import_history(
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    max_listens: u32,
+) {
+    let cfg = scrobbler_settings::get();
+    if cfg.listenbrainz_username.is_empty() {
+        set_status(&weak, qbz_i18n::t("Connect ListenBrainz first"), 3);
+        return;
+    }
+    let set_busy = |weak: &Weak<AppWindow>, busy: bool| {
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            w.global::<ScrobbleState>().set_listenbrainz_import_busy(busy);
+        });
+    };
+    set_busy(&weak, true);
+    handle.spawn(async move {
+        let Some(client) = runtime.core().client().read().await.clone() else {
+            set_status(&weak, qbz_i18n::t("Not logged in to Qobuz"), 3);
+            set_busy(&weak, false);
+            return;
+        };
+        let lb = ListenBrainzClient::new();
+        lb.restore_token(cfg.listenbrainz_token.clone(), cfg.listenbrainz_username.clone())
+            .await;
+        let cursor = tokio::task::spawn_blocking(|| crate::reco::import_cursor(LB_IMPORT_SOURCE))
+            .await
+            .ok()
+            .flatten();
+
+        set_status(&weak, qbz_i18n::t("Fetching listening history from ListenBrainz..."), 1);
+        let mut listens = Vec::new();
+        let mut max_ts: Option<u64> = None;
+        'pages: while listens.len() < max_listens as usize {
+            let page = match lb
+                .get_user_listens(&cfg.listenbrainz_username, None, max_ts, LB_HISTORY_PAGE_SIZE)
+                .await
+            {
+                Ok(page) => page,
+                Err(e) => {
+                    log::warn!("[qbz-slint] ListenBrainz history page failed: {e}");
+                    set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e.to_string()]), 3);
+                    set_busy(&weak, false);
+                    return;
+                }
+            };
+            // An empty page is the end of the history; a short one isn't
+            // (ListenBrainz can return fewer than asked mid-history).
+            let Some(oldest) = page.last().map(|l| l.listened_at.max(0) as u64) else {
+                break;
+            };
+            for listen in page {
+                if cursor.is_some_and(|c| listen.listened_at <= c) {
+                    break 'pages;
+                }
+                listens.push(listen);
+                if listens.len() >= max_listens as usize {
+                    break 'pages;
+                }
+            }
+            // No progress (every listen on the page shares one timestamp).
+            if max_ts == Some(oldest) {
+                break;
+            }
+            max_ts = Some(oldest);
+        }
+
+        if listens.is_empty() {
+            set_status(&weak, qbz_i18n::t("No new listens to import"), 2);
+            set_busy(&weak, false);
+            return;
+        }
+        // Newest-first walk: the first listen is the new cursor.
+        let new_cursor = listens.first().map(|l| l.listened_at);
+        let listens: Vec<HistoryListen> = listens
+            .into_iter()
+            .map(|listen| HistoryListen {
+                artist: listen.artist_name,
+                title: listen.track_name,
+                played_at: listen.listened_at,
+                recording_mbid: listen.recording_mbid,
+            })
+            .collect();
+        let summary =
+            match import_history_listens(&client, &weak, LB_IMPORT_SOURCE, &listens, new_cursor)
+                .await
+            {
+                Ok(summary) => summary,
+                Err(e) => {
+                    set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e]), 3);
+                    set_busy(&weak, false);
+                    return;
+                }
+            };
+
+        log::info!(
+            "[qbz-slint] ListenBrainz history import: {} fetched, {} imported, {} unmatched",
+            summary.fetched,
+            summary.imported,
+            summary.unmatched
+        );
+        set_status(
+            &weak,
+            qbz_i18n::t_args(
+                "Imported {} of {} listens ({} not found on Qobuz)",
+                &[
+                    &summary.imported.to_string(),
+                    &summary.fetched.to_string(),
+                    &summary.unmatched.to_string(),
+                ],
+            ),
+            2,
+        );
+        set_busy(&weak, false);
+    });
+}
+
+/// Reco import cursor key for Spotify data exports.
+const SPOTIFY_IMPORT_SOURCE: &str = "spotify";
+
+/// Import a Spotify data export ("Download your data") into the reco store.
+/// The user picks the `StreamingHistory_music_*.json` files; listens older
+/// than the previous Spotify import are skipped (so re-importing the same
+/// export is a no-op), skipped plays (under 30 s, or flagged skipped) are
+/// dropped, repeats within 30 s collapse into one play, and only the newest
+/// `max_listens` are kept. Matching and logging are shared with
+/// the ListenBrainz import. Local-library tracks can't seed reco (non-Qobuz
+/// ids are gated out), so matching is Qobuz-only.
+pub fn spotify_import_history(
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    max_listens: u32,
+) {
+    let set_busy = |weak: &Weak<AppWindow>, busy: bool| {
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            w.global::<ScrobbleState>().set_spotify_import_busy(busy);
+        });
+    };
+    handle.spawn(async move {
+        let Some(files) = rfd::AsyncFileDialog::new()
+            .set_title(&qbz_i18n::t("Choose Spotify streaming history files"))
+            .add_filter("JSON", &["json"])
+            .pick_files()
+            .await
+        else {
+            return; // user cancelled
+        };
+        let Some(client) = runtime.core().client().read().await.clone() else {
+            set_status(&weak, qbz_i18n::t("Not logged in to Qobuz"), 3);
+            return;
+        };
+        set_busy(&weak, true);
+
+        let paths: Vec<PathBuf> = files.iter().map(|f| f.path().to_path_buf()).collect();
+        let parsed = tokio::task::spawn_blocking(move || {
+            let mut listens = Vec::new();
+            for path in &paths {
+                listens.extend(spotify_history::parse(path)?);
+            }
+            listens.sort_by_key(|l: &spotify_history::SpotifyListen| l.ended_at);
+            let cursor = crate::reco::import_cursor(SPOTIFY_IMPORT_SOURCE);
+            Ok::<_, qbz_playlist_import::PlaylistImportError>(
+                spotify_history::dedupe(listens)
+                    .into_iter()
+                    .filter(|l| !cursor.is_some_and(|c| l.ended_at <= c))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .await;
+        let mut listens = match parsed {
+            Ok(Ok(listens)) => listens,
+            Ok(Err(e)) => {
+                set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e.to_string()]), 3);
+                set_busy(&weak, false);
+                return;
+            }
+            Err(e) => {
+                log::warn!("[qbz-slint] Spotify history parse task failed: {e}");
+                set_busy(&weak, false);
+                return;
+            }
+        };
+        if listens.is_empty() {
+            set_status(&weak, qbz_i18n::t("No new listens to import"), 2);
+            set_busy(&weak, false);
+            return;
+        }
+        // Oldest-first: keep the newest tail, which also holds the cursor.
+        if listens.len() > max_listens as usize {
+            listens.drain(..listens.len() - max_listens as usize);
+        }
+        let new_cursor = listens.last().map(|l| l.ended_at);
+        let listens: Vec<HistoryListen> = listens
+            .into_iter()
+            .map(|listen| HistoryListen {
+                artist: listen.artist,
+                title: listen.title,
+                played_at: listen.ended_at,
+                recording_mbid: None,
+            })
+            .collect();
+        let summary = match import_history_listens(
+            &client,
+            &weak,
+            SPOTIFY_IMPORT_SOURCE,
+            &listens,
+            new_cursor,
+        )
+        .await
+        {
+            Ok(summary) => summary,
+            Err(e) => {
+                set_status(&weak, qbz_i18n::t_args("Error: {}", &[&e]), 3);
+                set_busy(&weak, false);
+                return;
+            }
+        };
+
+        log::info!(
+            "[qbz-slint] Spotify history import: {} listens, {} imported, {} unmatched",
+            summary.fetched,
+            summary.imported,
+            summary.unmatched
+        );
+        set_status(
+            &weak,
+            qbz_i18n::t_args(
+                "Imported {} of {} listens ({} not found on Qobuz)",
+                &[
+                    &summary.imported.to_string(),
+                    &summary.fetched.to_string(),
+                    &summary.unmatched.to_string(),
+                ],
+            ),
+            2,
+        );
+        set_busy(&weak, false);
+    });
+}
+
 pub fn listenbrainz_disconnect(weak: Weak<AppWindow>) {
     scrobbler_settings::disconnect_listenbrainz();
     // Clear the shared cache credentials too (mirrors Tauri's disconnect).
@@ -489,9 +1028,10 @@ async fn send_now_playing(meta: &ScrobbleMeta, cfg: &scrobbler_settings::Scrobbl
 }
 
 /// Fire the actual scrobble for each enabled service. Engine offline OR call
-/// failure queues it — Last.fm to the shared `scrobble_queue`, ListenBrainz to
-/// the shared `listen_queue`. Re-reads settings in case the user disconnected
-/// while the timer waited.
+/// failure queues it — Last.fm to the shared `scrobble_queue`; ListenBrainz
+/// is queued to the shared `listen_queue` up front and only marked sent once
+/// the submit succeeds. Re-reads settings in case the user disconnected while
+/// the timer waited.
 async fn send_scrobble(meta: &ScrobbleMeta) {
     let cfg = scrobbler_settings::get();
     let album = meta.album.as_deref();
@@ -530,9 +1070,8 @@ async fn send_scrobble(meta: &ScrobbleMeta) {
     }
 
     if cfg.listenbrainz_active() {
-        let sent = if offline {
-            false
-        } else {
+        let queued_id = queue_listenbrainz(meta, timestamp).await;
+        if !offline {
             let client = ListenBrainzClient::new();
             client
                 .restore_token(cfg.listenbrainz_token.clone(), cfg.listenbrainz_username.clone())
@@ -553,18 +1092,16 @@ async fn send_scrobble(meta: &ScrobbleMeta) {
                         meta.artist,
                         meta.track
                     );
-                    true
+                    if let Some(id) = queued_id {
+                        mark_listenbrainz_sent(id).await;
+                    }
                 }
                 Err(e) => {
                     log::warn!(
-                        "[qbz-slint] ListenBrainz scrobble failed ({e}); queueing for later"
+                        "[qbz-slint] ListenBrainz scrobble failed ({e}); left queued for later"
                     );
-                    false
                 }
             }
-        };
-        if !sent {
-            queue_listenbrainz(meta, timestamp).await;
         }
     }
 }
@@ -594,33 +1131,51 @@ async fn queue_lastfm(meta: &ScrobbleMeta, timestamp: i64) {
 
 /// Queue a ListenBrainz listen into the SHARED per-user
 /// `ListenBrainzCache.listen_queue` (the canonical LB offline store).
-async fn queue_listenbrainz(meta: &ScrobbleMeta, timestamp: i64) {
-    let Some(path) = listenbrainz_cache_path() else {
-        return;
-    };
+/// Returns the row id so the caller can mark it sent after a live submit.
+async fn queue_listenbrainz(meta: &ScrobbleMeta, timestamp: i64) -> Option<i64> {
+    let path = listenbrainz_cache_path()?;
     let artist = meta.artist.clone();
     let track = meta.track.clone();
     let album = meta.album.clone();
     let duration_ms = (meta.duration_secs > 0).then_some(meta.duration_secs * 1000);
-    let _ = tokio::task::spawn_blocking(move || match ListenBrainzCache::new(&path) {
-        Ok(cache) => {
-            if let Err(e) = cache.queue_listen(
-                timestamp,
-                &artist,
-                &track,
-                album.as_deref(),
-                None,
-                None,
-                None,
-                None,
-                duration_ms,
-            ) {
+    tokio::task::spawn_blocking(move || match ListenBrainzCache::new(&path) {
+        Ok(cache) => match cache.queue_listen(
+            timestamp,
+            &artist,
+            &track,
+            album.as_deref(),
+            None,
+            None,
+            None,
+            None,
+            duration_ms,
+        ) {
+            Ok(id) => Some(id),
+            Err(e) => {
                 log::warn!("[qbz-slint] queue ListenBrainz listen failed: {e}");
+                None
             }
+        },
+        Err(e) => {
+            log::warn!("[qbz-slint] open ListenBrainz cache failed: {e}");
+            None
         }
-        Err(e) => log::warn!("[qbz-slint] open ListenBrainz cache failed: {e}"),
     })
-    .await;
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Mark one written-ahead listen as delivered.
+async fn mark_listenbrainz_sent(id: i64) {
+    let Some(path) = listenbrainz_cache_path() else {
+        return;
+    };
+    let result =
+        tokio::task::spawn_blocking(move || ListenBrainzCache::new(&path)?.mark_sent(id)).await;
+    if let Ok(Err(e)) = result {
+        log::warn!("[qbz-slint] mark ListenBrainz listen sent failed: {e}");
+    }
 }
 
 /// `<user_dir>/cache/listenbrainz_v2.db` — the SAME per-user file Tauri's
@@ -638,7 +1193,7 @@ fn listenbrainz_cache_path() -> Option<PathBuf> {
 
 async fn flush_offline_queues() {
     flush_lastfm_queue().await;
-    flush_listenbrainz_queue().await;
+    flush_listenbrainz_with_backoff().await;
 }
 
 /// Flush the Last.fm queue: up to 50 per pass (the Last.fm batch limit),
@@ -713,16 +1268,44 @@ async fn flush_lastfm_queue() {
     }
 }
 
-/// Flush the ListenBrainz queue from the shared cache. Stops at the first
-/// failure and retries on the next edge.
-async fn flush_listenbrainz_queue() {
+/// Drain the ListenBrainz queue, retrying a stalled pass after 1 s, 2 s, 4 s
+/// and 8 s; gives up after the fifth attempt (rows stay queued for the next
+/// edge). Bails out early if the engine goes offline meanwhile. Returns the
+/// number of listens sent.
+async fn flush_listenbrainz_with_backoff() -> usize {
+    let mut total = 0;
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let (sent, stalled) = flush_listenbrainz_queue().await;
+        total += sent;
+        if !stalled {
+            break;
+        }
+        let Some(delay) = flush_retry_delay(attempt) else {
+            log::warn!("[qbz-slint] ListenBrainz flush: giving up after {attempt} attempts");
+            break;
+        };
+        tokio::time::sleep(delay).await;
+        if crate::offline_mode::engine().is_offline() {
+            break;
+        }
+    }
+    total
+}
+
+/// One pass over the ListenBrainz queue in the shared cache, oldest first.
+/// Stops at the first failure. Returns `(sent, stalled)` — `stalled` means a
+/// submit failed with listens still pending.
+async fn flush_listenbrainz_queue() -> (usize, bool) {
     let cfg = scrobbler_settings::get();
     if !cfg.listenbrainz_is_authed() {
-        return;
+        return (0, false);
     }
     let Some(path) = listenbrainz_cache_path() else {
-        return;
+        return (0, false);
     };
+    let _flush = LISTENBRAINZ_FLUSH.lock().await;
     let pending = match tokio::task::spawn_blocking({
         let path = path.clone();
         move || ListenBrainzCache::new(&path).and_then(|c| c.get_pending_listens(500))
@@ -730,10 +1313,10 @@ async fn flush_listenbrainz_queue() {
     .await
     {
         Ok(Ok(p)) => p,
-        _ => return,
+        _ => return (0, false),
     };
     if pending.is_empty() {
-        return;
+        return (0, false);
     }
 
     let client = ListenBrainzClient::new();
@@ -741,6 +1324,7 @@ async fn flush_listenbrainz_queue() {
         .restore_token(cfg.listenbrainz_token.clone(), cfg.listenbrainz_username.clone())
         .await;
     let mut sent_ids: Vec<i64> = Vec::new();
+    let mut failed_id = None;
     for item in pending {
         let info = AdditionalInfo {
             recording_mbid: item.recording_mbid.clone(),
@@ -763,15 +1347,131 @@ async fn flush_listenbrainz_queue() {
         {
             sent_ids.push(item.id);
         } else {
-            break; // still failing — retry on the next edge
+            failed_id = Some(item.id);
+            break; // still failing — the caller decides whether to retry
         }
     }
-    if !sent_ids.is_empty() {
-        let count = sent_ids.len();
-        let _ = tokio::task::spawn_blocking(move || {
-            ListenBrainzCache::new(&path).and_then(|c| c.mark_listens_sent(&sent_ids))
-        })
-        .await;
+    let count = sent_ids.len();
+    let _ = tokio::task::spawn_blocking(move || {
+        let cache = ListenBrainzCache::new(&path)?;
+        if let Some(id) = failed_id {
+            cache.increment_attempts(id)?;
+        }
+        cache.mark_listens_sent(&sent_ids)
+    })
+    .await;
+    if count > 0 {
         log::info!("[qbz-slint] ListenBrainz flush: {count} listen(s) sent");
     }
+    (count, failed_id.is_some())
+}
+
+/// Settings > Integrations "Send now": one immediate pass over the queued
+/// listens (no backoff — the user can press it again), toasting how many
+/// went out.
+pub fn listenbrainz_flush_queue(weak: Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    let _ = weak.upgrade_in_event_loop(|w| {
+        w.global::<ScrobbleState>()
+            .set_listenbrainz_flush_busy(true)
+    });
+    handle.spawn(async move {
+        let (sent, stalled) = flush_listenbrainz_queue().await;
+        if stalled {
+            crate::toast::error_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Sent {} queued listen; ListenBrainz is unreachable for the rest",
+                    "Sent {} queued listens; ListenBrainz is unreachable for the rest",
+                    sent as i64,
+                    &[&sent.to_string()],
+                ),
+            );
+        } else {
+            crate::toast::success_weak(
+                &weak,
+                qbz_i18n::tf(
+                    "Sent {} queued listen",
+                    "Sent {} queued listens",
+                    sent as i64,
+                    &[&sent.to_string()],
+                ),
+            );
+        }
+        refresh_listenbrainz_queue_count(&weak).await;
+        let _ = weak.upgrade_in_event_loop(|w| {
+            w.global::<ScrobbleState>()
+                .set_listenbrainz_flush_busy(false)
+        });
+    });
+}
+
+/// Publish the number of listens still waiting in the queue.
+async fn refresh_listenbrainz_queue_count(weak: &Weak<AppWindow>) {
+    let Some(path) = listenbrainz_cache_path() else {
+        return;
+    };
+    let count =
+        tokio::task::spawn_blocking(move || ListenBrainzCache::new(&path)?.get_queue_count())
+            .await
+            .ok()
+            .and_then(Result::ok)
+            .unwrap_or(0);
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        w.global::<ScrobbleState>()
+            .set_listenbrainz_queue_count(count as i32)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matched(id: Option<u64>) -> TrackMatch {
+        TrackMatch {
+            source: ImportTrack {
+                title: "Title".to_string(),
+                artist: "Artist".to_string(),
+                album: None,
+                duration_ms: None,
+                isrc: None,
+                provider_id: None,
+                provider_url: None,
+            },
+            qobuz_track_id: id,
+            qobuz_title: None,
+            qobuz_artist: None,
+            qobuz_album_id: None,
+            qobuz_artist_id: None,
+            score: if id.is_some() { 0.9 } else { 0.0 },
+        }
+    }
+
+    #[test]
+    fn listen_key_ignores_case_and_padding() {
+        assert_eq!(
+            listen_key(" Radiohead", "Reckoner "),
+            listen_key("radiohead", "RECKONER")
+        );
+        assert_ne!(listen_key("Radiohead", "Reckoner"), listen_key("Radiohead", "Nude"));
+    }
+
+    #[test]
+    fn loved_sync_skips_existing_and_duplicate_favorites() {
+        let matches = [
+            matched(Some(1)),
+            matched(Some(2)),
+            matched(None),
+            matched(Some(2)),
+        ];
+        let (summary, to_add) = SyncSummary::tally(&matches, |id| id == 1);
+        assert_eq!(
+            summary,
+            SyncSummary {
+                matched: 3,
+                unmatched: 1,
+                added: 1,
+            }
+        );
+        assert_eq!(to_add, vec![2]);
+    }
 }
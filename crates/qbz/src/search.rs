@@ -5,8 +5,11 @@
 //! types into plain `Send` rows (the unit-tested layer), and
 //! `apply_search` writes the `SearchState` global on the Slint event loop.
 
+use std::cell::RefCell;
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use qbz_app::shell::AppRuntime;
 use qbz_core::FrontendAdapter;
@@ -82,6 +85,79 @@ pub fn is_current_immersive_search_version(version: u64) -> bool {
     IMMERSIVE_SEARCH_VERSION.with(|c| c.get() == version)
 }
 
+// ==================== Live-search debounce + cancellation ====================
+
+/// The live (type-ahead) search surfaces, each debounced and cancelled on its
+/// own so a keystroke in one never disturbs another's load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveSurface {
+    /// The results page (Intelligent Search off).
+    Results,
+    /// The header preview dropdown.
+    Cortinilla,
+    /// The immersive overlay's dropdown.
+    Immersive,
+}
+
+impl LiveSurface {
+    fn slot(self) -> usize {
+        match self {
+            LiveSurface::Results => 0,
+            LiveSurface::Cortinilla => 1,
+            LiveSurface::Immersive => 2,
+        }
+    }
+
+    fn built_in_debounce_ms(self) -> u64 {
+        match self {
+            LiveSurface::Results => 300,
+            LiveSurface::Cortinilla | LiveSurface::Immersive => 220,
+        }
+    }
+}
+
+/// User debounce override (`ui_prefs.search_debounce_ms`); 0 = built-in.
+static LIVE_DEBOUNCE_MS: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    /// The in-flight load per live surface. A newer fire aborts the previous
+    /// task, which drops its Qobuz request mid-flight rather than letting it
+    /// finish only for the version guard to discard it. UI thread only.
+    static LIVE_TASKS: RefCell<[Option<tokio::task::AbortHandle>; 3]> =
+        const { RefCell::new([None, None, None]) };
+}
+
+/// Apply the persisted debounce override (0 = built-in delays).
+pub fn set_live_debounce_ms(ms: u32) {
+    LIVE_DEBOUNCE_MS.store(ms, Ordering::Relaxed);
+}
+
+/// How long `surface` waits after the last keystroke before loading.
+pub fn live_debounce(surface: LiveSurface) -> Duration {
+    match LIVE_DEBOUNCE_MS.load(Ordering::Relaxed) {
+        0 => Duration::from_millis(surface.built_in_debounce_ms()),
+        ms => Duration::from_millis(ms as u64),
+    }
+}
+
+/// Record `task` as `surface`'s in-flight load, aborting the one it replaces.
+pub fn track_live_load(surface: LiveSurface, task: tokio::task::AbortHandle) {
+    replace_live_load(surface, Some(task));
+}
+
+/// Abort `surface`'s in-flight load, if any (query cleared / too short).
+pub fn cancel_live_load(surface: LiveSurface) {
+    replace_live_load(surface, None);
+}
+
+fn replace_live_load(surface: LiveSurface, task: Option<tokio::task::AbortHandle>) {
+    let previous =
+        LIVE_TASKS.with(|tasks| std::mem::replace(&mut tasks.borrow_mut()[surface.slot()], task));
+    if let Some(previous) = previous {
+        previous.abort();
+    }
+}
+
 // ==================== Plain (Send) row types ====================
 
 /// An album result row, before it becomes a Slint `AlbumCardItem`.
@@ -1858,6 +1934,17 @@ pub fn artwork_jobs_for_more(more: &MoreRows, start: usize) -> Vec<ArtworkJob> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn live_debounce_uses_the_override_for_every_surface() {
+        set_live_debounce_ms(0);
+        assert_eq!(live_debounce(LiveSurface::Results), Duration::from_millis(300));
+        assert_eq!(live_debounce(LiveSurface::Cortinilla), Duration::from_millis(220));
+        set_live_debounce_ms(500);
+        assert_eq!(live_debounce(LiveSurface::Results), Duration::from_millis(500));
+        assert_eq!(live_debounce(LiveSurface::Immersive), Duration::from_millis(500));
+        set_live_debounce_ms(0);
+    }
+
     #[test]
     fn category_for_tab_maps_per_type_tabs() {
         assert_eq!(category_for_tab(0), None);
@@ -18,14 +18,19 @@
 //! NO protected-audio code beyond threading an existing `start_position_secs`.
 //! The saved position rides along via [`take_resume_for`] and is consumed on the
 //! first play of the restored track, reusing the player's session-resume offset.
+//!
+//! Listening statistics ride on the same store but are not session restore, so
+//! they ignore the gates: the poll tick feeds a [`PlaybackStatisticsCollector`]
+//! and each position save (plus track change and exit) flushes it.
 
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use qbz_app::session_store::{
-    PersistedPlaybackSession, PersistedQueueTrack, PersistedSessionSnapshot,
-    PersistedShellViewState, SessionStore,
+    Bookmark, ListenedSegment, ListeningStats, ListeningTrack, PersistedPlaybackSession,
+    PersistedQueueTrack, PersistedSessionSnapshot, PersistedShellViewState,
+    PlaybackStatisticsCollector, SessionStore,
 };
 use qbz_app::settings::playback::PlaybackPreferencesStore;
 use qbz_app::shell::AppRuntime;
@@ -47,6 +52,8 @@ static PENDING_RESUME: AtomicU64 = AtomicU64::new(0);
 /// Track id the pending resume position applies to, so ONLY the restored current
 /// track resumes — playing any other track first starts from 0. 0 = none.
 static PENDING_RESUME_TRACK: AtomicU64 = AtomicU64::new(0);
+/// Listening time of the current track not yet written to the store.
+static LISTENING: Mutex<Option<PlaybackStatisticsCollector>> = Mutex::new(None);
 /// Runtime + tokio handle captured at shell entry, so the synchronous window
 /// close handlers can flush a final full snapshot before the loop quits.
 static EXIT_CTX: OnceLock<(Runtime, tokio::runtime::Handle)> = OnceLock::new();
@@ -61,6 +68,7 @@ pub fn bind_exit_ctx(runtime: Runtime, handle: tokio::runtime::Handle) {
 /// the UI thread, off the tokio runtime, so we `block_on`). No-op until the exit
 /// context is bound or unless `persist_session` is on.
 pub fn save_on_exit() {
+    flush_listening();
     if !persist_enabled() {
         return;
     }
@@ -73,6 +81,9 @@ pub fn save_on_exit() {
 /// the playback preferences. Called at session activation alongside the other
 /// `init_for_user` stores. Failures degrade to "no persistence" (logged).
 pub fn init_for_user(base_dir: &Path) {
+    // Listening of the previous user's session belongs to their store.
+    flush_listening();
+    *LISTENING.lock().unwrap() = None;
     let opened = match SessionStore::new_at(base_dir) {
         Ok(store) => {
             *STORE.lock().unwrap() = Some(store);
@@ -257,6 +268,7 @@ pub async fn capture_and_save(runtime: &Runtime) {
 /// Quick position-only save (a single cheap UPDATE) — for the poll loop's
 /// throttled tick and the pause edge, so a crash keeps a near-current position.
 pub fn save_position(position_secs: u64) {
+    flush_listening();
     if !persist_enabled() {
         return;
     }
@@ -265,6 +277,91 @@ pub fn save_position(position_secs: u64) {
     }
 }
 
+/// Start attributing listening time to `track` (called on each track start),
+/// flushing what was listened of the previous one.
+pub fn begin_listening(track: &QueueTrack, genre: &str) {
+    let segment = LISTENING
+        .lock()
+        .unwrap()
+        .get_or_insert_with(PlaybackStatisticsCollector::new)
+        .start_track(ListeningTrack {
+            track_id: track.id,
+            artist_id: track.artist_id,
+            artist: track.artist.clone(),
+            genre: (!genre.is_empty()).then(|| genre.to_string()),
+        });
+    if let Some(segment) = segment {
+        record_listening(&segment);
+    }
+}
+
+/// Feed one poll tick to the listening collector.
+pub fn observe_listening(track_id: u64, position_secs: u64, is_playing: bool) {
+    if let Some(collector) = LISTENING.lock().unwrap().as_mut() {
+        collector.observe(track_id, position_secs, is_playing);
+    }
+}
+
+fn flush_listening() {
+    let Some(segment) = LISTENING
+        .lock()
+        .unwrap()
+        .as_mut()
+        .and_then(|c| c.take_segment())
+    else {
+        return;
+    };
+    record_listening(&segment);
+}
+
+fn record_listening(segment: &ListenedSegment) {
+    if let Some(store) = STORE.lock().unwrap().as_ref() {
+        if let Err(e) = store.record_listening(segment) {
+            log::warn!("[qbz-slint] session_persist: listening stats failed: {e}");
+        }
+    }
+}
+
+/// Top artists and genres for "week" | "month" | "year" (the Settings >
+/// Playback LISTENING group, see `crate::listening_stats`).
+pub fn listening_stats(period: &str) -> Result<ListeningStats, String> {
+    match STORE.lock().unwrap().as_ref() {
+        Some(store) => store.get_listening_stats(period),
+        None => Err("Session store not open".to_string()),
+    }
+}
+
+/// Bookmark a position of a track. Not gated on `persist_session` — bookmarks
+/// are user data, not session restore.
+pub fn set_bookmark(
+    source: &str,
+    track_id: u64,
+    position_ms: u64,
+    label: Option<String>,
+) -> Result<(), String> {
+    match STORE.lock().unwrap().as_ref() {
+        Some(store) => store.set_bookmark(source, track_id, position_ms, label),
+        None => Err("Session store not open".to_string()),
+    }
+}
+
+/// A track's bookmarks in position order (empty without a store).
+pub fn bookmarks(source: &str, track_id: u64) -> Vec<Bookmark> {
+    STORE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|store| store.get_bookmarks(source, track_id).ok())
+        .unwrap_or_default()
+}
+
+pub fn delete_bookmark(source: &str, track_id: u64, bookmark_id: i64) -> Result<(), String> {
+    match STORE.lock().unwrap().as_ref() {
+        Some(store) => store.delete_bookmark(source, track_id, bookmark_id),
+        None => Err("Session store not open".to_string()),
+    }
+}
+
 /// Restore the persisted queue at startup. Returns true if a non-empty queue was
 /// restored (so the caller refreshes the now-playing bar). Restores PAUSED; when
 /// `resume_playback_position` is on, primes [`PENDING_RESUME`] for Phase B.
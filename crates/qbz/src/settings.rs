@@ -2,7 +2,7 @@
 //!
 //! Owns the two persistence stores (`AudioSettingsStore` from `qbz-audio`,
 //! `PlaybackPreferencesStore` from `qbz-app`) plus the JSON `ui_prefs`
-//! store (Streaming Quality), and bridges them to the `SettingsState`
+//! store (Streaming Quality, media-controls refresh), and bridges them to the `SettingsState`
 //! Slint global.
 //!
 //! Audio changes are persisted and then applied to the live `Player`:
@@ -24,7 +24,8 @@ use qbz_app::settings::playback::{
 };
 use qbz_app::shell::AppRuntime;
 use qbz_audio::backend::{AlsaPlugin, AudioBackendType, BackendManager};
-use qbz_audio::settings::{AudioSettingsState, AudioSettingsStore};
+use qbz_audio::{AudioDiagnostic, EqBand, FilterType, GRAPHIC_EQ_FREQS};
+use qbz_audio::settings::{AudioSettingsState, AudioSettingsStore, MAX_CROSSFADE_MS};
 use qconnect_app::QconnectStartupMode;
 use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
 
@@ -70,6 +71,135 @@ const QCONNECT_STARTUP_MODES: &[(&str, QconnectStartupMode)] = &[
     (qbz_i18n::mark("Off by default"), QconnectStartupMode::Off),
 ];
 
+/// Adaptive-quality threshold dropdowns (Mb/s; 0 = off). Rough sustained
+/// rates: 24/192 FLAC ~5-9 Mb/s, 24/96 ~3-5, CD ~1-1.4.
+const HIRES_MIN_MBPS: &[f32] = &[0.0, 5.0, 10.0, 20.0];
+const CD_MIN_MBPS: &[f32] = &[0.0, 1.5, 3.0, 5.0];
+
+fn mbps_labels(values: &[f32]) -> Vec<String> {
+    values
+        .iter()
+        .map(|v| {
+            if *v <= 0.0 {
+                qbz_i18n::t("Off")
+            } else {
+                format!("{v} Mb/s")
+            }
+        })
+        .collect()
+}
+
+/// Crossfade length dropdown (ms; 0 = off), topped at the audio side's
+/// `MAX_CROSSFADE_MS` — anything longer would be clamped on save.
+pub(crate) const CROSSFADE_MS: &[u32] = &[0, 1000, 2000, 3000, 5000, 8000, MAX_CROSSFADE_MS];
+
+pub(crate) fn crossfade_labels() -> Vec<String> {
+    CROSSFADE_MS
+        .iter()
+        .map(|ms| {
+            if *ms == 0 {
+                qbz_i18n::t("Off")
+            } else {
+                format!("{} s", ms / 1000)
+            }
+        })
+        .collect()
+}
+
+/// Podcast-mode speed dropdown (×, within the player's 0.5-3.0 range).
+const PLAYBACK_SPEEDS: &[f32] = &[0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0];
+
+/// Podcast-mode silence skipping dropdown: the longest pause kept (ms; 0 =
+/// off).
+const SKIP_SILENCE_GAPS_MS: &[u32] = &[0, 250, 500, 1000, 2000];
+
+fn skip_silence_labels() -> Vec<String> {
+    SKIP_SILENCE_GAPS_MS
+        .iter()
+        .map(|ms| {
+            if *ms == 0 {
+                qbz_i18n::t("Off")
+            } else {
+                format!("{} s", *ms as f32 / 1000.0)
+            }
+        })
+        .collect()
+}
+
+/// Media-controls position refresh dropdown (ms), within the
+/// `media_controls` 100..=10000 bounds.
+const MPRIS_POSITION_INTERVALS_MS: &[u32] = &[250, 500, 1000, 2000, 5000];
+
+fn mpris_position_interval_labels() -> Vec<String> {
+    MPRIS_POSITION_INTERVALS_MS
+        .iter()
+        .map(|ms| format!("{} s", *ms as f32 / 1000.0))
+        .collect()
+}
+
+fn mbps_index(values: &[f32], current: f32) -> i32 {
+    values.iter().position(|v| *v == current).unwrap_or(0) as i32
+}
+
+/// Graphic EQ presets (dB per `GRAPHIC_EQ_FREQS` band). The dropdown lists
+/// these plus a trailing "Custom" entry for hand-tuned sliders.
+const EQ_PRESETS: &[(&str, [f32; 10])] = &[
+    (qbz_i18n::mark("Flat"), [0.0; 10]),
+    (
+        qbz_i18n::mark("Bass boost"),
+        [6.0, 5.0, 4.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    ),
+    (
+        qbz_i18n::mark("Treble boost"),
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 3.0, 4.0, 5.0],
+    ),
+    (
+        qbz_i18n::mark("Vocal"),
+        [-2.0, -2.0, -1.0, 0.0, 2.0, 3.0, 3.0, 2.0, 0.0, -1.0],
+    ),
+    (
+        qbz_i18n::mark("Loudness"),
+        [5.0, 4.0, 2.0, 0.0, -1.0, 0.0, 0.0, 1.0, 3.0, 4.0],
+    ),
+];
+
+/// Dropdown index for the current slider gains: the matching preset, else
+/// the trailing "Custom" entry.
+fn eq_preset_index(gains: &[f32; 10]) -> i32 {
+    EQ_PRESETS
+        .iter()
+        .position(|(_, preset)| preset == gains)
+        .unwrap_or(EQ_PRESETS.len()) as i32
+}
+
+/// Persist new graphic EQ gains and reload the player's DSP chain. Bands
+/// off the graphic grid (an imported headphone curve) are kept as-is.
+fn set_eq_gains(
+    ctx: &SettingsCtx,
+    runtime: &AppRuntime<SlintAdapter>,
+    gains: &[f32; 10],
+) -> Result<(), String> {
+    let current = with_audio(&ctx.audio, |s| s.get_settings())?;
+    let mut bands: Vec<EqBand> = current
+        .eq_bands
+        .into_iter()
+        .filter(|b| !(b.filter_type == FilterType::Peaking && GRAPHIC_EQ_FREQS.contains(&b.freq)))
+        .collect();
+    bands.extend(qbz_audio::graphic_eq_bands(gains));
+    with_audio(&ctx.audio, |s| s.set_eq_bands(&bands))?;
+    apply_audio(ctx, runtime, Apply::Reload);
+    Ok(())
+}
+
+/// Hand the adaptive-quality thresholds to the Qobuz client's bandwidth
+/// gate (process-global, read on every stream URL request).
+fn push_bandwidth_thresholds(audio: &qbz_audio::settings::AudioSettings) {
+    qbz_qobuz::network::set_thresholds(qbz_qobuz::network::BandwidthThresholds {
+        hires_min_mbps: audio.hires_min_mbps,
+        cd_min_mbps: audio.cd_min_mbps,
+    });
+}
+
 /// What a persisted audio change requires of the live `Player`.
 enum Apply {
     /// Not a player-applied setting — nothing to apply.
@@ -110,6 +240,9 @@ impl SettingsCtx {
             log::warn!("[qbz-slint] playback preferences store unavailable: {e}");
             PlaybackPreferencesState::new_empty()
         });
+        if let Ok(settings) = with_audio(&audio, |s| s.get_settings()) {
+            push_bandwidth_thresholds(&settings);
+        }
         Arc::new(Self {
             audio,
             playback,
@@ -141,6 +274,9 @@ pub struct SettingsSnapshot {
     device_index: i32,
     alsa_plugins: Vec<String>,
     alsa_plugin_index: i32,
+    // UCM verbs of the selected ALSA output's card (empty = no profile).
+    ucm_verbs: Vec<String>,
+    ucm_verb_index: i32,
     // Audio — toggles.
     limit_quality_to_device: bool,
     // Detected local device limit (#638 fix 3): the read-only value line
@@ -148,6 +284,7 @@ pub struct SettingsSnapshot {
     // detection (false = fallback set → the Settings caveat shows).
     device_cap_summary: String,
     device_cap_detected: bool,
+    output_latency: String,
     alsa_hardware_volume: bool,
     dsd_modes: Vec<String>,
     dsd_mode_index: i32,
@@ -158,6 +295,15 @@ pub struct SettingsSnapshot {
     allow_quality_fallback: bool,
     sync_audio_on_startup: bool,
     skip_sink_switch: bool,
+    // Audio — adaptive quality thresholds.
+    hires_min_mbps_options: Vec<String>,
+    hires_min_mbps_index: i32,
+    cd_min_mbps_options: Vec<String>,
+    cd_min_mbps_index: i32,
+    // Audio — graphic EQ (dB per band) + preset dropdown.
+    eq_gains: Vec<i32>,
+    eq_presets: Vec<String>,
+    eq_preset_index: i32,
     // Audio — conditional flags.
     backend_is_alsa: bool,
     backend_is_pipewire: bool,
@@ -169,6 +315,17 @@ pub struct SettingsSnapshot {
     persist_session: bool,
     resume_position: bool,
     gapless: bool,
+    crossfade_options: Vec<String>,
+    crossfade_index: i32,
+    repeat_crossfade_index: i32,
+    podcast_mode: bool,
+    playback_speed_options: Vec<String>,
+    playback_speed_index: i32,
+    skip_silence_options: Vec<String>,
+    skip_silence_index: i32,
+    trim_silence: bool,
+    mpris_position_interval_options: Vec<String>,
+    mpris_position_interval_index: i32,
     stream_uncached: bool,
     streaming_only: bool,
     normalization: bool,
@@ -188,6 +345,28 @@ pub struct SettingsSnapshot {
     output_mode_active: bool,
 }
 
+/// "32.3 ms (device 11.0 ms + buffer 21.3 ms)" for the open output, or empty
+/// when the backend can't say (nothing playing on an ALSA device, no device
+/// selected, a backend that doesn't report it). Blocking: `pw-dump` / procfs.
+fn output_latency_label(backend_type: AudioBackendType, device_id: Option<&str>) -> String {
+    let latency = BackendManager::create_backend(backend_type)
+        .and_then(|backend| AudioDiagnostic::measure_latency(backend.as_ref(), device_id));
+    match latency {
+        Ok(l) => qbz_i18n::t_args(
+            "{} ms (device {} ms + buffer {} ms)",
+            &[
+                &format!("{:.1}", l.total_latency_ms),
+                &format!("{:.1}", l.device_latency_ms),
+                &format!("{:.1}", l.software_buffer_ms),
+            ],
+        ),
+        Err(e) => {
+            log::debug!("[qbz-slint] output latency unavailable: {e}");
+            String::new()
+        }
+    }
+}
+
 /// Compute the two now-playing output leds from the real audio constraints:
 /// led 1 = the active backend, led 2 = the effective output mode for THAT
 /// backend (bit-perfect / exclusive / routed / shared). `*_active` is true
@@ -443,6 +622,33 @@ fn with_audio<T>(
     f(store)
 }
 
+/// Hardware volume drives the card's ALSA mixer (ALSA Direct devices only);
+/// tell the user up front when the selected card has no playback-volume
+/// control to drive.
+fn warn_if_no_mixer(ctx: &SettingsCtx, weak: &slint::Weak<AppWindow>) {
+    let device = with_audio(&ctx.audio, |s| s.get_settings())
+        .ok()
+        .and_then(|s| s.output_device)
+        .filter(|d| qbz_audio::AlsaDirectStream::is_hw_device(d));
+    let Some(device) = device else {
+        return;
+    };
+    if qbz_audio::alsa_mixer_elements(&device).is_empty() {
+        crate::toast::info_weak(
+            weak,
+            qbz_i18n::t("This device has no hardware volume control; use its own volume knob"),
+        );
+    }
+}
+
+/// Save a rollback checkpoint of the audio settings before a change.
+/// Best-effort: a failed checkpoint never blocks the change itself.
+fn checkpoint_audio(ctx: &SettingsCtx) {
+    if let Err(e) = with_audio(&ctx.audio, |s| s.checkpoint()) {
+        log::warn!("[qbz-slint] audio settings checkpoint failed: {e}");
+    }
+}
+
 fn with_playback<T>(
     playback: &PlaybackPreferencesState,
     f: impl FnOnce(&PlaybackPreferencesStore) -> Result<T, String>,
@@ -464,7 +670,7 @@ fn build_snapshot(
     ctx: &SettingsCtx,
     audio: qbz_audio::settings::AudioSettings,
     prefs: qbz_app::settings::playback::PlaybackPreferences,
-    streaming_quality_key: &str,
+    ui: &ui_prefs::UiPrefs,
 ) -> SettingsSnapshot {
     // Keep the session-persistence gates in step with the live playback prefs
     // whenever a settings snapshot is built (startup load + post-reset rebuild).
@@ -486,6 +692,7 @@ fn build_snapshot(
         Some(id) => device_list.ids.iter().position(|d| d == id).unwrap_or(0),
     };
 
+    let (ucm_verbs, ucm_verb_index) = ucm_verbs(active_backend, audio.output_device.as_deref());
     let alsa_plugin = audio.alsa_plugin.unwrap_or(AlsaPlugin::Hw);
     let alsa_plugin_index = ALSA_PLUGINS
         .iter()
@@ -513,6 +720,7 @@ fn build_snapshot(
     // Detected device limit (#638 fix 3): a cheap cache read — the probe
     // itself only runs on the explicit refresh triggers, never here.
     let (device_cap_summary, device_cap_detected) = crate::device_cap::summary();
+    let output_latency = output_latency_label(active_backend, audio.output_device.as_deref());
 
     let backend_is_alsa = active_backend == AudioBackendType::Alsa;
     let backend_is_pipewire = active_backend == AudioBackendType::PipeWire;
@@ -521,6 +729,7 @@ fn build_snapshot(
     let (out_backend_label, out_mode_label, out_backend_active, out_mode_active) =
         output_labels(&audio);
     let continue_playback = prefs.autoplay_mode == AutoplayMode::ContinueWithinSource;
+    let eq_gains = qbz_audio::graphic_eq_gains(&audio.eq_bands);
 
     {
         let mut maps = ctx.maps.lock().unwrap_or_else(|e| e.into_inner());
@@ -533,7 +742,7 @@ fn build_snapshot(
             .iter()
             .map(|q| q.label.to_string())
             .collect(),
-        streaming_quality_index: ui_prefs::streaming_quality_index(streaming_quality_key) as i32,
+        streaming_quality_index: ui_prefs::streaming_quality_index(&ui.streaming_quality) as i32,
         // Index 0 is "Auto" (a resolve-and-set action, #470); the concrete
         // backends follow. backend_type is always persisted concrete, so the
         // current selection is its position shifted by 1 past the Auto entry —
@@ -548,9 +757,12 @@ fn build_snapshot(
         device_index: device_index as i32,
         alsa_plugins: ALSA_PLUGINS.iter().map(|(l, _)| qbz_i18n::t(l)).collect(),
         alsa_plugin_index: alsa_plugin_index as i32,
+        ucm_verbs,
+        ucm_verb_index,
         limit_quality_to_device: audio.limit_quality_to_device,
         device_cap_summary,
         device_cap_detected,
+        output_latency,
         alsa_hardware_volume: audio.alsa_hardware_volume,
         dsd_modes: DSD_MODES.iter().map(|(l, _)| qbz_i18n::t(l)).collect(),
         dsd_mode_index: DSD_MODES
@@ -564,6 +776,17 @@ fn build_snapshot(
         allow_quality_fallback: audio.allow_quality_fallback,
         sync_audio_on_startup: audio.sync_audio_on_startup,
         skip_sink_switch: audio.skip_sink_switch,
+        hires_min_mbps_options: mbps_labels(HIRES_MIN_MBPS),
+        hires_min_mbps_index: mbps_index(HIRES_MIN_MBPS, audio.hires_min_mbps),
+        cd_min_mbps_options: mbps_labels(CD_MIN_MBPS),
+        cd_min_mbps_index: mbps_index(CD_MIN_MBPS, audio.cd_min_mbps),
+        eq_gains: eq_gains.iter().map(|g| g.round() as i32).collect(),
+        eq_presets: EQ_PRESETS
+            .iter()
+            .map(|(l, _)| qbz_i18n::t(l))
+            .chain(std::iter::once(qbz_i18n::t("Custom")))
+            .collect(),
+        eq_preset_index: eq_preset_index(&eq_gains),
         backend_is_alsa,
         backend_is_pipewire,
         backend_is_jack,
@@ -573,6 +796,38 @@ fn build_snapshot(
         persist_session: prefs.persist_session,
         resume_position: prefs.resume_playback_position,
         gapless: audio.gapless_enabled,
+        crossfade_options: crossfade_labels(),
+        crossfade_index: CROSSFADE_MS
+            .iter()
+            .position(|ms| *ms == audio.crossfade_ms)
+            .unwrap_or(0) as i32,
+        repeat_crossfade_index: CROSSFADE_MS
+            .iter()
+            .position(|ms| *ms == audio.repeat_crossfade_ms)
+            .unwrap_or(0) as i32,
+        podcast_mode: audio.podcast_mode,
+        playback_speed_options: PLAYBACK_SPEEDS.iter().map(|x| format!("{x}×")).collect(),
+        // Nearest entry, so a speed set elsewhere still shows sensibly.
+        playback_speed_index: PLAYBACK_SPEEDS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - audio.playback_speed)
+                    .abs()
+                    .total_cmp(&(*b - audio.playback_speed).abs())
+            })
+            .map_or(0, |(i, _)| i) as i32,
+        skip_silence_options: skip_silence_labels(),
+        skip_silence_index: SKIP_SILENCE_GAPS_MS
+            .iter()
+            .position(|ms| *ms == audio.skip_silence_threshold_ms)
+            .unwrap_or(0) as i32,
+        trim_silence: audio.skip_silence,
+        mpris_position_interval_options: mpris_position_interval_labels(),
+        mpris_position_interval_index: MPRIS_POSITION_INTERVALS_MS
+            .iter()
+            .position(|ms| *ms == ui.mpris_position_update_ms)
+            .unwrap_or(2) as i32,
         stream_uncached: audio.stream_first_track,
         streaming_only: audio.streaming_only,
         normalization: audio.normalization_enabled,
@@ -600,7 +855,7 @@ pub fn load_snapshot(ctx: &SettingsCtx) -> SettingsSnapshot {
     let audio = with_audio(&ctx.audio, |s| s.get_settings()).unwrap_or_default();
     let prefs = with_playback(&ctx.playback, |s| s.get_preferences()).unwrap_or_default();
     let ui = ui_prefs::load();
-    build_snapshot(ctx, audio, prefs, &ui.streaming_quality)
+    build_snapshot(ctx, audio, prefs, &ui)
 }
 
 fn string_model(items: Vec<String>) -> ModelRc<SharedString> {
@@ -629,11 +884,14 @@ pub fn apply_snapshot(window: &AppWindow, snap: SettingsSnapshot) {
     st.set_device_groups(string_model(snap.device_groups));
     st.set_device_index(snap.device_index);
     st.set_alsa_plugins(string_model(snap.alsa_plugins));
+    st.set_ucm_verbs(string_model(snap.ucm_verbs));
+    st.set_ucm_verb_index(snap.ucm_verb_index);
     st.set_alsa_plugin_index(snap.alsa_plugin_index);
     // Audio — toggles.
     st.set_limit_quality_to_device(snap.limit_quality_to_device);
     st.set_device_cap_summary(snap.device_cap_summary.into());
     st.set_device_cap_detected(snap.device_cap_detected);
+    st.set_output_latency(snap.output_latency.into());
     st.set_alsa_hardware_volume(snap.alsa_hardware_volume);
     st.set_dsd_modes(string_model(snap.dsd_modes));
     st.set_dsd_mode_index(snap.dsd_mode_index);
@@ -644,6 +902,15 @@ pub fn apply_snapshot(window: &AppWindow, snap: SettingsSnapshot) {
     st.set_allow_quality_fallback(snap.allow_quality_fallback);
     st.set_sync_audio_on_startup(snap.sync_audio_on_startup);
     st.set_skip_sink_switch(snap.skip_sink_switch);
+    // Audio — adaptive quality thresholds.
+    st.set_hires_min_mbps_options(string_model(snap.hires_min_mbps_options));
+    st.set_hires_min_mbps_index(snap.hires_min_mbps_index);
+    st.set_cd_min_mbps_options(string_model(snap.cd_min_mbps_options));
+    st.set_cd_min_mbps_index(snap.cd_min_mbps_index);
+    // Audio — graphic EQ.
+    st.set_eq_gains(ModelRc::new(VecModel::from(snap.eq_gains)));
+    st.set_eq_presets(string_model(snap.eq_presets));
+    st.set_eq_preset_index(snap.eq_preset_index);
     // Audio — conditional flags.
     st.set_backend_is_alsa(snap.backend_is_alsa);
     st.set_backend_is_pipewire(snap.backend_is_pipewire);
@@ -655,6 +922,17 @@ pub fn apply_snapshot(window: &AppWindow, snap: SettingsSnapshot) {
     st.set_persist_session(snap.persist_session);
     st.set_resume_position(snap.resume_position);
     st.set_gapless(snap.gapless);
+    st.set_crossfade_options(string_model(snap.crossfade_options));
+    st.set_crossfade_index(snap.crossfade_index);
+    st.set_repeat_crossfade_index(snap.repeat_crossfade_index);
+    st.set_podcast_mode(snap.podcast_mode);
+    st.set_playback_speed_options(string_model(snap.playback_speed_options));
+    st.set_playback_speed_index(snap.playback_speed_index);
+    st.set_skip_silence_options(string_model(snap.skip_silence_options));
+    st.set_skip_silence_index(snap.skip_silence_index);
+    st.set_trim_silence(snap.trim_silence);
+    st.set_mpris_position_interval_options(string_model(snap.mpris_position_interval_options));
+    st.set_mpris_position_interval_index(snap.mpris_position_interval_index);
     st.set_stream_uncached(snap.stream_uncached);
     st.set_streaming_only(snap.streaming_only);
     st.set_normalization(snap.normalization);
@@ -688,13 +966,14 @@ fn apply_audio(ctx: &SettingsCtx, runtime: &AppRuntime<SlintAdapter>, apply: App
         Apply::Reload => false,
         Apply::Reinit => true,
     };
-    let fresh = match with_audio(&ctx.audio, |s| s.get_settings()) {
+    let fresh = match with_audio(&ctx.audio, crate::playlist_audio::effective_settings) {
         Ok(s) => s,
         Err(e) => {
             log::error!("[qbz-slint] re-read audio settings failed: {e}");
             return;
         }
     };
+    push_bandwidth_thresholds(&fresh);
     let player = runtime.core().player();
     if let Err(e) = player.reload_settings(fresh.clone()) {
         log::error!("[qbz-slint] player.reload_settings failed: {e}");
@@ -816,6 +1095,72 @@ fn push_conditional_flags(ctx: &SettingsCtx, weak: &slint::Weak<AppWindow>) {
     });
 }
 
+/// UCM verbs of `device_id`'s card and the index of the one in effect, when
+/// it is an ALSA output whose card ships a UCM2 profile. Blocking (opens the
+/// card's use-case manager).
+#[cfg(target_os = "linux")]
+fn ucm_verbs(backend: AudioBackendType, device_id: Option<&str>) -> (Vec<String>, i32) {
+    let Some(id) = device_id.filter(|_| backend == AudioBackendType::Alsa) else {
+        return (Vec::new(), 0);
+    };
+    let verbs = qbz_audio::get_ucm_verbs(id).unwrap_or_else(|e| {
+        log::warn!("[qbz-slint] list UCM verbs failed: {e}");
+        Vec::new()
+    });
+    let current = qbz_audio::current_ucm_verb(id).ok().flatten();
+    let index = current
+        .and_then(|v| verbs.iter().position(|x| *x == v))
+        .unwrap_or(0);
+    (verbs, index as i32)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn ucm_verbs(_backend: AudioBackendType, _device_id: Option<&str>) -> (Vec<String>, i32) {
+    (Vec::new(), 0)
+}
+
+/// Re-read the UCM verbs for the persisted output and push them onto
+/// `SettingsState`. Called after an output-device change.
+async fn push_ucm_verbs(ctx: Arc<SettingsCtx>, weak: slint::Weak<AppWindow>) {
+    let read = tokio::task::spawn_blocking(move || {
+        let audio = with_audio(&ctx.audio, |s| s.get_settings())?;
+        let backend = audio.backend_type.unwrap_or_default();
+        Ok::<_, String>(ucm_verbs(backend, audio.output_device.as_deref()))
+    })
+    .await;
+    let (verbs, index) = match read {
+        Ok(Ok(v)) => v,
+        Ok(Err(e)) => {
+            log::error!("[qbz-slint] re-read audio settings for UCM verbs failed: {e}");
+            return;
+        }
+        Err(e) => {
+            log::error!("[qbz-slint] UCM verbs task failed: {e}");
+            return;
+        }
+    };
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        let st = w.global::<SettingsState>();
+        st.set_ucm_verbs(string_model(verbs));
+        st.set_ucm_verb_index(index);
+    });
+}
+
+/// Apply verb `index` of `device_id`'s UCM verb list.
+#[cfg(target_os = "linux")]
+fn set_ucm_verb_at(device_id: &str, index: usize) -> Result<(), String> {
+    let verbs = qbz_audio::get_ucm_verbs(device_id)?;
+    let verb = verbs
+        .get(index)
+        .ok_or_else(|| format!("no UCM verb at index {index}"))?;
+    qbz_audio::set_ucm_verb(device_id, verb)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_ucm_verb_at(_device_id: &str, _index: usize) -> Result<(), String> {
+    Err("UCM is ALSA-only".to_string())
+}
+
 /// Rebuild the full snapshot off the UI thread and push it onto
 /// `SettingsState`. Used after a cross-setting cascade so the UI reflects
 /// every forced change (and the conditional flags) in one shot.
@@ -858,6 +1203,39 @@ pub async fn handle_bool(
         set_offline_mode(ctx, runtime, weak, value).await;
         return;
     }
+    // Wi-Fi-only downloads live in the same per-user offline store.
+    if key == "wifi-only-download" {
+        let engine = crate::offline_mode::engine();
+        if let Err(e) = engine.set_wifi_only_download(value) {
+            log::error!("[qbz-slint] wifi-only download toggle failed: {e}");
+            let _ = weak.upgrade_in_event_loop(move |w| {
+                w.global::<SettingsState>().set_wifi_only_download(!value);
+            });
+        }
+        return;
+    }
+    // Toggles backed by AudioSettings are checkpointed first so the change
+    // (cascades included) can be rolled back in one step.
+    if matches!(
+        key,
+        "limit-quality-to-device"
+            | "alsa-hardware-volume"
+            | "exclusive-mode"
+            | "reserve-dac"
+            | "dac-passthrough"
+            | "pw-force-bitperfect"
+            | "allow-quality-fallback"
+            | "sync-audio-on-startup"
+            | "skip-sink-switch"
+            | "gapless"
+            | "normalization"
+            | "podcast-mode"
+            | "trim-silence"
+            | "stream-uncached"
+            | "streaming-only"
+    ) {
+        checkpoint_audio(&ctx);
+    }
     // Cross-setting cascades — force dependent settings off and persist
     // those forced changes. `cascaded` flags whether a full snapshot
     // re-push is needed afterwards.
@@ -894,6 +1272,9 @@ pub async fn handle_bool(
                 .map(|_| Apply::Reload)
         }
         "alsa-hardware-volume" => {
+            if value {
+                warn_if_no_mixer(&ctx, &weak);
+            }
             with_audio(&ctx.audio, |s| s.set_alsa_hardware_volume(value)).map(|_| Apply::Reinit)
         }
         "exclusive-mode" => {
@@ -929,6 +1310,20 @@ pub async fn handle_bool(
             // audio thread re-reads the settings struct, no device re-init.
             with_audio(&ctx.audio, |s| s.set_normalization_enabled(value)).map(|_| Apply::Reload)
         }
+        "podcast-mode" => {
+            // Applied straight to the player, which rebuilds the loaded
+            // track's chain in place to add or drop the stretcher.
+            with_audio(&ctx.audio, |s| s.set_podcast_mode(value)).map(|_| {
+                if let Err(e) = runtime.core().set_podcast_mode(value) {
+                    log::error!("[qbz-slint] set podcast mode failed: {e}");
+                }
+                Apply::None
+            })
+        }
+        "trim-silence" => {
+            // Local files only; read when the next local track starts.
+            with_audio(&ctx.audio, |s| s.set_skip_silence(value)).map(|_| Apply::Reload)
+        }
         "stream-uncached" => {
             with_audio(&ctx.audio, |s| s.set_stream_first_track(value)).map(|_| Apply::Reload)
         }
@@ -1005,6 +1400,7 @@ pub async fn handle_bool(
                     match key.as_str() {
                         "normalization" => st.set_normalization(value),
                         "gapless" => st.set_gapless(value),
+                        "podcast-mode" => st.set_podcast_mode(value),
                         _ => {}
                     }
                 });
@@ -1083,7 +1479,8 @@ async fn set_offline_mode(
 }
 
 /// Handle a slider change: persist it and reload the player settings.
-/// Currently only the Initial Buffer Size slider exists.
+/// The Initial Buffer Size slider and the graphic EQ band columns
+/// (`eq-band-<0..9>`, dB).
 pub fn handle_slider(
     ctx: &SettingsCtx,
     runtime: &AppRuntime<SlintAdapter>,
@@ -1093,11 +1490,34 @@ pub fn handle_slider(
     match key {
         "buffer-seconds" => {
             let seconds = value.clamp(1, 10) as u8;
+            checkpoint_audio(ctx);
             match with_audio(&ctx.audio, |s| s.set_stream_buffer_seconds(seconds)) {
                 Ok(()) => apply_audio(ctx, runtime, Apply::Reload),
                 Err(e) => log::error!("[qbz-slint] persist buffer seconds failed: {e}"),
             }
         }
+        band if band.starts_with("eq-band-") => {
+            let Some(i) = band["eq-band-".len()..]
+                .parse::<usize>()
+                .ok()
+                .filter(|i| *i < GRAPHIC_EQ_FREQS.len())
+            else {
+                log::warn!("[qbz-slint] unknown EQ band key: {band}");
+                return;
+            };
+            checkpoint_audio(ctx);
+            let mut gains = match with_audio(&ctx.audio, |s| s.get_settings()) {
+                Ok(s) => qbz_audio::graphic_eq_gains(&s.eq_bands),
+                Err(e) => {
+                    log::error!("[qbz-slint] read EQ bands failed: {e}");
+                    return;
+                }
+            };
+            gains[i] = value.clamp(-12, 12) as f32;
+            if let Err(e) = set_eq_gains(ctx, runtime, &gains) {
+                log::error!("[qbz-slint] persist EQ bands failed: {e}");
+            }
+        }
         other => log::warn!("[qbz-slint] unknown settings slider key: {other}"),
     }
 }
@@ -1149,6 +1569,22 @@ pub async fn handle_select(
     key: String,
     index: usize,
 ) {
+    if matches!(
+        key.as_str(),
+        "backend"
+            | "device"
+            | "dsd-mode"
+            | "alsa-plugin"
+            | "hires-min-mbps"
+            | "cd-min-mbps"
+            | "eq-preset"
+            | "crossfade"
+            | "repeat-crossfade"
+            | "playback-speed"
+            | "skip-silence"
+    ) {
+        checkpoint_audio(&ctx);
+    }
     match key.as_str() {
         "streaming-quality" => {
             // UI-only preference, persisted to ui_prefs.json.
@@ -1276,6 +1712,33 @@ pub async fn handle_select(
             // The cap is per-device — re-detect for the new output (#638
             // fix 3). No-op while the limit toggle is off.
             refresh_device_cap(&ctx, &weak).await;
+            push_ucm_verbs(ctx, weak).await;
+        }
+        "ucm-verb" => {
+            // Process-wide, like the verb QBZ applies on its own when the
+            // device opens: not persisted, the default verb returns on the
+            // next start.
+            let device = with_audio(&ctx.audio, |s| s.get_settings())
+                .ok()
+                .and_then(|a| a.output_device);
+            let Some(device) = device else {
+                return;
+            };
+            let set = tokio::task::spawn_blocking(move || set_ucm_verb_at(&device, index)).await;
+            // The verb's enable sequence reroutes the card in place; the
+            // open stream needs no re-init.
+            match set {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    log::error!("[qbz-slint] set UCM verb failed: {e}");
+                    crate::toast::error_weak(
+                        &weak,
+                        qbz_i18n::t("Couldn't switch the card profile"),
+                    );
+                    push_ucm_verbs(ctx, weak).await;
+                }
+                Err(e) => log::error!("[qbz-slint] set UCM verb task failed: {e}"),
+            }
         }
         "dsd-mode" => {
             let Some((_, mode)) = DSD_MODES.get(index) else {
@@ -1303,6 +1766,101 @@ pub async fn handle_select(
             // re-apply the force-100 (no-op when not ALSA-direct-hw).
             maybe_force_bitperfect_volume(&ctx, &runtime, &weak).await;
         }
+        "hires-min-mbps" | "cd-min-mbps" => {
+            let current = match with_audio(&ctx.audio, |s| s.get_settings()) {
+                Ok(s) => s,
+                Err(e) => {
+                    log::error!("[qbz-slint] read bandwidth thresholds failed: {e}");
+                    return;
+                }
+            };
+            let (hires, cd) = if key == "hires-min-mbps" {
+                let Some(v) = HIRES_MIN_MBPS.get(index) else {
+                    return;
+                };
+                (*v, current.cd_min_mbps)
+            } else {
+                let Some(v) = CD_MIN_MBPS.get(index) else {
+                    return;
+                };
+                (current.hires_min_mbps, *v)
+            };
+            if let Err(e) = with_audio(&ctx.audio, |s| s.set_bandwidth_thresholds(hires, cd)) {
+                log::error!("[qbz-slint] persist bandwidth thresholds failed: {e}");
+                return;
+            }
+            apply_audio(&ctx, &runtime, Apply::Reload);
+        }
+        "eq-preset" => {
+            // The trailing "Custom" entry only reflects hand-tuned sliders.
+            let Some((_, gains)) = EQ_PRESETS.get(index) else {
+                return;
+            };
+            if let Err(e) = set_eq_gains(&ctx, &runtime, gains) {
+                log::error!("[qbz-slint] persist EQ preset failed: {e}");
+                return;
+            }
+            let gains: Vec<i32> = gains.iter().map(|g| *g as i32).collect();
+            let _ = weak.upgrade_in_event_loop(move |w| {
+                w.global::<SettingsState>()
+                    .set_eq_gains(ModelRc::new(VecModel::from(gains)));
+            });
+        }
+        "crossfade" => {
+            let Some(ms) = CROSSFADE_MS.get(index) else {
+                return;
+            };
+            if let Err(e) = with_audio(&ctx.audio, |s| s.set_crossfade_ms(*ms)) {
+                log::error!("[qbz-slint] persist crossfade failed: {e}");
+                return;
+            }
+            apply_audio(&ctx, &runtime, Apply::Reload);
+        }
+        "repeat-crossfade" => {
+            // The fade at the repeat-all wrap (last track -> first); shares
+            // the crossfade options.
+            let Some(ms) = CROSSFADE_MS.get(index) else {
+                return;
+            };
+            if let Err(e) = with_audio(&ctx.audio, |s| s.set_repeat_crossfade_ms(*ms)) {
+                log::error!("[qbz-slint] persist repeat crossfade failed: {e}");
+                return;
+            }
+            apply_audio(&ctx, &runtime, Apply::Reload);
+        }
+        "playback-speed" => {
+            let Some(speed) = PLAYBACK_SPEEDS.get(index) else {
+                return;
+            };
+            if let Err(e) = with_audio(&ctx.audio, |s| s.set_playback_speed(*speed)) {
+                log::error!("[qbz-slint] persist playback speed failed: {e}");
+                return;
+            }
+            // Live: the stretcher reads the speed per hop.
+            if let Err(e) = runtime.core().set_playback_speed(*speed) {
+                log::error!("[qbz-slint] set playback speed failed: {e}");
+            }
+        }
+        "skip-silence" => {
+            let Some(ms) = SKIP_SILENCE_GAPS_MS.get(index) else {
+                return;
+            };
+            if let Err(e) = with_audio(&ctx.audio, |s| s.set_skip_silence_threshold_ms(*ms)) {
+                log::error!("[qbz-slint] persist skip silence threshold failed: {e}");
+                return;
+            }
+            apply_audio(&ctx, &runtime, Apply::Reload);
+        }
+        "mpris-position-interval" => {
+            // UI-only preference, persisted to ui_prefs.json.
+            let Some(ms) = MPRIS_POSITION_INTERVALS_MS.get(index) else {
+                return;
+            };
+            let mut prefs = ui_prefs::load();
+            prefs.mpris_position_update_ms = *ms;
+            ui_prefs::save(&prefs);
+            crate::media_controls::set_position_update_interval(*ms);
+        }
         "retry-behavior" => {
             let behavior = RETRY_BEHAVIORS.get(index).map(|(_, v)| *v).unwrap_or("ask");
             if let Err(e) = with_audio(&ctx.audio, |s| s.set_quality_fallback_behavior(behavior)) {
@@ -1339,6 +1897,7 @@ pub async fn handle_reset(
     runtime: Arc<AppRuntime<SlintAdapter>>,
     weak: slint::Weak<AppWindow>,
 ) {
+    checkpoint_audio(&ctx);
     if let Err(e) = with_audio(&ctx.audio, |s| s.reset_all()) {
         log::error!("[qbz-slint] audio reset_all failed: {e}");
     }
@@ -1367,6 +1926,72 @@ pub async fn handle_reset(
     apply_audio(&ctx, &runtime, Apply::Reinit);
 }
 
+/// "Undo last change" under the Audio settings: restore the most recent
+/// audio checkpoint (taken before every audio change and before a reset),
+/// re-push the snapshot and re-init the device on the restored settings.
+pub async fn handle_rollback(
+    ctx: Arc<SettingsCtx>,
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: slint::Weak<AppWindow>,
+) {
+    let restored = with_audio(&ctx.audio, |s| match s.latest_checkpoint()? {
+        Some(checkpoint) => s.rollback(&checkpoint).map(|_| true),
+        None => Ok(false),
+    });
+    match restored {
+        Ok(true) => {}
+        Ok(false) => {
+            crate::toast::info_weak(&weak, qbz_i18n::t("No earlier audio settings to restore"));
+            return;
+        }
+        Err(e) => {
+            log::error!("[qbz-slint] audio settings rollback failed: {e}");
+            crate::toast::error_weak(&weak, qbz_i18n::t("Could not restore audio settings"));
+            return;
+        }
+    }
+    // The device (and with it the cap) may have changed back — re-detect
+    // before the snapshot reads the cache (#638 fix 3).
+    refresh_device_cap(&ctx, &weak).await;
+    apply_audio(&ctx, &runtime, Apply::Reinit);
+    maybe_force_bitperfect_volume(&ctx, &runtime, &weak).await;
+    rebuild_and_push(ctx, weak.clone()).await;
+    crate::toast::success_weak(&weak, qbz_i18n::t("Previous audio settings restored"));
+}
+
+/// "Measure" next to the bandwidth thresholds: run a fresh probe through the
+/// Qobuz client (so it honours the offline gate and the proxy) and show the
+/// result. Also refreshes the cached value the stream path uses.
+pub async fn handle_measure_bandwidth(
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: slint::Weak<AppWindow>,
+) {
+    let client_lock = runtime.core().client();
+    let client = {
+        let guard = client_lock.read().await;
+        guard.as_ref().cloned()
+    };
+    let result = match client {
+        Some(client) => client.measure_bandwidth().await.map_err(|e| e.to_string()),
+        None => Err("not logged in".to_string()),
+    };
+    let label = match &result {
+        Ok(mbps) => format!("{mbps:.1} Mb/s"),
+        Err(e) => {
+            log::warn!("[qbz-slint] bandwidth measurement failed: {e}");
+            crate::toast::error_weak(&weak, qbz_i18n::t("Bandwidth measurement failed"));
+            String::new()
+        }
+    };
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        let st = w.global::<SettingsState>();
+        st.set_bandwidth_measuring(false);
+        if !label.is_empty() {
+            st.set_network_bandwidth(label.into());
+        }
+    });
+}
+
 /// Release the held output device, then re-enumerate. Frees a device QBZ is
 /// holding exclusively (ALSA Direct, which leaves the DAC invisible to
 /// PipeWire/other apps) and rebuilds the snapshot so a freed or hot-plugged
@@ -1397,6 +2022,47 @@ pub async fn handle_release_device(
     });
 }
 
+/// ALSA hot-plug (from the `/dev/snd` watcher in `main.rs`): let the core
+/// move playback off a vanished active device, rebuild the device list so
+/// the plugged/unplugged DAC shows up or disappears, and toast the change.
+#[cfg(target_os = "linux")]
+pub async fn handle_device_hotplug(
+    ctx: Arc<SettingsCtx>,
+    runtime: Arc<AppRuntime<SlintAdapter>>,
+    weak: slint::Weak<AppWindow>,
+    event: qbz_audio::DeviceHotplugEvent,
+) {
+    let (card, connected) = match event {
+        qbz_audio::DeviceHotplugEvent::Added { card } => (card, true),
+        qbz_audio::DeviceHotplugEvent::Removed { card } => (card, false),
+    };
+    let lost = runtime.core().handle_audio_hotplug(card, connected).await;
+    // The PCM node shows up before /proc/asound and PipeWire finish
+    // registering the card; list a beat later.
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    let snap = {
+        let ctx = ctx.clone();
+        match tokio::task::spawn_blocking(move || load_snapshot(&ctx)).await {
+            Ok(s) => s,
+            Err(e) => {
+                log::error!("[qbz-slint] hot-plug rebuild task failed: {e}");
+                return;
+            }
+        }
+    };
+    let _ = weak.upgrade_in_event_loop(move |w| {
+        apply_snapshot(&w, snap);
+        if lost {
+            crate::toast::warning(
+                &w,
+                qbz_i18n::t("Audio device disconnected — switched to the default output"),
+            );
+        } else if connected {
+            crate::toast::info(&w, qbz_i18n::t("Audio device connected"));
+        }
+    });
+}
+
 /// Export the desktop's settings to a user-chosen `.qbzb` bundle (Settings >
 /// Developer > "Export settings…", 04 §4.2). The modal collects the single
 /// `--include-auth` gate; this glue reads it, closes the modal, then off the
@@ -1489,10 +2155,63 @@ pub fn export_settings(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Han
     });
 }
 
+/// Render the desktop's settings as a QR code in the export modal (the
+/// `v2_export_settings_as_qrcode` equivalent). Always exported without
+/// credentials, whatever the auth checkbox says — the engine refuses a QR
+/// bundle that carries any. Failures (e.g. settings too large for one code)
+/// surface as an error toast and leave the form in place.
+pub fn export_settings_qr(weak: slint::Weak<AppWindow>, handle: tokio::runtime::Handle) {
+    handle.spawn(async move {
+        let rendered = tokio::task::spawn_blocking(|| {
+            let doc = bundle::export(
+                ExportSource::Desktop,
+                &ExportOptions {
+                    include_auth: false,
+                },
+            )?;
+            let png = bundle::settings_to_qr_png(&doc)?;
+            image::load_from_memory(&png)
+                .map(|img| img.to_rgba8())
+                .map_err(|e| bundle::BundleError::Io(e.to_string()))
+        })
+        .await;
+        let rgba = match rendered {
+            Ok(Ok(rgba)) => rgba,
+            Ok(Err(e)) => {
+                crate::toast::error_weak(
+                    &weak,
+                    format!("{}: {e}", qbz_i18n::t("Could not export settings")),
+                );
+                return;
+            }
+            Err(e) => {
+                crate::toast::error_weak(
+                    &weak,
+                    format!("{}: {e}", qbz_i18n::t("Could not export settings")),
+                );
+                return;
+            }
+        };
+        let (width, height) = rgba.dimensions();
+        let pixels = rgba.into_raw();
+        let _ = weak.upgrade_in_event_loop(move |w| {
+            let st = w.global::<SettingsExportState>();
+            st.set_qr_code(crate::artwork::pixels_to_image(&pixels, width, height));
+            st.set_showing_qr(true);
+        });
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn crossfade_choices_stay_within_the_audio_cap() {
+        assert!(CROSSFADE_MS.iter().all(|ms| *ms <= MAX_CROSSFADE_MS));
+        assert_eq!(CROSSFADE_MS.last(), Some(&MAX_CROSSFADE_MS));
+    }
+
     #[test]
     fn alsa_plugin_table_first_is_hw() {
         assert_eq!(ALSA_PLUGINS[0].1, AlsaPlugin::Hw);
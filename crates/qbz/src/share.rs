@@ -157,6 +157,37 @@ pub async fn albumlink_for_album(album_id: &str, upc: Option<&str>) -> Option<St
     songlink_url(&qobuz_album_url(album_id)).await
 }
 
+/// The link the album Share action copies.
+pub enum AlbumShareLink {
+    /// The album's own Album.link page.
+    Album(String),
+    /// No Album.link could be resolved; the Song.link of the first track.
+    FirstTrack(String),
+}
+
+/// Share link for an album (the `v2_share_album_songlink` equivalent):
+/// its Album.link via [`albumlink_for_album`] or, when neither the UPC nor
+/// Odesli resolves it, the Song.link of its first track so the action still
+/// copies a cross-platform link. `album` is the fetched album (UPC and
+/// track list); `None` when fetching it failed.
+pub async fn album_share_link(
+    album_id: &str,
+    album: Option<&qbz_models::Album>,
+) -> Option<AlbumShareLink> {
+    let upc = album.and_then(|a| a.upc.as_deref());
+    if let Some(url) = albumlink_for_album(album_id, upc).await {
+        return Some(AlbumShareLink::Album(url));
+    }
+    let first = album?.tracks.as_ref()?.items.first()?;
+    log::info!(
+        "[qbz-slint] album {album_id}: no Album.link; falling back to track {}",
+        first.id
+    );
+    songlink_for_track(&first.id.to_string(), first.isrc.as_deref())
+        .await
+        .map(AlbumShareLink::FirstTrack)
+}
+
 /// Resolve a source URL to its universal Song.link (Odesli) page URL.
 /// One GET to the Odesli API; returns the `pageUrl` field. NOTE: Odesli
 /// cannot resolve Qobuz URLs (400 `could_not_resolve_entity`) — for Qobuz
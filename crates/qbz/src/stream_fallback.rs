@@ -0,0 +1,97 @@
+//! Qobuz streams standing in for local files that can't be opened.
+//!
+//! A local track can be linked to its Qobuz equivalent
+//! (`LibraryDatabase::set_stream_fallback`). When the file is gone or
+//! unreadable — an unmounted drive, a moved folder — playback streams the
+//! linked track in its place (`playback::play_stream_fallback`). The link
+//! is made from the local album's track menu: the track is matched on
+//! Qobuz by artist, title, album and duration with the playlist importer's
+//! scoring, and the best match is stored once the user confirms it.
+
+use std::sync::Arc;
+
+use slint::Weak;
+
+use qbz_app::shell::AppRuntime;
+use qbz_library::LocalTrack;
+use qbz_playlist_import::match_qobuz::match_tracks;
+use qbz_playlist_import::{ImportEvent, ImportProgressSink, ImportTrack};
+
+use crate::adapter::SlintAdapter;
+use crate::AppWindow;
+
+type Runtime = Arc<AppRuntime<SlintAdapter>>;
+
+/// Track-menu action: find `track` on Qobuz and, once the user confirms the
+/// match, link it as the track's stream fallback. Reports the linked title,
+/// or that nothing matched.
+pub fn link_track(
+    runtime: Runtime,
+    weak: Weak<AppWindow>,
+    handle: tokio::runtime::Handle,
+    track: LocalTrack,
+) {
+    handle.spawn(async move {
+        let Some(client) = runtime.core().client().read().await.clone() else {
+            crate::toast::error_weak(&weak, qbz_i18n::t("Sign in to Qobuz to link a stream"));
+            return;
+        };
+        let query = ImportTrack {
+            title: track.title.clone(),
+            artist: track.artist.clone(),
+            album: Some(track.album.clone()).filter(|a| !a.is_empty()),
+            duration_ms: Some(track.duration_secs * 1000).filter(|ms| *ms > 0),
+            isrc: None,
+            provider_id: None,
+            provider_url: None,
+        };
+        let sink: Arc<dyn ImportProgressSink> = Arc::new(|_: ImportEvent| {});
+        let matched = match match_tracks(&client, std::slice::from_ref(&query), sink).await {
+            Ok(matches) => matches.into_iter().next(),
+            Err(e) => {
+                log::warn!("[qbz-slint] stream fallback: match failed: {e}");
+                None
+            }
+        };
+        let Some((qobuz_id, title, artist)) = matched.and_then(|m| {
+            let title = m.qobuz_title.unwrap_or_else(|| track.title.clone());
+            let artist = m.qobuz_artist.unwrap_or_else(|| track.artist.clone());
+            Some((m.qobuz_track_id?, title, artist))
+        }) else {
+            crate::toast::info_weak(&weak, qbz_i18n::t("No matching track found on Qobuz"));
+            return;
+        };
+        // The match is scored, not exact: show it before it stands in for
+        // the file.
+        let confirmed = rfd::AsyncMessageDialog::new()
+            .set_title(&qbz_i18n::t("Link Qobuz stream?"))
+            .set_description(&qbz_i18n::t_args(
+                "\"{}\" by {} will play on Qobuz whenever \"{}\" can't be opened.",
+                &[&title, &artist, &track.title],
+            ))
+            .set_buttons(rfd::MessageButtons::YesNo)
+            .show()
+            .await
+            == rfd::MessageDialogResult::Yes;
+        if !confirmed {
+            return;
+        }
+
+        let track_id = track.id;
+        let saved = tokio::task::spawn_blocking(move || {
+            crate::library_db::with_db(|db| db.set_stream_fallback(track_id, qobuz_id))
+        })
+        .await
+        .ok()
+        .flatten();
+        if saved.is_none() {
+            crate::toast::error_weak(&weak, qbz_i18n::t("Could not save the Qobuz link"));
+            return;
+        }
+        log::info!("[qbz-slint] stream fallback: local track {track_id} -> Qobuz track {qobuz_id}");
+        crate::toast::success_weak(
+            &weak,
+            qbz_i18n::t_args("Linked to \"{}\" on Qobuz", &[&title]),
+        );
+    });
+}
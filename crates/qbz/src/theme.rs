@@ -10,10 +10,25 @@
 //! source of truth (persisted in `ui_prefs.theme`), and the dropdown index is
 //! DERIVED from it — never the reverse.
 
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
 use crate::{AppWindow, Theme as SlintTheme, ThemeColors as SlintThemeColors};
 use qbz_theme::{Rgba, ThemeId};
 use slint::{Color, ComponentHandle};
 
+/// Frame cadence of an animated theme change (~60 fps).
+const TRANSITION_FRAME: Duration = Duration::from_millis(16);
+
+thread_local! {
+    /// The palette last pushed into `Theme.c` — the start point of the next
+    /// animated change. UI thread only, like every `push_colors` caller.
+    static LAST_COLORS: RefCell<Option<qbz_theme::ThemeColors>> = const { RefCell::new(None) };
+    /// Drives [`transition_colors`]; restarting it mid-fade continues from
+    /// whatever palette is on screen.
+    static TRANSITION_TIMER: slint::Timer = slint::Timer::default();
+}
+
 /// Convert a registry `Rgba` to a Slint `Color` (straight alpha).
 fn to_color(c: Rgba) -> Color {
     Color::from_argb_u8(c.a, c.r, c.g, c.b)
@@ -92,13 +107,27 @@ fn to_slint(c: &qbz_theme::ThemeColors) -> SlintThemeColors {
 /// Push a fully-materialized registry `ThemeColors` into the running window's
 /// `Theme` global. Shared by [`apply_theme`] (static themes) and the auto-theme
 /// path (`crate::auto_theme`), so both go through the exact same conversion +
-/// global-set sequence.
+/// global-set sequence. Cancels a cross-fade in progress, which would
+/// otherwise paint over this palette on its next frame.
 pub fn push_colors(
     window: &AppWindow,
     colors: &qbz_theme::ThemeColors,
     is_system: bool,
     is_high_contrast: bool,
 ) {
+    TRANSITION_TIMER.with(|timer| timer.stop());
+    set_colors(window, colors, is_system, is_high_contrast);
+}
+
+/// [`push_colors`] without touching the transition timer — the per-frame
+/// step of [`transition_colors`].
+fn set_colors(
+    window: &AppWindow,
+    colors: &qbz_theme::ThemeColors,
+    is_system: bool,
+    is_high_contrast: bool,
+) {
+    LAST_COLORS.with(|last| *last.borrow_mut() = Some(*colors));
     let theme = window.global::<SlintTheme>();
     theme.set_c(to_slint(colors));
     theme.set_is_system(is_system);
@@ -113,6 +142,40 @@ pub fn push_colors(
     theme.set_is_dark(luma < 128.0);
 }
 
+/// Cross-fade from the palette on screen to `colors` over `duration_ms`
+/// (ease-in-out), then land exactly on `colors`. `0`, or nothing pushed yet,
+/// switches instantly through [`push_colors`]. UI thread only.
+pub fn transition_colors(
+    window: &AppWindow,
+    colors: &qbz_theme::ThemeColors,
+    is_system: bool,
+    is_high_contrast: bool,
+    duration_ms: u32,
+) {
+    let from = LAST_COLORS.with(|last| *last.borrow());
+    let Some(from) = from.filter(|from| duration_ms > 0 && from != colors) else {
+        push_colors(window, colors, is_system, is_high_contrast);
+        return;
+    };
+    let to = *colors;
+    let duration = Duration::from_millis(duration_ms as u64);
+    let started = Instant::now();
+    let weak = window.as_weak();
+    TRANSITION_TIMER.with(|timer| {
+        timer.start(slint::TimerMode::Repeated, TRANSITION_FRAME, move || {
+            let Some(w) = weak.upgrade() else {
+                return;
+            };
+            let t = (started.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.0);
+            let eased = t * t * (3.0 - 2.0 * t);
+            set_colors(&w, &from.lerp(&to, eased), is_system, is_high_contrast);
+            if t >= 1.0 {
+                TRANSITION_TIMER.with(|timer| timer.stop());
+            }
+        });
+    });
+}
+
 /// Push the palette for `id` into the running window's `Theme` global. Sets
 /// `is-system` so the System theme follows the OS (the struct is still pushed as
 /// a sane fallback for any non-System-overridden tokens).
@@ -109,6 +109,27 @@ pub const DEFAULT_ALBUM_HEADER_GRADIENT: bool = true;
 /// Default intelligent-search setting (smart cache, ranking, preview dropdown).
 pub const DEFAULT_INTELLIGENT_SEARCH: bool = true;
 
+/// Live-search debounce choices, in select order. `0` = the built-in delays
+/// (220 ms for the preview dropdowns, 300 ms for the results page); any other
+/// value applies to every live-search surface.
+pub const SEARCH_DEBOUNCE_CHOICES_MS: [u32; 5] = [0, 150, 300, 500, 800];
+
+/// The select index for a persisted debounce, falling back to 0 (built-in).
+pub fn search_debounce_index(ms: u32) -> i32 {
+    SEARCH_DEBOUNCE_CHOICES_MS
+        .iter()
+        .position(|&choice| choice == ms)
+        .unwrap_or(0) as i32
+}
+
+/// Inverse of [`search_debounce_index`]; unknown indices map to built-in (0).
+pub fn search_debounce_for_index(index: i32) -> u32 {
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| SEARCH_DEBOUNCE_CHOICES_MS.get(i).copied())
+        .unwrap_or(0)
+}
+
 /// Default immersive-search action. The in-immersive search dropdown acts on
 /// playback (immersive has no navigation): `"disabled"` turns the field inert,
 /// `"replace"` swaps the queue and plays, `"next"` inserts after the current
@@ -144,6 +165,38 @@ pub fn immersive_search_action_index(key: &str) -> i32 {
 /// was open last time; the other keys PIN a fixed FOCUS-mode foreground.
 pub const DEFAULT_IMMERSIVE_DEFAULT_VIEW: &str = "remember";
 
+/// Default immersive beat sensitivity. Other keys: "low" | "high".
+pub const DEFAULT_BEAT_SENSITIVITY: &str = "normal";
+
+/// Map a beat-sensitivity select index (Low / Normal / High, 0-2) to its
+/// persisted key; unknown indices fall back to the default (`"normal"`).
+pub fn beat_sensitivity_for_index(index: i32) -> &'static str {
+    match index {
+        0 => "low",
+        2 => "high",
+        _ => DEFAULT_BEAT_SENSITIVITY,
+    }
+}
+
+/// Inverse of [`beat_sensitivity_for_index`].
+pub fn beat_sensitivity_index(key: &str) -> i32 {
+    match key {
+        "low" => 0,
+        "high" => 2,
+        _ => 1,
+    }
+}
+
+/// Beat detector threshold for a sensitivity key: how far the bass energy
+/// must rise above its rolling average. More sensitive = lower ratio.
+pub fn beat_threshold(key: &str) -> f32 {
+    match key {
+        "low" => 2.0,
+        "high" => 1.15,
+        _ => qbz_audio::visualizer::DEFAULT_BEAT_THRESHOLD,
+    }
+}
+
 /// Default app-wide dynamic background: "off". Other keys: "ambient" (GPU
 /// shader scene, wgpu tier only) | "blurred" (blurred-artwork atmosphere).
 pub const DEFAULT_APP_BACKGROUND: &str = "off";
@@ -354,6 +407,12 @@ pub struct UiPrefs {
     /// renderer id is LAN-local and meaningless (or colliding) elsewhere.
     #[serde(default)]
     pub cast_quality_caps: BTreeMap<String, CastDeviceCap>,
+    /// Cast failure policy: `false` fails fast on the connected renderer,
+    /// `true` walks the fallback chain (the other renderers seen at connect
+    /// time, Chromecast before DLNA) when a track cannot be cast. Default
+    /// OFF — hopping to another room's speaker is never a surprise.
+    #[serde(default)]
+    pub cast_fallback: bool,
     /// Now-playing bar layout: `"new"` | `"classic"` | `"small"` | `"large"`.
     /// Maps to `ShellState.npb-mode` (0 / 1 / 2 / 3).
     #[serde(default = "default_npb_mode")]
@@ -377,6 +436,10 @@ pub struct UiPrefs {
     /// Whether intelligent search (cache, ranking, preview dropdown) is enabled.
     #[serde(default = "default_intelligent_search")]
     pub intelligent_search: bool,
+    /// Live-search debounce in ms (one of [`SEARCH_DEBOUNCE_CHOICES_MS`]);
+    /// `0` keeps the built-in per-surface delays.
+    #[serde(default)]
+    pub search_debounce_ms: u32,
     /// Appearance toggles (persisted; the live Slint globals are seeded from
     /// these at startup, so the user's choice survives a restart).
     #[serde(default = "default_window_title_show")]
@@ -497,6 +560,10 @@ pub struct UiPrefs {
     /// `"queue"` pins a fixed FOCUS view. See [`DEFAULT_IMMERSIVE_DEFAULT_VIEW`].
     #[serde(default = "default_immersive_default_view")]
     pub immersive_default_view: String,
+    /// Immersive beat-detection sensitivity: `"low"` | `"normal"` | `"high"`.
+    /// See [`DEFAULT_BEAT_SENSITIVITY`] and [`beat_threshold`].
+    #[serde(default = "default_beat_sensitivity")]
+    pub beat_sensitivity: String,
     /// App-wide dynamic background key: `"off"` (none) | `"ambient"` (GPU shader
     /// scene, wgpu tier only) | `"blurred"` (blurred-artwork atmosphere). See
     /// [`DEFAULT_APP_BACKGROUND`].
@@ -623,6 +690,16 @@ pub struct UiPrefs {
     /// env — those stay separate knobs, not forced by the profile.
     #[serde(default = "default_profile")]
     pub profile: String,
+    /// File name of the newest crash report already announced at startup
+    /// (`qbz_log::crash`). A newer report gets the "QBZ crashed" toast once;
+    /// empty = none announced yet.
+    #[serde(default)]
+    pub last_crash_report_seen: String,
+    /// How often the playing position is republished to the OS media
+    /// controls (ms; see `crate::media_controls`). Applied at startup and by
+    /// Settings > Playback.
+    #[serde(default = "default_mpris_position_update_ms")]
+    pub mpris_position_update_ms: u32,
 }
 
 /// Sentinel for "no saved window position" (let the WM place the window).
@@ -660,6 +737,10 @@ fn default_profile() -> String {
     "desktop".to_string()
 }
 
+fn default_mpris_position_update_ms() -> u32 {
+    crate::media_controls::DEFAULT_POSITION_UPDATE_MS
+}
+
 fn default_last_dpr() -> f32 {
     1.0
 }
@@ -737,6 +818,10 @@ fn default_immersive_default_view() -> String {
     DEFAULT_IMMERSIVE_DEFAULT_VIEW.to_string()
 }
 
+fn default_beat_sensitivity() -> String {
+    DEFAULT_BEAT_SENSITIVITY.to_string()
+}
+
 fn default_app_background() -> String {
     DEFAULT_APP_BACKGROUND.to_string()
 }
@@ -757,12 +842,14 @@ impl Default for UiPrefs {
         Self {
             streaming_quality: default_streaming_quality(),
             cast_quality_caps: BTreeMap::new(),
+            cast_fallback: false,
             npb_mode: default_npb_mode(),
             language: default_language(),
             large_visualizer: default_large_visualizer(),
             large_spectrum_mode: default_large_spectrum_mode(),
             album_header_gradient: default_album_header_gradient(),
             intelligent_search: default_intelligent_search(),
+            search_debounce_ms: 0,
             window_title_show: default_window_title_show(),
             show_volume_steppers: default_show_volume_steppers(),
             sidebar_playlist_collage: default_sidebar_playlist_collage(),
@@ -791,6 +878,7 @@ impl Default for UiPrefs {
             last_nav: None,
             immersive_search_action: default_immersive_search_action(),
             immersive_default_view: default_immersive_default_view(),
+            beat_sensitivity: default_beat_sensitivity(),
             app_background: default_app_background(),
             immersive_last_view_mode: 0,
             immersive_last_mode: 0,
@@ -816,6 +904,8 @@ impl Default for UiPrefs {
             ui_scale: default_ui_scale(),
             last_dpr: default_last_dpr(),
             profile: default_profile(),
+            last_crash_report_seen: String::new(),
+            mpris_position_update_ms: default_mpris_position_update_ms(),
         }
     }
 }
@@ -949,6 +1039,18 @@ mod tests {
         assert_eq!(streaming_quality_for_key(""), Quality::UltraHiRes);
     }
 
+    #[test]
+    fn beat_sensitivity_maps_into_the_detector_range() {
+        for index in 0..3 {
+            let key = beat_sensitivity_for_index(index);
+            assert_eq!(beat_sensitivity_index(key), index);
+            let threshold = beat_threshold(key);
+            let (lo, hi) = qbz_audio::visualizer::BEAT_THRESHOLD_RANGE;
+            assert!((lo..=hi).contains(&threshold), "{key}: {threshold}");
+        }
+        assert_eq!(UiPrefs::default().beat_sensitivity, "normal");
+    }
+
     #[test]
     fn legacy_json_without_field_deserializes() {
         let prefs: UiPrefs = serde_json::from_str("{}").expect("empty object deserializes");
@@ -958,6 +1060,7 @@ mod tests {
         assert_eq!(prefs.theme, "oled");
         // A profile that predates the per-renderer caps has none (#638 fix 4).
         assert!(prefs.cast_quality_caps.is_empty());
+        assert!(!prefs.cast_fallback);
     }
 
     #[test]
@@ -975,6 +1078,18 @@ mod tests {
         assert_eq!(back.cast_quality_caps, prefs.cast_quality_caps);
     }
 
+    #[test]
+    fn search_debounce_index_roundtrips_and_defaults_to_built_in() {
+        assert_eq!(UiPrefs::default().search_debounce_ms, 0);
+        for (i, ms) in SEARCH_DEBOUNCE_CHOICES_MS.iter().enumerate() {
+            assert_eq!(search_debounce_index(*ms), i as i32);
+            assert_eq!(search_debounce_for_index(i as i32), *ms);
+        }
+        assert_eq!(search_debounce_index(42), 0);
+        assert_eq!(search_debounce_for_index(-1), 0);
+        assert_eq!(search_debounce_for_index(99), 0);
+    }
+
     #[test]
     fn default_theme_is_oled() {
         assert_eq!(UiPrefs::default().theme, "oled");
@@ -0,0 +1,277 @@
+//! About modal: "Check for updates" and the release-channel picker.
+//!
+//! The channel (Stable / Beta) is persisted in the global `updates.db`
+//! (`qbz_app::settings::updates`). Stable asks GitHub for the latest full
+//! release (`/releases/latest` never returns a pre-release); Beta takes the
+//! newest non-draft release from the list, pre-releases included.
+//!
+//! A check never offers a downgrade: after switching from Beta back to
+//! Stable the running beta is usually newer than the latest stable, and that
+//! reads as "up to date" until a stable release overtakes it.
+
+use std::cmp::Ordering;
+
+use serde::Deserialize;
+use slint::ComponentHandle;
+
+use qbz_app::settings::updates::{UpdateChannel, UpdatesStore};
+
+use crate::{AboutActions, AboutState, AppWindow};
+
+const GITHUB_RELEASES_URL: &str = "https://api.github.com/repos/vicrodh/qbz/releases";
+
+/// `AboutState.update-channel-index` order.
+const CHANNELS: [UpdateChannel; 2] = [UpdateChannel::Stable, UpdateChannel::Beta];
+
+/// GitHub release JSON (only the fields the check needs).
+#[derive(Debug, Clone, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    html_url: String,
+    #[serde(default)]
+    draft: bool,
+}
+
+/// Outcome of one check.
+#[derive(Debug, Clone, PartialEq)]
+enum UpdateStatus {
+    UpToDate,
+    Available { version: String, url: String },
+}
+
+/// Seed the stored channel and wire the `AboutActions` update callbacks. Call
+/// once at shell setup.
+pub fn install(window: &AppWindow, handle: tokio::runtime::Handle) {
+    {
+        let weak = window.as_weak();
+        handle.spawn(async move {
+            let channel = tokio::task::spawn_blocking(load_channel)
+                .await
+                .unwrap_or_default();
+            let _ = weak.upgrade_in_event_loop(move |w| {
+                w.global::<AboutState>()
+                    .set_update_channel_index(channel_index(channel));
+            });
+        });
+    }
+
+    // set-update-channel(index) — persist, and drop any stale result.
+    {
+        let weak = window.as_weak();
+        let handle = handle.clone();
+        window
+            .global::<AboutActions>()
+            .on_set_update_channel(move |index| {
+                let Some(w) = weak.upgrade() else { return };
+                let channel = CHANNELS.get(index as usize).copied().unwrap_or_default();
+                let state = w.global::<AboutState>();
+                state.set_update_channel_index(channel_index(channel));
+                state.set_update_status("".into());
+                state.set_update_url("".into());
+                handle.spawn_blocking(move || {
+                    if let Err(e) = UpdatesStore::new().and_then(|s| s.set_channel(channel)) {
+                        log::warn!("[qbz-slint] saving update channel failed: {e}");
+                    }
+                });
+            });
+    }
+
+    // check-for-updates() — query GitHub for the selected channel.
+    {
+        let weak = window.as_weak();
+        window
+            .global::<AboutActions>()
+            .on_check_for_updates(move || {
+                let Some(w) = weak.upgrade() else { return };
+                let state = w.global::<AboutState>();
+                if state.get_update_checking() {
+                    return;
+                }
+                let channel = CHANNELS
+                    .get(state.get_update_channel_index() as usize)
+                    .copied()
+                    .unwrap_or_default();
+                state.set_update_checking(true);
+                state.set_update_status("".into());
+                state.set_update_url("".into());
+
+                let weak = weak.clone();
+                handle.spawn(async move {
+                    let result = check_for_updates(channel).await;
+                    let _ = weak.upgrade_in_event_loop(move |w| {
+                        let state = w.global::<AboutState>();
+                        state.set_update_checking(false);
+                        match result {
+                            Ok(UpdateStatus::UpToDate) => {
+                                state.set_update_status(qbz_i18n::t("QBZ is up to date").into());
+                            }
+                            Ok(UpdateStatus::Available { version, url }) => {
+                                state.set_update_status(
+                                    qbz_i18n::t_args("Version {} is available", &[&version]).into(),
+                                );
+                                state.set_update_url(url.into());
+                            }
+                            Err(e) => {
+                                log::warn!("[qbz-slint] update check failed: {e}");
+                                state.set_update_status(
+                                    qbz_i18n::t("Could not check for updates").into(),
+                                );
+                            }
+                        }
+                    });
+                });
+            });
+    }
+}
+
+fn load_channel() -> UpdateChannel {
+    UpdatesStore::new()
+        .and_then(|store| store.get_preferences())
+        .map(|prefs| prefs.channel)
+        .unwrap_or_default()
+}
+
+fn channel_index(channel: UpdateChannel) -> i32 {
+    CHANNELS.iter().position(|c| *c == channel).unwrap_or(0) as i32
+}
+
+/// Fetch the newest release on `channel` and compare it to the running
+/// version.
+async fn check_for_updates(channel: UpdateChannel) -> Result<UpdateStatus, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(8))
+        .user_agent("qbz")
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let latest = match channel {
+        UpdateChannel::Stable => client
+            .get(format!("{GITHUB_RELEASES_URL}/latest"))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?
+            .json::<GithubRelease>()
+            .await
+            .map_err(|e| e.to_string())?,
+        UpdateChannel::Beta => client
+            .get(format!("{GITHUB_RELEASES_URL}?per_page=20"))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?
+            .json::<Vec<GithubRelease>>()
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .find(|release| !release.draft)
+            .ok_or("no published releases")?,
+    };
+
+    Ok(evaluate(crate::about::app_version(), &latest))
+}
+
+/// Compare the running version with the channel's newest release. Anything
+/// not strictly newer (including an unparsable tag) is "up to date".
+fn evaluate(current: &str, latest: &GithubRelease) -> UpdateStatus {
+    match (parse_version(current), parse_version(&latest.tag_name)) {
+        (Some(running), Some(offered)) if compare_versions(&offered, &running).is_gt() => {
+            UpdateStatus::Available {
+                version: latest.tag_name.trim().trim_start_matches('v').to_string(),
+                url: latest.html_url.clone(),
+            }
+        }
+        _ => UpdateStatus::UpToDate,
+    }
+}
+
+/// `[v]MAJOR.MINOR.PATCH[-PRE]` → numeric core + pre-release identifiers.
+fn parse_version(tag: &str) -> Option<([u64; 3], Vec<String>)> {
+    let tag = tag.trim().trim_start_matches('v');
+    let tag = tag.split('+').next().unwrap_or(tag);
+    let (core, pre) = match tag.split_once('-') {
+        Some((core, pre)) => (core, pre.split('.').map(str::to_string).collect()),
+        None => (tag, Vec::new()),
+    };
+    let mut numbers = [0u64; 3];
+    let mut parts = core.split('.');
+    for slot in &mut numbers {
+        *slot = parts.next()?.parse().ok()?;
+    }
+    parts.next().is_none().then_some((numbers, pre))
+}
+
+/// Semver precedence: the numeric core first; on a tie a release outranks any
+/// pre-release, and pre-release identifiers compare numerically when both are
+/// numbers (`beta.10` > `beta.9`).
+fn compare_versions(a: &([u64; 3], Vec<String>), b: &([u64; 3], Vec<String>)) -> Ordering {
+    a.0.cmp(&b.0)
+        .then_with(|| match (a.1.is_empty(), b.1.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                for (x, y) in a.1.iter().zip(&b.1) {
+                    let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+                        (Ok(x), Ok(y)) => x.cmp(&y),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => x.cmp(y),
+                    };
+                    if ord.is_ne() {
+                        return ord;
+                    }
+                }
+                a.1.len().cmp(&b.1.len())
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str) -> GithubRelease {
+        GithubRelease {
+            tag_name: tag.to_string(),
+            html_url: format!("https://github.com/vicrodh/qbz/releases/tag/{tag}"),
+            draft: false,
+        }
+    }
+
+    #[test]
+    fn newer_release_is_offered() {
+        assert_eq!(
+            evaluate("1.2.15", &release("v1.3.0")),
+            UpdateStatus::Available {
+                version: "1.3.0".to_string(),
+                url: "https://github.com/vicrodh/qbz/releases/tag/v1.3.0".to_string(),
+            }
+        );
+        assert!(matches!(
+            evaluate("1.3.0-beta.9", &release("v1.3.0-beta.10")),
+            UpdateStatus::Available { .. }
+        ));
+        assert!(matches!(
+            evaluate("1.3.0-beta.2", &release("v1.3.0")),
+            UpdateStatus::Available { .. }
+        ));
+    }
+
+    #[test]
+    fn beta_ahead_of_stable_is_up_to_date() {
+        // Switched Beta -> Stable while running a newer beta: no downgrade.
+        assert_eq!(
+            evaluate("1.4.0-beta.1", &release("v1.3.2")),
+            UpdateStatus::UpToDate
+        );
+        assert_eq!(
+            evaluate("1.3.0", &release("v1.3.0")),
+            UpdateStatus::UpToDate
+        );
+        assert_eq!(
+            evaluate("1.3.0", &release("nightly")),
+            UpdateStatus::UpToDate
+        );
+    }
+}
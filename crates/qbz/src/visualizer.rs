@@ -17,12 +17,15 @@
 //! Backend System").
 
 use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
 use qbz_app::shell::AppRuntime;
-use qbz_audio::visualizer::{spawn_visualizer_thread, VizFrame, VizSink};
-use slint::{ComponentHandle, Model, ModelRc, VecModel};
+use qbz_audio::visualizer::{spawn_visualizer_thread, BeatEvent, VizFrame, VizSink};
+use qbz_audio::VisualizerMode;
+use slint::{ComponentHandle, Image, Model, ModelRc, Rgba8Pixel, SharedPixelBuffer, VecModel};
 
 use crate::adapter::SlintAdapter;
 use crate::{AppWindow, ImmersiveState, NowPlayingState, VisualizerState};
@@ -38,6 +41,52 @@ struct VizCells {
     energy: Mutex<Option<[f32; 5]>>,
     waveform: Mutex<Option<Box<[f32; 512]>>>,
     transient: Mutex<Option<f32>>,
+    beat: Mutex<Option<BeatEvent>>,
+    wave128: Mutex<Option<Vec<f32>>>,
+    waterfall: Mutex<Option<Vec<f32>>>,
+}
+
+/// Spectrogram rows kept on screen in waterfall mode (~5 s at 30 fps).
+const WATERFALL_ROWS: usize = 160;
+
+/// SVG path for the oscilloscope line, in the `0..n-1 x -1..1` viewbox the
+/// Spectrum panel's `Path` declares (y flipped: positive samples go up).
+fn oscilloscope_path(samples: &[f32]) -> String {
+    let mut path = String::with_capacity(samples.len() * 14);
+    for (i, s) in samples.iter().enumerate() {
+        let cmd = if i == 0 { 'M' } else { 'L' };
+        let _ = write!(path, "{cmd} {i} {:.3} ", -s.clamp(-1.0, 1.0));
+    }
+    path
+}
+
+/// Power (0..1) to a waterfall pixel: black, up to the spectrum cyan, on to
+/// its purple at full scale (the Spectrum bars' default gradient).
+fn waterfall_color(v: f32) -> Rgba8Pixel {
+    let v = v.clamp(0.0, 1.0);
+    let (r, g, b) = if v < 0.5 {
+        let t = v * 2.0;
+        (0.0, 220.0 * t, 200.0 * t)
+    } else {
+        let t = (v - 0.5) * 2.0;
+        (150.0 * t, 220.0 - 170.0 * t, 200.0 + 55.0 * t)
+    };
+    Rgba8Pixel::new(r as u8, g as u8, b as u8, 255)
+}
+
+/// Render the row history (oldest first) with the newest row at the bottom.
+fn waterfall_image(rows: &VecDeque<Vec<f32>>) -> Image {
+    let width = rows.back().map(Vec::len).unwrap_or(0).max(1);
+    let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::new(width as u32, WATERFALL_ROWS as u32);
+    let pixels = buffer.make_mut_slice();
+    let offset = WATERFALL_ROWS - rows.len().min(WATERFALL_ROWS);
+    for (y, row) in rows.iter().enumerate() {
+        let line = &mut pixels[(offset + y) * width..(offset + y + 1) * width];
+        for (px, v) in line.iter_mut().zip(row) {
+            *px = waterfall_color(*v);
+        }
+    }
+    Image::from_rgba8(buffer)
 }
 
 /// The producer-side sink: latches frames into the shared cells (no Slint access
@@ -54,6 +103,11 @@ impl VizSink for SlintVizSink {
             VizFrame::Energy5(b) => *self.cells.energy.lock().unwrap() = Some(b),
             VizFrame::Wave256x2(b) => *self.cells.waveform.lock().unwrap() = Some(b),
             VizFrame::Transient1(x) => *self.cells.transient.lock().unwrap() = Some(x),
+            VizFrame::Beat(b) => *self.cells.beat.lock().unwrap() = Some(b),
+            // Only produced while the Spectrum view's style picker selects
+            // the oscilloscope / waterfall mode (`VisualizerState.set-mode`).
+            VizFrame::Wave128(w) => *self.cells.wave128.lock().unwrap() = Some(w),
+            VizFrame::Waterfall256(r) => *self.cells.waterfall.lock().unwrap() = Some(r),
         }
     }
 }
@@ -118,6 +172,8 @@ pub fn install(window: &AppWindow, runtime: &Arc<AppRuntime<SlintAdapter>>) {
     let mut last_peak = 0.0f32;
     // Paused gate edge tracker (see the top of the closure).
     let mut drain_saw_playing = false;
+    // Waterfall row history (oldest first), rendered to one image per new row.
+    let mut waterfall_rows: VecDeque<Vec<f32>> = VecDeque::with_capacity(WATERFALL_ROWS);
     // Second handle to the producer thread for the resume unpark below (the
     // original moves into the set-enabled handler).
     let fft_thread_drain = fft_thread.clone();
@@ -169,6 +225,18 @@ pub fn install(window: &AppWindow, runtime: &Arc<AppRuntime<SlintAdapter>>) {
                     waveform.set_row_data(i, *v);
                 }
             }
+            if let Some(w) = cells.wave128.lock().unwrap().take() {
+                win.global::<VisualizerState>()
+                    .set_oscilloscope(oscilloscope_path(&w).into());
+            }
+            if let Some(row) = cells.waterfall.lock().unwrap().take() {
+                if waterfall_rows.len() == WATERFALL_ROWS {
+                    waterfall_rows.pop_front();
+                }
+                waterfall_rows.push_back(row);
+                win.global::<VisualizerState>()
+                    .set_waterfall(waterfall_image(&waterfall_rows));
+            }
             if let Some(x) = cells.transient.lock().unwrap().take() {
                 if let Some(w) = weak.upgrade() {
                     w.global::<VisualizerState>().set_transient(x);
@@ -176,6 +244,14 @@ pub fn install(window: &AppWindow, runtime: &Arc<AppRuntime<SlintAdapter>>) {
                 last_tr = x.max(last_tr);
                 last_beat = x.max(last_beat);
             }
+            // A detected bass beat is a full-strength pulse, softened while the
+            // tempo estimate is still unsure.
+            if let Some(b) = cells.beat.lock().unwrap().take() {
+                if let Some(w) = weak.upgrade() {
+                    w.global::<VisualizerState>().set_beat_bpm(b.bpm_estimate);
+                }
+                last_beat = (0.5 + 0.5 * b.confidence).max(last_beat);
+            }
 
             // WGPU UNDERLAY SPIKE: render one GPU shader frame into the wgpu
             // texture and hand it to ImmersiveState. Only runs while a shader
@@ -293,6 +369,20 @@ pub fn install(window: &AppWindow, runtime: &Arc<AppRuntime<SlintAdapter>>) {
     // and start/stop the UI drain timer. Registered AFTER the timer is stored
     // so any invoke — including the initial seed in main.rs, which runs right
     // after install() — always finds it.
+    {
+        let tap = tap.clone();
+        st.on_set_beat_threshold(move |threshold| tap.set_beat_threshold(threshold));
+    }
+    {
+        let tap = tap.clone();
+        st.on_set_mode(move |mode| {
+            tap.set_mode(match mode {
+                1 => VisualizerMode::Waveform,
+                2 => VisualizerMode::SpectrogramWaterfall,
+                _ => VisualizerMode::Bars,
+            })
+        });
+    }
     {
         let tap = tap.clone();
         st.on_set_enabled(move |on| {
@@ -27,8 +27,11 @@ pub mod reco;
 pub mod search;
 pub mod artwork;
 pub mod settings;
+pub mod spec;
 pub mod sse;
 pub mod status;
+pub mod subsonic;
+pub mod ws;
 
 use std::io::Cursor;
 use std::net::SocketAddr;
@@ -107,6 +110,25 @@ pub const P1_ROUTES: &[(&str, &str)] = &[
     ("POST", "/api/playlist/tracks/remove"),
     ("GET", "/api/events"),
     ("GET", "/api/artwork/current"),
+    ("GET", "/api/openapi.json"),
+    ("GET", "/api/ws"),
+];
+
+/// The Subsonic REST surface (api/subsonic.rs), served under `/rest/` only
+/// when `[subsonic]` credentials are configured. Outside the P0/P1 budget:
+/// its callers are third-party Subsonic clients, not the qbzd CLI, and the
+/// action set is the protocol's browse/search/stream core, no more.
+/// `.view`-suffixed paths are accepted as aliases.
+pub const SUBSONIC_ROUTES: &[(&str, &str)] = &[
+    ("GET", "/rest/ping"),
+    ("GET", "/rest/getLicense"),
+    ("GET", "/rest/getMusicFolders"),
+    ("GET", "/rest/getIndexes"),
+    ("GET", "/rest/getMusicDirectory"),
+    ("GET", "/rest/getSong"),
+    ("GET", "/rest/stream"),
+    ("GET", "/rest/search3"),
+    ("GET", "/rest/scrobble"),
 ];
 
 /// A socket bound at boot step 5, not yet serving. Wraps the tiny_http server
@@ -114,6 +136,15 @@ pub const P1_ROUTES: &[(&str, &str)] = &[
 /// (`unblock` from the handle terminates the thread's `incoming_requests`).
 pub struct BoundServer {
     server: Arc<tiny_http::Server>,
+    /// The `/api/ws` feed socket (port + 1); `None` = it could not be bound.
+    ws: Option<std::net::TcpListener>,
+}
+
+impl BoundServer {
+    /// Where the WebSocket feed listens, for `/api/info`'s `ws_url`.
+    pub fn ws_addr(&self) -> Option<SocketAddr> {
+        self.ws.as_ref().and_then(|l| l.local_addr().ok())
+    }
 }
 
 /// Everything the route handlers read. Owned by the single serving thread
@@ -129,8 +160,13 @@ pub struct ApiState {
     pub bus: broadcast::Sender<CoreEvent>,
     pub roots: ProfileRoots,
     pub token: Option<String>,
+    /// The opt-in `[subsonic]` surface; `None` = no `/rest/` routes.
+    pub subsonic: Option<subsonic::SubsonicRouter>,
     /// The bound address, echoed verbatim by `/api/info`.
     pub bind: String,
+    /// The WebSocket feed's address (`ws_url` in `/api/info`); `None` when
+    /// its port could not be bound.
+    pub ws_bind: Option<String>,
     /// Handle to the daemon's tokio runtime — the serving thread is a plain
     /// `std::thread`, so async core calls (`get_queue_state`) run via
     /// `Handle::block_on` (never called from a runtime worker → no panic).
@@ -172,10 +208,14 @@ pub struct DeviceCache {
 pub struct ApiHandle {
     server: Arc<tiny_http::Server>,
     thread: Option<std::thread::JoinHandle<()>>,
+    ws: Option<ws::WsHandle>,
 }
 
 impl ApiHandle {
     pub fn shutdown(mut self) {
+        if let Some(ws) = self.ws.take() {
+            ws.shutdown();
+        }
         self.server.unblock();
         if let Some(t) = self.thread.take() {
             let _ = t.join();
@@ -197,6 +237,7 @@ pub fn bind(addr: SocketAddr) -> Result<BoundServer, BindError> {
     match tiny_http::Server::http(addr) {
         Ok(server) => Ok(BoundServer {
             server: Arc::new(server),
+            ws: ws::bind(addr),
         }),
         Err(e) => Err(classify_bind_error(e, addr)),
     }
@@ -252,6 +293,21 @@ pub fn serve(server: BoundServer, state: ApiState) -> ApiHandle {
         P0_ROUTES.len(),
         P1_ROUTES.len()
     );
+    if state.subsonic.is_some() {
+        log::info!("subsonic surface serving {} action(s) under /rest/", SUBSONIC_ROUTES.len());
+    }
+    let ws = server.ws.and_then(|listener| {
+        ws::serve(
+            listener,
+            ws::WsFeed {
+                runtime: Arc::downgrade(&state.runtime),
+                shared: state.shared.clone(),
+                rt: state.rt.clone(),
+                bus: state.bus.clone(),
+                token: state.token.clone(),
+            },
+        )
+    });
     let srv = server.server;
     let srv_handle = srv.clone();
     let thread = std::thread::Builder::new()
@@ -262,6 +318,7 @@ pub fn serve(server: BoundServer, state: ApiState) -> ApiHandle {
                 // single serving thread forever. Move it onto its OWN thread
                 // (Request is Send) so the control plane keeps answering. The
                 // origin/token gate is applied first, identically to `route`.
+                // (`/api/ws` has its own listener, see api/ws.rs.)
                 let is_events = *req.method() == Method::Get
                     && req.url().split('?').next() == Some("/api/events");
                 if is_events {
@@ -292,6 +349,7 @@ pub fn serve(server: BoundServer, state: ApiState) -> ApiHandle {
     ApiHandle {
         server: srv_handle,
         thread: Some(thread),
+        ws,
     }
 }
 
@@ -357,6 +415,7 @@ fn route(state: &ApiState, req: &mut Request) -> Response<Cursor<Vec<u8>>> {
         ("GET", "/api/discover") => discover::discover(state, &query),
         ("GET", "/api/lyrics") => lyrics::lyrics(state, &query),
         ("GET", "/api/artwork/current") => artwork::current(state),
+        ("GET", "/api/openapi.json") => json(200, spec::generate_openapi()),
         ("POST", "/api/radio") => {
             let body = read_json_body(req);
             radio::radio(state, &body)
@@ -422,6 +481,22 @@ fn route(state: &ApiState, req: &mut Request) -> Response<Cursor<Vec<u8>>> {
             queue::stop_after(state, &body)
         }
         ("POST", "/api/settings/reload") => settings::reload(state),
+        ("GET" | "POST", p) if p.starts_with("/rest/") => match &state.subsonic {
+            Some(router) => {
+                // Subsonic clients may POST the same parameters form-encoded.
+                let mut raw = query;
+                if method == "POST" {
+                    let mut form = String::new();
+                    let _ = req.as_reader().read_to_string(&mut form);
+                    raw = format!("{raw}&{form}");
+                }
+                router.handle(state, p, &subsonic::parse_params(&raw))
+            }
+            None => err_json(404, "not_found", "the Subsonic API is off", "set [subsonic] user and password in qbzd.toml"),
+        },
+        // The feed has its own listener (api/ws.rs); point a client that
+        // dialled the control plane at it.
+        ("GET", "/api/ws") => err_json(404, "not_found", "the feed is on its own port", "connect to ws_url from /api/info"),
         _ => err_json(404, "not_found", "unknown route", "see qbzd --help"),
     }
 }
@@ -445,14 +520,21 @@ enum GateReject {
 
 impl GateReject {
     fn response(&self) -> Response<Cursor<Vec<u8>>> {
+        let (status, code, message, hint) = self.parts();
+        err_json(status, code, message, hint)
+    }
+
+    /// `(status, code, message, hint)` — also used by the WebSocket
+    /// handshake, which answers with its own response type.
+    fn parts(&self) -> (u16, &'static str, &'static str, &'static str) {
         match self {
-            GateReject::OriginForbidden => err_json(
+            GateReject::OriginForbidden => (
                 403,
                 "origin_forbidden",
                 "requests with an Origin header are refused",
                 "the control plane is not a browser API",
             ),
-            GateReject::InvalidToken => err_json(
+            GateReject::InvalidToken => (
                 401,
                 "invalid_token",
                 "missing or wrong bearer token",
@@ -464,7 +546,8 @@ impl GateReject {
 
 /// The pre-routing access decision (02 §3.1.2): Origin shield always on; the
 /// opt-in Bearer required on every route except `GET /api/ping` when `token`
-/// is `Some`. `None` = open (no auth machinery). Returns `Some(_)` to reject.
+/// is `Some`. `None` = open (no auth machinery). `/rest/` (Subsonic) skips the
+/// Bearer — it carries its own credentials. Returns `Some(_)` to reject.
 fn access_gate(
     has_origin: bool,
     method: &str,
@@ -475,6 +558,10 @@ fn access_gate(
     if has_origin {
         return Some(GateReject::OriginForbidden);
     }
+    // `/rest/` authenticates with Subsonic's own parameters (api/subsonic.rs).
+    if path.starts_with("/rest/") {
+        return None;
+    }
     if let Some(secret) = token {
         let is_ping = method == "GET" && path == "/api/ping";
         let expected = format!("Bearer {secret}");
@@ -575,9 +662,11 @@ mod tests {
         // §3.1.4 HARD RULE, applied to the content-verb door). Row 19:
         // GET /api/search — caller: `qbzd search`. Count is pinned so a route
         // with no caller cannot creep in; P1 must never overlap P0.
-        assert_eq!(P1_ROUTES.len(), 27);
+        assert_eq!(P1_ROUTES.len(), 29);
         assert!(P1_ROUTES.contains(&("GET", "/api/events"))); // caller: `qbzd watch`
         assert!(P1_ROUTES.contains(&("GET", "/api/artwork/current"))); // caller: `qbzd art`
+        assert!(P1_ROUTES.contains(&("GET", "/api/openapi.json"))); // caller: `qbzd api-spec`
+        assert!(P1_ROUTES.contains(&("GET", "/api/ws"))); // caller: `qbzd watch --ws`
         assert!(P1_ROUTES.contains(&("GET", "/api/discover")));
         assert!(P1_ROUTES.contains(&("GET", "/api/lyrics")));
         assert!(P1_ROUTES.contains(&("POST", "/api/reco/playlist")));
@@ -665,6 +754,31 @@ mod tests {
         assert!(access_gate(false, "GET", "/api/ping", Some("Bearer nope"), tok).is_none());
     }
 
+    #[test]
+    fn p1_routes_need_the_bearer_like_p0_including_the_ws_feed() {
+        // `/api/ws` is gated in its handshake (api/ws.rs `gate`) by this same
+        // function, so it is no more open than any other P1 route.
+        let tok = Some("s3cret");
+        for (m, p) in P1_ROUTES {
+            assert_eq!(code(access_gate(false, m, p, None, tok)), Some("invalid_token"), "{m} {p}");
+            assert!(access_gate(false, m, p, Some("Bearer s3cret"), tok).is_none(), "{m} {p}");
+        }
+    }
+
+    #[test]
+    fn subsonic_routes_skip_the_bearer_but_not_the_origin_shield() {
+        let tok = Some("s3cret");
+        assert!(access_gate(false, "GET", "/rest/ping", None, tok).is_none());
+        assert_eq!(
+            code(access_gate(true, "GET", "/rest/ping", None, tok)),
+            Some("origin_forbidden")
+        );
+        for r in SUBSONIC_ROUTES {
+            assert!(r.1.starts_with("/rest/"));
+            assert!(!P0_ROUTES.contains(r) && !P1_ROUTES.contains(r));
+        }
+    }
+
     #[test]
     fn canon_volume_pins_0_8_exactly_no_f32_widening() {
         // `serde_json::Number::from_f32` widens f32→f64 (`0.8f32` would
@@ -26,12 +26,14 @@
 // "muted (was 80%)") trivial reads of one JSON field, and mirrors the
 // desktop's PREMUTE_VOLUME/MUTED pair exactly, just relocated.
 use std::io::Cursor;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
+use qbz_app::shell::AppRuntime;
 use serde_json::Value;
 use tiny_http::Response;
 
-use crate::state::AuthState;
+use crate::adapter::DaemonAdapter;
+use crate::state::{AuthState, DaemonShared};
 
 use super::{canon_volume, err_json, json, ApiState};
 
@@ -48,15 +50,25 @@ pub fn now_playing(state: &ApiState) -> Response<Cursor<Vec<u8>>> {
     if let Some(resp) = auth_gate(state) {
         return resp;
     }
+    json(200, now_playing_doc(&state.runtime, &state.shared, &state.rt))
+}
 
-    let player = state.runtime.core().player();
+/// The `{playback, track}` document of `GET /api/now-playing`, built from the
+/// pieces rather than the whole `ApiState` so the WebSocket feed (its own
+/// thread, no `ApiState`) pushes the exact same shape.
+pub(crate) fn now_playing_doc(
+    runtime: &AppRuntime<DaemonAdapter>,
+    shared: &Mutex<DaemonShared>,
+    rt: &tokio::runtime::Handle,
+) -> Value {
+    let player = runtime.core().player();
     let mut ev = player.get_playback_event();
-    let queue = state.rt.block_on(state.runtime.core().get_queue_state());
+    let queue = rt.block_on(runtime.core().get_queue_state());
 
     ev.shuffle = Some(queue.shuffle);
     ev.repeat = Some(repeat_str(queue.repeat));
 
-    let (muted, nominal_volume) = nominal_volume(state, ev.volume);
+    let (muted, nominal_volume) = nominal_volume(shared, ev.volume);
     ev.volume = nominal_volume;
 
     let mut playback = serde_json::to_value(&ev).unwrap_or_else(|_| serde_json::json!({}));
@@ -74,7 +86,7 @@ pub fn now_playing(state: &ApiState) -> Response<Cursor<Vec<u8>>> {
         .map(|t| serde_json::to_value(t).unwrap_or(Value::Null))
         .unwrap_or(Value::Null);
 
-    json(200, serde_json::json!({"playback": playback, "track": track}))
+    serde_json::json!({"playback": playback, "track": track})
 }
 
 /// `POST /api/playback/play` (02 §3.3.5). Resume if paused; cold-start the
@@ -209,7 +221,7 @@ pub fn volume(state: &ApiState, body: &Value) -> Response<Cursor<Vec<u8>>> {
         return apply_mute(state, live, mute_arg);
     }
 
-    let (muted_before, nominal_before) = nominal_volume(state, live);
+    let (muted_before, nominal_before) = nominal_volume(&state.shared, live);
     let target = if let Some(v) = body.get("volume").and_then(|v| v.as_f64()) {
         (v as f32).clamp(0.0, 1.0)
     } else if let Some(d) = body.get("delta").and_then(|v| v.as_f64()) {
@@ -466,8 +478,8 @@ fn runtime_error(message: &str) -> Response<Cursor<Vec<u8>>> {
 /// — the player's real output is 0.0 while muted, but the reported level
 /// stays at what the user set it to, so `vol 80%` keeps reading `80%` through
 /// a mute/unmute cycle. Returns `(muted, nominal)`.
-fn nominal_volume(state: &ApiState, live: f32) -> (bool, f32) {
-    match state.shared.lock() {
+fn nominal_volume(shared: &Mutex<DaemonShared>, live: f32) -> (bool, f32) {
+    match shared.lock() {
         Ok(s) => (s.muted, if s.muted { s.premute_volume } else { live }),
         Err(_) => (false, live),
     }
@@ -511,6 +523,7 @@ mod tests {
             gapless_ready: false,
             gapless_next_track_id: 0,
             bit_perfect_mode: None,
+            output_format: None,
             buffer_progress: None,
         }
     }
@@ -0,0 +1,493 @@
+// crates/qbzd/src/api/spec.rs — GET /api/openapi.json: an OpenAPI 3.0 document
+// for the control plane.
+//
+// Generated from the route tables in `mod.rs` (P0 + P1), so the spec can never
+// list a route the router doesn't serve. Each route's parameters, body fields
+// and response shape live in [`ROUTE_DOCS`], next to the handler contracts they
+// describe; the `every_route_is_documented` test fails the build of a route
+// added to the tables without a doc row. The opt-in `[server] token` is the
+// `bearer` security scheme (every route except `GET /api/ping`). `qbzd
+// api-spec` is the shipped CLI caller (§3.1.4).
+use serde_json::{json, Map, Value};
+
+use super::{P0_ROUTES, P1_ROUTES};
+
+/// One query parameter or JSON body field.
+struct Field {
+    name: &'static str,
+    /// `string` | `integer` | `number` | `boolean` | `integer[]`
+    ty: &'static str,
+    required: bool,
+    description: &'static str,
+}
+
+const fn f(name: &'static str, ty: &'static str, description: &'static str) -> Field {
+    Field {
+        name,
+        ty,
+        required: false,
+        description,
+    }
+}
+
+const fn req(name: &'static str, ty: &'static str, description: &'static str) -> Field {
+    Field {
+        name,
+        ty,
+        required: true,
+        description,
+    }
+}
+
+struct RouteDoc {
+    method: &'static str,
+    path: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    query: &'static [Field],
+    body: &'static [Field],
+    /// Component schema of the 200 body; `None` for the non-JSON routes
+    /// (SSE stream, artwork redirect).
+    response: Option<&'static str>,
+}
+
+const fn doc(method: &'static str, path: &'static str, tag: &'static str, summary: &'static str) -> RouteDoc {
+    RouteDoc {
+        method,
+        path,
+        tag,
+        summary,
+        query: &[],
+        body: &[],
+        response: Some("Object"),
+    }
+}
+
+const LIMIT: Field = f("limit", "integer", "Page size");
+const OFFSET: Field = f("offset", "integer", "Page offset");
+
+const ROUTE_DOCS: &[RouteDoc] = &[
+    RouteDoc { response: Some("Ping"), ..doc("GET", "/api/ping", "system", "Liveness probe (never requires the token)") },
+    doc("GET", "/api/info", "system", "Daemon identity: version, bind address, data root"),
+    RouteDoc { response: Some("Status"), ..doc("GET", "/api/status", "system", "Composite status: auth, audio, playback, QConnect, network") },
+    doc("GET", "/api/openapi.json", "system", "This document"),
+    doc("POST", "/api/settings/reload", "system", "Re-read the settings stores and apply what changed"),
+    doc("GET", "/api/now-playing", "playback", "Playback snapshot and the current track"),
+    RouteDoc { response: Some("PlaybackState"), ..doc("POST", "/api/playback/play", "playback", "Resume playback") },
+    RouteDoc { response: Some("PlaybackState"), ..doc("POST", "/api/playback/pause", "playback", "Pause playback") },
+    RouteDoc { response: Some("PlaybackState"), ..doc("POST", "/api/playback/toggle", "playback", "Toggle play/pause") },
+    RouteDoc { response: Some("PlaybackState"), ..doc("POST", "/api/playback/stop", "playback", "Stop playback") },
+    doc("POST", "/api/playback/next", "playback", "Skip to the next track"),
+    doc("POST", "/api/playback/previous", "playback", "Go to the previous track"),
+    RouteDoc {
+        body: &[
+            f("position", "integer", "Absolute position in seconds"),
+            f("delta", "integer", "Relative seek in seconds (negative = back)"),
+        ],
+        ..doc("POST", "/api/playback/seek", "playback", "Seek within the current track")
+    },
+    RouteDoc {
+        body: &[
+            f("volume", "number", "Absolute level, 0.0-1.0"),
+            f("delta", "number", "Relative change, -1.0-1.0"),
+            f("mute", "string", "on | off | toggle"),
+        ],
+        response: Some("Volume"),
+        ..doc("POST", "/api/playback/volume", "playback", "Set, nudge or mute the volume")
+    },
+    RouteDoc {
+        body: &[f("mode", "string", "on | off | toggle (default toggle)")],
+        ..doc("POST", "/api/playback/shuffle", "playback", "Set shuffle")
+    },
+    RouteDoc {
+        body: &[req("mode", "string", "off | all | one")],
+        ..doc("POST", "/api/playback/repeat", "playback", "Set the repeat mode")
+    },
+    RouteDoc {
+        body: &[
+            f("url", "string", "Qobuz URL to resolve"),
+            f("track_id", "integer", "Track to play"),
+            f("album_id", "string", "Album to play"),
+            f("playlist_id", "integer", "Playlist to play"),
+            f("artist_id", "integer", "Artist whose top tracks to play"),
+            f("index", "integer", "Start index within the resolved tracks"),
+        ],
+        ..doc("POST", "/api/play", "playback", "Replace the queue with content and start it")
+    },
+    RouteDoc { query: &[OFFSET, LIMIT], ..doc("GET", "/api/queue", "queue", "The queue (paged)") },
+    RouteDoc {
+        body: &[
+            req("track_ids", "integer[]", "Tracks to add"),
+            f("position", "string", "end | next (default end)"),
+        ],
+        ..doc("POST", "/api/queue/add", "queue", "Add tracks to the queue")
+    },
+    RouteDoc {
+        body: &[req("index", "integer", "Queue index to remove")],
+        ..doc("POST", "/api/queue/remove", "queue", "Remove one queue entry")
+    },
+    RouteDoc {
+        body: &[f("keep_current", "boolean", "Keep the playing track (default true)")],
+        ..doc("POST", "/api/queue/clear", "queue", "Clear the queue")
+    },
+    RouteDoc {
+        body: &[req("from", "integer", "Source index"), req("to", "integer", "Destination index")],
+        ..doc("POST", "/api/queue/move", "queue", "Move a queue entry")
+    },
+    RouteDoc {
+        body: &[req("index", "integer", "Queue index to play")],
+        ..doc("POST", "/api/queue/jump", "queue", "Play a queue entry")
+    },
+    RouteDoc {
+        body: &[
+            f("track_id", "integer", "Stop after this track"),
+            f("current", "boolean", "Stop after the current track"),
+            f("off", "boolean", "Clear the stop-after marker"),
+        ],
+        ..doc("POST", "/api/queue/stop-after", "queue", "Set or clear the stop-after marker")
+    },
+    RouteDoc {
+        query: &[
+            req("q", "string", "Search text"),
+            f("type", "string", "all | albums | tracks | artists | playlists"),
+            LIMIT,
+            OFFSET,
+        ],
+        ..doc("GET", "/api/search", "catalog", "Search the Qobuz catalog")
+    },
+    RouteDoc {
+        query: &[req("id", "string", "Album id"), f("suggest", "boolean", "Include similar albums")],
+        ..doc("GET", "/api/album", "catalog", "Album with tracklist")
+    },
+    RouteDoc {
+        query: &[
+            req("id", "integer", "Artist id"),
+            f("view", "string", "page | top | albums (default page)"),
+            f("release_type", "string", "Release filter for view=albums"),
+            LIMIT,
+            OFFSET,
+        ],
+        ..doc("GET", "/api/artist", "catalog", "Artist page, top tracks or releases")
+    },
+    RouteDoc {
+        query: &[f("artist", "integer", "Artist id"), f("album", "string", "Album id")],
+        ..doc("GET", "/api/similar", "catalog", "Similar artists or albums")
+    },
+    RouteDoc {
+        query: &[f("seed", "string", "Comma-separated artist ids (default: the queue)"), LIMIT],
+        ..doc("GET", "/api/suggest", "catalog", "Dynamic suggestions from seed artists")
+    },
+    RouteDoc {
+        query: &[
+            f("section", "string", "Discover section (default index)"),
+            f("genre", "string", "Comma-separated genre ids"),
+            f("tag", "string", "Playlist tag"),
+            f("release_type", "string", "Release type filter"),
+            f("type", "string", "Featured type"),
+            LIMIT,
+            OFFSET,
+        ],
+        ..doc("GET", "/api/discover", "catalog", "Discover rails")
+    },
+    RouteDoc {
+        query: &[f("id", "string", "Track id or `current`")],
+        ..doc("GET", "/api/lyrics", "catalog", "Lyrics, flattened to lines")
+    },
+    RouteDoc {
+        response: None,
+        ..doc("GET", "/api/artwork/current", "catalog", "Redirect (302) to the current track's cover art")
+    },
+    RouteDoc {
+        body: &[
+            f("artist_id", "integer", "Artist seed"),
+            f("track_id", "integer", "Track seed"),
+            f("album_id", "string", "Album seed"),
+        ],
+        ..doc("POST", "/api/radio", "catalog", "Start a radio from a seed")
+    },
+    RouteDoc {
+        body: &[
+            f("playlist_id", "integer", "Playlist to extend"),
+            f("artists", "integer[]", "Seed artist ids"),
+            LIMIT,
+        ],
+        ..doc("POST", "/api/reco/playlist", "catalog", "Recommend tracks for a playlist")
+    },
+    RouteDoc {
+        query: &[f("type", "string", "tracks | albums | artists"), LIMIT, OFFSET],
+        ..doc("GET", "/api/favorites", "library", "List favorites")
+    },
+    RouteDoc {
+        body: &[
+            req("fav_type", "string", "track | album | artist"),
+            f("item_id", "string", "Item id"),
+            f("current", "boolean", "Use the current track"),
+        ],
+        ..doc("POST", "/api/favorites/add", "library", "Add a favorite")
+    },
+    RouteDoc {
+        body: &[req("fav_type", "string", "track | album | artist"), req("item_id", "string", "Item id")],
+        ..doc("POST", "/api/favorites/remove", "library", "Remove a favorite")
+    },
+    doc("GET", "/api/playlists", "library", "The user's playlists"),
+    RouteDoc {
+        query: &[req("id", "integer", "Playlist id")],
+        ..doc("GET", "/api/playlist", "library", "One playlist with tracks")
+    },
+    RouteDoc {
+        body: &[
+            req("name", "string", "Playlist name"),
+            f("description", "string", "Description"),
+            f("public", "boolean", "Public playlist (default false)"),
+        ],
+        ..doc("POST", "/api/playlist/create", "library", "Create a playlist")
+    },
+    RouteDoc {
+        body: &[
+            req("id", "integer", "Playlist id"),
+            f("name", "string", "New name"),
+            f("description", "string", "New description"),
+            f("public", "boolean", "New visibility"),
+        ],
+        ..doc("POST", "/api/playlist/update", "library", "Rename or edit a playlist")
+    },
+    RouteDoc {
+        body: &[req("id", "integer", "Playlist id")],
+        ..doc("POST", "/api/playlist/delete", "library", "Delete a playlist")
+    },
+    RouteDoc {
+        body: &[req("id", "integer", "Playlist id"), req("track_ids", "integer[]", "Tracks to add")],
+        ..doc("POST", "/api/playlist/tracks/add", "library", "Add tracks to a playlist")
+    },
+    RouteDoc {
+        body: &[req("id", "integer", "Playlist id"), req("track_ids", "integer[]", "Tracks to remove")],
+        ..doc("POST", "/api/playlist/tracks/remove", "library", "Remove tracks from a playlist")
+    },
+    RouteDoc {
+        response: None,
+        ..doc("GET", "/api/events", "events", "Server-sent CoreEvent stream (text/event-stream)")
+    },
+    RouteDoc {
+        response: None,
+        ..doc(
+            "GET",
+            "/api/ws",
+            "events",
+            "WebSocket feed on the API port + 1 (ws_url in /api/info): CoreEvent frames plus NowPlaying snapshots (250 ms playing / 1 s paused / 5 s stopped)",
+        )
+    },
+];
+
+/// The full OpenAPI 3.0 document for this build's route tables.
+pub fn generate_openapi() -> Value {
+    let mut paths = Map::new();
+    for (method, path) in P0_ROUTES.iter().chain(P1_ROUTES) {
+        let Some(route) = ROUTE_DOCS.iter().find(|d| d.method == *method && d.path == *path) else {
+            log::warn!("openapi: no doc for {method} {path}");
+            continue;
+        };
+        let entry = paths.entry(path.to_string()).or_insert_with(|| json!({}));
+        entry[method.to_lowercase()] = operation(route);
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "qbzd control plane",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "HTTP control API of the QBZ headless daemon. Requests carrying an Origin header are refused.",
+        },
+        "servers": [{"url": "/"}],
+        "security": [{"bearer": []}],
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                "bearer": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "Required only when `[server] token` is set in qbzd.toml.",
+                },
+            },
+            "schemas": schemas(),
+        },
+    })
+}
+
+fn operation(route: &RouteDoc) -> Value {
+    let mut op = json!({
+        "tags": [route.tag],
+        "summary": route.summary,
+        "responses": responses(route),
+    });
+    if route.path == "/api/ping" {
+        op["security"] = json!([]);
+    }
+    if !route.query.is_empty() {
+        op["parameters"] = route
+            .query
+            .iter()
+            .map(|p| {
+                json!({
+                    "name": p.name,
+                    "in": "query",
+                    "required": p.required,
+                    "description": p.description,
+                    "schema": type_schema(p.ty),
+                })
+            })
+            .collect();
+    }
+    if !route.body.is_empty() {
+        op["requestBody"] = json!({
+            "required": route.body.iter().any(|b| b.required),
+            "content": {"application/json": {"schema": object_schema(route.body)}},
+        });
+    }
+    op
+}
+
+fn responses(route: &RouteDoc) -> Value {
+    let error = json!({
+        "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Error"}}},
+    });
+    let mut out = json!({
+        "400": {"description": "Bad request"},
+        "401": {"description": "Missing or wrong bearer token"},
+        "403": {"description": "Request carried an Origin header"},
+    });
+    for code in ["400", "401", "403"] {
+        out[code]["content"] = error["content"].clone();
+    }
+    match (route.path, route.response) {
+        ("/api/ws", _) => {
+            out["101"] = json!({"description": "Switching to the WebSocket protocol"});
+        }
+        ("/api/events", _) => {
+            out["200"] = json!({
+                "description": "Event stream",
+                "content": {"text/event-stream": {"schema": {"type": "string"}}},
+            });
+        }
+        ("/api/artwork/current", _) => {
+            out["302"] = json!({"description": "Redirect to the cover image"});
+            out["404"] = json!({"description": "Nothing playing or no artwork", "content": error["content"].clone()});
+        }
+        (_, Some(schema)) => {
+            out["200"] = json!({
+                "description": "OK",
+                "content": {"application/json": {"schema": {"$ref": format!("#/components/schemas/{schema}")}}},
+            });
+        }
+        (_, None) => {
+            out["200"] = json!({"description": "OK"});
+        }
+    }
+    out
+}
+
+fn type_schema(ty: &str) -> Value {
+    match ty.strip_suffix("[]") {
+        Some(item) => json!({"type": "array", "items": {"type": item}}),
+        None => json!({"type": ty}),
+    }
+}
+
+fn object_schema(fields: &[Field]) -> Value {
+    let properties: Map<String, Value> = fields
+        .iter()
+        .map(|field| {
+            let mut schema = type_schema(field.ty);
+            schema["description"] = json!(field.description);
+            (field.name.to_string(), schema)
+        })
+        .collect();
+    let required: Vec<&str> = fields.iter().filter(|f| f.required).map(|f| f.name).collect();
+    let mut schema = json!({"type": "object", "properties": properties});
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    schema
+}
+
+/// Component schemas for the fixed response shapes. `Status` mirrors
+/// `status::StatusDoc`; nested sections stay open objects so a new field in
+/// them doesn't need a spec change.
+fn schemas() -> Value {
+    json!({
+        "Object": {"type": "object", "additionalProperties": true},
+        "Error": {
+            "type": "object",
+            "required": ["error"],
+            "properties": {
+                "error": object_schema(&[
+                    req("code", "string", "Machine-readable code (the CLI keys its exit code off it)"),
+                    req("message", "string", "What went wrong"),
+                    req("hint", "string", "How to fix it"),
+                ]),
+            },
+        },
+        "Ping": object_schema(&[
+            req("ok", "boolean", "Always true"),
+            req("app", "string", "Always `qbzd`"),
+            req("api_version", "integer", "Control-plane API version"),
+        ]),
+        "Status": {
+            "type": "object",
+            "properties": {
+                "version": {"type": "string"},
+                "api_version": {"type": "integer"},
+                "uptime_secs": {"type": "integer"},
+                "data_root": {"type": "string"},
+                "driver_tick_age_ms": {"type": "integer", "nullable": true},
+                "auth": {"$ref": "#/components/schemas/Object"},
+                "audio": {"$ref": "#/components/schemas/Object"},
+                "playback": {"$ref": "#/components/schemas/Object"},
+                "qconnect": {"$ref": "#/components/schemas/Object"},
+                "network": {"$ref": "#/components/schemas/Object"},
+                "last_errors": {"$ref": "#/components/schemas/Object"},
+            },
+        },
+        "PlaybackState": object_schema(&[req("state", "string", "playing | paused | stopped | loading")]),
+        "Volume": object_schema(&[
+            req("volume", "number", "Level, 0.0-1.0"),
+            req("muted", "boolean", "Muted"),
+        ]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_route_is_documented() {
+        for (method, path) in P0_ROUTES.iter().chain(P1_ROUTES) {
+            assert!(
+                ROUTE_DOCS.iter().any(|d| d.method == *method && d.path == *path),
+                "{method} {path} has no ROUTE_DOCS row"
+            );
+        }
+        assert_eq!(ROUTE_DOCS.len(), P0_ROUTES.len() + P1_ROUTES.len(), "stale ROUTE_DOCS row");
+    }
+
+    #[test]
+    fn document_lists_every_operation_with_bearer_security() {
+        let spec = generate_openapi();
+        assert_eq!(spec["openapi"], "3.0.3");
+        let operations: usize = spec["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|p| p.as_object().unwrap().len())
+            .sum();
+        assert_eq!(operations, P0_ROUTES.len() + P1_ROUTES.len());
+        assert_eq!(spec["components"]["securitySchemes"]["bearer"]["scheme"], "bearer");
+        // ping is the one token-exempt route.
+        assert_eq!(spec["paths"]["/api/ping"]["get"]["security"], json!([]));
+        let seek = &spec["paths"]["/api/playback/seek"]["post"];
+        assert_eq!(
+            seek["requestBody"]["content"]["application/json"]["schema"]["properties"]["delta"]["type"],
+            "integer"
+        );
+    }
+}
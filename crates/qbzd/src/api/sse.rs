@@ -98,15 +98,22 @@ impl Read for SseReader {
 /// hints, diagnostics). Everything else — playback, queue, volume, auth,
 /// favorites, playlists, errors, device changes — is emitted.
 fn format_event(ev: &CoreEvent) -> Option<String> {
-    if !emit(ev) {
-        return None;
-    }
-    let value = serde_json::to_value(ev).ok()?;
+    let value = event_value(ev)?;
     let typ = value.get("type").and_then(|v| v.as_str()).unwrap_or("event").to_string();
     let data = serde_json::to_string(&value).ok()?;
     Some(format!("event: {typ}\ndata: {data}\n\n"))
 }
 
+/// The tagged CoreEvent JSON, or `None` for a non-emitted event. Shared with
+/// the WebSocket feed so both transports push the same events in the same
+/// shape.
+pub(crate) fn event_value(ev: &CoreEvent) -> Option<serde_json::Value> {
+    if !emit(ev) {
+        return None;
+    }
+    serde_json::to_value(ev).ok()
+}
+
 fn emit(ev: &CoreEvent) -> bool {
     use CoreEvent::*;
     !matches!(
@@ -39,12 +39,16 @@ pub struct AudioStatus {
     pub configured_device: Option<String>,
     pub device_present: bool,
     pub device_open: bool,
-    /// `BitPerfectMode` serde variants: "DirectHardware"|"PluginFallback"|"Disabled"
-    /// (crates/qbz-audio/src/backend.rs:226-233). Kept as a plain string here so
+    /// `BitPerfectMode` serde variants: "DirectHardware"|"PluginFallback"|"DoP"|"Disabled"
+    /// (crates/qbz-audio/src/backend.rs:226-235). Kept as a plain string here so
     /// this crate does not need to depend on the exact qbz-audio enum shape yet.
     pub bit_perfect: Option<String>,
     pub sample_rate: Option<u32>,
     pub bit_depth: Option<u32>,
+    /// Formats tried when the current stream was opened and whether the
+    /// device accepted them (`"Tried int32: rejected"`). Empty when the
+    /// output wasn't negotiated (shared servers, ALSA hw: direct).
+    pub negotiation_log: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +87,10 @@ pub fn info(state: &super::ApiState) -> Response<Cursor<Vec<u8>>> {
             "version": env!("CARGO_PKG_VERSION"),
             "api_version": crate::API_VERSION,
             "bind": state.bind,
+            // Additive (§3.1.4): where the WebSocket feed lives (its own
+            // port), so a client doesn't have to assemble it from `bind`.
+            // `null` when the feed's port could not be bound.
+            "ws_url": state.ws_bind.as_ref().map(|b| format!("ws://{b}/api/ws")),
             "uptime_secs": uptime,
             "data_root": state.roots.data.display().to_string(),
         }),
@@ -193,6 +201,7 @@ fn assemble_live(state: &super::ApiState) -> StatusDoc {
             bit_perfect: bitperfect_label(ev.bit_perfect_mode),
             sample_rate: ev.sample_rate,
             bit_depth: ev.bit_depth,
+            negotiation_log: player.state.get_negotiation_log(),
         },
         playback: PlaybackStatus {
             state: pstate.to_string(),
@@ -212,13 +221,14 @@ fn assemble_live(state: &super::ApiState) -> StatusDoc {
 }
 
 /// `BitPerfectMode` → its serde variant string (02 §3.3.3:
-/// `"DirectHardware"|"PluginFallback"|"Disabled"`). `None` = no active stream.
+/// `"DirectHardware"|"PluginFallback"|"DoP"|"Disabled"`). `None` = no active stream.
 fn bitperfect_label(m: Option<qbz_audio::BitPerfectMode>) -> Option<String> {
     use qbz_audio::BitPerfectMode as M;
     m.map(|m| {
         match m {
             M::DirectHardware => "DirectHardware",
             M::PluginFallback => "PluginFallback",
+            M::DoP => "DoP",
             M::Disabled => "Disabled",
         }
         .to_string()
@@ -298,6 +308,7 @@ mod tests {
                 bit_perfect: None,
                 sample_rate: None,
                 bit_depth: None,
+                negotiation_log: Vec::new(),
             },
             playback: PlaybackStatus {
                 state: "stopped".into(),
@@ -386,6 +397,7 @@ mod tests {
             "bit_perfect",
             "sample_rate",
             "bit_depth",
+            "negotiation_log",
         ] {
             assert!(audio.contains_key(key), "missing audio key: {key}");
         }
@@ -0,0 +1,846 @@
+// crates/qbzd/src/api/subsonic.rs — a Subsonic / OpenSubsonic REST surface
+// under `/rest/` for existing third-party clients (DSub, Ultrasonic, ...).
+//
+// OPT-IN: the routes exist only when `[subsonic] user` + `password` are both
+// set in qbzd.toml (`ApiState::subsonic` is `None` otherwise and `/rest/*`
+// falls through to the control plane's 404). Subsonic clients authenticate
+// with their own query parameters (`u` + `p`, or `u` + `t`/`s` token), so the
+// `[server] token` Bearer gate does not apply here; the Origin shield does.
+//
+// Content is the Qobuz catalog of the logged-in account: the one music folder
+// is "Qobuz", the index is the favorite artists, directories are artist →
+// albums → tracks, and `stream` answers a 302 to the signed Qobuz stream URL
+// at the daemon's streaming quality (no transcoding — `maxBitRate`/`format`
+// are ignored; songs report MP3 or FLAC to match that quality). `scrobble` feeds the daemon's reco store and its Last.fm /
+// ListenBrainz scrobbler (scrobble_engine.rs). The daemon has no local
+// library (it never opens the desktop profile, paths.rs), so local files are
+// not served.
+//
+// Ids are prefixed strings (`ar-<id>`, `al-<id>`, `tr-<id>`); bare numbers
+// are accepted as track ids. Responses are XML unless `f=json`, and failures
+// are HTTP 200 with `status="failed"` + a Subsonic error code, as the spec
+// requires.
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+
+use md5::{Digest, Md5};
+use qbz_models::{Album, Quality, Track};
+use serde_json::{json, Map, Value};
+use tiny_http::{Header, Response};
+
+use crate::state::AuthState;
+
+use super::{constant_time_eq, ApiState};
+
+/// Subsonic REST protocol version implemented.
+pub const SUBSONIC_API_VERSION: &str = "1.16.1";
+
+const XMLNS: &str = "http://subsonic.org/restapi";
+const DEFAULT_COUNT: u32 = 20;
+const MAX_COUNT: u32 = 100;
+/// Favorite artists fetched for `getIndexes` (one page, Qobuz's cap).
+const INDEX_ARTISTS: u32 = 500;
+/// Albums listed per artist directory.
+const ARTIST_ALBUMS: u32 = 100;
+
+/// Subsonic error codes (subsonic.org/pages/api.jsp, "Error handling").
+const ERR_GENERIC: u32 = 0;
+const ERR_MISSING_PARAM: u32 = 10;
+const ERR_WRONG_CREDENTIALS: u32 = 40;
+const ERR_NOT_FOUND: u32 = 70;
+
+/// A Subsonic-level failure, rendered as `<error code message/>`.
+#[derive(Debug, PartialEq)]
+struct Failure {
+    code: u32,
+    message: String,
+}
+
+impl Failure {
+    fn new(code: u32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// The `/rest/` dispatcher plus the configured Subsonic credentials. Built at
+/// boot from `[subsonic]`; the password is a plain shared secret (the token
+/// scheme needs it in clear to recompute `md5(password + salt)`).
+pub struct SubsonicRouter {
+    user: String,
+    password: String,
+}
+
+impl SubsonicRouter {
+    pub fn new(user: String, password: String) -> Self {
+        Self { user, password }
+    }
+
+    /// Answer `/rest/<action>[.view]`. `params` is the query string merged
+    /// with a form body (POST), already percent-decoded.
+    pub fn handle(
+        &self,
+        state: &ApiState,
+        path: &str,
+        params: &HashMap<String, String>,
+    ) -> Response<Cursor<Vec<u8>>> {
+        let json_format = params.get("f").map(String::as_str) == Some("json");
+        let action = action_name(path);
+        let result = self
+            .authenticate(params)
+            .and_then(|()| self.dispatch(state, action, params));
+        match result {
+            Ok(Reply::Body(payload)) => render(json_format, true, payload),
+            Ok(Reply::Redirect(url)) => redirect(&url),
+            Err(failure) => {
+                if failure.code != ERR_WRONG_CREDENTIALS {
+                    log::warn!("[subsonic] {action}: {}", failure.message);
+                }
+                let error = json!({"code": failure.code, "message": failure.message});
+                render(json_format, false, body_map("error", error))
+            }
+        }
+    }
+
+    /// `u` + either `t`/`s` (md5 token) or `p` (clear or `enc:`-hex password).
+    fn authenticate(&self, params: &HashMap<String, String>) -> Result<(), Failure> {
+        let user = params
+            .get("u")
+            .ok_or_else(|| Failure::new(ERR_MISSING_PARAM, "required parameter 'u' is missing"))?;
+        let ok = if let (Some(token), Some(salt)) = (params.get("t"), params.get("s")) {
+            let expected = md5_hex(&format!("{}{salt}", self.password));
+            constant_time_eq(token.to_ascii_lowercase().as_bytes(), expected.as_bytes())
+        } else if let Some(password) = params.get("p") {
+            let clear = match password.strip_prefix("enc:") {
+                Some(hex) => decode_hex(hex).unwrap_or_default(),
+                None => password.clone(),
+            };
+            constant_time_eq(clear.as_bytes(), self.password.as_bytes())
+        } else {
+            return Err(Failure::new(
+                ERR_MISSING_PARAM,
+                "required parameter 'p' or 't'/'s' is missing",
+            ));
+        };
+        if ok && constant_time_eq(user.as_bytes(), self.user.as_bytes()) {
+            Ok(())
+        } else {
+            Err(Failure::new(
+                ERR_WRONG_CREDENTIALS,
+                "wrong username or password",
+            ))
+        }
+    }
+
+    fn dispatch(
+        &self,
+        state: &ApiState,
+        action: &str,
+        params: &HashMap<String, String>,
+    ) -> Result<Reply, Failure> {
+        match action {
+            "ping" => Ok(Reply::Body(Map::new())),
+            "getLicense" => Ok(body("license", json!({"valid": true}))),
+            "getMusicFolders" => Ok(body(
+                "musicFolders",
+                json!({"musicFolder": [{"id": 1, "name": "Qobuz"}]}),
+            )),
+            "getIndexes" => indexes(state),
+            "getMusicDirectory" => music_directory(state, required(params, "id")?),
+            "getSong" => song(state, required(params, "id")?),
+            "stream" => stream(state, required(params, "id")?),
+            "search3" => search3(state, params),
+            "scrobble" => {
+                let time = params.get("time").and_then(|t| t.parse::<u64>().ok());
+                let submission = params.get("submission").map(String::as_str) != Some("false");
+                handle_scrobble(state, required(params, "id")?, time, submission)
+                    .map(|()| Reply::Body(Map::new()))
+            }
+            other => Err(Failure::new(
+                ERR_NOT_FOUND,
+                format!("action '{other}' is not supported"),
+            )),
+        }
+    }
+}
+
+enum Reply {
+    Body(Map<String, Value>),
+    Redirect(String),
+}
+
+fn body(key: &str, value: Value) -> Reply {
+    Reply::Body(body_map(key, value))
+}
+
+// ============================ actions ============================
+
+/// `getIndexes`: the favorite artists, bucketed by initial ("#" for
+/// non-letters), a leading "The " ignored.
+fn indexes(state: &ApiState) -> Result<Reply, Failure> {
+    session_gate(state)?;
+    let favorites = state
+        .rt
+        .block_on(
+            state
+                .runtime
+                .core()
+                .get_favorites("artists", INDEX_ARTISTS, 0),
+        )
+        .map_err(|e| upstream(&e))?;
+    let artists: Vec<(u64, String)> = favorites
+        .pointer("/artists/items")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|a| {
+                    Some((a.get("id")?.as_u64()?, a.get("name")?.as_str()?.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let index: Vec<Value> = group_by_initial(artists)
+        .into_iter()
+        .map(|(name, artists)| {
+            let artist: Vec<Value> = artists
+                .into_iter()
+                .map(|(id, name)| json!({"id": format!("ar-{id}"), "name": name}))
+                .collect();
+            json!({"name": name, "artist": artist})
+        })
+        .collect();
+    Ok(body(
+        "indexes",
+        json!({"lastModified": 0, "ignoredArticles": "The", "index": index}),
+    ))
+}
+
+/// `getMusicDirectory`: `ar-<id>` lists the artist's albums, `al-<id>` the
+/// album's tracks.
+fn music_directory(state: &ApiState, id: &str) -> Result<Reply, Failure> {
+    session_gate(state)?;
+    let core = state.runtime.core();
+    match parse_id(id) {
+        Some(ItemId::Artist(artist_id)) => {
+            let albums = state
+                .rt
+                .block_on(core.get_artist_albums(artist_id, Some(ARTIST_ALBUMS), Some(0)))
+                .map_err(|_| not_found("artist", id))?;
+            let name = albums
+                .items
+                .iter()
+                .find(|a| a.artist.id == artist_id)
+                .map(|a| a.artist.name.clone())
+                .unwrap_or_default();
+            let child: Vec<Value> = albums.items.iter().map(album_child).collect();
+            Ok(body(
+                "directory",
+                json!({"id": id, "name": name, "child": child}),
+            ))
+        }
+        Some(ItemId::Album(album_id)) => {
+            let album = state
+                .rt
+                .block_on(core.get_album(&album_id))
+                .map_err(|_| not_found("album", id))?;
+            let quality = super::playback::resolve_quality(state);
+            let child: Vec<Value> = album
+                .tracks
+                .iter()
+                .flat_map(|tc| tc.items.iter())
+                .map(|t| song_child(t, Some(&album), quality))
+                .collect();
+            Ok(body(
+                "directory",
+                json!({
+                    "id": id,
+                    "parent": format!("ar-{}", album.artist.id),
+                    "name": album.title,
+                    "child": child,
+                }),
+            ))
+        }
+        _ => Err(not_found("directory", id)),
+    }
+}
+
+fn song(state: &ApiState, id: &str) -> Result<Reply, Failure> {
+    session_gate(state)?;
+    let track_id = track_id(id)?;
+    let track = state
+        .rt
+        .block_on(state.runtime.core().get_track(track_id))
+        .map_err(|_| not_found("song", id))?;
+    let quality = super::playback::resolve_quality(state);
+    Ok(body("song", song_child(&track, None, quality)))
+}
+
+/// `stream`: redirect the client to the Qobuz stream URL.
+fn stream(state: &ApiState, id: &str) -> Result<Reply, Failure> {
+    session_gate(state)?;
+    let track_id = track_id(id)?;
+    let quality = super::playback::resolve_quality(state);
+    let url = state
+        .rt
+        .block_on(state.runtime.core().get_stream_url(track_id, quality))
+        .map_err(|e| upstream(&e))?;
+    Ok(Reply::Redirect(url.url))
+}
+
+/// `search3?query=&artistCount=&artistOffset=&albumCount=&albumOffset=
+/// &songCount=&songOffset=`. A count of 0 skips that category; an empty query
+/// (some clients' "list everything") returns nothing.
+fn search3(state: &ApiState, params: &HashMap<String, String>) -> Result<Reply, Failure> {
+    session_gate(state)?;
+    let query = params
+        .get("query")
+        .map(|q| q.trim().trim_matches('"').to_string())
+        .unwrap_or_default();
+    let page = |kind: &str| {
+        let count = params
+            .get(&format!("{kind}Count"))
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(DEFAULT_COUNT)
+            .min(MAX_COUNT);
+        let offset = params
+            .get(&format!("{kind}Offset"))
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(0);
+        (count, offset)
+    };
+    let mut result = Map::new();
+    if query.is_empty() {
+        return Ok(body("searchResult3", Value::Object(result)));
+    }
+    let core = state.runtime.core();
+
+    let (count, offset) = page("artist");
+    if count > 0 {
+        let artists = state
+            .rt
+            .block_on(core.search_artists(&query, count, offset, None))
+            .map_err(|e| upstream(&e))?;
+        let artist: Vec<Value> = artists
+            .items
+            .iter()
+            .map(|a| json!({"id": format!("ar-{}", a.id), "name": a.name}))
+            .collect();
+        result.insert("artist".into(), Value::Array(artist));
+    }
+    let (count, offset) = page("album");
+    if count > 0 {
+        let albums = state
+            .rt
+            .block_on(core.search_albums(&query, count, offset, None))
+            .map_err(|e| upstream(&e))?;
+        let album: Vec<Value> = albums.items.iter().map(album_child).collect();
+        result.insert("album".into(), Value::Array(album));
+    }
+    let (count, offset) = page("song");
+    if count > 0 {
+        let tracks = state
+            .rt
+            .block_on(core.search_tracks(&query, count, offset, None))
+            .map_err(|e| upstream(&e))?;
+        let quality = super::playback::resolve_quality(state);
+        let song: Vec<Value> = tracks
+            .items
+            .iter()
+            .map(|t| song_child(t, None, quality))
+            .collect();
+        result.insert("song".into(), Value::Array(song));
+    }
+    Ok(body("searchResult3", Value::Object(result)))
+}
+
+/// `scrobble?id=&time=&submission=`: record a play from a Subsonic client.
+/// `submission=true` (the default) is a finished play — it is logged to the
+/// daemon's reco store as a play event and scrobbled to Last.fm /
+/// ListenBrainz, both at `time` (ms since the epoch; now when absent), so a
+/// client submitting plays late doesn't date them to the upload. `false`
+/// only updates "now playing". Provider calls run in the background so the
+/// client isn't held on them. One id per call (a repeated `id` keeps the
+/// last).
+pub fn handle_scrobble(
+    state: &ApiState,
+    id: &str,
+    time: Option<u64>,
+    submission: bool,
+) -> Result<(), Failure> {
+    session_gate(state)?;
+    let track_id = track_id(id)?;
+    let track = state
+        .rt
+        .block_on(state.runtime.core().get_track(track_id))
+        .map_err(|_| not_found("song", id))?;
+    let played_at = time.map(|ms| ms / 1000);
+    if submission {
+        let album = track.album.as_ref();
+        let album_id = album.map(|a| a.id.clone());
+        let artist_id = track.performer.as_ref().map(|p| p.id);
+        let genre_id = album.and_then(|a| a.genre.as_ref()).map(|g| g.id);
+        let logged =
+            qbz_app::settings::reco_store::RecoStore::new_at(&state.roots.data).and_then(|store| {
+                match played_at {
+                    Some(ts) => {
+                        store.log_play_event_at(track.id, album_id, artist_id, genre_id, ts as i64)
+                    }
+                    None => store.log_play_event(track.id, album_id, artist_id, genre_id),
+                }
+            });
+        if let Err(e) = logged {
+            log::warn!("[subsonic] reco play event for {track_id} failed: {e}");
+        }
+    }
+    let queue_track = super::queue::track_to_queue_track(&track);
+    state.rt.spawn(crate::scrobble_engine::submit_external(
+        state.roots.clone(),
+        queue_track,
+        played_at,
+        submission,
+    ));
+    Ok(())
+}
+
+// ============================ shaping ============================
+
+/// An album as a directory child (`isDir`).
+fn album_child(album: &Album) -> Value {
+    let mut child = json!({
+        "id": format!("al-{}", album.id),
+        "parent": format!("ar-{}", album.artist.id),
+        "isDir": true,
+        "title": album.title,
+        "name": album.title,
+        "album": album.title,
+        "artist": album.artist.name,
+        "artistId": format!("ar-{}", album.artist.id),
+    });
+    if let Some(count) = album.tracks_count.or(album.track_count) {
+        child["songCount"] = json!(count);
+    }
+    if let Some(duration) = album.duration {
+        child["duration"] = json!(duration);
+    }
+    if let Some(year) = year(album.release_date_original.as_deref()) {
+        child["year"] = json!(year);
+    }
+    if let Some(genre) = &album.genre {
+        child["genre"] = json!(genre.name);
+    }
+    child
+}
+
+/// A track as a song child. `album` fills in what the track payload lacks
+/// when listing an album directory.
+/// `contentType` and `suffix` of what `stream` serves at `quality`: the MP3
+/// tier is MP3, every other tier FLAC.
+fn stream_format(quality: Quality) -> (&'static str, &'static str) {
+    match quality {
+        Quality::Mp3 => ("audio/mpeg", "mp3"),
+        _ => ("audio/flac", "flac"),
+    }
+}
+
+fn song_child(track: &Track, album: Option<&Album>, quality: Quality) -> Value {
+    let title = match track.version.as_deref().filter(|v| !v.is_empty()) {
+        Some(version) => format!("{} ({version})", track.title),
+        None => track.title.clone(),
+    };
+    let (album_id, album_title) = match (&track.album, album) {
+        (Some(summary), _) => (summary.id.clone(), summary.title.clone()),
+        (None, Some(album)) => (album.id.clone(), album.title.clone()),
+        (None, None) => (String::new(), String::new()),
+    };
+    let artist = track
+        .performer
+        .as_ref()
+        .or(album.map(|a| &a.artist))
+        .map(|a| (a.id, a.name.clone()));
+    let (content_type, suffix) = stream_format(quality);
+    let mut song = json!({
+        "id": format!("tr-{}", track.id),
+        "isDir": false,
+        "title": title,
+        "track": track.track_number,
+        "discNumber": track.media_number.unwrap_or(1),
+        "duration": track.duration,
+        "contentType": content_type,
+        "suffix": suffix,
+        "type": "music",
+        "isVideo": false,
+    });
+    if !album_id.is_empty() {
+        song["parent"] = json!(format!("al-{album_id}"));
+        song["albumId"] = json!(format!("al-{album_id}"));
+        song["album"] = json!(album_title);
+    }
+    if let Some((id, name)) = artist {
+        song["artist"] = json!(name);
+        song["artistId"] = json!(format!("ar-{id}"));
+    }
+    if let Some(year) = album.and_then(|a| year(a.release_date_original.as_deref())) {
+        song["year"] = json!(year);
+    }
+    let genre = track
+        .album
+        .as_ref()
+        .and_then(|a| a.genre.as_ref())
+        .or(album.and_then(|a| a.genre.as_ref()));
+    if let Some(genre) = genre {
+        song["genre"] = json!(genre.name);
+    }
+    song
+}
+
+fn year(date: Option<&str>) -> Option<u32> {
+    date?.get(..4)?.parse().ok()
+}
+
+/// Bucket `(id, name)` artists by initial, sorted within and across buckets.
+fn group_by_initial(artists: Vec<(u64, String)>) -> BTreeMap<String, Vec<(u64, String)>> {
+    let mut groups: BTreeMap<String, Vec<(u64, String)>> = BTreeMap::new();
+    for (id, name) in artists {
+        let initial = sort_name(&name)
+            .chars()
+            .next()
+            .filter(|c| c.is_alphabetic())
+            .map(|c| c.to_uppercase().collect::<String>())
+            .unwrap_or_else(|| "#".to_string());
+        groups.entry(initial).or_default().push((id, name));
+    }
+    for artists in groups.values_mut() {
+        artists.sort_by_key(|(_, name)| sort_name(name).to_lowercase());
+    }
+    groups
+}
+
+fn sort_name(name: &str) -> &str {
+    let trimmed = name.trim();
+    match trimmed.get(..4) {
+        Some(article) if article.eq_ignore_ascii_case("the ") => trimmed[4..].trim_start(),
+        _ => trimmed,
+    }
+}
+
+// ============================ ids ============================
+
+#[derive(Debug, PartialEq)]
+enum ItemId {
+    Artist(u64),
+    Album(String),
+    Track(u64),
+}
+
+fn parse_id(id: &str) -> Option<ItemId> {
+    if let Some(rest) = id.strip_prefix("ar-") {
+        return rest.parse().ok().map(ItemId::Artist);
+    }
+    if let Some(rest) = id.strip_prefix("al-") {
+        return (!rest.is_empty()).then(|| ItemId::Album(rest.to_string()));
+    }
+    id.strip_prefix("tr-")
+        .unwrap_or(id)
+        .parse()
+        .ok()
+        .map(ItemId::Track)
+}
+
+fn track_id(id: &str) -> Result<u64, Failure> {
+    match parse_id(id) {
+        Some(ItemId::Track(id)) => Ok(id),
+        _ => Err(not_found("song", id)),
+    }
+}
+
+// ============================ internals ============================
+
+/// The Qobuz session is required for everything but `ping`/`getLicense`/
+/// `getMusicFolders`.
+fn session_gate(state: &ApiState) -> Result<(), Failure> {
+    let needs_auth = state
+        .shared
+        .lock()
+        .map(|s| s.auth == AuthState::NeedsAuth)
+        .unwrap_or(false);
+    if needs_auth {
+        Err(Failure::new(
+            ERR_GENERIC,
+            "qbzd is not logged in to Qobuz (run: qbzd login)",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn required<'a>(params: &'a HashMap<String, String>, key: &str) -> Result<&'a str, Failure> {
+    params
+        .get(key)
+        .map(String::as_str)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            Failure::new(
+                ERR_MISSING_PARAM,
+                format!("required parameter '{key}' is missing"),
+            )
+        })
+}
+
+fn not_found(kind: &str, id: &str) -> Failure {
+    Failure::new(ERR_NOT_FOUND, format!("{kind} {id} not found"))
+}
+
+fn upstream(e: &dyn std::fmt::Display) -> Failure {
+    Failure::new(ERR_GENERIC, format!("Qobuz request failed: {e}"))
+}
+
+/// `/rest/getSong.view` → `getSong`.
+fn action_name(path: &str) -> &str {
+    let action = path.strip_prefix("/rest/").unwrap_or(path);
+    action.strip_suffix(".view").unwrap_or(action)
+}
+
+/// Percent-decoded form/query map; `+` is a space, as browsers and HTTP
+/// client libraries encode form values.
+pub(crate) fn parse_params(raw: &str) -> HashMap<String, String> {
+    let mut m = HashMap::new();
+    for pair in raw.split('&').filter(|p| !p.is_empty()) {
+        let mut kv = pair.splitn(2, '=');
+        let key = kv.next().unwrap_or("").to_string();
+        let raw = kv.next().unwrap_or("").replace('+', " ");
+        let val = urlencoding::decode(&raw)
+            .map(|c| c.into_owned())
+            .unwrap_or(raw);
+        m.insert(key, val);
+    }
+    m
+}
+
+fn md5_hex(input: &str) -> String {
+    Md5::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn decode_hex(hex: &str) -> Option<String> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    let bytes: Option<Vec<u8>> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect();
+    String::from_utf8(bytes?).ok()
+}
+
+// ============================ rendering ============================
+
+/// Wrap `payload` in the `subsonic-response` envelope, as JSON (`f=json`) or
+/// XML. Always HTTP 200 — Subsonic reports failures in the body.
+fn render(json_format: bool, ok: bool, payload: Map<String, Value>) -> Response<Cursor<Vec<u8>>> {
+    let mut envelope = Map::new();
+    envelope.insert("status".into(), json!(if ok { "ok" } else { "failed" }));
+    envelope.insert("version".into(), json!(SUBSONIC_API_VERSION));
+    envelope.insert("type".into(), json!("qbzd"));
+    envelope.insert("serverVersion".into(), json!(env!("CARGO_PKG_VERSION")));
+    envelope.insert("openSubsonic".into(), json!(true));
+    envelope.extend(payload);
+
+    let (bytes, content_type) = if json_format {
+        let doc = json!({"subsonic-response": envelope});
+        (
+            serde_json::to_vec(&doc).unwrap_or_default(),
+            "application/json",
+        )
+    } else {
+        envelope.insert("xmlns".into(), json!(XMLNS));
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+        write_xml("subsonic-response", &Value::Object(envelope), &mut xml);
+        (xml.into_bytes(), "text/xml; charset=utf-8")
+    };
+    let header = Header::from_bytes(&b"Content-Type"[..], content_type.as_bytes())
+        .expect("static content-type header");
+    Response::from_data(bytes).with_header(header)
+}
+
+fn redirect(url: &str) -> Response<Cursor<Vec<u8>>> {
+    let response = Response::from_data(Vec::new()).with_status_code(302);
+    match Header::from_bytes(&b"Location"[..], url.as_bytes()) {
+        Ok(location) => response.with_header(location),
+        Err(()) => render(
+            false,
+            false,
+            body_map(
+                "error",
+                json!({"code": ERR_GENERIC, "message": "bad stream url"}),
+            ),
+        ),
+    }
+}
+
+fn body_map(key: &str, value: Value) -> Map<String, Value> {
+    let mut map = Map::new();
+    map.insert(key.into(), value);
+    map
+}
+
+/// The Subsonic JSON↔XML mapping: an object is an element whose scalar fields
+/// are attributes and whose object fields are child elements; an array is the
+/// element repeated once per item.
+fn write_xml(name: &str, value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            out.push('<');
+            out.push_str(name);
+            for (key, v) in map {
+                if let Some(text) = scalar_text(v) {
+                    out.push_str(&format!(" {key}=\"{}\"", xml_escape(&text)));
+                }
+            }
+            let children: Vec<(&String, &Value)> = map
+                .iter()
+                .filter(|(_, v)| v.is_object() || v.is_array())
+                .collect();
+            if children.is_empty() {
+                out.push_str("/>");
+                return;
+            }
+            out.push('>');
+            for (key, v) in children {
+                write_xml(key, v, out);
+            }
+            out.push_str(&format!("</{name}>"));
+        }
+        Value::Array(items) => {
+            for item in items {
+                write_xml(name, item, out);
+            }
+        }
+        scalar => {
+            let text = scalar_text(scalar).unwrap_or_default();
+            out.push_str(&format!("<{name}>{}</{name}>", xml_escape(&text)));
+        }
+    }
+}
+
+fn scalar_text(v: &Value) -> Option<String> {
+    match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router() -> SubsonicRouter {
+        SubsonicRouter::new("alice".into(), "sesame".into())
+    }
+
+    #[test]
+    fn token_and_password_auth_accept_only_the_configured_credentials() {
+        let r = router();
+        // Token auth: t = md5(password + salt).
+        let token = md5_hex("sesamec19b2d");
+        let ok = parse_params(&format!("u=alice&t={token}&s=c19b2d"));
+        assert_eq!(r.authenticate(&ok), Ok(()));
+        let wrong_salt = parse_params(&format!("u=alice&t={token}&s=other"));
+        assert_eq!(
+            r.authenticate(&wrong_salt).unwrap_err().code,
+            ERR_WRONG_CREDENTIALS
+        );
+        // Clear and hex-encoded passwords.
+        assert_eq!(r.authenticate(&parse_params("u=alice&p=sesame")), Ok(()));
+        assert_eq!(
+            r.authenticate(&parse_params("u=alice&p=enc:736573616d65")),
+            Ok(())
+        );
+        assert_eq!(
+            r.authenticate(&parse_params("u=bob&p=sesame"))
+                .unwrap_err()
+                .code,
+            ERR_WRONG_CREDENTIALS
+        );
+        assert_eq!(
+            r.authenticate(&parse_params("u=alice")).unwrap_err().code,
+            ERR_MISSING_PARAM
+        );
+    }
+
+    #[test]
+    fn ids_and_action_names_parse() {
+        assert_eq!(parse_id("ar-42"), Some(ItemId::Artist(42)));
+        assert_eq!(
+            parse_id("al-0060254735180"),
+            Some(ItemId::Album("0060254735180".into()))
+        );
+        assert_eq!(parse_id("tr-7"), Some(ItemId::Track(7)));
+        assert_eq!(parse_id("7"), Some(ItemId::Track(7)));
+        assert_eq!(parse_id("ar-x"), None);
+        assert_eq!(action_name("/rest/getSong.view"), "getSong");
+        assert_eq!(action_name("/rest/ping"), "ping");
+        assert_eq!(
+            parse_params("query=daft+punk%21").get("query").unwrap(),
+            "daft punk!"
+        );
+    }
+
+    #[test]
+    fn index_buckets_by_initial_ignoring_the_article() {
+        let groups = group_by_initial(vec![
+            (1, "The Beatles".into()),
+            (2, "björk".into()),
+            (3, "2Pac".into()),
+            (4, "Air".into()),
+        ]);
+        let names: Vec<&str> = groups.keys().map(String::as_str).collect();
+        assert_eq!(names, ["#", "A", "B"]);
+        let b: Vec<u64> = groups["B"].iter().map(|(id, _)| *id).collect();
+        assert_eq!(b, [1, 2]);
+    }
+
+    #[test]
+    fn xml_maps_scalars_to_attributes_and_arrays_to_repeated_elements() {
+        let mut xml = String::new();
+        let doc = json!({
+            "status": "ok",
+            "musicFolders": {"musicFolder": [{"id": 1, "name": "A & B"}, {"id": 2, "name": "C"}]},
+        });
+        write_xml("subsonic-response", &doc, &mut xml);
+        assert_eq!(
+            xml,
+            "<subsonic-response status=\"ok\"><musicFolders>\
+             <musicFolder id=\"1\" name=\"A &amp; B\"/><musicFolder id=\"2\" name=\"C\"/>\
+             </musicFolders></subsonic-response>"
+        );
+    }
+
+    #[test]
+    fn songs_report_the_format_stream_serves() {
+        assert_eq!(stream_format(Quality::Mp3), ("audio/mpeg", "mp3"));
+        assert_eq!(stream_format(Quality::Lossless), ("audio/flac", "flac"));
+        assert_eq!(stream_format(Quality::UltraHiRes), ("audio/flac", "flac"));
+    }
+}
@@ -0,0 +1,454 @@
+// crates/qbzd/src/api/ws.rs — the `/api/ws` WebSocket feed (CONSOLE ext).
+// The WebSocket sibling of `/api/events`: the same CoreEvent
+// frames, PLUS a `NowPlaying` snapshot (the exact `GET /api/now-playing`
+// document) pushed on an adaptive cadence so a client never has to poll for
+// the position:
+//   - 250 ms while playing (a smooth progress bar),
+//   - 1 s while paused (a track is loaded, nothing moves),
+//   - 5 s while stopped (a heartbeat).
+//
+// Listener: the feed has its OWN socket, the control-plane port + 1 on the
+// same address (`ws_url` in `/api/info`). A tiny_http upgrade hands back one
+// opaque read+write stream, and a WebSocket has to be read while it is being
+// written to (pings, close) — only a real `TcpStream` can be cloned into a
+// reader half and a writer half. A port that fails to bind disables the feed
+// (logged); the control plane is unaffected. The same origin/token gate as
+// every `/api/*` route runs inside the handshake.
+//
+// Concurrency: one accept thread, then two threads per connection, at most
+// `MAX_CLIENTS` connections at once (more are dropped at accept). A client
+// gets `HANDSHAKE_TIMEOUT` to finish the upgrade.
+//   - The writer owns the WebSocket and a broadcast receiver of the CoreEvent
+//     bus; `Handle::block_on(select!)` waits for the next event, the next
+//     control frame to send, or the next snapshot deadline.
+//   - The reader owns a clone of the stream and parses the client's frames:
+//     a Ping is handed to the writer to answer with a Pong, a Close (or EOF)
+//     makes the writer send the closing handshake and stop.
+// The writer holds the runtime only weakly and no bus sender (the receiver
+// is subscribed by the accept thread), so an open feed keeps neither the
+// `AppRuntime` nor the bus alive past shutdown (§8.2 ordering).
+//
+// Wire format: one text frame per message, `{"type":"…","data":{…}}` — the
+// CoreEvent's own tagged JSON, or `{"type":"NowPlaying","data":{playback,
+// track}}`. Other client → server frames are ignored (the feed is push-only).
+use std::io::{Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc};
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::protocol::{frame::coding::CloseCode, CloseFrame, Role};
+use tungstenite::{Message, WebSocket};
+
+use crate::adapter::DaemonAdapter;
+use crate::state::DaemonShared;
+use qbz_app::shell::AppRuntime;
+use qbz_models::CoreEvent;
+
+use super::{access_gate, error_body, playback, sse};
+
+/// The feed listens on the control-plane port plus this. Shared with
+/// `qbzd watch --ws`, which derives the feed address from `--host`.
+pub const WS_PORT_OFFSET: u16 = 1;
+
+const PLAYING_CADENCE: Duration = Duration::from_millis(250);
+const PAUSED_CADENCE: Duration = Duration::from_secs(1);
+const STOPPED_CADENCE: Duration = Duration::from_secs(5);
+
+/// Open feed connections allowed at once; each costs two threads.
+const MAX_CLIENTS: usize = 16;
+/// How long a client may take to send its upgrade request.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What the feed threads need — cloned out of `ApiState` (which stays on the
+/// control-plane thread).
+pub struct WsFeed {
+    pub runtime: Weak<AppRuntime<DaemonAdapter>>,
+    pub shared: Arc<Mutex<DaemonShared>>,
+    pub rt: tokio::runtime::Handle,
+    pub bus: broadcast::Sender<CoreEvent>,
+    pub token: Option<String>,
+}
+
+/// Bind the feed socket next to the control plane (boot step 5, alongside
+/// [`super::bind`]). `None` = no feed this run; the reason is logged.
+pub fn bind(api: SocketAddr) -> Option<TcpListener> {
+    let port = api.port().checked_add(WS_PORT_OFFSET)?;
+    let addr = SocketAddr::new(api.ip(), port);
+    match TcpListener::bind(addr) {
+        Ok(listener) => Some(listener),
+        Err(e) => {
+            log::warn!("websocket feed disabled: could not bind {addr}: {e}");
+            None
+        }
+    }
+}
+
+/// Live accept loop. [`WsHandle::shutdown`] stops it and joins the thread,
+/// dropping its bus sender. Open feeds end when the bus closes (the daemon
+/// drops the runtime's sender) or at their next snapshot once the runtime
+/// is gone.
+pub struct WsHandle {
+    stop: Arc<AtomicBool>,
+    addr: SocketAddr,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl WsHandle {
+    pub fn shutdown(mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the blocking accept with a throwaway connection.
+        let mut wake = self.addr;
+        if wake.ip().is_unspecified() {
+            wake.set_ip(std::net::Ipv4Addr::LOCALHOST.into());
+        }
+        let _ = TcpStream::connect_timeout(&wake, Duration::from_millis(500));
+        if let Some(t) = self.thread.take() {
+            let _ = t.join();
+        }
+    }
+}
+
+/// What a connection thread needs: [`WsFeed`] without the bus sender, which
+/// stays on the accept thread so open feeds don't keep the bus open.
+struct Client {
+    runtime: Weak<AppRuntime<DaemonAdapter>>,
+    shared: Arc<Mutex<DaemonShared>>,
+    rt: tokio::runtime::Handle,
+    token: Option<String>,
+}
+
+/// One of the [`MAX_CLIENTS`] connection slots, freed on drop.
+struct Slot(Arc<AtomicUsize>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Boot step 11: accept feed connections, each on its own pair of threads.
+pub fn serve(listener: TcpListener, feed: WsFeed) -> Option<WsHandle> {
+    let addr = listener.local_addr().ok()?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+    let WsFeed {
+        runtime,
+        shared,
+        rt,
+        bus,
+        token,
+    } = feed;
+    let client = Arc::new(Client {
+        runtime,
+        shared,
+        rt,
+        token,
+    });
+    let active = Arc::new(AtomicUsize::new(0));
+    let thread = std::thread::Builder::new()
+        .name("qbzd-ws-accept".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                if active.load(Ordering::SeqCst) >= MAX_CLIENTS {
+                    log::debug!(
+                        "websocket feed full ({MAX_CLIENTS} clients), dropping a connection"
+                    );
+                    let _ = stream.shutdown(Shutdown::Both);
+                    continue;
+                }
+                active.fetch_add(1, Ordering::SeqCst);
+                let slot = Slot(active.clone());
+                let client = client.clone();
+                let rx = bus.subscribe();
+                std::thread::Builder::new()
+                    .name("qbzd-ws".into())
+                    .spawn(move || {
+                        connection(stream, &client, rx);
+                        drop(slot);
+                    })
+                    .ok();
+            }
+        })
+        .ok()?;
+    log::info!("websocket feed listening on ws://{addr}/api/ws");
+    Some(WsHandle {
+        stop,
+        addr,
+        thread: Some(thread),
+    })
+}
+
+/// One client: handshake (with the access gate), spawn the reader half, run
+/// the writer half here until the client or the bus goes away.
+fn connection(stream: TcpStream, feed: &Client, rx: broadcast::Receiver<CoreEvent>) {
+    let (reader, closer) = match (stream.try_clone(), stream.try_clone()) {
+        (Ok(r), Ok(c)) => (r, c),
+        _ => return,
+    };
+    // A client that never finishes the upgrade must not hold its slot. The
+    // clones share the socket, so clearing the timeouts covers the reader.
+    if stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).is_err()
+        || stream.set_write_timeout(Some(HANDSHAKE_TIMEOUT)).is_err()
+    {
+        return;
+    }
+    let token = feed.token.clone();
+    let mut ws = match tungstenite::accept_hdr(stream, |req: &Request, resp: Response| {
+        gate(req, token.as_deref()).map(|()| resp)
+    }) {
+        Ok(ws) => ws,
+        Err(e) => {
+            log::debug!("websocket handshake refused: {e}");
+            return;
+        }
+    };
+    let _ = ws.get_ref().set_read_timeout(None);
+    let _ = ws.get_ref().set_write_timeout(None);
+
+    let (control_tx, control_rx) = mpsc::unbounded_channel();
+    std::thread::Builder::new()
+        .name("qbzd-ws-read".into())
+        .spawn(move || read_client(reader, control_tx))
+        .ok();
+
+    if push(&mut ws, feed, rx, control_rx).is_err() {
+        log::debug!("websocket client disconnected");
+    }
+    let _ = ws.close(None);
+    let _ = ws.flush();
+    // Unblocks the reader if the client never sent a Close.
+    let _ = closer.shutdown(Shutdown::Both);
+}
+
+/// The handshake-time access decision: only `/api/ws`, behind the same
+/// Origin shield and Bearer check as the control plane.
+fn gate(req: &Request, token: Option<&str>) -> Result<(), ErrorResponse> {
+    if req.uri().path() != "/api/ws" {
+        return Err(error_response(
+            404,
+            "not_found",
+            "no such route",
+            "the feed is served at /api/ws",
+        ));
+    }
+    let has_origin = req.headers().contains_key("Origin");
+    let auth = req
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok());
+    match access_gate(has_origin, "GET", "/api/ws", auth, token) {
+        None => Ok(()),
+        Some(reject) => {
+            let (status, code, message, hint) = reject.parts();
+            Err(error_response(status, code, message, hint))
+        }
+    }
+}
+
+fn error_response(status: u16, code: &str, message: &str, hint: &str) -> ErrorResponse {
+    let mut resp = ErrorResponse::new(Some(error_body(code, message, hint).to_string()));
+    *resp.status_mut() = tungstenite::http::StatusCode::from_u16(status)
+        .unwrap_or(tungstenite::http::StatusCode::BAD_REQUEST);
+    resp
+}
+
+/// What the reader half asks the writer half to do.
+#[derive(Debug, PartialEq)]
+enum Control {
+    Pong(Vec<u8>),
+    Close,
+}
+
+/// The reader half: parse the client's frames until Close, EOF or an error.
+/// Its WebSocket writes into the void ([`ReadHalf`]) — every reply goes
+/// through the writer, so frames on the wire never interleave.
+fn read_client<S: Read>(stream: S, control: mpsc::UnboundedSender<Control>) {
+    let mut ws = WebSocket::from_raw_socket(ReadHalf(stream), Role::Server, None);
+    loop {
+        match ws.read() {
+            Ok(Message::Ping(payload)) => {
+                if control.send(Control::Pong(payload.to_vec())).is_err() {
+                    return;
+                }
+            }
+            Ok(Message::Close(_)) | Err(_) => {
+                let _ = control.send(Control::Close);
+                return;
+            }
+            Ok(_) => {} // text/binary/pong: the feed is push-only
+        }
+    }
+}
+
+/// A stream's read side, with writes discarded: the reader's WebSocket queues
+/// its automatic replies (Pong, Close echo) here instead of on the wire.
+struct ReadHalf<S>(S);
+
+impl<S: Read> Read for ReadHalf<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<S> Write for ReadHalf<S> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The writer half. `Ok` = a clean end (bus closed, client closed, daemon
+/// shutting down); `Err` = the client is gone.
+fn push<S>(
+    ws: &mut WebSocket<S>,
+    feed: &Client,
+    mut rx: broadcast::Receiver<CoreEvent>,
+    mut control: mpsc::UnboundedReceiver<Control>,
+) -> tungstenite::Result<()>
+where
+    S: Read + Write,
+{
+    let mut next_snapshot = Instant::now();
+    loop {
+        let wait = next_snapshot.saturating_duration_since(Instant::now());
+        let woke = feed.rt.block_on(async {
+            tokio::select! {
+                ev = rx.recv() => Wake::Event(ev),
+                ctl = control.recv() => Wake::Control(ctl.unwrap_or(Control::Close)),
+                _ = tokio::time::sleep(wait) => Wake::Snapshot,
+            }
+        });
+        match woke {
+            Wake::Event(Ok(ev)) => {
+                if let Some(frame) = sse::event_value(&ev) {
+                    ws.send(Message::text(frame.to_string()))?;
+                }
+            }
+            Wake::Event(Err(broadcast::error::RecvError::Lagged(n))) => {
+                let frame = serde_json::json!({"type": "Lagged", "data": {"skipped": n}});
+                ws.send(Message::text(frame.to_string()))?;
+            }
+            Wake::Event(Err(broadcast::error::RecvError::Closed)) => {
+                ws.close(Some(CloseFrame {
+                    code: CloseCode::Away,
+                    reason: "daemon shutting down".into(),
+                }))?;
+                return Ok(());
+            }
+            Wake::Control(Control::Pong(payload)) => ws.send(Message::Pong(payload.into()))?,
+            Wake::Control(Control::Close) => return Ok(()),
+            Wake::Snapshot => {
+                let Some(runtime) = feed.runtime.upgrade() else {
+                    return Ok(());
+                };
+                let doc = playback::now_playing_doc(&runtime, &feed.shared, &feed.rt);
+                drop(runtime);
+                next_snapshot = Instant::now() + cadence(&doc);
+                let frame = serde_json::json!({"type": "NowPlaying", "data": doc});
+                ws.send(Message::text(frame.to_string()))?;
+            }
+        }
+    }
+}
+
+enum Wake {
+    Event(Result<CoreEvent, broadcast::error::RecvError>),
+    Control(Control),
+    Snapshot,
+}
+
+/// Snapshot interval for a now-playing document: fast while playing, slower
+/// while paused on a loaded track, a heartbeat while stopped.
+fn cadence(doc: &Value) -> Duration {
+    let playback = &doc["playback"];
+    if playback["is_playing"].as_bool().unwrap_or(false) {
+        PLAYING_CADENCE
+    } else if playback["track_id"].as_u64().unwrap_or(0) != 0 {
+        PAUSED_CADENCE
+    } else {
+        STOPPED_CADENCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cadence_follows_the_playback_state() {
+        let doc = |playing: bool, track_id: u64| serde_json::json!({"playback": {"is_playing": playing, "track_id": track_id}});
+        assert_eq!(cadence(&doc(true, 42)), PLAYING_CADENCE);
+        assert_eq!(cadence(&doc(false, 42)), PAUSED_CADENCE);
+        assert_eq!(cadence(&doc(false, 0)), STOPPED_CADENCE);
+        assert_eq!(cadence(&Value::Null), STOPPED_CADENCE);
+    }
+
+    #[test]
+    fn reader_hands_pings_and_close_to_the_writer() {
+        // Client frames are masked; an all-zero mask leaves the payload as is.
+        let mut wire = vec![0x89, 0x82, 0, 0, 0, 0, b'h', b'i']; // Ping "hi"
+        wire.extend([0x81, 0x81, 0, 0, 0, 0, b'x']); // Text "x" (ignored)
+        wire.extend([0x88, 0x80, 0, 0, 0, 0]); // Close
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        read_client(std::io::Cursor::new(wire), tx);
+        assert_eq!(rx.try_recv(), Ok(Control::Pong(b"hi".to_vec())));
+        assert_eq!(rx.try_recv(), Ok(Control::Close));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn reader_treats_eof_as_close() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        read_client(std::io::Cursor::new(Vec::new()), tx);
+        assert_eq!(rx.try_recv(), Ok(Control::Close));
+    }
+
+    #[test]
+    fn handshake_applies_the_access_gate() {
+        let req = |path: &str, origin: bool, auth: Option<&str>| {
+            let mut b = tungstenite::http::Request::builder().uri(path);
+            if origin {
+                b = b.header("Origin", "http://example.com");
+            }
+            if let Some(a) = auth {
+                b = b.header("Authorization", a);
+            }
+            b.body(()).unwrap()
+        };
+        assert!(gate(&req("/api/ws", false, None), None).is_ok());
+        assert_eq!(
+            gate(&req("/other", false, None), None)
+                .unwrap_err()
+                .status(),
+            404
+        );
+        assert_eq!(
+            gate(&req("/api/ws", true, None), None)
+                .unwrap_err()
+                .status(),
+            403
+        );
+        assert_eq!(
+            gate(&req("/api/ws", false, None), Some("s3cret"))
+                .unwrap_err()
+                .status(),
+            401
+        );
+        assert!(gate(
+            &req("/api/ws", false, Some("Bearer s3cret")),
+            Some("s3cret")
+        )
+        .is_ok());
+    }
+}
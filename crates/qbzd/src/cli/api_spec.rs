@@ -0,0 +1,19 @@
+// crates/qbzd/src/cli/api_spec.rs — the `qbzd api-spec` verb: prints the
+// running daemon's OpenAPI 3.0 document (GET /api/openapi.json), pretty JSON,
+// ready to feed a client generator or an API browser.
+use crate::cli::client::ApiClient;
+use crate::paths::ProfileRoots;
+
+pub async fn api_spec(host: Option<String>, roots: &ProfileRoots) -> i32 {
+    let client = ApiClient::new(host, roots);
+    match client.get("/api/openapi.json").await {
+        Ok(v) => {
+            println!("{}", serde_json::to_string_pretty(&v).unwrap_or_default());
+            0
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            e.exit_code()
+        }
+    }
+}
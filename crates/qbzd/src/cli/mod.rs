@@ -3,6 +3,7 @@
 // The CLI is a stateless renderer (02-cli-and-api.md §1.1); the copy strings it
 // prints are normative (§1.4 error voice, §2.2 per-verb output). Keeping them in
 // one place lets the spec and the code diff cleanly.
+pub mod api_spec;
 pub mod art;
 pub mod browse;
 pub mod client;
@@ -823,27 +823,39 @@ pub fn export(roots: &ProfileRoots, file: Option<String>, from: &str, include_au
     0
 }
 
-/// `qbzd settings import FILE [--include-auth] [--trust-dsd] [--remap OLD=NEW]...
+/// Where `settings import` reads its bundle from.
+pub enum ImportSource<'a> {
+    /// A `.qbzb` file path.
+    File(&'a str),
+    /// The text of a desktop settings QR code (`--qr`): gzip + base64, never
+    /// carrying credentials.
+    QrPayload(&'a str),
+}
+
+/// `qbzd settings import FILE [--qr] [--include-auth] [--trust-dsd] [--remap OLD=NEW]...
 /// [--dry-run]` (⬇, 04 §5.3). read → version-gate → plan → (TTY device re-pick /
 /// non-tty safe defaults) → validate secrets BEFORE any write → apply →
 /// reload-nudge → three-bucket summary. Exit: 0 · 1 · 2 · 4.
 pub async fn import(
     roots: &ProfileRoots,
-    file: &str,
+    source: ImportSource<'_>,
     include_auth: bool,
     trust_dsd: bool,
     remap_raw: &[String],
     dry_run: bool,
 ) -> i32 {
     // Step 1: read + parse.
-    let text = match std::fs::read_to_string(file) {
-        Ok(t) => t,
-        Err(e) => {
-            eprintln!("error: cannot read bundle: {e}");
-            return 1;
-        }
+    let parsed = match source {
+        ImportSource::File(file) => match std::fs::read_to_string(file) {
+            Ok(text) => Bundle::parse(&text),
+            Err(e) => {
+                eprintln!("error: cannot read bundle: {e}");
+                return 1;
+            }
+        },
+        ImportSource::QrPayload(payload) => Bundle::from_qr_payload(payload),
     };
-    let bundle = match Bundle::parse(&text) {
+    let bundle = match parsed {
         Ok(b) => b,
         Err(bundle::BundleError::VersionMalformed) => {
             eprintln!("error: cannot read bundle: missing or non-integer schema_version");
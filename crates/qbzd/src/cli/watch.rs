@@ -7,15 +7,25 @@
 // through verbatim (event:/data:/comment lines). Unlike `ApiClient` this uses a
 // bespoke reqwest client with NO read timeout (the stream is meant to stay
 // open); only the connect attempt is bounded.
+//
+// `--ws` reads the `GET /api/ws` WebSocket feed instead (the shipped caller of
+// that route): the same events plus the adaptive `NowPlaying` snapshots, one
+// JSON frame per line. The feed listens on the control port + 1
+// (`api::ws::WS_PORT_OFFSET`).
 use std::io::Write;
 use std::time::Duration;
 
-use crate::cli::client::{resolve_host, resolve_token, CliError};
+use crate::cli::client::{resolve_host, resolve_token, CliError, Target};
 use crate::paths::ProfileRoots;
 
-pub async fn watch(host: Option<String>, raw: bool, roots: &ProfileRoots) -> i32 {
+pub async fn watch(host: Option<String>, raw: bool, ws: bool, roots: &ProfileRoots) -> i32 {
     let target = resolve_host(host);
     let token = resolve_token(&target, roots);
+    if ws {
+        return tokio::task::spawn_blocking(move || watch_ws(target, token))
+            .await
+            .unwrap_or(1);
+    }
     let base = format!("http://{}", target.addr);
 
     let client = match reqwest::Client::builder()
@@ -97,3 +107,92 @@ pub async fn watch(host: Option<String>, raw: bool, roots: &ProfileRoots) -> i32
         }
     }
 }
+
+/// Blocking WebSocket reader for `--ws` (tungstenite's sync client, on a
+/// blocking thread). Prints every text frame verbatim — each is one JSON
+/// object.
+fn watch_ws(target: Target, token: Option<String>) -> i32 {
+    use tungstenite::client::IntoClientRequest;
+
+    let Some(addr) = ws_addr(&target.addr) else {
+        eprintln!(
+            "error: cannot derive the WebSocket port from {}",
+            target.addr
+        );
+        return 1;
+    };
+    let mut request = match format!("ws://{addr}/api/ws").into_client_request() {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return 1;
+        }
+    };
+    if let Some(t) = &token {
+        if let Ok(value) = format!("Bearer {t}").parse() {
+            request.headers_mut().insert("Authorization", value);
+        }
+    }
+
+    let mut socket = match tungstenite::connect(request) {
+        Ok((socket, _)) => socket,
+        Err(tungstenite::Error::Http(resp)) => {
+            let code = resp.status().as_u16();
+            let hint = if code == 401 || code == 403 {
+                " — check QBZD_TOKEN / the daemon [server] token"
+            } else {
+                ""
+            };
+            eprintln!("error: daemon returned {code}{hint}");
+            return 1;
+        }
+        Err(_) => {
+            let err = CliError::Unreachable(target.addr);
+            eprintln!("{err}");
+            return err.exit_code();
+        }
+    };
+
+    let stdout = std::io::stdout();
+    loop {
+        match socket.read() {
+            Ok(tungstenite::Message::Text(text)) => {
+                let mut lock = stdout.lock();
+                let _ = writeln!(lock, "{text}");
+                let _ = lock.flush();
+            }
+            Ok(tungstenite::Message::Close(_)) | Err(tungstenite::Error::ConnectionClosed) => {
+                eprintln!("{}", CliError::Unreachable(target.addr.clone()));
+                return CliError::Unreachable(target.addr).exit_code();
+            }
+            Ok(_) => {} // ping/pong/binary: nothing to print
+            Err(e) => {
+                eprintln!("error: websocket read failed: {e}");
+                return 1;
+            }
+        }
+    }
+}
+
+/// `host:port` of the control plane -> `host:port+1` of the WebSocket feed.
+fn ws_addr(api_addr: &str) -> Option<String> {
+    let (host, port) = api_addr.rsplit_once(':')?;
+    let port = port
+        .parse::<u16>()
+        .ok()?
+        .checked_add(crate::api::ws::WS_PORT_OFFSET)?;
+    Some(format!("{host}:{port}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ws_addr_is_the_next_port() {
+        assert_eq!(ws_addr("127.0.0.1:8182").as_deref(), Some("127.0.0.1:8183"));
+        assert_eq!(ws_addr("[::1]:8182").as_deref(), Some("[::1]:8183"));
+        assert_eq!(ws_addr("host:65535"), None);
+        assert_eq!(ws_addr("host"), None);
+    }
+}
@@ -12,6 +12,7 @@ pub struct QbzdConfig {
     pub server: ServerCfg,
     pub log: LogCfg,
     pub mpris: MprisCfg, // documented now, inert in P0 (01 §11)
+    pub subsonic: SubsonicCfg,
 }
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(default)]
@@ -29,6 +30,12 @@ pub struct ServerCfg {
 #[serde(default)]
 pub struct LogCfg {
     pub level: String,
+    /// `"text"` (default) or `"json"` — one `{"ts","level","module","msg"}`
+    /// object per line on stderr and in the log file, for log aggregators.
+    pub format: String,
+    /// Per-module levels layered over `level`, e.g. `qbz_audio = "debug"`.
+    /// Applied at startup; `RUST_LOG` still replaces `level` wholesale.
+    pub modules: std::collections::BTreeMap<String, String>,
 }
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(default)]
@@ -36,6 +43,17 @@ pub struct MprisCfg {
     pub enabled: bool,
 }
 
+/// Opt-in Subsonic REST surface under `/rest/` (api/subsonic.rs). Both keys
+/// set = enabled with these credentials; either missing = no `/rest/` routes.
+/// The password is stored in clear because Subsonic token auth recomputes
+/// `md5(password + salt)` server-side.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SubsonicCfg {
+    pub user: Option<String>,
+    pub password: Option<String>,
+}
+
 impl Default for ServerCfg {
     fn default() -> Self {
         Self {
@@ -51,6 +69,8 @@ impl Default for LogCfg {
     fn default() -> Self {
         Self {
             level: "info".into(),
+            format: "text".into(),
+            modules: Default::default(),
         }
     }
 }
@@ -67,6 +87,7 @@ impl Default for QbzdConfig {
             server: Default::default(),
             log: Default::default(),
             mpris: Default::default(),
+            subsonic: Default::default(),
         }
     }
 }
@@ -80,7 +101,11 @@ const KNOWN: &[(&str, &str)] = &[
     ("server", "port"),
     ("server", "token"),
     ("log", "level"),
+    ("log", "format"),
+    ("log", "modules"),
     ("mpris", "enabled"),
+    ("subsonic", "user"),
+    ("subsonic", "password"),
 ];
 
 impl QbzdConfig {
@@ -133,6 +158,8 @@ mod tests {
         assert_eq!(c.server.bind, "0.0.0.0");
         assert_eq!(c.server.port, 8182);
         assert_eq!(c.log.level, "info");
+        assert_eq!(c.log.format, "text");
+        assert!(c.log.modules.is_empty());
         assert!(c.mpris.enabled);
         assert!(warns.is_empty());
     }
@@ -143,6 +170,19 @@ mod tests {
         assert_eq!(warns, vec!["[server].bindd".to_string()]);
     }
     #[test]
+    fn log_format_and_module_levels_parse_without_warnings() {
+        let (c, warns) = QbzdConfig::from_str(
+            "[log]\nformat = \"json\"\n[log.modules]\nqbz_audio = \"debug\"\n",
+        )
+        .unwrap();
+        assert_eq!(c.log.format, "json");
+        assert_eq!(
+            c.log.modules.get("qbz_audio").map(String::as_str),
+            Some("debug")
+        );
+        assert!(warns.is_empty(), "known keys must not warn: {warns:?}");
+    }
+    #[test]
     fn server_token_defaults_none_and_parses_when_set() {
         // 02-cli-and-api.md §3.1.2: `[server] token` is opt-in — absent = None
         // (open control plane); present = the shared secret, no warning.
@@ -156,6 +196,18 @@ mod tests {
         assert!(warns.is_empty(), "known key must not warn: {warns:?}");
     }
     #[test]
+    fn subsonic_is_off_by_default_and_parses_credentials() {
+        let (off, _) = QbzdConfig::from_str("").unwrap();
+        assert_eq!(off.subsonic.user, None);
+        assert_eq!(off.subsonic.password, None);
+
+        let (on, warns) =
+            QbzdConfig::from_str("[subsonic]\nuser = \"alice\"\npassword = \"sesame\"\n").unwrap();
+        assert_eq!(on.subsonic.user.as_deref(), Some("alice"));
+        assert_eq!(on.subsonic.password.as_deref(), Some("sesame"));
+        assert!(warns.is_empty(), "known keys must not warn: {warns:?}");
+    }
+    #[test]
     fn server_token_empty_string_parses_as_present_but_filtering_gates_it() {
         // Empty or whitespace-only tokens in the config file parse successfully,
         // but are filtered to None by daemon.rs and client.rs to prevent
@@ -41,7 +41,17 @@ pub struct BootedRuntime {
 /// the unknown-key warnings surfaced by [`QbzdConfig::load`] in `main`.
 pub async fn run(roots: ProfileRoots, cfg: QbzdConfig, warns: Vec<String>) -> Result<i32, String> {
     // 1. argv parse happened in main(). 2. logging:
-    qbz_log::install(&cfg.log.level);
+    if cfg.log.format.eq_ignore_ascii_case("json") {
+        qbz_log::install_structured(&cfg.log.level);
+    } else {
+        qbz_log::install(&cfg.log.level);
+    }
+    for (module, level) in &cfg.log.modules {
+        match level.parse::<log::LevelFilter>() {
+            Ok(level) => qbz_log::set_module_level(module, level),
+            Err(_) => log::warn!("[config] [log.modules] {module}: unknown level {level:?}"),
+        }
+    }
     // 3. config: surface unknown-key warnings (they never abort — D14).
     for w in &warns {
         log::warn!("[config] unknown key: {w}");
@@ -148,6 +158,7 @@ pub async fn run(roots: ProfileRoots, cfg: QbzdConfig, warns: Vec<String>) -> Re
     // the vanishingly small window before that.
     let qconnect_control: Arc<std::sync::OnceLock<crate::qconnect::QconnectControl>> =
         Arc::new(std::sync::OnceLock::new());
+    let ws_bind = bound.ws_addr().map(|addr| addr.to_string());
     let api = crate::api::serve(
         bound,
         crate::api::ApiState {
@@ -156,7 +167,9 @@ pub async fn run(roots: ProfileRoots, cfg: QbzdConfig, warns: Vec<String>) -> Re
             bus: booted.bus.clone(),
             roots: roots.clone(),
             token: cfg.server.token.filter(|t| !t.trim().is_empty()),
+            subsonic: subsonic_router(cfg.subsonic),
             bind: bind_addr.to_string(),
+            ws_bind,
             rt: tokio::runtime::Handle::current(),
             audio: api_audio,
             devices: std::sync::Mutex::new(crate::api::DeviceCache::default()),
@@ -647,6 +660,17 @@ fn resolve_bind_addr(cfg: &QbzdConfig) -> Result<std::net::SocketAddr, String> {
         })
 }
 
+/// The `/rest/` Subsonic router when `[subsonic] user` and `password` are both
+/// set (blank counts as unset). The password is registered as a log secret
+/// before anything can echo it.
+fn subsonic_router(cfg: crate::config::SubsonicCfg) -> Option<crate::api::subsonic::SubsonicRouter> {
+    let user = cfg.user.filter(|u| !u.trim().is_empty())?;
+    let password = cfg.password.filter(|p| !p.is_empty())?;
+    qbz_log::register_secret(password.clone());
+    log::info!("Subsonic API enabled under /rest/ for user '{user}'");
+    Some(crate::api::subsonic::SubsonicRouter::new(user, password))
+}
+
 /// The step-5 bind-conflict diagnosis (02 §8.1-5 / §2.2): a qbzd occupant on a
 /// different data root vs. an unrelated process on the port.
 fn diagnose_port_conflict(addr: std::net::SocketAddr) -> String {
@@ -47,8 +47,9 @@ enum Cmd {
     Ping   { #[arg(long)] json: bool },
     /// One-line now-playing
     Now    { #[arg(long)] json: bool },
-    /// Stream live daemon events (SSE); default = newline-delimited JSON
-    Watch  { #[arg(long)] raw: bool },
+    /// Stream live daemon events (SSE); default = newline-delimited JSON;
+    /// --ws reads the WebSocket feed (events + now-playing snapshots)
+    Watch  { #[arg(long)] raw: bool, #[arg(long)] ws: bool },
     /// Search Qobuz — top hits with ids (--ids pipes into `queue add -`)
     Search {
         query: String,
@@ -119,6 +120,8 @@ enum Cmd {
     Art { #[arg(long)] save: Option<String> },
     /// Resolve a Qobuz URL to a kind:ID token (pure, no daemon)
     Resolve { url: String },
+    /// Print the control plane's OpenAPI 3.0 document (JSON)
+    ApiSpec,
     /// Resume (bare) or play content: album:ID | track:ID | artist:ID | playlist:ID | URL
     Play   { content: Option<String> },
     Pause, Toggle, Stop, Next, Prev,
@@ -217,6 +220,8 @@ enum SettingsCmd {
     },
     Import {
         file: String,
+        /// FILE is the text of a scanned desktop settings QR code
+        #[arg(long)] qr: bool,
         #[arg(long)] include_auth: bool,
         #[arg(long)] trust_dsd: bool,
         #[arg(long)] remap: Vec<String>,   // OLD=NEW, repeatable
@@ -353,9 +358,9 @@ async fn main() {
             let roots = paths::ProfileRoots::resolve(None, None);
             cli::transport::now(cli.host, json, &roots).await
         }
-        Cmd::Watch { raw } => {
+        Cmd::Watch { raw, ws } => {
             let roots = paths::ProfileRoots::resolve(None, None);
-            cli::watch::watch(cli.host, raw, &roots).await
+            cli::watch::watch(cli.host, raw, ws, &roots).await
         }
         Cmd::Search { query, kind, limit, offset, ids, json } => {
             let roots = paths::ProfileRoots::resolve(None, None);
@@ -442,6 +447,10 @@ async fn main() {
             cli::art::art(cli.host, save, &roots).await
         }
         Cmd::Resolve { url } => cli::resolve::resolve(url),
+        Cmd::ApiSpec => {
+            let roots = paths::ProfileRoots::resolve(None, None);
+            cli::api_spec::api_spec(cli.host, &roots).await
+        }
         Cmd::Play { content } => {
             let roots = paths::ProfileRoots::resolve(None, None);
             cli::play::play(cli.host, content, &roots).await
@@ -507,12 +516,18 @@ async fn main() {
                 } => cli::settings::export(&roots, file, &from, include_auth),
                 SettingsCmd::Import {
                     file,
+                    qr,
                     include_auth,
                     trust_dsd,
                     remap,
                     dry_run,
                 } => {
-                    cli::settings::import(&roots, &file, include_auth, trust_dsd, &remap, dry_run)
+                    let source = if qr {
+                        cli::settings::ImportSource::QrPayload(&file)
+                    } else {
+                        cli::settings::ImportSource::File(&file)
+                    };
+                    cli::settings::import(&roots, source, include_auth, trust_dsd, &remap, dry_run)
                         .await
                 }
             }
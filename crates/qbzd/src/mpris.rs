@@ -8,7 +8,8 @@
 //
 // Two halves:
 //   * OUTBOUND — a CoreEvent-bus subscriber pushes now-playing metadata plus
-//     play/pause/position/volume into the OS controls.
+//     play/pause/position/volume, repeat/shuffle and the queue (MPRIS
+//     TrackList) into the OS controls.
 //   * INBOUND — the qbz-media-controls callback maps MediaEvent (media keys,
 //     the desktop widget) back onto core transport commands.
 //
@@ -24,8 +25,8 @@ use std::sync::{Arc, Weak};
 use std::time::Duration;
 
 use qbz_app::shell::AppRuntime;
-use qbz_media_controls::{MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
-use qbz_models::{CoreEvent, PlaybackState, QueueTrack};
+use qbz_media_controls::{LoopMode, MediaEvent, MediaIntegration, PlaybackStatus, TrackMeta};
+use qbz_models::{CoreEvent, PlaybackState, QueueState, QueueTrack, RepeatMode};
 use tokio::runtime::Handle;
 use tokio::sync::broadcast;
 use tokio::task::JoinHandle;
@@ -102,6 +103,9 @@ pub fn spawn(
             if let Some(track) = queue.current_track.as_ref() {
                 updater_integ.set_metadata(&track_meta(track));
             }
+            updater_integ.set_track_list(&track_list(&queue));
+            updater_integ.set_loop_status(loop_mode(queue.repeat));
+            updater_integ.set_shuffle(queue.shuffle);
             let player = rt.core().player();
             let ev = player.get_playback_event();
             last = if ev.is_playing {
@@ -133,6 +137,9 @@ pub fn spawn(
                     }
                 }
                 Ok(CoreEvent::VolumeChanged { volume }) => updater_integ.set_volume(volume as f64),
+                Ok(CoreEvent::QueueUpdated { state }) => updater_integ.set_track_list(&track_list(&state)),
+                Ok(CoreEvent::RepeatModeChanged { mode }) => updater_integ.set_loop_status(loop_mode(mode)),
+                Ok(CoreEvent::ShuffleChanged { enabled }) => updater_integ.set_shuffle(enabled),
                 Ok(_) => {}
                 Err(RecvError::Lagged(_)) => continue,
                 Err(RecvError::Closed) => return,
@@ -197,6 +204,71 @@ fn handle_media_event(rt: &Runtime, roots: &ProfileRoots, handle: &Handle, ev: M
                 let _ = core.set_volume((vol as f32).clamp(0.0, 1.0));
             }
         }
+        MediaEvent::PlayUpcoming(index) => {
+            let rt = rt.clone();
+            let quality = persisted_quality(roots);
+            handle.spawn(async move {
+                if let Some(track) = rt.core().play_upcoming_at(index).await {
+                    play_and_persist(&rt, track.id, quality).await;
+                }
+            });
+        }
+        MediaEvent::RemoveUpcoming(index) => {
+            let rt = rt.clone();
+            handle.spawn(async move {
+                rt.core().remove_upcoming_track(index).await;
+            });
+        }
+        MediaEvent::AddTrack {
+            uri,
+            next,
+            play_now,
+        } => {
+            let Some(track_id) = qbz_media_controls::track_id_from_uri(&uri) else {
+                log::warn!("[mpris] add-track: unsupported uri {uri}");
+                return;
+            };
+            let rt = rt.clone();
+            let quality = persisted_quality(roots);
+            handle.spawn(async move {
+                let track = match rt.core().get_track(track_id).await {
+                    Ok(track) => crate::api::queue::track_to_queue_track(&track),
+                    Err(e) => {
+                        log::warn!("[mpris] add-track {track_id}: {e}");
+                        return;
+                    }
+                };
+                // SetAsCurrent: insert right after the current track and jump
+                // to it, so the rest of the queue survives.
+                if next || play_now {
+                    rt.core().add_track_next(track).await;
+                } else {
+                    rt.core().add_track(track).await;
+                }
+                if play_now {
+                    if let Some(track) = rt.core().play_upcoming_at(0).await {
+                        play_and_persist(&rt, track.id, quality).await;
+                    }
+                }
+            });
+        }
+        // The core emits RepeatModeChanged / ShuffleChanged, which both the SSE
+        // stream and the outbound updater above pick up — so the widget's
+        // property only flips once the core has actually applied it.
+        MediaEvent::SetLoopStatus(mode) => {
+            let rt = rt.clone();
+            handle.spawn(async move {
+                rt.core().set_repeat_mode(repeat_mode(mode)).await;
+                qbz_app::playback_driver::save_session_now(rt.as_ref()).await;
+            });
+        }
+        MediaEvent::SetShuffle(enabled) => {
+            let rt = rt.clone();
+            handle.spawn(async move {
+                rt.core().set_shuffle(enabled).await;
+                qbz_app::playback_driver::save_session_now(rt.as_ref()).await;
+            });
+        }
         // Headless daemon: no window to raise, and self-quit on a media-widget
         // "close" would be surprising — ignore both.
         MediaEvent::Raise | MediaEvent::Quit => {}
@@ -208,14 +280,33 @@ fn handle_media_event(rt: &Runtime, roots: &ProfileRoots, handle: &Handle, ev: M
 /// (the same key the driver seeds at boot).
 fn spawn_advance(rt: &Runtime, roots: &ProfileRoots, handle: &Handle, forward: bool) {
     let rt = rt.clone();
-    let quality = qbz_app::playback_driver::quality_from_key(
-        &qbz_app::settings::daemon_prefs::load_at(&roots.data).streaming_quality,
-    );
+    let quality = persisted_quality(roots);
     handle.spawn(async move {
         let _ = qbz_app::playback_driver::advance_and_play(rt.as_ref(), quality, forward).await;
     });
 }
 
+/// The daemon's persisted streaming quality (the key the driver seeds at boot).
+fn persisted_quality(roots: &ProfileRoots) -> qbz_models::Quality {
+    qbz_app::playback_driver::quality_from_key(
+        &qbz_app::settings::daemon_prefs::load_at(&roots.data).streaming_quality,
+    )
+}
+
+/// Start the track the queue cursor was just moved to and persist the
+/// session — the same resolve+play leg as `POST /api/queue/jump`.
+async fn play_and_persist(rt: &Runtime, track_id: u64, quality: qbz_models::Quality) {
+    if let Err(err) = rt
+        .core()
+        .play_track_resolved(track_id, quality, None, None, 0)
+        .await
+    {
+        log::warn!("[mpris] play of {track_id} failed: {err}");
+        return;
+    }
+    qbz_app::playback_driver::save_session_now(rt.as_ref()).await;
+}
+
 // ============================ mapping ============================
 
 fn track_meta(t: &QueueTrack) -> TrackMeta {
@@ -228,6 +319,32 @@ fn track_meta(t: &QueueTrack) -> TrackMeta {
     }
 }
 
+/// MPRIS TrackList: the current track, then the upcoming queue.
+fn track_list(queue: &QueueState) -> Vec<TrackMeta> {
+    queue
+        .current_track
+        .iter()
+        .chain(queue.upcoming.iter())
+        .map(track_meta)
+        .collect()
+}
+
+fn loop_mode(mode: RepeatMode) -> LoopMode {
+    match mode {
+        RepeatMode::Off => LoopMode::None,
+        RepeatMode::One => LoopMode::Track,
+        RepeatMode::All => LoopMode::Playlist,
+    }
+}
+
+fn repeat_mode(mode: LoopMode) -> RepeatMode {
+    match mode {
+        LoopMode::None => RepeatMode::Off,
+        LoopMode::Track => RepeatMode::One,
+        LoopMode::Playlist => RepeatMode::All,
+    }
+}
+
 fn map_state(s: PlaybackState) -> PlaybackStatus {
     match s {
         PlaybackState::Playing => PlaybackStatus::Playing,
@@ -250,6 +367,15 @@ mod tests {
         assert_eq!(map_state(PlaybackState::Loading), PlaybackStatus::Playing);
     }
 
+    #[test]
+    fn repeat_and_loop_status_map_both_ways() {
+        for mode in [RepeatMode::Off, RepeatMode::All, RepeatMode::One] {
+            assert_eq!(repeat_mode(loop_mode(mode)), mode);
+        }
+        assert_eq!(loop_mode(RepeatMode::One), LoopMode::Track);
+        assert_eq!(loop_mode(RepeatMode::All), LoopMode::Playlist);
+    }
+
     #[test]
     fn enabled_defaults_on_and_respects_falsey_overrides() {
         // Default (unset) is ON; only explicit falsey values disable. We can't
@@ -110,6 +110,34 @@ pub fn spawn(roots: ProfileRoots, mut rx: broadcast::Receiver<CoreEvent>) -> Joi
     })
 }
 
+/// Forward a play reported by a remote client (the Subsonic `scrobble`
+/// action) to the active providers: `submission` scrobbles it at `played_at`
+/// (unix seconds, now when absent), otherwise it is sent as "now playing".
+/// Reads the settings fresh, like the bus loop; a no-op when scrobbling is off.
+pub async fn submit_external(
+    roots: ProfileRoots,
+    track: QueueTrack,
+    played_at: Option<u64>,
+    submission: bool,
+) {
+    let settings = match ScrobblerSettingsStore::new_at(&roots.data) {
+        Ok(store) => store.get_settings().unwrap_or_default(),
+        Err(e) => {
+            log::warn!("[scrobbler] store open failed: {e}");
+            return;
+        }
+    };
+    if !settings.enabled {
+        return;
+    }
+    if submission {
+        let started_at = played_at.unwrap_or_else(now_unix);
+        scrobble(&settings, &track, started_at, &roots).await;
+    } else {
+        now_playing(&settings, &track).await;
+    }
+}
+
 // ============================ internals ============================
 
 /// Whether the current track is due to scrobble now: it has a threshold, has